@@ -11,18 +11,13 @@
 
 use std::io;
 
-use crossterm::{
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
 use ratatui::{
-    backend::CrosstermBackend,
     layout::{Alignment, Constraint, Flex, Layout, Rect},
     style::{Color, Style},
     widgets::{Block, Borders, Paragraph},
-    Frame, Terminal,
+    Frame,
 };
-use tui_dispatch::{debug::DebugLayer, Action, DispatchRuntime, EventKind, RenderContext};
+use tui_dispatch::{debug::DebugLayer, Action, EventKind, RenderContext, Runtime};
 
 // ============================================================================
 // State - What the app knows
@@ -123,38 +118,20 @@ fn map_event(event: &EventKind, _state: &AppState) -> Option<AppAction> {
 }
 
 // ============================================================================
-// Main - Setup terminal, run event loop, cleanup
+// Main - Runtime owns terminal setup and cleanup
 // ============================================================================
 
 #[tokio::main]
 async fn main() -> io::Result<()> {
-    // Terminal setup
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    // start the loop
-    let result = run_app(&mut terminal).await;
-
-    // Cleanup
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
-
-    result
-}
-
-async fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
     // Debug layer (F12 to toggle)
     let debug: DebugLayer<AppAction> = DebugLayer::simple();
 
-    let mut runtime = DispatchRuntime::new(AppState::default(), reducer).with_debug(debug);
-
-    runtime
-        .run(terminal, render_app, map_event, |action| {
-            matches!(action, AppAction::Quit)
-        })
+    Runtime::builder()
+        .state(AppState::default(), reducer)
+        .configure(|runtime| runtime.with_debug(debug))
+        .render(render_app)
+        .map_event(map_event)
+        .quit_on(|action| matches!(action, AppAction::Quit))
+        .run()
         .await
 }
@@ -24,15 +24,10 @@ mod state;
 mod ui;
 
 use clap::Parser;
-use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture, KeyCode, KeyModifiers},
-    execute,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
-};
-use ratatui::{Terminal, backend::CrosstermBackend};
+use crossterm::event::{KeyCode, KeyModifiers};
 use std::io;
 use tui_dispatch::{
-    DispatchRuntime, EventKind, EventOutcome, FeatureFlags,
+    EventKind, EventOutcome, FeatureFlags, Runtime,
     debug::{DebugLayer, DebugSection, DebugState},
 };
 
@@ -67,16 +62,17 @@ struct Args {
 /// Implement DebugState for our AppState
 impl DebugState for AppState {
     fn debug_sections(&self) -> Vec<DebugSection> {
+        let stats = &self.document.stats;
         vec![
             DebugSection::new("Document")
                 .entry("file", &self.file_path)
-                .entry("total_lines", self.stats.total_lines.to_string()),
+                .entry("total_lines", self.document.len().to_string()),
             DebugSection::new("AST Statistics")
-                .entry("headings", self.stats.heading_count.to_string())
-                .entry("links", self.stats.link_count.to_string())
-                .entry("code_blocks", self.stats.code_block_count.to_string())
-                .entry("list_items", self.stats.list_item_count.to_string())
-                .entry("paragraphs", self.stats.paragraph_count.to_string()),
+                .entry("headings", stats.heading_count.to_string())
+                .entry("links", stats.link_count.to_string())
+                .entry("code_blocks", stats.code_block_count.to_string())
+                .entry("list_items", stats.list_item_count.to_string())
+                .entry("paragraphs", stats.paragraph_count.to_string()),
             DebugSection::new("View")
                 .entry("scroll_offset", self.scroll_offset.to_string())
                 .entry("max_scroll", self.max_scroll().to_string())
@@ -107,13 +103,6 @@ async fn main() -> io::Result<()> {
         std::process::exit(1);
     }
 
-    // Terminal setup
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
     // Setup features from CLI args
     let mut features = Features::default();
     for name in &args.enable {
@@ -123,49 +112,31 @@ async fn main() -> io::Result<()> {
         features.disable(name);
     }
 
-    let result = run_app(&mut terminal, args.file, features, args.debug).await;
-
-    // Cleanup
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
-
-    result
-}
-
-async fn run_app<B: ratatui::backend::Backend>(
-    terminal: &mut Terminal<B>,
-    file_path: String,
-    features: Features,
-    debug_enabled: bool,
-) -> io::Result<()> {
-    let mut state = AppState::new(file_path, features);
+    let mut state = AppState::new(args.file, features);
 
     // Update terminal size in state
-    let size = terminal.size()?;
-    state.terminal_height = size.height;
+    let (_, height) = crossterm::terminal::size()?;
+    state.terminal_height = height;
 
     // Debug layer - only active when --debug flag passed
-    let debug: DebugLayer<Action> = DebugLayer::simple().active(debug_enabled);
-
-    let mut runtime = DispatchRuntime::new(state, reducer).with_debug(debug);
-
-    runtime
-        .run(terminal, render_app, map_event, |action| {
-            matches!(action, Action::Quit)
+    let debug: DebugLayer<Action> = DebugLayer::simple().active(args.debug);
+
+    Runtime::builder()
+        .mouse_capture(true)
+        .state(state, reducer)
+        .configure(|runtime| {
+            runtime
+                .with_debug(debug)
+                .on_resize(|_, height| Action::UiTerminalResize(height))
         })
+        .render(render_app)
+        .map_event(map_event)
+        .quit_on(|action| matches!(action, Action::Quit))
+        .run()
         .await
 }
 
 fn map_event(event: &EventKind, state: &AppState) -> EventOutcome<Action> {
-    if let EventKind::Resize(_, height) = event {
-        return EventOutcome::action(Action::UiTerminalResize(*height)).with_render();
-    }
-
     EventOutcome::from(handle_event(event, state))
 }
 
@@ -51,16 +51,12 @@ impl Component<Action> for StatusBar {
                 String::new()
             };
 
-            let line_info = format!(
-                " {}:{} ",
-                state.scroll_offset + 1,
-                state.rendered_lines.len()
-            );
+            let line_info = format!(" {}:{} ", state.scroll_offset + 1, state.document.len());
 
             let stats_info = if state.features.show_stats {
                 format!(
                     " §{} ¶{} ",
-                    state.stats.heading_count, state.stats.paragraph_count
+                    state.document.stats.heading_count, state.document.stats.paragraph_count
                 )
             } else {
                 String::new()
@@ -1,8 +1,8 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout, Rect},
+    layout::{Constraint, Rect},
 };
-use tui_dispatch::RenderContext;
+use tui_dispatch::{GridSpec, RenderContext};
 
 use super::components::{
     Component, ContentView, ContentViewProps, StatusBar, StatusBarProps, TitleBar, TitleBarProps,
@@ -10,25 +10,28 @@ use super::components::{
 use crate::state::AppState;
 
 pub fn render_app(frame: &mut Frame, area: Rect, state: &AppState, _ctx: RenderContext) {
-    let chunks = Layout::vertical([
-        Constraint::Length(1), // Title bar
-        Constraint::Min(1),    // Content
-        Constraint::Length(1), // Status bar
-    ])
-    .split(area);
+    let areas = GridSpec::new()
+        .rows([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(["title", "content", "status"])
+        .resolve(area)
+        .expect("static grid template");
 
     let mut title_bar = TitleBar;
     title_bar.render(
         frame,
-        chunks[0],
+        areas["title"],
         TitleBarProps {
             file_path: &state.file_path,
         },
     );
 
-    let mut content = ContentView;
-    content.render(frame, chunks[1], ContentViewProps { state });
+    let mut content = ContentView::default();
+    content.render(frame, areas["content"], ContentViewProps { state });
 
     let mut status_bar = StatusBar;
-    status_bar.render(frame, chunks[2], StatusBarProps { state });
+    status_bar.render(frame, areas["status"], StatusBarProps { state });
 }
@@ -4,9 +4,10 @@ use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::Color,
 };
-use tui_dispatch::EventKind;
+use tui_dispatch::{EventKind, Theme};
 use tui_dispatch_components::{
-    ModalStyle, SelectList, SelectListProps, TextInput, TextInputProps, centered_rect, render_modal,
+    EditMode, HighlightConfig, InputMask, ModalStyle, ScrollbarConfig, SelectList, SelectListProps,
+    TextInput, TextInputProps, centered_rect, render_modal,
 };
 
 use super::Component;
@@ -65,6 +66,30 @@ impl SearchOverlay {
             .map(|location| location.name.clone())
             .collect()
     }
+
+    fn input_events(&mut self, event: &EventKind, props: SearchOverlayProps<'_>) -> Vec<Action> {
+        let input_props = TextInputProps {
+            value: props.query,
+            placeholder: "Search for a city...",
+            is_focused: true,
+            show_border: false,
+            bg_color: None,
+            theme: &Theme::DARK,
+            padding_x: 0,
+            padding_y: 1,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
+            on_change: props.on_query_change,
+            on_submit: props.on_query_submit,
+        };
+
+        self.input
+            .handle_event(event, input_props)
+            .into_iter()
+            .collect()
+    }
 }
 
 impl Component<Action> for SearchOverlay {
@@ -79,11 +104,11 @@ impl Component<Action> for SearchOverlay {
             return Vec::new();
         }
 
+        // Handle special keys first
         let EventKind::Key(key) = event else {
-            return Vec::new();
+            // Pastes go straight to the input
+            return self.input_events(event, props);
         };
-
-        // Handle special keys first
         match key.code {
             KeyCode::Esc => return vec![Action::SearchClose],
             KeyCode::Enter => {
@@ -105,6 +130,15 @@ impl Component<Action> for SearchOverlay {
                         padding_x: 1,
                         padding_y: 1,
                         highlight_query: None,
+                        highlight: HighlightConfig::default(),
+                        scrollbar: ScrollbarConfig::default(),
+                        theme: &Theme::DARK,
+                        groups: &[],
+                        on_toggle_group: None,
+                        type_ahead: None,
+                        page: None,
+                        on_page_change: None,
+                        keybindings: None,
                         on_select: props.on_select,
                     };
                     return self
@@ -119,22 +153,7 @@ impl Component<Action> for SearchOverlay {
         }
 
         // All other keys go to the input
-        let input_props = TextInputProps {
-            value: props.query,
-            placeholder: "Search for a city...",
-            is_focused: true,
-            show_border: false,
-            bg_color: None,
-            padding_x: 0,
-            padding_y: 1,
-            on_change: props.on_query_change,
-            on_submit: props.on_query_submit,
-        };
-
-        self.input
-            .handle_event(event, input_props)
-            .into_iter()
-            .collect()
+        self.input_events(event, props)
     }
 
     fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
@@ -163,8 +182,13 @@ impl Component<Action> for SearchOverlay {
             is_focused: props.is_focused,
             show_border: false,
             bg_color: Some(Color::Rgb(50, 50, 60)),
+            theme: &Theme::DARK,
             padding_x: 1,
             padding_y: 1,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
             on_change: props.on_query_change,
             on_submit: props.on_query_submit,
         };
@@ -183,6 +207,15 @@ impl Component<Action> for SearchOverlay {
             } else {
                 Some(props.query)
             },
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
             on_select: props.on_select,
         };
         self.list.render(frame, chunks[1], list_props);
@@ -52,7 +52,7 @@ use ratatui::{Frame, Terminal, backend::CrosstermBackend, layout::Rect};
 use tui_dispatch::debug::DebugLayer;
 use tui_dispatch::{
     EffectContext, EffectRuntime, EffectStoreWithMiddleware, EventKind, EventOutcome,
-    RenderContext, TaskKey,
+    RenderContext, TaskKey, disable_bracketed_paste, enable_bracketed_paste,
 };
 
 use crate::action::Action;
@@ -111,6 +111,7 @@ async fn main() -> io::Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    enable_bracketed_paste()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -118,6 +119,7 @@ async fn main() -> io::Result<()> {
     let result = run_app(&mut terminal, location, args.refresh_interval, args.debug).await;
 
     // ===== Cleanup =====
+    disable_bracketed_paste()?;
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -172,10 +174,6 @@ impl WeatherUi {
     }
 
     fn map_event(&mut self, event: &EventKind, state: &AppState) -> EventOutcome<Action> {
-        if let EventKind::Resize(width, height) = event {
-            return EventOutcome::action(Action::UiTerminalResize(*width, *height)).with_render();
-        }
-
         if state.search_mode {
             let props = SearchOverlayProps {
                 query: &state.search_query,
@@ -214,7 +212,9 @@ async fn run_app<B: ratatui::backend::Backend>(
     // Debug layer for inspection (F12) - only active when --debug
     let debug = DebugLayer::simple().active(debug_enabled);
 
-    let mut runtime = EffectRuntime::from_store(store).with_debug(debug);
+    let mut runtime = EffectRuntime::from_store(store)
+        .with_debug(debug)
+        .on_resize(Action::UiTerminalResize);
 
     // Tick timer for loading animation
     runtime
@@ -0,0 +1,377 @@
+//! Text input with a dropdown of asynchronously loaded suggestions
+
+use crossterm::event::KeyCode;
+use ratatui::{layout::Rect, widgets::Clear, Frame};
+use tui_dispatch_core::{Component, EventKind, Theme};
+
+use crate::select_list::{
+    HighlightConfig, ScrollbarConfig, SelectItem, SelectList, SelectListProps,
+};
+use crate::text_input::{EditMode, InputMask, TextInput, TextInputProps};
+
+/// Props for Autocomplete component
+pub struct AutocompleteProps<'a, A, T = String> {
+    /// Current input value
+    pub value: &'a str,
+    /// Placeholder text when empty
+    pub placeholder: &'a str,
+    /// Suggestions for the current query (empty = dropdown hidden)
+    pub suggestions: &'a [T],
+    /// Currently highlighted suggestion
+    pub selected: usize,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Colors for the input and dropdown
+    pub theme: &'a Theme,
+    /// Maximum number of suggestion rows in the dropdown
+    pub max_visible: u16,
+    /// Ticks to wait after the last edit before emitting on_query (0 = immediately)
+    pub debounce_ticks: u32,
+    /// Callback when value changes
+    pub on_change: fn(String) -> A,
+    /// Callback when the value has settled and suggestions should be fetched
+    pub on_query: fn(String) -> A,
+    /// Callback when the highlighted suggestion changes
+    pub on_select: fn(usize) -> A,
+    /// Callback when a suggestion is accepted (Enter)
+    pub on_accept: fn(usize) -> A,
+}
+
+/// Query waiting for the debounce delay to pass
+struct PendingQuery {
+    query: String,
+    ticks_left: u32,
+}
+
+/// A text input that queries for suggestions as the user types
+///
+/// Every edit emits `on_change` right away. `on_query` follows once the
+/// value has been left alone for `debounce_ticks` `Tick` events, so the app
+/// only starts one lookup per pause in typing; the app's async task then
+/// fills `suggestions`. Up/Down move through the dropdown and Enter accepts
+/// the highlighted suggestion. With no suggestions, Enter sends any
+/// still-pending query without waiting.
+pub struct Autocomplete<T = String> {
+    input: TextInput,
+    list: SelectList<T>,
+    pending: Option<PendingQuery>,
+}
+
+impl<T> Default for Autocomplete<T> {
+    fn default() -> Self {
+        Self {
+            input: TextInput::new(),
+            list: SelectList::new(),
+            pending: None,
+        }
+    }
+}
+
+impl<T> Autocomplete<T> {
+    /// Create a new Autocomplete
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Query waiting to be emitted, if the debounce delay hasn't passed yet
+    pub fn pending_query(&self) -> Option<&str> {
+        self.pending.as_ref().map(|pending| pending.query.as_str())
+    }
+
+    /// Props for the wrapped text input, which reports new values directly
+    fn input_props<'a, A>(props: &AutocompleteProps<'a, A, T>) -> TextInputProps<'a, String> {
+        TextInputProps {
+            value: props.value,
+            placeholder: props.placeholder,
+            is_focused: props.is_focused,
+            show_border: true,
+            bg_color: None,
+            theme: props.theme,
+            padding_x: 0,
+            padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
+            on_change: std::convert::identity,
+            on_submit: std::convert::identity,
+        }
+    }
+
+    /// Props for the dropdown list
+    fn list_props<'a, A>(props: &AutocompleteProps<'a, A, T>) -> SelectListProps<'a, A, T> {
+        SelectListProps {
+            items: props.suggestions,
+            selected: props.selected,
+            is_focused: props.is_focused,
+            show_border: true,
+            padding_x: 0,
+            padding_y: 0,
+            highlight_query: Some(props.value).filter(|value| !value.is_empty()),
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: props.theme,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
+            on_select: props.on_select,
+        }
+    }
+}
+
+impl<A, T: SelectItem> Component<A> for Autocomplete<T> {
+    type Props<'a> = AutocompleteProps<'a, A, T>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        let mut actions = Vec::new();
+        if !props.is_focused {
+            return actions;
+        }
+
+        match event {
+            // Count down the debounce delay
+            EventKind::Tick => {
+                if let Some(mut pending) = self.pending.take() {
+                    pending.ticks_left = pending.ticks_left.saturating_sub(1);
+                    if pending.ticks_left == 0 {
+                        actions.push((props.on_query)(pending.query));
+                    } else {
+                        self.pending = Some(pending);
+                    }
+                }
+            }
+            EventKind::Key(key) if key.code == KeyCode::Enter => {
+                if props.suggestions.is_empty() {
+                    if let Some(pending) = self.pending.take() {
+                        actions.push((props.on_query)(pending.query));
+                    }
+                } else {
+                    let index = props.selected.min(props.suggestions.len() - 1);
+                    actions.push((props.on_accept)(index));
+                }
+            }
+            // Up/Down navigate the dropdown instead of the input history
+            EventKind::Key(key) if matches!(key.code, KeyCode::Up | KeyCode::Down) => {
+                actions.extend(self.list.handle_event(event, Self::list_props(&props)));
+            }
+            _ => {
+                let changed = <TextInput as Component<String>>::handle_event(
+                    &mut self.input,
+                    event,
+                    Self::input_props(&props),
+                )
+                .into_iter()
+                .next();
+                if let Some(value) = changed {
+                    actions.push((props.on_change)(value.clone()));
+                    if props.debounce_ticks == 0 {
+                        self.pending = None;
+                        actions.push((props.on_query)(value));
+                    } else {
+                        self.pending = Some(PendingQuery {
+                            query: value,
+                            ticks_left: props.debounce_ticks,
+                        });
+                    }
+                }
+            }
+        }
+
+        actions
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let input_area = Rect {
+            height: area.height.min(3),
+            ..area
+        };
+        <TextInput as Component<String>>::render(
+            &mut self.input,
+            frame,
+            input_area,
+            Self::input_props(&props),
+        );
+
+        if !props.is_focused || props.suggestions.is_empty() {
+            return;
+        }
+
+        // Dropdown below the input, overlaying whatever is underneath
+        let rows = (props.suggestions.len() as u16).min(props.max_visible.max(1));
+        let dropdown_area = Rect {
+            y: input_area.y + input_area.height,
+            height: rows.saturating_add(2).min(area.height - input_area.height),
+            ..area
+        };
+        if dropdown_area.height < 3 {
+            return;
+        }
+        frame.render_widget(Clear, dropdown_area);
+        self.list
+            .render(frame, dropdown_area, Self::list_props(&props));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Change(String),
+        Query(String),
+        Select(usize),
+        Accept(usize),
+    }
+
+    fn props<'a>(
+        value: &'a str,
+        suggestions: &'a [String],
+        debounce_ticks: u32,
+    ) -> AutocompleteProps<'a, TestAction> {
+        AutocompleteProps {
+            value,
+            placeholder: "Search for a city...",
+            suggestions,
+            selected: 0,
+            is_focused: true,
+            theme: &Theme::DARK,
+            max_visible: 5,
+            debounce_ticks,
+            on_change: TestAction::Change,
+            on_query: TestAction::Query,
+            on_select: TestAction::Select,
+            on_accept: TestAction::Accept,
+        }
+    }
+
+    fn send(
+        autocomplete: &mut Autocomplete,
+        event: EventKind,
+        props: AutocompleteProps<'_, TestAction>,
+    ) -> Vec<TestAction> {
+        autocomplete
+            .handle_event(&event, props)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_query_waits_for_debounce() {
+        let mut autocomplete = Autocomplete::new();
+
+        assert_eq!(
+            send(
+                &mut autocomplete,
+                EventKind::Key(key("p")),
+                props("", &[], 2)
+            ),
+            vec![TestAction::Change("p".into())]
+        );
+        assert!(send(&mut autocomplete, EventKind::Tick, props("p", &[], 2)).is_empty());
+
+        // Another keystroke restarts the delay
+        assert_eq!(
+            send(
+                &mut autocomplete,
+                EventKind::Key(key("a")),
+                props("p", &[], 2)
+            ),
+            vec![TestAction::Change("pa".into())]
+        );
+        assert_eq!(autocomplete.pending_query(), Some("pa"));
+        assert!(send(&mut autocomplete, EventKind::Tick, props("pa", &[], 2)).is_empty());
+        assert_eq!(
+            send(&mut autocomplete, EventKind::Tick, props("pa", &[], 2)),
+            vec![TestAction::Query("pa".into())]
+        );
+        assert_eq!(autocomplete.pending_query(), None);
+        assert!(send(&mut autocomplete, EventKind::Tick, props("pa", &[], 2)).is_empty());
+    }
+
+    #[test]
+    fn test_zero_debounce_queries_immediately() {
+        let mut autocomplete = Autocomplete::new();
+        assert_eq!(
+            send(
+                &mut autocomplete,
+                EventKind::Key(key("x")),
+                props("", &[], 0)
+            ),
+            vec![
+                TestAction::Change("x".into()),
+                TestAction::Query("x".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enter_flushes_pending_query() {
+        let mut autocomplete = Autocomplete::new();
+        send(
+            &mut autocomplete,
+            EventKind::Key(key("o")),
+            props("", &[], 5),
+        );
+        assert_eq!(
+            send(
+                &mut autocomplete,
+                EventKind::Key(key("enter")),
+                props("o", &[], 5)
+            ),
+            vec![TestAction::Query("o".into())]
+        );
+        assert!(send(&mut autocomplete, EventKind::Tick, props("o", &[], 5)).is_empty());
+    }
+
+    #[test]
+    fn test_navigate_and_accept() {
+        let mut autocomplete = Autocomplete::new();
+        let suggestions = vec!["Paris".to_string(), "Parma".to_string()];
+
+        assert_eq!(
+            send(
+                &mut autocomplete,
+                EventKind::Key(key("down")),
+                props("par", &suggestions, 2)
+            ),
+            vec![TestAction::Select(1)]
+        );
+        let selected = AutocompleteProps {
+            selected: 1,
+            ..props("par", &suggestions, 2)
+        };
+        assert_eq!(
+            send(&mut autocomplete, EventKind::Key(key("enter")), selected),
+            vec![TestAction::Accept(1)]
+        );
+    }
+
+    #[test]
+    fn test_render_dropdown() {
+        let mut autocomplete = Autocomplete::new();
+        let suggestions = vec!["Paris".to_string(), "Parma".to_string()];
+        let mut render = RenderHarness::new(30, 10);
+
+        let output = render.render_to_string_plain(|frame| {
+            autocomplete.render(frame, frame.area(), props("Par", &suggestions, 2));
+        });
+        assert!(output.contains("Par"));
+        assert!(output.contains("> Paris"));
+        assert!(output.contains("Parma"));
+
+        let output = render.render_to_string_plain(|frame| {
+            autocomplete.render(frame, frame.area(), props("", &[], 2));
+        });
+        assert!(output.contains("Search for a city..."));
+        assert!(!output.contains("Paris"));
+    }
+}
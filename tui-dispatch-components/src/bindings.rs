@@ -0,0 +1,104 @@
+//! Rebindable component keys through a `Keybindings` context
+
+use crossterm::event::KeyEvent;
+use tui_dispatch_core::keybindings::{BindingContext, Keybindings};
+
+/// A [`Keybindings`] table and the context a component looks keys up in
+///
+/// Components with a `keybindings` prop translate keys through it instead
+/// of their built-in keys. Bind the component's commands (e.g.
+/// `"list.next"`, `"input.submit"`) in the context the component is
+/// rendered in:
+///
+/// ```ignore
+/// let mut keybindings = Keybindings::new();
+/// keybindings.add(Context::Sidebar, "list.next", vec!["n".into()]);
+/// keybindings.add(Context::Sidebar, "list.prev", vec!["p".into()]);
+///
+/// list.handle_event(&event, SelectListProps {
+///     keybindings: Some(ComponentBindings::new(&state.keybindings, Context::Sidebar)),
+///     ..props
+/// });
+/// ```
+///
+/// A key bound to one of the component's commands runs that command. Other
+/// keys keep their built-in meaning, unless that command is bound to
+/// different keys: above, `j` and Down no longer move the list.
+#[derive(Clone, Copy)]
+pub struct ComponentBindings<'a> {
+    keybindings: &'a dyn ContextLookup,
+    context: &'static str,
+}
+
+impl<'a> ComponentBindings<'a> {
+    /// Look keys up in `context` (and its parents, then global bindings)
+    pub fn new<C: BindingContext + 'static>(keybindings: &'a Keybindings<C>, context: C) -> Self {
+        Self {
+            keybindings,
+            context: context.name(),
+        }
+    }
+
+    /// Command bound to `key`, if any
+    pub fn command(&self, key: KeyEvent) -> Option<String> {
+        self.keybindings.command(key, self.context)
+    }
+
+    /// Whether `command` is bound to any key
+    pub fn is_bound(&self, command: &str) -> bool {
+        self.keybindings.is_bound(command, self.context)
+    }
+
+    /// Resolve `key` to one of a component's commands
+    pub(crate) fn resolve<T: ComponentCommand>(&self, key: &KeyEvent) -> Option<T> {
+        let bound = self
+            .command(*key)
+            .and_then(|name| T::ALL.iter().copied().find(|cmd| cmd.name() == name));
+        if bound.is_some() {
+            return bound;
+        }
+        T::from_key(key).filter(|cmd| !self.is_bound(cmd.name()))
+    }
+}
+
+/// Commands a component can be driven by
+pub(crate) trait ComponentCommand: Copy + 'static {
+    /// Every command, for matching bound names
+    const ALL: &'static [Self];
+
+    /// Name used in keybinding configs, e.g. `"list.next"`
+    fn name(self) -> &'static str;
+
+    /// Command for a built-in key
+    fn from_key(key: &KeyEvent) -> Option<Self>;
+
+    /// Resolve `key` through `bindings` if given, or the built-in keys
+    fn resolve(key: &KeyEvent, bindings: Option<ComponentBindings<'_>>) -> Option<Self> {
+        match bindings {
+            Some(bindings) => bindings.resolve(key),
+            None => Self::from_key(key),
+        }
+    }
+}
+
+/// [`Keybindings`] with the context type erased
+trait ContextLookup {
+    fn command(&self, key: KeyEvent, context: &str) -> Option<String>;
+    fn is_bound(&self, command: &str, context: &str) -> bool;
+}
+
+impl<C: BindingContext> ContextLookup for Keybindings<C> {
+    fn command(&self, key: KeyEvent, context: &str) -> Option<String> {
+        match C::from_name(context) {
+            Some(context) => self.get_command(key, context),
+            None => self.get_global_command(key),
+        }
+    }
+
+    fn is_bound(&self, command: &str, context: &str) -> bool {
+        match C::from_name(context) {
+            Some(context) => self.get_first_keybinding(command, context).is_some(),
+            None => self.global_bindings().contains_key(command),
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Breadcrumb path component
+
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, Theme};
+use unicode_width::UnicodeWidthStr;
+
+use crate::status_bar::truncate;
+
+/// Placeholder for a run of hidden segments
+const ELLIPSIS: &str = "…";
+
+/// Props for Breadcrumb component
+pub struct BreadcrumbProps<'a, A> {
+    /// Path segments, outermost first
+    pub segments: &'a [String],
+    /// Index of the highlighted segment (usually the last)
+    pub active: usize,
+    /// Whether this component has focus (enables number keys)
+    pub is_focused: bool,
+    /// Text between segments, e.g. `" › "` or `" / "`
+    pub separator: &'a str,
+    /// Colors for segments and separators
+    pub theme: &'a Theme,
+    /// Callback with the index of a clicked or number-selected segment
+    pub on_segment_click: fn(usize) -> A,
+}
+
+/// Something drawn in the breadcrumb line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Piece {
+    /// A segment, by index
+    Segment(usize),
+    /// A run of hidden segments ending at this index
+    Hidden(usize),
+}
+
+/// A path of segments such as `home › projects › tui-dispatch › src`
+///
+/// The active segment is highlighted. When the path doesn't fit, segments
+/// are hidden behind "…", middle ones first, then the outer ones, but never
+/// the active one, which is truncated as a last resort. Clicking a segment
+/// emits `on_segment_click`; clicking "…" selects the innermost segment it
+/// hides. When focused, 1-9 select a segment by position.
+#[derive(Default)]
+pub struct Breadcrumb {
+    /// Clickable areas from the last render, with the segment they select
+    hit_areas: Vec<(usize, Rect)>,
+}
+
+impl Breadcrumb {
+    /// Create a new Breadcrumb
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Emit a click action if the index is a different, existing segment
+    fn select<A>(props: &BreadcrumbProps<'_, A>, index: usize) -> Option<A> {
+        if index != props.active && index < props.segments.len() {
+            Some((props.on_segment_click)(index))
+        } else {
+            None
+        }
+    }
+}
+
+/// Pieces to draw so the line fits in `available` columns
+///
+/// Returns the pieces and the total width they need.
+fn fit(widths: &[usize], separator: usize, active: usize, available: usize) -> (Vec<Piece>, usize) {
+    let len = widths.len();
+    let mut hidden = vec![false; len];
+
+    // Middle segments left to right, then the first and the last
+    let mut order: Vec<usize> = (1..len.saturating_sub(1)).collect();
+    order.push(0);
+    if len > 1 {
+        order.push(len - 1);
+    }
+    order.retain(|&index| index != active);
+
+    let mut order = order.into_iter();
+    loop {
+        let pieces = visible_pieces(&hidden);
+        let width = pieces
+            .iter()
+            .map(|piece| match piece {
+                Piece::Segment(index) => widths[*index],
+                Piece::Hidden(_) => ELLIPSIS.width(),
+            })
+            .sum::<usize>()
+            + separator * pieces.len().saturating_sub(1);
+        if width <= available {
+            return (pieces, width);
+        }
+        match order.next() {
+            Some(index) => hidden[index] = true,
+            None => return (pieces, width),
+        }
+    }
+}
+
+/// Visible segments, with each run of hidden ones collapsed to one piece
+fn visible_pieces(hidden: &[bool]) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    for (index, &is_hidden) in hidden.iter().enumerate() {
+        match (is_hidden, pieces.last_mut()) {
+            (true, Some(Piece::Hidden(end))) => *end = index,
+            (true, _) => pieces.push(Piece::Hidden(index)),
+            (false, _) => pieces.push(Piece::Segment(index)),
+        }
+    }
+    pieces
+}
+
+impl<A> Component<A> for Breadcrumb {
+    type Props<'a> = BreadcrumbProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        match event {
+            EventKind::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                let position = (mouse.column, mouse.row).into();
+                self.hit_areas
+                    .iter()
+                    .find(|(_, area)| area.contains(position))
+                    .and_then(|&(index, _)| Self::select(&props, index))
+            }
+            EventKind::Key(key) if props.is_focused => match key.code {
+                KeyCode::Char(c @ '1'..='9') => Self::select(&props, c as usize - '1' as usize),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        self.hit_areas.clear();
+        if area.width == 0 || area.height == 0 || props.segments.is_empty() {
+            return;
+        }
+
+        let widths: Vec<usize> = props.segments.iter().map(|s| s.width()).collect();
+        let separator_width = props.separator.width();
+        let available = area.width as usize;
+        let (pieces, width) = fit(&widths, separator_width, props.active, available);
+        let overflow = width.saturating_sub(available);
+
+        let mut spans = Vec::new();
+        let mut x = area.x;
+        for (i, piece) in pieces.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(props.separator, props.theme.muted()));
+                x = x.saturating_add(separator_width as u16);
+            }
+            let (index, label, style) = match *piece {
+                Piece::Segment(index) => {
+                    let text = &props.segments[index];
+                    if index == props.active {
+                        let label = truncate(text, widths[index].saturating_sub(overflow));
+                        (index, label, props.theme.selected())
+                    } else {
+                        (index, text.as_str().into(), props.theme.text())
+                    }
+                }
+                Piece::Hidden(end) => (end, ELLIPSIS.into(), props.theme.muted()),
+            };
+            let label_width = label.width() as u16;
+            self.hit_areas.push((
+                index,
+                Rect::new(x, area.y, label_width, 1).intersection(area),
+            ));
+            spans.push(Span::styled(label, style));
+            x = x.saturating_add(label_width);
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyModifiers, MouseEvent};
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Open(usize),
+    }
+
+    fn path() -> Vec<String> {
+        ["home", "projects", "tui-dispatch", "src", "lib.rs"]
+            .map(String::from)
+            .to_vec()
+    }
+
+    fn props(segments: &[String], active: usize) -> BreadcrumbProps<'_, TestAction> {
+        BreadcrumbProps {
+            segments,
+            active,
+            is_focused: true,
+            separator: " / ",
+            theme: &Theme::DARK,
+            on_segment_click: TestAction::Open,
+        }
+    }
+
+    fn render(
+        crumb: &mut Breadcrumb,
+        width: u16,
+        props: BreadcrumbProps<'_, TestAction>,
+    ) -> String {
+        let mut render = RenderHarness::new(width, 1);
+        let output = render.render_to_string_plain(|frame| {
+            crumb.render(frame, frame.area(), props);
+        });
+        output.trim_end().to_string()
+    }
+
+    fn click(column: u16) -> EventKind {
+        EventKind::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_full_path_fits() {
+        let segments = path();
+        let output = render(&mut Breadcrumb::new(), 60, props(&segments, 4));
+        assert_eq!(output, "home / projects / tui-dispatch / src / lib.rs");
+    }
+
+    #[test]
+    fn test_hides_middle_segments_when_narrow() {
+        let segments = path();
+        let mut crumb = Breadcrumb::new();
+        assert_eq!(
+            render(&mut crumb, 30, props(&segments, 4)),
+            "home / … / src / lib.rs"
+        );
+        assert_eq!(
+            render(&mut crumb, 17, props(&segments, 4)),
+            "home / … / lib.rs"
+        );
+        assert_eq!(render(&mut crumb, 16, props(&segments, 4)), "… / lib.rs");
+        // As a last resort the active segment is truncated
+        assert_eq!(render(&mut crumb, 8, props(&segments, 4)), "… / lib…");
+
+        // The active segment stays visible
+        assert_eq!(
+            render(&mut crumb, 30, props(&segments, 2)),
+            "… / tui-dispatch / … / lib.rs"
+        );
+    }
+
+    #[test]
+    fn test_click_selects_segment() {
+        let segments = path();
+        let mut crumb = Breadcrumb::new();
+        render(&mut crumb, 30, props(&segments, 4));
+
+        let mut press = |event: EventKind| -> Vec<TestAction> {
+            crumb
+                .handle_event(&event, props(&segments, 4))
+                .into_iter()
+                .collect()
+        };
+        // "home / … / src / lib.rs"
+        assert_eq!(press(click(1)), vec![TestAction::Open(0)]);
+        assert_eq!(press(click(7)), vec![TestAction::Open(2)]);
+        assert_eq!(press(click(12)), vec![TestAction::Open(3)]);
+        assert!(press(click(5)).is_empty());
+        assert!(press(click(18)).is_empty());
+    }
+
+    #[test]
+    fn test_number_keys_select() {
+        let segments = path();
+        let mut crumb = Breadcrumb::new();
+        let mut press = |code: &str, focused: bool| -> Vec<TestAction> {
+            let mut p = props(&segments, 4);
+            p.is_focused = focused;
+            crumb
+                .handle_event(&EventKind::Key(key(code)), p)
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(press("2", true), vec![TestAction::Open(1)]);
+        assert!(press("5", true).is_empty());
+        assert!(press("9", true).is_empty());
+        assert!(press("2", false).is_empty());
+    }
+}
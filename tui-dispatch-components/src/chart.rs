@@ -0,0 +1,453 @@
+//! Sparkline and bar chart components
+
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind};
+use unicode_width::UnicodeWidthStr;
+
+use crate::status_bar::truncate;
+
+/// Eighth-block glyphs, from lowest to full
+const LEVELS: [&str; 8] = ["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+/// Configuration for chart appearance
+#[derive(Debug, Clone, Copy)]
+pub struct ChartStyle {
+    /// Style for bars and sparkline columns
+    pub bar: Style,
+    /// Style for the selected bar
+    pub highlight: Style,
+    /// Style for the min/max labels
+    pub axis: Style,
+    /// Style for bar labels
+    pub label: Style,
+}
+
+impl Default for ChartStyle {
+    fn default() -> Self {
+        Self {
+            bar: Style::default().fg(Color::Cyan),
+            highlight: Style::default().fg(Color::Yellow),
+            axis: Style::default().fg(Color::DarkGray),
+            label: Style::default().fg(Color::Gray),
+        }
+    }
+}
+
+/// Resolve the value range drawn from the bottom to the top of a chart
+///
+/// Unset bounds scale to the data: from 0 (or the lowest negative value)
+/// up to the largest value. Non-finite values are ignored.
+fn scale_bounds(data: &[f64], min: Option<f64>, max: Option<f64>) -> (f64, f64) {
+    let finite = || data.iter().copied().filter(|value| value.is_finite());
+    let min = min.unwrap_or_else(|| finite().fold(0.0, f64::min));
+    let max = max.unwrap_or_else(|| finite().fold(min, f64::max));
+    (min, max)
+}
+
+/// Position of `value` between `min` and `max` (clamped to 0.0 - 1.0)
+fn scale_ratio(value: f64, min: f64, max: f64) -> f64 {
+    if !value.is_finite() {
+        return 0.0;
+    }
+    if max <= min {
+        return if value > min { 1.0 } else { 0.0 };
+    }
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+/// Glyph for each row of a column `height` cells tall, bottom row first
+fn column_glyphs(ratio: f64, height: u16) -> impl Iterator<Item = Option<&'static str>> {
+    let units = (ratio * (height as usize * LEVELS.len()) as f64).round() as usize;
+    (0..height as usize).map(move |row| {
+        let fill = units.saturating_sub(row * LEVELS.len()).min(LEVELS.len());
+        fill.checked_sub(1).map(|level| LEVELS[level])
+    })
+}
+
+/// Draw a column of `ratio` height from the bottom of `area` at column `x`
+fn draw_column(buf: &mut Buffer, area: Rect, x: u16, ratio: f64, style: Style) {
+    let bottom = area.y + area.height - 1;
+    for (row, glyph) in column_glyphs(ratio, area.height).enumerate() {
+        let Some(glyph) = glyph else {
+            break;
+        };
+        if let Some(cell) = buf.cell_mut((x, bottom - row as u16)) {
+            cell.set_symbol(glyph).set_style(style);
+        }
+    }
+}
+
+/// Compact label for an axis bound
+fn format_tick(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e9 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+/// Draw the max and min labels in a left gutter and return the plot area
+fn draw_range(buf: &mut Buffer, area: Rect, (min, max): (f64, f64), style: Style) -> Rect {
+    let (top, bottom) = (format_tick(max), format_tick(min));
+    let gutter = (top.width().max(bottom.width()) as u16 + 1).min(area.width);
+    let label_width = gutter.saturating_sub(1);
+
+    let right_aligned = |text: &str| format!("{text:>width$}", width = label_width as usize);
+    buf.set_stringn(
+        area.x,
+        area.y,
+        right_aligned(&top),
+        label_width as usize,
+        style,
+    );
+    if area.height > 1 {
+        let y = area.y + area.height - 1;
+        buf.set_stringn(
+            area.x,
+            y,
+            right_aligned(&bottom),
+            label_width as usize,
+            style,
+        );
+    }
+
+    Rect {
+        x: area.x + gutter,
+        width: area.width - gutter,
+        ..area
+    }
+}
+
+/// Draw the optional border and return the area inside it
+fn chart_area(frame: &mut Frame, area: Rect, show_border: bool) -> Rect {
+    if !show_border {
+        return area;
+    }
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    inner
+}
+
+/// Props for Sparkline component
+pub struct SparklineProps<'a> {
+    /// Samples, oldest first
+    pub data: &'a [f64],
+    /// Value at the bottom of the chart (None = 0, or the lowest negative sample)
+    pub min: Option<f64>,
+    /// Value at the top of the chart (None = the largest sample)
+    pub max: Option<f64>,
+    /// Draw the max and min values in a gutter on the left
+    pub show_range: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Appearance
+    pub style: ChartStyle,
+}
+
+/// A compact chart of recent samples, one column per sample
+///
+/// The newest sample is drawn at the right edge; when there are more
+/// samples than columns, the oldest ones are left out. Columns use eighth
+/// blocks, so a chart several rows tall resolves small changes.
+#[derive(Default)]
+pub struct Sparkline;
+
+impl Sparkline {
+    /// Create a new Sparkline
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<A> Component<A> for Sparkline {
+    type Props<'a> = SparklineProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let mut plot = chart_area(frame, area, props.show_border);
+        let bounds = scale_bounds(props.data, props.min, props.max);
+        let buf = frame.buffer_mut();
+        if props.show_range && plot.width > 0 && plot.height > 0 {
+            plot = draw_range(buf, plot, bounds, props.style.axis);
+        }
+        if plot.width == 0 || plot.height == 0 {
+            return;
+        }
+
+        let visible = &props.data[props.data.len().saturating_sub(plot.width as usize)..];
+        let first_x = plot.x + plot.width - visible.len() as u16;
+        for (i, &value) in visible.iter().enumerate() {
+            let ratio = scale_ratio(value, bounds.0, bounds.1);
+            draw_column(buf, plot, first_x + i as u16, ratio, props.style.bar);
+        }
+    }
+}
+
+/// Props for BarChart component
+pub struct BarChartProps<'a, A> {
+    /// Bar values, left to right
+    pub data: &'a [f64],
+    /// Label under each bar, cut to the bar width (empty = no label row)
+    pub labels: &'a [&'a str],
+    /// Value at the bottom of the chart (None = 0, or the lowest negative value)
+    pub min: Option<f64>,
+    /// Value at the top of the chart (None = the largest value)
+    pub max: Option<f64>,
+    /// Width of each bar in cells
+    pub bar_width: u16,
+    /// Space between bars in cells
+    pub bar_gap: u16,
+    /// Draw the max and min values in a gutter on the left
+    pub show_range: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Highlighted bar
+    pub selected: Option<usize>,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Appearance
+    pub style: ChartStyle,
+    /// Callback when a bar is clicked or selected with Left/Right (None = display only)
+    pub on_select: Option<fn(usize) -> A>,
+}
+
+/// Vertical bars with optional labels and a min/max gutter
+///
+/// Bars that don't fit the width are left out. With `on_select` set,
+/// clicking a bar selects it, and Left/Right (h/l) move the selection
+/// when focused, so a chart can drive a detail view.
+#[derive(Default)]
+pub struct BarChart {
+    /// Screen area of each drawn bar, for mouse hit testing
+    bar_areas: Vec<Rect>,
+}
+
+impl BarChart {
+    /// Create a new BarChart
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<A> Component<A> for BarChart {
+    type Props<'a> = BarChartProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        let (Some(on_select), Some(last)) = (props.on_select, props.data.len().checked_sub(1))
+        else {
+            return None;
+        };
+
+        let target = match event {
+            EventKind::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                let position = Position::new(mouse.column, mouse.row);
+                self.bar_areas
+                    .iter()
+                    .position(|area| area.contains(position))
+            }
+            EventKind::Key(key) if props.is_focused => match (key.code, props.selected) {
+                (KeyCode::Right | KeyCode::Char('l'), Some(selected)) => {
+                    Some((selected + 1).min(last))
+                }
+                (KeyCode::Left | KeyCode::Char('h'), Some(selected)) => {
+                    Some(selected.saturating_sub(1).min(last))
+                }
+                (KeyCode::Right | KeyCode::Char('l'), None) => Some(0),
+                (KeyCode::Left | KeyCode::Char('h'), None) => Some(last),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        target
+            .filter(|&index| Some(index) != props.selected)
+            .map(on_select)
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        self.bar_areas.clear();
+        let mut plot = chart_area(frame, area, props.show_border);
+        let bounds = scale_bounds(props.data, props.min, props.max);
+        let buf = frame.buffer_mut();
+
+        let label_row = (!props.labels.is_empty() && plot.height > 1).then(|| {
+            plot.height -= 1;
+            plot.y + plot.height
+        });
+        if props.show_range && plot.width > 0 && plot.height > 0 {
+            plot = draw_range(buf, plot, bounds, props.style.axis);
+        }
+        if plot.width == 0 || plot.height == 0 {
+            return;
+        }
+
+        let bar_width = props.bar_width.max(1);
+        let mut x = plot.x;
+        for (index, &value) in props.data.iter().enumerate() {
+            if x + bar_width > plot.x + plot.width {
+                break;
+            }
+            let style = if props.selected == Some(index) {
+                props.style.highlight
+            } else {
+                props.style.bar
+            };
+            let ratio = scale_ratio(value, bounds.0, bounds.1);
+            for column in x..x + bar_width {
+                draw_column(buf, plot, column, ratio, style);
+            }
+
+            let mut bar_area = Rect::new(x, plot.y, bar_width, plot.height);
+            if let Some(y) = label_row {
+                let label = props.labels.get(index).copied().unwrap_or_default();
+                let label = truncate(label, bar_width as usize);
+                buf.set_string(x, y, label, props.style.label);
+                bar_area.height += 1;
+            }
+            self.bar_areas.push(bar_area);
+            x += bar_width + props.bar_gap;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyModifiers, MouseEvent};
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Select(usize),
+    }
+
+    fn bar_props<'a>(data: &'a [f64], labels: &'a [&'a str]) -> BarChartProps<'a, TestAction> {
+        BarChartProps {
+            data,
+            labels,
+            min: None,
+            max: None,
+            bar_width: 2,
+            bar_gap: 1,
+            show_range: false,
+            show_border: false,
+            selected: None,
+            is_focused: true,
+            style: ChartStyle::default(),
+            on_select: Some(TestAction::Select),
+        }
+    }
+
+    #[test]
+    fn test_scale_bounds_and_ratio() {
+        assert_eq!(scale_bounds(&[3.0, 8.0, f64::NAN], None, None), (0.0, 8.0));
+        assert_eq!(scale_bounds(&[-2.0, 4.0], None, None), (-2.0, 4.0));
+        assert_eq!(scale_bounds(&[3.0], Some(1.0), Some(5.0)), (1.0, 5.0));
+        assert_eq!(scale_bounds(&[], None, None), (0.0, 0.0));
+
+        assert_eq!(scale_ratio(4.0, 0.0, 8.0), 0.5);
+        assert_eq!(scale_ratio(12.0, 0.0, 8.0), 1.0);
+        assert_eq!(scale_ratio(1.0, 1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_column_glyphs() {
+        // Half of two rows = 8 of 16 eighths: a full bottom cell
+        let glyphs: Vec<_> = column_glyphs(0.5, 2).collect();
+        assert_eq!(glyphs, vec![Some("█"), None]);
+
+        let glyphs: Vec<_> = column_glyphs(0.75, 2).collect();
+        assert_eq!(glyphs, vec![Some("█"), Some("▄")]);
+    }
+
+    #[test]
+    fn test_sparkline_keeps_newest_samples() {
+        let mut render = RenderHarness::new(4, 1);
+        let mut sparkline = Sparkline::new();
+
+        let output = render.render_to_string_plain(|frame| {
+            <Sparkline as Component<()>>::render(
+                &mut sparkline,
+                frame,
+                frame.area(),
+                SparklineProps {
+                    data: &[8.0, 8.0, 1.0, 2.0, 4.0, 8.0],
+                    min: None,
+                    max: None,
+                    show_range: false,
+                    show_border: false,
+                    style: ChartStyle::default(),
+                },
+            );
+        });
+
+        assert!(output.starts_with("▁▂▄█"));
+    }
+
+    #[test]
+    fn test_bar_chart_labels_and_range() {
+        let mut render = RenderHarness::new(12, 3);
+        let mut chart = BarChart::new();
+
+        let output = render.render_to_string_plain(|frame| {
+            let props = BarChartProps {
+                show_range: true,
+                ..bar_props(&[2.0, 4.0], &["cpu", "mem"])
+            };
+            chart.render(frame, frame.area(), props);
+        });
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0].trim_end(), "4    ██");
+        assert_eq!(lines[1].trim_end(), "0 ██ ██");
+        assert_eq!(lines[2].trim_end(), "  c… m…");
+    }
+
+    #[test]
+    fn test_bar_chart_select() {
+        let data = [1.0, 2.0, 3.0];
+        let mut render = RenderHarness::new(12, 2);
+        let mut chart = BarChart::new();
+        render.render_to_string_plain(|frame| {
+            chart.render(frame, frame.area(), bar_props(&data, &[]));
+        });
+
+        // Second bar spans columns 3-4
+        let click = EventKind::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 4,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        });
+        let actions: Vec<_> = chart
+            .handle_event(&click, bar_props(&data, &[]))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Select(1)]);
+
+        let props = BarChartProps {
+            selected: Some(1),
+            ..bar_props(&data, &[])
+        };
+        let actions: Vec<_> = chart
+            .handle_event(&EventKind::Key(key("right")), props)
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Select(2)]);
+    }
+}
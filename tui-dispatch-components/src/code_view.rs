@@ -0,0 +1,398 @@
+//! Source code viewer with line numbers and highlighted ranges
+
+use std::ops::Range;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, Theme};
+
+/// Lines moved per mouse wheel tick
+const WHEEL_STEP: usize = 3;
+
+/// Background for a range of lines, e.g. a search hit or diff hunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineHighlight {
+    /// Zero-based line indices
+    pub lines: Range<usize>,
+    /// Style patched over the line (usually just a background)
+    pub style: Style,
+}
+
+impl LineHighlight {
+    /// Highlight `lines` with `style`
+    pub fn new(lines: Range<usize>, style: Style) -> Self {
+        Self { lines, style }
+    }
+}
+
+/// Props for CodeView component
+pub struct CodeViewProps<'a, A> {
+    /// Lines to show, e.g. from `SyntaxHighlighter::highlight` or `Line::raw`
+    pub lines: &'a [Line<'static>],
+    /// Index of the first visible line (owned by app state)
+    pub scroll_offset: usize,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Show line numbers in a gutter
+    pub line_numbers: bool,
+    /// Number shown for the first line (for snippets of a larger file)
+    pub first_line_number: usize,
+    /// Line ranges to highlight; later entries win where they overlap
+    pub highlights: &'a [LineHighlight],
+    /// Colors for the border and gutter
+    pub theme: &'a Theme,
+    /// Callback with the new scroll offset (None = scrolling handled by the app)
+    pub on_scroll: Option<fn(usize) -> A>,
+}
+
+/// A scrollable view of source code
+///
+/// Takes already highlighted lines so highlighting runs once per file, not
+/// per frame. With the `syntax` feature, `SyntaxHighlighter` produces them
+/// with syntect. When focused with `on_scroll` set, handles arrows/jk,
+/// PageUp/PageDown, Home/End and the mouse wheel.
+#[derive(Default)]
+pub struct CodeView {
+    /// Content area from the last render
+    viewport: Rect,
+}
+
+impl CodeView {
+    /// Create a new CodeView
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Emit a scroll action if the clamped offset differs from the current one
+    fn scroll_to<A>(&self, props: &CodeViewProps<'_, A>, offset: usize) -> Option<A> {
+        let max = props
+            .lines
+            .len()
+            .saturating_sub(self.viewport.height as usize);
+        let offset = offset.min(max);
+        let on_scroll = props.on_scroll?;
+        (offset != props.scroll_offset).then(|| on_scroll(offset))
+    }
+
+    fn contains(&self, column: u16, row: u16) -> bool {
+        let area = self.viewport;
+        column >= area.x
+            && column < area.x.saturating_add(area.width)
+            && row >= area.y
+            && row < area.y.saturating_add(area.height)
+    }
+}
+
+impl<A> Component<A> for CodeView {
+    type Props<'a> = CodeViewProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        let offset = props.scroll_offset;
+        let page = (self.viewport.height as usize).max(1);
+
+        match event {
+            EventKind::Scroll { column, row, delta } if self.contains(*column, *row) => {
+                if *delta > 0 {
+                    self.scroll_to(&props, offset.saturating_add(WHEEL_STEP))
+                } else {
+                    self.scroll_to(&props, offset.saturating_sub(WHEEL_STEP))
+                }
+            }
+            EventKind::Key(key) if props.is_focused => match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.scroll_to(&props, offset + 1),
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.scroll_to(&props, offset.saturating_sub(1))
+                }
+                KeyCode::PageDown => self.scroll_to(&props, offset.saturating_add(page)),
+                KeyCode::PageUp => self.scroll_to(&props, offset.saturating_sub(page)),
+                KeyCode::Home => self.scroll_to(&props, 0),
+                KeyCode::End => self.scroll_to(&props, usize::MAX),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let inner = if props.show_border {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(props.theme.border_style(props.is_focused));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            inner
+        } else {
+            area
+        };
+        self.viewport = inner;
+
+        let start = props.scroll_offset.min(props.lines.len());
+        let end = (start + inner.height as usize).min(props.lines.len());
+
+        let last_number = props.first_line_number + props.lines.len().saturating_sub(1);
+        let gutter_width = if props.line_numbers {
+            (last_number.to_string().len() + 1) as u16
+        } else {
+            0
+        };
+        let content = Rect {
+            x: inner.x + gutter_width.min(inner.width),
+            width: inner.width.saturating_sub(gutter_width),
+            ..inner
+        };
+
+        for (i, line) in props.lines[start..end].iter().enumerate() {
+            let index = start + i;
+            let y = inner.y + i as u16;
+
+            if props.line_numbers {
+                let number = format!(
+                    "{:>width$} ",
+                    props.first_line_number + index,
+                    width = gutter_width as usize - 1
+                );
+                frame.render_widget(
+                    Paragraph::new(Span::styled(number, props.theme.muted())),
+                    Rect::new(inner.x, y, gutter_width.min(inner.width), 1),
+                );
+            }
+
+            let line_area = Rect::new(content.x, y, content.width, 1);
+            let highlight = props
+                .highlights
+                .iter()
+                .rev()
+                .find(|h| h.lines.contains(&index))
+                .map(|h| h.style);
+            let line = match highlight {
+                Some(style) => {
+                    frame.render_widget(Block::default().style(style), line_area);
+                    Line::from(
+                        line.spans
+                            .iter()
+                            .map(|s| Span::styled(s.content.clone(), s.style.patch(style)))
+                            .collect::<Vec<_>>(),
+                    )
+                }
+                None => line.clone(),
+            };
+            frame.render_widget(Paragraph::new(line), line_area);
+        }
+    }
+}
+
+#[cfg(feature = "syntax")]
+pub use highlighter::SyntaxHighlighter;
+
+#[cfg(feature = "syntax")]
+mod highlighter {
+    use ratatui::{
+        style::{Color, Modifier, Style},
+        text::{Line, Span},
+    };
+    use syntect::easy::HighlightLines;
+    use syntect::highlighting::{FontStyle, Theme as SyntectTheme, ThemeSet};
+    use syntect::parsing::SyntaxSet;
+
+    /// Default syntect theme
+    const DEFAULT_THEME: &str = "base16-ocean.dark";
+
+    /// Turns source text into styled lines using syntect
+    ///
+    /// Loading the syntax definitions takes a while, so create one at
+    /// startup and keep it around. Only foreground colors and font styles
+    /// are kept; backgrounds come from the view.
+    pub struct SyntaxHighlighter {
+        syntax_set: SyntaxSet,
+        theme: SyntectTheme,
+    }
+
+    impl std::fmt::Debug for SyntaxHighlighter {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("SyntaxHighlighter")
+                .field("theme", &self.theme.name)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl Default for SyntaxHighlighter {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl SyntaxHighlighter {
+        /// Create a highlighter with syntect's default syntaxes and the
+        /// "base16-ocean.dark" theme
+        pub fn new() -> Self {
+            let mut themes = ThemeSet::load_defaults().themes;
+            Self {
+                syntax_set: SyntaxSet::load_defaults_newlines(),
+                theme: themes.remove(DEFAULT_THEME).unwrap_or_default(),
+            }
+        }
+
+        /// Use one of syntect's bundled themes, e.g. "InspiredGitHub"
+        ///
+        /// Unknown names keep the current theme.
+        pub fn with_theme(mut self, name: &str) -> Self {
+            if let Some(theme) = ThemeSet::load_defaults().themes.remove(name) {
+                self.theme = theme;
+            }
+            self
+        }
+
+        /// Use a custom syntect theme
+        pub fn with_syntect_theme(mut self, theme: SyntectTheme) -> Self {
+            self.theme = theme;
+            self
+        }
+
+        /// Highlight `code` as `lang` (a name or file extension like "rs")
+        ///
+        /// Unknown languages are returned as plain text.
+        pub fn highlight(&self, code: &str, lang: &str) -> Vec<Line<'static>> {
+            let syntax = self
+                .syntax_set
+                .find_syntax_by_token(lang)
+                .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+            let mut highlighter = HighlightLines::new(syntax, &self.theme);
+
+            code.lines()
+                .map(|line| {
+                    let ranges = highlighter
+                        .highlight_line(line, &self.syntax_set)
+                        .unwrap_or_default();
+                    Line::from(
+                        ranges
+                            .into_iter()
+                            .map(|(style, text)| Span::styled(text.to_string(), to_style(style)))
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect()
+        }
+    }
+
+    fn to_style(style: syntect::highlighting::Style) -> Style {
+        let fg = style.foreground;
+        let mut result = Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b));
+        if style.font_style.contains(FontStyle::BOLD) {
+            result = result.add_modifier(Modifier::BOLD);
+        }
+        if style.font_style.contains(FontStyle::ITALIC) {
+            result = result.add_modifier(Modifier::ITALIC);
+        }
+        if style.font_style.contains(FontStyle::UNDERLINE) {
+            result = result.add_modifier(Modifier::UNDERLINED);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Scroll(usize),
+    }
+
+    fn source() -> Vec<Line<'static>> {
+        (1..=20).map(|i| Line::raw(format!("line {i}"))).collect()
+    }
+
+    fn props(lines: &[Line<'static>], scroll_offset: usize) -> CodeViewProps<'_, TestAction> {
+        CodeViewProps {
+            lines,
+            scroll_offset,
+            is_focused: true,
+            show_border: false,
+            line_numbers: true,
+            first_line_number: 1,
+            highlights: &[],
+            theme: &Theme::DARK,
+            on_scroll: Some(TestAction::Scroll),
+        }
+    }
+
+    #[test]
+    fn test_render_gutter_and_highlights() {
+        let lines = source();
+        let highlights = [
+            LineHighlight::new(1..3, Style::default().bg(Color::Green)),
+            LineHighlight::new(2..3, Style::default().bg(Color::Red)),
+        ];
+        let mut render = RenderHarness::new(20, 4);
+        let mut view = CodeView::new();
+
+        let buffer = render.render(|frame| {
+            view.render(
+                frame,
+                frame.area(),
+                CodeViewProps {
+                    first_line_number: 9,
+                    highlights: &highlights,
+                    ..props(&lines, 0)
+                },
+            )
+        });
+
+        // Numbers run 9..=28, so the gutter is 3 columns wide
+        assert_eq!(buffer[(1, 0)].symbol(), "9");
+        assert_eq!(buffer[(0, 1)].symbol(), "1");
+        assert_eq!(buffer[(3, 0)].symbol(), "l");
+        assert_eq!(buffer[(3, 0)].bg, Color::Reset);
+        assert_eq!(buffer[(3, 1)].bg, Color::Green);
+        assert_eq!(buffer[(3, 2)].bg, Color::Red);
+        // Highlight spans the full width, past the end of the text
+        assert_eq!(buffer[(19, 1)].bg, Color::Green);
+    }
+
+    #[test]
+    fn test_scroll_keys() {
+        let lines = source();
+        let mut render = RenderHarness::new(20, 5);
+        let mut view = CodeView::new();
+        render.render(|frame| view.render(frame, frame.area(), props(&lines, 0)));
+
+        let mut press = |code: &str, offset: usize| -> Vec<TestAction> {
+            view.handle_event(&EventKind::Key(key(code)), props(&lines, offset))
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(press("down", 0), vec![TestAction::Scroll(1)]);
+        assert_eq!(press("pageup", 7), vec![TestAction::Scroll(2)]);
+        assert_eq!(press("end", 0), vec![TestAction::Scroll(15)]);
+        assert!(press("pagedown", 15).is_empty());
+    }
+
+    #[cfg(feature = "syntax")]
+    #[test]
+    fn test_syntax_highlighter() {
+        let highlighter = SyntaxHighlighter::new();
+        let lines = highlighter.highlight("fn main() {\n    let x = 1;\n}", "rs");
+
+        assert_eq!(lines.len(), 3);
+        let text: String = lines[0].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "fn main() {");
+        assert!(lines[0].spans.len() > 1);
+        assert!(lines[0].spans.iter().all(|s| s.style.fg.is_some()));
+    }
+}
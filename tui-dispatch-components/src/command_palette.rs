@@ -0,0 +1,464 @@
+//! Command palette with fuzzy matching
+//!
+//! Combines a [`TextInput`] query line with a [`SelectList`] of ranked
+//! candidates. Matching is done with [`fuzzy_match`], a small subsequence
+//! matcher that rewards consecutive runs and word-boundary hits.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::Color,
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, Theme};
+
+use crate::select_list::{HighlightConfig, ScrollbarConfig, SelectList, SelectListProps};
+use crate::text_input::{EditMode, InputMask, TextInput, TextInputProps};
+
+/// Score awarded for every matched character
+const SCORE_MATCH: i64 = 16;
+/// Bonus when a match directly follows the previous one
+const BONUS_CONSECUTIVE: i64 = 24;
+/// Bonus when a match starts a word (after separator or camelCase hump)
+const BONUS_BOUNDARY: i64 = 20;
+/// Bonus when the first query character matches the first candidate character
+const BONUS_FIRST_CHAR: i64 = 12;
+/// Penalty per skipped candidate character between matches
+const PENALTY_GAP: i64 = 2;
+/// Maximum gap penalty applied between two matches
+const MAX_GAP_PENALTY: i64 = 12;
+
+/// Result of a successful fuzzy match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Match score (higher is better)
+    pub score: i64,
+    /// Char indices in the candidate that matched the query
+    pub indices: Vec<usize>,
+}
+
+/// Fuzzy-match `query` against `candidate` (case-insensitive)
+///
+/// Every query character must appear in the candidate in order. Returns
+/// `None` if the query is not a subsequence of the candidate. An empty
+/// query matches everything with a score of 0.
+///
+/// # Example
+///
+/// ```
+/// use tui_dispatch_components::fuzzy_match;
+///
+/// let m = fuzzy_match("ofi", "Open File").unwrap();
+/// assert_eq!(m.indices, vec![0, 5, 6]);
+/// assert!(fuzzy_match("xyz", "Open File").is_none());
+/// ```
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    fuzzy_match_case(query, candidate, false)
+}
+
+/// [`fuzzy_match`] with optional exact-case matching
+pub(crate) fn fuzzy_match_case(
+    query: &str,
+    candidate: &str,
+    case_sensitive: bool,
+) -> Option<FuzzyMatch> {
+    let chars: Vec<char> = candidate.chars().collect();
+    let query: Vec<char> = if case_sensitive {
+        query.chars().collect()
+    } else {
+        query.chars().flat_map(char::to_lowercase).collect()
+    };
+    let matches = |c: char, q: char| {
+        if case_sensitive {
+            c == q
+        } else {
+            c.to_lowercase().eq([q])
+        }
+    };
+    let mut indices = Vec::new();
+    let mut score = 0;
+    let mut pos = 0;
+
+    for q in query {
+        let found = (pos..chars.len()).find(|&i| matches(chars[i], q))?;
+
+        score += SCORE_MATCH;
+        if is_boundary(&chars, found) {
+            score += BONUS_BOUNDARY;
+        }
+        let gap = match indices.last() {
+            Some(&prev) => found - prev - 1,
+            None => found,
+        };
+        if gap == 0 {
+            score += if indices.is_empty() {
+                BONUS_FIRST_CHAR
+            } else {
+                BONUS_CONSECUTIVE
+            };
+        } else {
+            score -= (PENALTY_GAP * gap as i64).min(MAX_GAP_PENALTY);
+        }
+
+        indices.push(found);
+        pos = found + 1;
+    }
+
+    Some(FuzzyMatch { score, indices })
+}
+
+/// Whether the char at `index` starts a word
+fn is_boundary(chars: &[char], index: usize) -> bool {
+    if index == 0 {
+        return true;
+    }
+    let prev = chars[index - 1];
+    let current = chars[index];
+    !prev.is_alphanumeric() || (prev.is_lowercase() && current.is_uppercase())
+}
+
+/// Rank candidates against a query
+///
+/// Returns `(candidate_index, match)` pairs for every matching candidate,
+/// sorted by score (best first). Ties are broken by shorter candidates,
+/// then by original order. An empty query returns all candidates in order.
+pub fn fuzzy_rank(query: &str, candidates: &[String]) -> Vec<(usize, FuzzyMatch)> {
+    let mut ranked: Vec<(usize, FuzzyMatch)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| fuzzy_match(query, c).map(|m| (i, m)))
+        .collect();
+
+    if !query.is_empty() {
+        ranked.sort_by(|(ia, a), (ib, b)| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| candidates[*ia].len().cmp(&candidates[*ib].len()))
+                .then_with(|| ia.cmp(ib))
+        });
+    }
+
+    ranked
+}
+
+/// Props for CommandPalette component
+pub struct CommandPaletteProps<'a, A> {
+    /// All candidates to search through
+    pub candidates: &'a [String],
+    /// Current query string
+    pub query: &'a str,
+    /// Placeholder text when the query is empty
+    pub placeholder: &'a str,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Background color for the query line (None = transparent)
+    pub bg_color: Option<Color>,
+    /// Colors for the query line and results
+    pub theme: &'a Theme,
+    /// Callback when the query changes
+    pub on_query_change: fn(String) -> A,
+    /// Callback when a candidate is accepted (index into `candidates`)
+    pub on_accept: fn(usize) -> A,
+    /// Callback when the palette is dismissed (Esc)
+    pub on_dismiss: fn() -> A,
+}
+
+/// A fuzzy-finding command palette
+///
+/// Typing edits the query, Up/Down (or Ctrl+P/Ctrl+N) move through the
+/// ranked results, Enter accepts the highlighted candidate and Esc dismisses.
+/// The highlighted row is internal UI state and resets whenever the query
+/// changes.
+#[derive(Default)]
+pub struct CommandPalette {
+    input: TextInput,
+    list: SelectList,
+    /// Highlighted row within the ranked results
+    selected: usize,
+    /// Query seen on the last event/render, used to reset selection
+    last_query: String,
+}
+
+impl CommandPalette {
+    /// Create a new CommandPalette
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reset selection when the query changed since the last call
+    fn sync_query(&mut self, query: &str) {
+        if self.last_query != query {
+            self.last_query = query.to_string();
+            self.selected = 0;
+        }
+    }
+}
+
+impl<A> Component<A> for CommandPalette {
+    type Props<'a> = CommandPaletteProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        if !props.is_focused {
+            return None;
+        }
+
+        self.sync_query(props.query);
+
+        let EventKind::Key(key) = event else {
+            return None;
+        };
+
+        let ranked = fuzzy_rank(props.query, props.candidates);
+        let last = ranked.len().saturating_sub(1);
+        self.selected = self.selected.min(last);
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return Some((props.on_dismiss)()),
+            KeyCode::Enter => {
+                return ranked
+                    .get(self.selected)
+                    .map(|(index, _)| (props.on_accept)(*index));
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(last);
+                return None;
+            }
+            KeyCode::Char('n') if ctrl => {
+                self.selected = (self.selected + 1).min(last);
+                return None;
+            }
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            KeyCode::Char('p') if ctrl => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            _ => {}
+        }
+
+        let input_props = TextInputProps {
+            value: props.query,
+            placeholder: props.placeholder,
+            is_focused: true,
+            show_border: true,
+            bg_color: props.bg_color,
+            theme: props.theme,
+            padding_x: 0,
+            padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
+            on_change: props.on_query_change,
+            on_submit: props.on_query_change,
+        };
+        self.input
+            .handle_event(event, input_props)
+            .into_iter()
+            .next()
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        self.sync_query(props.query);
+
+        let ranked = fuzzy_rank(props.query, props.candidates);
+        self.selected = self.selected.min(ranked.len().saturating_sub(1));
+
+        let [input_area, list_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let input_props = TextInputProps {
+            value: props.query,
+            placeholder: props.placeholder,
+            is_focused: props.is_focused,
+            show_border: true,
+            bg_color: props.bg_color,
+            theme: props.theme,
+            padding_x: 0,
+            padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
+            on_change: props.on_query_change,
+            on_submit: props.on_query_change,
+        };
+        self.input.render(frame, input_area, input_props);
+
+        let items: Vec<String> = ranked
+            .iter()
+            .map(|(index, _)| props.candidates[*index].clone())
+            .collect();
+        let list_props = SelectListProps {
+            items: &items,
+            selected: self.selected,
+            is_focused: props.is_focused,
+            show_border: true,
+            padding_x: 0,
+            padding_y: 0,
+            highlight_query: Some(props.query),
+            highlight: HighlightConfig::fuzzy(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: props.theme,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
+            on_select: props.on_accept,
+        };
+        self.list.render(frame, list_area, list_props);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Query(String),
+        Accept(usize),
+        Dismiss,
+    }
+
+    fn make_candidates() -> Vec<String> {
+        vec![
+            "Open File".into(),
+            "Close Window".into(),
+            "Toggle Sidebar".into(),
+            "Open Folder".into(),
+        ]
+    }
+
+    fn props<'a>(candidates: &'a [String], query: &'a str) -> CommandPaletteProps<'a, TestAction> {
+        CommandPaletteProps {
+            candidates,
+            query,
+            placeholder: "Search commands...",
+            is_focused: true,
+            bg_color: None,
+            theme: &Theme::DARK,
+            on_query_change: TestAction::Query,
+            on_accept: TestAction::Accept,
+            on_dismiss: || TestAction::Dismiss,
+        }
+    }
+
+    fn send(
+        palette: &mut CommandPalette,
+        candidates: &[String],
+        query: &str,
+        k: &str,
+    ) -> Vec<TestAction> {
+        palette
+            .handle_event(&EventKind::Key(key(k)), props(candidates, query))
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_fuzzy_match_subsequence() {
+        assert!(fuzzy_match("opf", "Open File").is_some());
+        assert!(fuzzy_match("OPEN", "open file").is_some());
+        assert!(fuzzy_match("fo", "Open File").is_none());
+        assert_eq!(fuzzy_match("", "anything").unwrap().score, 0);
+    }
+
+    #[test]
+    fn test_fuzzy_rank_prefers_boundaries_and_runs() {
+        let candidates = make_candidates();
+        let ranked = fuzzy_rank("of", &candidates);
+        let order: Vec<usize> = ranked.iter().map(|(i, _)| *i).collect();
+
+        // "Open File" and "Open Folder" both hit word starts; shorter wins the tie
+        assert_eq!(order[0], 0);
+        assert_eq!(order[1], 3);
+        assert!(!order.contains(&1));
+    }
+
+    #[test]
+    fn test_typing_emits_query_change() {
+        let mut palette = CommandPalette::new();
+        let candidates = make_candidates();
+
+        let actions = send(&mut palette, &candidates, "", "o");
+        assert_eq!(actions, vec![TestAction::Query("o".into())]);
+    }
+
+    #[test]
+    fn test_enter_accepts_ranked_candidate() {
+        let mut palette = CommandPalette::new();
+        let candidates = make_candidates();
+
+        // Best match for "side" is "Toggle Sidebar" (index 2)
+        let actions = send(&mut palette, &candidates, "side", "enter");
+        assert_eq!(actions, vec![TestAction::Accept(2)]);
+    }
+
+    #[test]
+    fn test_navigation_moves_selection() {
+        let mut palette = CommandPalette::new();
+        let candidates = make_candidates();
+
+        assert!(send(&mut palette, &candidates, "", "down").is_empty());
+        assert!(send(&mut palette, &candidates, "", "ctrl+n").is_empty());
+        assert!(send(&mut palette, &candidates, "", "up").is_empty());
+
+        let actions = send(&mut palette, &candidates, "", "enter");
+        assert_eq!(actions, vec![TestAction::Accept(1)]);
+    }
+
+    #[test]
+    fn test_query_change_resets_selection() {
+        let mut palette = CommandPalette::new();
+        let candidates = make_candidates();
+
+        send(&mut palette, &candidates, "", "down");
+        send(&mut palette, &candidates, "", "down");
+
+        let actions = send(&mut palette, &candidates, "o", "enter");
+        assert_eq!(actions, vec![TestAction::Accept(0)]);
+    }
+
+    #[test]
+    fn test_esc_dismisses() {
+        let mut palette = CommandPalette::new();
+        let candidates = make_candidates();
+
+        let actions = send(&mut palette, &candidates, "abc", "esc");
+        assert_eq!(actions, vec![TestAction::Dismiss]);
+    }
+
+    #[test]
+    fn test_enter_without_matches() {
+        let mut palette = CommandPalette::new();
+        let candidates = make_candidates();
+
+        assert!(send(&mut palette, &candidates, "zzz", "enter").is_empty());
+    }
+
+    #[test]
+    fn test_render_filters_candidates() {
+        let mut render = RenderHarness::new(40, 10);
+        let mut palette = CommandPalette::new();
+        let candidates = make_candidates();
+
+        let output = render.render_to_string_plain(|frame| {
+            palette.render(frame, frame.area(), props(&candidates, "open"));
+        });
+
+        assert!(output.contains("Open File"));
+        assert!(output.contains("Open Folder"));
+        assert!(!output.contains("Toggle Sidebar"));
+    }
+}
@@ -0,0 +1,346 @@
+//! Yes/no confirmation dialog
+
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind};
+
+/// Gap between the two buttons
+const BUTTON_GAP: u16 = 2;
+
+/// Props for ConfirmDialog component
+pub struct ConfirmDialogProps<'a, A> {
+    /// Dialog title, shown in the border
+    pub title: &'a str,
+    /// Question or explanation shown above the buttons
+    pub message: &'a str,
+    /// Label of the confirm button (e.g. "Yes", "Delete")
+    pub confirm_label: &'a str,
+    /// Label of the cancel button (e.g. "No", "Keep")
+    pub cancel_label: &'a str,
+    /// Style the confirm button as a destructive action (red)
+    pub destructive: bool,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Callback when the user confirms
+    pub on_confirm: fn() -> A,
+    /// Callback when the user cancels
+    pub on_cancel: fn() -> A,
+}
+
+/// A modal yes/no confirmation dialog
+///
+/// `y` confirms, `n` and Esc cancel, Left/Right (or `h`/`l`) and Tab move
+/// between the buttons and Enter activates the selected one. Mouse clicks hit the
+/// buttons directly. The cancel button is selected initially so that a
+/// stray Enter never triggers a destructive action; call [`reset`](Self::reset)
+/// before showing the dialog again. Render it inside a modal area, e.g. with
+/// [`centered_rect`](crate::centered_rect) and [`render_modal`](crate::render_modal).
+#[derive(Default)]
+pub struct ConfirmDialog {
+    /// Whether the confirm button is selected (otherwise cancel)
+    confirm_selected: bool,
+    /// Confirm button area from the last render
+    confirm_area: Rect,
+    /// Cancel button area from the last render
+    cancel_area: Rect,
+}
+
+impl ConfirmDialog {
+    /// Create a new ConfirmDialog
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select the cancel button again
+    pub fn reset(&mut self) {
+        self.confirm_selected = false;
+    }
+
+    /// Whether the confirm button is currently selected
+    pub fn is_confirm_selected(&self) -> bool {
+        self.confirm_selected
+    }
+
+    /// Handle a mouse click at the given position
+    fn handle_click<A>(
+        &self,
+        props: &ConfirmDialogProps<'_, A>,
+        column: u16,
+        row: u16,
+    ) -> Option<A> {
+        let contains = |area: Rect| {
+            column >= area.x
+                && column < area.x.saturating_add(area.width)
+                && row >= area.y
+                && row < area.y.saturating_add(area.height)
+        };
+
+        if contains(self.confirm_area) {
+            Some((props.on_confirm)())
+        } else if contains(self.cancel_area) {
+            Some((props.on_cancel)())
+        } else {
+            None
+        }
+    }
+}
+
+/// Render a button label, highlighted when selected
+fn button(label: &str, selected: bool, is_focused: bool, accent: Color) -> Span<'static> {
+    let text = format!("[ {label} ]");
+    if selected {
+        let bg = if is_focused { accent } else { Color::DarkGray };
+        Span::styled(
+            text,
+            Style::default()
+                .fg(Color::Black)
+                .bg(bg)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::styled(text, Style::default().fg(Color::Gray))
+    }
+}
+
+impl<A> Component<A> for ConfirmDialog {
+    type Props<'a> = ConfirmDialogProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        match event {
+            EventKind::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                self.handle_click(&props, mouse.column, mouse.row)
+            }
+            EventKind::Key(key) if props.is_focused => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => Some((props.on_confirm)()),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => Some((props.on_cancel)()),
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.confirm_selected = true;
+                    None
+                }
+                KeyCode::Right | KeyCode::Char('l') => {
+                    self.confirm_selected = false;
+                    None
+                }
+                KeyCode::Tab | KeyCode::BackTab => {
+                    self.confirm_selected = !self.confirm_selected;
+                    None
+                }
+                KeyCode::Enter => {
+                    if self.confirm_selected {
+                        Some((props.on_confirm)())
+                    } else {
+                        Some((props.on_cancel)())
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        frame.render_widget(Clear, area);
+
+        let border_color = if props.is_focused {
+            Color::Cyan
+        } else {
+            Color::DarkGray
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .title(format!(" {} ", props.title));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        self.confirm_area = Rect::default();
+        self.cancel_area = Rect::default();
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let [message_area, buttons_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+
+        frame.render_widget(
+            Paragraph::new(props.message)
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true }),
+            message_area.inner(Margin::new(1, 0)),
+        );
+
+        let accent = if props.destructive {
+            Color::Red
+        } else {
+            Color::Cyan
+        };
+        let confirm = button(
+            props.confirm_label,
+            self.confirm_selected,
+            props.is_focused,
+            accent,
+        );
+        let cancel = button(
+            props.cancel_label,
+            !self.confirm_selected,
+            props.is_focused,
+            Color::Cyan,
+        );
+
+        let confirm_width = confirm.width() as u16;
+        let cancel_width = cancel.width() as u16;
+        let total = confirm_width + BUTTON_GAP + cancel_width;
+        let x = buttons_area.x + buttons_area.width.saturating_sub(total) / 2;
+        let right = buttons_area.x.saturating_add(buttons_area.width);
+        self.confirm_area = Rect::new(
+            x,
+            buttons_area.y,
+            confirm_width.min(right.saturating_sub(x)),
+            1,
+        );
+        let cancel_x = x.saturating_add(confirm_width + BUTTON_GAP);
+        self.cancel_area = Rect::new(
+            cancel_x,
+            buttons_area.y,
+            cancel_width.min(right.saturating_sub(cancel_x)),
+            1,
+        );
+
+        let line = Line::from(vec![
+            confirm,
+            Span::raw(" ".repeat(BUTTON_GAP as usize)),
+            cancel,
+        ]);
+        frame.render_widget(
+            Paragraph::new(line),
+            Rect::new(x, buttons_area.y, right.saturating_sub(x), 1),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyModifiers, MouseEvent};
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Confirm,
+        Cancel,
+    }
+
+    fn props(is_focused: bool) -> ConfirmDialogProps<'static, TestAction> {
+        ConfirmDialogProps {
+            title: "Delete preset",
+            message: "Delete \"sunset\"? This cannot be undone.",
+            confirm_label: "Delete",
+            cancel_label: "Keep",
+            destructive: true,
+            is_focused,
+            on_confirm: || TestAction::Confirm,
+            on_cancel: || TestAction::Cancel,
+        }
+    }
+
+    fn send(dialog: &mut ConfirmDialog, event: EventKind) -> Vec<TestAction> {
+        dialog
+            .handle_event(&event, props(true))
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_shortcut_keys() {
+        let mut dialog = ConfirmDialog::new();
+
+        assert_eq!(
+            send(&mut dialog, EventKind::Key(key("y"))),
+            vec![TestAction::Confirm]
+        );
+        assert_eq!(
+            send(&mut dialog, EventKind::Key(key("n"))),
+            vec![TestAction::Cancel]
+        );
+        assert_eq!(
+            send(&mut dialog, EventKind::Key(key("esc"))),
+            vec![TestAction::Cancel]
+        );
+    }
+
+    #[test]
+    fn test_enter_activates_selected_button() {
+        let mut dialog = ConfirmDialog::new();
+
+        // Cancel is selected initially
+        assert_eq!(
+            send(&mut dialog, EventKind::Key(key("enter"))),
+            vec![TestAction::Cancel]
+        );
+
+        assert!(send(&mut dialog, EventKind::Key(key("left"))).is_empty());
+        assert!(dialog.is_confirm_selected());
+        assert_eq!(
+            send(&mut dialog, EventKind::Key(key("enter"))),
+            vec![TestAction::Confirm]
+        );
+
+        dialog.reset();
+        assert!(!dialog.is_confirm_selected());
+    }
+
+    #[test]
+    fn test_unfocused_ignores_keys() {
+        let mut dialog = ConfirmDialog::new();
+        let actions: Vec<_> = dialog
+            .handle_event(&EventKind::Key(key("y")), props(false))
+            .into_iter()
+            .collect();
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_render_and_click_buttons() {
+        let mut dialog = ConfirmDialog::new();
+        let mut render = RenderHarness::new(40, 7);
+
+        let output = render.render_to_string_plain(|frame| {
+            dialog.render(frame, frame.area(), props(true));
+        });
+        assert!(output.contains("Delete preset"));
+        assert!(output.contains("This cannot be undone."));
+        assert!(output.contains("[ Delete ]  [ Keep ]"));
+
+        let click = |area: Rect| {
+            EventKind::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: area.x,
+                row: area.y,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let confirm_area = dialog.confirm_area;
+        let cancel_area = dialog.cancel_area;
+        assert_eq!(
+            send(&mut dialog, click(confirm_area)),
+            vec![TestAction::Confirm]
+        );
+        assert_eq!(
+            send(&mut dialog, click(cancel_area)),
+            vec![TestAction::Cancel]
+        );
+        assert!(send(&mut dialog, click(Rect::new(0, 0, 1, 1))).is_empty());
+    }
+}
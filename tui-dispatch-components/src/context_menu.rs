@@ -0,0 +1,593 @@
+//! Context menu component with nested submenus
+
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::{Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, EventOutcome, Theme};
+use unicode_width::UnicodeWidthStr;
+
+use crate::modal::{render_modal, ModalStyle};
+
+/// Marker shown after items that open a submenu
+const SUBMENU_MARKER: &str = "▸";
+
+/// An entry in a [`ContextMenu`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuItem {
+    /// Command id passed to `on_select`
+    pub id: String,
+    /// Text shown in the menu
+    pub label: String,
+    /// Key that activates the item while its menu is open (underlined in the label)
+    pub accelerator: Option<char>,
+    /// Shortcut text shown at the right, e.g. "F2"
+    pub hint: Option<String>,
+    /// Disabled items are shown dimmed and can't be selected
+    pub enabled: bool,
+    /// Items of the submenu this item opens (empty = a command)
+    pub children: Vec<MenuItem>,
+    /// Whether this is a separator line
+    pub separator: bool,
+}
+
+impl MenuItem {
+    /// Create a command item
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            accelerator: None,
+            hint: None,
+            enabled: true,
+            children: Vec::new(),
+            separator: false,
+        }
+    }
+
+    /// Create an item that opens a submenu
+    pub fn submenu(label: impl Into<String>, children: Vec<MenuItem>) -> Self {
+        Self {
+            children,
+            ..Self::new("", label)
+        }
+    }
+
+    /// Create a separator line
+    pub fn separator() -> Self {
+        Self {
+            enabled: false,
+            separator: true,
+            ..Self::new("", "")
+        }
+    }
+
+    /// Set the accelerator key
+    pub fn accelerator(mut self, key: char) -> Self {
+        self.accelerator = Some(key);
+        self
+    }
+
+    /// Set the shortcut hint
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Enable or disable the item
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Whether the item can be selected
+    pub fn is_selectable(&self) -> bool {
+        self.enabled && !self.separator
+    }
+
+    fn matches_accelerator(&self, key: char) -> bool {
+        self.is_selectable()
+            && self
+                .accelerator
+                .is_some_and(|accelerator| accelerator.eq_ignore_ascii_case(&key))
+    }
+}
+
+/// Props for ContextMenu component
+pub struct ContextMenuProps<'a, A> {
+    /// Top-level items
+    pub items: &'a [MenuItem],
+    /// Cell the menu opens at (top-left corner), e.g. below the selected row
+    pub anchor: (u16, u16),
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Colors for the menu
+    pub theme: &'a Theme,
+    /// Background dim factor, as in [`ModalStyle::dim_factor`]
+    pub dim_factor: f32,
+    /// Callback with the id of the chosen command
+    pub on_select: fn(&str) -> A,
+    /// Callback when the menu is dismissed (Esc or a click outside)
+    pub on_dismiss: fn() -> A,
+}
+
+/// A popup list of commands anchored at a position
+///
+/// Up/Down move the selection, Enter or Right open a submenu, Enter
+/// chooses a command, Left closes a submenu and Esc closes the innermost
+/// submenu or dismisses the menu. Accelerator keys activate items of the
+/// innermost menu directly. Submenus open to the right, or to the left
+/// when there is no room. Clicks choose items; a click outside dismisses.
+///
+/// The background is dimmed with [`render_modal`]. Render the menu after
+/// the rest of the screen, with the whole frame as `area` so it can be
+/// kept on screen. Call [`reset`](Self::reset) before opening it again.
+#[derive(Default)]
+pub struct ContextMenu {
+    /// Selected index per open menu level, root first
+    open: Vec<usize>,
+    /// Menu areas from the last render, per level
+    areas: Vec<Rect>,
+}
+
+impl ContextMenu {
+    /// Create a new ContextMenu
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Close submenus and select the first item again
+    pub fn reset(&mut self) {
+        self.open.clear();
+        self.areas.clear();
+    }
+
+    /// Selected index per open menu level, root first
+    pub fn selection(&self) -> &[usize] {
+        &self.open
+    }
+
+    /// Drop selections that no longer match `items`, selecting the first item if needed
+    fn sync(&mut self, items: &[MenuItem]) {
+        let mut level = items;
+        for depth in 0..self.open.len() {
+            match level.get(self.open[depth]) {
+                Some(item) if item.is_selectable() => level = &item.children,
+                _ => {
+                    self.open.truncate(depth);
+                    break;
+                }
+            }
+        }
+        if self.open.is_empty() {
+            self.open.extend(first_selectable(items));
+        }
+    }
+
+    /// Items of each open menu level
+    fn levels<'a>(&self, items: &'a [MenuItem]) -> Vec<&'a [MenuItem]> {
+        let mut levels = vec![items];
+        for &index in &self.open[..self.open.len().saturating_sub(1)] {
+            let level = levels[levels.len() - 1];
+            levels.push(&level[index].children);
+        }
+        levels
+    }
+
+    /// Choose item `index` of menu level `depth`
+    fn activate<A>(
+        &mut self,
+        props: &ContextMenuProps<'_, A>,
+        depth: usize,
+        index: usize,
+    ) -> Option<A> {
+        let level = self.levels(props.items)[depth];
+        let item = &level[index];
+        if !item.is_selectable() {
+            return None;
+        }
+        self.open.truncate(depth + 1);
+        self.open[depth] = index;
+        if item.children.is_empty() {
+            return Some((props.on_select)(&item.id));
+        }
+        self.open.extend(first_selectable(&item.children));
+        None
+    }
+
+    /// Move the selection of the innermost menu by `step`, skipping unselectable items
+    fn step(&mut self, items: &[MenuItem], step: isize) {
+        let Some(&current) = self.open.last() else {
+            return;
+        };
+        let len = items.len() as isize;
+        let mut index = current as isize;
+        for _ in 0..len {
+            index = (index + step).rem_euclid(len);
+            if items[index as usize].is_selectable() {
+                *self.open.last_mut().expect("checked above") = index as usize;
+                return;
+            }
+        }
+    }
+
+    /// Handle a mouse click at the given position
+    fn handle_click<A>(
+        &mut self,
+        props: &ContextMenuProps<'_, A>,
+        column: u16,
+        row: u16,
+    ) -> Option<A> {
+        let position = (column, row).into();
+        let Some(depth) = self.areas.iter().rposition(|area| area.contains(position)) else {
+            return Some((props.on_dismiss)());
+        };
+        let inner = self.areas[depth].inner(Margin::new(1, 1));
+        let level = self.levels(props.items).get(depth).copied()?;
+        if !inner.contains(position) {
+            return None;
+        }
+        let index = (row - inner.y) as usize;
+        if index < level.len() {
+            self.activate(props, depth, index)
+        } else {
+            None
+        }
+    }
+}
+
+/// Index of the first selectable item
+fn first_selectable(items: &[MenuItem]) -> Option<usize> {
+    items.iter().position(MenuItem::is_selectable)
+}
+
+/// Width inside the border needed to show `items`
+fn menu_width(items: &[MenuItem]) -> u16 {
+    let has_submenu = items.iter().any(|item| !item.children.is_empty());
+    let widest = items
+        .iter()
+        .map(|item| {
+            let hint = item.hint.as_deref().map_or(0, |hint| hint.width() + 2);
+            item.label.width() + hint
+        })
+        .max()
+        .unwrap_or(0);
+    let marker = if has_submenu {
+        SUBMENU_MARKER.width() + 1
+    } else {
+        0
+    };
+    (widest + marker + 2) as u16
+}
+
+/// Place a `width` x `height` box at `x`, `y`, moved back inside `bounds`
+fn clamp_rect(x: u16, y: u16, width: u16, height: u16, bounds: Rect) -> Rect {
+    let width = width.min(bounds.width);
+    let height = height.min(bounds.height);
+    let x = x.min(bounds.right().saturating_sub(width)).max(bounds.x);
+    let y = y.min(bounds.bottom().saturating_sub(height)).max(bounds.y);
+    Rect::new(x, y, width, height)
+}
+
+/// Label spans with the accelerator's first occurrence underlined
+fn label_spans(item: &MenuItem, style: Style) -> Vec<Span<'_>> {
+    let position = item.accelerator.and_then(|key| {
+        item.label
+            .char_indices()
+            .find(|(_, c)| c.eq_ignore_ascii_case(&key))
+    });
+    match position {
+        Some((start, c)) => {
+            let end = start + c.len_utf8();
+            vec![
+                Span::styled(&item.label[..start], style),
+                Span::styled(
+                    &item.label[start..end],
+                    style.add_modifier(Modifier::UNDERLINED),
+                ),
+                Span::styled(&item.label[end..], style),
+            ]
+        }
+        None => vec![Span::styled(item.label.as_str(), style)],
+    }
+}
+
+impl<A> Component<A> for ContextMenu {
+    type Props<'a> = ContextMenuProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        self.sync(props.items);
+        if self.open.is_empty() {
+            return None;
+        }
+        let depth = self.open.len() - 1;
+        let items = self.levels(props.items)[depth];
+
+        match event {
+            EventKind::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                self.handle_click(&props, mouse.column, mouse.row)
+            }
+            EventKind::Key(key) if props.is_focused => match key.code {
+                KeyCode::Down => {
+                    self.step(items, 1);
+                    None
+                }
+                KeyCode::Up => {
+                    self.step(items, -1);
+                    None
+                }
+                KeyCode::Enter | KeyCode::Right => {
+                    let index = self.open[depth];
+                    // Right only opens submenus
+                    if key.code != KeyCode::Enter && items[index].children.is_empty() {
+                        return None;
+                    }
+                    self.activate(&props, depth, index)
+                }
+                KeyCode::Left => {
+                    if depth > 0 {
+                        self.open.pop();
+                    }
+                    None
+                }
+                KeyCode::Esc => {
+                    if depth > 0 {
+                        self.open.pop();
+                        None
+                    } else {
+                        Some((props.on_dismiss)())
+                    }
+                }
+                KeyCode::Char(c) => items
+                    .iter()
+                    .position(|item| item.matches_accelerator(c))
+                    .and_then(|index| self.activate(&props, depth, index)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn handle_event_outcome(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> EventOutcome<A> {
+        let before = self.open.clone();
+        let is_focused = props.is_focused;
+        let outcome = EventOutcome::from_actions(self.handle_event(event, props));
+        if self.open != before {
+            return outcome.with_render();
+        }
+        // An open menu swallows keys even when they do nothing
+        if is_focused && matches!(event, EventKind::Key(_)) {
+            return EventOutcome {
+                consumed: true,
+                ..outcome
+            };
+        }
+        outcome
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        self.sync(props.items);
+        self.areas.clear();
+        if self.open.is_empty() {
+            return;
+        }
+
+        let levels = self.levels(props.items);
+        let innermost = levels.len() - 1;
+        let (mut x, mut y) = props.anchor;
+        let mut parent: Option<Rect> = None;
+
+        for (depth, items) in levels.into_iter().enumerate() {
+            let width = menu_width(items) + 2;
+            let height = items.len() as u16 + 2;
+            if let Some(parent) = parent {
+                // Beside the parent menu, aligned with the item that opened it
+                y = parent.y + self.open[depth - 1] as u16;
+                x = if parent.right().saturating_add(width) <= area.right() {
+                    parent.right()
+                } else {
+                    parent.x.saturating_sub(width)
+                };
+            }
+            let rect = clamp_rect(x, y, width, height, area);
+
+            // Only the root menu dims what is behind it
+            let dim = if depth == 0 { props.dim_factor } else { 0.0 };
+            render_modal(frame, rect, &ModalStyle::from_theme(props.theme).dim(dim));
+            let block = Block::default().borders(Borders::ALL).border_style(
+                props
+                    .theme
+                    .border_style(props.is_focused && depth == innermost),
+            );
+            let inner = block.inner(rect);
+            frame.render_widget(block, rect);
+
+            let selected = self.open[depth];
+            let lines: Vec<Line> = items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    if item.separator {
+                        return Line::styled("─".repeat(inner.width as usize), props.theme.muted());
+                    }
+                    let style = if !item.enabled {
+                        props.theme.muted()
+                    } else if index == selected && depth == innermost {
+                        Style::default()
+                            .fg(props.theme.surface)
+                            .bg(props.theme.accent)
+                            .add_modifier(Modifier::BOLD)
+                    } else if index == selected {
+                        props.theme.selected()
+                    } else {
+                        props.theme.text()
+                    };
+
+                    let mut spans = vec![Span::styled(" ", style)];
+                    spans.extend(label_spans(item, style));
+                    let right = if !item.children.is_empty() {
+                        SUBMENU_MARKER
+                    } else {
+                        item.hint.as_deref().unwrap_or("")
+                    };
+                    let used = 1 + item.label.width() + right.width() + 1;
+                    let padding = (inner.width as usize).saturating_sub(used);
+                    spans.push(Span::styled(" ".repeat(padding), style));
+                    spans.push(Span::styled(right, style.patch(props.theme.muted())));
+                    spans.push(Span::styled(" ", style));
+                    Line::from(spans)
+                })
+                .collect();
+            frame.render_widget(Paragraph::new(lines), inner);
+
+            self.areas.push(rect);
+            parent = Some(rect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyModifiers, MouseEvent};
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Run(String),
+        Dismiss,
+    }
+
+    fn items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("open", "Open").accelerator('o').hint("Enter"),
+            MenuItem::new("rename", "Rename")
+                .accelerator('r')
+                .hint("F2"),
+            MenuItem::separator(),
+            MenuItem::submenu(
+                "Sort by",
+                vec![
+                    MenuItem::new("sort.name", "Name").accelerator('n'),
+                    MenuItem::new("sort.size", "Size").accelerator('s'),
+                ],
+            )
+            .accelerator('s'),
+            MenuItem::new("delete", "Delete").enabled(false),
+        ]
+    }
+
+    fn props(items: &[MenuItem]) -> ContextMenuProps<'_, TestAction> {
+        ContextMenuProps {
+            items,
+            anchor: (2, 1),
+            is_focused: true,
+            theme: &Theme::DARK,
+            dim_factor: 0.5,
+            on_select: |id| TestAction::Run(id.to_string()),
+            on_dismiss: || TestAction::Dismiss,
+        }
+    }
+
+    fn press(menu: &mut ContextMenu, items: &[MenuItem], code: &str) -> Vec<TestAction> {
+        menu.handle_event(&EventKind::Key(key(code)), props(items))
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_navigation_skips_separators_and_disabled() {
+        let items = items();
+        let mut menu = ContextMenu::new();
+
+        assert!(press(&mut menu, &items, "down").is_empty());
+        assert!(press(&mut menu, &items, "down").is_empty());
+        assert_eq!(menu.selection(), [3]);
+        // Wraps past the disabled item back to the top
+        press(&mut menu, &items, "down");
+        assert_eq!(menu.selection(), [0]);
+        press(&mut menu, &items, "up");
+        assert_eq!(menu.selection(), [3]);
+
+        assert_eq!(
+            press(&mut menu, &items, "r"),
+            vec![TestAction::Run("rename".into())]
+        );
+    }
+
+    #[test]
+    fn test_submenus_open_and_close() {
+        let items = items();
+        let mut menu = ContextMenu::new();
+
+        // The accelerator opens the submenu
+        assert!(press(&mut menu, &items, "s").is_empty());
+        assert_eq!(menu.selection(), [3, 0]);
+        assert_eq!(
+            press(&mut menu, &items, "s"),
+            vec![TestAction::Run("sort.size".into())]
+        );
+
+        assert!(press(&mut menu, &items, "left").is_empty());
+        assert_eq!(menu.selection(), [3]);
+        press(&mut menu, &items, "right");
+        press(&mut menu, &items, "down");
+        assert_eq!(
+            press(&mut menu, &items, "enter"),
+            vec![TestAction::Run("sort.size".into())]
+        );
+
+        // Esc closes the submenu first, then dismisses
+        assert!(press(&mut menu, &items, "esc").is_empty());
+        assert_eq!(press(&mut menu, &items, "esc"), vec![TestAction::Dismiss]);
+    }
+
+    #[test]
+    fn test_render_and_click() {
+        let items = items();
+        let mut menu = ContextMenu::new();
+        press(&mut menu, &items, "s");
+
+        let mut render = RenderHarness::new(40, 10);
+        let output = render.render_to_string_plain(|frame| {
+            menu.render(frame, frame.area(), props(&items));
+        });
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines[1], "  ┌───────────────┐");
+        assert_eq!(lines[2], "  │ Open    Enter │");
+        assert_eq!(lines[4], "  │───────────────│┌──────┐");
+        assert_eq!(lines[5], "  │ Sort by     ▸ ││ Name │");
+        assert_eq!(lines[6], "  │ Delete        ││ Size │");
+
+        let click = |column, row| {
+            EventKind::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let mut clicked = |column, row| -> Vec<TestAction> {
+            menu.handle_event(&click(column, row), props(&items))
+                .into_iter()
+                .collect()
+        };
+        assert_eq!(clicked(24, 6), vec![TestAction::Run("sort.size".into())]);
+        assert_eq!(clicked(5, 3), vec![TestAction::Run("rename".into())]);
+        assert_eq!(clicked(39, 9), vec![TestAction::Dismiss]);
+    }
+}
@@ -0,0 +1,497 @@
+//! Keybinding help overlay
+//!
+//! Renders a searchable cheat sheet from a [`Keybindings`] configuration:
+//! commands bound in the current context first, then global commands that
+//! the context does not override. Keys are shown with
+//! [`format_key_for_display`].
+
+use std::marker::PhantomData;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+use tui_dispatch_core::keybindings::{format_key_for_display, BindingContext, Keybindings};
+use tui_dispatch_core::{Component, EventKind, Strings, Theme};
+
+use crate::command_palette::fuzzy_match;
+use crate::text_input::{EditMode, InputMask, TextInput, TextInputProps};
+
+/// Lines moved per mouse wheel tick
+const WHEEL_STEP: usize = 3;
+
+/// Props for HelpOverlay component
+pub struct HelpOverlayProps<'a, C: BindingContext, A> {
+    /// Keybindings to describe
+    pub keybindings: &'a Keybindings<C>,
+    /// Context whose bindings are listed first
+    pub context: C,
+    /// Current search query
+    pub query: &'a str,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Colors for the search input and border
+    pub theme: &'a Theme,
+    /// Callback when the query changes
+    pub on_query_change: fn(String) -> A,
+    /// Callback when the overlay is dismissed (Esc)
+    pub on_dismiss: fn() -> A,
+}
+
+/// A single command row
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct HelpEntry {
+    keys: String,
+    command: String,
+}
+
+/// A titled group of command rows
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct HelpSection {
+    title: String,
+    entries: Vec<HelpEntry>,
+}
+
+/// Build sorted entries from a binding map, keeping those matching `query`
+fn entries<'a>(
+    bindings: impl Iterator<Item = (&'a String, &'a Vec<String>)>,
+    query: &str,
+) -> Vec<HelpEntry> {
+    let query_lower = query.to_lowercase();
+    let mut entries: Vec<HelpEntry> = bindings
+        .filter(|(_, keys)| !keys.is_empty())
+        .map(|(command, keys)| HelpEntry {
+            keys: keys
+                .iter()
+                .map(|k| format_key_for_display(k))
+                .collect::<Vec<_>>()
+                .join(", "),
+            command: command.clone(),
+        })
+        .filter(|entry| {
+            fuzzy_match(query, &entry.command).is_some()
+                || entry.keys.to_lowercase().contains(&query_lower)
+        })
+        .collect();
+    entries.sort_by(|a, b| a.command.cmp(&b.command));
+    entries
+}
+
+/// Group the bindings visible in `context` into sections
+fn collect_sections<C: BindingContext>(
+    keybindings: &Keybindings<C>,
+    context: C,
+    query: &str,
+    strings: &Strings,
+) -> Vec<HelpSection> {
+    let context_bindings = keybindings.get_context_bindings(context);
+    let shadowed = |command: &String| context_bindings.is_some_and(|b| b.contains_key(command));
+
+    let sections = [
+        HelpSection {
+            title: context.name().to_string(),
+            entries: context_bindings
+                .map(|bindings| entries(bindings.iter(), query))
+                .unwrap_or_default(),
+        },
+        HelpSection {
+            title: strings.help_global.to_string(),
+            entries: entries(
+                keybindings
+                    .global_bindings()
+                    .iter()
+                    .filter(|(command, _)| !shadowed(command)),
+                query,
+            ),
+        },
+    ];
+
+    sections
+        .into_iter()
+        .filter(|section| !section.entries.is_empty())
+        .collect()
+}
+
+/// Render sections as lines with an aligned key column
+fn section_lines(sections: &[HelpSection]) -> Vec<Line<'static>> {
+    let key_width = sections
+        .iter()
+        .flat_map(|s| &s.entries)
+        .map(|e| e.keys.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = Vec::new();
+    for (i, section) in sections.iter().enumerate() {
+        if i > 0 {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::styled(
+            section.title.clone(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for entry in &section.entries {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<key_width$}  ", entry.keys),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(entry.command.clone()),
+            ]));
+        }
+    }
+    lines
+}
+
+/// A searchable keybinding cheat sheet
+///
+/// Lists the commands available in the current context, grouped by
+/// context and global bindings. Typing filters commands (fuzzy) and keys,
+/// Up/Down and PageUp/PageDown (or the mouse wheel) scroll, and Esc
+/// dismisses. Render it inside a modal area, e.g. with
+/// [`centered_rect`](crate::centered_rect) and [`render_modal`](crate::render_modal).
+pub struct HelpOverlay<C> {
+    input: TextInput,
+    /// First visible line
+    scroll: usize,
+    /// Total lines from the last render
+    line_count: usize,
+    /// List area from the last render, used for viewport size and mouse hits
+    list_area: Rect,
+    /// Query seen on the last event/render, used to reset scrolling
+    last_query: String,
+    _context: PhantomData<fn() -> C>,
+}
+
+impl<C> Default for HelpOverlay<C> {
+    fn default() -> Self {
+        Self {
+            input: TextInput::default(),
+            scroll: 0,
+            line_count: 0,
+            list_area: Rect::default(),
+            last_query: String::new(),
+            _context: PhantomData,
+        }
+    }
+}
+
+impl<C> HelpOverlay<C> {
+    /// Create a new HelpOverlay
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reset scrolling when the query changed since the last call
+    fn sync_query(&mut self, query: &str) {
+        if self.last_query != query {
+            self.last_query = query.to_string();
+            self.scroll = 0;
+        }
+    }
+
+    /// Largest valid scroll offset for the last rendered viewport
+    fn max_scroll(&self) -> usize {
+        self.line_count
+            .saturating_sub(self.list_area.height.saturating_sub(2) as usize)
+    }
+
+    /// Scroll by `delta` lines, clamped to the content
+    fn scroll_by(&mut self, delta: isize) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(delta)
+            .min(self.max_scroll());
+    }
+}
+
+impl<C: BindingContext, A> Component<A> for HelpOverlay<C> {
+    type Props<'a> = HelpOverlayProps<'a, C, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        self.sync_query(props.query);
+
+        let page = self.list_area.height.saturating_sub(2).max(1) as isize;
+        match event {
+            EventKind::Scroll { column, row, delta } => {
+                let area = self.list_area;
+                let inside = *column >= area.x
+                    && *column < area.x.saturating_add(area.width)
+                    && *row >= area.y
+                    && *row < area.y.saturating_add(area.height);
+                if inside {
+                    self.scroll_by(delta.signum() * WHEEL_STEP as isize);
+                }
+                None
+            }
+            EventKind::Key(key) if props.is_focused => match key.code {
+                KeyCode::Esc => Some((props.on_dismiss)()),
+                KeyCode::Down => {
+                    self.scroll_by(1);
+                    None
+                }
+                KeyCode::Up => {
+                    self.scroll_by(-1);
+                    None
+                }
+                KeyCode::PageDown => {
+                    self.scroll_by(page);
+                    None
+                }
+                KeyCode::PageUp => {
+                    self.scroll_by(-page);
+                    None
+                }
+                _ => {
+                    let input_props = TextInputProps {
+                        value: props.query,
+                        placeholder: props.theme.strings.help_search,
+                        is_focused: true,
+                        show_border: true,
+                        bg_color: None,
+                        theme: props.theme,
+                        padding_x: 0,
+                        padding_y: 0,
+                        history: &[],
+                        mask: InputMask::default(),
+                        edit_mode: EditMode::default(),
+                        keybindings: None,
+                        on_change: props.on_query_change,
+                        on_submit: props.on_query_change,
+                    };
+                    self.input
+                        .handle_event(event, input_props)
+                        .into_iter()
+                        .next()
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        self.sync_query(props.query);
+        frame.render_widget(Clear, area);
+
+        let [input_area, list_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let input_props = TextInputProps {
+            value: props.query,
+            placeholder: props.theme.strings.help_search,
+            is_focused: props.is_focused,
+            show_border: true,
+            bg_color: None,
+            theme: props.theme,
+            padding_x: 0,
+            padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
+            on_change: props.on_query_change,
+            on_submit: props.on_query_change,
+        };
+        self.input.render(frame, input_area, input_props);
+
+        let sections = collect_sections(
+            props.keybindings,
+            props.context,
+            props.query,
+            &props.theme.strings,
+        );
+        let lines = if sections.is_empty() {
+            vec![Line::styled(
+                props.theme.strings.help_no_matches,
+                props.theme.muted(),
+            )]
+        } else {
+            section_lines(&sections)
+        };
+
+        self.list_area = list_area;
+        self.line_count = lines.len();
+        self.scroll = self.scroll.min(self.max_scroll());
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(props.theme.border_style(props.is_focused))
+            .title(format!(" {} ", props.theme.strings.help_title));
+        let inner = block.inner(list_area);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .scroll((self.scroll as u16, 0)),
+            list_area,
+        );
+
+        if self.line_count > inner.height as usize {
+            let mut state = ScrollbarState::new(self.max_scroll()).position(self.scroll);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                list_area,
+                &mut state,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestContext {
+        Main,
+        Search,
+    }
+
+    impl BindingContext for TestContext {
+        fn name(&self) -> &'static str {
+            match self {
+                TestContext::Main => "main",
+                TestContext::Search => "search",
+            }
+        }
+
+        fn from_name(name: &str) -> Option<Self> {
+            match name {
+                "main" => Some(TestContext::Main),
+                "search" => Some(TestContext::Search),
+                _ => None,
+            }
+        }
+
+        fn all() -> &'static [Self] {
+            &[TestContext::Main, TestContext::Search]
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Query(String),
+        Dismiss,
+    }
+
+    fn make_keybindings() -> Keybindings<TestContext> {
+        let mut kb = Keybindings::new();
+        kb.add_global("quit", vec!["q".into(), "ctrl+c".into()]);
+        kb.add_global("help", vec!["?".into()]);
+        kb.add(TestContext::Main, "open_file", vec!["ctrl+p".into()]);
+        kb.add(TestContext::Main, "help", vec!["f1".into()]);
+        kb.add(TestContext::Search, "next_match", vec!["n".into()]);
+        kb
+    }
+
+    fn props<'a>(
+        keybindings: &'a Keybindings<TestContext>,
+        query: &'a str,
+    ) -> HelpOverlayProps<'a, TestContext, TestAction> {
+        HelpOverlayProps {
+            keybindings,
+            context: TestContext::Main,
+            query,
+            is_focused: true,
+            theme: &Theme::DARK,
+            on_query_change: TestAction::Query,
+            on_dismiss: || TestAction::Dismiss,
+        }
+    }
+
+    #[test]
+    fn test_sections_grouped_and_shadowed() {
+        let kb = make_keybindings();
+        let sections = collect_sections(&kb, TestContext::Main, "", &Strings::ENGLISH);
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].title, "main");
+        let main: Vec<_> = sections[0]
+            .entries
+            .iter()
+            .map(|e| e.command.as_str())
+            .collect();
+        assert_eq!(main, vec!["help", "open_file"]);
+        assert_eq!(sections[0].entries[1].keys, "^P");
+
+        // Global "help" is overridden by the context binding
+        assert_eq!(sections[1].title, "global");
+        assert_eq!(
+            sections[1].entries,
+            vec![HelpEntry {
+                keys: "Q, ^C".into(),
+                command: "quit".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_query_filters_commands_and_keys() {
+        let kb = make_keybindings();
+
+        let sections = collect_sections(&kb, TestContext::Main, "opf", &Strings::ENGLISH);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].entries[0].command, "open_file");
+
+        let sections = collect_sections(&kb, TestContext::Main, "^c", &Strings::ENGLISH);
+        assert_eq!(sections[0].entries[0].command, "quit");
+
+        assert!(collect_sections(&kb, TestContext::Main, "zzz", &Strings::ENGLISH).is_empty());
+    }
+
+    #[test]
+    fn test_render_cheat_sheet() {
+        let kb = make_keybindings();
+        let mut overlay = HelpOverlay::<TestContext>::new();
+        let mut render = RenderHarness::new(40, 14);
+
+        let output = render.render_to_string_plain(|frame| {
+            overlay.render(frame, frame.area(), props(&kb, ""));
+        });
+
+        assert!(output.contains("Keybindings"));
+        assert!(output.contains("main"));
+        assert!(output.contains("^P"));
+        assert!(output.contains("open_file"));
+        assert!(output.contains("Q, ^C"));
+        assert!(!output.contains("next_match"));
+    }
+
+    #[test]
+    fn test_keys_dismiss_scroll_and_search() {
+        let kb = make_keybindings();
+        let mut overlay = HelpOverlay::<TestContext>::new();
+        let mut render = RenderHarness::new(40, 8);
+        render.render(|frame| {
+            overlay.render(frame, frame.area(), props(&kb, ""));
+        });
+
+        let send = |overlay: &mut HelpOverlay<TestContext>, k: &str| -> Vec<TestAction> {
+            overlay
+                .handle_event(&EventKind::Key(key(k)), props(&kb, ""))
+                .into_iter()
+                .collect()
+        };
+
+        // 3 visible lines out of 6
+        assert!(send(&mut overlay, "down").is_empty());
+        assert_eq!(overlay.scroll, 1);
+        send(&mut overlay, "pagedown");
+        assert_eq!(overlay.scroll, 3);
+        send(&mut overlay, "up");
+        assert_eq!(overlay.scroll, 2);
+
+        assert_eq!(send(&mut overlay, "o"), vec![TestAction::Query("o".into())]);
+        assert_eq!(send(&mut overlay, "esc"), vec![TestAction::Dismiss]);
+    }
+}
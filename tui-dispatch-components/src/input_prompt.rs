@@ -0,0 +1,187 @@
+//! Modal text prompt
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin, Rect},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, Theme};
+
+use crate::text_input::{EditMode, InputMask, TextInput, TextInputProps};
+
+/// Props for InputPrompt component
+pub struct InputPromptProps<'a, A> {
+    /// Prompt title, shown in the border
+    pub title: &'a str,
+    /// Text shown above the input (empty = none)
+    pub message: &'a str,
+    /// Current input value
+    pub value: &'a str,
+    /// Placeholder text when empty
+    pub placeholder: &'a str,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Colors for the border and input
+    pub theme: &'a Theme,
+    /// Callback when value changes
+    pub on_change: fn(String) -> A,
+    /// Callback when user submits (Enter)
+    pub on_submit: fn(String) -> A,
+    /// Callback when the prompt is cancelled (Esc)
+    pub on_cancel: fn() -> A,
+}
+
+/// A modal prompt asking for a single line of text
+///
+/// Wraps a [`TextInput`]: typing and cursor keys edit the value, Enter
+/// submits it and Esc cancels. Render it inside a modal area, e.g. with
+/// [`centered_rect`](crate::centered_rect) and [`render_modal`](crate::render_modal).
+#[derive(Default)]
+pub struct InputPrompt {
+    input: TextInput,
+}
+
+impl InputPrompt {
+    /// Create a new InputPrompt
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Props for the wrapped text input
+    fn input_props<'a, A>(props: &InputPromptProps<'a, A>) -> TextInputProps<'a, A> {
+        TextInputProps {
+            value: props.value,
+            placeholder: props.placeholder,
+            is_focused: props.is_focused,
+            show_border: true,
+            bg_color: None,
+            theme: props.theme,
+            padding_x: 0,
+            padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
+            on_change: props.on_change,
+            on_submit: props.on_submit,
+        }
+    }
+}
+
+impl<A> Component<A> for InputPrompt {
+    type Props<'a> = InputPromptProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        match event {
+            EventKind::Key(key) if props.is_focused && key.code == KeyCode::Esc => {
+                Some((props.on_cancel)())
+            }
+            _ => self
+                .input
+                .handle_event(event, Self::input_props(&props))
+                .into_iter()
+                .next(),
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(props.theme.border_style(props.is_focused))
+            .title(format!(" {} ", props.title));
+        let inner = block.inner(area).inner(Margin::new(1, 0));
+        frame.render_widget(block, area);
+
+        let message_height = if props.message.is_empty() { 0 } else { 1 };
+        let [message_area, input_area, hint_area] = Layout::vertical([
+            Constraint::Min(message_height),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+
+        if !props.message.is_empty() {
+            frame.render_widget(
+                Paragraph::new(props.message).wrap(Wrap { trim: true }),
+                message_area,
+            );
+        }
+        self.input
+            .render(frame, input_area, Self::input_props(&props));
+        frame.render_widget(
+            Paragraph::new(props.theme.strings.prompt_hint).style(props.theme.muted()),
+            hint_area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Change(String),
+        Submit(String),
+        Cancel,
+    }
+
+    fn props(value: &str) -> InputPromptProps<'_, TestAction> {
+        InputPromptProps {
+            title: "Save preset",
+            message: "Name for the new preset:",
+            value,
+            placeholder: "preset name",
+            is_focused: true,
+            theme: &Theme::DARK,
+            on_change: TestAction::Change,
+            on_submit: TestAction::Submit,
+            on_cancel: || TestAction::Cancel,
+        }
+    }
+
+    fn send(prompt: &mut InputPrompt, k: &str, value: &str) -> Vec<TestAction> {
+        prompt
+            .handle_event(&EventKind::Key(key(k)), props(value))
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_typing_submit_and_cancel() {
+        let mut prompt = InputPrompt::new();
+
+        assert_eq!(
+            send(&mut prompt, "a", ""),
+            vec![TestAction::Change("a".into())]
+        );
+        assert_eq!(
+            send(&mut prompt, "enter", "dusk"),
+            vec![TestAction::Submit("dusk".into())]
+        );
+        assert_eq!(send(&mut prompt, "esc", "dusk"), vec![TestAction::Cancel]);
+    }
+
+    #[test]
+    fn test_render_prompt() {
+        let mut prompt = InputPrompt::new();
+        let mut render = RenderHarness::new(40, 8);
+
+        let output = render.render_to_string_plain(|frame| {
+            prompt.render(frame, frame.area(), props(""));
+        });
+
+        assert!(output.contains("Save preset"));
+        assert!(output.contains("Name for the new preset:"));
+        assert!(output.contains("preset name"));
+        assert!(output.contains("Esc to cancel"));
+    }
+}
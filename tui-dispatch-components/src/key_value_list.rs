@@ -0,0 +1,335 @@
+//! Aligned key/value rows for property inspectors
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use tui_dispatch_core::debug::DebugTableRow;
+use tui_dispatch_core::{Component, EventKind, Theme};
+use unicode_width::UnicodeWidthStr;
+
+use crate::status_bar::truncate;
+
+/// Narrowest and widest key column when `key_width` is not set
+const KEY_WIDTH_RANGE: (usize, usize) = (8, 30);
+
+/// A row in a [`KeyValueList`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyValueRow {
+    /// Section header (e.g. "Connection")
+    Section(String),
+    /// Key/value entry (e.g. "host" -> "localhost")
+    Entry { key: String, value: String },
+}
+
+impl KeyValueRow {
+    /// Create a section header
+    pub fn section(title: impl Into<String>) -> Self {
+        Self::Section(title.into())
+    }
+
+    /// Create a key/value entry
+    pub fn entry(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Entry {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Value of an entry (None for sections)
+    pub fn value(&self) -> Option<&str> {
+        match self {
+            Self::Section(_) => None,
+            Self::Entry { value, .. } => Some(value),
+        }
+    }
+}
+
+/// Rows built with the debug layer's `DebugTableBuilder` display as is
+impl From<DebugTableRow> for KeyValueRow {
+    fn from(row: DebugTableRow) -> Self {
+        match row {
+            DebugTableRow::Section(title) => Self::Section(title),
+            DebugTableRow::Entry { key, value } => Self::Entry { key, value },
+        }
+    }
+}
+
+/// Props for KeyValueList component
+pub struct KeyValueListProps<'a, A> {
+    /// Sections and entries, in display order
+    pub rows: &'a [KeyValueRow],
+    /// Index of the selected row (should point at an entry)
+    pub selected: usize,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Width of the key column (None = fit the longest key)
+    pub key_width: Option<u16>,
+    /// Colors for keys, values, section headers and selection
+    pub theme: &'a Theme,
+    /// Callback when the selection moves
+    pub on_select: fn(usize) -> A,
+    /// Callback with the selected value on Enter, for copying it (None = disabled)
+    pub on_copy: Option<fn(String) -> A>,
+}
+
+/// A scrollable list of aligned key/value rows grouped under section headers
+///
+/// Keys share one column and long values are cut with an ellipsis. When
+/// focused, j/k or Up/Down move between entries (skipping sections),
+/// g/G, Home/End and PageUp/PageDown jump, and Enter passes the selected
+/// value to `on_copy` so the app can put it on the clipboard (e.g. with
+/// OSC 52). The selection lives in app state.
+#[derive(Default)]
+pub struct KeyValueList {
+    /// Scroll offset for viewport
+    scroll_offset: usize,
+    /// Rows visible in the last render, for PageUp/PageDown
+    viewport_rows: usize,
+}
+
+impl KeyValueList {
+    /// Create a new KeyValueList
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ensure the selected row is visible
+    fn ensure_visible(&mut self, selected: usize, viewport_height: usize) {
+        if viewport_height == 0 {
+            return;
+        }
+
+        if selected < self.scroll_offset {
+            self.scroll_offset = selected;
+        } else if selected >= self.scroll_offset + viewport_height {
+            self.scroll_offset = selected.saturating_sub(viewport_height - 1);
+        }
+    }
+}
+
+impl<A> Component<A> for KeyValueList {
+    type Props<'a> = KeyValueListProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        if !props.is_focused {
+            return None;
+        }
+        let EventKind::Key(key) = event else {
+            return None;
+        };
+
+        let entries: Vec<usize> = props
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| matches!(row, KeyValueRow::Entry { .. }))
+            .map(|(index, _)| index)
+            .collect();
+        let selected = props.selected;
+        let position = entries.iter().position(|&i| i >= selected).unwrap_or(0);
+
+        let target = match key.code {
+            KeyCode::Char('j') | KeyCode::Down => entries.iter().copied().find(|&i| i > selected),
+            KeyCode::Char('k') | KeyCode::Up => {
+                entries.iter().rev().copied().find(|&i| i < selected)
+            }
+            KeyCode::Char('g') | KeyCode::Home => entries.first().copied(),
+            KeyCode::Char('G') | KeyCode::End => entries.last().copied(),
+            KeyCode::PageDown => {
+                let last = entries.len().saturating_sub(1);
+                let target = (position + self.viewport_rows.max(1)).min(last);
+                entries.get(target).copied()
+            }
+            KeyCode::PageUp => {
+                let target = position.saturating_sub(self.viewport_rows.max(1));
+                entries.get(target).copied()
+            }
+            KeyCode::Enter => {
+                let value = props.rows.get(selected).and_then(KeyValueRow::value);
+                return props
+                    .on_copy
+                    .zip(value)
+                    .map(|(on_copy, value)| on_copy(value.to_string()));
+            }
+            _ => None,
+        };
+
+        target
+            .filter(|&index| index != selected)
+            .map(props.on_select)
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let mut inner = area;
+        if props.show_border {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(props.theme.border_style(props.is_focused));
+            inner = block.inner(area);
+            frame.render_widget(block, area);
+        }
+
+        let viewport_height = inner.height as usize;
+        self.viewport_rows = viewport_height;
+        self.ensure_visible(props.selected, viewport_height);
+
+        let key_width = match props.key_width {
+            Some(width) => width as usize,
+            None => {
+                let longest = props
+                    .rows
+                    .iter()
+                    .filter_map(|row| match row {
+                        KeyValueRow::Entry { key, .. } => Some(key.width()),
+                        KeyValueRow::Section(_) => None,
+                    })
+                    .max()
+                    .unwrap_or(0);
+                longest.clamp(KEY_WIDTH_RANGE.0, KEY_WIDTH_RANGE.1)
+            }
+        };
+        // Selection marker, key column and a gap before the value
+        let value_width = (inner.width as usize).saturating_sub(key_width + 4);
+
+        let lines: Vec<Line> = props
+            .rows
+            .iter()
+            .enumerate()
+            .skip(self.scroll_offset)
+            .take(viewport_height)
+            .map(|(index, row)| {
+                let is_selected = index == props.selected;
+                match row {
+                    KeyValueRow::Section(title) => Line::from(Span::styled(
+                        title.clone(),
+                        Style::default()
+                            .fg(props.theme.accent)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+                    KeyValueRow::Entry { key, value } => {
+                        let key = truncate(key, key_width);
+                        let padding = " ".repeat(key_width - key.width());
+                        let (prefix, key_style, value_style) = if is_selected && props.is_focused {
+                            ("> ", props.theme.selected(), props.theme.selected())
+                        } else {
+                            ("  ", props.theme.muted(), props.theme.text())
+                        };
+                        Line::from(vec![
+                            Span::raw(prefix),
+                            Span::styled(format!("{key}{padding}"), key_style),
+                            Span::raw("  "),
+                            Span::styled(truncate(value, value_width).into_owned(), value_style),
+                        ])
+                    }
+                }
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Select(usize),
+        Copy(String),
+    }
+
+    fn rows() -> Vec<KeyValueRow> {
+        vec![
+            KeyValueRow::section("Connection"),
+            KeyValueRow::entry("host", "localhost"),
+            KeyValueRow::entry("port", "6379"),
+            KeyValueRow::section("Stats"),
+            KeyValueRow::entry("keys", "1024"),
+        ]
+    }
+
+    fn props(rows: &[KeyValueRow], selected: usize) -> KeyValueListProps<'_, TestAction> {
+        KeyValueListProps {
+            rows,
+            selected,
+            is_focused: true,
+            show_border: false,
+            key_width: None,
+            theme: &Theme::DARK,
+            on_select: TestAction::Select,
+            on_copy: Some(TestAction::Copy),
+        }
+    }
+
+    #[test]
+    fn test_navigation_skips_sections() {
+        let rows = rows();
+        let mut list = KeyValueList::new();
+        let mut press = |code: &str, selected: usize| -> Vec<TestAction> {
+            list.handle_event(&EventKind::Key(key(code)), props(&rows, selected))
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(press("down", 2), vec![TestAction::Select(4)]);
+        assert_eq!(press("k", 4), vec![TestAction::Select(2)]);
+        assert_eq!(press("home", 4), vec![TestAction::Select(1)]);
+        assert!(press("up", 1).is_empty());
+        assert!(press("end", 4).is_empty());
+    }
+
+    #[test]
+    fn test_enter_copies_value() {
+        let rows = rows();
+        let mut list = KeyValueList::new();
+
+        let actions: Vec<_> = list
+            .handle_event(&EventKind::Key(key("enter")), props(&rows, 2))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Copy("6379".into())]);
+
+        let actions: Vec<_> = list
+            .handle_event(
+                &EventKind::Key(key("enter")),
+                KeyValueListProps {
+                    on_copy: None,
+                    ..props(&rows, 2)
+                },
+            )
+            .into_iter()
+            .collect();
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_render_aligns_and_truncates() {
+        let rows = vec![
+            KeyValueRow::entry("id", "7"),
+            KeyValueRow::entry("name", "a rather long value"),
+        ];
+        let mut render = RenderHarness::new(24, 2);
+        let mut list = KeyValueList::new();
+
+        let output = render.render_to_string_plain(|frame| {
+            list.render(frame, frame.area(), props(&rows, 0));
+        });
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0].trim_end(), "> id        7");
+        assert_eq!(lines[1].trim_end(), "  name      a rather lo…");
+    }
+}
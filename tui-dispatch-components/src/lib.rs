@@ -7,13 +7,39 @@
 //! # Components
 //!
 //! - [`SelectList`] - Scrollable selection list with keyboard navigation
-//! - [`TextInput`] - Single-line text input with cursor
-//! - [`Modal`] - Overlay with dimmed background snapshot
+//! - [`TextInput`] - Single-line text input with cursor, input masks, password mode
+//!   and optional emacs or vim editing
+//! - [`Modal`] - Overlay with dimmed background, size presets and an optional title bar
+//! - [`Tabs`] - Horizontal tab bar with optional close buttons
+//! - [`CommandPalette`] - Fuzzy finder over a candidate list
+//! - [`ScrollView`] - Scrollable viewport for content larger than its area
+//! - [`ProgressBar`] - Horizontal progress bar with configurable glyphs
+//! - [`Spinner`] - Tick-driven loading indicator
+//! - [`Sparkline`] and [`BarChart`] - Charts of `f64` series with auto-scaling and min/max labels
+//! - [`ToastStack`] - Stacked notifications anchored to a screen corner
+//! - [`HelpOverlay`] - Searchable keybinding cheat sheet
+//! - [`ConfirmDialog`] - Yes/no confirmation for destructive operations
+//! - [`ContextMenu`] - Anchored popup of commands with accelerators and nested submenus
+//! - [`InputPrompt`] - Modal prompt wrapping a text input
+//! - [`KeyValueList`] - Aligned key/value rows with section headers for property inspectors
+//! - [`NumberInput`] - Numeric field with stepper keys, bounds and a unit suffix
+//! - [`StatusBar`] - Left/center/right segments that truncate and drop by priority
+//! - [`Autocomplete`] - Text input with debounced queries and a suggestion dropdown
+//! - [`Paginator`] - Page indicator footer for paged results
+//! - [`Breadcrumb`] - Clickable path of segments that collapses to "…" when narrow
+//! - [`Stack`] and [`Spacer`] - Row/column layout of child render closures with
+//!   weights, gaps and alignment
+//! - [`CodeView`] - Source code with line numbers and highlighted line ranges
+//!   (syntect highlighting with the `syntax` feature)
+//! - `MarkdownView` - Scrollable markdown document with search highlighting
+//!   (requires the `markdown` feature)
+//! - `TextEditor` - Multi-line editor over a rope-backed `TextBuffer` with
+//!   selection, undo and edit patches (requires the `editor` feature)
 //!
 //! # Example
 //!
 //! ```ignore
-//! use tui_dispatch_components::{SelectList, SelectListProps};
+//! use tui_dispatch_components::{HighlightConfig, ScrollbarConfig, SelectList, SelectListProps};
 //!
 //! // In your render function:
 //! let mut list = SelectList::default();
@@ -25,22 +51,128 @@
 //!     padding_x: 0,
 //!     padding_y: 0,
 //!     highlight_query: None,
+//!     highlight: HighlightConfig::default(),
+//!     scrollbar: ScrollbarConfig::default(),
+//!     theme: &state.theme,
+//!     groups: &[],
+//!     on_toggle_group: None,
+//!     type_ahead: None,
+//!     page: None,
+//!     on_page_change: None,
+//!     keybindings: None,
 //!     on_select: |i| Action::Select(i),
 //! });
 //! ```
+//!
+//! # Theming
+//!
+//! Components that draw focus, selection or placeholders take a
+//! [`Theme`](tui_dispatch_core::Theme) prop. Pass the same theme everywhere
+//! (e.g. from app state) to restyle the whole app at once, and use
+//! [`ModalStyle::from_theme`] and `DebugStyle::from_theme` to match.
+//! Built-in labels (help overlay, prompt hint, paginator) come from the
+//! theme's [`Strings`](tui_dispatch_core::Strings), so they translate along.
+//!
+//! # Rebinding keys
+//!
+//! [`SelectList`] and [`TextInput`] take an optional `keybindings` prop
+//! ([`ComponentBindings`]) that maps keys to commands such as `"list.next"`
+//! or `"input.submit"` through the app's `Keybindings`, so their keys can
+//! be changed from a keybindings config.
 
+mod autocomplete;
+mod bindings;
+mod breadcrumb;
+mod chart;
+mod code_view;
+mod command_palette;
+mod confirm_dialog;
+mod context_menu;
+mod help_overlay;
+mod input_prompt;
+mod key_value_list;
+#[cfg(feature = "markdown")]
+mod markdown_view;
 mod modal;
+mod number_input;
+mod paginator;
+mod progress_bar;
+mod scroll_view;
 mod select_list;
+mod spinner;
+mod stack;
+mod status_bar;
+mod tabs;
+#[cfg(feature = "editor")]
+mod text_buffer;
+#[cfg(feature = "editor")]
+mod text_editor;
 mod text_input;
+mod toast_stack;
 
-pub use modal::{centered_rect, render_modal, ModalStyle};
-pub use select_list::{SelectList, SelectListProps};
-pub use text_input::{TextInput, TextInputProps};
+pub use autocomplete::{Autocomplete, AutocompleteProps};
+pub use bindings::ComponentBindings;
+pub use breadcrumb::{Breadcrumb, BreadcrumbProps};
+pub use chart::{BarChart, BarChartProps, ChartStyle, Sparkline, SparklineProps};
+#[cfg(feature = "syntax")]
+pub use code_view::SyntaxHighlighter;
+pub use code_view::{CodeView, CodeViewProps, LineHighlight};
+pub use command_palette::{
+    fuzzy_match, fuzzy_rank, CommandPalette, CommandPaletteProps, FuzzyMatch,
+};
+pub use confirm_dialog::{ConfirmDialog, ConfirmDialogProps};
+pub use context_menu::{ContextMenu, ContextMenuProps, MenuItem};
+pub use help_overlay::{HelpOverlay, HelpOverlayProps};
+pub use input_prompt::{InputPrompt, InputPromptProps};
+pub use key_value_list::{KeyValueList, KeyValueListProps, KeyValueRow};
+#[cfg(feature = "markdown")]
+pub use markdown_view::{
+    CodeHighlighter, MarkdownDocument, MarkdownLine, MarkdownStats, MarkdownStyle, MarkdownView,
+    MarkdownViewProps,
+};
+pub use modal::{centered_rect, render_modal, render_modal_sized, ModalSize, ModalStyle};
+pub use number_input::{NumberInput, NumberInputProps, NumberValue};
+pub use paginator::{PageInfo, Paginator, PaginatorProps};
+pub use progress_bar::{BarGlyphs, ProgressBar, ProgressBarProps, ProgressBarStyle};
+pub use scroll_view::{ScrollOffset, ScrollView, ScrollViewProps};
+pub use select_list::{
+    HighlightConfig, MatchMode, ScrollbarConfig, SelectGroup, SelectItem, SelectList,
+    SelectListProps, TYPE_AHEAD_TIMEOUT,
+};
+pub use spinner::{Spinner, SpinnerGlyphs, SpinnerProps, SpinnerStyle};
+pub use stack::{Spacer, SpacerProps, Stack, StackAlign, StackItem, StackProps};
+pub use status_bar::{StatusBar, StatusBarProps, StatusSegment};
+pub use tabs::{Tabs, TabsProps};
+#[cfg(feature = "editor")]
+pub use text_buffer::{EditorCommand, Motion, TextBuffer, TextPatch, TextPosition};
+#[cfg(feature = "editor")]
+pub use text_editor::{TextEditor, TextEditorProps};
+pub use text_input::{EditMode, InputMask, TextInput, TextInputProps};
+pub use toast_stack::{ToastCorner, ToastStack, ToastStackProps};
+pub use tui_dispatch_core::debug::ScrollbarStyle;
 
 /// Prelude for convenient imports
 pub mod prelude {
+    #[cfg(feature = "syntax")]
+    pub use crate::SyntaxHighlighter;
+    pub use crate::{
+        centered_rect, render_modal, render_modal_sized, Autocomplete, AutocompleteProps, BarChart,
+        BarChartProps, BarGlyphs, Breadcrumb, BreadcrumbProps, ChartStyle, CodeView, CodeViewProps,
+        CommandPalette, CommandPaletteProps, ComponentBindings, ConfirmDialog, ConfirmDialogProps,
+        ContextMenu, ContextMenuProps, EditMode, HelpOverlay, HelpOverlayProps, HighlightConfig,
+        InputMask, InputPrompt, InputPromptProps, KeyValueList, KeyValueListProps, KeyValueRow,
+        MenuItem, ModalSize, ModalStyle, NumberInput, NumberInputProps, NumberValue, PageInfo,
+        Paginator, PaginatorProps, ProgressBar, ProgressBarProps, ProgressBarStyle, ScrollOffset,
+        ScrollView, ScrollViewProps, ScrollbarConfig, ScrollbarStyle, SelectGroup, SelectItem,
+        SelectList, SelectListProps, Spacer, SpacerProps, Sparkline, SparklineProps, Spinner,
+        SpinnerGlyphs, SpinnerProps, SpinnerStyle, Stack, StackAlign, StackItem, StackProps,
+        StatusBar, StatusBarProps, StatusSegment, Tabs, TabsProps, TextInput, TextInputProps,
+        ToastCorner, ToastStack, ToastStackProps,
+    };
+    #[cfg(feature = "editor")]
     pub use crate::{
-        centered_rect, render_modal, ModalStyle, SelectList, SelectListProps, TextInput,
-        TextInputProps,
+        EditorCommand, Motion, TextBuffer, TextEditor, TextEditorProps, TextPatch, TextPosition,
     };
+    #[cfg(feature = "markdown")]
+    pub use crate::{MarkdownDocument, MarkdownStyle, MarkdownView, MarkdownViewProps};
 }
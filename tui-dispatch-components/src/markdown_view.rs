@@ -0,0 +1,731 @@
+//! Scrollable markdown viewer
+//!
+//! Requires the `markdown` feature.
+
+use crossterm::event::KeyCode;
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, Theme};
+use unicode_width::UnicodeWidthStr;
+
+/// Lines moved per mouse wheel tick
+const WHEEL_STEP: usize = 3;
+
+/// Highlights a code block: `(code, lang) -> lines`, one per source line
+pub type CodeHighlighter<'a> = &'a dyn Fn(&str, &str) -> Vec<Line<'static>>;
+
+/// Styles used when parsing and drawing markdown
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownStyle {
+    /// Heading styles for levels 1-6
+    pub headings: [Style; 6],
+    /// Link text
+    pub link: Style,
+    /// Inline `code` spans
+    pub inline_code: Style,
+    /// Background of fenced and indented code blocks
+    pub code_bg: Color,
+    /// Language label in the corner of code blocks
+    pub code_label: Style,
+    /// Bullets, rules, table borders and line numbers
+    pub muted: Style,
+    /// Table header cells
+    pub table_header: Style,
+    /// Background of lines matching the search
+    pub match_bg: Color,
+    /// Background of the current search match
+    pub current_match_bg: Color,
+}
+
+impl Default for MarkdownStyle {
+    fn default() -> Self {
+        let heading = |color| Style::default().fg(color).add_modifier(Modifier::BOLD);
+        Self {
+            headings: [
+                heading(Color::Cyan),
+                heading(Color::Green),
+                heading(Color::Yellow),
+                heading(Color::Magenta),
+                heading(Color::Magenta),
+                heading(Color::Magenta),
+            ],
+            link: Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::UNDERLINED),
+            inline_code: Style::default()
+                .fg(Color::Rgb(220, 180, 100))
+                .bg(Color::Rgb(40, 40, 50)),
+            code_bg: Color::Rgb(30, 30, 40),
+            code_label: Style::default().fg(Color::Rgb(90, 90, 110)),
+            muted: Style::default().fg(Color::DarkGray),
+            table_header: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            match_bg: Color::Rgb(50, 50, 30),
+            current_match_bg: Color::Rgb(80, 80, 40),
+        }
+    }
+}
+
+impl MarkdownStyle {
+    /// Create a style from the theme's accent, surface and muted colors
+    pub fn from_theme(theme: &Theme) -> Self {
+        let accent = Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD);
+        Self {
+            headings: [accent; 6],
+            link: Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::UNDERLINED),
+            inline_code: Style::default().fg(theme.warning).bg(theme.surface),
+            code_bg: theme.surface,
+            code_label: theme.muted(),
+            muted: theme.muted(),
+            table_header: accent,
+            ..Self::default()
+        }
+    }
+
+    /// Style for a heading level (1-6)
+    pub fn heading(&self, level: u8) -> Style {
+        self.headings[(level.clamp(1, 6) - 1) as usize]
+    }
+}
+
+/// A rendered line of a [`MarkdownDocument`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownLine {
+    /// Styled content
+    pub line: Line<'static>,
+    /// Whether the line belongs to a code block (drawn with a full-width background)
+    pub is_code: bool,
+    /// Language label, set on the first line of a fenced code block
+    pub lang: Option<String>,
+}
+
+impl MarkdownLine {
+    fn text(line: impl Into<Line<'static>>) -> Self {
+        Self {
+            line: line.into(),
+            is_code: false,
+            lang: None,
+        }
+    }
+
+    /// Plain text of the line, without styling
+    pub fn plain(&self) -> String {
+        self.line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+}
+
+/// Element counts collected while parsing
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MarkdownStats {
+    /// Headings of any level
+    pub heading_count: usize,
+    /// Links
+    pub link_count: usize,
+    /// Fenced and indented code blocks
+    pub code_block_count: usize,
+    /// List items
+    pub list_item_count: usize,
+    /// Paragraphs, including those inside list items
+    pub paragraph_count: usize,
+}
+
+/// Markdown parsed into styled lines, ready for [`MarkdownView`]
+///
+/// Parse once when the source changes and keep the document in state;
+/// rendering only slices the lines.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarkdownDocument {
+    /// Rendered lines
+    pub lines: Vec<MarkdownLine>,
+    /// Element counts
+    pub stats: MarkdownStats,
+}
+
+impl MarkdownDocument {
+    /// Parse markdown, drawing code blocks without syntax highlighting
+    pub fn parse(source: &str, style: &MarkdownStyle) -> Self {
+        Self::parse_with(source, style, None)
+    }
+
+    /// Parse markdown, passing each code block through `highlight`
+    ///
+    /// Plug in a highlighter such as syntect here; the code block
+    /// background is applied on top of the returned spans.
+    pub fn parse_with_highlighter(
+        source: &str,
+        style: &MarkdownStyle,
+        highlight: CodeHighlighter<'_>,
+    ) -> Self {
+        Self::parse_with(source, style, Some(highlight))
+    }
+
+    /// Number of rendered lines
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Whether the document has no lines
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Indices of lines containing `query` (case-insensitive)
+    pub fn find(&self, query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let query = query.to_lowercase();
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.plain().to_lowercase().contains(&query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn parse_with(
+        source: &str,
+        style: &MarkdownStyle,
+        highlight: Option<CodeHighlighter<'_>>,
+    ) -> Self {
+        let parser = Parser::new_ext(
+            source,
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+        );
+        let mut doc = Self::default();
+        let mut spans: Vec<Span<'static>> = Vec::new();
+
+        // Style stack for nested formatting
+        let mut style_stack: Vec<Style> = vec![Style::default()];
+        let mut code_block: Option<(String, String)> = None;
+        let mut table: Option<Vec<Vec<String>>> = None;
+        let mut row: Vec<String> = Vec::new();
+        let mut cell = String::new();
+
+        for event in parser {
+            match event {
+                Event::Start(tag) => {
+                    let current = *style_stack.last().unwrap_or(&Style::default());
+                    let next = match &tag {
+                        Tag::Heading { level, .. } => {
+                            doc.stats.heading_count += 1;
+                            style.heading(heading_level(*level))
+                        }
+                        Tag::Strong => current.add_modifier(Modifier::BOLD),
+                        Tag::Emphasis => current.add_modifier(Modifier::ITALIC),
+                        Tag::Strikethrough => current.add_modifier(Modifier::CROSSED_OUT),
+                        Tag::Link { .. } => {
+                            doc.stats.link_count += 1;
+                            current.patch(style.link)
+                        }
+                        Tag::CodeBlock(kind) => {
+                            doc.stats.code_block_count += 1;
+                            let lang = match kind {
+                                CodeBlockKind::Fenced(lang) => lang.to_string(),
+                                CodeBlockKind::Indented => String::new(),
+                            };
+                            code_block = Some((lang, String::new()));
+                            Style::default()
+                        }
+                        Tag::Item => {
+                            doc.stats.list_item_count += 1;
+                            spans.push(Span::styled("  • ", style.muted));
+                            Style::default()
+                        }
+                        Tag::Paragraph => {
+                            doc.stats.paragraph_count += 1;
+                            current
+                        }
+                        Tag::Table(_) => {
+                            table = Some(Vec::new());
+                            Style::default()
+                        }
+                        Tag::TableHead | Tag::TableRow => {
+                            row.clear();
+                            Style::default()
+                        }
+                        Tag::TableCell => {
+                            cell.clear();
+                            Style::default()
+                        }
+                        _ => current,
+                    };
+                    style_stack.push(next);
+                }
+                Event::End(tag_end) => {
+                    style_stack.pop();
+                    match tag_end {
+                        TagEnd::Heading(_) | TagEnd::Paragraph => {
+                            doc.flush(&mut spans);
+                            doc.lines.push(MarkdownLine::text(""));
+                        }
+                        TagEnd::Item => doc.flush(&mut spans),
+                        TagEnd::CodeBlock => {
+                            if let Some((lang, code)) = code_block.take() {
+                                doc.push_code(&code, &lang, style, highlight);
+                            }
+                        }
+                        TagEnd::TableCell => row.push(std::mem::take(&mut cell)),
+                        TagEnd::TableHead | TagEnd::TableRow => {
+                            if let Some(rows) = table.as_mut().filter(|_| !row.is_empty()) {
+                                rows.push(std::mem::take(&mut row));
+                            }
+                        }
+                        TagEnd::Table => {
+                            if let Some(rows) = table.take() {
+                                doc.push_table(&rows, style);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some((_, code)) = code_block.as_mut() {
+                        code.push_str(&text);
+                    } else if table.is_some() {
+                        cell.push_str(&text);
+                    } else {
+                        let current = *style_stack.last().unwrap_or(&Style::default());
+                        spans.push(Span::styled(text.to_string(), current));
+                    }
+                }
+                Event::Code(code) => {
+                    if table.is_some() {
+                        cell.push_str(&format!("`{}`", code));
+                    } else {
+                        spans.push(Span::styled(format!(" {} ", code), style.inline_code));
+                    }
+                }
+                Event::SoftBreak => spans.push(Span::raw(" ")),
+                Event::HardBreak => doc.flush(&mut spans),
+                Event::Rule => {
+                    doc.flush(&mut spans);
+                    doc.lines.push(MarkdownLine::text(Span::styled(
+                        "─".repeat(40),
+                        style.muted,
+                    )));
+                    doc.lines.push(MarkdownLine::text(""));
+                }
+                _ => {}
+            }
+        }
+
+        doc.flush(&mut spans);
+        doc
+    }
+
+    /// Move pending spans into a new line
+    fn flush(&mut self, spans: &mut Vec<Span<'static>>) {
+        if !spans.is_empty() {
+            self.lines.push(MarkdownLine::text(std::mem::take(spans)));
+        }
+    }
+
+    fn push_code(
+        &mut self,
+        code: &str,
+        lang: &str,
+        style: &MarkdownStyle,
+        highlight: Option<CodeHighlighter<'_>>,
+    ) {
+        let bg = Style::default().bg(style.code_bg);
+        let highlighted = match highlight {
+            Some(highlight) => highlight(code, lang),
+            None => code.lines().map(|l| Line::raw(l.to_string())).collect(),
+        };
+
+        for (i, code_line) in highlighted.into_iter().enumerate() {
+            let mut spans = vec![Span::styled("  ", bg)];
+            spans.extend(
+                code_line
+                    .spans
+                    .into_iter()
+                    .map(|s| Span::styled(s.content, s.style.bg(style.code_bg))),
+            );
+            self.lines.push(MarkdownLine {
+                line: Line::from(spans).style(bg),
+                is_code: true,
+                lang: (i == 0 && !lang.is_empty()).then(|| lang.to_string()),
+            });
+        }
+        self.lines.push(MarkdownLine::text(""));
+    }
+
+    fn push_table(&mut self, rows: &[Vec<String>], style: &MarkdownStyle) {
+        let Some(header) = rows.first() else {
+            return;
+        };
+
+        let col_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut col_widths = vec![0usize; col_count];
+        for row in rows {
+            for (i, cell) in row.iter().enumerate() {
+                col_widths[i] = col_widths[i].max(cell.width());
+            }
+        }
+
+        let table_row = |row: &[String], cell_style: Style| {
+            let mut spans = Vec::new();
+            for (i, cell) in row.iter().enumerate() {
+                let pad = col_widths[i].saturating_sub(cell.width());
+                spans.push(Span::styled(
+                    format!(" {}{} ", cell, " ".repeat(pad)),
+                    cell_style,
+                ));
+                if i + 1 < row.len() {
+                    spans.push(Span::styled("│", style.muted));
+                }
+            }
+            MarkdownLine::text(spans)
+        };
+
+        self.lines.push(table_row(header, style.table_header));
+        let separator = col_widths
+            .iter()
+            .map(|w| "─".repeat(w + 2))
+            .collect::<Vec<_>>()
+            .join("┼");
+        self.lines
+            .push(MarkdownLine::text(Span::styled(separator, style.muted)));
+        for row in &rows[1..] {
+            self.lines.push(table_row(row, Style::default()));
+        }
+        self.lines.push(MarkdownLine::text(""));
+    }
+}
+
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+/// Props for MarkdownView component
+pub struct MarkdownViewProps<'a, A> {
+    /// Parsed document
+    pub document: &'a MarkdownDocument,
+    /// Index of the first visible line (owned by app state)
+    pub scroll_offset: usize,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Show line numbers in a gutter
+    pub line_numbers: bool,
+    /// Wrap long lines (code blocks are never wrapped)
+    pub wrap: bool,
+    /// Line indices to highlight, e.g. from [`MarkdownDocument::find`]
+    pub search_matches: &'a [usize],
+    /// Line index of the current match, drawn brighter
+    pub current_match: Option<usize>,
+    /// Colors for code blocks, search matches and the gutter
+    pub style: &'a MarkdownStyle,
+    /// Colors for the border
+    pub theme: &'a Theme,
+    /// Callback with the new scroll offset (None = scrolling handled by the app)
+    pub on_scroll: Option<fn(usize) -> A>,
+}
+
+/// A scrollable view of a [`MarkdownDocument`]
+///
+/// Draws code blocks with a full-width background and a language label,
+/// an optional line number gutter, and search matches. When focused with
+/// `on_scroll` set, handles arrows/jk, PageUp/PageDown, Home/End and the
+/// mouse wheel.
+#[derive(Default)]
+pub struct MarkdownView {
+    /// Content area from the last render
+    viewport: Rect,
+}
+
+impl MarkdownView {
+    /// Create a new MarkdownView
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Emit a scroll action if the clamped offset differs from the current one
+    fn scroll_to<A>(&self, props: &MarkdownViewProps<'_, A>, offset: usize) -> Option<A> {
+        let max = props
+            .document
+            .len()
+            .saturating_sub(self.viewport.height as usize);
+        let offset = offset.min(max);
+        let on_scroll = props.on_scroll?;
+        (offset != props.scroll_offset).then(|| on_scroll(offset))
+    }
+
+    fn contains(&self, column: u16, row: u16) -> bool {
+        let area = self.viewport;
+        column >= area.x
+            && column < area.x.saturating_add(area.width)
+            && row >= area.y
+            && row < area.y.saturating_add(area.height)
+    }
+}
+
+impl<A> Component<A> for MarkdownView {
+    type Props<'a> = MarkdownViewProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        let offset = props.scroll_offset;
+        let page = (self.viewport.height as usize).max(1);
+
+        match event {
+            EventKind::Scroll { column, row, delta } if self.contains(*column, *row) => {
+                if *delta > 0 {
+                    self.scroll_to(&props, offset.saturating_add(WHEEL_STEP))
+                } else {
+                    self.scroll_to(&props, offset.saturating_sub(WHEEL_STEP))
+                }
+            }
+            EventKind::Key(key) if props.is_focused => match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.scroll_to(&props, offset + 1),
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.scroll_to(&props, offset.saturating_sub(1))
+                }
+                KeyCode::PageDown => self.scroll_to(&props, offset.saturating_add(page)),
+                KeyCode::PageUp => self.scroll_to(&props, offset.saturating_sub(page)),
+                KeyCode::Home => self.scroll_to(&props, 0),
+                KeyCode::End => self.scroll_to(&props, usize::MAX),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let style = props.style;
+        let inner = if props.show_border {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(props.theme.border_style(props.is_focused));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            inner
+        } else {
+            area
+        };
+        self.viewport = inner;
+
+        let lines = &props.document.lines;
+        let start = props.scroll_offset.min(lines.len());
+        let end = (start + inner.height as usize).min(lines.len());
+
+        let gutter_width = if props.line_numbers {
+            (lines.len().to_string().len() + 1) as u16
+        } else {
+            0
+        };
+        let content = Rect {
+            x: inner.x + gutter_width.min(inner.width),
+            width: inner.width.saturating_sub(gutter_width),
+            ..inner
+        };
+
+        for (i, rendered) in lines[start..end].iter().enumerate() {
+            let index = start + i;
+            let y = inner.y + i as u16;
+
+            if props.line_numbers {
+                let number = format!("{:>width$} ", index + 1, width = gutter_width as usize - 1);
+                frame.render_widget(
+                    Paragraph::new(Span::styled(number, style.muted)),
+                    Rect::new(inner.x, y, gutter_width.min(inner.width), 1),
+                );
+            }
+
+            let line_area = Rect::new(content.x, y, content.width, 1);
+            if rendered.is_code {
+                frame.render_widget(
+                    Block::default().style(Style::default().bg(style.code_bg)),
+                    line_area,
+                );
+            }
+
+            let match_bg = if props.current_match == Some(index) {
+                Some(style.current_match_bg)
+            } else if props.search_matches.contains(&index) {
+                Some(style.match_bg)
+            } else {
+                None
+            };
+            let line = match match_bg {
+                Some(bg) => Line::from(
+                    rendered
+                        .line
+                        .spans
+                        .iter()
+                        .map(|s| Span::styled(s.content.clone(), s.style.bg(bg)))
+                        .collect::<Vec<_>>(),
+                ),
+                None => rendered.line.clone(),
+            };
+
+            let mut paragraph = Paragraph::new(line);
+            if props.wrap && !rendered.is_code {
+                paragraph = paragraph.wrap(Wrap { trim: false });
+            }
+            frame.render_widget(paragraph, line_area);
+
+            // Language label in the top-right corner of code blocks
+            if let Some(lang) = &rendered.lang {
+                let label = format!(" {} ", lang);
+                let width = (label.width() as u16).min(line_area.width);
+                let label_area = Rect {
+                    x: line_area.right().saturating_sub(width + 1).max(line_area.x),
+                    width,
+                    ..line_area
+                };
+                frame.render_widget(
+                    Paragraph::new(Span::styled(label, style.code_label.bg(style.code_bg))),
+                    label_area,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Scroll(usize),
+    }
+
+    const SOURCE: &str = "# Title\n\nSome *text* with a [link](https://example.com).\n\n\
+        ```rust\nfn main() {}\n```\n\n| Name | Size |\n|------|------|\n| a | 1 |\n";
+
+    fn props<'a>(
+        document: &'a MarkdownDocument,
+        style: &'a MarkdownStyle,
+        scroll_offset: usize,
+    ) -> MarkdownViewProps<'a, TestAction> {
+        MarkdownViewProps {
+            document,
+            scroll_offset,
+            is_focused: true,
+            show_border: false,
+            line_numbers: false,
+            wrap: true,
+            search_matches: &[],
+            current_match: None,
+            style,
+            theme: &Theme::DARK,
+            on_scroll: Some(TestAction::Scroll),
+        }
+    }
+
+    #[test]
+    fn test_parse_styles_and_stats() {
+        let style = MarkdownStyle::default();
+        let doc = MarkdownDocument::parse(SOURCE, &style);
+
+        assert_eq!(doc.lines[0].plain(), "Title");
+        assert_eq!(doc.lines[0].line.spans[0].style, style.heading(1));
+
+        let link = doc.lines[2]
+            .line
+            .spans
+            .iter()
+            .find(|s| s.content == "link")
+            .unwrap();
+        assert!(link.style.add_modifier.contains(Modifier::UNDERLINED));
+
+        let code = doc.lines.iter().find(|l| l.is_code).unwrap();
+        assert_eq!(code.plain(), "  fn main() {}");
+        assert_eq!(code.lang.as_deref(), Some("rust"));
+
+        assert!(doc.lines.iter().any(|l| l.plain() == " Name │ Size "));
+        assert_eq!(doc.stats.heading_count, 1);
+        assert_eq!(doc.stats.link_count, 1);
+        assert_eq!(doc.stats.code_block_count, 1);
+        assert_eq!(doc.find("MAIN"), vec![4]);
+    }
+
+    #[test]
+    fn test_highlighter_hook() {
+        let style = MarkdownStyle::default();
+        let highlight = |code: &str, lang: &str| -> Vec<Line<'static>> {
+            code.lines()
+                .map(|l| Line::raw(format!("{lang}:{l}")))
+                .collect()
+        };
+        let doc = MarkdownDocument::parse_with_highlighter(SOURCE, &style, &highlight);
+
+        let code = doc.lines.iter().find(|l| l.is_code).unwrap();
+        assert_eq!(code.plain(), "  rust:fn main() {}");
+        assert_eq!(code.line.spans[1].style.bg, Some(style.code_bg));
+    }
+
+    #[test]
+    fn test_scroll_keys() {
+        let style = MarkdownStyle::default();
+        let doc = MarkdownDocument::parse(SOURCE, &style);
+        let mut render = RenderHarness::new(40, 4);
+        let mut view = MarkdownView::new();
+        render.render(|frame| view.render(frame, frame.area(), props(&doc, &style, 0)));
+
+        let mut press = |code: &str, offset: usize| -> Vec<TestAction> {
+            view.handle_event(&EventKind::Key(key(code)), props(&doc, &style, offset))
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(press("j", 0), vec![TestAction::Scroll(1)]);
+        assert_eq!(press("pagedown", 0), vec![TestAction::Scroll(4)]);
+        assert_eq!(press("end", 0), vec![TestAction::Scroll(doc.len() - 4)]);
+        assert!(press("k", 0).is_empty());
+    }
+
+    #[test]
+    fn test_render_search_match() {
+        let style = MarkdownStyle::default();
+        let doc = MarkdownDocument::parse(SOURCE, &style);
+        let matches = doc.find("text");
+        let mut render = RenderHarness::new(40, 6);
+        let mut view = MarkdownView::new();
+
+        let buffer = render.render(|frame| {
+            view.render(
+                frame,
+                frame.area(),
+                MarkdownViewProps {
+                    line_numbers: true,
+                    search_matches: &matches,
+                    current_match: matches.first().copied(),
+                    ..props(&doc, &style, 0)
+                },
+            )
+        });
+
+        // 10 lines need a 3-column gutter
+        assert_eq!(buffer[(1, 0)].symbol(), "1");
+        assert_eq!(buffer[(3, 0)].symbol(), "T");
+        assert_eq!(buffer[(3, 2)].bg, style.current_match_bg);
+        assert_eq!(buffer[(3, 1)].bg, Color::Reset);
+    }
+}
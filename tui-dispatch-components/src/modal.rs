@@ -1,17 +1,82 @@
 //! Modal overlay component with background dimming
 //!
 //! Dims the background on each frame (keeping animations live) and renders
-//! modal content on top.
+//! modal content on top, optionally inside a bordered title bar that matches
+//! the debug layer's modals.
 
-use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget, Frame};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Widget},
+    Frame,
+};
 use tui_dispatch_core::debug::dim_buffer;
+use tui_dispatch_core::Theme;
+
+/// Size preset for [`render_modal_sized`]
+///
+/// Sizes are relative to the bounds the modal is centered in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ModalSize {
+    /// 40% wide, 30% tall
+    Small,
+    /// 60% wide, 50% tall
+    #[default]
+    Medium,
+    /// 80% wide, 60% tall (the debug layer's table modal)
+    Large,
+    /// The whole bounds
+    Fullscreen,
+    /// Custom width and height percentages
+    Percent(u16, u16),
+}
+
+impl ModalSize {
+    /// Width and height percentages for this preset
+    pub fn percents(self) -> (u16, u16) {
+        match self {
+            Self::Small => (40, 30),
+            Self::Medium => (60, 50),
+            Self::Large => (80, 60),
+            Self::Fullscreen => (100, 100),
+            Self::Percent(width, height) => (width.min(100), height.min(100)),
+        }
+    }
+
+    /// Centered area for this preset within `bounds`
+    pub fn area(self, bounds: Rect) -> Rect {
+        let (width_pct, height_pct) = self.percents();
+        let width = (bounds.width as u32 * width_pct as u32 / 100) as u16;
+        let height = (bounds.height as u32 * height_pct as u32 / 100) as u16;
+        let x = bounds.x + (bounds.width - width) / 2;
+        let y = bounds.y + (bounds.height - height) / 2;
+        Rect::new(x, y, width, height)
+    }
+}
 
 /// Configuration for modal appearance
+#[derive(Clone, Debug)]
 pub struct ModalStyle {
     /// Dim factor for background (0.0 = no dim, 1.0 = black)
     pub dim_factor: f32,
     /// Background color for the modal area (None = transparent/cleared)
     pub bg_color: Option<Color>,
+    /// Size preset used by [`render_modal_sized`]
+    pub size: ModalSize,
+    /// Title shown in the top border (a border is drawn when set)
+    pub title: Option<String>,
+    /// Hint shown at the right of the top border, e.g. "Esc close"
+    /// (a border is drawn when set)
+    pub close_hint: Option<String>,
+    /// Style for the border, title and close hint
+    pub border_style: Style,
+    /// Open animation progress (0.0 = closed, 1.0 = fully open)
+    ///
+    /// Scales the dim factor and grows the modal from its center, e.g. from
+    /// an [`Animations`](tui_dispatch_core::Animations) tween value.
+    pub open_progress: f32,
 }
 
 impl Default for ModalStyle {
@@ -19,6 +84,11 @@ impl Default for ModalStyle {
         Self {
             dim_factor: 0.5,
             bg_color: None,
+            size: ModalSize::default(),
+            title: None,
+            close_hint: None,
+            border_style: Style::default(),
+            open_progress: 1.0,
         }
     }
 }
@@ -31,15 +101,62 @@ impl ModalStyle {
             ..Default::default()
         }
     }
+
+    /// Create a style using the theme's surface color as background
+    /// and its border color for the title bar
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            border_style: Style::default().fg(theme.border),
+            ..Self::with_bg(theme.surface)
+        }
+    }
+
+    /// Set the size preset
+    pub fn size(mut self, size: ModalSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the title bar text
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the close hint shown at the right of the title bar
+    pub fn close_hint(mut self, hint: impl Into<String>) -> Self {
+        self.close_hint = Some(hint.into());
+        self
+    }
+
+    /// Set the background dim factor
+    pub fn dim(mut self, factor: f32) -> Self {
+        self.dim_factor = factor;
+        self
+    }
+
+    /// Set the open animation progress
+    pub fn open_progress(mut self, progress: f32) -> Self {
+        self.open_progress = progress;
+        self
+    }
+
+    fn has_border(&self) -> bool {
+        self.title.is_some() || self.close_hint.is_some()
+    }
 }
 
 /// Render a modal overlay with dimmed background
 ///
 /// Call this AFTER rendering background content. It dims the current buffer
-/// and fills the modal area with the background color.
+/// and fills the modal area with the background color. When the style has a
+/// title or close hint, the area is bordered like the debug layer's modals.
 ///
 /// The background continues to update/animate - it's dimmed fresh each frame.
 ///
+/// Returns the area for modal content: inside the border if one is drawn,
+/// and shrunk while [`ModalStyle::open_progress`] is below 1.0.
+///
 /// # Example
 ///
 /// ```ignore
@@ -49,18 +166,75 @@ impl ModalStyle {
 /// // Then render modal on top (if open)
 /// if state.show_dialog {
 ///     let modal_area = centered_rect(60, 12, frame.area());
-///     render_modal(frame, modal_area, &ModalStyle::with_bg(Color::Rgb(30, 30, 40)));
-///     // Render modal content in modal_area
+///     let content = render_modal(frame, modal_area, &ModalStyle::with_bg(Color::Rgb(30, 30, 40)));
+///     // Render modal content in content
 /// }
 /// ```
-pub fn render_modal(frame: &mut Frame, area: Rect, style: &ModalStyle) {
+pub fn render_modal(frame: &mut Frame, area: Rect, style: &ModalStyle) -> Rect {
+    let progress = style.open_progress.clamp(0.0, 1.0);
+
     // Dim the background (everything rendered so far)
-    dim_buffer(frame.buffer_mut(), style.dim_factor);
+    dim_buffer(frame.buffer_mut(), style.dim_factor * progress);
+
+    let area = grow_from_center(area, progress);
 
     // Fill modal area with background color
     if let Some(bg) = style.bg_color {
         frame.render_widget(BgFill(bg), area);
     }
+
+    if !style.has_border() {
+        return area;
+    }
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(style.border_style);
+    if let Some(bg) = style.bg_color {
+        block = block.style(Style::default().bg(bg));
+    }
+    if let Some(ref title) = style.title {
+        block = block.title(Line::styled(format!(" {title} "), style.border_style));
+    }
+    if let Some(ref hint) = style.close_hint {
+        block = block.title(Line::styled(format!(" {hint} "), style.border_style).right_aligned());
+    }
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    inner
+}
+
+/// Render a modal sized by [`ModalStyle::size`], centered in `bounds`
+///
+/// Returns the area for modal content, like [`render_modal`].
+///
+/// # Example
+///
+/// ```ignore
+/// let style = ModalStyle::from_theme(&theme)
+///     .size(ModalSize::Small)
+///     .title("Delete file?")
+///     .close_hint("Esc close");
+/// let content = render_modal_sized(frame, frame.area(), &style);
+/// frame.render_widget(Paragraph::new("This cannot be undone."), content);
+/// ```
+pub fn render_modal_sized(frame: &mut Frame, bounds: Rect, style: &ModalStyle) -> Rect {
+    render_modal(frame, style.size.area(bounds), style)
+}
+
+/// Shrink `area` around its center by `progress` (0.0 - 1.0)
+fn grow_from_center(area: Rect, progress: f32) -> Rect {
+    if progress >= 1.0 {
+        return area;
+    }
+    let width = (area.width as f32 * progress).round() as u16;
+    let height = (area.height as f32 * progress).round() as u16;
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
 }
 
 /// Simple widget that fills an area with a background color
@@ -109,6 +283,81 @@ mod tests {
         assert!(output.contains("Modal content"));
     }
 
+    #[test]
+    fn test_modal_uses_theme_surface() {
+        let mut harness = RenderHarness::new(20, 10);
+        let area = Rect::new(5, 3, 10, 4);
+        let buffer = harness.render(|frame| {
+            render_modal(frame, area, &ModalStyle::from_theme(&Theme::LIGHT));
+        });
+        assert_eq!(buffer[(6, 4)].bg, Theme::LIGHT.surface);
+        assert_ne!(buffer[(0, 0)].bg, Theme::LIGHT.surface);
+    }
+
+    #[test]
+    fn test_modal_size_presets() {
+        let bounds = Rect::new(0, 0, 100, 40);
+
+        assert_eq!(ModalSize::Small.area(bounds), Rect::new(30, 14, 40, 12));
+        assert_eq!(ModalSize::Medium.area(bounds), Rect::new(20, 10, 60, 20));
+        assert_eq!(ModalSize::Large.area(bounds), Rect::new(10, 8, 80, 24));
+        assert_eq!(ModalSize::Fullscreen.area(bounds), bounds);
+        assert_eq!(
+            ModalSize::Percent(50, 150).area(bounds),
+            Rect::new(25, 0, 50, 40)
+        );
+    }
+
+    #[test]
+    fn test_modal_title_bar_and_close_hint() {
+        let mut harness = RenderHarness::new(40, 12);
+        let style = ModalStyle::default()
+            .size(ModalSize::Fullscreen)
+            .title("Settings")
+            .close_hint("Esc close");
+
+        let mut content = Rect::default();
+        let output = harness.render_to_string_plain(|frame| {
+            content = render_modal_sized(frame, frame.area(), &style);
+        });
+
+        let first_line = output.lines().next().unwrap();
+        assert!(first_line.starts_with("┌ Settings "));
+        assert!(first_line.ends_with(" Esc close ┐"));
+        assert_eq!(content, Rect::new(1, 1, 38, 10));
+    }
+
+    #[test]
+    fn test_modal_without_title_has_no_border() {
+        let mut harness = RenderHarness::new(20, 10);
+        let area = Rect::new(5, 3, 10, 4);
+        let mut content = Rect::default();
+        harness.render(|frame| {
+            content = render_modal(frame, area, &ModalStyle::default());
+        });
+        assert_eq!(content, area);
+    }
+
+    #[test]
+    fn test_modal_open_progress_scales_area_and_dim() {
+        let mut harness = RenderHarness::new(20, 10);
+        let area = Rect::new(0, 0, 20, 10);
+        let bg = Color::Rgb(200, 200, 200);
+        let mut content = Rect::default();
+        let buffer = harness.render(|frame| {
+            frame.buffer_mut()[(0, 0)].set_bg(bg);
+            let style = ModalStyle::default().dim(0.8).open_progress(0.0);
+            content = render_modal(frame, area, &style);
+        });
+
+        // Closed: nothing dimmed and no modal area yet
+        assert_eq!(buffer[(0, 0)].bg, bg);
+        assert_eq!(content.area(), 0);
+
+        let content = grow_from_center(area, 0.5);
+        assert_eq!(content, Rect::new(5, 2, 10, 5));
+    }
+
     #[test]
     fn test_centered_rect() {
         let area = Rect::new(0, 0, 80, 24);
@@ -0,0 +1,419 @@
+//! Numeric input with stepper keys and range validation
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, Theme};
+use unicode_width::UnicodeWidthStr;
+
+/// Number types a [`NumberInput`] can edit (`i64` and `f64`)
+pub trait NumberValue: Copy + PartialOrd + Display + FromStr {
+    /// Whether a decimal point can be typed
+    const FRACTIONAL: bool;
+
+    /// `self + step`, saturating for integers
+    fn step_up(self, step: Self) -> Self;
+
+    /// `self - step`, saturating for integers
+    fn step_down(self, step: Self) -> Self;
+}
+
+impl NumberValue for i64 {
+    const FRACTIONAL: bool = false;
+
+    fn step_up(self, step: Self) -> Self {
+        self.saturating_add(step)
+    }
+
+    fn step_down(self, step: Self) -> Self {
+        self.saturating_sub(step)
+    }
+}
+
+impl NumberValue for f64 {
+    const FRACTIONAL: bool = true;
+
+    fn step_up(self, step: Self) -> Self {
+        self + step
+    }
+
+    fn step_down(self, step: Self) -> Self {
+        self - step
+    }
+}
+
+/// Props for NumberInput component
+pub struct NumberInputProps<'a, A, N = f64> {
+    /// Current value
+    pub value: N,
+    /// Smallest accepted value (None = unbounded)
+    pub min: Option<N>,
+    /// Largest accepted value (None = unbounded)
+    pub max: Option<N>,
+    /// Amount added or removed per step
+    pub step: N,
+    /// Digits after the decimal point when displaying floats (None = as is)
+    pub precision: Option<usize>,
+    /// Unit shown after the value, e.g. "ms" or "%"
+    pub unit: Option<&'a str>,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Colors for text, unit, border and validation errors
+    pub theme: &'a Theme,
+    /// Callback with the new value
+    pub on_change: fn(N) -> A,
+}
+
+impl<A, N: NumberValue> NumberInputProps<'_, A, N> {
+    fn in_range(&self, value: N) -> bool {
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+
+    fn clamp(&self, mut value: N) -> N {
+        if let Some(min) = self.min.filter(|&min| value < min) {
+            value = min;
+        }
+        if let Some(max) = self.max.filter(|&max| value > max) {
+            value = max;
+        }
+        value
+    }
+
+    fn format(&self, value: N) -> String {
+        match self.precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None => value.to_string(),
+        }
+    }
+}
+
+/// A number field stepped with Up/Down or +/- and edited by typing
+///
+/// Steps are clamped to `min`/`max` and reported through `on_change`
+/// right away; Home/End jump to the bounds. Typing digits starts a draft
+/// that replaces the value on Enter (Esc discards it). A draft that does
+/// not parse or is out of range is drawn in the theme's error color and
+/// not committed. `-` starts a negative draft when typed first, and steps
+/// down otherwise.
+///
+/// The value lives in app state; the component only holds the draft,
+/// which is dropped when focus leaves.
+pub struct NumberInput<N = f64> {
+    /// Text typed since the last commit
+    draft: Option<String>,
+    _value: PhantomData<N>,
+}
+
+impl<N> Default for NumberInput<N> {
+    fn default() -> Self {
+        Self {
+            draft: None,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<N: NumberValue> NumberInput<N> {
+    /// Create a new NumberInput
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a typed value is waiting to be committed
+    pub fn is_editing(&self) -> bool {
+        self.draft.is_some()
+    }
+
+    /// Step from the draft (if it parses) or the current value
+    fn step<A>(&mut self, props: &NumberInputProps<'_, A, N>, up: bool) -> Option<A> {
+        let base = self
+            .draft
+            .take()
+            .and_then(|draft| draft.parse().ok())
+            .unwrap_or(props.value);
+        let value = if up {
+            base.step_up(props.step)
+        } else {
+            base.step_down(props.step)
+        };
+        Some((props.on_change)(props.clamp(value)))
+    }
+
+    fn commit<A>(&mut self, props: &NumberInputProps<'_, A, N>) -> Option<A> {
+        let value: N = self.draft.as_ref()?.parse().ok()?;
+        if !props.in_range(value) {
+            return None;
+        }
+        self.draft = None;
+        Some((props.on_change)(value))
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        let draft = self.draft.as_deref().unwrap_or_default();
+        c.is_ascii_digit() || (N::FRACTIONAL && c == '.' && !draft.contains('.'))
+    }
+}
+
+impl<A, N: NumberValue> Component<A> for NumberInput<N> {
+    type Props<'a> = NumberInputProps<'a, A, N>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        if !props.is_focused {
+            return None;
+        }
+        let EventKind::Key(key) = event else {
+            return None;
+        };
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('+') => self.step(&props, true),
+            KeyCode::Char('-') if self.draft.as_deref() == Some("") => {
+                self.draft = Some("-".to_string());
+                None
+            }
+            KeyCode::Down | KeyCode::Char('-') => self.step(&props, false),
+            KeyCode::Home => {
+                self.draft = None;
+                props.min.map(props.on_change)
+            }
+            KeyCode::End => {
+                self.draft = None;
+                props.max.map(props.on_change)
+            }
+            KeyCode::Char(c) if self.accepts(c) => {
+                self.draft.get_or_insert_with(String::new).push(c);
+                None
+            }
+            KeyCode::Backspace => {
+                let draft = self.draft.get_or_insert_with(|| props.format(props.value));
+                draft.pop();
+                None
+            }
+            KeyCode::Enter => self.commit(&props),
+            KeyCode::Esc => {
+                self.draft = None;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        if !props.is_focused {
+            self.draft = None;
+        }
+
+        let (text, valid) = match &self.draft {
+            Some(draft) => {
+                let valid = draft
+                    .parse::<N>()
+                    .ok()
+                    .is_some_and(|value| props.in_range(value));
+                (draft.clone(), valid)
+            }
+            None => (props.format(props.value), props.in_range(props.value)),
+        };
+        let error = Style::default().fg(props.theme.error);
+
+        let mut spans = vec![Span::styled(
+            text.clone(),
+            if valid { props.theme.text() } else { error },
+        )];
+        if let Some(unit) = props.unit {
+            spans.push(Span::styled(format!(" {unit}"), props.theme.muted()));
+        }
+
+        let mut paragraph = Paragraph::new(Line::from(spans));
+        let border_offset = if props.show_border { 1 } else { 0 };
+        if props.show_border {
+            let border_style = if valid {
+                props.theme.border_style(props.is_focused)
+            } else {
+                error
+            };
+            paragraph = paragraph.block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(border_style),
+            );
+        }
+        frame.render_widget(paragraph, area);
+
+        // Show the cursor after the draft while editing
+        if props.is_focused && self.draft.is_some() {
+            let text_width = area.width.saturating_sub(border_offset * 2);
+            let cursor_col = text.width() as u16;
+            if cursor_col < text_width {
+                frame.set_cursor_position((
+                    area.x + border_offset + cursor_col,
+                    area.y + border_offset,
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::{char_key, key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Int(i64),
+        Float(f64),
+    }
+
+    fn int_props(value: i64) -> NumberInputProps<'static, TestAction, i64> {
+        NumberInputProps {
+            value,
+            min: Some(0),
+            max: Some(10),
+            step: 2,
+            precision: None,
+            unit: None,
+            is_focused: true,
+            show_border: false,
+            theme: &Theme::DARK,
+            on_change: TestAction::Int,
+        }
+    }
+
+    fn press<N: NumberValue>(
+        input: &mut NumberInput<N>,
+        event: crossterm::event::KeyEvent,
+        props: NumberInputProps<'static, TestAction, N>,
+    ) -> Vec<TestAction> {
+        input
+            .handle_event(&EventKind::Key(event), props)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_step_clamps_to_range() {
+        let mut input = NumberInput::new();
+
+        assert_eq!(
+            press(&mut input, key("up"), int_props(4)),
+            vec![TestAction::Int(6)]
+        );
+        assert_eq!(
+            press(&mut input, char_key('-'), int_props(4)),
+            vec![TestAction::Int(2)]
+        );
+        assert_eq!(
+            press(&mut input, char_key('+'), int_props(9)),
+            vec![TestAction::Int(10)]
+        );
+        assert_eq!(
+            press(&mut input, key("down"), int_props(1)),
+            vec![TestAction::Int(0)]
+        );
+        assert_eq!(
+            press(&mut input, key("end"), int_props(1)),
+            vec![TestAction::Int(10)]
+        );
+    }
+
+    #[test]
+    fn test_typed_value_commits_on_enter() {
+        let mut input = NumberInput::new();
+
+        assert!(press(&mut input, char_key('7'), int_props(4)).is_empty());
+        assert!(input.is_editing());
+        // Integers reject a decimal point
+        assert!(press(&mut input, char_key('.'), int_props(4)).is_empty());
+        assert_eq!(input.draft.as_deref(), Some("7"));
+
+        assert_eq!(
+            press(&mut input, key("enter"), int_props(4)),
+            vec![TestAction::Int(7)]
+        );
+        assert!(!input.is_editing());
+
+        // Out of range drafts stay until fixed or discarded
+        press(&mut input, char_key('4'), int_props(4));
+        press(&mut input, char_key('2'), int_props(4));
+        assert!(press(&mut input, key("enter"), int_props(4)).is_empty());
+        assert!(input.is_editing());
+        press(&mut input, key("esc"), int_props(4));
+        assert!(!input.is_editing());
+    }
+
+    #[test]
+    fn test_float_draft_and_negative_sign() {
+        let props = || NumberInputProps {
+            value: 1.5,
+            min: None,
+            max: None,
+            step: 0.5,
+            precision: Some(2),
+            unit: Some("kg"),
+            is_focused: true,
+            show_border: false,
+            theme: &Theme::DARK,
+            on_change: TestAction::Float,
+        };
+        let mut input = NumberInput::new();
+
+        // Backspace edits the formatted value
+        press(&mut input, key("backspace"), props());
+        assert_eq!(input.draft.as_deref(), Some("1.5"));
+        for _ in 0..3 {
+            press(&mut input, key("backspace"), props());
+        }
+        press(&mut input, char_key('-'), props());
+        press(&mut input, char_key('2'), props());
+        press(&mut input, char_key('.'), props());
+        press(&mut input, char_key('5'), props());
+        assert_eq!(
+            press(&mut input, key("enter"), props()),
+            vec![TestAction::Float(-2.5)]
+        );
+
+        // Stepping continues from the draft
+        press(&mut input, char_key('3'), props());
+        assert_eq!(
+            press(&mut input, key("up"), props()),
+            vec![TestAction::Float(3.5)]
+        );
+    }
+
+    #[test]
+    fn test_render_value_and_unit() {
+        let mut render = RenderHarness::new(20, 1);
+        let mut input = NumberInput::<i64>::new();
+
+        let output = render.render_to_string_plain(|frame| {
+            let props = NumberInputProps {
+                unit: Some("ms"),
+                ..int_props(8)
+            };
+            input.render(frame, frame.area(), props);
+        });
+
+        assert!(output.starts_with("8 ms"));
+    }
+}
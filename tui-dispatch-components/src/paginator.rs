@@ -0,0 +1,245 @@
+//! Page indicator for paged results
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, Strings, Theme};
+
+/// Position in a result set that is loaded one page at a time
+///
+/// For backends that page results (SQL `LIMIT`/`OFFSET`, Redis `SCAN`,
+/// paged REST APIs), the list only holds the current page and the app
+/// fetches another one when asked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageInfo {
+    /// Total number of items across all pages
+    pub total_count: usize,
+    /// Items per page
+    pub page_size: usize,
+    /// Zero-based index of the current page
+    pub current_page: usize,
+}
+
+impl PageInfo {
+    /// Create page info
+    pub fn new(total_count: usize, page_size: usize, current_page: usize) -> Self {
+        Self {
+            total_count,
+            page_size,
+            current_page,
+        }
+    }
+
+    /// Number of pages (at least 1)
+    pub fn page_count(&self) -> usize {
+        self.total_count.div_ceil(self.page_size.max(1)).max(1)
+    }
+
+    /// Whether a page follows the current one
+    pub fn has_next(&self) -> bool {
+        self.current_page + 1 < self.page_count()
+    }
+
+    /// Whether a page precedes the current one
+    pub fn has_prev(&self) -> bool {
+        self.current_page > 0
+    }
+
+    /// Index of the current page's first item in the whole result set
+    pub fn first_item(&self) -> usize {
+        self.current_page * self.page_size
+    }
+
+    /// Label such as "Page 3/12 (240 items)"
+    pub fn label(&self) -> String {
+        self.label_in(&Strings::ENGLISH)
+    }
+
+    /// [`label`](Self::label) with translated words
+    pub fn label_in(&self, strings: &Strings) -> String {
+        let noun = if self.total_count == 1 {
+            strings.item
+        } else {
+            strings.items
+        };
+        format!(
+            "{} {}/{} ({} {})",
+            strings.page,
+            self.current_page + 1,
+            self.page_count(),
+            self.total_count,
+            noun
+        )
+    }
+}
+
+/// Props for Paginator component
+pub struct PaginatorProps<'a, A> {
+    /// Current page
+    pub page: PageInfo,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Colors for the label and arrows
+    pub theme: &'a Theme,
+    /// Callback with the requested page index (None = display only)
+    pub on_page_change: Option<fn(usize) -> A>,
+}
+
+/// A one-line footer showing the current page, e.g. "‹ Page 3/12 (240 items) ›"
+///
+/// When focused, PageUp/PageDown and left/right request the previous or
+/// next page through `on_page_change`. The arrows dim on the first and last
+/// page.
+#[derive(Default)]
+pub struct Paginator;
+
+impl Paginator {
+    /// Create a new Paginator
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<A> Component<A> for Paginator {
+    type Props<'a> = PaginatorProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        let Some(on_page_change) = props.on_page_change.filter(|_| props.is_focused) else {
+            return None;
+        };
+        let page = props.page;
+
+        let target = match event {
+            EventKind::Key(key) => match key.code {
+                KeyCode::PageDown | KeyCode::Right if page.has_next() => page.current_page + 1,
+                KeyCode::PageUp | KeyCode::Left if page.has_prev() => page.current_page - 1,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(on_page_change(target))
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let page = props.page;
+        let arrow = |symbol: &'static str, enabled: bool| {
+            let style = if enabled {
+                props.theme.border_style(props.is_focused)
+            } else {
+                props.theme.muted()
+            };
+            Span::styled(symbol, style)
+        };
+
+        let line = Line::from(vec![
+            arrow("‹ ", page.has_prev()),
+            Span::styled(page.label_in(&props.theme.strings), props.theme.text()),
+            arrow(" ›", page.has_next()),
+        ]);
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Page(usize),
+    }
+
+    fn props(page: PageInfo) -> PaginatorProps<'static, TestAction> {
+        PaginatorProps {
+            page,
+            is_focused: true,
+            theme: &Theme::DARK,
+            on_page_change: Some(TestAction::Page),
+        }
+    }
+
+    #[test]
+    fn test_page_info() {
+        let page = PageInfo::new(240, 20, 2);
+        assert_eq!(page.page_count(), 12);
+        assert_eq!(page.first_item(), 40);
+        assert_eq!(page.label(), "Page 3/12 (240 items)");
+        assert!(page.has_prev() && page.has_next());
+
+        let empty = PageInfo::new(0, 20, 0);
+        assert_eq!(empty.page_count(), 1);
+        assert!(!empty.has_prev() && !empty.has_next());
+        assert_eq!(PageInfo::new(21, 20, 1).page_count(), 2);
+    }
+
+    #[test]
+    fn test_page_keys() {
+        let mut paginator = Paginator::new();
+        let mut press = |code: &str, page: PageInfo| -> Vec<TestAction> {
+            paginator
+                .handle_event(&EventKind::Key(key(code)), props(page))
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(
+            press("pagedown", PageInfo::new(50, 10, 1)),
+            vec![TestAction::Page(2)]
+        );
+        assert_eq!(
+            press("left", PageInfo::new(50, 10, 1)),
+            vec![TestAction::Page(0)]
+        );
+        // No page before the first or after the last
+        assert!(press("pageup", PageInfo::new(50, 10, 0)).is_empty());
+        assert!(press("right", PageInfo::new(50, 10, 4)).is_empty());
+    }
+
+    #[test]
+    fn test_render_label() {
+        let mut render = RenderHarness::new(30, 1);
+        let mut paginator = Paginator::new();
+
+        let output = render.render_to_string_plain(|frame| {
+            paginator.render(frame, frame.area(), props(PageInfo::new(240, 20, 2)));
+        });
+
+        assert!(output.starts_with("‹ Page 3/12 (240 items) ›"));
+    }
+    #[test]
+    fn test_render_translated_label() {
+        let mut render = RenderHarness::new(30, 1);
+        let mut paginator = Paginator::new();
+        let theme = Theme {
+            strings: Strings {
+                page: "Seite",
+                item: "Eintrag",
+                items: "Einträge",
+                ..Strings::ENGLISH
+            },
+            ..Theme::DARK
+        };
+
+        let output = render.render_to_string_plain(|frame| {
+            paginator.render(
+                frame,
+                frame.area(),
+                PaginatorProps {
+                    theme: &theme,
+                    ..props(PageInfo::new(1, 20, 0))
+                },
+            );
+        });
+
+        assert!(output.starts_with("‹ Seite 1/1 (1 Eintrag) ›"));
+    }
+}
@@ -0,0 +1,232 @@
+//! Progress bar component
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders},
+    Frame,
+};
+use tui_dispatch_core::Component;
+use unicode_width::UnicodeWidthStr;
+
+/// Glyphs used to draw a progress bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BarGlyphs {
+    /// Glyph for a fully filled cell
+    pub filled: &'static str,
+    /// Glyph for an empty cell
+    pub empty: &'static str,
+    /// Partially filled glyphs, from least to most filled (may be empty)
+    pub partials: &'static [&'static str],
+}
+
+impl BarGlyphs {
+    /// Smooth unicode blocks with 1/8 cell resolution
+    pub const BLOCKS: Self = Self {
+        filled: "█",
+        empty: " ",
+        partials: &["▏", "▎", "▍", "▌", "▋", "▊", "▉"],
+    };
+    /// Thin line, heavy where filled
+    pub const LINE: Self = Self {
+        filled: "━",
+        empty: "─",
+        partials: &[],
+    };
+    /// Plain ASCII
+    pub const ASCII: Self = Self {
+        filled: "#",
+        empty: "-",
+        partials: &[],
+    };
+}
+
+impl Default for BarGlyphs {
+    fn default() -> Self {
+        Self::BLOCKS
+    }
+}
+
+/// Configuration for progress bar appearance
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressBarStyle {
+    /// Glyph set
+    pub glyphs: BarGlyphs,
+    /// Style for the filled part
+    pub filled: Style,
+    /// Style for the empty part
+    pub empty: Style,
+    /// Style for the label (patched over the bar)
+    pub label: Style,
+}
+
+impl Default for ProgressBarStyle {
+    fn default() -> Self {
+        Self {
+            glyphs: BarGlyphs::default(),
+            filled: Style::default().fg(Color::Cyan),
+            empty: Style::default().fg(Color::DarkGray),
+            label: Style::default().fg(Color::White),
+        }
+    }
+}
+
+impl ProgressBarStyle {
+    /// Create a style with the given glyph set
+    pub fn with_glyphs(glyphs: BarGlyphs) -> Self {
+        Self {
+            glyphs,
+            ..Default::default()
+        }
+    }
+}
+
+/// Props for ProgressBar component
+pub struct ProgressBarProps<'a> {
+    /// Completion ratio (clamped to 0.0 - 1.0)
+    pub ratio: f64,
+    /// Optional label centered over the bar
+    pub label: Option<&'a str>,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Appearance
+    pub style: ProgressBarStyle,
+}
+
+/// A horizontal progress bar
+///
+/// Renders `ratio` as a filled bar across the full width, using partial
+/// glyphs for sub-cell precision when the glyph set provides them.
+#[derive(Default)]
+pub struct ProgressBar;
+
+impl ProgressBar {
+    /// Create a new ProgressBar
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Glyph for each cell of a bar `width` cells wide
+fn bar_cells(ratio: f64, width: u16, glyphs: &BarGlyphs) -> Vec<(&'static str, bool)> {
+    let levels = glyphs.partials.len() as u64 + 1;
+    let ratio = if ratio.is_finite() {
+        ratio.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let units = (ratio * (width as u64 * levels) as f64).round() as u64;
+    let full = (units / levels) as u16;
+    let remainder = (units % levels) as usize;
+
+    (0..width)
+        .map(|i| {
+            if i < full {
+                (glyphs.filled, true)
+            } else if i == full && remainder > 0 {
+                (glyphs.partials[remainder - 1], true)
+            } else {
+                (glyphs.empty, false)
+            }
+        })
+        .collect()
+}
+
+impl<A> Component<A> for ProgressBar {
+    type Props<'a> = ProgressBarProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let inner = if props.show_border {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let style = props.style;
+        let cells = bar_cells(props.ratio, inner.width, &style.glyphs);
+        let buf = frame.buffer_mut();
+
+        for y in inner.y..inner.y + inner.height {
+            for (i, (glyph, filled)) in cells.iter().enumerate() {
+                let cell_style = if *filled { style.filled } else { style.empty };
+                if let Some(cell) = buf.cell_mut((inner.x + i as u16, y)) {
+                    cell.set_symbol(glyph).set_style(cell_style);
+                }
+            }
+        }
+
+        if let Some(label) = props.label {
+            let label_width = (label.width() as u16).min(inner.width);
+            let x = inner.x + (inner.width - label_width) / 2;
+            let y = inner.y + inner.height / 2;
+            buf.set_stringn(x, y, label, label_width as usize, style.label);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::RenderHarness;
+
+    fn render(ratio: f64, label: Option<&str>, glyphs: BarGlyphs, width: u16) -> String {
+        let mut render = RenderHarness::new(width, 1);
+        let mut bar = ProgressBar::new();
+        render.render_to_string_plain(|frame| {
+            <ProgressBar as Component<()>>::render(
+                &mut bar,
+                frame,
+                frame.area(),
+                ProgressBarProps {
+                    ratio,
+                    label,
+                    show_border: false,
+                    style: ProgressBarStyle::with_glyphs(glyphs),
+                },
+            );
+        })
+    }
+
+    #[test]
+    fn test_ascii_fill() {
+        let output = render(0.5, None, BarGlyphs::ASCII, 10);
+        assert!(output.starts_with("#####-----"));
+    }
+
+    #[test]
+    fn test_partial_blocks() {
+        // 0.25 of 3 cells = 6/24 eighths -> "▊" in the first cell
+        let cells = bar_cells(0.25, 3, &BarGlyphs::BLOCKS);
+        assert_eq!(cells[0], ("▊", true));
+        assert_eq!(cells[1], (" ", false));
+
+        let cells = bar_cells(1.0, 3, &BarGlyphs::BLOCKS);
+        assert!(cells.iter().all(|(glyph, filled)| *glyph == "█" && *filled));
+    }
+
+    #[test]
+    fn test_ratio_clamped() {
+        assert!(render(-1.0, None, BarGlyphs::ASCII, 4).starts_with("----"));
+        assert!(render(2.0, None, BarGlyphs::ASCII, 4).starts_with("####"));
+        assert!(render(f64::NAN, None, BarGlyphs::ASCII, 4).starts_with("----"));
+    }
+
+    #[test]
+    fn test_label_centered() {
+        let output = render(0.0, Some("50%"), BarGlyphs::ASCII, 11);
+        assert!(output.starts_with("----50%----"));
+
+        // Wide glyphs are centered by display width, not char count
+        let output = render(0.0, Some("日本"), BarGlyphs::ASCII, 10);
+        assert!(output.starts_with("---日本"));
+    }
+}
@@ -0,0 +1,385 @@
+//! Scrollable viewport component
+
+use crossterm::event::{KeyCode, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind};
+
+/// Lines (or columns) moved per mouse wheel tick
+const WHEEL_STEP: u16 = 3;
+
+/// Scroll position of a [`ScrollView`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollOffset {
+    /// Horizontal offset (columns)
+    pub x: u16,
+    /// Vertical offset (rows)
+    pub y: u16,
+}
+
+impl ScrollOffset {
+    /// Create a new scroll offset
+    pub fn new(x: u16, y: u16) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Props for ScrollView component
+pub struct ScrollViewProps<'a, A> {
+    /// Full size of the content (width, height)
+    pub content_size: (u16, u16),
+    /// Current scroll offset (owned by app state)
+    pub offset: ScrollOffset,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Renders the full content into a buffer of `content_size`
+    pub render_content: &'a dyn Fn(&mut Buffer, Rect),
+    /// Callback to create action when the offset changes
+    pub on_scroll: fn(ScrollOffset) -> A,
+}
+
+/// A container that scrolls content larger than its area
+///
+/// Content is rendered off-screen at its full size and the visible part
+/// is copied into the frame. Handles arrows/hjkl, PageUp/PageDown and
+/// Home/End when focused, and the mouse wheel over the viewport.
+/// Scrollbars are drawn for each axis that overflows.
+#[derive(Default)]
+pub struct ScrollView {
+    /// Visible content area from the last render
+    viewport: Rect,
+}
+
+impl ScrollView {
+    /// Create a new ScrollView
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Largest valid offset for the last rendered viewport
+    fn max_offset(&self, content_size: (u16, u16)) -> ScrollOffset {
+        ScrollOffset {
+            x: content_size.0.saturating_sub(self.viewport.width),
+            y: content_size.1.saturating_sub(self.viewport.height),
+        }
+    }
+
+    /// Emit a scroll action if the clamped offset differs from the current one
+    fn scroll_to<A>(&self, props: &ScrollViewProps<'_, A>, x: i32, y: i32) -> Option<A> {
+        let max = self.max_offset(props.content_size);
+        let offset = ScrollOffset {
+            x: x.clamp(0, max.x as i32) as u16,
+            y: y.clamp(0, max.y as i32) as u16,
+        };
+        if offset != props.offset {
+            Some((props.on_scroll)(offset))
+        } else {
+            None
+        }
+    }
+
+    /// Emit a scroll action relative to the current offset
+    fn scroll_by<A>(&self, props: &ScrollViewProps<'_, A>, dx: i32, dy: i32) -> Option<A> {
+        self.scroll_to(
+            props,
+            props.offset.x as i32 + dx,
+            props.offset.y as i32 + dy,
+        )
+    }
+
+    fn contains(&self, column: u16, row: u16) -> bool {
+        let area = self.viewport;
+        column >= area.x
+            && column < area.x.saturating_add(area.width)
+            && row >= area.y
+            && row < area.y.saturating_add(area.height)
+    }
+}
+
+impl<A> Component<A> for ScrollView {
+    type Props<'a> = ScrollViewProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        let page = self.viewport.height.max(1) as i32;
+        let step = WHEEL_STEP as i32;
+
+        match event {
+            EventKind::Scroll { column, row, delta } if self.contains(*column, *row) => {
+                self.scroll_by(&props, 0, (*delta as i32).signum() * step)
+            }
+            EventKind::Mouse(mouse) if self.contains(mouse.column, mouse.row) => match mouse.kind {
+                MouseEventKind::ScrollLeft => self.scroll_by(&props, -step, 0),
+                MouseEventKind::ScrollRight => self.scroll_by(&props, step, 0),
+                _ => None,
+            },
+            EventKind::Key(key) if props.is_focused => match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.scroll_by(&props, 0, 1),
+                KeyCode::Char('k') | KeyCode::Up => self.scroll_by(&props, 0, -1),
+                KeyCode::Char('l') | KeyCode::Right => self.scroll_by(&props, 1, 0),
+                KeyCode::Char('h') | KeyCode::Left => self.scroll_by(&props, -1, 0),
+                KeyCode::PageDown => self.scroll_by(&props, 0, page),
+                KeyCode::PageUp => self.scroll_by(&props, 0, -page),
+                KeyCode::Home => self.scroll_to(&props, props.offset.x as i32, 0),
+                KeyCode::End => self.scroll_to(&props, props.offset.x as i32, i32::MAX),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let inner = if props.show_border {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(if props.is_focused {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                });
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            inner
+        } else {
+            area
+        };
+
+        let (content_width, content_height) = props.content_size;
+
+        // Reserve space for scrollbars on overflowing axes. A vertical bar
+        // narrows the viewport, which can in turn cause horizontal overflow.
+        let mut show_vertical = content_height > inner.height;
+        let show_horizontal = content_width > inner.width.saturating_sub(u16::from(show_vertical));
+        if !show_vertical && show_horizontal {
+            show_vertical = content_height > inner.height.saturating_sub(1);
+        }
+
+        self.viewport = Rect {
+            x: inner.x,
+            y: inner.y,
+            width: inner.width.saturating_sub(u16::from(show_vertical)),
+            height: inner.height.saturating_sub(u16::from(show_horizontal)),
+        };
+
+        if self.viewport.width == 0 || self.viewport.height == 0 {
+            return;
+        }
+
+        // Render the full content off-screen, then copy the visible window
+        let content_area = Rect::new(0, 0, content_width, content_height);
+        let mut content = Buffer::empty(content_area);
+        (props.render_content)(&mut content, content_area);
+
+        let max = self.max_offset(props.content_size);
+        let offset_x = props.offset.x.min(max.x);
+        let offset_y = props.offset.y.min(max.y);
+
+        let buf = frame.buffer_mut();
+        for row in 0..self.viewport.height.min(content_height) {
+            for col in 0..self.viewport.width.min(content_width) {
+                let src = content.cell((offset_x + col, offset_y + row));
+                let dst = buf.cell_mut((self.viewport.x + col, self.viewport.y + row));
+                if let (Some(src), Some(dst)) = (src, dst) {
+                    *dst = src.clone();
+                }
+            }
+        }
+
+        if show_vertical {
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some("│"))
+                .thumb_symbol("█");
+            let mut state = ScrollbarState::new(max.y as usize + 1)
+                .position(offset_y as usize)
+                .viewport_content_length(self.viewport.height as usize);
+            let bar_area = Rect {
+                height: self.viewport.height,
+                ..inner
+            };
+            frame.render_stateful_widget(scrollbar, bar_area, &mut state);
+        }
+
+        if show_horizontal {
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some("─"))
+                .thumb_symbol("█");
+            let mut state = ScrollbarState::new(max.x as usize + 1)
+                .position(offset_x as usize)
+                .viewport_content_length(self.viewport.width as usize);
+            let bar_area = Rect {
+                width: self.viewport.width,
+                ..inner
+            };
+            frame.render_stateful_widget(scrollbar, bar_area, &mut state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::{Paragraph, Widget};
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Scroll(ScrollOffset),
+    }
+
+    fn render_lines(buf: &mut Buffer, area: Rect) {
+        let text: Vec<_> = (0..area.height)
+            .map(|i| format!("line {i:02} {}", "-".repeat(30)))
+            .map(ratatui::text::Line::from)
+            .collect();
+        Paragraph::new(text).render(area, buf);
+    }
+
+    fn props(offset: ScrollOffset) -> ScrollViewProps<'static, TestAction> {
+        ScrollViewProps {
+            content_size: (40, 50),
+            offset,
+            is_focused: true,
+            show_border: false,
+            render_content: &render_lines,
+            on_scroll: TestAction::Scroll,
+        }
+    }
+
+    /// Render once so the viewport is known (20x10, both scrollbars shown)
+    fn rendered_view(offset: ScrollOffset) -> ScrollView {
+        let mut render = RenderHarness::new(20, 10);
+        let mut view = ScrollView::new();
+        render.render(|frame| {
+            view.render(frame, frame.area(), props(offset));
+        });
+        view
+    }
+
+    fn events(view: &mut ScrollView, event: EventKind, offset: ScrollOffset) -> Vec<TestAction> {
+        view.handle_event(&event, props(offset))
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_arrow_keys_scroll() {
+        let mut view = rendered_view(ScrollOffset::default());
+
+        let actions = events(&mut view, EventKind::Key(key("j")), ScrollOffset::default());
+        assert_eq!(actions, vec![TestAction::Scroll(ScrollOffset::new(0, 1))]);
+
+        let actions = events(
+            &mut view,
+            EventKind::Key(key("right")),
+            ScrollOffset::default(),
+        );
+        assert_eq!(actions, vec![TestAction::Scroll(ScrollOffset::new(1, 0))]);
+
+        // Already at the top
+        let actions = events(
+            &mut view,
+            EventKind::Key(key("up")),
+            ScrollOffset::default(),
+        );
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_page_and_home_end() {
+        let mut view = rendered_view(ScrollOffset::default());
+        // Viewport is 19x9 (one row/column taken by scrollbars)
+        let actions = events(
+            &mut view,
+            EventKind::Key(key("pagedown")),
+            ScrollOffset::default(),
+        );
+        assert_eq!(actions, vec![TestAction::Scroll(ScrollOffset::new(0, 9))]);
+
+        let actions = events(
+            &mut view,
+            EventKind::Key(key("end")),
+            ScrollOffset::new(2, 0),
+        );
+        assert_eq!(actions, vec![TestAction::Scroll(ScrollOffset::new(2, 41))]);
+
+        let actions = events(
+            &mut view,
+            EventKind::Key(key("home")),
+            ScrollOffset::new(2, 20),
+        );
+        assert_eq!(actions, vec![TestAction::Scroll(ScrollOffset::new(2, 0))]);
+
+        let actions = events(
+            &mut view,
+            EventKind::Key(key("pageup")),
+            ScrollOffset::new(0, 4),
+        );
+        assert_eq!(actions, vec![TestAction::Scroll(ScrollOffset::new(0, 0))]);
+    }
+
+    #[test]
+    fn test_mouse_wheel() {
+        let mut view = rendered_view(ScrollOffset::default());
+
+        let wheel_down = EventKind::Scroll {
+            column: 5,
+            row: 5,
+            delta: 1,
+        };
+        let actions = events(&mut view, wheel_down, ScrollOffset::default());
+        assert_eq!(
+            actions,
+            vec![TestAction::Scroll(ScrollOffset::new(0, WHEEL_STEP))]
+        );
+
+        // Outside the viewport is ignored
+        let outside = EventKind::Scroll {
+            column: 30,
+            row: 30,
+            delta: 1,
+        };
+        assert!(events(&mut view, outside, ScrollOffset::default()).is_empty());
+    }
+
+    #[test]
+    fn test_unfocused_ignores_keys() {
+        let mut view = rendered_view(ScrollOffset::default());
+        let mut p = props(ScrollOffset::default());
+        p.is_focused = false;
+
+        let actions: Vec<_> = view
+            .handle_event(&EventKind::Key(key("j")), p)
+            .into_iter()
+            .collect();
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_render_offset() {
+        let mut render = RenderHarness::new(20, 10);
+        let mut view = ScrollView::new();
+
+        let output = render.render_to_string_plain(|frame| {
+            view.render(frame, frame.area(), props(ScrollOffset::new(0, 5)));
+        });
+
+        assert!(output.starts_with("line 05"));
+        assert!(!output.contains("line 04"));
+        assert!(output.contains("line 13"));
+        assert!(!output.contains("line 14"));
+    }
+}
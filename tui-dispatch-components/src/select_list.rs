@@ -1,6 +1,9 @@
 //! Scrollable selection list component
 
-use crossterm::event::KeyCode;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -10,12 +13,333 @@ use ratatui::{
     },
     Frame,
 };
-use tui_dispatch_core::{Component, EventKind};
+use tui_dispatch_core::debug::ScrollbarStyle;
+use tui_dispatch_core::{Component, EventKind, Theme};
+
+use crate::bindings::{ComponentBindings, ComponentCommand};
+use crate::command_palette::fuzzy_match_case;
+use crate::PageInfo;
+
+/// Scrollbar configuration for SelectList
+///
+/// Symbols and styles use the same [`ScrollbarStyle`] as the debug layer.
+/// Symbols left as `None` keep the list's default look: a `█` thumb on a
+/// `│` track without begin/end arrows.
+#[derive(Debug, Clone)]
+pub struct ScrollbarConfig {
+    /// Symbols and styles for the scrollbar
+    pub style: ScrollbarStyle,
+    /// Hide the scrollbar when all items fit in the viewport
+    pub auto_hide: bool,
+    /// Show a "selected/total" indicator (e.g. "3/120") in the bottom border
+    pub show_position: bool,
+}
+
+impl Default for ScrollbarConfig {
+    fn default() -> Self {
+        Self {
+            style: ScrollbarStyle::default(),
+            auto_hide: true,
+            show_position: false,
+        }
+    }
+}
+
+impl ScrollbarConfig {
+    /// Create a config with the given scrollbar style
+    pub fn with_style(style: ScrollbarStyle) -> Self {
+        Self {
+            style,
+            ..Default::default()
+        }
+    }
+
+    /// Build the scrollbar widget
+    fn build(&self) -> Scrollbar<'static> {
+        let style = &self.style;
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(style.begin_symbol)
+            .end_symbol(style.end_symbol)
+            .track_symbol(Some(style.track_symbol.unwrap_or("│")))
+            .thumb_symbol(style.thumb_symbol.unwrap_or("█"))
+            .thumb_style(style.thumb)
+            .track_style(style.track)
+            .begin_style(style.begin)
+            .end_style(style.end)
+    }
+}
+
+/// How SelectList finds the parts of items to highlight
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Every occurrence of the query
+    #[default]
+    Substring,
+    /// Query characters in order, as ranked by [`fuzzy_match`](crate::fuzzy_match);
+    /// each matched character is highlighted
+    Fuzzy,
+    /// Every match of the query as a regular expression (`regex` feature);
+    /// an invalid pattern highlights nothing
+    #[cfg(feature = "regex")]
+    Regex,
+}
+
+/// Query highlighting options for SelectList
+///
+/// The default highlights case-insensitive substring matches in bold, using
+/// the theme's warning color.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HighlightConfig {
+    /// How the query is matched
+    pub mode: MatchMode,
+    /// Match case exactly (default: false)
+    pub case_sensitive: bool,
+    /// Style patched onto matched text (None = bold in the theme's warning color)
+    pub style: Option<Style>,
+}
+
+impl HighlightConfig {
+    /// Create a config with the given match mode
+    pub fn with_mode(mode: MatchMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// Highlight fuzzy matches, as the command palette does
+    pub fn fuzzy() -> Self {
+        Self::with_mode(MatchMode::Fuzzy)
+    }
+
+    /// Style for matched text
+    fn highlight_style(&self, theme: &Theme) -> Style {
+        self.style.unwrap_or_else(|| {
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD)
+        })
+    }
+}
+
+/// A query prepared for matching, built once per render
+enum Matcher<'q> {
+    Substring {
+        query: &'q str,
+        case_sensitive: bool,
+    },
+    Fuzzy {
+        query: &'q str,
+        case_sensitive: bool,
+    },
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl<'q> Matcher<'q> {
+    /// Prepare `query`; `None` if nothing can match
+    fn new(query: &'q str, config: &HighlightConfig) -> Option<Self> {
+        if query.is_empty() {
+            return None;
+        }
+        let case_sensitive = config.case_sensitive;
+        match config.mode {
+            MatchMode::Substring => Some(Self::Substring {
+                query,
+                case_sensitive,
+            }),
+            MatchMode::Fuzzy => Some(Self::Fuzzy {
+                query,
+                case_sensitive,
+            }),
+            #[cfg(feature = "regex")]
+            MatchMode::Regex => regex::RegexBuilder::new(query)
+                .case_insensitive(!case_sensitive)
+                .build()
+                .ok()
+                .map(Self::Regex),
+        }
+    }
+
+    /// Sorted, non-overlapping byte ranges of matches in `text`
+    fn ranges(&self, text: &str) -> Vec<(usize, usize)> {
+        match self {
+            Self::Substring {
+                query,
+                case_sensitive: true,
+            } => substring_ranges(text, query),
+            Self::Substring {
+                query,
+                case_sensitive: false,
+            } => {
+                let text_lower = text.to_lowercase();
+                // Lowercasing can change byte lengths; offsets would no longer line up
+                if text_lower.len() != text.len() {
+                    return Vec::new();
+                }
+                substring_ranges(&text_lower, &query.to_lowercase())
+            }
+            Self::Fuzzy {
+                query,
+                case_sensitive,
+            } => {
+                let Some(fuzzy) = fuzzy_match_case(query, text, *case_sensitive) else {
+                    return Vec::new();
+                };
+                let chars: Vec<(usize, char)> = text.char_indices().collect();
+                let mut ranges: Vec<(usize, usize)> = Vec::new();
+                for index in fuzzy.indices {
+                    let (start, c) = chars[index];
+                    let end = start + c.len_utf8();
+                    // Merge runs of consecutive characters into one range
+                    match ranges.last_mut() {
+                        Some(last) if last.1 == start => last.1 = end,
+                        _ => ranges.push((start, end)),
+                    }
+                }
+                ranges
+            }
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex
+                .find_iter(text)
+                .filter(|found| !found.is_empty())
+                .map(|found| (found.start(), found.end()))
+                .collect(),
+        }
+    }
+}
+
+/// Byte ranges of every occurrence of `query` in `text`
+fn substring_ranges(text: &str, query: &str) -> Vec<(usize, usize)> {
+    text.match_indices(query)
+        .map(|(start, matched)| (start, start + matched.len()))
+        .collect()
+}
+
+/// An item that SelectList can display
+///
+/// Implemented for plain strings and for ratatui [`Line`]s and [`Span`]s, so
+/// items can carry colors, icons and multi-span formatting. Query
+/// highlighting matches against the plain text and keeps each span's style.
+pub trait SelectItem {
+    /// The line to render for this item
+    fn line(&self) -> Line<'_>;
+}
+
+impl SelectItem for String {
+    fn line(&self) -> Line<'_> {
+        Line::raw(self.as_str())
+    }
+}
+
+impl SelectItem for &str {
+    fn line(&self) -> Line<'_> {
+        Line::raw(*self)
+    }
+}
+
+impl SelectItem for Line<'_> {
+    fn line(&self) -> Line<'_> {
+        let spans = self
+            .spans
+            .iter()
+            .map(|span| Span::styled(span.content.as_ref(), span.style))
+            .collect::<Vec<_>>();
+        Line::from(spans).style(self.style)
+    }
+}
+
+impl SelectItem for Span<'_> {
+    fn line(&self) -> Line<'_> {
+        Line::from(Span::styled(self.content.as_ref(), self.style))
+    }
+}
+
+/// A section of consecutive items shown under a header
+///
+/// Groups take items in order: the first group covers `items[..len]`, the
+/// next one the following `len` items, and so on. Items past the last group
+/// are listed without a header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectGroup<'a> {
+    /// Header text
+    pub header: &'a str,
+    /// Number of items in this group
+    pub len: usize,
+    /// Hide the group's items, leaving only the header
+    pub collapsed: bool,
+}
+
+impl<'a> SelectGroup<'a> {
+    /// Create an expanded group
+    pub fn new(header: &'a str, len: usize) -> Self {
+        Self {
+            header,
+            len,
+            collapsed: false,
+        }
+    }
+
+    /// Set whether the group is collapsed
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+}
+
+/// A displayed row: a group header or an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    Header(usize),
+    Item(usize),
+}
+
+/// Rows to display for `len` items split into `groups`
+fn build_rows(len: usize, groups: &[SelectGroup<'_>]) -> Vec<Row> {
+    let mut rows = Vec::with_capacity(len + groups.len());
+    let mut start = 0;
+    for (index, group) in groups.iter().enumerate() {
+        rows.push(Row::Header(index));
+        let end = (start + group.len).min(len);
+        if !group.collapsed {
+            rows.extend((start..end).map(Row::Item));
+        }
+        start = end;
+    }
+    rows.extend((start..len).map(Row::Item));
+    rows
+}
+
+/// Index of the group containing an item
+fn group_of(item: usize, groups: &[SelectGroup<'_>]) -> Option<usize> {
+    let mut start = 0;
+    for (index, group) in groups.iter().enumerate() {
+        start += group.len;
+        if item < start {
+            return Some(index);
+        }
+    }
+    None
+}
+
+/// Row showing the selection: the item itself, or its header when collapsed
+fn selected_row(rows: &[Row], selected: usize, groups: &[SelectGroup<'_>]) -> usize {
+    rows.iter()
+        .position(|row| *row == Row::Item(selected))
+        .or_else(|| {
+            let group = group_of(selected, groups)?;
+            rows.iter().position(|row| *row == Row::Header(group))
+        })
+        .unwrap_or(0)
+}
+
+/// A reasonable reset timeout for [`SelectListProps::type_ahead`]
+pub const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
 
 /// Props for SelectList component
-pub struct SelectListProps<'a, A> {
+pub struct SelectListProps<'a, A, T = String> {
     /// Items to display
-    pub items: &'a [String],
+    pub items: &'a [T],
     /// Currently selected index
     pub selected: usize,
     /// Whether this component has focus
@@ -26,8 +350,30 @@ pub struct SelectListProps<'a, A> {
     pub padding_x: u16,
     /// Vertical padding
     pub padding_y: u16,
-    /// Query string to highlight in items (case-insensitive)
+    /// Query string to highlight in items
     pub highlight_query: Option<&'a str>,
+    /// How `highlight_query` is matched and styled
+    pub highlight: HighlightConfig,
+    /// Scrollbar appearance and behavior
+    pub scrollbar: ScrollbarConfig,
+    /// Colors for selection, headers, matches and border
+    pub theme: &'a Theme,
+    /// Item groups with section headers (empty for a flat list)
+    pub groups: &'a [SelectGroup<'a>],
+    /// Callback when space toggles the selected item's group (None = no collapsing)
+    pub on_toggle_group: Option<fn(usize) -> A>,
+    /// Type-ahead reset timeout (None = off, letters are key bindings)
+    ///
+    /// When set, typed characters jump to the next item starting with them
+    /// instead of acting as j/k/g/G bindings; arrows, Home and End still
+    /// navigate. The typed prefix resets after this long without a key.
+    pub type_ahead: Option<Duration>,
+    /// Position of `items` in a paged result set (None = all items are loaded)
+    pub page: Option<PageInfo>,
+    /// Callback when PageUp/PageDown request another page (None = move the cursor)
+    pub on_page_change: Option<fn(usize) -> A>,
+    /// Rebound navigation keys (None = built-in keys, see [`SelectList`])
+    pub keybindings: Option<ComponentBindings<'a>>,
     /// Callback to create action when selection changes
     pub on_select: fn(usize) -> A,
 }
@@ -35,61 +381,219 @@ pub struct SelectListProps<'a, A> {
 /// A scrollable selection list with keyboard navigation
 ///
 /// Handles j/k/up/down for navigation and enter for selection.
-/// Renders with highlight on the selected item.
-#[derive(Default)]
-pub struct SelectList {
+/// Renders with highlight on the selected item. With
+/// [`groups`](SelectListProps::groups), section headers are drawn between
+/// items and skipped during navigation. With
+/// [`type_ahead`](SelectListProps::type_ahead), typing jumps to matching
+/// items like in a file manager. PageUp/PageDown move the cursor by a
+/// screenful, or with [`page`](SelectListProps::page) and
+/// [`on_page_change`](SelectListProps::on_page_change) request the
+/// neighbouring page. Items are `String`s by
+/// default; any [`SelectItem`] (e.g. styled [`Line`]s) works as well.
+///
+/// With [`keybindings`](SelectListProps::keybindings), keys are looked up
+/// as `list.next`, `list.prev`, `list.first`, `list.last`,
+/// `list.page_down`, `list.page_up`, `list.select` and `list.toggle_group`.
+pub struct SelectList<T = String> {
     /// Scroll offset for viewport
     scroll_offset: usize,
+    /// Rows visible in the last render, for PageUp/PageDown
+    viewport_rows: usize,
+    /// Type-ahead prefix typed so far (lowercase)
+    typed: String,
+    /// When the last type-ahead character was typed
+    last_typed: Option<Instant>,
+    _item: PhantomData<fn() -> T>,
 }
 
-/// Highlight matching characters in text (case-insensitive)
-fn highlight_matches(text: &str, query: &str) -> Vec<Span<'static>> {
-    if query.is_empty() {
-        return vec![Span::raw(text.to_string())];
+impl<T> Default for SelectList<T> {
+    fn default() -> Self {
+        Self {
+            scroll_offset: 0,
+            viewport_rows: 0,
+            typed: String::new(),
+            last_typed: None,
+            _item: PhantomData,
+        }
     }
+}
 
-    let text_lower = text.to_lowercase();
-    let query_lower = query.to_lowercase();
+/// Highlight matches in a line, keeping span styles
+fn highlight_matches(
+    line: Line<'_>,
+    matcher: &Matcher<'_>,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    let text: String = line
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect();
+    let matches = matcher.ranges(&text);
 
     let mut spans = Vec::new();
-    let mut last_end = 0;
+    let mut offset = 0;
+    for span in &line.spans {
+        let content = span.content.as_ref();
+        let span_end = offset + content.len();
 
-    // Find all occurrences of query in text
-    for (start, _) in text_lower.match_indices(&query_lower) {
-        // Add non-matching part before this match
-        if start > last_end {
-            spans.push(Span::raw(text[last_end..start].to_string()));
+        // Cut the span at every match boundary inside it
+        let mut cursor = offset;
+        for &(start, end) in &matches {
+            let (start, end) = (start.max(cursor), end.min(span_end));
+            if start >= end {
+                continue;
+            }
+            if start > cursor {
+                spans.push(Span::styled(
+                    content[cursor - offset..start - offset].to_string(),
+                    span.style,
+                ));
+            }
+            spans.push(Span::styled(
+                content[start - offset..end - offset].to_string(),
+                span.style.patch(highlight),
+            ));
+            cursor = end;
         }
+        if cursor < span_end {
+            spans.push(Span::styled(
+                content[cursor - offset..].to_string(),
+                span.style,
+            ));
+        }
+        offset = span_end;
+    }
+    spans
+}
 
-        // Add matching part with highlight (yellow bold)
-        let end = start + query.len();
-        let matched = &text[start..end];
-        let style = Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD);
-        spans.push(Span::styled(matched.to_string(), style));
+/// Whether a key types a character (no Ctrl/Alt, not a space)
+fn is_typed(key: &crossterm::event::KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char(c) if c != ' ')
+        && !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+}
 
-        last_end = end;
-    }
+/// Keyboard commands, rebindable through [`SelectListProps::keybindings`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListCommand {
+    Next,
+    Prev,
+    First,
+    Last,
+    PageDown,
+    PageUp,
+    Select,
+    ToggleGroup,
+}
+
+impl ComponentCommand for ListCommand {
+    const ALL: &'static [Self] = &[
+        Self::Next,
+        Self::Prev,
+        Self::First,
+        Self::Last,
+        Self::PageDown,
+        Self::PageUp,
+        Self::Select,
+        Self::ToggleGroup,
+    ];
 
-    // Add remaining part after last match
-    if last_end < text.len() {
-        spans.push(Span::raw(text[last_end..].to_string()));
+    fn name(self) -> &'static str {
+        match self {
+            Self::Next => "list.next",
+            Self::Prev => "list.prev",
+            Self::First => "list.first",
+            Self::Last => "list.last",
+            Self::PageDown => "list.page_down",
+            Self::PageUp => "list.page_up",
+            Self::Select => "list.select",
+            Self::ToggleGroup => "list.toggle_group",
+        }
     }
 
-    if spans.is_empty() {
-        vec![Span::raw(text.to_string())]
-    } else {
-        spans
+    fn from_key(key: &crossterm::event::KeyEvent) -> Option<Self> {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Some(Self::Next),
+            KeyCode::Char('k') | KeyCode::Up => Some(Self::Prev),
+            KeyCode::Char('g') | KeyCode::Home => Some(Self::First),
+            KeyCode::Char('G') | KeyCode::End => Some(Self::Last),
+            KeyCode::PageDown => Some(Self::PageDown),
+            KeyCode::PageUp => Some(Self::PageUp),
+            KeyCode::Enter => Some(Self::Select),
+            KeyCode::Char(' ') => Some(Self::ToggleGroup),
+            _ => None,
+        }
     }
 }
 
-impl SelectList {
+/// Plain text of an item, lowercased for prefix matching
+fn item_text(item: &impl SelectItem) -> String {
+    item.line()
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+impl<T> SelectList<T> {
     /// Create a new SelectList
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Extend the type-ahead prefix with `c` and find the item it matches
+    ///
+    /// Searches visible items from the selection onwards, wrapping around.
+    /// A longer prefix may keep the current item; repeating one character
+    /// cycles through the items starting with it.
+    fn type_ahead(
+        &mut self,
+        c: char,
+        now: Instant,
+        timeout: Duration,
+        items: &[T],
+        visible: &[usize],
+        selected: usize,
+    ) -> Option<usize>
+    where
+        T: SelectItem,
+    {
+        if self
+            .last_typed
+            .is_some_and(|last| now.saturating_duration_since(last) > timeout)
+        {
+            self.typed.clear();
+        }
+        self.last_typed = Some(now);
+        self.typed.extend(c.to_lowercase());
+
+        if visible.is_empty() {
+            return None;
+        }
+        let start = visible.iter().position(|&i| i == selected).unwrap_or(0);
+        let find = |prefix: &str, skip: usize| {
+            (0..visible.len())
+                .map(|offset| visible[(start + skip + offset) % visible.len()])
+                .find(|&i| item_text(&items[i]).starts_with(prefix))
+        };
+
+        if self.typed.chars().count() > 1 {
+            if let Some(found) = find(&self.typed, 0) {
+                return Some(found);
+            }
+        }
+        // One character, or the same one repeated, cycles through its items
+        let mut chars = self.typed.chars();
+        let first = chars.next()?;
+        if chars.all(|ch| ch == first) {
+            return find(first.encode_utf8(&mut [0; 4]), 1);
+        }
+        None
+    }
+
     /// Ensure the selected index is visible within the viewport
     fn ensure_visible(&mut self, selected: usize, viewport_height: usize) {
         if viewport_height == 0 {
@@ -104,8 +608,8 @@ impl SelectList {
     }
 }
 
-impl<A> Component<A> for SelectList {
-    type Props<'a> = SelectListProps<'a, A>;
+impl<A, T: SelectItem> Component<A> for SelectList<T> {
+    type Props<'a> = SelectListProps<'a, A, T>;
 
     fn handle_event(
         &mut self,
@@ -116,51 +620,81 @@ impl<A> Component<A> for SelectList {
             return None;
         }
 
-        let len = props.items.len();
-
-        match event {
-            EventKind::Key(key) => match key.code {
-                // Navigate down
-                KeyCode::Char('j') | KeyCode::Down => {
-                    let new_idx = (props.selected + 1).min(len.saturating_sub(1));
-                    if new_idx != props.selected {
-                        Some((props.on_select)(new_idx))
-                    } else {
-                        None
-                    }
-                }
-                // Navigate up
-                KeyCode::Char('k') | KeyCode::Up => {
-                    let new_idx = props.selected.saturating_sub(1);
-                    if new_idx != props.selected {
-                        Some((props.on_select)(new_idx))
-                    } else {
-                        None
-                    }
+        let visible: Vec<usize> = build_rows(props.items.len(), props.groups)
+            .into_iter()
+            .filter_map(|row| match row {
+                Row::Item(index) => Some(index),
+                Row::Header(_) => None,
+            })
+            .collect();
+        let selected = props.selected;
+
+        // Type-ahead takes typed characters; any other key resets the prefix
+        if let (EventKind::Key(key), Some(timeout)) = (event, props.type_ahead) {
+            match key.code {
+                KeyCode::Char(c) if is_typed(key) => {
+                    return self
+                        .type_ahead(c, Instant::now(), timeout, props.items, &visible, selected)
+                        .filter(|&i| i != selected)
+                        .map(props.on_select);
                 }
-                // Jump to top
-                KeyCode::Char('g') | KeyCode::Home => {
-                    if props.selected != 0 {
-                        Some((props.on_select)(0))
-                    } else {
-                        None
-                    }
+                _ => self.typed.clear(),
+            }
+        }
+
+        let EventKind::Key(key) = event else {
+            return None;
+        };
+        let Some(command) = ListCommand::resolve(key, props.keybindings) else {
+            return None;
+        };
+        let target = match command {
+            // Navigate down
+            ListCommand::Next => visible.iter().copied().find(|&i| i > selected),
+            // Navigate up
+            ListCommand::Prev => visible.iter().rev().copied().find(|&i| i < selected),
+            // Jump to top
+            ListCommand::First => visible.first().copied().filter(|&i| i != selected),
+            // Jump to bottom
+            ListCommand::Last => visible.last().copied().filter(|&i| i != selected),
+            // Request the next/previous page, or move by a screenful
+            ListCommand::PageDown => {
+                let request = props
+                    .page
+                    .filter(PageInfo::has_next)
+                    .zip(props.on_page_change);
+                if let Some((page, on_page_change)) = request {
+                    return Some(on_page_change(page.current_page + 1));
                 }
-                // Jump to bottom
-                KeyCode::Char('G') | KeyCode::End => {
-                    let last = len.saturating_sub(1);
-                    if props.selected != last {
-                        Some((props.on_select)(last))
-                    } else {
-                        None
-                    }
+                let position = visible.iter().position(|&i| i == selected).unwrap_or(0);
+                let last = visible.len().saturating_sub(1);
+                let target = (position + self.viewport_rows.max(1)).min(last);
+                visible.get(target).copied().filter(|&i| i != selected)
+            }
+            ListCommand::PageUp => {
+                let request = props
+                    .page
+                    .filter(PageInfo::has_prev)
+                    .zip(props.on_page_change);
+                if let Some((page, on_page_change)) = request {
+                    return Some(on_page_change(page.current_page - 1));
                 }
-                // Select current (re-emit for confirmation actions)
-                KeyCode::Enter => Some((props.on_select)(props.selected)),
-                _ => None,
-            },
-            _ => None,
-        }
+                let position = visible.iter().position(|&i| i == selected).unwrap_or(0);
+                let target = position.saturating_sub(self.viewport_rows.max(1));
+                visible.get(target).copied().filter(|&i| i != selected)
+            }
+            // Select current (re-emit for confirmation actions)
+            ListCommand::Select => Some(selected),
+            // Collapse/expand the selected item's group
+            ListCommand::ToggleGroup => {
+                return props
+                    .on_toggle_group
+                    .zip(group_of(selected, props.groups))
+                    .map(|(on_toggle, group)| on_toggle(group));
+            }
+        };
+
+        target.map(props.on_select)
     }
 
     fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
@@ -175,32 +709,63 @@ impl<A> Component<A> for SelectList {
         // Calculate viewport height (account for borders if shown)
         let border_offset = if props.show_border { 2 } else { 0 };
         let viewport_height = content_area.height.saturating_sub(border_offset) as usize;
+        self.viewport_rows = viewport_height;
 
-        // Ensure selected item is visible
-        self.ensure_visible(props.selected, viewport_height);
+        // Ensure the selected row is visible
+        let rows = build_rows(props.items.len(), props.groups);
+        let selected_row = selected_row(&rows, props.selected, props.groups);
+        self.ensure_visible(selected_row, viewport_height);
+
+        let matcher = props
+            .highlight_query
+            .and_then(|query| Matcher::new(query, &props.highlight));
+        let highlight = props.highlight.highlight_style(props.theme);
 
         // Build list items with selection marker and highlight
-        let items: Vec<ListItem> = props
-            .items
+        let items: Vec<ListItem> = rows
             .iter()
             .enumerate()
-            .map(|(i, item)| {
-                let is_selected = i == props.selected;
+            .map(|(row_index, row)| {
+                let is_selected = row_index == selected_row;
                 let prefix = if is_selected { "> " } else { "  " };
 
-                let line = if let Some(query) = props.highlight_query {
+                let index = match *row {
+                    Row::Header(group) => {
+                        let group = &props.groups[group];
+                        let (marker, count) = if group.collapsed {
+                            ("▸ ", format!(" ({})", group.len))
+                        } else {
+                            ("▾ ", String::new())
+                        };
+                        let line = Line::from(vec![
+                            Span::raw(prefix),
+                            Span::raw(marker),
+                            Span::raw(group.header),
+                            Span::raw(count),
+                        ]);
+                        let style = if is_selected {
+                            props.theme.selected()
+                        } else {
+                            props.theme.muted().add_modifier(Modifier::BOLD)
+                        };
+                        return ListItem::new(line).style(style);
+                    }
+                    Row::Item(index) => index,
+                };
+
+                let item_line = props.items[index].line();
+                let line_style = item_line.style;
+                let mut spans = vec![Span::raw(prefix)];
+                if let Some(matcher) = &matcher {
                     // Build line with highlighted matches
-                    let mut spans = vec![Span::raw(prefix)];
-                    spans.extend(highlight_matches(item, query));
-                    Line::from(spans)
+                    spans.extend(highlight_matches(item_line, matcher, highlight));
                 } else {
-                    Line::raw(format!("{prefix}{item}"))
-                };
+                    spans.extend(item_line.spans);
+                }
+                let line = Line::from(spans).style(line_style);
 
                 let style = if is_selected {
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD)
+                    props.theme.selected()
                 } else {
                     Style::default()
                 };
@@ -209,39 +774,36 @@ impl<A> Component<A> for SelectList {
             .collect();
 
         // Create the list widget
-        let mut list = List::new(items).highlight_style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        );
+        let mut list = List::new(items).highlight_style(props.theme.selected());
 
         if props.show_border {
-            list = list.block(Block::default().borders(Borders::ALL).border_style(
-                if props.is_focused {
-                    Style::default().fg(Color::Cyan)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                },
-            ));
+            let mut block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(props.theme.border_style(props.is_focused));
+            if props.scrollbar.show_position && !props.items.is_empty() {
+                // Paged lists count across all pages
+                let (offset, total) = props.page.map_or((0, props.items.len()), |page| {
+                    (page.first_item(), page.total_count)
+                });
+                let position = format!(" {}/{} ", offset + props.selected + 1, total);
+                block = block.title_bottom(Line::from(position).right_aligned());
+            }
+            list = list.block(block);
         }
 
         // Use ListState to handle scroll offset
-        let mut state = ListState::default().with_selected(Some(props.selected));
+        let mut state = ListState::default().with_selected(Some(selected_row));
         *state.offset_mut() = self.scroll_offset;
 
         frame.render_stateful_widget(list, content_area, &mut state);
 
-        // Render scrollbar if content exceeds viewport
-        if props.items.len() > viewport_height {
-            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .begin_symbol(None)
-                .end_symbol(None)
-                .track_symbol(Some("│"))
-                .thumb_symbol("█");
+        // Render scrollbar if content exceeds viewport (or always, without auto-hide)
+        let overflows = rows.len() > viewport_height;
+        if overflows || !props.scrollbar.auto_hide {
+            let scrollbar = props.scrollbar.build();
 
-            // Use selected index for position - shows where selection is in full list
-            let mut scrollbar_state =
-                ScrollbarState::new(props.items.len()).position(props.selected);
+            // Use selected row for position - shows where selection is in full list
+            let mut scrollbar_state = ScrollbarState::new(rows.len()).position(selected_row);
 
             // Render scrollbar in the inner area (account for border if shown)
             let scrollbar_area = if props.show_border {
@@ -263,6 +825,7 @@ impl<A> Component<A> for SelectList {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tui_dispatch_core::keybindings::{BindingContext, Keybindings};
     use tui_dispatch_core::testing::{key, RenderHarness};
 
     #[derive(Debug, Clone, PartialEq)]
@@ -286,6 +849,15 @@ mod tests {
             padding_x: 0,
             padding_y: 0,
             highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
             on_select: TestAction::Select,
         };
 
@@ -309,6 +881,15 @@ mod tests {
             padding_x: 0,
             padding_y: 0,
             highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
             on_select: TestAction::Select,
         };
 
@@ -334,6 +915,15 @@ mod tests {
             padding_x: 0,
             padding_y: 0,
             highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
             on_select: TestAction::Select,
         };
         let actions: Vec<_> = list
@@ -351,6 +941,15 @@ mod tests {
             padding_x: 0,
             padding_y: 0,
             highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
             on_select: TestAction::Select,
         };
         let actions: Vec<_> = list
@@ -372,6 +971,15 @@ mod tests {
             padding_x: 0,
             padding_y: 0,
             highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
             on_select: TestAction::Select,
         };
 
@@ -395,6 +1003,15 @@ mod tests {
             padding_x: 0,
             padding_y: 0,
             highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
             on_select: TestAction::Select,
         };
 
@@ -421,6 +1038,15 @@ mod tests {
                 padding_x: 0,
                 padding_y: 0,
                 highlight_query: None,
+                highlight: HighlightConfig::default(),
+                scrollbar: ScrollbarConfig::default(),
+                theme: &Theme::DARK,
+                groups: &[],
+                on_toggle_group: None,
+                type_ahead: None,
+                page: None,
+                on_page_change: None,
+                keybindings: None,
                 on_select: |_| (),
             };
             list.render(frame, frame.area(), props);
@@ -430,4 +1056,517 @@ mod tests {
         assert!(output.contains("Item 1"));
         assert!(output.contains("Item 2"));
     }
+
+    fn render_list(items: &[String], selected: usize, scrollbar: ScrollbarConfig) -> String {
+        let mut render = RenderHarness::new(20, 5);
+        let mut list = SelectList::new();
+        render.render_to_string_plain(|frame| {
+            let props = SelectListProps {
+                items,
+                selected,
+                is_focused: true,
+                show_border: true,
+                padding_x: 0,
+                padding_y: 0,
+                highlight_query: None,
+                highlight: HighlightConfig::default(),
+                scrollbar,
+                groups: &[],
+                on_toggle_group: None,
+                type_ahead: None,
+                page: None,
+                on_page_change: None,
+                keybindings: None,
+                on_select: |_| (),
+            };
+            list.render(frame, frame.area(), props);
+        })
+    }
+
+    #[test]
+    fn test_scrollbar_auto_hide() {
+        let items = make_items();
+
+        // 3 items fit in 3 rows: hidden by default
+        let output = render_list(&items, 0, ScrollbarConfig::default());
+        assert!(!output.contains('█'));
+
+        let always = ScrollbarConfig {
+            auto_hide: false,
+            ..Default::default()
+        };
+        let output = render_list(&items, 0, always);
+        assert!(output.contains('█'));
+    }
+
+    #[test]
+    fn test_scrollbar_symbols_and_position() {
+        let items: Vec<String> = (0..10).map(|i| format!("Item {i}")).collect();
+        let config = ScrollbarConfig {
+            style: ScrollbarStyle {
+                thumb_symbol: Some("#"),
+                track_symbol: Some(":"),
+                ..Default::default()
+            },
+            auto_hide: true,
+            show_position: true,
+        };
+
+        let output = render_list(&items, 2, config);
+        assert!(output.contains('#'));
+        assert!(output.contains(':'));
+        assert!(output.contains("3/10"));
+    }
+    #[test]
+    fn test_highlight_keeps_span_styles() {
+        let red = Style::default().fg(Color::Red);
+        let line = Line::from(vec![Span::styled("● ", red), Span::raw("Berlin")]);
+
+        let highlighted = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let matcher = Matcher::new("● b", &HighlightConfig::default()).unwrap();
+        let spans = highlight_matches(line, &matcher, highlighted);
+        assert_eq!(
+            spans,
+            vec![
+                Span::styled("● ", red.patch(highlighted)),
+                Span::styled("B", highlighted),
+                Span::raw("erlin"),
+            ]
+        );
+
+        // No match leaves the line untouched
+        let matcher = Matcher::new("x", &HighlightConfig::default()).unwrap();
+        let spans = highlight_matches(
+            Line::from(vec![Span::styled("ab", red)]),
+            &matcher,
+            highlighted,
+        );
+        assert_eq!(spans, vec![Span::styled("ab", red)]);
+        assert!(Matcher::new("", &HighlightConfig::default()).is_none());
+    }
+
+    fn ranges(query: &str, text: &str, config: HighlightConfig) -> Vec<(usize, usize)> {
+        Matcher::new(query, &config).unwrap().ranges(text)
+    }
+
+    #[test]
+    fn test_highlight_match_modes() {
+        let case_sensitive = HighlightConfig {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            ranges("ab", "Ab ab", HighlightConfig::default()),
+            [(0, 2), (3, 5)]
+        );
+        assert_eq!(ranges("ab", "Ab ab", case_sensitive), [(3, 5)]);
+
+        // Consecutive fuzzy hits merge into one range
+        assert_eq!(
+            ranges("ofi", "Open File", HighlightConfig::fuzzy()),
+            [(0, 1), (5, 7)]
+        );
+        let fuzzy_case = HighlightConfig {
+            case_sensitive: true,
+            ..HighlightConfig::fuzzy()
+        };
+        assert!(ranges("ofi", "Open File", fuzzy_case).is_empty());
+        assert_eq!(ranges("OF", "Open File", fuzzy_case), [(0, 1), (5, 6)]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_highlight_regex_mode() {
+        let config = HighlightConfig::with_mode(MatchMode::Regex);
+        assert_eq!(ranges(r"\d+", "v12 and 3", config), [(1, 3), (8, 9)]);
+        assert_eq!(ranges("^o", "Open", config), [(0, 1)]);
+        assert!(Matcher::new("(", &config).is_none());
+    }
+
+    #[test]
+    fn test_render_custom_highlight_style() {
+        let mut render = RenderHarness::new(30, 5);
+        let mut list = SelectList::new();
+        let items = vec!["Close".to_string(), "Open File".to_string()];
+
+        let buffer = render.render(|frame| {
+            let props = SelectListProps {
+                items: &items,
+                selected: 0,
+                is_focused: true,
+                show_border: true,
+                padding_x: 0,
+                padding_y: 0,
+                highlight_query: Some("of"),
+                highlight: HighlightConfig {
+                    style: Some(Style::default().fg(Color::Magenta)),
+                    ..HighlightConfig::fuzzy()
+                },
+                scrollbar: ScrollbarConfig::default(),
+                theme: &Theme::DARK,
+                groups: &[],
+                on_toggle_group: None,
+                type_ahead: None,
+                page: None,
+                on_page_change: None,
+                keybindings: None,
+                on_select: |_| (),
+            };
+            list.render(frame, frame.area(), props);
+        });
+
+        // "O" and "F" are highlighted, "p" is not
+        assert_eq!(buffer[(3, 2)].fg, Color::Magenta);
+        assert_ne!(buffer[(4, 2)].fg, Color::Magenta);
+        assert_eq!(buffer[(8, 2)].fg, Color::Magenta);
+    }
+
+    #[test]
+    fn test_render_styled_items() {
+        let mut render = RenderHarness::new(30, 5);
+        let mut list = SelectList::new();
+        let items = vec![
+            Line::from(vec![
+                Span::styled("★ ", Style::default().fg(Color::Yellow)),
+                Span::raw("Favorites"),
+            ]),
+            Line::from(Span::styled("Archive", Style::default().fg(Color::Green))),
+        ];
+
+        let buffer = render.render(|frame| {
+            let props = SelectListProps {
+                items: &items,
+                selected: 0,
+                is_focused: true,
+                show_border: true,
+                padding_x: 0,
+                padding_y: 0,
+                highlight_query: Some("arch"),
+                highlight: HighlightConfig::default(),
+                scrollbar: ScrollbarConfig::default(),
+                theme: &Theme::DARK,
+                groups: &[],
+                on_toggle_group: None,
+                type_ahead: None,
+                page: None,
+                on_page_change: None,
+                keybindings: None,
+                on_select: |_| (),
+            };
+            list.render(frame, frame.area(), props);
+        });
+
+        // "★" keeps its color, "Arch" is highlighted, "ive" stays green
+        assert_eq!(buffer[(3, 1)].symbol(), "★");
+        assert_eq!(buffer[(3, 1)].fg, Color::Yellow);
+        assert_eq!(buffer[(5, 1)].symbol(), "F");
+        assert_eq!(buffer[(3, 2)].symbol(), "A");
+        assert!(buffer[(3, 2)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buffer[(3, 2)].fg, Color::Yellow);
+        assert_eq!(buffer[(7, 2)].fg, Color::Green);
+    }
+
+    fn type_ahead_props(items: &[String], selected: usize) -> SelectListProps<'_, TestAction> {
+        SelectListProps {
+            items,
+            selected,
+            is_focused: true,
+            show_border: true,
+            padding_x: 0,
+            padding_y: 0,
+            highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: Some(TYPE_AHEAD_TIMEOUT),
+            page: None,
+            on_page_change: None,
+            keybindings: None,
+            on_select: TestAction::Select,
+        }
+    }
+
+    #[test]
+    fn test_type_ahead_jumps_to_prefix() {
+        let mut list = SelectList::new();
+        let items: Vec<String> = ["Berlin", "Bern", "Jakarta", "Bergen"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        // "j" is no longer a navigation key
+        let actions: Vec<_> = list
+            .handle_event(&EventKind::Key(key("j")), type_ahead_props(&items, 0))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Select(2)]);
+
+        // Arrows still navigate, and reset the prefix
+        let actions: Vec<_> = list
+            .handle_event(&EventKind::Key(key("down")), type_ahead_props(&items, 2))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Select(3)]);
+        assert!(list.typed.is_empty());
+
+        // A longer prefix narrows the match, keeping the current item if it fits
+        let now = Instant::now();
+        let typed = |list: &mut SelectList, c, selected, at| {
+            list.type_ahead(c, at, TYPE_AHEAD_TIMEOUT, &items, &[0, 1, 2, 3], selected)
+        };
+        assert_eq!(typed(&mut list, 'B', 2, now), Some(3));
+        assert_eq!(typed(&mut list, 'e', 3, now), Some(3));
+        assert_eq!(typed(&mut list, 'r', 3, now), Some(3));
+        assert_eq!(typed(&mut list, 'n', 3, now), Some(1));
+    }
+
+    #[test]
+    fn test_type_ahead_cycles_and_resets() {
+        let mut list = SelectList::new();
+        let items: Vec<String> = ["Berlin", "Jakarta", "Bern"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let visible = [0, 1, 2];
+        let now = Instant::now();
+        let soon = now + Duration::from_millis(100);
+
+        // Repeating a character cycles through its items
+        let mut selected = 0;
+        for expected in [2, 0, 2] {
+            selected = list
+                .type_ahead('b', soon, TYPE_AHEAD_TIMEOUT, &items, &visible, selected)
+                .unwrap();
+            assert_eq!(selected, expected);
+        }
+
+        // No match keeps the selection
+        assert_eq!(
+            list.type_ahead('x', soon, TYPE_AHEAD_TIMEOUT, &items, &visible, 0),
+            None
+        );
+
+        // After the timeout, typing starts a new prefix
+        let later = soon + TYPE_AHEAD_TIMEOUT * 2;
+        assert_eq!(
+            list.type_ahead('j', later, TYPE_AHEAD_TIMEOUT, &items, &visible, 0),
+            Some(1)
+        );
+        assert_eq!(list.typed, "j");
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum PageAction {
+        Select(usize),
+        Page(usize),
+    }
+
+    #[test]
+    fn test_page_keys_request_pages() {
+        let mut list = SelectList::new();
+        let items: Vec<String> = (0..10).map(|i| format!("Key {i}")).collect();
+        let mut press = |code: &str, selected: usize, page: Option<PageInfo>| -> Vec<PageAction> {
+            let props = SelectListProps {
+                items: &items,
+                selected,
+                is_focused: true,
+                show_border: false,
+                padding_x: 0,
+                padding_y: 0,
+                highlight_query: None,
+                highlight: HighlightConfig::default(),
+                scrollbar: ScrollbarConfig::default(),
+                theme: &Theme::DARK,
+                groups: &[],
+                on_toggle_group: None,
+                type_ahead: None,
+                page,
+                on_page_change: Some(PageAction::Page),
+                keybindings: None,
+                on_select: PageAction::Select,
+            };
+            list.handle_event(&EventKind::Key(key(code)), props)
+                .into_iter()
+                .collect()
+        };
+
+        let middle = Some(PageInfo::new(240, 10, 3));
+        assert_eq!(press("pagedown", 2, middle), vec![PageAction::Page(4)]);
+        assert_eq!(press("pageup", 2, middle), vec![PageAction::Page(2)]);
+
+        // On the last page, or without paging, the cursor moves instead
+        // (one row at a time until the list has been rendered)
+        let last = Some(PageInfo::new(240, 10, 23));
+        assert_eq!(press("pagedown", 2, last), vec![PageAction::Select(3)]);
+        assert_eq!(press("pageup", 2, None), vec![PageAction::Select(1)]);
+    }
+
+    #[test]
+    fn test_page_moves_by_viewport() {
+        let mut render = RenderHarness::new(20, 6);
+        let mut list = SelectList::new();
+        let items: Vec<String> = (0..20).map(|i| format!("Item {i}")).collect();
+        let props = |selected| SelectListProps {
+            items: &items,
+            selected,
+            is_focused: true,
+            show_border: true,
+            padding_x: 0,
+            padding_y: 0,
+            highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig {
+                show_position: true,
+                ..Default::default()
+            },
+            theme: &Theme::DARK,
+            groups: &[],
+            on_toggle_group: None,
+            type_ahead: None,
+            page: Some(PageInfo::new(120, 20, 2)),
+            on_page_change: None,
+            keybindings: None,
+            on_select: TestAction::Select,
+        };
+
+        // Paged lists number items across pages
+        let output = render.render_to_string_plain(|frame| {
+            list.render(frame, frame.area(), props(1));
+        });
+        assert!(output.contains("42/120"));
+
+        // Four rows fit inside the border
+        let actions: Vec<_> = list
+            .handle_event(&EventKind::Key(key("pagedown")), props(1))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Select(5)]);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum GroupAction {
+        Select(usize),
+        Toggle(usize),
+    }
+
+    fn grouped_props<'a>(
+        items: &'a [String],
+        groups: &'a [SelectGroup<'a>],
+        selected: usize,
+    ) -> SelectListProps<'a, GroupAction> {
+        SelectListProps {
+            items,
+            selected,
+            is_focused: true,
+            show_border: false,
+            padding_x: 0,
+            padding_y: 0,
+            highlight_query: None,
+            highlight: HighlightConfig::default(),
+            scrollbar: ScrollbarConfig::default(),
+            theme: &Theme::DARK,
+            groups,
+            on_toggle_group: Some(GroupAction::Toggle),
+            type_ahead: None,
+            page: None,
+            on_page_change: None,
+            keybindings: None,
+            on_select: GroupAction::Select,
+        }
+    }
+
+    #[test]
+    fn test_groups_skip_headers_and_collapsed_items() {
+        let mut list = SelectList::new();
+        let items: Vec<String> = (0..5).map(|i| format!("Item {i}")).collect();
+        let groups = [
+            SelectGroup::new("Recent", 2),
+            SelectGroup::new("Pinned", 1).collapsed(true),
+            SelectGroup::new("All", 2),
+        ];
+
+        let mut press = |code: &str, selected: usize| -> Vec<GroupAction> {
+            list.handle_event(
+                &EventKind::Key(key(code)),
+                grouped_props(&items, &groups, selected),
+            )
+            .into_iter()
+            .collect()
+        };
+
+        // Item 1 -> Item 3: the "Pinned" header and its hidden item are skipped
+        assert_eq!(press("j", 1), vec![GroupAction::Select(3)]);
+        assert_eq!(press("k", 3), vec![GroupAction::Select(1)]);
+        assert_eq!(press("end", 0), vec![GroupAction::Select(4)]);
+        assert_eq!(press("space", 3), vec![GroupAction::Toggle(2)]);
+        assert_eq!(press("space", 2), vec![GroupAction::Toggle(1)]);
+    }
+
+    #[test]
+    fn test_render_group_headers() {
+        let mut render = RenderHarness::new(24, 6);
+        let mut list = SelectList::new();
+        let items: Vec<String> = (0..3).map(|i| format!("Item {i}")).collect();
+        let groups = [
+            SelectGroup::new("Recent", 1),
+            SelectGroup::new("All", 2).collapsed(true),
+        ];
+
+        let output = render.render_to_string_plain(|frame| {
+            list.render(frame, frame.area(), grouped_props(&items, &groups, 0));
+        });
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0].trim_end(), "  ▾ Recent");
+        assert_eq!(lines[1].trim_end(), "> Item 0");
+        assert_eq!(lines[2].trim_end(), "  ▸ All (2)");
+        assert!(!output.contains("Item 1"));
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    struct ListContext;
+
+    impl BindingContext for ListContext {
+        fn name(&self) -> &'static str {
+            "list"
+        }
+
+        fn from_name(name: &str) -> Option<Self> {
+            (name == "list").then_some(ListContext)
+        }
+
+        fn all() -> &'static [Self] {
+            &[ListContext]
+        }
+    }
+
+    #[test]
+    fn test_keybindings_rebind_navigation() {
+        let items = make_items();
+        let mut keybindings = Keybindings::new();
+        keybindings.add(ListContext, "list.next", vec!["n".into()]);
+        keybindings.add_global("quit", vec!["q".into()]);
+        let mut list = SelectList::new();
+        let mut press = |code: &str| -> Vec<TestAction> {
+            let props = SelectListProps {
+                type_ahead: None,
+                keybindings: Some(ComponentBindings::new(&keybindings, ListContext)),
+                ..type_ahead_props(&items, 0)
+            };
+            list.handle_event(&EventKind::Key(key(code)), props)
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(press("n"), vec![TestAction::Select(1)]);
+        // `list.next` moved to "n", so j and Down no longer navigate
+        assert!(press("j").is_empty());
+        assert!(press("down").is_empty());
+        // Other list commands keep their keys; non-list commands are ignored
+        assert_eq!(press("end"), vec![TestAction::Select(2)]);
+        assert!(press("q").is_empty());
+    }
 }
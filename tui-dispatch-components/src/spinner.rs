@@ -0,0 +1,158 @@
+//! Spinner component
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use tui_dispatch_core::Component;
+
+/// Frame set for a spinner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpinnerGlyphs(pub &'static [&'static str]);
+
+impl SpinnerGlyphs {
+    /// Braille dots
+    pub const DOTS: Self = Self(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]);
+    /// Rotating ASCII line
+    pub const LINE: Self = Self(&["-", "\\", "|", "/"]);
+    /// Rotating half circle
+    pub const CIRCLE: Self = Self(&["◐", "◓", "◑", "◒"]);
+    /// Growing and shrinking bar
+    pub const PULSE: Self = Self(&["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"]);
+
+    /// Frame to show for the given tick count
+    pub fn frame(&self, tick: u64, ticks_per_frame: u64) -> &'static str {
+        if self.0.is_empty() {
+            return "";
+        }
+        let index = (tick / ticks_per_frame.max(1)) % self.0.len() as u64;
+        self.0[index as usize]
+    }
+}
+
+impl Default for SpinnerGlyphs {
+    fn default() -> Self {
+        Self::DOTS
+    }
+}
+
+/// Configuration for spinner appearance
+#[derive(Debug, Clone, Copy)]
+pub struct SpinnerStyle {
+    /// Frame set
+    pub glyphs: SpinnerGlyphs,
+    /// Number of ticks each frame is shown for (minimum 1)
+    pub ticks_per_frame: u64,
+    /// Style for the spinner glyph
+    pub spinner: Style,
+    /// Style for the label
+    pub label: Style,
+}
+
+impl Default for SpinnerStyle {
+    fn default() -> Self {
+        Self {
+            glyphs: SpinnerGlyphs::default(),
+            ticks_per_frame: 1,
+            spinner: Style::default().fg(Color::Cyan),
+            label: Style::default(),
+        }
+    }
+}
+
+impl SpinnerStyle {
+    /// Create a style with the given frame set
+    pub fn with_glyphs(glyphs: SpinnerGlyphs) -> Self {
+        Self {
+            glyphs,
+            ..Default::default()
+        }
+    }
+}
+
+/// Props for Spinner component
+pub struct SpinnerProps<'a> {
+    /// Tick count from app state (e.g. incremented on each `Tick` action)
+    pub tick: u64,
+    /// Optional label rendered after the spinner
+    pub label: Option<&'a str>,
+    /// Appearance
+    pub style: SpinnerStyle,
+}
+
+/// An animated loading indicator
+///
+/// Stateless: the current frame is derived from `tick`, so the animation
+/// advances whenever the app increments its tick counter and re-renders.
+#[derive(Default)]
+pub struct Spinner;
+
+impl Spinner {
+    /// Create a new Spinner
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<A> Component<A> for Spinner {
+    type Props<'a> = SpinnerProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let style = props.style;
+        let glyph = style.glyphs.frame(props.tick, style.ticks_per_frame);
+
+        let mut spans = vec![Span::styled(glyph, style.spinner)];
+        if let Some(label) = props.label {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(label, style.label));
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::RenderHarness;
+
+    #[test]
+    fn test_frame_advances_with_tick() {
+        let glyphs = SpinnerGlyphs::LINE;
+        assert_eq!(glyphs.frame(0, 1), "-");
+        assert_eq!(glyphs.frame(1, 1), "\\");
+        assert_eq!(glyphs.frame(4, 1), "-");
+
+        // Slower animation
+        assert_eq!(glyphs.frame(1, 2), "-");
+        assert_eq!(glyphs.frame(2, 2), "\\");
+
+        // Zero ticks per frame is treated as one
+        assert_eq!(glyphs.frame(1, 0), "\\");
+        assert_eq!(SpinnerGlyphs(&[]).frame(3, 1), "");
+    }
+
+    #[test]
+    fn test_render_with_label() {
+        let mut render = RenderHarness::new(20, 1);
+        let mut spinner = Spinner::new();
+
+        let output = render.render_to_string_plain(|frame| {
+            <Spinner as Component<()>>::render(
+                &mut spinner,
+                frame,
+                frame.area(),
+                SpinnerProps {
+                    tick: 2,
+                    label: Some("Loading"),
+                    style: SpinnerStyle::with_glyphs(SpinnerGlyphs::LINE),
+                },
+            );
+        });
+
+        assert!(output.starts_with("| Loading"));
+    }
+}
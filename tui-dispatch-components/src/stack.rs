@@ -0,0 +1,352 @@
+//! Stack and Spacer layout components
+
+use ratatui::{
+    layout::{Constraint, Direction, Flex, Layout, Rect},
+    style::Style,
+    widgets::Block,
+    Frame,
+};
+use tui_dispatch_core::Component;
+
+/// Render callback for a stack child
+type RenderFn<'a> = Box<dyn FnOnce(&mut Frame, Rect) + 'a>;
+
+/// Cross-axis placement of children narrower than the stack
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StackAlign {
+    /// Left of a vertical stack, top of a horizontal one
+    #[default]
+    Start,
+    /// Centered
+    Center,
+    /// Right of a vertical stack, bottom of a horizontal one
+    End,
+}
+
+/// One child of a [`Stack`]: a size along the stack and a render callback
+pub struct StackItem<'a> {
+    /// Size along the stack direction
+    pub constraint: Constraint,
+    /// Size across the stack (None = full width/height)
+    pub cross_size: Option<u16>,
+    render: Option<RenderFn<'a>>,
+}
+
+impl<'a> StackItem<'a> {
+    /// Create a child with any ratatui constraint
+    pub fn new(constraint: Constraint, render: impl FnOnce(&mut Frame, Rect) + 'a) -> Self {
+        Self {
+            constraint,
+            cross_size: None,
+            render: Some(Box::new(render)),
+        }
+    }
+
+    /// Share leftover space with other `fill` children by `weight`
+    pub fn fill(weight: u16, render: impl FnOnce(&mut Frame, Rect) + 'a) -> Self {
+        Self::new(Constraint::Fill(weight), render)
+    }
+
+    /// Take exactly `length` cells
+    pub fn length(length: u16, render: impl FnOnce(&mut Frame, Rect) + 'a) -> Self {
+        Self::new(Constraint::Length(length), render)
+    }
+
+    /// Take at least `min` cells, growing like a `fill(1)` child
+    pub fn min(min: u16, render: impl FnOnce(&mut Frame, Rect) + 'a) -> Self {
+        Self::new(Constraint::Min(min), render)
+    }
+
+    /// Take a percentage of the stack
+    pub fn percentage(percent: u16, render: impl FnOnce(&mut Frame, Rect) + 'a) -> Self {
+        Self::new(Constraint::Percentage(percent), render)
+    }
+
+    /// Empty space (see also [`Spacer`] for a styled one)
+    pub fn spacer(constraint: Constraint) -> Self {
+        Self {
+            constraint,
+            cross_size: None,
+            render: None,
+        }
+    }
+
+    /// Limit the size across the stack, placed by [`StackProps::align`]
+    pub fn cross_size(mut self, size: u16) -> Self {
+        self.cross_size = Some(size);
+        self
+    }
+}
+
+impl std::fmt::Debug for StackItem<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StackItem")
+            .field("constraint", &self.constraint)
+            .field("cross_size", &self.cross_size)
+            .field("spacer", &self.render.is_none())
+            .finish()
+    }
+}
+
+/// Props for Stack component
+pub struct StackProps<'a> {
+    /// Children in order
+    pub items: Vec<StackItem<'a>>,
+    /// Vertical (rows) or horizontal (columns)
+    pub direction: Direction,
+    /// Cells between adjacent children
+    pub gap: u16,
+    /// Placement along the stack when children don't fill it
+    pub flex: Flex,
+    /// Placement across the stack of children with a `cross_size`
+    pub align: StackAlign,
+    /// Background style for the whole area
+    pub style: Style,
+}
+
+impl Default for StackProps<'_> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            direction: Direction::Vertical,
+            gap: 0,
+            flex: Flex::Start,
+            align: StackAlign::Start,
+            style: Style::default(),
+        }
+    }
+}
+
+impl<'a> StackProps<'a> {
+    /// Children stacked top to bottom
+    pub fn vertical(items: Vec<StackItem<'a>>) -> Self {
+        Self {
+            items,
+            ..Default::default()
+        }
+    }
+
+    /// Children placed left to right
+    pub fn horizontal(items: Vec<StackItem<'a>>) -> Self {
+        Self {
+            items,
+            direction: Direction::Horizontal,
+            ..Default::default()
+        }
+    }
+}
+
+/// Lays out children in a row or column and renders each in its area
+///
+/// A thin layer over ratatui's `Layout` for render functions: sizes, gaps
+/// and alignment sit next to the code that draws each child.
+///
+/// ```ignore
+/// let mut stack = Stack::new();
+/// stack.render(frame, area, StackProps {
+///     gap: 1,
+///     ..StackProps::vertical(vec![
+///         StackItem::length(1, |frame, area| header.render(frame, area, header_props)),
+///         StackItem::fill(1, |frame, area| list.render(frame, area, list_props)),
+///         StackItem::length(1, |frame, area| status.render(frame, area, status_props)),
+///     ])
+/// });
+/// ```
+///
+/// The areas from the last render are kept, so mouse events can be routed
+/// with [`child_at`](Self::child_at).
+#[derive(Debug, Default)]
+pub struct Stack {
+    areas: Vec<Rect>,
+}
+
+impl Stack {
+    /// Create a new Stack
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Child areas from the last render (spacers included)
+    pub fn areas(&self) -> &[Rect] {
+        &self.areas
+    }
+
+    /// Index of the child containing the cell at `column`, `row`
+    pub fn child_at(&self, column: u16, row: u16) -> Option<usize> {
+        self.areas
+            .iter()
+            .position(|area| area.contains((column, row).into()))
+    }
+}
+
+/// Shrink `area` across the stack to `size`, placed by `align`
+fn place_cross(area: Rect, direction: Direction, size: u16, align: StackAlign) -> Rect {
+    let (start, available) = match direction {
+        Direction::Vertical => (area.x, area.width),
+        Direction::Horizontal => (area.y, area.height),
+    };
+    let size = size.min(available);
+    let offset = match align {
+        StackAlign::Start => 0,
+        StackAlign::Center => (available - size) / 2,
+        StackAlign::End => available - size,
+    };
+    match direction {
+        Direction::Vertical => Rect {
+            x: start + offset,
+            width: size,
+            ..area
+        },
+        Direction::Horizontal => Rect {
+            y: start + offset,
+            height: size,
+            ..area
+        },
+    }
+}
+
+impl<A> Component<A> for Stack {
+    type Props<'a> = StackProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        if props.style != Style::default() {
+            frame.render_widget(Block::default().style(props.style), area);
+        }
+
+        let constraints: Vec<Constraint> = props.items.iter().map(|item| item.constraint).collect();
+        let areas = Layout::new(props.direction, constraints)
+            .spacing(props.gap)
+            .flex(props.flex)
+            .split(area);
+
+        self.areas.clear();
+        for (item, &rect) in props.items.into_iter().zip(areas.iter()) {
+            let rect = match item.cross_size {
+                Some(size) => place_cross(rect, props.direction, size, props.align),
+                None => rect,
+            };
+            self.areas.push(rect);
+            if let Some(render) = item.render {
+                if !rect.is_empty() {
+                    render(frame, rect);
+                }
+            }
+        }
+    }
+}
+
+/// Props for Spacer component
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpacerProps {
+    /// Fill style (default leaves the area untouched)
+    pub style: Style,
+}
+
+/// Empty space, optionally filled with a style
+///
+/// Inside a [`Stack`], [`StackItem::spacer`] is usually enough; render a
+/// `Spacer` when the gap needs a background.
+#[derive(Debug, Default)]
+pub struct Spacer;
+
+impl Spacer {
+    /// Create a new Spacer
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<A> Component<A> for Spacer {
+    type Props<'a> = SpacerProps;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        if props.style != Style::default() {
+            frame.render_widget(Block::default().style(props.style), area);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Paragraph;
+    use tui_dispatch_core::testing::RenderHarness;
+
+    fn text(label: &'static str) -> impl FnOnce(&mut Frame, Rect) {
+        move |frame, area| frame.render_widget(Paragraph::new(label), area)
+    }
+
+    fn render_stack(width: u16, height: u16, props: StackProps<'_>) -> (Stack, String) {
+        let mut render = RenderHarness::new(width, height);
+        let mut stack = Stack::new();
+        let output = render.render_to_string_plain(|frame| {
+            <Stack as Component<()>>::render(&mut stack, frame, frame.area(), props);
+        });
+        (stack, output)
+    }
+
+    #[test]
+    fn test_vertical_weights_and_gap() {
+        let (stack, output) = render_stack(
+            4,
+            6,
+            StackProps {
+                gap: 1,
+                ..StackProps::vertical(vec![
+                    StackItem::length(1, text("head")),
+                    StackItem::fill(1, text("body")),
+                    StackItem::length(1, text("foot")),
+                ])
+            },
+        );
+        assert_eq!(output, "head\n    \nbody\n    \n    \nfoot\n");
+        assert_eq!(stack.areas()[1], Rect::new(0, 2, 4, 2));
+        assert_eq!(stack.child_at(0, 3), Some(1));
+        assert_eq!(stack.child_at(0, 1), None);
+    }
+
+    #[test]
+    fn test_horizontal_flex_and_spacer() {
+        let (_, output) = render_stack(
+            11,
+            1,
+            StackProps {
+                flex: Flex::Center,
+                ..StackProps::horizontal(vec![
+                    StackItem::length(2, text("ab")),
+                    StackItem::spacer(Constraint::Length(1)),
+                    StackItem::length(2, text("cd")),
+                ])
+            },
+        );
+        assert_eq!(output, "   ab cd   \n");
+
+        let (_, output) = render_stack(
+            11,
+            1,
+            StackProps::horizontal(vec![
+                StackItem::fill(2, text("left")),
+                StackItem::spacer(Constraint::Fill(1)),
+                StackItem::length(2, text("r")),
+            ]),
+        );
+        assert_eq!(output, "left     r \n");
+    }
+
+    #[test]
+    fn test_cross_alignment() {
+        let (stack, output) = render_stack(
+            7,
+            2,
+            StackProps {
+                align: StackAlign::Center,
+                ..StackProps::vertical(vec![
+                    StackItem::length(1, text("ok")).cross_size(2),
+                    StackItem::length(1, text("x")).cross_size(20),
+                ])
+            },
+        );
+        assert_eq!(output, "  ok   \nx      \n");
+        assert_eq!(stack.areas()[0], Rect::new(2, 0, 2, 1));
+    }
+}
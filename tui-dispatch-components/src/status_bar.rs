@@ -0,0 +1,349 @@
+//! Status bar component
+
+use std::borrow::Cow;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Block,
+    Frame,
+};
+use tui_dispatch_core::Component;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Marker appended to truncated segments
+const ELLIPSIS: &str = "…";
+
+/// A piece of text in a status bar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusSegment<'a> {
+    /// Text to display
+    pub text: Cow<'a, str>,
+    /// Style patched over the bar style
+    pub style: Style,
+    /// Narrowest width the segment may be truncated to (None = never truncate)
+    pub min_width: Option<u16>,
+    /// Segments with lower priority are truncated and dropped first
+    pub priority: u8,
+}
+
+impl<'a> StatusSegment<'a> {
+    /// Create an unstyled segment with priority 0
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            text: text.into(),
+            style: Style::default(),
+            min_width: None,
+            priority: 0,
+        }
+    }
+
+    /// Set the segment style
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Allow truncating the segment down to `width` columns
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    /// Set the priority (higher survives longer when space is tight)
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Props for StatusBar component
+pub struct StatusBarProps<'a> {
+    /// Segments aligned to the left edge
+    pub left: &'a [StatusSegment<'a>],
+    /// Segments centered in the bar
+    pub center: &'a [StatusSegment<'a>],
+    /// Segments aligned to the right edge
+    pub right: &'a [StatusSegment<'a>],
+    /// Text between adjacent segments on the same side
+    pub separator: &'a str,
+    /// Base style for the whole bar
+    pub style: Style,
+}
+
+impl Default for StatusBarProps<'_> {
+    fn default() -> Self {
+        Self {
+            left: &[],
+            center: &[],
+            right: &[],
+            separator: " ",
+            style: Style::default().bg(Color::DarkGray),
+        }
+    }
+}
+
+/// A single-row bar with left, center and right segments
+///
+/// When the segments don't fit, truncatable segments (with a `min_width`)
+/// are shortened with an ellipsis, lowest priority first. If that is not
+/// enough, whole segments are dropped, again lowest priority first (ties
+/// drop the rightmost segment).
+#[derive(Default)]
+pub struct StatusBar;
+
+impl StatusBar {
+    /// Create a new StatusBar
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Width bookkeeping for one segment while fitting the bar
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    side: usize,
+    width: usize,
+    min: usize,
+    priority: u8,
+    visible: bool,
+}
+
+/// Columns needed by the visible slots, per side and in total
+fn needed(slots: &[Slot], separator: usize) -> ([usize; 3], usize) {
+    let mut sides = [0; 3];
+    let mut counts = [0; 3];
+    for slot in slots.iter().filter(|slot| slot.visible) {
+        sides[slot.side] += slot.width;
+        counts[slot.side] += 1;
+    }
+    for (width, count) in sides.iter_mut().zip(counts) {
+        *width += separator * count.saturating_sub(1);
+    }
+    let gaps = counts.iter().filter(|&&count| count > 0).count();
+    (sides, sides.iter().sum::<usize>() + gaps.saturating_sub(1))
+}
+
+/// Shrink or hide slots until they fit in `available` columns
+fn fit(slots: &mut [Slot], separator: usize, available: usize) {
+    // Lowest priority first; among equals, the rightmost first
+    let mut order: Vec<usize> = (0..slots.len()).collect();
+    order.sort_by_key(|&i| (slots[i].priority, std::cmp::Reverse(i)));
+
+    loop {
+        let (_, total) = needed(slots, separator);
+        if total <= available {
+            return;
+        }
+
+        let slack: usize = slots
+            .iter()
+            .filter(|slot| slot.visible)
+            .map(|slot| slot.width - slot.min)
+            .sum();
+        if total - slack <= available {
+            let mut excess = total - available;
+            for &i in &order {
+                let slot = &mut slots[i];
+                if !slot.visible || excess == 0 {
+                    continue;
+                }
+                let cut = (slot.width - slot.min).min(excess);
+                slot.width -= cut;
+                excess -= cut;
+            }
+            return;
+        }
+
+        match order.iter().find(|&&i| slots[i].visible) {
+            Some(&i) => slots[i].visible = false,
+            None => return,
+        }
+    }
+}
+
+/// Truncate text to `width` columns, ending with an ellipsis when cut
+pub(crate) fn truncate(text: &str, width: usize) -> Cow<'_, str> {
+    if text.width() <= width {
+        return Cow::Borrowed(text);
+    }
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+
+    let budget = width - ELLIPSIS.width();
+    let mut out = String::new();
+    let mut used = 0;
+    for grapheme in text.graphemes(true) {
+        let w = grapheme.width();
+        if used + w > budget {
+            break;
+        }
+        out.push_str(grapheme);
+        used += w;
+    }
+    out.push_str(ELLIPSIS);
+    Cow::Owned(out)
+}
+
+impl<A> Component<A> for StatusBar {
+    type Props<'a> = StatusBarProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        frame.render_widget(Block::default().style(props.style), area);
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let sides = [props.left, props.center, props.right];
+        let mut slots: Vec<Slot> = Vec::new();
+        for (side, segments) in sides.iter().enumerate() {
+            for segment in segments.iter() {
+                let width = segment.text.width();
+                slots.push(Slot {
+                    side,
+                    width,
+                    min: segment
+                        .min_width
+                        .map_or(width, |min| (min as usize).max(1).min(width)),
+                    priority: segment.priority,
+                    visible: width > 0,
+                });
+            }
+        }
+
+        let separator_width = props.separator.width();
+        fit(&mut slots, separator_width, area.width as usize);
+        let (widths, _) = needed(&slots, separator_width);
+
+        let mut slot_iter = slots.iter();
+        let mut lines: [Vec<Span>; 3] = Default::default();
+        for (side, segments) in sides.iter().enumerate() {
+            for segment in segments.iter() {
+                let slot = slot_iter.next().expect("one slot per segment");
+                if !slot.visible {
+                    continue;
+                }
+                if !lines[side].is_empty() {
+                    lines[side].push(Span::raw(props.separator));
+                }
+                lines[side].push(Span::styled(
+                    truncate(&segment.text, slot.width),
+                    segment.style,
+                ));
+            }
+        }
+
+        let [left_width, center_width, right_width] = widths.map(|w| w as u16);
+        let [left, center, right] = lines;
+        let row = Rect { height: 1, ..area };
+
+        let left_end = if left_width > 0 { left_width + 1 } else { 0 };
+        let right_start = area.width.saturating_sub(right_width);
+        let right_limit = if right_width > 0 {
+            right_start.saturating_sub(1)
+        } else {
+            area.width
+        };
+        let center_x = area
+            .width
+            .saturating_sub(center_width)
+            .div_ceil(2)
+            .max(left_end)
+            .min(right_limit.saturating_sub(center_width));
+
+        for (x, width, spans) in [
+            (0, left_width, left),
+            (center_x, center_width, center),
+            (right_start, right_width, right),
+        ] {
+            if width == 0 {
+                continue;
+            }
+            let rect = Rect {
+                x: row.x + x,
+                width,
+                ..row
+            };
+            frame.render_widget(Line::from(spans), rect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_dispatch_core::testing::RenderHarness;
+
+    fn render_bar(width: u16, props: StatusBarProps<'_>) -> String {
+        let mut render = RenderHarness::new(width, 1);
+        let mut bar = StatusBar::new();
+        render.render_to_string_plain(|frame| {
+            <StatusBar as Component<()>>::render(&mut bar, frame, frame.area(), props);
+        })
+    }
+
+    #[test]
+    fn test_alignment() {
+        let left = [StatusSegment::new("NORMAL")];
+        let center = [StatusSegment::new("main.rs")];
+        let right = [StatusSegment::new("1:1")];
+        let output = render_bar(
+            21,
+            StatusBarProps {
+                left: &left,
+                center: &center,
+                right: &right,
+                ..Default::default()
+            },
+        );
+        assert_eq!(output.trim_end_matches('\n'), "NORMAL main.rs    1:1");
+    }
+
+    #[test]
+    fn test_truncates_lowest_priority_first() {
+        let left = [
+            StatusSegment::new("docs/guide/intro.md")
+                .min_width(6)
+                .priority(1),
+            StatusSegment::new("[+]").priority(2),
+        ];
+        let right = [StatusSegment::new("42%").priority(3)];
+        let output = render_bar(
+            20,
+            StatusBarProps {
+                left: &left,
+                right: &right,
+                ..Default::default()
+            },
+        );
+        assert_eq!(output.trim_end_matches('\n'), "docs/guide/… [+] 42%");
+    }
+
+    #[test]
+    fn test_drops_segments_when_tight() {
+        let left = [StatusSegment::new("mode").priority(5)];
+        let right = [
+            StatusSegment::new("j/k:scroll q:quit").priority(0),
+            StatusSegment::new("3/10").priority(4),
+        ];
+        let output = render_bar(
+            12,
+            StatusBarProps {
+                left: &left,
+                right: &right,
+                ..Default::default()
+            },
+        );
+        assert_eq!(output.trim_end_matches('\n'), "mode    3/10");
+    }
+
+    #[test]
+    fn test_truncate_wide_text() {
+        assert_eq!(truncate("日本語テキスト", 7), "日本語…");
+        assert_eq!(truncate("abc", 3), "abc");
+        assert_eq!(truncate("abc", 1), "…");
+    }
+}
@@ -0,0 +1,394 @@
+//! Tab bar component
+
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind};
+use unicode_width::UnicodeWidthStr;
+
+/// Separator rendered between tab titles
+const DIVIDER: &str = "│";
+/// Close button rendered after a tab title when closing is enabled
+const CLOSE_SYMBOL: &str = "×";
+
+/// Props for Tabs component
+pub struct TabsProps<'a, A> {
+    /// Tab titles
+    pub titles: &'a [String],
+    /// Currently active tab index
+    pub selected: usize,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Callback to create action when a tab is selected
+    pub on_select: fn(usize) -> A,
+    /// Callback to create action when a tab is closed (None = no close buttons)
+    pub on_close: Option<fn(usize) -> A>,
+}
+
+/// Clickable region of a rendered tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TabHitArea {
+    /// Tab title area (including padding)
+    title: Rect,
+    /// Close button area, if rendered
+    close: Option<Rect>,
+}
+
+/// A horizontal tab bar with keyboard and mouse navigation
+///
+/// Handles Tab/Shift+Tab for cycling, 1-9 for direct selection and
+/// `x` for closing the active tab (when `on_close` is set). Mouse clicks
+/// select tabs or hit their close buttons.
+#[derive(Default)]
+pub struct Tabs {
+    /// Hit areas from the last render, used for mouse handling
+    hit_areas: Vec<TabHitArea>,
+}
+
+impl Tabs {
+    /// Create a new Tabs component
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Emit a select action if the index differs from the current one
+    fn select<A>(props: &TabsProps<'_, A>, index: usize) -> Option<A> {
+        if index != props.selected && index < props.titles.len() {
+            Some((props.on_select)(index))
+        } else {
+            None
+        }
+    }
+
+    /// Handle a mouse click at the given position
+    fn handle_click<A>(&self, props: &TabsProps<'_, A>, column: u16, row: u16) -> Option<A> {
+        let contains = |area: Rect| {
+            column >= area.x
+                && column < area.x.saturating_add(area.width)
+                && row >= area.y
+                && row < area.y.saturating_add(area.height)
+        };
+
+        for (index, hit) in self.hit_areas.iter().enumerate() {
+            if let (Some(close), Some(on_close)) = (hit.close, props.on_close) {
+                if contains(close) {
+                    return Some(on_close(index));
+                }
+            }
+            if contains(hit.title) {
+                return Self::select(props, index);
+            }
+        }
+        None
+    }
+}
+
+impl<A> Component<A> for Tabs {
+    type Props<'a> = TabsProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        if props.titles.is_empty() {
+            return None;
+        }
+
+        let len = props.titles.len();
+
+        match event {
+            EventKind::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                self.handle_click(&props, mouse.column, mouse.row)
+            }
+            EventKind::Key(key) if props.is_focused => match key.code {
+                // Next tab (wraps around)
+                KeyCode::Tab => Self::select(&props, (props.selected % len + 1) % len),
+                // Previous tab (wraps around)
+                KeyCode::BackTab => Self::select(&props, (props.selected % len + len - 1) % len),
+                // Direct selection (1-based)
+                KeyCode::Char(c @ '1'..='9') => {
+                    let index = c as usize - '1' as usize;
+                    Self::select(&props, index)
+                }
+                // Close active tab
+                KeyCode::Char('x') => props.on_close.map(|on_close| on_close(props.selected)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let inner = if props.show_border {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(if props.is_focused {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                });
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            inner
+        } else {
+            area
+        };
+
+        self.hit_areas.clear();
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let divider_style = Style::default().fg(Color::DarkGray);
+        let mut spans = Vec::new();
+        let mut x = inner.x;
+        let right = inner.x.saturating_add(inner.width);
+
+        for (i, title) in props.titles.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(DIVIDER, divider_style));
+                x = x.saturating_add(1);
+            }
+
+            let is_selected = i == props.selected;
+            let style = if is_selected {
+                let style = Style::default().add_modifier(Modifier::BOLD);
+                if props.is_focused {
+                    style.fg(Color::Cyan)
+                } else {
+                    style.fg(Color::White)
+                }
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+
+            let label = format!(" {title} ");
+            let label_width = label.width() as u16;
+            let title_area = Rect::new(x, inner.y, label_width.min(right.saturating_sub(x)), 1);
+            spans.push(Span::styled(label, style));
+            x = x.saturating_add(label_width);
+
+            let close = if props.on_close.is_some() {
+                let close_area = Rect::new(x, inner.y, 1u16.min(right.saturating_sub(x)), 1);
+                spans.push(Span::styled(
+                    CLOSE_SYMBOL,
+                    Style::default().fg(Color::DarkGray),
+                ));
+                spans.push(Span::raw(" "));
+                x = x.saturating_add(2);
+                Some(close_area)
+            } else {
+                None
+            };
+
+            self.hit_areas.push(TabHitArea {
+                title: title_area,
+                close,
+            });
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), inner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyModifiers, MouseEvent};
+    use tui_dispatch_core::testing::{key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Select(usize),
+        Close(usize),
+    }
+
+    fn make_titles() -> Vec<String> {
+        vec!["One".into(), "Two".into(), "Three".into()]
+    }
+
+    fn props(titles: &[String], selected: usize) -> TabsProps<'_, TestAction> {
+        TabsProps {
+            titles,
+            selected,
+            is_focused: true,
+            show_border: false,
+            on_select: TestAction::Select,
+            on_close: Some(TestAction::Close),
+        }
+    }
+
+    fn click(column: u16, row: u16) -> EventKind {
+        EventKind::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_tab_cycles_forward() {
+        let mut tabs = Tabs::new();
+        let titles = make_titles();
+
+        let actions: Vec<_> = tabs
+            .handle_event(&EventKind::Key(key("tab")), props(&titles, 2))
+            .into_iter()
+            .collect();
+
+        assert_eq!(actions, vec![TestAction::Select(0)]);
+    }
+
+    #[test]
+    fn test_shift_tab_cycles_backward() {
+        let mut tabs = Tabs::new();
+        let titles = make_titles();
+
+        let actions: Vec<_> = tabs
+            .handle_event(&EventKind::Key(key("shift+tab")), props(&titles, 0))
+            .into_iter()
+            .collect();
+
+        assert_eq!(actions, vec![TestAction::Select(2)]);
+    }
+
+    #[test]
+    fn test_number_keys_select() {
+        let mut tabs = Tabs::new();
+        let titles = make_titles();
+
+        let actions: Vec<_> = tabs
+            .handle_event(&EventKind::Key(key("2")), props(&titles, 0))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Select(1)]);
+
+        // Out of range and already-selected indices are ignored
+        let actions: Vec<_> = tabs
+            .handle_event(&EventKind::Key(key("9")), props(&titles, 0))
+            .into_iter()
+            .collect();
+        assert!(actions.is_empty());
+
+        let actions: Vec<_> = tabs
+            .handle_event(&EventKind::Key(key("1")), props(&titles, 0))
+            .into_iter()
+            .collect();
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_close_active_tab() {
+        let mut tabs = Tabs::new();
+        let titles = make_titles();
+
+        let actions: Vec<_> = tabs
+            .handle_event(&EventKind::Key(key("x")), props(&titles, 1))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Close(1)]);
+
+        // Without on_close, x does nothing
+        let mut no_close = props(&titles, 1);
+        no_close.on_close = None;
+        let actions: Vec<_> = tabs
+            .handle_event(&EventKind::Key(key("x")), no_close)
+            .into_iter()
+            .collect();
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_unfocused_ignores_keys() {
+        let mut tabs = Tabs::new();
+        let titles = make_titles();
+        let mut p = props(&titles, 0);
+        p.is_focused = false;
+
+        let actions: Vec<_> = tabs
+            .handle_event(&EventKind::Key(key("tab")), p)
+            .into_iter()
+            .collect();
+
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_mouse_click_selects_and_closes() {
+        let mut render = RenderHarness::new(40, 1);
+        let mut tabs = Tabs::new();
+        let titles = make_titles();
+
+        render.render(|frame| {
+            tabs.render(frame, frame.area(), props(&titles, 0));
+        });
+
+        // Layout: " One " "×" " " "│" " Two " ...
+        // " Two " starts at column 8
+        let actions: Vec<_> = tabs
+            .handle_event(&click(9, 0), props(&titles, 0))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Select(1)]);
+
+        // Close button for first tab is at column 5
+        let actions: Vec<_> = tabs
+            .handle_event(&click(5, 0), props(&titles, 0))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Close(0)]);
+    }
+
+    #[test]
+    fn test_mouse_click_wide_titles() {
+        let mut render = RenderHarness::new(40, 1);
+        let mut tabs = Tabs::new();
+        let titles = vec!["日本".to_string(), "Two".to_string()];
+
+        render.render(|frame| {
+            tabs.render(frame, frame.area(), props(&titles, 0));
+        });
+
+        // " 日本 " is 6 columns wide, so its close button is at column 6
+        let actions: Vec<_> = tabs
+            .handle_event(&click(6, 0), props(&titles, 0))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Close(0)]);
+
+        let actions: Vec<_> = tabs
+            .handle_event(&click(10, 0), props(&titles, 0))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Select(1)]);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut render = RenderHarness::new(40, 3);
+        let mut tabs = Tabs::new();
+        let titles = make_titles();
+
+        let output = render.render_to_string_plain(|frame| {
+            let mut p = props(&titles, 1);
+            p.show_border = true;
+            tabs.render(frame, frame.area(), p);
+        });
+
+        assert!(output.contains("One"));
+        assert!(output.contains("Two"));
+        assert!(output.contains("Three"));
+        assert!(output.contains(CLOSE_SYMBOL));
+    }
+}
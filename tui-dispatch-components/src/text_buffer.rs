@@ -0,0 +1,620 @@
+//! Rope-backed text buffer with a cursor, selection and undo history
+
+use std::ops::Range;
+
+use ropey::Rope;
+
+/// Zero-based line and column (in chars) of a position in a [`TextBuffer`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TextPosition {
+    /// Line index
+    pub line: usize,
+    /// Char offset within the line
+    pub column: usize,
+}
+
+/// Replacement of a char range, the unit every edit is made of
+///
+/// [`TextBuffer::execute`] returns the patch for each change, and
+/// [`TextBuffer::apply`] replays one, so edits can be logged, synced to
+/// another buffer or forwarded to a language server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextPatch {
+    /// Char index where the change starts
+    pub start: usize,
+    /// Text removed at `start`
+    pub removed: String,
+    /// Text inserted at `start`
+    pub inserted: String,
+}
+
+impl TextPatch {
+    /// Insert `text` at char index `at`
+    pub fn insert(at: usize, text: impl Into<String>) -> Self {
+        Self {
+            start: at,
+            removed: String::new(),
+            inserted: text.into(),
+        }
+    }
+
+    /// The patch that reverts this one
+    pub fn inverse(&self) -> Self {
+        Self {
+            start: self.start,
+            removed: self.inserted.clone(),
+            inserted: self.removed.clone(),
+        }
+    }
+
+    /// Char range of the removed text before the patch
+    pub fn removed_range(&self) -> Range<usize> {
+        self.start..self.start + self.removed.chars().count()
+    }
+
+    /// Char range of the inserted text after the patch
+    pub fn inserted_range(&self) -> Range<usize> {
+        self.start..self.start + self.inserted.chars().count()
+    }
+
+    /// Whether typing `next` right after this patch continues the same word
+    fn continues_with(&self, next: &TextPatch) -> bool {
+        self.removed.is_empty()
+            && next.removed.is_empty()
+            && self.inserted_range().end == next.start
+            && !self.inserted.ends_with(char::is_whitespace)
+    }
+}
+
+/// Cursor movement for [`EditorCommand::Move`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    /// One char left
+    Left,
+    /// One char right
+    Right,
+    /// To the start of the previous word
+    WordLeft,
+    /// To the end of the next word
+    WordRight,
+    /// Up a number of lines, keeping the column
+    Up(usize),
+    /// Down a number of lines, keeping the column
+    Down(usize),
+    /// To the start of the line
+    LineStart,
+    /// To the end of the line
+    LineEnd,
+    /// To the start of the buffer
+    BufferStart,
+    /// To the end of the buffer
+    BufferEnd,
+}
+
+/// An editing operation on a [`TextBuffer`]
+///
+/// [`TextEditor`](crate::TextEditor) turns keys into commands; the app
+/// dispatches them as actions and its reducer runs
+/// [`TextBuffer::execute`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorCommand {
+    /// Type or paste text, replacing the selection
+    Insert(String),
+    /// Delete the selection or the char before the cursor
+    DeleteBackward,
+    /// Delete the selection or the char after the cursor
+    DeleteForward,
+    /// Delete the selection or back to the start of the word
+    DeleteWordBackward,
+    /// Move the cursor; `extend` grows the selection instead of clearing it
+    Move { motion: Motion, extend: bool },
+    /// Put the cursor at a position, e.g. from a mouse click
+    SetCursor {
+        position: TextPosition,
+        extend: bool,
+    },
+    /// Select the whole buffer
+    SelectAll,
+    /// Revert the last change
+    Undo,
+    /// Reapply the last reverted change
+    Redo,
+}
+
+/// Text storage for editor-like apps
+///
+/// Backed by a [`Rope`], so edits and line lookups stay fast on large
+/// files. Tracks one cursor with an optional selection (between the anchor
+/// and the cursor), an undo history of [`TextPatch`]es and a version that
+/// changes with every edit. Positions are char indices.
+///
+/// Keep the buffer in app state and route [`EditorCommand`]s to it from
+/// the reducer:
+///
+/// ```ignore
+/// Action::Editor(command) => {
+///     let patch = state.buffer.execute(command);
+///     // Cursor moves change no text but still need a redraw
+///     true
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TextBuffer {
+    rope: Rope,
+    cursor: usize,
+    /// Other end of the selection
+    anchor: Option<usize>,
+    /// Column kept while moving up and down through shorter lines
+    goal_column: Option<usize>,
+    undo: Vec<TextPatch>,
+    redo: Vec<TextPatch>,
+    version: u64,
+    saved_version: u64,
+}
+
+impl TextBuffer {
+    /// Create an empty buffer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The underlying rope
+    pub fn rope(&self) -> &Rope {
+        &self.rope
+    }
+
+    /// The whole text
+    pub fn text(&self) -> String {
+        self.rope.to_string()
+    }
+
+    /// Whether the buffer has no text
+    pub fn is_empty(&self) -> bool {
+        self.rope.len_chars() == 0
+    }
+
+    /// Number of chars
+    pub fn len_chars(&self) -> usize {
+        self.rope.len_chars()
+    }
+
+    /// Number of lines (an empty buffer has one)
+    pub fn len_lines(&self) -> usize {
+        self.rope.len_lines()
+    }
+
+    /// Text of a line without its line break (empty past the end)
+    pub fn line(&self, index: usize) -> String {
+        if index >= self.rope.len_lines() {
+            return String::new();
+        }
+        let line = self.rope.line(index).to_string();
+        line.trim_end_matches(['\n', '\r']).to_string()
+    }
+
+    /// Number of chars in a line, without its line break
+    pub fn line_len(&self, index: usize) -> usize {
+        self.line(index).chars().count()
+    }
+
+    /// Char index of the cursor
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Line and column of the cursor
+    pub fn cursor_position(&self) -> TextPosition {
+        self.position_of(self.cursor)
+    }
+
+    /// Line and column of a char index (clamped to the buffer)
+    pub fn position_of(&self, index: usize) -> TextPosition {
+        let index = index.min(self.rope.len_chars());
+        let line = self.rope.char_to_line(index);
+        TextPosition {
+            line,
+            column: index - self.rope.line_to_char(line),
+        }
+    }
+
+    /// Char index of a line and column (clamped to the line)
+    pub fn index_of(&self, position: TextPosition) -> usize {
+        let line = position.line.min(self.rope.len_lines() - 1);
+        self.rope.line_to_char(line) + position.column.min(self.line_len(line))
+    }
+
+    /// Selected char range, if any text is selected
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor?;
+        let range = anchor.min(self.cursor)..anchor.max(self.cursor);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// Selected text, if any
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection()
+            .map(|range| self.rope.slice(range).to_string())
+    }
+
+    /// Counter bumped by every edit, for memoizing derived data
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Whether the text changed since [`mark_saved`](Self::mark_saved)
+    pub fn is_modified(&self) -> bool {
+        self.version != self.saved_version
+    }
+
+    /// Remember the current text as saved
+    pub fn mark_saved(&mut self) {
+        self.saved_version = self.version;
+    }
+
+    /// Whether there is a change to undo
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Whether there is an undone change to redo
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Run a command, returning the patch if the text changed
+    pub fn execute(&mut self, command: EditorCommand) -> Option<TextPatch> {
+        match command {
+            EditorCommand::Insert(text) => self.replace_selection(&text),
+            EditorCommand::DeleteBackward => match self.selection() {
+                Some(range) => self.replace(range, ""),
+                None => {
+                    let start = self.prev_char(self.cursor);
+                    self.replace(start..self.cursor, "")
+                }
+            },
+            EditorCommand::DeleteForward => match self.selection() {
+                Some(range) => self.replace(range, ""),
+                None => {
+                    let end = self.next_char(self.cursor);
+                    self.replace(self.cursor..end, "")
+                }
+            },
+            EditorCommand::DeleteWordBackward => match self.selection() {
+                Some(range) => self.replace(range, ""),
+                None => {
+                    let start = self.word_left(self.cursor);
+                    self.replace(start..self.cursor, "")
+                }
+            },
+            EditorCommand::Move { motion, extend } => {
+                self.move_cursor(motion, extend);
+                None
+            }
+            EditorCommand::SetCursor { position, extend } => {
+                let index = self.index_of(position);
+                self.goal_column = None;
+                self.move_to(index, extend);
+                None
+            }
+            EditorCommand::SelectAll => {
+                self.anchor = Some(0);
+                self.cursor = self.rope.len_chars();
+                self.goal_column = None;
+                None
+            }
+            EditorCommand::Undo => self.undo(),
+            EditorCommand::Redo => self.redo(),
+        }
+    }
+
+    /// Apply a patch made elsewhere, e.g. by another buffer or a peer
+    ///
+    /// Returns false and leaves the buffer unchanged if `patch.removed`
+    /// does not match the text at `patch.start`. Applied patches can be
+    /// undone like local edits.
+    pub fn apply(&mut self, patch: &TextPatch) -> bool {
+        let range = patch.removed_range();
+        if range.end > self.rope.len_chars() || self.rope.slice(range) != patch.removed.as_str() {
+            return false;
+        }
+        self.record(patch.clone());
+        true
+    }
+
+    /// Revert the last change, returning the patch that reverted it
+    pub fn undo(&mut self) -> Option<TextPatch> {
+        let patch = self.undo.pop()?;
+        let inverse = patch.inverse();
+        self.write(&inverse);
+        self.redo.push(patch);
+        Some(inverse)
+    }
+
+    /// Reapply the last reverted change
+    pub fn redo(&mut self) -> Option<TextPatch> {
+        let patch = self.redo.pop()?;
+        self.write(&patch);
+        self.undo.push(patch.clone());
+        Some(patch)
+    }
+
+    fn replace_selection(&mut self, text: &str) -> Option<TextPatch> {
+        let range = self.selection().unwrap_or(self.cursor..self.cursor);
+        self.replace(range, text)
+    }
+
+    fn replace(&mut self, range: Range<usize>, text: &str) -> Option<TextPatch> {
+        if range.is_empty() && text.is_empty() {
+            return None;
+        }
+        let patch = TextPatch {
+            start: range.start,
+            removed: self.rope.slice(range).to_string(),
+            inserted: text.to_string(),
+        };
+        self.record(patch.clone());
+        Some(patch)
+    }
+
+    /// Apply a new edit and add it to the undo history
+    fn record(&mut self, patch: TextPatch) {
+        self.write(&patch);
+        self.redo.clear();
+        match self.undo.last_mut() {
+            // Typing a word is undone as one change
+            Some(last) if last.continues_with(&patch) => last.inserted.push_str(&patch.inserted),
+            _ => self.undo.push(patch),
+        }
+    }
+
+    /// Change the text and put the cursor after the inserted text
+    fn write(&mut self, patch: &TextPatch) {
+        self.rope.remove(patch.removed_range());
+        self.rope.insert(patch.start, &patch.inserted);
+        self.cursor = patch.inserted_range().end;
+        self.anchor = None;
+        self.goal_column = None;
+        self.version += 1;
+    }
+
+    fn move_cursor(&mut self, motion: Motion, extend: bool) {
+        let position = self.cursor_position();
+        let target = match motion {
+            // Collapse a selection to the side the cursor moves towards
+            Motion::Left if !extend && self.selection().is_some() => {
+                self.selection().map_or(self.cursor, |range| range.start)
+            }
+            Motion::Right if !extend && self.selection().is_some() => {
+                self.selection().map_or(self.cursor, |range| range.end)
+            }
+            Motion::Left => self.prev_char(self.cursor),
+            Motion::Right => self.next_char(self.cursor),
+            Motion::WordLeft => self.word_left(self.cursor),
+            Motion::WordRight => self.word_right(self.cursor),
+            Motion::Up(lines) | Motion::Down(lines) => {
+                let column = *self.goal_column.get_or_insert(position.column);
+                let last = self.rope.len_lines() - 1;
+                let index = match motion {
+                    Motion::Up(_) if position.line == 0 => 0,
+                    Motion::Up(_) => self.index_of(TextPosition {
+                        line: position.line.saturating_sub(lines),
+                        column,
+                    }),
+                    _ if position.line == last => self.rope.len_chars(),
+                    _ => self.index_of(TextPosition {
+                        line: (position.line + lines).min(last),
+                        column,
+                    }),
+                };
+                self.move_to(index, extend);
+                return;
+            }
+            Motion::LineStart => self.rope.line_to_char(position.line),
+            Motion::LineEnd => self.index_of(TextPosition {
+                line: position.line,
+                column: usize::MAX,
+            }),
+            Motion::BufferStart => 0,
+            Motion::BufferEnd => self.rope.len_chars(),
+        };
+        self.goal_column = None;
+        self.move_to(target, extend);
+    }
+
+    fn move_to(&mut self, index: usize, extend: bool) {
+        if extend {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = index.min(self.rope.len_chars());
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        (index < self.rope.len_chars()).then(|| self.rope.char(index))
+    }
+
+    /// Index one char back, treating `\r\n` as one char
+    fn prev_char(&self, index: usize) -> usize {
+        if index >= 2
+            && self.char_at(index - 2) == Some('\r')
+            && self.char_at(index - 1) == Some('\n')
+        {
+            index - 2
+        } else {
+            index.saturating_sub(1)
+        }
+    }
+
+    /// Index one char forward, treating `\r\n` as one char
+    fn next_char(&self, index: usize) -> usize {
+        if self.char_at(index) == Some('\r') && self.char_at(index + 1) == Some('\n') {
+            index + 2
+        } else {
+            (index + 1).min(self.rope.len_chars())
+        }
+    }
+
+    fn word_left(&self, mut index: usize) -> usize {
+        while index > 0 && self.char_at(index - 1).is_some_and(char::is_whitespace) {
+            index -= 1;
+        }
+        let class = index
+            .checked_sub(1)
+            .and_then(|i| self.char_at(i))
+            .map(CharClass::of);
+        while index > 0 && self.char_at(index - 1).map(CharClass::of) == class {
+            index -= 1;
+        }
+        index
+    }
+
+    fn word_right(&self, mut index: usize) -> usize {
+        while self.char_at(index).is_some_and(char::is_whitespace) {
+            index += 1;
+        }
+        let class = self.char_at(index).map(CharClass::of);
+        while class.is_some() && self.char_at(index).map(CharClass::of) == class {
+            index += 1;
+        }
+        index
+    }
+}
+
+impl From<&str> for TextBuffer {
+    fn from(text: &str) -> Self {
+        Self {
+            rope: Rope::from_str(text),
+            ..Self::default()
+        }
+    }
+}
+
+impl From<String> for TextBuffer {
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+/// Runs of chars of one class make up a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Word,
+    Punctuation,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_whitespace() {
+            Self::Whitespace
+        } else if c.is_alphanumeric() || c == '_' {
+            Self::Word
+        } else {
+            Self::Punctuation
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(buffer: &mut TextBuffer, text: &str) -> Option<TextPatch> {
+        buffer.execute(EditorCommand::Insert(text.into()))
+    }
+
+    fn move_by(buffer: &mut TextBuffer, motion: Motion, extend: bool) {
+        buffer.execute(EditorCommand::Move { motion, extend });
+    }
+
+    #[test]
+    fn test_insert_and_delete_produce_patches() {
+        let mut buffer = TextBuffer::from("hello world");
+        move_by(&mut buffer, Motion::WordRight, false);
+
+        assert_eq!(insert(&mut buffer, ","), Some(TextPatch::insert(5, ",")));
+        assert_eq!(buffer.text(), "hello, world");
+        assert_eq!(buffer.cursor(), 6);
+
+        let patch = buffer.execute(EditorCommand::DeleteWordBackward).unwrap();
+        assert_eq!(patch.removed, ",");
+        let patch = buffer.execute(EditorCommand::DeleteWordBackward).unwrap();
+        assert_eq!((patch.start, patch.removed.as_str()), (0, "hello"));
+        assert_eq!(buffer.text(), " world");
+        assert_eq!(buffer.execute(EditorCommand::DeleteBackward), None);
+        assert!(buffer.is_modified());
+    }
+
+    #[test]
+    fn test_selection_is_replaced() {
+        let mut buffer = TextBuffer::from("one two");
+        move_by(&mut buffer, Motion::BufferEnd, false);
+        move_by(&mut buffer, Motion::WordLeft, true);
+        assert_eq!(buffer.selected_text().as_deref(), Some("two"));
+
+        insert(&mut buffer, "2");
+        assert_eq!(buffer.text(), "one 2");
+        assert_eq!(buffer.selection(), None);
+
+        buffer.execute(EditorCommand::SelectAll);
+        buffer.execute(EditorCommand::DeleteForward);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_vertical_motion_keeps_column() {
+        let mut buffer = TextBuffer::from("long line\nab\nanother line");
+        move_by(&mut buffer, Motion::LineEnd, false);
+        move_by(&mut buffer, Motion::Left, false);
+
+        move_by(&mut buffer, Motion::Down(1), false);
+        assert_eq!(
+            buffer.cursor_position(),
+            TextPosition { line: 1, column: 2 }
+        );
+        move_by(&mut buffer, Motion::Down(1), false);
+        assert_eq!(
+            buffer.cursor_position(),
+            TextPosition { line: 2, column: 8 }
+        );
+        move_by(&mut buffer, Motion::Down(5), false);
+        assert_eq!(buffer.cursor(), buffer.len_chars());
+        move_by(&mut buffer, Motion::Up(10), false);
+        assert_eq!(buffer.cursor(), 0);
+    }
+
+    #[test]
+    fn test_undo_groups_words() {
+        let mut buffer = TextBuffer::new();
+        for c in "ab cd".chars() {
+            insert(&mut buffer, &c.to_string());
+        }
+        assert_eq!(buffer.text(), "ab cd");
+
+        assert_eq!(buffer.undo().unwrap().removed, "cd");
+        assert_eq!(buffer.text(), "ab ");
+        buffer.undo();
+        assert!(buffer.is_empty());
+        assert!(!buffer.can_undo());
+
+        buffer.execute(EditorCommand::Redo);
+        assert_eq!(buffer.text(), "ab ");
+        insert(&mut buffer, "x");
+        assert!(!buffer.can_redo());
+    }
+
+    #[test]
+    fn test_apply_checks_removed_text() {
+        let mut source = TextBuffer::from("a\r\nb");
+        let mut copy = source.clone();
+        move_by(&mut source, Motion::Down(1), false);
+        let patch = source.execute(EditorCommand::DeleteBackward).unwrap();
+        assert_eq!(patch.removed, "\r\n");
+
+        assert!(copy.apply(&patch));
+        assert_eq!(copy.text(), "ab");
+        assert!(!copy.apply(&patch));
+        assert_eq!(copy.line(0), "ab");
+    }
+}
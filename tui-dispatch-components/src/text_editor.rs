@@ -0,0 +1,400 @@
+//! Multi-line text editor view over a TextBuffer
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::Rect,
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use tui_dispatch_core::{Component, EventKind, Theme};
+use unicode_width::UnicodeWidthChar;
+
+use crate::text_buffer::{EditorCommand, Motion, TextBuffer, TextPosition};
+
+/// Lines moved per mouse wheel tick
+const WHEEL_STEP: usize = 3;
+
+/// Spaces inserted by Tab
+const TAB: &str = "    ";
+
+/// Props for TextEditor component
+pub struct TextEditorProps<'a, A> {
+    /// Text, cursor and selection (owned by app state)
+    pub buffer: &'a TextBuffer,
+    /// Whether this component has focus
+    pub is_focused: bool,
+    /// Whether to show border
+    pub show_border: bool,
+    /// Show line numbers in a gutter
+    pub line_numbers: bool,
+    /// Text shown while the buffer is empty
+    pub placeholder: &'a str,
+    /// Colors for the border, gutter and text
+    pub theme: &'a Theme,
+    /// Callback with each editing command, to run with
+    /// [`TextBuffer::execute`] in the reducer
+    pub on_command: fn(EditorCommand) -> A,
+}
+
+/// A multi-line text editor
+///
+/// Renders a [`TextBuffer`] and turns input into [`EditorCommand`]s; the
+/// buffer itself lives in app state and only changes in the reducer. Keeps
+/// the cursor in view by scrolling both ways.
+///
+/// Keys when focused: typing, Enter and Tab insert; Backspace/Delete and
+/// Ctrl+Backspace or Ctrl+W delete; arrows, Home/End and PageUp/PageDown
+/// move, by word or to the buffer ends with Ctrl, and select with Shift;
+/// Ctrl+A selects all; Ctrl+Z and Ctrl+Y undo and redo. Paste inserts the
+/// pasted text, a click moves the cursor (Shift+click selects) and the
+/// mouse wheel moves it three lines.
+#[derive(Default)]
+pub struct TextEditor {
+    /// First visible line
+    scroll_row: usize,
+    /// First visible display column
+    scroll_col: usize,
+    /// Text area from the last render (without border and gutter)
+    viewport: Rect,
+}
+
+impl TextEditor {
+    /// Create a new TextEditor
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Command for a key press
+    fn command_for_key(&self, key: &KeyEvent) -> Option<EditorCommand> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let extend = key.modifiers.contains(KeyModifiers::SHIFT);
+        let page = (self.viewport.height as usize).max(1);
+        let motion = match key.code {
+            KeyCode::Char('a') if ctrl => return Some(EditorCommand::SelectAll),
+            KeyCode::Char('z') if ctrl => return Some(EditorCommand::Undo),
+            KeyCode::Char('y') if ctrl => return Some(EditorCommand::Redo),
+            KeyCode::Char('w') if ctrl => return Some(EditorCommand::DeleteWordBackward),
+            KeyCode::Char(_) if ctrl => return None,
+            KeyCode::Char(c) => return Some(EditorCommand::Insert(c.to_string())),
+            KeyCode::Enter => return Some(EditorCommand::Insert("\n".into())),
+            KeyCode::Tab => return Some(EditorCommand::Insert(TAB.into())),
+            KeyCode::Backspace if ctrl => return Some(EditorCommand::DeleteWordBackward),
+            KeyCode::Backspace => return Some(EditorCommand::DeleteBackward),
+            KeyCode::Delete => return Some(EditorCommand::DeleteForward),
+            KeyCode::Left if ctrl => Motion::WordLeft,
+            KeyCode::Right if ctrl => Motion::WordRight,
+            KeyCode::Left => Motion::Left,
+            KeyCode::Right => Motion::Right,
+            KeyCode::Up => Motion::Up(1),
+            KeyCode::Down => Motion::Down(1),
+            KeyCode::PageUp => Motion::Up(page),
+            KeyCode::PageDown => Motion::Down(page),
+            KeyCode::Home if ctrl => Motion::BufferStart,
+            KeyCode::End if ctrl => Motion::BufferEnd,
+            KeyCode::Home => Motion::LineStart,
+            KeyCode::End => Motion::LineEnd,
+            _ => return None,
+        };
+        Some(EditorCommand::Move { motion, extend })
+    }
+
+    /// Buffer position under a screen cell, if it is in the text area
+    fn position_at(&self, buffer: &TextBuffer, column: u16, row: u16) -> Option<TextPosition> {
+        let area = self.viewport;
+        if column < area.x
+            || column >= area.x.saturating_add(area.width)
+            || row < area.y
+            || row >= area.y.saturating_add(area.height)
+        {
+            return None;
+        }
+        let line = self.scroll_row + (row - area.y) as usize;
+        let target = self.scroll_col + (column - area.x) as usize;
+
+        // Land on the char covering the clicked cell
+        let mut width = 0;
+        let mut index = 0;
+        for c in buffer.line(line).chars() {
+            let next = width + char_width(c);
+            if next > target {
+                break;
+            }
+            width = next;
+            index += 1;
+        }
+        Some(TextPosition {
+            line,
+            column: index,
+        })
+    }
+
+    /// Adjust scrolling so the cursor cell is visible
+    fn scroll_to_cursor(&mut self, buffer: &TextBuffer) {
+        let height = (self.viewport.height as usize).max(1);
+        let width = (self.viewport.width as usize).max(1);
+        let cursor = buffer.cursor_position();
+
+        let max_row = buffer.len_lines().saturating_sub(height);
+        self.scroll_row = self.scroll_row.min(max_row);
+        if cursor.line < self.scroll_row {
+            self.scroll_row = cursor.line;
+        } else if cursor.line >= self.scroll_row + height {
+            self.scroll_row = cursor.line + 1 - height;
+        }
+
+        let col = line_width(buffer.line(cursor.line).chars().take(cursor.column));
+        if col < self.scroll_col {
+            self.scroll_col = col;
+        } else if col >= self.scroll_col + width {
+            self.scroll_col = col + 1 - width;
+        }
+    }
+}
+
+impl<A> Component<A> for TextEditor {
+    type Props<'a> = TextEditorProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        let command = match event {
+            EventKind::Key(key) if props.is_focused => self.command_for_key(key),
+            EventKind::Paste(text) if props.is_focused => {
+                Some(EditorCommand::Insert(text.replace("\r\n", "\n")))
+            }
+            EventKind::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                self.position_at(props.buffer, mouse.column, mouse.row)
+                    .map(|position| EditorCommand::SetCursor {
+                        position,
+                        extend: mouse.modifiers.contains(KeyModifiers::SHIFT),
+                    })
+            }
+            EventKind::Scroll { column, row, delta }
+                if self.position_at(props.buffer, *column, *row).is_some() =>
+            {
+                let motion = if *delta > 0 {
+                    Motion::Down(WHEEL_STEP)
+                } else {
+                    Motion::Up(WHEEL_STEP)
+                };
+                Some(EditorCommand::Move {
+                    motion,
+                    extend: false,
+                })
+            }
+            _ => None,
+        };
+        command.map(props.on_command)
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let inner = if props.show_border {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(props.theme.border_style(props.is_focused));
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            inner
+        } else {
+            area
+        };
+
+        let buffer = props.buffer;
+        let gutter_width = if props.line_numbers {
+            (buffer.len_lines().to_string().len() + 1) as u16
+        } else {
+            0
+        };
+        self.viewport = Rect {
+            x: inner.x + gutter_width.min(inner.width),
+            width: inner.width.saturating_sub(gutter_width),
+            ..inner
+        };
+        self.scroll_to_cursor(buffer);
+        let content = self.viewport;
+
+        if buffer.is_empty() && !props.placeholder.is_empty() {
+            frame.render_widget(
+                Paragraph::new(props.placeholder).style(props.theme.muted()),
+                content,
+            );
+        }
+
+        let selection = buffer.selection();
+        let selected_style = props.theme.text().add_modifier(Modifier::REVERSED);
+        let end = (self.scroll_row + inner.height as usize).min(buffer.len_lines());
+        for (i, index) in (self.scroll_row..end).enumerate() {
+            let y = inner.y + i as u16;
+
+            if props.line_numbers {
+                let number = format!("{:>width$} ", index + 1, width = gutter_width as usize - 1);
+                frame.render_widget(
+                    Paragraph::new(Span::styled(number, props.theme.muted())),
+                    Rect::new(inner.x, y, gutter_width.min(inner.width), 1),
+                );
+            }
+
+            let line_start = buffer.index_of(TextPosition {
+                line: index,
+                column: 0,
+            });
+            let mut spans: Vec<Span> = Vec::new();
+            let mut col = 0;
+            for (offset, c) in buffer.line(index).chars().enumerate() {
+                let width = char_width(c);
+                col += width;
+                if col <= self.scroll_col {
+                    continue;
+                }
+                if col > self.scroll_col + content.width as usize {
+                    break;
+                }
+                let style = match &selection {
+                    Some(range) if range.contains(&(line_start + offset)) => selected_style,
+                    _ => props.theme.text(),
+                };
+                let c = if c == '\t' { ' ' } else { c };
+                // Group runs of equally styled chars into one span
+                match spans.last_mut() {
+                    Some(span) if span.style == style => span.content.to_mut().push(c),
+                    _ => spans.push(Span::styled(c.to_string(), style)),
+                }
+            }
+            if spans.is_empty() {
+                continue;
+            }
+            frame.render_widget(
+                Paragraph::new(Line::from(spans)),
+                Rect::new(content.x, y, content.width, 1),
+            );
+        }
+
+        if props.is_focused && content.width > 0 {
+            let cursor = buffer.cursor_position();
+            let col = line_width(buffer.line(cursor.line).chars().take(cursor.column));
+            let x = content.x + (col - self.scroll_col) as u16;
+            let y = content.y + (cursor.line - self.scroll_row) as u16;
+            frame.set_cursor_position((x, y));
+        }
+    }
+}
+
+/// Display width of a char; tabs show as one space
+fn char_width(c: char) -> usize {
+    if c == '\t' {
+        1
+    } else {
+        c.width().unwrap_or(0)
+    }
+}
+
+fn line_width(chars: impl Iterator<Item = char>) -> usize {
+    chars.map(char_width).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::MouseEvent;
+    use tui_dispatch_core::testing::{char_key, key, RenderHarness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Edit(EditorCommand),
+    }
+
+    fn props(buffer: &TextBuffer) -> TextEditorProps<'_, TestAction> {
+        TextEditorProps {
+            buffer,
+            is_focused: true,
+            show_border: false,
+            line_numbers: true,
+            placeholder: "",
+            theme: &Theme::DARK,
+            on_command: TestAction::Edit,
+        }
+    }
+
+    fn send(editor: &mut TextEditor, buffer: &mut TextBuffer, event: EventKind) {
+        let actions: Vec<_> = editor
+            .handle_event(&event, props(buffer))
+            .into_iter()
+            .collect();
+        for TestAction::Edit(command) in actions {
+            buffer.execute(command);
+        }
+    }
+
+    #[test]
+    fn test_keys_edit_buffer() {
+        let mut buffer = TextBuffer::new();
+        let mut editor = TextEditor::new();
+
+        for c in "ab".chars() {
+            send(&mut editor, &mut buffer, EventKind::Key(char_key(c)));
+        }
+        send(&mut editor, &mut buffer, EventKind::Key(key("enter")));
+        send(&mut editor, &mut buffer, EventKind::Paste("c\r\nd".into()));
+        assert_eq!(buffer.text(), "ab\nc\nd");
+
+        send(&mut editor, &mut buffer, EventKind::Key(key("shift+up")));
+        send(&mut editor, &mut buffer, EventKind::Key(key("backspace")));
+        assert_eq!(buffer.text(), "ab\nc");
+
+        send(&mut editor, &mut buffer, EventKind::Key(key("ctrl+z")));
+        assert_eq!(buffer.text(), "ab\nc\nd");
+        assert!(editor
+            .handle_event(&EventKind::Key(key("ctrl+q")), props(&buffer))
+            .into_iter()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_render_scrolls_to_cursor() {
+        let text: String = (1..=20).map(|i| format!("line {i}\n")).collect();
+        let mut buffer = TextBuffer::from(text);
+        buffer.execute(EditorCommand::Move {
+            motion: Motion::BufferEnd,
+            extend: false,
+        });
+        buffer.execute(EditorCommand::Move {
+            motion: Motion::WordLeft,
+            extend: true,
+        });
+        let mut render = RenderHarness::new(12, 4);
+        let mut editor = TextEditor::new();
+
+        let output = render.render(|frame| editor.render(frame, frame.area(), props(&buffer)));
+
+        // Lines 18..=21 are visible, with a 3 column gutter
+        assert_eq!(output[(0, 0)].symbol(), "1");
+        assert_eq!(output[(1, 0)].symbol(), "8");
+        assert_eq!(output[(3, 0)].symbol(), "l");
+        // "20" on the line before the cursor is selected
+        assert!(output[(8, 2)].modifier.contains(Modifier::REVERSED));
+        assert!(!output[(7, 2)].modifier.contains(Modifier::REVERSED));
+
+        // Clicking on "line 19" puts the cursor before "9"
+        let click = EventKind::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 9,
+            row: 1,
+            modifiers: KeyModifiers::empty(),
+        });
+        send(&mut editor, &mut buffer, click);
+        assert_eq!(
+            buffer.cursor_position(),
+            TextPosition {
+                line: 18,
+                column: 6
+            }
+        );
+    }
+}
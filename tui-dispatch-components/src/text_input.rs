@@ -1,13 +1,19 @@
 //! Single-line text input component
 
-use crossterm::event::{KeyCode, KeyModifiers};
+use std::ops::Range;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Color,
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
-use tui_dispatch_core::{Component, EventKind};
+use tui_dispatch_core::{Component, EventKind, Theme};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::bindings::{ComponentBindings, ComponentCommand};
 
 /// Props for TextInput component
 pub struct TextInputProps<'a, A> {
@@ -21,24 +27,152 @@ pub struct TextInputProps<'a, A> {
     pub show_border: bool,
     /// Background color (None = transparent)
     pub bg_color: Option<Color>,
+    /// Colors for text, placeholder and border
+    pub theme: &'a Theme,
     /// Horizontal padding (left and right)
     pub padding_x: u16,
     /// Vertical padding (top and bottom)
     pub padding_y: u16,
+    /// Previous submissions, oldest first, recalled with Up/Down (empty = none)
+    pub history: &'a [String],
+    /// Accepted characters, length limit and password display
+    pub mask: InputMask,
+    /// Key binding set: standard, emacs or modal vim editing
+    pub edit_mode: EditMode,
+    /// Rebound submit, history and line keys (None = built-in keys, see [`TextInput`])
+    pub keybindings: Option<ComponentBindings<'a>>,
     /// Callback when value changes
     pub on_change: fn(String) -> A,
     /// Callback when user submits (Enter)
     pub on_submit: fn(String) -> A,
 }
 
+/// Glyph drawn for each character in password mode
+const PASSWORD_BULLET: char = '•';
+
+/// Input restrictions and display mode for TextInput
+///
+/// Rejected characters are dropped from typed, pasted and yanked text
+/// before `on_change` fires, so the app state only ever holds valid values.
+/// `InputMask::default()` accepts everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InputMask {
+    /// Only accept ASCII digits
+    pub numeric: bool,
+    /// Maximum number of characters (None = unlimited)
+    pub max_len: Option<usize>,
+    /// Custom filter; characters for which it returns false are rejected
+    pub char_filter: Option<fn(char) -> bool>,
+    /// Draw a bullet per character; `on_change` still gets the real value
+    pub password: bool,
+}
+
+impl InputMask {
+    /// Accept only ASCII digits
+    pub fn numeric() -> Self {
+        Self {
+            numeric: true,
+            ..Default::default()
+        }
+    }
+
+    /// Hide the value behind bullets
+    pub fn password() -> Self {
+        Self {
+            password: true,
+            ..Default::default()
+        }
+    }
+
+    /// Accept only characters for which `filter` returns true
+    pub fn filter(filter: fn(char) -> bool) -> Self {
+        Self {
+            char_filter: Some(filter),
+            ..Default::default()
+        }
+    }
+
+    /// Limit the value to `max_len` characters
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Whether `c` passes the numeric and custom filters
+    pub fn accepts(&self, c: char) -> bool {
+        (!self.numeric || c.is_ascii_digit()) && self.char_filter.is_none_or(|f| f(c))
+    }
+
+    /// Keep the accepted characters of `text` that fit after `value`
+    fn fit(&self, value: &str, text: &str) -> String {
+        let room = self
+            .max_len
+            .map_or(usize::MAX, |max| max.saturating_sub(value.chars().count()));
+        text.chars()
+            .filter(|&c| self.accepts(c))
+            .take(room)
+            .collect()
+    }
+}
+
+/// Key binding set for TextInput
+///
+/// Every mode keeps Enter for `on_submit` and reports edits through
+/// `on_change`; vim's normal/insert state lives in the component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EditMode {
+    /// Arrow keys, Home/End, Up/Down history and the Ctrl+A/E/U/W/K/Y shortcuts
+    #[default]
+    Standard,
+    /// Standard bindings plus Ctrl+B/F (move), Ctrl+D/H (delete), Ctrl+P/N
+    /// (history), Alt+B/F (word motions) and Alt+D/Alt+Backspace (kill word)
+    Emacs,
+    /// Modal editing, starting in insert mode with the standard bindings.
+    ///
+    /// Esc enters normal mode: h/l, w/b/e, 0/^/$ move; i/a/I/A insert;
+    /// x/X delete; d and c take a motion (dd/cc for the whole line) and
+    /// D/C act to the end; p/P paste the last deleted text; k/j recall
+    /// history.
+    Vim,
+}
+
 /// A single-line text input with cursor
 ///
-/// Handles typing, backspace, delete, and cursor movement.
-/// Emits on_change for each keystroke and on_submit for Enter.
+/// Handles typing, pasting, backspace, delete, and cursor movement.
+/// Emits on_change for each keystroke or paste and on_submit for Enter.
+///
+/// The cursor moves and deletes by grapheme cluster and is placed by display
+/// width, so wide (CJK, emoji) and combining characters line up. Values
+/// wider than the box scroll horizontally to keep the cursor visible.
+///
+/// Up/Down step through `history` like a shell, restoring the unsent draft
+/// when stepping past the newest entry. Ctrl+W (delete word), Ctrl+K (kill
+/// to end) and Ctrl+U (clear line) save the removed text, which Ctrl+Y
+/// pastes back at the cursor.
+///
+/// `mask` restricts input to digits, a custom character filter or a maximum
+/// length, and password mode draws bullets instead of the value.
+///
+/// `edit_mode` adds emacs bindings or modal vim editing (see [`EditMode`]).
+/// With `keybindings`, keys bound to `input.submit`, `input.history_prev`,
+/// `input.history_next`, `input.start`, `input.end` and `input.clear` run
+/// those commands before any editing keys; typing is not rebindable.
 #[derive(Default)]
 pub struct TextInput {
     /// Cursor position (byte index)
     cursor: usize,
+    /// First visible display column, for horizontal scrolling
+    scroll: usize,
+    /// Index into history while recalling entries
+    history_index: Option<usize>,
+    /// Value being edited before history recall started
+    draft: String,
+    /// Text removed by the last kill command
+    killed: String,
+    /// Vim normal mode (insert mode otherwise)
+    normal_mode: bool,
+    /// Vim operator (`d` or `c`) waiting for its motion
+    pending_operator: Option<char>,
 }
 
 impl TextInput {
@@ -47,33 +181,79 @@ impl TextInput {
         Self::default()
     }
 
+    /// Whether vim editing is in normal mode (see [`EditMode::Vim`])
+    ///
+    /// Useful for drawing a mode indicator.
+    pub fn is_normal_mode(&self) -> bool {
+        self.normal_mode
+    }
+
+    fn run_command<A>(
+        &mut self,
+        command: InputCommand,
+        props: &TextInputProps<'_, A>,
+    ) -> Option<A> {
+        match command {
+            InputCommand::Submit => {
+                self.history_index = None;
+                self.draft.clear();
+                self.normal_mode = false;
+                Some((props.on_submit)(props.value.to_string()))
+            }
+            InputCommand::HistoryPrev => self
+                .history_prev(props.value, props.history)
+                .map(|v| (props.on_change)(v)),
+            InputCommand::HistoryNext => self
+                .history_next(props.history)
+                .map(|v| (props.on_change)(v)),
+            InputCommand::Start => {
+                self.cursor = 0;
+                None
+            }
+            InputCommand::End => {
+                self.cursor = props.value.len();
+                None
+            }
+            InputCommand::Clear => {
+                if !props.value.is_empty() {
+                    self.killed = props.value.to_string();
+                }
+                self.cursor = 0;
+                Some((props.on_change)(String::new()))
+            }
+        }
+    }
+
     /// Clamp cursor to valid range for the given value
     fn clamp_cursor(&mut self, value: &str) {
         self.cursor = self.cursor.min(value.len());
+        while !value.is_char_boundary(self.cursor) {
+            self.cursor -= 1;
+        }
+    }
+
+    /// Byte index of the grapheme boundary before the cursor
+    fn prev_boundary(&self, value: &str) -> usize {
+        grapheme_start_before(value, self.cursor)
     }
 
-    /// Move cursor left by one character
+    /// Byte index of the grapheme boundary after the cursor
+    fn next_boundary(&self, value: &str) -> usize {
+        value[self.cursor..]
+            .graphemes(true)
+            .next()
+            .map(|g| self.cursor + g.len())
+            .unwrap_or(value.len())
+    }
+
+    /// Move cursor left by one grapheme
     fn move_cursor_left(&mut self, value: &str) {
-        if self.cursor > 0 {
-            // Find previous char boundary
-            let mut new_pos = self.cursor - 1;
-            while new_pos > 0 && !value.is_char_boundary(new_pos) {
-                new_pos -= 1;
-            }
-            self.cursor = new_pos;
-        }
+        self.cursor = self.prev_boundary(value);
     }
 
-    /// Move cursor right by one character
+    /// Move cursor right by one grapheme
     fn move_cursor_right(&mut self, value: &str) {
-        if self.cursor < value.len() {
-            // Find next char boundary
-            let mut new_pos = self.cursor + 1;
-            while new_pos < value.len() && !value.is_char_boundary(new_pos) {
-                new_pos += 1;
-            }
-            self.cursor = new_pos;
-        }
+        self.cursor = self.next_boundary(value);
     }
 
     /// Insert character at cursor position
@@ -86,45 +266,459 @@ impl TextInput {
         new_value
     }
 
-    /// Delete character before cursor (backspace)
+    /// Insert pasted text at cursor position
+    ///
+    /// Line breaks and tabs become spaces and other control characters are
+    /// dropped, since the input is a single line.
+    fn insert_str(&mut self, value: &str, text: &str, mask: &InputMask) -> Option<String> {
+        let text: String = text
+            .replace("\r\n", "\n")
+            .chars()
+            .filter_map(|c| match c {
+                '\n' | '\r' | '\t' => Some(' '),
+                c if c.is_control() => None,
+                c => Some(c),
+            })
+            .collect();
+        let text = mask.fit(value, &text);
+        if text.is_empty() {
+            return None;
+        }
+        let new_value = format!("{}{}{}", &value[..self.cursor], text, &value[self.cursor..]);
+        self.cursor += text.len();
+        Some(new_value)
+    }
+
+    /// Delete grapheme before cursor (backspace)
     fn delete_char_before(&mut self, value: &str) -> Option<String> {
         if self.cursor == 0 {
             return None;
         }
 
-        let mut new_value = String::with_capacity(value.len());
-        let before_cursor = &value[..self.cursor];
-
-        // Find the previous character boundary
-        let char_start = before_cursor
-            .char_indices()
-            .last()
-            .map(|(i, _)| i)
-            .unwrap_or(0);
-
-        new_value.push_str(&value[..char_start]);
-        new_value.push_str(&value[self.cursor..]);
-        self.cursor = char_start;
+        let start = self.prev_boundary(value);
+        let new_value = format!("{}{}", &value[..start], &value[self.cursor..]);
+        self.cursor = start;
         Some(new_value)
     }
 
-    /// Delete character at cursor (delete key)
+    /// Delete grapheme at cursor (delete key)
     fn delete_char_at(&self, value: &str) -> Option<String> {
         if self.cursor >= value.len() {
             return None;
         }
 
-        let mut new_value = String::with_capacity(value.len());
-        new_value.push_str(&value[..self.cursor]);
+        let end = self.next_boundary(value);
+        Some(format!("{}{}", &value[..self.cursor], &value[end..]))
+    }
+
+    /// Scroll so the cursor is visible in `width` columns
+    ///
+    /// Returns the visible part of `value` and the cursor's column in it.
+    fn viewport<'v>(&mut self, value: &'v str, width: usize) -> (&'v str, usize) {
+        self.viewport_at(value, self.cursor, width)
+    }
+
+    /// Like `viewport`, with the cursor at byte index `cursor` of `value`
+    fn viewport_at<'v>(&mut self, value: &'v str, cursor: usize, width: usize) -> (&'v str, usize) {
+        if width == 0 {
+            return ("", 0);
+        }
+
+        // Keep one column free after the text for the cursor
+        let total = value.width();
+        let cursor_col = value[..cursor].width();
+        self.scroll = self.scroll.min((total + 1).saturating_sub(width));
+        if cursor_col < self.scroll {
+            self.scroll = cursor_col;
+        } else if cursor_col >= self.scroll + width {
+            self.scroll = cursor_col + 1 - width;
+        }
+
+        // Skip graphemes left of the scroll column (a wide one straddling
+        // it is skipped whole), then take what fits in the width
+        let mut col = 0;
+        let mut start = value.len();
+        let mut start_col = None;
+        let mut end = value.len();
+        for (i, grapheme) in value.grapheme_indices(true) {
+            let grapheme_width = grapheme.width();
+            if start_col.is_none() && col >= self.scroll {
+                start = i;
+                start_col = Some(col);
+            }
+            if let Some(first) = start_col {
+                if col + grapheme_width > first + width {
+                    end = i;
+                    break;
+                }
+            }
+            col += grapheme_width;
+        }
+
+        let start_col = start_col.unwrap_or(col);
+        (&value[start..end], cursor_col.saturating_sub(start_col))
+    }
+
+    /// Delete the word before the cursor (Ctrl+W)
+    fn kill_word_before(&mut self, value: &str) -> Option<String> {
+        let start = self.prev_word_start(value);
+        self.kill_range(value, start..self.cursor)
+    }
+
+    /// Delete `range`, saving it for yank, and move the cursor to its start
+    fn kill_range(&mut self, value: &str, range: Range<usize>) -> Option<String> {
+        if range.is_empty() {
+            return None;
+        }
+
+        self.killed = value[range.clone()].to_string();
+        let new_value = format!("{}{}", &value[..range.start], &value[range.end..]);
+        self.cursor = range.start;
+        Some(new_value)
+    }
+
+    /// Byte index of the start of the word before the cursor (vim `b`, Alt+B)
+    ///
+    /// Words are runs of non-whitespace, like Ctrl+W.
+    fn prev_word_start(&self, value: &str) -> usize {
+        let before = value[..self.cursor].trim_end();
+        before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map(|(i, c)| i + c.len_utf8())
+            .unwrap_or(0)
+    }
+
+    /// Byte index of the start of the next word after the cursor (vim `w`)
+    fn next_word_start(&self, value: &str) -> usize {
+        let rest = &value[self.cursor..];
+        let word_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let next = rest[word_end..]
+            .find(|c: char| !c.is_whitespace())
+            .map_or(rest.len(), |i| word_end + i);
+        self.cursor + next
+    }
+
+    /// Byte index just past the end of the word after the cursor
+    ///
+    /// Alt+F moves here; vim `e` stops on the grapheme before it. Starts one
+    /// grapheme right so repeated motions advance to the next word.
+    fn word_end(&self, value: &str) -> usize {
+        let start = self.next_boundary(value);
+        let rest = &value[start..];
+        let word_start = rest
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(rest.len());
+        let end = rest[word_start..]
+            .find(char::is_whitespace)
+            .map_or(rest.len(), |i| word_start + i);
+        start + end
+    }
 
-        // Find the next character boundary
-        let after_cursor = &value[self.cursor..];
-        if let Some((_, c)) = after_cursor.char_indices().next() {
-            new_value.push_str(&value[self.cursor + c.len_utf8()..]);
+    /// Keep the cursor on a grapheme, as vim's normal mode does
+    fn clamp_normal_cursor(&mut self, value: &str) {
+        if self.cursor >= value.len() {
+            self.cursor = grapheme_start_before(value, value.len());
         }
+    }
 
+    /// Delete from the cursor to the end (Ctrl+K)
+    fn kill_to_end(&mut self, value: &str) -> Option<String> {
+        if self.cursor >= value.len() {
+            return None;
+        }
+        self.killed = value[self.cursor..].to_string();
+        Some(value[..self.cursor].to_string())
+    }
+
+    /// Insert the last killed text at the cursor (Ctrl+Y)
+    fn yank(&mut self, value: &str, mask: &InputMask) -> Option<String> {
+        let text = mask.fit(value, &self.killed);
+        if text.is_empty() {
+            return None;
+        }
+        let new_value = format!("{}{}{}", &value[..self.cursor], text, &value[self.cursor..]);
+        self.cursor += text.len();
         Some(new_value)
     }
+
+    /// Step to an older history entry (Up)
+    fn history_prev(&mut self, value: &str, history: &[String]) -> Option<String> {
+        let index = match self.history_index {
+            None if history.is_empty() => return None,
+            None => {
+                self.draft = value.to_string();
+                history.len() - 1
+            }
+            Some(0) => return None,
+            Some(i) => (i - 1).min(history.len().saturating_sub(1)),
+        };
+        let entry = history.get(index)?.clone();
+        self.history_index = Some(index);
+        self.cursor = entry.len();
+        Some(entry)
+    }
+
+    /// Step to a newer history entry, or back to the draft (Down)
+    fn history_next(&mut self, history: &[String]) -> Option<String> {
+        let index = self.history_index?;
+        let entry = match history.get(index + 1) {
+            Some(entry) => {
+                self.history_index = Some(index + 1);
+                entry.clone()
+            }
+            None => {
+                self.history_index = None;
+                std::mem::take(&mut self.draft)
+            }
+        };
+        self.cursor = entry.len();
+        Some(entry)
+    }
+
+    /// Emacs bindings on top of the standard ones
+    ///
+    /// Returns `None` for keys left to the standard bindings.
+    fn emacs_key<A>(&mut self, key: &KeyEvent, props: &TextInputProps<'_, A>) -> Option<Option<A>> {
+        let value = props.value;
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Some(match key.code {
+                // Ctrl+B / Ctrl+F: move by grapheme
+                KeyCode::Char('b') => {
+                    self.move_cursor_left(value);
+                    None
+                }
+                KeyCode::Char('f') => {
+                    self.move_cursor_right(value);
+                    None
+                }
+                // Ctrl+D / Ctrl+H: delete at / before cursor
+                KeyCode::Char('d') => self.delete_char_at(value).map(|v| (props.on_change)(v)),
+                KeyCode::Char('h') => self.delete_char_before(value).map(|v| (props.on_change)(v)),
+                // Ctrl+P / Ctrl+N: history recall
+                KeyCode::Char('p') => self
+                    .history_prev(value, props.history)
+                    .map(|v| (props.on_change)(v)),
+                KeyCode::Char('n') => self
+                    .history_next(props.history)
+                    .map(|v| (props.on_change)(v)),
+                _ => return None,
+            });
+        }
+        if key.modifiers.contains(KeyModifiers::ALT) {
+            return Some(match key.code {
+                // Alt+B / Alt+F: move by word
+                KeyCode::Char('b') => {
+                    self.cursor = self.prev_word_start(value);
+                    None
+                }
+                KeyCode::Char('f') => {
+                    self.cursor = self.word_end(value);
+                    None
+                }
+                // Alt+D / Alt+Backspace: kill word after / before cursor
+                KeyCode::Char('d') => {
+                    let end = self.word_end(value);
+                    self.kill_range(value, self.cursor..end)
+                        .map(|v| (props.on_change)(v))
+                }
+                KeyCode::Backspace => self.kill_word_before(value).map(|v| (props.on_change)(v)),
+                _ => return None,
+            });
+        }
+        None
+    }
+
+    /// Vim normal mode keys
+    fn vim_normal_key<A>(&mut self, key: &KeyEvent, props: &TextInputProps<'_, A>) -> Option<A> {
+        let value = props.value;
+        self.clamp_normal_cursor(value);
+
+        // Operator waiting for a motion: delete (d) or change (c) its range
+        if let Some(operator) = self.pending_operator.take() {
+            let range = match key.code {
+                KeyCode::Char(c) if c == operator => 0..value.len(),
+                // `cw` changes to the end of the word, like vim
+                KeyCode::Char('w') if operator == 'c' => self.cursor..self.word_end(value),
+                KeyCode::Char('w') => self.cursor..self.next_word_start(value),
+                KeyCode::Char('e') => self.cursor..self.word_end(value),
+                KeyCode::Char('b') => self.prev_word_start(value)..self.cursor,
+                KeyCode::Char('$') | KeyCode::End => self.cursor..value.len(),
+                KeyCode::Char('0') | KeyCode::Char('^') | KeyCode::Home => 0..self.cursor,
+                // Anything else cancels the operator
+                _ => return None,
+            };
+            if operator == 'c' {
+                self.normal_mode = false;
+            }
+            let new_value = self.kill_range(value, range)?;
+            if self.normal_mode {
+                self.clamp_normal_cursor(&new_value);
+            }
+            return Some((props.on_change)(new_value));
+        }
+
+        let action = match key.code {
+            // Motions
+            KeyCode::Char('h') | KeyCode::Left | KeyCode::Backspace => {
+                self.move_cursor_left(value);
+                None
+            }
+            KeyCode::Char('l') | KeyCode::Right | KeyCode::Char(' ') => {
+                self.move_cursor_right(value);
+                None
+            }
+            KeyCode::Char('w') => {
+                self.cursor = self.next_word_start(value);
+                None
+            }
+            KeyCode::Char('b') => {
+                self.cursor = self.prev_word_start(value);
+                None
+            }
+            KeyCode::Char('e') => {
+                self.cursor = grapheme_start_before(value, self.word_end(value));
+                None
+            }
+            KeyCode::Char('0') | KeyCode::Char('^') | KeyCode::Home => {
+                self.cursor = 0;
+                None
+            }
+            KeyCode::Char('$') | KeyCode::End => {
+                self.cursor = value.len();
+                None
+            }
+            // Back to insert mode
+            KeyCode::Char('i') => {
+                self.normal_mode = false;
+                None
+            }
+            KeyCode::Char('a') => {
+                self.move_cursor_right(value);
+                self.normal_mode = false;
+                None
+            }
+            KeyCode::Char('I') => {
+                self.cursor = 0;
+                self.normal_mode = false;
+                None
+            }
+            KeyCode::Char('A') => {
+                self.cursor = value.len();
+                self.normal_mode = false;
+                None
+            }
+            // Deletes
+            KeyCode::Char('x') | KeyCode::Delete => {
+                let end = self.next_boundary(value);
+                self.kill_range(value, self.cursor..end)
+                    .map(|v| (props.on_change)(v))
+            }
+            KeyCode::Char('X') => {
+                let start = self.prev_boundary(value);
+                self.kill_range(value, start..self.cursor)
+                    .map(|v| (props.on_change)(v))
+            }
+            KeyCode::Char(c @ ('d' | 'c')) => {
+                self.pending_operator = Some(c);
+                None
+            }
+            KeyCode::Char(c @ ('D' | 'C')) => {
+                if c == 'C' {
+                    self.normal_mode = false;
+                }
+                self.kill_range(value, self.cursor..value.len())
+                    .map(|v| (props.on_change)(v))
+            }
+            // Paste after / at the cursor
+            KeyCode::Char(c @ ('p' | 'P')) => {
+                if c == 'p' {
+                    self.move_cursor_right(value);
+                }
+                self.yank(value, &props.mask).map(|v| (props.on_change)(v))
+            }
+            // History recall
+            KeyCode::Char('k') | KeyCode::Up => self
+                .history_prev(value, props.history)
+                .map(|v| (props.on_change)(v)),
+            KeyCode::Char('j') | KeyCode::Down => self
+                .history_next(props.history)
+                .map(|v| (props.on_change)(v)),
+            KeyCode::Enter => {
+                self.history_index = None;
+                self.draft.clear();
+                self.normal_mode = false;
+                Some((props.on_submit)(value.to_string()))
+            }
+            _ => None,
+        };
+        if self.normal_mode && action.is_none() {
+            self.clamp_normal_cursor(value);
+        }
+        action
+    }
+}
+
+/// Keyboard commands, rebindable through [`TextInputProps::keybindings`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputCommand {
+    Submit,
+    HistoryPrev,
+    HistoryNext,
+    Start,
+    End,
+    Clear,
+}
+
+impl ComponentCommand for InputCommand {
+    const ALL: &'static [Self] = &[
+        Self::Submit,
+        Self::HistoryPrev,
+        Self::HistoryNext,
+        Self::Start,
+        Self::End,
+        Self::Clear,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Submit => "input.submit",
+            Self::HistoryPrev => "input.history_prev",
+            Self::HistoryNext => "input.history_next",
+            Self::Start => "input.start",
+            Self::End => "input.end",
+            Self::Clear => "input.clear",
+        }
+    }
+
+    fn from_key(key: &KeyEvent) -> Option<Self> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return match key.code {
+                KeyCode::Char('a') => Some(Self::Start),
+                KeyCode::Char('e') => Some(Self::End),
+                KeyCode::Char('u') => Some(Self::Clear),
+                _ => None,
+            };
+        }
+        match key.code {
+            KeyCode::Enter => Some(Self::Submit),
+            KeyCode::Up => Some(Self::HistoryPrev),
+            KeyCode::Down => Some(Self::HistoryNext),
+            KeyCode::Home => Some(Self::Start),
+            KeyCode::End => Some(Self::End),
+            _ => None,
+        }
+    }
+}
+
+/// Byte index of the grapheme boundary before `index`
+fn grapheme_start_before(value: &str, index: usize) -> usize {
+    value[..index]
+        .grapheme_indices(true)
+        .next_back()
+        .map(|(i, _)| i)
+        .unwrap_or(0)
 }
 
 impl<A> Component<A> for TextInput {
@@ -144,24 +738,55 @@ impl<A> Component<A> for TextInput {
 
         match event {
             EventKind::Key(key) => {
+                if let Some(bindings) = props.keybindings {
+                    match bindings.resolve::<InputCommand>(key) {
+                        Some(command) => return self.run_command(command, &props),
+                        // The key's built-in command is bound to other keys
+                        None if InputCommand::from_key(key).is_some() => return None,
+                        None => {}
+                    }
+                }
+
+                match props.edit_mode {
+                    EditMode::Standard => {}
+                    EditMode::Emacs => {
+                        if let Some(action) = self.emacs_key(key, &props) {
+                            return action;
+                        }
+                    }
+                    EditMode::Vim => {
+                        if self.normal_mode && !key.modifiers.contains(KeyModifiers::CONTROL) {
+                            return self.vim_normal_key(key, &props);
+                        }
+                        if !self.normal_mode && key.code == KeyCode::Esc {
+                            self.normal_mode = true;
+                            self.move_cursor_left(props.value);
+                            return None;
+                        }
+                    }
+                }
+
                 // Handle Ctrl+key shortcuts
                 if key.modifiers.contains(KeyModifiers::CONTROL) {
                     return match key.code {
                         // Ctrl+A: move to start
-                        KeyCode::Char('a') => {
-                            self.cursor = 0;
-                            None
-                        }
+                        KeyCode::Char('a') => self.run_command(InputCommand::Start, &props),
                         // Ctrl+E: move to end
-                        KeyCode::Char('e') => {
-                            self.cursor = props.value.len();
-                            None
-                        }
+                        KeyCode::Char('e') => self.run_command(InputCommand::End, &props),
                         // Ctrl+U: clear line
-                        KeyCode::Char('u') => {
-                            self.cursor = 0;
-                            Some((props.on_change)(String::new()))
+                        KeyCode::Char('u') => self.run_command(InputCommand::Clear, &props),
+                        // Ctrl+W: delete word before cursor
+                        KeyCode::Char('w') => self
+                            .kill_word_before(props.value)
+                            .map(|v| (props.on_change)(v)),
+                        // Ctrl+K: kill to end of line
+                        KeyCode::Char('k') => {
+                            self.kill_to_end(props.value).map(|v| (props.on_change)(v))
                         }
+                        // Ctrl+Y: yank last killed text
+                        KeyCode::Char('y') => self
+                            .yank(props.value, &props.mask)
+                            .map(|v| (props.on_change)(v)),
                         _ => None,
                     };
                 }
@@ -169,6 +794,13 @@ impl<A> Component<A> for TextInput {
                 match key.code {
                     // Character input
                     KeyCode::Char(c) => {
+                        if props
+                            .mask
+                            .fit(props.value, c.encode_utf8(&mut [0; 4]))
+                            .is_empty()
+                        {
+                            return None;
+                        }
                         let new_value = self.insert_char(props.value, c);
                         Some((props.on_change)(new_value))
                     }
@@ -189,19 +821,20 @@ impl<A> Component<A> for TextInput {
                         self.move_cursor_right(props.value);
                         None
                     }
-                    KeyCode::Home => {
-                        self.cursor = 0;
-                        None
-                    }
-                    KeyCode::End => {
-                        self.cursor = props.value.len();
-                        None
-                    }
+                    KeyCode::Home => self.run_command(InputCommand::Start, &props),
+                    KeyCode::End => self.run_command(InputCommand::End, &props),
+                    // History recall
+                    KeyCode::Up => self.run_command(InputCommand::HistoryPrev, &props),
+                    KeyCode::Down => self.run_command(InputCommand::HistoryNext, &props),
                     // Submit
-                    KeyCode::Enter => Some((props.on_submit)(props.value.to_string())),
+                    KeyCode::Enter => self.run_command(InputCommand::Submit, &props),
                     _ => None,
                 }
             }
+            // Bracketed paste: insert the whole text as one change
+            EventKind::Paste(text) => self
+                .insert_str(props.value, text, &props.mask)
+                .map(|v| (props.on_change)(v)),
             _ => None,
         }
     }
@@ -209,6 +842,9 @@ impl<A> Component<A> for TextInput {
     fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
         // Ensure cursor is valid
         self.clamp_cursor(props.value);
+        if props.edit_mode == EditMode::Vim && self.normal_mode {
+            self.clamp_normal_cursor(props.value);
+        }
 
         // Fill background if color provided
         if let Some(bg) = props.bg_color {
@@ -228,17 +864,26 @@ impl<A> Component<A> for TextInput {
             height: area.height.saturating_sub(props.padding_y * 2),
         };
 
-        // Determine display text
-        let display_text = if props.value.is_empty() {
-            props.placeholder
+        // Determine display text, scrolled so the cursor stays visible
+        let border_offset = if props.show_border { 1 } else { 0 };
+        let text_width = content_area.width.saturating_sub(border_offset * 2) as usize;
+        let masked: String;
+        let (display_text, cursor_col) = if props.value.is_empty() {
+            self.scroll = 0;
+            (props.placeholder, 0)
+        } else if props.mask.password {
+            let before = props.value[..self.cursor].graphemes(true).count();
+            masked =
+                std::iter::repeat_n(PASSWORD_BULLET, props.value.graphemes(true).count()).collect();
+            self.viewport_at(&masked, before * PASSWORD_BULLET.len_utf8(), text_width)
         } else {
-            props.value
+            self.viewport(props.value, text_width)
         };
 
         let mut style = if props.value.is_empty() {
-            Style::default().fg(Color::DarkGray)
+            props.theme.muted()
         } else {
-            Style::default()
+            props.theme.text()
         };
 
         // Preserve background color in text style
@@ -249,13 +894,11 @@ impl<A> Component<A> for TextInput {
         let mut paragraph = Paragraph::new(display_text).style(style);
 
         if props.show_border {
-            paragraph = paragraph.block(Block::default().borders(Borders::ALL).border_style(
-                if props.is_focused {
-                    Style::default().fg(Color::Cyan)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                },
-            ));
+            paragraph = paragraph.block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(props.theme.border_style(props.is_focused)),
+            );
         }
 
         frame.render_widget(paragraph, content_area);
@@ -263,17 +906,11 @@ impl<A> Component<A> for TextInput {
         // Show cursor if focused
         if props.is_focused {
             // Calculate cursor screen position (account for border and padding)
-            let border_offset = if props.show_border { 1 } else { 0 };
-            let cursor_x = content_area.x + border_offset + self.cursor as u16;
+            let cursor_x = content_area.x + border_offset + cursor_col as u16;
             let cursor_y = content_area.y + border_offset;
 
             // Only show cursor if within bounds
-            let max_x = if props.show_border {
-                content_area.x + content_area.width - 1
-            } else {
-                content_area.x + content_area.width
-            };
-            if cursor_x < max_x {
+            if cursor_col < text_width {
                 frame.set_cursor_position((cursor_x, cursor_y));
             }
         }
@@ -283,7 +920,8 @@ impl<A> Component<A> for TextInput {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tui_dispatch_core::testing::{key, RenderHarness};
+    use tui_dispatch_core::keybindings::{BindingContext, Keybindings};
+    use tui_dispatch_core::testing::{char_key, key, RenderHarness};
 
     #[derive(Debug, Clone, PartialEq)]
     enum TestAction {
@@ -300,8 +938,13 @@ mod tests {
             is_focused: true,
             show_border: true,
             bg_color: None,
+            theme: &Theme::DARK,
             padding_x: 0,
             padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
             on_change: TestAction::Change,
             on_submit: TestAction::Submit,
         };
@@ -325,8 +968,13 @@ mod tests {
             is_focused: true,
             show_border: true,
             bg_color: None,
+            theme: &Theme::DARK,
             padding_x: 0,
             padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
             on_change: TestAction::Change,
             on_submit: TestAction::Submit,
         };
@@ -350,8 +998,13 @@ mod tests {
             is_focused: true,
             show_border: true,
             bg_color: None,
+            theme: &Theme::DARK,
             padding_x: 0,
             padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
             on_change: TestAction::Change,
             on_submit: TestAction::Submit,
         };
@@ -376,8 +1029,13 @@ mod tests {
             is_focused: true,
             show_border: true,
             bg_color: None,
+            theme: &Theme::DARK,
             padding_x: 0,
             padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
             on_change: TestAction::Change,
             on_submit: TestAction::Submit,
         };
@@ -400,8 +1058,13 @@ mod tests {
             is_focused: true,
             show_border: true,
             bg_color: None,
+            theme: &Theme::DARK,
             padding_x: 0,
             padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
             on_change: TestAction::Change,
             on_submit: TestAction::Submit,
         };
@@ -424,8 +1087,13 @@ mod tests {
             is_focused: false,
             show_border: true,
             bg_color: None,
+            theme: &Theme::DARK,
             padding_x: 0,
             padding_y: 0,
+            history: &[],
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
             on_change: TestAction::Change,
             on_submit: TestAction::Submit,
         };
@@ -450,8 +1118,13 @@ mod tests {
                 is_focused: true,
                 show_border: true,
                 bg_color: None,
+                theme: &Theme::DARK,
                 padding_x: 0,
                 padding_y: 0,
+                history: &[],
+                mask: InputMask::default(),
+                edit_mode: EditMode::default(),
+                keybindings: None,
                 on_change: |_| (),
                 on_submit: |_| (),
             };
@@ -473,8 +1146,13 @@ mod tests {
                 is_focused: true,
                 show_border: true,
                 bg_color: None,
+                theme: &Theme::DARK,
                 padding_x: 0,
                 padding_y: 0,
+                history: &[],
+                mask: InputMask::default(),
+                edit_mode: EditMode::default(),
+                keybindings: None,
                 on_change: |_| (),
                 on_submit: |_| (),
             };
@@ -483,4 +1161,496 @@ mod tests {
 
         assert!(output.contains("Type here..."));
     }
+
+    fn props_with<'a>(value: &'a str, history: &'a [String]) -> TextInputProps<'a, TestAction> {
+        TextInputProps {
+            value,
+            placeholder: "",
+            is_focused: true,
+            show_border: true,
+            bg_color: None,
+            theme: &Theme::DARK,
+            padding_x: 0,
+            padding_y: 0,
+            history,
+            mask: InputMask::default(),
+            edit_mode: EditMode::default(),
+            keybindings: None,
+            on_change: TestAction::Change,
+            on_submit: TestAction::Submit,
+        }
+    }
+
+    fn send(input: &mut TextInput, k: &str, value: &str, history: &[String]) -> Vec<TestAction> {
+        input
+            .handle_event(&EventKind::Key(key(k)), props_with(value, history))
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_history_recall() {
+        let mut input = TextInput::new();
+        let history = vec!["first".to_string(), "second".to_string()];
+
+        // Up from a draft walks back through history, stopping at the oldest
+        assert_eq!(
+            send(&mut input, "up", "dra", &history),
+            vec![TestAction::Change("second".into())]
+        );
+        assert_eq!(input.cursor, 6);
+        assert_eq!(
+            send(&mut input, "up", "second", &history),
+            vec![TestAction::Change("first".into())]
+        );
+        assert!(send(&mut input, "up", "first", &history).is_empty());
+
+        // Down walks forward, then restores the draft
+        assert_eq!(
+            send(&mut input, "down", "first", &history),
+            vec![TestAction::Change("second".into())]
+        );
+        assert_eq!(
+            send(&mut input, "down", "second", &history),
+            vec![TestAction::Change("dra".into())]
+        );
+        assert!(send(&mut input, "down", "dra", &history).is_empty());
+
+        // Without history, Up/Down do nothing
+        assert!(send(&mut input, "up", "x", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_submit_resets_history_position() {
+        let mut input = TextInput::new();
+        let history = vec!["first".to_string(), "second".to_string()];
+
+        send(&mut input, "up", "", &history);
+        send(&mut input, "up", "second", &history);
+        send(&mut input, "enter", "first", &history);
+        assert_eq!(
+            send(&mut input, "up", "", &history),
+            vec![TestAction::Change("second".into())]
+        );
+    }
+
+    #[test]
+    fn test_kill_word_and_yank() {
+        let mut input = TextInput::new();
+        input.cursor = 11;
+
+        assert_eq!(
+            send(&mut input, "ctrl+w", "hello world", &[]),
+            vec![TestAction::Change("hello ".into())]
+        );
+        assert_eq!(input.cursor, 6);
+
+        // Trailing whitespace is deleted along with the word
+        assert_eq!(
+            send(&mut input, "ctrl+w", "hello ", &[]),
+            vec![TestAction::Change("".into())]
+        );
+
+        // The last kill wins
+        input.cursor = 0;
+        assert_eq!(
+            send(&mut input, "ctrl+y", "abc", &[]),
+            vec![TestAction::Change("hello abc".into())]
+        );
+        assert_eq!(input.cursor, 6);
+    }
+
+    #[test]
+    fn test_kill_to_end_and_yank() {
+        let mut input = TextInput::new();
+        input.cursor = 2;
+
+        assert_eq!(
+            send(&mut input, "ctrl+k", "abcdef", &[]),
+            vec![TestAction::Change("ab".into())]
+        );
+        assert_eq!(input.cursor, 2);
+        assert!(send(&mut input, "ctrl+k", "ab", &[]).is_empty());
+
+        input.cursor = 0;
+        assert_eq!(
+            send(&mut input, "ctrl+y", "ab", &[]),
+            vec![TestAction::Change("cdefab".into())]
+        );
+
+        // Nothing to yank yet
+        let mut fresh = TextInput::new();
+        assert!(send(&mut fresh, "ctrl+y", "ab", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_grapheme_editing() {
+        let mut input = TextInput::new();
+
+        // "e" + combining acute accent is one grapheme of three bytes
+        let value = "e\u{301}x";
+        input.cursor = value.len();
+        send(&mut input, "left", value, &[]);
+        assert_eq!(input.cursor, 3);
+        send(&mut input, "left", value, &[]);
+        assert_eq!(input.cursor, 0);
+        assert_eq!(
+            send(&mut input, "delete", value, &[]),
+            vec![TestAction::Change("x".into())]
+        );
+
+        // A ZWJ family emoji is removed whole
+        let value = "a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        input.cursor = value.len();
+        assert_eq!(
+            send(&mut input, "backspace", value, &[]),
+            vec![TestAction::Change("a".into())]
+        );
+        assert_eq!(input.cursor, 1);
+    }
+
+    #[test]
+    fn test_wide_chars_cursor_column() {
+        let mut input = TextInput::new();
+        let value = "日本語";
+        input.cursor = value.len();
+
+        assert_eq!(input.viewport(value, 20), ("日本語", 6));
+
+        input.cursor = "日".len();
+        assert_eq!(input.viewport(value, 20), ("日本語", 2));
+    }
+
+    #[test]
+    fn test_horizontal_scroll() {
+        let mut input = TextInput::new();
+        let value = "abcdefghijklmnop";
+        input.cursor = value.len();
+
+        // Cursor at the end keeps one free column after the text
+        assert_eq!(input.viewport(value, 8), ("jklmnop", 7));
+
+        // Moving back inside the window does not scroll
+        input.cursor = 12;
+        assert_eq!(input.viewport(value, 8), ("jklmnop", 3));
+
+        // Moving left of the window scrolls back
+        input.cursor = 2;
+        assert_eq!(input.viewport(value, 8), ("cdefghij", 0));
+
+        // A wide character straddling the scroll column is skipped whole
+        let value = "日本語テキスト";
+        input.scroll = 9;
+        input.cursor = "日本語テキス".len();
+        assert_eq!(input.viewport(value, 5), ("スト", 2));
+    }
+
+    #[test]
+    fn test_render_scrolls_long_value() {
+        let mut render = RenderHarness::new(10, 3);
+        let mut input = TextInput::new();
+        let value = "abcdefghijklmnop";
+        input.cursor = value.len();
+
+        let output = render.render_to_string_plain(|frame| {
+            input.render(frame, frame.area(), props_with(value, &[]));
+        });
+
+        assert!(output.contains("jklmnop"));
+        assert!(!output.contains("abc"));
+    }
+
+    #[test]
+    fn test_paste_inserts_whole_text() {
+        let mut input = TextInput::new();
+        input.cursor = 2;
+
+        let actions: Vec<_> = input
+            .handle_event(
+                &EventKind::Paste("new\r\nline".into()),
+                props_with("abcd", &[]),
+            )
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Change("abnew linecd".into())]);
+        assert_eq!(input.cursor, 10);
+
+        // Nothing printable to insert
+        let actions: Vec<_> = input
+            .handle_event(&EventKind::Paste("\u{7}".into()), props_with("abcd", &[]))
+            .into_iter()
+            .collect();
+        assert!(actions.is_empty());
+    }
+
+    fn send_masked(
+        input: &mut TextInput,
+        event: EventKind,
+        value: &str,
+        mask: InputMask,
+    ) -> Vec<TestAction> {
+        let props = TextInputProps {
+            mask,
+            ..props_with(value, &[])
+        };
+        input.handle_event(&event, props).into_iter().collect()
+    }
+
+    #[test]
+    fn test_mask_filters_input() {
+        let mut input = TextInput::new();
+
+        // Numeric: letters are rejected, digits pass
+        let mask = InputMask::numeric();
+        assert!(send_masked(&mut input, EventKind::Key(key("a")), "", mask).is_empty());
+        assert_eq!(
+            send_masked(&mut input, EventKind::Key(key("7")), "", mask),
+            vec![TestAction::Change("7".into())]
+        );
+
+        // Max length: pastes are truncated, typing stops at the limit
+        let mask = InputMask::default().with_max_len(4);
+        input.cursor = 2;
+        assert_eq!(
+            send_masked(&mut input, EventKind::Paste("xyz".into()), "ab", mask),
+            vec![TestAction::Change("abxy".into())]
+        );
+        assert!(send_masked(&mut input, EventKind::Key(key("c")), "abcd", mask).is_empty());
+
+        // Custom filter drops rejected characters from pastes
+        let mask = InputMask::filter(|c| c.is_ascii_lowercase());
+        input.cursor = 0;
+        assert_eq!(
+            send_masked(&mut input, EventKind::Paste("aB-c".into()), "", mask),
+            vec![TestAction::Change("ac".into())]
+        );
+    }
+
+    #[test]
+    fn test_password_renders_bullets() {
+        let mut render = RenderHarness::new(20, 3);
+        let mut input = TextInput::new();
+        input.cursor = 6;
+        let props = TextInputProps {
+            mask: InputMask::password(),
+            ..props_with("secret", &[])
+        };
+
+        let output = render.render_to_string_plain(|frame| {
+            input.render(frame, frame.area(), props);
+        });
+
+        assert!(output.contains("••••••"));
+        assert!(!output.contains("secret"));
+
+        // The real value is still emitted
+        let mut input = TextInput::new();
+        input.cursor = 6;
+        assert_eq!(
+            send_masked(
+                &mut input,
+                EventKind::Key(key("!")),
+                "secret",
+                InputMask::password()
+            ),
+            vec![TestAction::Change("secret!".into())]
+        );
+    }
+
+    fn send_mode(
+        input: &mut TextInput,
+        key_event: KeyEvent,
+        value: &str,
+        edit_mode: EditMode,
+    ) -> Vec<TestAction> {
+        let props = TextInputProps {
+            edit_mode,
+            ..props_with(value, &[])
+        };
+        input
+            .handle_event(&EventKind::Key(key_event), props)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_vim_modes_and_motions() {
+        let mut input = TextInput::new();
+        let value = "one two three";
+        input.cursor = value.len();
+
+        // Starts in insert mode: typing inserts
+        assert_eq!(
+            send_mode(&mut input, key("x"), "one two thre", EditMode::Vim),
+            vec![TestAction::Change("one two threx".into())]
+        );
+
+        // Esc enters normal mode and steps back onto the last character
+        input.cursor = value.len();
+        assert!(send_mode(&mut input, key("esc"), value, EditMode::Vim).is_empty());
+        assert!(input.is_normal_mode());
+        assert_eq!(input.cursor, 12);
+
+        // Word motions
+        for (k, cursor) in [("0", 0), ("w", 4), ("w", 8), ("b", 4), ("e", 6), ("$", 12)] {
+            assert!(send_mode(&mut input, key(k), value, EditMode::Vim).is_empty());
+            assert_eq!(input.cursor, cursor, "after {k}");
+        }
+
+        // `a` appends after the cursor and leaves normal mode
+        send_mode(&mut input, key("a"), value, EditMode::Vim);
+        assert!(!input.is_normal_mode());
+        assert_eq!(input.cursor, value.len());
+    }
+
+    #[test]
+    fn test_vim_operators() {
+        let mut input = TextInput::new();
+        input.normal_mode = true;
+
+        // dw deletes to the next word
+        input.cursor = 4;
+        assert!(send_mode(&mut input, key("d"), "one two three", EditMode::Vim).is_empty());
+        assert_eq!(
+            send_mode(&mut input, key("w"), "one two three", EditMode::Vim),
+            vec![TestAction::Change("one three".into())]
+        );
+
+        // cw changes to the end of the word and enters insert mode
+        input.cursor = 4;
+        send_mode(&mut input, key("c"), "one three", EditMode::Vim);
+        assert_eq!(
+            send_mode(&mut input, key("w"), "one three", EditMode::Vim),
+            vec![TestAction::Change("one ".into())]
+        );
+        assert!(!input.is_normal_mode());
+
+        // dd clears the line, and p pastes it back
+        input.normal_mode = true;
+        send_mode(&mut input, key("d"), "hello", EditMode::Vim);
+        assert_eq!(
+            send_mode(&mut input, key("d"), "hello", EditMode::Vim),
+            vec![TestAction::Change("".into())]
+        );
+        assert_eq!(
+            send_mode(&mut input, key("p"), "", EditMode::Vim),
+            vec![TestAction::Change("hello".into())]
+        );
+
+        // cc clears the line and enters insert mode
+        send_mode(&mut input, key("c"), "hello", EditMode::Vim);
+        assert_eq!(
+            send_mode(&mut input, key("c"), "hello", EditMode::Vim),
+            vec![TestAction::Change("".into())]
+        );
+        assert!(!input.is_normal_mode());
+
+        // An unknown motion cancels the operator
+        input.normal_mode = true;
+        input.cursor = 0;
+        send_mode(&mut input, key("d"), "abc", EditMode::Vim);
+        assert!(send_mode(&mut input, key("z"), "abc", EditMode::Vim).is_empty());
+        assert_eq!(
+            send_mode(&mut input, key("x"), "abc", EditMode::Vim),
+            vec![TestAction::Change("bc".into())]
+        );
+
+        // D deletes to the end, A appends at the end
+        input.cursor = 1;
+        assert_eq!(
+            send_mode(&mut input, char_key('D'), "abc", EditMode::Vim),
+            vec![TestAction::Change("a".into())]
+        );
+        send_mode(&mut input, char_key('A'), "a", EditMode::Vim);
+        assert!(!input.is_normal_mode());
+        assert_eq!(input.cursor, 1);
+    }
+
+    #[test]
+    fn test_vim_keys_ignored_in_other_modes() {
+        let mut input = TextInput::new();
+        assert!(send_mode(&mut input, key("esc"), "abc", EditMode::Standard).is_empty());
+        assert!(!input.is_normal_mode());
+        assert_eq!(
+            send_mode(&mut input, key("d"), "", EditMode::Standard),
+            vec![TestAction::Change("d".into())]
+        );
+    }
+
+    #[test]
+    fn test_emacs_bindings() {
+        let mut input = TextInput::new();
+        let value = "one two three";
+        input.cursor = 0;
+
+        assert!(send_mode(&mut input, key("alt+f"), value, EditMode::Emacs).is_empty());
+        assert_eq!(input.cursor, 3);
+        assert!(send_mode(&mut input, key("alt+f"), value, EditMode::Emacs).is_empty());
+        assert_eq!(input.cursor, 7);
+        assert!(send_mode(&mut input, key("alt+b"), value, EditMode::Emacs).is_empty());
+        assert_eq!(input.cursor, 4);
+        assert!(send_mode(&mut input, key("ctrl+f"), value, EditMode::Emacs).is_empty());
+        assert_eq!(input.cursor, 5);
+        assert!(send_mode(&mut input, key("ctrl+b"), value, EditMode::Emacs).is_empty());
+        assert_eq!(input.cursor, 4);
+
+        // Alt+D kills the next word, Ctrl+Y yanks it back
+        assert_eq!(
+            send_mode(&mut input, key("alt+d"), value, EditMode::Emacs),
+            vec![TestAction::Change("one  three".into())]
+        );
+        assert_eq!(
+            send_mode(&mut input, key("ctrl+y"), "one  three", EditMode::Emacs),
+            vec![TestAction::Change(value.into())]
+        );
+
+        // Ctrl+D deletes at the cursor; in standard mode it does nothing
+        input.cursor = 0;
+        assert_eq!(
+            send_mode(&mut input, key("ctrl+d"), "abc", EditMode::Emacs),
+            vec![TestAction::Change("bc".into())]
+        );
+        assert!(send_mode(&mut input, key("ctrl+d"), "abc", EditMode::Standard).is_empty());
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    struct InputContext;
+
+    impl BindingContext for InputContext {
+        fn name(&self) -> &'static str {
+            "input"
+        }
+
+        fn from_name(name: &str) -> Option<Self> {
+            (name == "input").then_some(InputContext)
+        }
+
+        fn all() -> &'static [Self] {
+            &[InputContext]
+        }
+    }
+
+    #[test]
+    fn test_keybindings_rebind_submit() {
+        let mut keybindings = Keybindings::new();
+        keybindings.add(InputContext, "input.submit", vec!["ctrl+s".into()]);
+        let mut input = TextInput::new();
+        input.cursor = 2;
+        let mut press = |k: &str| -> Vec<TestAction> {
+            let props = TextInputProps {
+                keybindings: Some(ComponentBindings::new(&keybindings, InputContext)),
+                ..props_with("hi", &[])
+            };
+            input
+                .handle_event(&EventKind::Key(key(k)), props)
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(press("ctrl+s"), vec![TestAction::Submit("hi".into())]);
+        assert!(press("enter").is_empty());
+        // Typing and unbound commands are unchanged
+        assert_eq!(press("x"), vec![TestAction::Change("hix".into())]);
+        assert_eq!(press("ctrl+u"), vec![TestAction::Change(String::new())]);
+    }
 }
@@ -0,0 +1,254 @@
+//! Toast notification stack component
+
+use crossterm::event::{MouseButton, MouseEventKind};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use tui_dispatch_core::notifications::{NotificationLevel, NotificationState};
+use tui_dispatch_core::{Component, EventKind};
+
+/// Height of a single toast (border + one line of text)
+const TOAST_HEIGHT: u16 = 3;
+
+/// Screen corner the toast stack is anchored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastCorner {
+    /// Top-left corner
+    TopLeft,
+    /// Top-right corner
+    #[default]
+    TopRight,
+    /// Bottom-left corner
+    BottomLeft,
+    /// Bottom-right corner
+    BottomRight,
+}
+
+/// Props for ToastStack component
+pub struct ToastStackProps<'a, A> {
+    /// Notification queue from app state
+    pub notifications: &'a NotificationState,
+    /// Corner to stack toasts in
+    pub corner: ToastCorner,
+    /// Width of each toast
+    pub width: u16,
+    /// Maximum number of toasts shown at once (newest win)
+    pub max_visible: usize,
+    /// Callback to create action when a toast is clicked
+    pub on_dismiss: fn(u64) -> A,
+}
+
+/// Stacked toast notifications anchored to a screen corner
+///
+/// Renders the newest notifications closest to the corner. Clicking a
+/// toast emits `on_dismiss` with its id. Expiry is driven by app state:
+/// call [`NotificationState::tick`] from the reducer on each `Tick`.
+#[derive(Default)]
+pub struct ToastStack {
+    /// Toast areas and ids from the last render, used for mouse handling
+    hit_areas: Vec<(Rect, u64)>,
+}
+
+impl ToastStack {
+    /// Create a new ToastStack
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Border color for a notification level
+fn level_color(level: NotificationLevel) -> Color {
+    match level {
+        NotificationLevel::Info => Color::Cyan,
+        NotificationLevel::Success => Color::Green,
+        NotificationLevel::Warning => Color::Yellow,
+        NotificationLevel::Error => Color::Red,
+    }
+}
+
+impl<A> Component<A> for ToastStack {
+    type Props<'a> = ToastStackProps<'a, A>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = A> {
+        match event {
+            EventKind::Mouse(mouse)
+                if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) =>
+            {
+                self.hit_areas.iter().find_map(|(area, id)| {
+                    let hit = mouse.column >= area.x
+                        && mouse.column < area.x.saturating_add(area.width)
+                        && mouse.row >= area.y
+                        && mouse.row < area.y.saturating_add(area.height);
+                    hit.then(|| (props.on_dismiss)(*id))
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        self.hit_areas.clear();
+
+        let width = props.width.min(area.width);
+        if width < 3 || area.height < TOAST_HEIGHT {
+            return;
+        }
+
+        let x = match props.corner {
+            ToastCorner::TopLeft | ToastCorner::BottomLeft => area.x,
+            ToastCorner::TopRight | ToastCorner::BottomRight => area.x + area.width - width,
+        };
+        let from_top = matches!(props.corner, ToastCorner::TopLeft | ToastCorner::TopRight);
+
+        let fits = (area.height / TOAST_HEIGHT) as usize;
+        let newest_first = props
+            .notifications
+            .iter()
+            .rev()
+            .take(props.max_visible.min(fits));
+
+        for (slot, notification) in newest_first.enumerate() {
+            let offset = slot as u16 * TOAST_HEIGHT;
+            let y = if from_top {
+                area.y + offset
+            } else {
+                area.y + area.height - TOAST_HEIGHT - offset
+            };
+            let toast_area = Rect::new(x, y, width, TOAST_HEIGHT);
+
+            let color = level_color(notification.level);
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color))
+                .title(Span::styled(
+                    format!(" {} ", notification.level.name()),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ));
+
+            frame.render_widget(Clear, toast_area);
+            frame.render_widget(
+                Paragraph::new(Line::raw(notification.message.as_str())).block(block),
+                toast_area,
+            );
+            self.hit_areas.push((toast_area, notification.id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyModifiers, MouseEvent};
+    use tui_dispatch_core::testing::RenderHarness;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestAction {
+        Dismiss(u64),
+    }
+
+    fn props(
+        notifications: &NotificationState,
+        corner: ToastCorner,
+    ) -> ToastStackProps<'_, TestAction> {
+        ToastStackProps {
+            notifications,
+            corner,
+            width: 20,
+            max_visible: 3,
+            on_dismiss: TestAction::Dismiss,
+        }
+    }
+
+    fn click(column: u16, row: u16) -> EventKind {
+        EventKind::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_render_newest_nearest_corner() {
+        let mut notifications = NotificationState::new();
+        notifications.info("first");
+        notifications.error("second");
+
+        let mut render = RenderHarness::new(40, 10);
+        let mut toasts = ToastStack::new();
+        let output = render.render_to_string_plain(|frame| {
+            toasts.render(
+                frame,
+                frame.area(),
+                props(&notifications, ToastCorner::TopRight),
+            );
+        });
+
+        let lines: Vec<_> = output.lines().collect();
+        assert!(lines[0].contains("Error"));
+        assert!(lines[1].contains("second"));
+        assert!(lines[3].contains("Info"));
+        assert!(lines[4].contains("first"));
+        // Right-aligned
+        assert!(lines[1].starts_with(&" ".repeat(20)));
+    }
+
+    #[test]
+    fn test_bottom_corner_and_max_visible() {
+        let mut notifications = NotificationState::new();
+        for i in 0..5 {
+            notifications.info(format!("msg {i}"));
+        }
+
+        let mut render = RenderHarness::new(40, 12);
+        let mut toasts = ToastStack::new();
+        let output = render.render_to_string_plain(|frame| {
+            toasts.render(
+                frame,
+                frame.area(),
+                props(&notifications, ToastCorner::BottomLeft),
+            );
+        });
+
+        let lines: Vec<_> = output.lines().collect();
+        assert!(lines[10].contains("msg 4"));
+        assert!(output.contains("msg 2"));
+        assert!(!output.contains("msg 1"));
+    }
+
+    #[test]
+    fn test_click_dismisses() {
+        let mut notifications = NotificationState::new();
+        let id = notifications.warning("click me");
+
+        let mut render = RenderHarness::new(40, 10);
+        let mut toasts = ToastStack::new();
+        render.render(|frame| {
+            toasts.render(
+                frame,
+                frame.area(),
+                props(&notifications, ToastCorner::TopLeft),
+            );
+        });
+
+        let actions: Vec<_> = toasts
+            .handle_event(&click(2, 1), props(&notifications, ToastCorner::TopLeft))
+            .into_iter()
+            .collect();
+        assert_eq!(actions, vec![TestAction::Dismiss(id)]);
+
+        let actions: Vec<_> = toasts
+            .handle_event(&click(30, 8), props(&notifications, ToastCorner::TopLeft))
+            .into_iter()
+            .collect();
+        assert!(actions.is_empty());
+    }
+}
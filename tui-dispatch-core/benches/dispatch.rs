@@ -0,0 +1,157 @@
+//! Dispatch overhead with and without action logging and the debug layer
+//!
+//! Run with `cargo bench -p tui-dispatch-core --bench dispatch`.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use crossterm::event::KeyCode;
+use tui_dispatch_core::debug::{
+    ActionLog, ActionLogConfig, ActionLoggerConfig, ActionLoggerMiddleware, DebugLayer,
+    DebugSection, DebugState,
+};
+use tui_dispatch_core::{Action, ActionParams, Store, StoreWithMiddleware};
+
+#[derive(Clone, Debug)]
+enum BenchAction {
+    Select(usize),
+    Load { path: String, lines: Vec<String> },
+}
+
+impl Action for BenchAction {
+    fn name(&self) -> &'static str {
+        match self {
+            BenchAction::Select(_) => "Select",
+            BenchAction::Load { .. } => "Load",
+        }
+    }
+}
+
+impl ActionParams for BenchAction {
+    fn params(&self) -> String {
+        match self {
+            BenchAction::Select(index) => format!("{index}"),
+            BenchAction::Load { path, lines } => format!("{path:?}, {lines:?}"),
+        }
+    }
+}
+
+#[derive(Default)]
+struct BenchState {
+    selected: usize,
+    lines: usize,
+}
+
+impl DebugState for BenchState {
+    fn debug_sections(&self) -> Vec<DebugSection> {
+        vec![DebugSection::new("State")
+            .entry("selected", self.selected.to_string())
+            .entry("lines", self.lines.to_string())]
+    }
+}
+
+fn reducer(state: &mut BenchState, action: BenchAction) -> bool {
+    match action {
+        BenchAction::Select(index) => state.selected = index,
+        BenchAction::Load { lines, .. } => state.lines = lines.len(),
+    }
+    true
+}
+
+fn load_action() -> BenchAction {
+    BenchAction::Load {
+        path: "src/main.rs".into(),
+        lines: (0..16).map(|i| format!("line {i}")).collect(),
+    }
+}
+
+fn log_all(capacity: usize) -> ActionLogConfig {
+    ActionLogConfig::new(capacity, ActionLoggerConfig::with_patterns(vec![], vec![]))
+}
+
+fn bench_dispatch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dispatch");
+    let action = load_action();
+
+    group.bench_function("store", |b| {
+        let mut store = Store::new(BenchState::default(), reducer);
+        b.iter(|| store.dispatch(black_box(action.clone())));
+    });
+
+    group.bench_function("action_logger_middleware", |b| {
+        let middleware = ActionLoggerMiddleware::with_log(log_all(100));
+        let mut store = StoreWithMiddleware::new(BenchState::default(), reducer, middleware);
+        b.iter(|| store.dispatch(black_box(action.clone())));
+    });
+
+    // What the runtimes do per action when a debug layer is attached
+    for (name, active, state_diff) in [
+        ("debug_layer_inactive", false, false),
+        ("debug_layer_active", true, false),
+        ("debug_layer_active_state_diff", true, true),
+    ] {
+        group.bench_function(name, |b| {
+            let mut store = Store::new(BenchState::default(), reducer);
+            let mut debug: DebugLayer<BenchAction> = DebugLayer::new(KeyCode::F(12))
+                .with_state_diff(state_diff)
+                .active(active);
+            b.iter(|| {
+                let action = black_box(action.clone());
+                debug.log_action(&action);
+                let changed = store.dispatch(action);
+                debug.record_dispatched_state(store.state(), changed);
+                changed
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_action_log(c: &mut Criterion) {
+    let mut group = c.benchmark_group("action_log");
+    let action = load_action();
+
+    // Steady state: the ring buffer is full and every log evicts an entry
+    group.bench_function("log_full", |b| {
+        let mut log = ActionLog::new(log_all(100));
+        for _ in 0..100 {
+            log.log(&action);
+        }
+        b.iter(|| {
+            log.log(black_box(&action));
+        });
+    });
+
+    group.bench_function("log_filtered_out", |b| {
+        let mut log = ActionLog::new(ActionLogConfig::new(
+            100,
+            ActionLoggerConfig::with_patterns(vec![], vec!["Load".into()]),
+        ));
+        b.iter(|| log.log(black_box(&action)).is_some());
+    });
+
+    // Formatting cost paid when the overlay opens
+    group.bench_function("format_100", |b| {
+        b.iter_batched(
+            || {
+                let mut log = ActionLog::new(log_all(100));
+                for _ in 0..100 {
+                    log.log(&action);
+                }
+                log
+            },
+            |log| {
+                log.entries()
+                    .map(|entry| entry.params().len())
+                    .sum::<usize>()
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dispatch, bench_action_log);
+criterion_main!(benches);
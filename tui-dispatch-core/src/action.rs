@@ -3,6 +3,8 @@
 use std::fmt::Debug;
 use std::hash::Hash;
 
+use serde::{Deserialize, Serialize};
+
 /// Marker trait for actions that can be dispatched to the store
 ///
 /// Actions represent intents to change state. They should be:
@@ -77,6 +79,114 @@ pub trait ActionParams: Action {
     fn params(&self) -> String;
 }
 
+/// Trait for actions that can describe their own variants
+///
+/// Auto-implemented by `#[derive(Action)]`, which also adds an inherent
+/// `schema()` on the enum. The schema lists every variant with its fields,
+/// category (with `infer_categories`) and doc comments, for generating
+/// keybinding docs, validating replay files or feeding remote tooling.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Action, Clone, Debug)]
+/// #[action(infer_categories)]
+/// enum MyAction {
+///     /// Move the selection
+///     ListMove { delta: i32 },
+///     Quit,
+/// }
+///
+/// let schema = MyAction::schema();
+/// assert_eq!(schema.variants[0].name, "ListMove");
+/// assert_eq!(schema.variants[0].category.as_deref(), Some("list"));
+/// assert_eq!(schema.variants[0].fields[0].ty, "i32");
+/// println!("{}", schema.to_json());
+/// ```
+pub trait ActionDescribe: Action {
+    /// Describe every variant of this action type
+    fn schema() -> ActionSchema;
+}
+
+/// Machine-readable description of an action enum
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionSchema {
+    /// Enum name, e.g. `"AppAction"`
+    pub name: String,
+    /// Doc comment on the enum
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    /// Variants in declaration order
+    pub variants: Vec<VariantSchema>,
+}
+
+impl ActionSchema {
+    /// Look up a variant by name (as returned by [`Action::name`])
+    pub fn variant(&self, name: &str) -> Option<&VariantSchema> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+
+    /// Names of all variants in a category
+    pub fn category(&self, category: &str) -> Vec<&str> {
+        self.variants
+            .iter()
+            .filter(|variant| variant.category.as_deref() == Some(category))
+            .map(|variant| variant.name.as_str())
+            .collect()
+    }
+
+    /// The schema as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// One variant of an [`ActionSchema`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariantSchema {
+    /// Variant name, e.g. `"SearchStart"`
+    pub name: String,
+    /// Category from `infer_categories` or `#[action(category = "...")]`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Doc comment on the variant
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    /// Unit, tuple or struct variant
+    pub kind: VariantKind,
+    /// Fields in declaration order (empty for unit variants)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldSchema>,
+}
+
+/// Shape of a variant's fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VariantKind {
+    /// `Quit`
+    Unit,
+    /// `Select(usize)`
+    Tuple,
+    /// `Move { delta: i32 }`
+    Struct,
+}
+
+/// One field of a [`VariantSchema`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldSchema {
+    /// Field name (None for tuple fields)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Type as written in the source, e.g. `"Vec<String>"`
+    pub ty: String,
+    /// Doc comment on the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    /// Marked `#[param(redact)]`; its value is hidden in logs
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub redacted: bool,
+}
+
 /// Trait for actions that provide a summary representation for logging
 ///
 /// The default implementation uses the Debug representation. Override
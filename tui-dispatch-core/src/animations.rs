@@ -0,0 +1,385 @@
+//! Named tweens for smooth UI transitions
+//!
+//! [`Animations`] is a small state helper meant to live inside app state,
+//! like [`NotificationState`](crate::notifications::NotificationState).
+//! Reducers start tweens by name and advance them on each `Tick` action (or
+//! by the real elapsed time); render code reads the interpolated values.
+//!
+//! # Example
+//!
+//! ```
+//! use std::time::Duration;
+//! use tui_dispatch_core::animations::{Animations, Easing};
+//!
+//! let mut animations = Animations::new().with_tick_interval(Duration::from_millis(50));
+//! animations.start("scroll", 0.0, 10.0, Duration::from_millis(100), Easing::Linear);
+//!
+//! // In the reducer, on Tick:
+//! assert!(animations.tick());
+//! assert_eq!(animations.value("scroll"), Some(5.0));
+//! animations.tick();
+//! assert_eq!(animations.value("scroll"), Some(10.0));
+//!
+//! // Finished tweens keep their final value
+//! assert!(!animations.is_animating());
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use ratatui::style::Color;
+
+/// Easing curve applied to tween progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Easing {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// Quadratic, slow start
+    EaseIn,
+    /// Quadratic, slow end
+    EaseOut,
+    /// Quadratic, slow start and end
+    EaseInOut,
+    /// Cubic, slow start
+    EaseInCubic,
+    /// Cubic, slow end
+    EaseOutCubic,
+    /// Cubic, slow start and end
+    EaseInOutCubic,
+}
+
+impl Easing {
+    /// Map linear progress `t` (clamped to `0.0..=1.0`) onto the curve
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::EaseInCubic => t * t * t,
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// Linear interpolation between two values
+pub fn lerp(from: f64, to: f64, t: f64) -> f64 {
+    from + (to - from) * t
+}
+
+/// Interpolate between two colors
+///
+/// RGB colors blend per channel. Other colors can't be blended, so the
+/// result switches from `from` to `to` halfway through.
+pub fn lerp_color(from: Color, to: Color, t: f64) -> Color {
+    match (from, to) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let channel =
+                |a: u8, b: u8| lerp(a as f64, b as f64, t).round().clamp(0.0, 255.0) as u8;
+            Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+        }
+        _ if t < 0.5 => from,
+        _ => to,
+    }
+}
+
+/// A single value moving from `from` to `to` over `duration`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween {
+    /// Start value
+    pub from: f64,
+    /// End value
+    pub to: f64,
+    /// Total duration
+    pub duration: Duration,
+    /// Time elapsed so far
+    pub elapsed: Duration,
+    /// Easing curve
+    pub easing: Easing,
+}
+
+impl Tween {
+    /// Create a tween at its start
+    pub fn new(from: f64, to: f64, duration: Duration, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            elapsed: Duration::ZERO,
+            easing,
+        }
+    }
+
+    /// Linear progress in `0.0..=1.0` (before easing)
+    pub fn progress(&self) -> f64 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        (self.elapsed.as_secs_f64() / self.duration.as_secs_f64()).min(1.0)
+    }
+
+    /// Current interpolated value
+    pub fn value(&self) -> f64 {
+        if self.is_finished() {
+            return self.to;
+        }
+        lerp(self.from, self.to, self.easing.apply(self.progress()))
+    }
+
+    /// Whether the tween has reached its end value
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Advance by `dt`; returns whether the tween was still running
+    pub fn advance(&mut self, dt: Duration) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+        true
+    }
+}
+
+/// Named tweens advanced by ticks or elapsed time
+///
+/// Finished tweens keep reporting their end value until removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animations {
+    tweens: HashMap<String, Tween>,
+    tick_interval: Duration,
+}
+
+impl Default for Animations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Animations {
+    /// Create an empty set (16ms tick interval)
+    pub fn new() -> Self {
+        Self {
+            tweens: HashMap::new(),
+            tick_interval: Duration::from_millis(16),
+        }
+    }
+
+    /// Set how much time one [`tick`](Self::tick) represents
+    ///
+    /// Match this to the app's `Tick` action rate.
+    pub fn with_tick_interval(mut self, interval: Duration) -> Self {
+        self.tick_interval = interval;
+        self
+    }
+
+    /// Start (or restart) a tween
+    pub fn start(
+        &mut self,
+        name: impl Into<String>,
+        from: f64,
+        to: f64,
+        duration: Duration,
+        easing: Easing,
+    ) {
+        self.tweens
+            .insert(name.into(), Tween::new(from, to, duration, easing));
+    }
+
+    /// Animate towards `to`, starting from the tween's current value
+    ///
+    /// Retargeting a running tween stays smooth. If the name is unknown the
+    /// value jumps straight to `to`.
+    pub fn animate_to(
+        &mut self,
+        name: impl Into<String>,
+        to: f64,
+        duration: Duration,
+        easing: Easing,
+    ) {
+        let name = name.into();
+        let from = self.value(&name).unwrap_or(to);
+        self.start(name, from, to, duration, easing);
+    }
+
+    /// Set a value immediately, without animating
+    pub fn set(&mut self, name: impl Into<String>, value: f64) {
+        self.start(name, value, value, Duration::ZERO, Easing::Linear);
+    }
+
+    /// Current value of a tween
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.tweens.get(name).map(Tween::value)
+    }
+
+    /// Current value of a tween, or `default` if it doesn't exist
+    pub fn value_or(&self, name: &str, default: f64) -> f64 {
+        self.value(name).unwrap_or(default)
+    }
+
+    /// Eased progress (`0.0..=1.0`) of a tween
+    pub fn progress(&self, name: &str) -> Option<f64> {
+        self.tweens
+            .get(name)
+            .map(|tween| tween.easing.apply(tween.progress()))
+    }
+
+    /// Interpolate between two colors using a tween's eased progress
+    ///
+    /// Returns `to` if the tween doesn't exist.
+    pub fn color(&self, name: &str, from: Color, to: Color) -> Color {
+        lerp_color(from, to, self.progress(name).unwrap_or(1.0))
+    }
+
+    /// Get a tween by name
+    pub fn get(&self, name: &str) -> Option<&Tween> {
+        self.tweens.get(name)
+    }
+
+    /// Whether a tween is still running
+    pub fn is_running(&self, name: &str) -> bool {
+        self.tweens
+            .get(name)
+            .is_some_and(|tween| !tween.is_finished())
+    }
+
+    /// Whether any tween is still running
+    pub fn is_animating(&self) -> bool {
+        self.tweens.values().any(|tween| !tween.is_finished())
+    }
+
+    /// Remove a tween; returns whether it was present
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.tweens.remove(name).is_some()
+    }
+
+    /// Remove all tweens
+    pub fn clear(&mut self) {
+        self.tweens.clear();
+    }
+
+    /// Advance time by one tick interval
+    ///
+    /// Returns whether any tween moved (i.e. a re-render is needed).
+    pub fn tick(&mut self) -> bool {
+        self.advance(self.tick_interval)
+    }
+
+    /// Advance time by `dt`, for apps that measure real elapsed time
+    ///
+    /// Returns whether any tween moved (i.e. a re-render is needed).
+    pub fn advance(&mut self, dt: Duration) -> bool {
+        let mut moved = false;
+        for tween in self.tweens.values_mut() {
+            moved |= tween.advance(dt);
+        }
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS_100: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn test_easing_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::EaseInCubic,
+            Easing::EaseOutCubic,
+            Easing::EaseInOutCubic,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0, "{easing:?}");
+            assert_eq!(easing.apply(1.0), 1.0, "{easing:?}");
+            assert_eq!(easing.apply(2.0), 1.0, "{easing:?}");
+        }
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    }
+
+    #[test]
+    fn test_tick_advances_tweens() {
+        let mut animations = Animations::new().with_tick_interval(Duration::from_millis(25));
+        animations.start("fade", 0.0, 1.0, MS_100, Easing::Linear);
+
+        assert_eq!(animations.value("fade"), Some(0.0));
+        assert!(animations.tick());
+        assert_eq!(animations.value("fade"), Some(0.25));
+        assert!(animations.is_running("fade"));
+
+        for _ in 0..3 {
+            animations.tick();
+        }
+        assert_eq!(animations.value("fade"), Some(1.0));
+        assert!(!animations.is_animating());
+        assert!(!animations.tick());
+    }
+
+    #[test]
+    fn test_advance_by_elapsed_time() {
+        let mut animations = Animations::new();
+        animations.start("x", 10.0, 20.0, MS_100, Easing::Linear);
+        animations.advance(Duration::from_millis(500));
+        assert_eq!(animations.value("x"), Some(20.0));
+    }
+
+    #[test]
+    fn test_animate_to_retargets_from_current_value() {
+        let mut animations = Animations::new();
+        animations.animate_to("scroll", 5.0, MS_100, Easing::Linear);
+        assert_eq!(animations.value("scroll"), Some(5.0));
+
+        animations.animate_to("scroll", 15.0, MS_100, Easing::Linear);
+        animations.advance(Duration::from_millis(50));
+        assert_eq!(animations.value("scroll"), Some(10.0));
+
+        animations.animate_to("scroll", 0.0, MS_100, Easing::Linear);
+        assert_eq!(animations.get("scroll").unwrap().from, 10.0);
+    }
+
+    #[test]
+    fn test_set_and_remove() {
+        let mut animations = Animations::new();
+        animations.set("offset", 3.0);
+        assert_eq!(animations.value_or("offset", 0.0), 3.0);
+        assert!(!animations.is_animating());
+        assert!(animations.remove("offset"));
+        assert_eq!(animations.value_or("offset", 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_color_lerp() {
+        let from = Color::Rgb(0, 0, 0);
+        let to = Color::Rgb(200, 100, 50);
+        assert_eq!(lerp_color(from, to, 0.5), Color::Rgb(100, 50, 25));
+        assert_eq!(lerp_color(Color::Red, Color::Blue, 0.4), Color::Red);
+        assert_eq!(lerp_color(Color::Red, Color::Blue, 0.6), Color::Blue);
+
+        let mut animations = Animations::new();
+        animations.start("modal", 0.0, 1.0, MS_100, Easing::Linear);
+        animations.advance(Duration::from_millis(50));
+        assert_eq!(animations.color("modal", from, to), Color::Rgb(100, 50, 25));
+        assert_eq!(animations.color("missing", from, to), to);
+    }
+}
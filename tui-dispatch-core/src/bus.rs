@@ -1,9 +1,46 @@
 //! Event bus for dispatching events to subscribed components
+//!
+//! Components subscribe to [`EventType`]s by [`ComponentId`]. [`EventBus::route`]
+//! picks which subscribers receive an event, and [`EventBus::dispatch`] feeds
+//! each of them to a handler and sends the resulting actions:
+//!
+//! - **Key** events go to the focused component
+//! - **Mouse** and **Scroll** events go to the component under the pointer
+//! - **Resize** and **Tick** events go to every subscriber
+//! - While a modal is open, key, mouse and scroll events only reach the modal
+//! - Global events (Esc, Ctrl+C, Ctrl+Q, resize) also reach `Global` subscribers
+//! - Focus changes only go to `Global` subscribers
+//!
+//! # Example
+//!
+//! ```ignore
+//! bus.subscribe_many(Id::Sidebar, &[EventType::Key, EventType::Scroll]);
+//! bus.subscribe_many(Id::Editor, &[EventType::Key, EventType::Mouse]);
+//! bus.subscribe(Id::App, EventType::Global);
+//!
+//! bus.dispatch(process_raw_event(raw), |id, event| -> Vec<Action> {
+//!     match id {
+//!         Id::Sidebar => sidebar
+//!             .handle_event(&event.kind, sidebar_props(&state))
+//!             .into_iter()
+//!             .collect(),
+//!         Id::Editor => editor
+//!             .handle_event(&event.kind, editor_props(&state))
+//!             .into_iter()
+//!             .collect(),
+//!         Id::App => app_keys(event),
+//!     }
+//! });
+//! ```
 
 use crate::event::{ComponentId, Event, EventContext, EventKind, EventType};
+use crate::runtime::EventOutcome;
 use crate::Action;
-use crossterm::event::{self, KeyModifiers, MouseEventKind};
+use crossterm::event::{
+    self, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, KeyboardEnhancementFlags, MouseEventKind,
+};
 use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
@@ -15,6 +52,9 @@ pub enum RawEvent {
     Key(crossterm::event::KeyEvent),
     Mouse(crossterm::event::MouseEvent),
     Resize(u16, u16),
+    Paste(String),
+    FocusGained,
+    FocusLost,
 }
 
 /// Event bus that manages subscriptions and dispatches events
@@ -97,6 +137,133 @@ impl<A: Action, C: ComponentId> EventBus<A, C> {
         subscribers.into_iter().collect()
     }
 
+    /// Get the components that should receive an event
+    ///
+    /// Only subscribers of the event's type are considered. Key events go to
+    /// the focused component, mouse and scroll events to the component under
+    /// the pointer, and resize/tick events to every subscriber. While a modal
+    /// is open, key, mouse and scroll events only reach the modal. Global
+    /// events are also delivered to `Global` subscribers. The focused
+    /// component, if targeted, comes first.
+    pub fn route(&self, event: &Event<C>) -> Vec<C> {
+        let context = &event.context;
+        let subscribed = |component: &C| {
+            self.subscriptions
+                .get(&event.event_type())
+                .is_some_and(|subs| subs.contains(component))
+        };
+
+        let mut targets: Vec<C> = match &event.kind {
+            EventKind::Key(_) | EventKind::KeyRelease(_) | EventKind::Paste(_) => context
+                .active_modal
+                .or(context.focused_component)
+                .filter(subscribed)
+                .into_iter()
+                .collect(),
+            EventKind::Mouse(mouse) => context
+                .component_at(mouse.column, mouse.row)
+                .filter(|id| context.active_modal.is_none_or(|modal| modal == *id))
+                .filter(subscribed)
+                .into_iter()
+                .collect(),
+            EventKind::Scroll { column, row, .. } => context
+                .component_at(*column, *row)
+                .filter(|id| context.active_modal.is_none_or(|modal| modal == *id))
+                .filter(subscribed)
+                .into_iter()
+                .collect(),
+            EventKind::Resize(..)
+            | EventKind::Tick
+            | EventKind::FocusGained
+            | EventKind::FocusLost => self.get_subscribers(event.event_type()),
+        };
+
+        if event.is_global() {
+            if let Some(global_subs) = self.subscriptions.get(&EventType::Global) {
+                for &component in global_subs {
+                    if !targets.contains(&component) {
+                        targets.push(component);
+                    }
+                }
+            }
+        }
+
+        if let Some(focused) = context.focused_component {
+            if let Some(index) = targets.iter().position(|&id| id == focused) {
+                targets[..=index].rotate_right(1);
+            }
+        }
+
+        targets
+    }
+
+    /// Route an event to its target components and send their actions
+    ///
+    /// Updates the mouse position and modifiers in the context, builds the
+    /// event, then calls `handler` once per component returned by
+    /// [`route`](Self::route). Returns the number of actions sent.
+    pub fn dispatch<F, I>(&mut self, kind: EventKind, mut handler: F) -> usize
+    where
+        F: FnMut(C, &Event<C>) -> I,
+        I: IntoIterator<Item = A>,
+    {
+        self.update_context(&kind);
+
+        let event = self.create_event(kind);
+        let mut sent = 0;
+        for component in self.route(&event) {
+            for action in handler(component, &event) {
+                if self.action_tx.send(action).is_ok() {
+                    sent += 1;
+                }
+            }
+        }
+        sent
+    }
+
+    /// Update the mouse position and modifiers from an incoming event
+    fn update_context(&mut self, kind: &EventKind) {
+        match kind {
+            EventKind::Key(key) | EventKind::KeyRelease(key) => {
+                self.update_modifiers(key.modifiers)
+            }
+            EventKind::Mouse(mouse) => {
+                self.update_mouse_position(mouse.column, mouse.row);
+                self.update_modifiers(mouse.modifiers);
+            }
+            EventKind::Scroll { column, row, .. } => self.update_mouse_position(*column, *row),
+            EventKind::Paste(_)
+            | EventKind::Resize(..)
+            | EventKind::Tick
+            | EventKind::FocusGained
+            | EventKind::FocusLost => {}
+        }
+    }
+
+    /// Offer an event to its target components until one consumes it
+    ///
+    /// Like [`dispatch`](Self::dispatch), but `handler` returns an
+    /// [`EventOutcome`] and components are tried in [`route`](Self::route)
+    /// order (focused component first), stopping at the first that consumes
+    /// the event. Actions are not sent; return the outcome from `map_event`
+    /// so the runtime enqueues them and honors the render request.
+    pub fn bubble<F>(&mut self, kind: EventKind, mut handler: F) -> EventOutcome<A>
+    where
+        F: FnMut(C, &Event<C>) -> EventOutcome<A>,
+    {
+        self.update_context(&kind);
+
+        let event = self.create_event(kind);
+        let mut outcome = EventOutcome::ignored();
+        for component in self.route(&event) {
+            outcome = outcome.or_else(|| handler(component, &event));
+            if outcome.consumed {
+                break;
+            }
+        }
+        outcome
+    }
+
     /// Get mutable reference to context
     pub fn context_mut(&mut self) -> &mut EventContext<C> {
         &mut self.context
@@ -148,6 +315,26 @@ pub fn spawn_event_poller(
     poll_timeout: Duration,
     loop_sleep: Duration,
     cancel_token: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    spawn_gated_event_poller(
+        tx,
+        poll_timeout,
+        loop_sleep,
+        cancel_token,
+        Arc::new(Mutex::new(())),
+    )
+}
+
+/// Spawn the event poller, reading only while `gate` is unlocked
+///
+/// Holding the gate's lock pauses polling, so a child process can read the
+/// terminal without the poller stealing its input.
+pub(crate) fn spawn_gated_event_poller(
+    tx: mpsc::UnboundedSender<RawEvent>,
+    poll_timeout: Duration,
+    loop_sleep: Duration,
+    cancel_token: CancellationToken,
+    gate: Arc<Mutex<()>>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         const MAX_EVENTS_PER_BATCH: usize = 20;
@@ -163,6 +350,7 @@ pub fn spawn_event_poller(
                     break;
                 }
                 _ = tokio::time::sleep(loop_sleep) => {
+                    let _open = gate.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
                     // Process up to MAX_EVENTS_PER_BATCH events per iteration
                     let mut events_processed = 0;
                     while events_processed < MAX_EVENTS_PER_BATCH
@@ -174,7 +362,9 @@ pub fn spawn_event_poller(
                                 event::Event::Key(key) => Some(RawEvent::Key(key)),
                                 event::Event::Mouse(mouse) => Some(RawEvent::Mouse(mouse)),
                                 event::Event::Resize(w, h) => Some(RawEvent::Resize(w, h)),
-                                _ => None,
+                                event::Event::Paste(text) => Some(RawEvent::Paste(text)),
+                                event::Event::FocusGained => Some(RawEvent::FocusGained),
+                                event::Event::FocusLost => Some(RawEvent::FocusLost),
                             };
                             if let Some(raw) = raw {
                                 if tx.send(raw).is_err() {
@@ -191,9 +381,22 @@ pub fn spawn_event_poller(
 }
 
 /// Process a raw event into an EventKind
+///
+/// Key releases become [`EventKind::KeyRelease`], so handlers matching
+/// [`EventKind::Key`] only see presses and repeats, also on Windows, which
+/// reports releases without the kitty keyboard protocol. On Windows, AltGr
+/// characters (reported as Ctrl+Alt) lose those modifiers so they match
+/// plain character bindings.
 pub fn process_raw_event(raw: RawEvent) -> EventKind {
     match raw {
-        RawEvent::Key(key) => EventKind::Key(key),
+        RawEvent::Key(key) => {
+            let key = if cfg!(windows) { strip_altgr(key) } else { key };
+            if key.kind == KeyEventKind::Release {
+                EventKind::KeyRelease(key)
+            } else {
+                EventKind::Key(key)
+            }
+        }
         RawEvent::Mouse(mouse) => match mouse.kind {
             MouseEventKind::ScrollDown => EventKind::Scroll {
                 column: mouse.column,
@@ -208,7 +411,99 @@ pub fn process_raw_event(raw: RawEvent) -> EventKind {
             _ => EventKind::Mouse(mouse),
         },
         RawEvent::Resize(w, h) => EventKind::Resize(w, h),
+        RawEvent::Paste(text) => EventKind::Paste(text),
+        RawEvent::FocusGained => EventKind::FocusGained,
+        RawEvent::FocusLost => EventKind::FocusLost,
+    }
+}
+
+/// Drop the Ctrl+Alt that Windows reports for AltGr characters like `@`
+///
+/// Ctrl+Alt with a letter or digit is kept, as that is a real chord.
+fn strip_altgr(mut key: KeyEvent) -> KeyEvent {
+    let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    if let KeyCode::Char(c) = key.code {
+        if key.modifiers.contains(altgr) && !c.is_ascii_alphanumeric() {
+            key.modifiers.remove(altgr);
+        }
     }
+    key
+}
+
+/// Enable bracketed paste on stdout
+///
+/// Pasted text then arrives as a single [`EventKind::Paste`] instead of one
+/// key event per character. Call during terminal setup, alongside entering
+/// the alternate screen, and pair with [`disable_bracketed_paste`] on exit.
+pub fn enable_bracketed_paste() -> std::io::Result<()> {
+    crossterm::execute!(std::io::stdout(), event::EnableBracketedPaste)
+}
+
+/// Disable bracketed paste on stdout
+///
+/// Call during terminal cleanup to undo [`enable_bracketed_paste`].
+pub fn disable_bracketed_paste() -> std::io::Result<()> {
+    crossterm::execute!(std::io::stdout(), event::DisableBracketedPaste)
+}
+
+/// Enable focus change reporting on stdout
+///
+/// The terminal then reports [`EventKind::FocusGained`] and
+/// [`EventKind::FocusLost`] when its window gains or loses focus. Not every
+/// terminal supports it. Pair with [`disable_focus_change`] on exit.
+pub fn enable_focus_change() -> std::io::Result<()> {
+    crossterm::execute!(std::io::stdout(), event::EnableFocusChange)
+}
+
+/// Disable focus change reporting on stdout
+///
+/// Call during terminal cleanup to undo [`enable_focus_change`].
+pub fn disable_focus_change() -> std::io::Result<()> {
+    crossterm::execute!(std::io::stdout(), event::DisableFocusChange)
+}
+
+/// Flags pushed by [`enable_keyboard_enhancement`]
+pub(crate) fn keyboard_enhancement_flags() -> KeyboardEnhancementFlags {
+    KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+        | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+        | KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
+}
+
+/// Whether the terminal supports the kitty keyboard protocol
+///
+/// Queries the terminal, so call it during setup before the event poller
+/// starts. Always `false` on Windows, whose console reports key releases
+/// without the protocol.
+pub fn supports_keyboard_enhancement() -> std::io::Result<bool> {
+    crossterm::terminal::supports_keyboard_enhancement()
+}
+
+/// Enable the kitty keyboard protocol on stdout, if the terminal supports it
+///
+/// The terminal then reports key repeats (`KeyEventKind::Repeat` on
+/// [`EventKind::Key`]) and releases ([`EventKind::KeyRelease`]), modifiers
+/// such as Super and Hyper, and keys like Ctrl+I apart from Tab. Returns
+/// whether the protocol was enabled; apps that need releases (hold-to-scroll,
+/// games) should fall back to repeats or timeouts when it wasn't. Pair with
+/// [`disable_keyboard_enhancement`] on exit, and set
+/// `TerminalModes::keyboard_enhancement` so suspend and the panic hook
+/// restore it.
+pub fn enable_keyboard_enhancement() -> std::io::Result<bool> {
+    if !supports_keyboard_enhancement()? {
+        return Ok(false);
+    }
+    crossterm::execute!(
+        std::io::stdout(),
+        event::PushKeyboardEnhancementFlags(keyboard_enhancement_flags())
+    )?;
+    Ok(true)
+}
+
+/// Disable the kitty keyboard protocol on stdout
+///
+/// Call during terminal cleanup to undo [`enable_keyboard_enhancement`].
+pub fn disable_keyboard_enhancement() -> std::io::Result<()> {
+    crossterm::execute!(std::io::stdout(), event::PopKeyboardEnhancementFlags)
 }
 
 #[cfg(test)]
@@ -272,6 +567,157 @@ mod tests {
         assert!(bus.get_subscribers(EventType::Scroll).is_empty());
     }
 
+    fn key_event(code: crossterm::event::KeyCode) -> EventKind {
+        EventKind::Key(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn scroll_at(column: u16, row: u16) -> EventKind {
+        EventKind::Scroll {
+            column,
+            row,
+            delta: 1,
+        }
+    }
+
+    fn routing_bus() -> (
+        EventBus<TestAction, NumericComponentId>,
+        mpsc::UnboundedReceiver<TestAction>,
+    ) {
+        use ratatui::layout::Rect;
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut bus = EventBus::new(tx);
+        let left = NumericComponentId(1);
+        let right = NumericComponentId(2);
+        bus.subscribe_many(left, &[EventType::Key, EventType::Scroll, EventType::Tick]);
+        bus.subscribe_many(right, &[EventType::Key, EventType::Scroll, EventType::Tick]);
+        bus.context_mut()
+            .set_component_area(left, Rect::new(0, 0, 10, 10));
+        bus.context_mut()
+            .set_component_area(right, Rect::new(10, 0, 10, 10));
+        bus.context_mut().set_focus(Some(left));
+        (bus, rx)
+    }
+
+    #[test]
+    fn test_route_key_to_focused() {
+        use crossterm::event::KeyCode;
+
+        let (mut bus, _rx) = routing_bus();
+        let event = bus.create_event(key_event(KeyCode::Char('j')));
+        assert_eq!(bus.route(&event), vec![NumericComponentId(1)]);
+
+        bus.context_mut().set_focus(Some(NumericComponentId(3)));
+        let event = bus.create_event(key_event(KeyCode::Char('j')));
+        assert!(bus.route(&event).is_empty());
+    }
+
+    #[test]
+    fn test_route_scroll_by_position() {
+        let (bus, _rx) = routing_bus();
+
+        let event = bus.create_event(scroll_at(12, 3));
+        assert_eq!(bus.route(&event), vec![NumericComponentId(2)]);
+
+        let event = bus.create_event(scroll_at(30, 3));
+        assert!(bus.route(&event).is_empty());
+    }
+
+    #[test]
+    fn test_route_broadcast_and_global() {
+        use crossterm::event::KeyCode;
+
+        let (mut bus, _rx) = routing_bus();
+        let app = NumericComponentId(9);
+        bus.subscribe(app, EventType::Global);
+
+        let event = bus.create_event(EventKind::Tick);
+        let targets = bus.route(&event);
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0], NumericComponentId(1));
+
+        let event = bus.create_event(key_event(KeyCode::Esc));
+        assert_eq!(bus.route(&event), vec![NumericComponentId(1), app]);
+
+        let event = bus.create_event(EventKind::FocusLost);
+        assert_eq!(bus.route(&event), vec![app]);
+    }
+
+    #[test]
+    fn test_route_modal_captures_input() {
+        use crossterm::event::KeyCode;
+        use ratatui::layout::Rect;
+
+        let (mut bus, _rx) = routing_bus();
+        let modal = NumericComponentId(5);
+        bus.subscribe_many(modal, &[EventType::Key, EventType::Scroll]);
+        bus.context_mut()
+            .set_component_area(modal, Rect::new(2, 2, 4, 4));
+        bus.context_mut().set_modal(Some(modal));
+
+        let event = bus.create_event(key_event(KeyCode::Char('j')));
+        assert_eq!(bus.route(&event), vec![modal]);
+
+        let event = bus.create_event(scroll_at(3, 3));
+        assert_eq!(bus.route(&event), vec![modal]);
+
+        let event = bus.create_event(scroll_at(12, 3));
+        assert!(bus.route(&event).is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_sends_handler_actions() {
+        let (mut bus, mut rx) = routing_bus();
+
+        let mut seen = Vec::new();
+        let sent = bus.dispatch(scroll_at(12, 3), |id, _event| {
+            seen.push(id);
+            vec![TestAction::Test, TestAction::Test]
+        });
+
+        assert_eq!(sent, 2);
+        assert_eq!(seen, vec![NumericComponentId(2)]);
+        assert_eq!(bus.context().mouse_position, Some((12, 3)));
+        assert!(rx.try_recv().is_ok());
+        assert!(rx.try_recv().is_ok());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_bubble_stops_at_consumer() {
+        use crossterm::event::KeyCode;
+
+        let (mut bus, mut rx) = routing_bus();
+        let app = NumericComponentId(9);
+        bus.subscribe(app, EventType::Global);
+
+        // The focused component swallows the key; the app never sees it
+        let mut seen = Vec::new();
+        let outcome = bus.bubble(key_event(KeyCode::Esc), |id, _event| {
+            seen.push(id);
+            EventOutcome::consumed()
+        });
+        assert!(outcome.consumed);
+        assert_eq!(seen, vec![NumericComponentId(1)]);
+
+        // Unhandled by the focused component: falls through to the app,
+        // keeping the render request
+        let mut seen = Vec::new();
+        let outcome = bus.bubble(key_event(KeyCode::Esc), |id, _event| {
+            seen.push(id);
+            if id == app {
+                EventOutcome::action(TestAction::Test)
+            } else {
+                EventOutcome::ignored().with_render()
+            }
+        });
+        assert_eq!(seen, vec![NumericComponentId(1), app]);
+        assert!(outcome.consumed && outcome.needs_render);
+        assert_eq!(outcome.actions.len(), 1);
+        // Actions are returned, not sent
+        assert!(rx.try_recv().is_err());
+    }
+
     #[test]
     fn test_process_raw_event_key() {
         use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
@@ -287,6 +733,56 @@ mod tests {
         assert!(matches!(kind, EventKind::Key(_)));
     }
 
+    #[test]
+    fn test_process_raw_event_key_release() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+        let release = KeyEvent::new_with_kind(
+            KeyCode::Char('j'),
+            KeyModifiers::SUPER,
+            KeyEventKind::Release,
+        );
+        let kind = process_raw_event(RawEvent::Key(release));
+        assert!(matches!(kind, EventKind::KeyRelease(key) if key.modifiers == KeyModifiers::SUPER));
+        assert_eq!(kind.event_type(), EventType::Key);
+
+        // Repeats stay key events, with the kind preserved
+        let repeat =
+            KeyEvent::new_with_kind(KeyCode::Char('j'), KeyModifiers::NONE, KeyEventKind::Repeat);
+        let kind = process_raw_event(RawEvent::Key(repeat));
+        assert!(matches!(kind, EventKind::Key(key) if key.kind == KeyEventKind::Repeat));
+    }
+
+    #[test]
+    fn test_strip_altgr() {
+        let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+
+        let at = strip_altgr(KeyEvent::new(KeyCode::Char('@'), altgr));
+        assert_eq!(at.modifiers, KeyModifiers::NONE);
+        let shifted = strip_altgr(KeyEvent::new(
+            KeyCode::Char('{'),
+            altgr | KeyModifiers::SHIFT,
+        ));
+        assert_eq!(shifted.modifiers, KeyModifiers::SHIFT);
+        // Real Ctrl+Alt chords stay
+        let chord = strip_altgr(KeyEvent::new(KeyCode::Char('d'), altgr));
+        assert_eq!(chord.modifiers, altgr);
+    }
+
+    #[test]
+    fn test_route_key_release_to_focused() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+        let (bus, _rx) = routing_bus();
+        let release = KeyEvent::new_with_kind(
+            KeyCode::Char('j'),
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+        );
+        let event = bus.create_event(EventKind::KeyRelease(release));
+        assert_eq!(bus.route(&event), vec![NumericComponentId(1)]);
+    }
+
     #[test]
     fn test_process_raw_event_scroll() {
         use crossterm::event::{MouseEvent, MouseEventKind};
@@ -314,4 +810,22 @@ mod tests {
         let kind = process_raw_event(RawEvent::Resize(80, 24));
         assert!(matches!(kind, EventKind::Resize(80, 24)));
     }
+
+    #[test]
+    fn test_process_raw_event_paste() {
+        let kind = process_raw_event(RawEvent::Paste("hello world".into()));
+        assert!(matches!(&kind, EventKind::Paste(text) if text == "hello world"));
+        assert_eq!(kind.event_type(), EventType::Key);
+    }
+
+    #[test]
+    fn test_process_raw_event_focus() {
+        let kind = process_raw_event(RawEvent::FocusGained);
+        assert!(matches!(kind, EventKind::FocusGained));
+        assert!(kind.is_global());
+
+        let kind = process_raw_event(RawEvent::FocusLost);
+        assert!(matches!(kind, EventKind::FocusLost));
+        assert_eq!(kind.event_type(), EventType::Global);
+    }
 }
@@ -0,0 +1,103 @@
+//! Copying text to the system clipboard
+//!
+//! [`copy_to_clipboard`] writes an OSC 52 escape sequence, which most
+//! terminals turn into a clipboard write, also over SSH. The classic Windows
+//! console (conhost) ignores it, so there the text goes to the OS clipboard
+//! instead, which requires the `clipboard` feature:
+//!
+//! ```ignore
+//! match copy_to_clipboard(&selection) {
+//!     Ok(_) => state.status = "Copied".into(),
+//!     Err(err) => state.status = format!("Copy failed: {err}"),
+//! }
+//! ```
+
+use std::io::{self, Write};
+
+use base64::prelude::*;
+
+use crate::terminal::TerminalHost;
+
+/// How text reaches the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardBackend {
+    /// OSC 52 escape sequence written to stdout, handled by the terminal
+    Osc52,
+    /// The OS clipboard (requires the `clipboard` feature)
+    Native,
+}
+
+impl ClipboardBackend {
+    /// OSC 52 unless `host` ignores it
+    pub fn for_host(host: TerminalHost) -> Self {
+        if host.supports_osc52() {
+            Self::Osc52
+        } else {
+            Self::Native
+        }
+    }
+}
+
+/// Copy `text` with the backend that works in the current terminal
+///
+/// Returns the backend used. Fails on conhost without the `clipboard`
+/// feature.
+pub fn copy_to_clipboard(text: &str) -> io::Result<ClipboardBackend> {
+    let backend = ClipboardBackend::for_host(TerminalHost::detect());
+    copy_with(backend, text)?;
+    Ok(backend)
+}
+
+/// Copy `text` with a specific backend
+pub fn copy_with(backend: ClipboardBackend, text: &str) -> io::Result<()> {
+    match backend {
+        ClipboardBackend::Osc52 => {
+            let mut stdout = io::stdout();
+            stdout.write_all(osc52_sequence(text).as_bytes())?;
+            stdout.flush()
+        }
+        ClipboardBackend::Native => native_copy(text),
+    }
+}
+
+/// OSC 52 escape sequence that sets the clipboard to `text`
+pub fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", BASE64_STANDARD.encode(text))
+}
+
+#[cfg(feature = "clipboard")]
+fn native_copy(text: &str) -> io::Result<()> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(io::Error::other)
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn native_copy(_text: &str) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "copying without OSC 52 requires the `clipboard` feature",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_for_host() {
+        assert_eq!(
+            ClipboardBackend::for_host(TerminalHost::WindowsTerminal),
+            ClipboardBackend::Osc52
+        );
+        assert_eq!(
+            ClipboardBackend::for_host(TerminalHost::Other),
+            ClipboardBackend::Osc52
+        );
+        assert_eq!(
+            ClipboardBackend::for_host(TerminalHost::Conhost),
+            ClipboardBackend::Native
+        );
+        assert_eq!(osc52_sequence("hi"), "\x1b]52;c;aGk=\x07");
+    }
+}
@@ -1,8 +1,10 @@
 //! Component trait for pure UI elements
 
+use crossterm::event::{MouseButton, MouseEventKind};
 use ratatui::{layout::Rect, Frame};
 
-use crate::event::EventKind;
+use crate::event::{ComponentId, EventKind};
+use crate::runtime::EventOutcome;
 
 /// A pure UI component that renders based on props and emits actions
 ///
@@ -79,6 +81,296 @@ pub trait Component<A> {
         None::<A>
     }
 
+    /// Handle an event and report whether it was consumed
+    ///
+    /// Override this when a component handles events without emitting
+    /// actions (e.g. swallowing keys while a popup is open) or needs a
+    /// re-render for purely internal state, so parents can stop bubbling
+    /// with [`EventOutcome::or_else`].
+    ///
+    /// Default implementation wraps [`handle_event`](Self::handle_event):
+    /// the event counts as consumed if any actions were returned.
+    fn handle_event_outcome(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> EventOutcome<A> {
+        EventOutcome::from_actions(self.handle_event(event, props))
+    }
+
     /// Render the component to the frame
     fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>);
 }
+
+/// Child registry for a parent component
+///
+/// A parent that lays out several children registers each child's area
+/// while rendering, then hands incoming events to [`dispatch`](Self::dispatch),
+/// which picks the target child:
+///
+/// - key and paste events go to the focused child
+/// - mouse and scroll events go to the child under the pointer; a left click
+///   also focuses it
+/// - resize, tick and focus events go to every child
+///
+/// Children registered later are drawn on top, so they win when areas
+/// overlap.
+///
+/// # Example
+///
+/// ```ignore
+/// struct Playground {
+///     children: ComponentTree<PaneId>,
+///     canvas: Canvas,
+///     palette: Palette,
+/// }
+///
+/// impl Component<Action> for Playground {
+///     fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+///         let [left, right] = Layout::horizontal([Constraint::Fill(1); 2]).areas(area);
+///         self.children.clear();
+///         self.children.register(PaneId::Canvas, left);
+///         self.children.register(PaneId::Palette, right);
+///         self.canvas.render(frame, left, canvas_props(&props));
+///         self.palette.render(frame, right, palette_props(&props));
+///     }
+///
+///     fn handle_event_outcome(
+///         &mut self,
+///         event: &EventKind,
+///         props: Self::Props<'_>,
+///     ) -> EventOutcome<Action> {
+///         let Self { children, canvas, palette } = self;
+///         children.dispatch(event, |id, event| match id {
+///             PaneId::Canvas => canvas.handle_event_outcome(event, canvas_props(&props)),
+///             PaneId::Palette => palette.handle_event_outcome(event, palette_props(&props)),
+///         })
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ComponentTree<C: ComponentId> {
+    /// Children in registration order, with their last rendered areas
+    children: Vec<(C, Rect)>,
+    focused: Option<C>,
+}
+
+impl<C: ComponentId> Default for ComponentTree<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: ComponentId> ComponentTree<C> {
+    /// Create an empty tree
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            focused: None,
+        }
+    }
+
+    /// Forget all child areas (call at the start of each render)
+    ///
+    /// Focus is kept.
+    pub fn clear(&mut self) {
+        self.children.clear();
+    }
+
+    /// Register a child and the area it was rendered into
+    ///
+    /// Registering the same child again replaces its area. The first child
+    /// registered gets focus if nothing is focused yet.
+    pub fn register(&mut self, child: C, area: Rect) {
+        match self.children.iter_mut().find(|(id, _)| *id == child) {
+            Some(entry) => entry.1 = area,
+            None => self.children.push((child, area)),
+        }
+        if self.focused.is_none() {
+            self.focused = Some(child);
+        }
+    }
+
+    /// Area a child was last registered with
+    pub fn area(&self, child: C) -> Option<Rect> {
+        self.children
+            .iter()
+            .find(|(id, _)| *id == child)
+            .map(|(_, area)| *area)
+    }
+
+    /// Registered children, in registration order
+    pub fn children(&self) -> impl Iterator<Item = C> + '_ {
+        self.children.iter().map(|(id, _)| *id)
+    }
+
+    /// The focused child
+    pub fn focused(&self) -> Option<C> {
+        self.focused
+    }
+
+    /// Whether `child` has focus (pass this to the child's props)
+    pub fn is_focused(&self, child: C) -> bool {
+        self.focused == Some(child)
+    }
+
+    /// Focus a child
+    pub fn focus(&mut self, child: C) {
+        self.focused = Some(child);
+    }
+
+    /// Move focus to the next registered child, wrapping around
+    pub fn focus_next(&mut self) {
+        self.cycle_focus(1);
+    }
+
+    /// Move focus to the previous registered child, wrapping around
+    pub fn focus_prev(&mut self) {
+        self.cycle_focus(self.children.len().saturating_sub(1));
+    }
+
+    fn cycle_focus(&mut self, step: usize) {
+        let len = self.children.len();
+        if len == 0 {
+            return;
+        }
+        let next = match self.focused.and_then(|f| self.position(f)) {
+            Some(index) => (index + step) % len,
+            None => 0,
+        };
+        self.focused = Some(self.children[next].0);
+    }
+
+    fn position(&self, child: C) -> Option<usize> {
+        self.children.iter().position(|(id, _)| *id == child)
+    }
+
+    /// Topmost child whose area contains the point
+    pub fn child_at(&self, column: u16, row: u16) -> Option<C> {
+        self.children
+            .iter()
+            .rev()
+            .find(|(_, area)| {
+                column >= area.x
+                    && column < area.x.saturating_add(area.width)
+                    && row >= area.y
+                    && row < area.y.saturating_add(area.height)
+            })
+            .map(|(id, _)| *id)
+    }
+
+    /// Route an event to the child (or children) it targets
+    ///
+    /// Calls `handler` for each target and merges their outcomes. A left
+    /// click focuses the child under the pointer before it is handled.
+    pub fn dispatch<A, F>(&mut self, event: &EventKind, mut handler: F) -> EventOutcome<A>
+    where
+        F: FnMut(C, &EventKind) -> EventOutcome<A>,
+    {
+        let target = match event {
+            EventKind::Key(_) | EventKind::KeyRelease(_) | EventKind::Paste(_) => self.focused,
+            EventKind::Mouse(mouse) => {
+                let child = self.child_at(mouse.column, mouse.row);
+                if let (Some(child), MouseEventKind::Down(MouseButton::Left)) = (child, mouse.kind)
+                {
+                    self.focused = Some(child);
+                }
+                child
+            }
+            EventKind::Scroll { column, row, .. } => self.child_at(*column, *row),
+            EventKind::Resize(..)
+            | EventKind::Tick
+            | EventKind::FocusGained
+            | EventKind::FocusLost => {
+                let mut merged = EventOutcome::ignored();
+                for child in self.children() {
+                    let outcome = handler(child, event);
+                    merged.actions.extend(outcome.actions);
+                    merged.needs_render |= outcome.needs_render;
+                    merged.consumed |= outcome.consumed;
+                }
+                return merged;
+            }
+        };
+
+        match target {
+            Some(child) => handler(child, event),
+            None => EventOutcome::ignored(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::NumericComponentId;
+    use crate::testing::key;
+    use crossterm::event::{KeyModifiers, MouseEvent};
+
+    const LEFT: NumericComponentId = NumericComponentId(1);
+    const RIGHT: NumericComponentId = NumericComponentId(2);
+
+    fn tree() -> ComponentTree<NumericComponentId> {
+        let mut tree = ComponentTree::new();
+        tree.register(LEFT, Rect::new(0, 0, 10, 5));
+        tree.register(RIGHT, Rect::new(10, 0, 10, 5));
+        tree
+    }
+
+    fn click(column: u16, row: u16) -> EventKind {
+        EventKind::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn record(tree: &mut ComponentTree<NumericComponentId>, event: EventKind) -> Vec<u32> {
+        tree.dispatch(&event, |id, _| EventOutcome::action(id.0))
+            .actions
+    }
+
+    #[test]
+    fn test_routes_keys_to_focus_and_mouse_to_pointer() {
+        let mut tree = tree();
+        assert_eq!(tree.focused(), Some(LEFT));
+        assert_eq!(record(&mut tree, EventKind::Key(key("a"))), vec![1]);
+
+        let scroll = EventKind::Scroll {
+            column: 12,
+            row: 2,
+            delta: 1,
+        };
+        assert_eq!(record(&mut tree, scroll), vec![2]);
+        // Scrolling does not move focus, clicking does
+        assert!(tree.is_focused(LEFT));
+        assert_eq!(record(&mut tree, click(15, 1)), vec![2]);
+        assert!(tree.is_focused(RIGHT));
+        assert_eq!(record(&mut tree, EventKind::Key(key("a"))), vec![2]);
+
+        assert!(record(&mut tree, click(30, 1)).is_empty());
+        assert_eq!(record(&mut tree, EventKind::Tick), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_focus_cycle_and_overlap() {
+        let mut tree = tree();
+        tree.focus_next();
+        assert_eq!(tree.focused(), Some(RIGHT));
+        tree.focus_next();
+        assert_eq!(tree.focused(), Some(LEFT));
+        tree.focus_prev();
+        assert_eq!(tree.focused(), Some(RIGHT));
+
+        // A popup registered last wins where it overlaps
+        let popup = NumericComponentId(3);
+        tree.register(popup, Rect::new(5, 1, 10, 2));
+        assert_eq!(tree.child_at(6, 1), Some(popup));
+        assert_eq!(tree.child_at(6, 4), Some(LEFT));
+
+        tree.clear();
+        assert_eq!(tree.child_at(6, 4), None);
+        assert_eq!(tree.focused(), Some(RIGHT));
+    }
+}
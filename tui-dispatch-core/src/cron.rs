@@ -0,0 +1,302 @@
+//! Cron-style time-of-day schedules
+//!
+//! A [`CronSchedule`] is parsed from the classic five-field cron syntax
+//! (`minute hour day-of-month month day-of-week`) and computes the next
+//! matching minute. It is used by
+//! [`Subscriptions::schedule`](crate::subscriptions::Subscriptions::schedule).
+//!
+//! Each field accepts `*`, a value, a range (`1-5`), a step (`*/15`,
+//! `8-18/2`) or a comma-separated list of those. Day-of-week is `0-7`,
+//! where both `0` and `7` are Sunday. As in cron, when both day-of-month
+//! and day-of-week are restricted, a day matching either one fires.
+//!
+//! Times are evaluated in UTC unless a fixed offset is set with
+//! [`CronSchedule::with_utc_offset`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! use tui_dispatch_core::cron::CronSchedule;
+//!
+//! // Weekdays at 09:30, in UTC+2
+//! let schedule = CronSchedule::parse("30 9 * * 1-5")
+//!     .unwrap()
+//!     .with_utc_offset(2 * 60);
+//! assert!(schedule.next_after(std::time::SystemTime::now()).is_some());
+//! ```
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How far ahead to search before giving up (covers leap years).
+const SEARCH_DAYS: i64 = 366 * 5;
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// Error parsing a cron expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronParseError {
+    /// The expression that failed to parse.
+    pub expr: String,
+    /// What was wrong with it.
+    pub message: String,
+}
+
+impl fmt::Display for CronParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid cron expression \"{}\": {}",
+            self.expr, self.message
+        )
+    }
+}
+
+impl std::error::Error for CronParseError {}
+
+/// A parsed five-field cron schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u32,
+    days: u32,
+    months: u16,
+    weekdays: u8,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+    offset_minutes: i32,
+}
+
+impl CronSchedule {
+    /// Parse a five-field cron expression such as `"*/5 8-18 * * 1-5"`.
+    pub fn parse(expr: &str) -> Result<Self, CronParseError> {
+        let error = |message: String| CronParseError {
+            expr: expr.to_string(),
+            message,
+        };
+
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(error(format!("expected 5 fields, found {}", fields.len())));
+        };
+
+        let field = |text: &str, name: &str, min: u32, max: u32| {
+            parse_field(text, min, max).map_err(|message| error(format!("{name}: {message}")))
+        };
+
+        let weekdays = field(weekday, "day-of-week", 0, 7)?;
+        // Fold 7 (Sunday) onto 0
+        let weekdays = ((weekdays | (weekdays >> 7)) & 0x7f) as u8;
+
+        Ok(Self {
+            minutes: field(minute, "minute", 0, 59)?,
+            hours: field(hour, "hour", 0, 23)? as u32,
+            days: field(day, "day-of-month", 1, 31)? as u32,
+            months: field(month, "month", 1, 12)? as u16,
+            weekdays,
+            days_restricted: !day.starts_with('*'),
+            weekdays_restricted: !weekday.starts_with('*'),
+            offset_minutes: 0,
+        })
+    }
+
+    /// Evaluate the schedule at a fixed offset from UTC, in minutes.
+    pub fn with_utc_offset(mut self, offset_minutes: i32) -> Self {
+        self.offset_minutes = offset_minutes;
+        self
+    }
+
+    /// The first matching minute strictly after `time`.
+    ///
+    /// Returns `None` if nothing matches within five years (e.g. `0 0 30 2 *`).
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        let unix_secs = match time.duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_secs() as i64,
+            Err(before) => -(before.duration().as_secs() as i64) - 1,
+        };
+        let local_minute = (unix_secs + self.offset_minutes as i64 * 60).div_euclid(60) + 1;
+
+        let first_day = local_minute.div_euclid(MINUTES_PER_DAY);
+        for day in first_day..first_day + SEARCH_DAYS {
+            if !self.matches_day(day) {
+                continue;
+            }
+            let start = if day == first_day {
+                local_minute.rem_euclid(MINUTES_PER_DAY)
+            } else {
+                0
+            };
+            for minute_of_day in start..MINUTES_PER_DAY {
+                let hour = minute_of_day / 60;
+                let minute = minute_of_day % 60;
+                if self.hours & (1 << hour) != 0 && self.minutes & (1 << minute) != 0 {
+                    let local = day * MINUTES_PER_DAY + minute_of_day;
+                    let unix = (local - self.offset_minutes as i64) * 60;
+                    return Some(if unix >= 0 {
+                        UNIX_EPOCH + Duration::from_secs(unix as u64)
+                    } else {
+                        UNIX_EPOCH - Duration::from_secs(unix.unsigned_abs())
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Whether a day (counted from 1970-01-01) matches the date fields.
+    fn matches_day(&self, day: i64) -> bool {
+        let (_, month, day_of_month) = civil_from_days(day);
+        if self.months & (1 << month) == 0 {
+            return false;
+        }
+        // 1970-01-01 was a Thursday
+        let weekday = (day + 4).rem_euclid(7);
+        let day_matches = self.days & (1 << day_of_month) != 0;
+        let weekday_matches = self.weekdays & (1 << weekday) != 0;
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day_matches || weekday_matches,
+            _ => day_matches && weekday_matches,
+        }
+    }
+}
+
+/// Parse one field into a bitset of allowed values.
+fn parse_field(text: &str, min: u32, max: u32) -> Result<u64, String> {
+    let mut bits = 0u64;
+    for part in text.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("invalid step \"{step}\""))?;
+                if step == 0 {
+                    return Err("step must be at least 1".to_string());
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let value = |s: &str| -> Result<u32, String> {
+            let v: u32 = s.parse().map_err(|_| format!("invalid value \"{s}\""))?;
+            if v < min || v > max {
+                return Err(format!("{v} is outside {min}-{max}"));
+            }
+            Ok(v)
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            let (a, b) = (value(a)?, value(b)?);
+            if a > b {
+                return Err(format!("range {a}-{b} is reversed"));
+            }
+            (a, b)
+        } else {
+            let v = value(range)?;
+            // "5/10" means "5-max/10", as in cron
+            (v, if step > 1 { max } else { v })
+        };
+
+        for v in (start..=end).step_by(step as usize) {
+            bits |= 1 << v;
+        }
+    }
+    Ok(bits)
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) civil date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    // Howard Hinnant's days_from_civil inverse
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2024-03-15 (a Friday) 10:20:30 UTC.
+    fn friday_morning() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_710_498_030)
+    }
+
+    fn secs(time: SystemTime) -> u64 {
+        time.duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_797), (2024, 3, 15));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("5-1 * * * *").is_err());
+        let err = CronSchedule::parse("* x * * *").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid cron expression \"* x * * *\": hour: invalid value \"x\""
+        );
+    }
+
+    #[test]
+    fn test_next_minute_and_step() {
+        let every = CronSchedule::parse("* * * * *").unwrap();
+        assert_eq!(
+            secs(every.next_after(friday_morning()).unwrap()),
+            1_710_498_060
+        );
+
+        let quarter = CronSchedule::parse("*/15 * * * *").unwrap();
+        // 10:20:30 -> 10:30
+        assert_eq!(
+            secs(quarter.next_after(friday_morning()).unwrap()),
+            1_710_498_600
+        );
+    }
+
+    #[test]
+    fn test_weekday_and_offset() {
+        // Monday 09:00 -> 2024-03-18 09:00 UTC
+        let monday = CronSchedule::parse("0 9 * * 1").unwrap();
+        assert_eq!(
+            secs(monday.next_after(friday_morning()).unwrap()),
+            1_710_752_400
+        );
+
+        // Same wall-clock time at UTC+2 fires two hours earlier
+        let monday_cet = monday.with_utc_offset(120);
+        assert_eq!(
+            secs(monday_cet.next_after(friday_morning()).unwrap()),
+            1_710_752_400 - 2 * 3600
+        );
+
+        // Sunday as 7
+        let sunday = CronSchedule::parse("0 0 * * 7").unwrap();
+        assert_eq!(
+            secs(sunday.next_after(friday_morning()).unwrap()),
+            1_710_633_600
+        );
+    }
+
+    #[test]
+    fn test_impossible_date() {
+        let never = CronSchedule::parse("0 0 30 2 *").unwrap();
+        assert_eq!(never.next_after(friday_morning()), None);
+    }
+}
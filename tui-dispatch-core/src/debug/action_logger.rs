@@ -4,6 +4,11 @@
 //! specific actions from logs. Supports both tracing output and in-memory
 //! ring buffer storage for display in debug overlays.
 //!
+//! Logging is cheap on the dispatch path: the log keeps a clone of each
+//! action and formats its params only when they are read (overlay, export,
+//! crash report). Once the ring buffer is full, evicted entries are reused,
+//! including the allocation holding the action.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -20,15 +25,21 @@
 //! // Access the action log
 //! if let Some(log) = middleware.log() {
 //!     for entry in log.recent(10) {
-//!         println!("{}: {}", entry.elapsed, entry.params);
+//!         println!("{}: {}", entry.elapsed(), entry.params());
 //!     }
 //! }
 //! ```
 
-use crate::action::ActionParams;
+use crate::action::{ActionCategory, ActionParams};
 use crate::store::Middleware;
+use serde::Serialize;
+use std::any::Any;
 use std::collections::VecDeque;
-use std::time::Instant;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
 
 /// Configuration for action logging with glob pattern filtering.
 ///
@@ -127,36 +138,128 @@ impl ActionLoggerConfig {
 // In-Memory Action Log
 // ============================================================================
 
+/// A logged action, kept unformatted until its params are read
+trait LoggedAction: Send {
+    fn params(&self) -> String;
+    fn clone_box(&self) -> Box<dyn LoggedAction>;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<A: ActionParams> LoggedAction for A {
+    fn params(&self) -> String {
+        ActionParams::params(self)
+    }
+
+    fn clone_box(&self) -> Box<dyn LoggedAction> {
+        Box::new(self.clone())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
 /// An entry in the action log
-#[derive(Debug, Clone)]
 pub struct ActionLogEntry {
     /// Action name (from Action::name())
     pub name: &'static str,
-    /// Action parameters (from ActionParams::params())
-    pub params: String,
+    /// Action category (from ActionCategory::category(), if logged with it)
+    pub category: Option<&'static str>,
     /// Timestamp when the action was logged
     pub timestamp: Instant,
-    /// Elapsed time display, frozen at creation time
-    pub elapsed: String,
     /// Sequence number for ordering
     pub sequence: u64,
+    /// Time since the log was created
+    since_start: Duration,
+    /// The action, formatted on the first `params()` call
+    action: Option<Box<dyn LoggedAction>>,
+    /// Formatted params
+    params: OnceLock<String>,
 }
 
 impl ActionLogEntry {
-    /// Create a new log entry
+    /// Create a new log entry with already formatted params
     pub fn new(name: &'static str, params: String, sequence: u64) -> Self {
         Self {
             name,
-            params,
+            category: None,
             timestamp: Instant::now(),
-            elapsed: "0ms".to_string(),
             sequence,
+            since_start: Duration::ZERO,
+            action: None,
+            params: OnceLock::from(params),
+        }
+    }
+
+    /// Action parameters (from ActionParams::params()), formatted on first use
+    pub fn params(&self) -> &str {
+        self.params.get_or_init(|| {
+            self.action
+                .as_ref()
+                .map(|action| action.params())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Time since the log was created, e.g. "2.3s" or "150ms"
+    pub fn elapsed(&self) -> String {
+        format_elapsed(self.since_start)
+    }
+
+    /// Point this entry at `action`, reusing the old action's allocation
+    /// when it has the same type
+    fn set_action<A: ActionParams>(&mut self, action: &A) {
+        match self
+            .action
+            .as_mut()
+            .and_then(|old| old.as_any_mut().downcast_mut::<A>())
+        {
+            Some(old) => old.clone_from(action),
+            None => self.action = Some(Box::new(action.clone())),
         }
+        self.params = OnceLock::new();
     }
 }
 
+impl Clone for ActionLogEntry {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            category: self.category,
+            timestamp: self.timestamp,
+            sequence: self.sequence,
+            since_start: self.since_start,
+            action: self.action.as_ref().map(|action| action.clone_box()),
+            params: self.params.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ActionLogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ActionLogEntry")
+            .field("name", &self.name)
+            .field("category", &self.category)
+            .field("params", &self.params())
+            .field("elapsed", &self.elapsed())
+            .field("sequence", &self.sequence)
+            .finish()
+    }
+}
+
+/// One line of an action log JSON Lines export
+#[derive(Serialize)]
+struct ActionLogRecord<'a> {
+    sequence: u64,
+    /// Milliseconds since the log was created
+    timestamp_ms: u64,
+    name: &'a str,
+    category: Option<&'a str>,
+    params: &'a str,
+}
+
 /// Format elapsed time for display (e.g., "2.3s", "150ms")
-fn format_elapsed(elapsed: std::time::Duration) -> String {
+fn format_elapsed(elapsed: Duration) -> String {
     if elapsed.as_secs() >= 1 {
         format!("{:.1}s", elapsed.as_secs_f64())
     } else {
@@ -237,19 +340,66 @@ impl ActionLog {
             return None;
         }
 
-        let params = action.params();
-        let mut entry = ActionLogEntry::new(name, params, self.next_sequence);
-        // Freeze the elapsed time at creation
-        entry.elapsed = format_elapsed(self.start_time.elapsed());
-        self.next_sequence += 1;
+        let entry = self.push_entry(name);
+        entry.set_action(action);
+        Some(&*entry)
+    }
 
-        // Maintain capacity
-        if self.entries.len() >= self.config.capacity {
-            self.entries.pop_front();
-        }
+    /// Append an entry without params, reusing the oldest one when full
+    fn push_entry(&mut self, name: &'static str) -> &mut ActionLogEntry {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        let timestamp = Instant::now();
+        let since_start = timestamp.duration_since(self.start_time);
+
+        let entry = if self.entries.len() >= self.config.capacity {
+            self.entries.pop_front().map(|mut entry| {
+                entry.name = name;
+                entry.category = None;
+                entry.timestamp = timestamp;
+                entry.sequence = sequence;
+                entry.since_start = since_start;
+                entry
+            })
+        } else {
+            None
+        };
+        let entry = entry.unwrap_or_else(|| ActionLogEntry {
+            name,
+            category: None,
+            timestamp,
+            sequence,
+            since_start,
+            action: None,
+            params: OnceLock::new(),
+        });
 
         self.entries.push_back(entry);
-        self.entries.back()
+        self.entries.back_mut().expect("entry was just pushed")
+    }
+
+    /// Log an action together with its category (if it passes the filter)
+    ///
+    /// Like [`log`](Self::log), but also records `ActionCategory::category()`
+    /// so exports can group actions by category.
+    pub fn log_categorized<A: ActionParams + ActionCategory>(
+        &mut self,
+        action: &A,
+    ) -> Option<&ActionLogEntry> {
+        self.log(action)?;
+        let entry = self.entries.back_mut()?;
+        entry.category = action.category();
+        Some(entry)
+    }
+
+    /// Log a warning about an action, bypassing the filter
+    ///
+    /// The entry shows `message` (prefixed with ⚠) in place of parameters.
+    pub fn log_warning(&mut self, name: &'static str, message: &str) -> &ActionLogEntry {
+        let entry = self.push_entry(name);
+        entry.action = None;
+        entry.params = OnceLock::from(format!("⚠ {}", message));
+        entry
     }
 
     /// Get all entries (oldest first)
@@ -282,6 +432,43 @@ impl ActionLog {
         self.entries.clear();
     }
 
+    /// Get entries whose name matches `filter` (oldest first)
+    ///
+    /// See [`matches_filter`] for the filter syntax.
+    pub fn matching<'a>(&'a self, filter: &'a str) -> impl Iterator<Item = &'a ActionLogEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| matches_filter(filter, entry.name))
+    }
+
+    /// Write entries matching `filter` as JSON Lines (oldest first)
+    ///
+    /// Each line holds `sequence`, `timestamp_ms` (since the log was
+    /// created), `name`, `category` and `params`. Returns the number of
+    /// entries written.
+    pub fn write_jsonl<W: Write>(&self, mut writer: W, filter: &str) -> io::Result<usize> {
+        let mut written = 0;
+        for entry in self.matching(filter) {
+            let record = ActionLogRecord {
+                sequence: entry.sequence,
+                timestamp_ms: entry.since_start.as_millis() as u64,
+                name: entry.name,
+                category: entry.category,
+                params: entry.params(),
+            };
+            serde_json::to_writer(&mut writer, &record)?;
+            writer.write_all(b"\n")?;
+            written += 1;
+        }
+        writer.flush()?;
+        Ok(written)
+    }
+
+    /// Write entries matching `filter` to a JSON Lines file (truncating it)
+    pub fn export_jsonl(&self, path: impl AsRef<Path>, filter: &str) -> io::Result<usize> {
+        self.write_jsonl(BufWriter::new(File::create(path)?), filter)
+    }
+
     /// Get configuration
     pub fn config(&self) -> &ActionLogConfig {
         &self.config
@@ -318,7 +505,7 @@ impl ActionLog {
 /// // Access the log for display
 /// if let Some(log) = middleware.log() {
 ///     for entry in log.recent(10) {
-///         println!("{}", entry.params);
+///         println!("{}", entry.params());
 ///     }
 /// }
 /// ```
@@ -442,6 +629,28 @@ pub fn glob_match(pattern: &str, text: &str) -> bool {
     glob_match_impl(&pattern, &text)
 }
 
+/// Match an action name against an overlay filter
+///
+/// The filter is a comma-separated list of glob patterns, like
+/// [`ActionLoggerConfig`] patterns; the name must match one of them.
+/// Patterns without `*` or `?` match anywhere in the name, so typing
+/// `Search` finds `StartSearch`. An empty filter matches everything.
+pub fn matches_filter(filter: &str, name: &str) -> bool {
+    let mut patterns = filter
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .peekable();
+    patterns.peek().is_none()
+        || patterns.any(|pattern| {
+            if pattern.contains(['*', '?']) {
+                glob_match(pattern, name)
+            } else {
+                name.contains(pattern)
+            }
+        })
+}
+
 fn glob_match_impl(pattern: &[char], text: &[char]) -> bool {
     let mut pi = 0;
     let mut ti = 0;
@@ -636,7 +845,55 @@ mod tests {
     fn test_action_log_entry_elapsed() {
         let entry = ActionLogEntry::new("Test", "test_params".to_string(), 0);
         // Should have "0ms" as default elapsed
-        assert_eq!(entry.elapsed, "0ms");
+        assert_eq!(entry.elapsed(), "0ms");
+        assert_eq!(entry.params(), "test_params");
+    }
+
+    #[test]
+    fn test_action_log_formats_params_lazily() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static FORMATTED: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Clone, Debug)]
+        struct Counted(String);
+
+        impl crate::Action for Counted {
+            fn name(&self) -> &'static str {
+                "Counted"
+            }
+        }
+
+        impl crate::ActionParams for Counted {
+            fn params(&self) -> String {
+                FORMATTED.fetch_add(1, Ordering::SeqCst);
+                format!("{:?}", self.0)
+            }
+        }
+
+        let config = ActionLogConfig::new(2, ActionLoggerConfig::with_patterns(vec![], vec![]));
+        let mut log = ActionLog::new(config);
+        for text in ["a", "b", "c"] {
+            log.log(&Counted(text.into()));
+        }
+        assert_eq!(FORMATTED.load(Ordering::SeqCst), 0);
+
+        // Evicted entries are reused for new actions
+        let params: Vec<_> = log
+            .entries()
+            .map(|entry| entry.params().to_string())
+            .collect();
+        assert_eq!(params, [r#""b""#, r#""c""#]);
+        assert_eq!(FORMATTED.load(Ordering::SeqCst), 2);
+
+        // Formatted once, then cached
+        log.entries().for_each(|entry| {
+            entry.params();
+        });
+        assert_eq!(FORMATTED.load(Ordering::SeqCst), 2);
+
+        log.log_warning("Counted", "slow");
+        assert_eq!(log.entries().last().unwrap().params(), "⚠ slow");
     }
 
     #[test]
@@ -662,4 +919,36 @@ mod tests {
         let log = middleware.log().unwrap();
         assert_eq!(log.len(), 1);
     }
+
+    #[test]
+    fn test_matches_filter() {
+        assert!(matches_filter("", "Connect"));
+        assert!(matches_filter(" , ", "Connect"));
+        assert!(matches_filter("Search", "StartSearch"));
+        assert!(matches_filter("Search*", "SearchAddChar"));
+        assert!(!matches_filter("Search*", "StartSearch"));
+        assert!(matches_filter("Tick, Conn*", "Connect"));
+        assert!(!matches_filter("Tick", "Connect"));
+    }
+
+    #[test]
+    fn test_action_log_write_jsonl() {
+        let config = ActionLogConfig::new(10, ActionLoggerConfig::with_patterns(vec![], vec![]));
+        let mut log = ActionLog::new(config);
+        log.log(&TestAction::Tick);
+        log.log(&TestAction::Connect);
+
+        let mut out = Vec::new();
+        assert_eq!(log.write_jsonl(&mut out, "Conn*").unwrap(), 1);
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 1);
+        let record: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(record["sequence"], 1);
+        assert_eq!(record["name"], "Connect");
+        assert_eq!(record["category"], serde_json::Value::Null);
+        assert_eq!(record["params"], "");
+        assert!(record["timestamp_ms"].is_u64());
+    }
 }
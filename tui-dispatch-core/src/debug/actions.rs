@@ -1,5 +1,9 @@
 //! Debug actions and side effects
 
+use std::path::PathBuf;
+
+use super::export::{FrameExport, FrameFormat};
+
 /// Debug actions provided by tui-dispatch
 ///
 /// These are framework-level debug actions that apps can map from their own
@@ -10,10 +14,18 @@ pub enum DebugAction {
     Toggle,
     /// Copy frozen frame to clipboard
     CopyFrame,
+    /// Export frozen frame in a colored format
+    ExportFrame(FrameFormat),
+    /// Write the captured action log to a JSON Lines file
+    ExportActionLog,
+    /// Write the current state to a JSON file (see `DebugState::debug_snapshot`)
+    ExportState,
     /// Toggle state overlay
     ToggleState,
     /// Toggle action log overlay
     ToggleActionLog,
+    /// Toggle performance metrics overlay
+    ToggleMetrics,
     /// Toggle mouse capture mode for cell inspection
     ToggleMouseCapture,
     /// Inspect cell at position (from mouse click)
@@ -38,14 +50,23 @@ pub enum DebugAction {
     ActionLogShowDetail,
     /// Go back from detail view to action log
     ActionLogBackToList,
+    /// Filter the action log by name (comma-separated globs, empty = all)
+    ActionLogFilter(String),
 }
 
 impl DebugAction {
     /// Standard command names for keybinding lookup
     pub const CMD_TOGGLE: &'static str = "debug.toggle";
     pub const CMD_COPY_FRAME: &'static str = "debug.copy";
+    pub const CMD_EXPORT_TEXT: &'static str = "debug.export.text";
+    pub const CMD_EXPORT_ANSI: &'static str = "debug.export.ansi";
+    pub const CMD_EXPORT_HTML: &'static str = "debug.export.html";
+    pub const CMD_EXPORT_SVG: &'static str = "debug.export.svg";
+    pub const CMD_EXPORT_ACTION_LOG: &'static str = "debug.export.actions";
+    pub const CMD_EXPORT_STATE: &'static str = "debug.export.state";
     pub const CMD_TOGGLE_STATE: &'static str = "debug.state";
     pub const CMD_TOGGLE_ACTION_LOG: &'static str = "debug.action_log";
+    pub const CMD_TOGGLE_METRICS: &'static str = "debug.metrics";
     pub const CMD_TOGGLE_MOUSE: &'static str = "debug.mouse";
     pub const CMD_CLOSE_OVERLAY: &'static str = "debug.close";
 
@@ -54,8 +75,15 @@ impl DebugAction {
         match cmd {
             Self::CMD_TOGGLE => Some(Self::Toggle),
             Self::CMD_COPY_FRAME => Some(Self::CopyFrame),
+            Self::CMD_EXPORT_TEXT => Some(Self::ExportFrame(FrameFormat::Text)),
+            Self::CMD_EXPORT_ANSI => Some(Self::ExportFrame(FrameFormat::Ansi)),
+            Self::CMD_EXPORT_HTML => Some(Self::ExportFrame(FrameFormat::Html)),
+            Self::CMD_EXPORT_SVG => Some(Self::ExportFrame(FrameFormat::Svg)),
+            Self::CMD_EXPORT_ACTION_LOG => Some(Self::ExportActionLog),
+            Self::CMD_EXPORT_STATE => Some(Self::ExportState),
             Self::CMD_TOGGLE_STATE => Some(Self::ToggleState),
             Self::CMD_TOGGLE_ACTION_LOG => Some(Self::ToggleActionLog),
+            Self::CMD_TOGGLE_METRICS => Some(Self::ToggleMetrics),
             Self::CMD_TOGGLE_MOUSE => Some(Self::ToggleMouseCapture),
             Self::CMD_CLOSE_OVERLAY => Some(Self::CloseOverlay),
             _ => None,
@@ -67,8 +95,17 @@ impl DebugAction {
         match self {
             Self::Toggle => Some(Self::CMD_TOGGLE),
             Self::CopyFrame => Some(Self::CMD_COPY_FRAME),
+            Self::ExportFrame(format) => Some(match format {
+                FrameFormat::Text => Self::CMD_EXPORT_TEXT,
+                FrameFormat::Ansi => Self::CMD_EXPORT_ANSI,
+                FrameFormat::Html => Self::CMD_EXPORT_HTML,
+                FrameFormat::Svg => Self::CMD_EXPORT_SVG,
+            }),
+            Self::ExportActionLog => Some(Self::CMD_EXPORT_ACTION_LOG),
+            Self::ExportState => Some(Self::CMD_EXPORT_STATE),
             Self::ToggleState => Some(Self::CMD_TOGGLE_STATE),
             Self::ToggleActionLog => Some(Self::CMD_TOGGLE_ACTION_LOG),
+            Self::ToggleMetrics => Some(Self::CMD_TOGGLE_METRICS),
             Self::ToggleMouseCapture => Some(Self::CMD_TOGGLE_MOUSE),
             Self::CloseOverlay => Some(Self::CMD_CLOSE_OVERLAY),
             // These don't have command strings (triggered programmatically)
@@ -81,7 +118,8 @@ impl DebugAction {
             | Self::ActionLogPageUp
             | Self::ActionLogPageDown
             | Self::ActionLogShowDetail
-            | Self::ActionLogBackToList => None,
+            | Self::ActionLogBackToList
+            | Self::ActionLogFilter(_) => None,
         }
     }
 }
@@ -102,6 +140,25 @@ pub enum DebugSideEffect<A> {
     ///
     /// The app should use its preferred clipboard mechanism (OSC52, etc).
     CopyToClipboard(String),
+
+    /// Frozen frame serialized in the requested format
+    ///
+    /// The app decides where the content goes (file, clipboard, bug report).
+    ExportFrame(FrameExport),
+
+    /// Action log written to a JSON Lines file
+    ActionLogExported {
+        /// File the log was written to
+        path: PathBuf,
+        /// Number of entries written
+        entries: usize,
+    },
+
+    /// State snapshot written to a JSON file
+    StateExported {
+        /// File the snapshot was written to
+        path: PathBuf,
+    },
 }
 
 #[cfg(test)]
@@ -126,6 +183,10 @@ mod tests {
             DebugAction::from_command("debug.action_log"),
             Some(DebugAction::ToggleActionLog)
         );
+        assert_eq!(
+            DebugAction::from_command("debug.export.svg"),
+            Some(DebugAction::ExportFrame(FrameFormat::Svg))
+        );
         assert_eq!(DebugAction::from_command("unknown"), None);
     }
 
@@ -134,8 +195,15 @@ mod tests {
         let actions = [
             DebugAction::Toggle,
             DebugAction::CopyFrame,
+            DebugAction::ExportFrame(FrameFormat::Text),
+            DebugAction::ExportFrame(FrameFormat::Ansi),
+            DebugAction::ExportFrame(FrameFormat::Html),
+            DebugAction::ExportFrame(FrameFormat::Svg),
+            DebugAction::ExportActionLog,
+            DebugAction::ExportState,
             DebugAction::ToggleState,
             DebugAction::ToggleActionLog,
+            DebugAction::ToggleMetrics,
             DebugAction::ToggleMouseCapture,
             DebugAction::CloseOverlay,
         ];
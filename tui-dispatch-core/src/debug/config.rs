@@ -2,6 +2,8 @@
 
 use super::SimpleDebugContext;
 use crate::keybindings::{BindingContext, Keybindings};
+use crate::strings::Strings;
+use crate::theme::Theme;
 use ratatui::style::{Color, Modifier, Style};
 
 // Neon color palette (matches memtui theme)
@@ -38,6 +40,8 @@ pub struct DebugStyle {
     pub value_style: Style,
     /// Dim factor for background (0.0-1.0)
     pub dim_factor: f32,
+    /// Banner labels
+    pub strings: Strings,
 }
 
 /// Style and symbol overrides for debug scrollbars
@@ -107,6 +111,43 @@ impl Default for DebugStyle {
             label_style: Style::default().fg(TEXT_SECONDARY),
             value_style: Style::default().fg(TEXT_PRIMARY),
             dim_factor: 0.7,
+            strings: Strings::ENGLISH,
+        }
+    }
+}
+
+impl DebugStyle {
+    /// Build a debug style from an app [`Theme`]
+    ///
+    /// The banner, key hints, labels and scrollbars take the theme's colors
+    /// so the debug layer blends in with the rest of the app, and the
+    /// banner uses the theme's [`Strings`].
+    pub fn from_theme(theme: &Theme) -> Self {
+        let key_base = |bg: Color| {
+            Style::default()
+                .fg(theme.surface)
+                .bg(bg)
+                .add_modifier(Modifier::BOLD)
+        };
+        Self {
+            banner_bg: Style::default().bg(theme.surface),
+            title_style: key_base(theme.accent),
+            key_styles: KeyStyles {
+                toggle: key_base(theme.error),
+                state: key_base(theme.accent),
+                copy: key_base(theme.warning),
+                mouse: key_base(theme.text_muted),
+                actions: key_base(theme.success),
+            },
+            scrollbar: ScrollbarStyle {
+                thumb: Style::default().fg(theme.accent),
+                track: Style::default().fg(theme.border),
+                ..ScrollbarStyle::default()
+            },
+            label_style: theme.muted(),
+            value_style: theme.text(),
+            dim_factor: 0.7,
+            strings: theme.strings,
         }
     }
 }
@@ -117,6 +158,10 @@ impl DebugStyle {
     pub const fn neon_purple() -> Color {
         NEON_PURPLE
     }
+    /// Get the neon pink color
+    pub const fn neon_pink() -> Color {
+        NEON_PINK
+    }
     /// Get the neon cyan color
     pub const fn neon_cyan() -> Color {
         NEON_CYAN
@@ -316,6 +361,11 @@ pub fn default_debug_keybindings_with_toggle(
         "debug.action_log",
         vec!["a".into(), "A".into()],
     );
+    kb.add(
+        SimpleDebugContext::Debug,
+        "debug.metrics",
+        vec!["m".into(), "M".into()],
+    );
     kb
 }
 
@@ -352,6 +402,15 @@ mod tests {
         assert!(styled.style.is_some());
     }
 
+    #[test]
+    fn test_style_from_theme() {
+        let style = DebugStyle::from_theme(&Theme::LIGHT);
+        assert_eq!(style.banner_bg.bg, Some(Theme::LIGHT.surface));
+        assert_eq!(style.title_style.bg, Some(Theme::LIGHT.accent));
+        assert_eq!(style.key_styles.toggle.bg, Some(Theme::LIGHT.error));
+        assert_eq!(style.label_style.fg, Some(Theme::LIGHT.text_muted));
+    }
+
     #[test]
     fn test_config_with_status_provider() {
         let config = DebugConfig::new(Keybindings::new(), TestContext::Debug)
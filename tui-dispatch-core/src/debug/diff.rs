@@ -0,0 +1,180 @@
+//! State diffing over debug sections
+//!
+//! Compares two [`DebugSection`] snapshots and reports which entries were
+//! added, removed, or changed. Used by [`DebugLayer`](super::DebugLayer) to
+//! show what each dispatched action did to the state.
+
+use std::collections::HashMap;
+
+use super::state::{DebugEntry, DebugSection};
+
+/// Kind of change for a single state entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateChangeKind {
+    /// Entry exists only in the new snapshot
+    Added,
+    /// Entry exists only in the old snapshot
+    Removed,
+    /// Entry exists in both with different values
+    Changed,
+}
+
+/// A single changed entry between two state snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateDiffEntry {
+    /// Section title the entry belongs to
+    pub section: String,
+    /// Entry key
+    pub key: String,
+    /// Value before the change (None if added)
+    pub before: Option<String>,
+    /// Value after the change (None if removed)
+    pub after: Option<String>,
+}
+
+impl StateDiffEntry {
+    /// Classify this change
+    pub fn kind(&self) -> StateChangeKind {
+        match (&self.before, &self.after) {
+            (None, _) => StateChangeKind::Added,
+            (_, None) => StateChangeKind::Removed,
+            _ => StateChangeKind::Changed,
+        }
+    }
+}
+
+/// Diff two state snapshots
+///
+/// Entries are matched by `(section title, key)`, where nested sections are
+/// titled by their path (`"App / Connection"`). The result follows the
+/// order of `after`, with removed entries appended in `before` order.
+///
+/// # Example
+///
+/// ```
+/// use tui_dispatch_core::debug::{diff_sections, DebugSection};
+///
+/// let before = vec![DebugSection::new("Counter").entry("value", "1")];
+/// let after = vec![DebugSection::new("Counter").entry("value", "2")];
+///
+/// let diff = diff_sections(&before, &after);
+/// assert_eq!(diff.len(), 1);
+/// assert_eq!(diff[0].before.as_deref(), Some("1"));
+/// assert_eq!(diff[0].after.as_deref(), Some("2"));
+/// ```
+pub fn diff_sections(before: &[DebugSection], after: &[DebugSection]) -> Vec<StateDiffEntry> {
+    let before = flat_entries(before);
+    let after = flat_entries(after);
+    let old: HashMap<(&str, &str), &str> = before
+        .iter()
+        .map(|(section, e)| ((section.as_str(), e.key.as_str()), e.value.as_str()))
+        .collect();
+
+    let mut seen = std::collections::HashSet::new();
+    let mut diff = Vec::new();
+
+    for (section, entry) in &after {
+        let id = (section.as_str(), entry.key.as_str());
+        seen.insert(id);
+        match old.get(&id) {
+            Some(value) if *value == entry.value => {}
+            value => diff.push(StateDiffEntry {
+                section: section.clone(),
+                key: entry.key.clone(),
+                before: value.map(|v| v.to_string()),
+                after: Some(entry.value.clone()),
+            }),
+        }
+    }
+
+    for (section, entry) in &before {
+        if !seen.contains(&(section.as_str(), entry.key.as_str())) {
+            diff.push(StateDiffEntry {
+                section: section.clone(),
+                key: entry.key.clone(),
+                before: Some(entry.value.clone()),
+                after: None,
+            });
+        }
+    }
+
+    diff
+}
+
+/// Entries of all sections with their section path, depth first
+fn flat_entries(sections: &[DebugSection]) -> Vec<(String, &DebugEntry)> {
+    fn walk<'a>(section: &'a DebugSection, path: String, out: &mut Vec<(String, &'a DebugEntry)>) {
+        out.extend(section.entries.iter().map(|entry| (path.clone(), entry)));
+        for child in &section.children {
+            walk(child, format!("{path} / {}", child.title), out);
+        }
+    }
+
+    let mut out = Vec::new();
+    for section in sections {
+        walk(section, section.title.clone(), &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_changes() {
+        let state = vec![DebugSection::new("A").entry("x", "1")];
+        assert!(diff_sections(&state, &state).is_empty());
+    }
+
+    #[test]
+    fn test_added_removed_changed() {
+        let before = vec![
+            DebugSection::new("A").entry("x", "1").entry("gone", "bye"),
+            DebugSection::new("B").entry("y", "same"),
+        ];
+        let after = vec![
+            DebugSection::new("A").entry("x", "2").entry("new", "hi"),
+            DebugSection::new("B").entry("y", "same"),
+        ];
+
+        let diff = diff_sections(&before, &after);
+        let kinds: Vec<_> = diff.iter().map(|d| (d.key.as_str(), d.kind())).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("x", StateChangeKind::Changed),
+                ("new", StateChangeKind::Added),
+                ("gone", StateChangeKind::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_same_key_in_different_sections() {
+        let before = vec![
+            DebugSection::new("A").entry("count", "1"),
+            DebugSection::new("B").entry("count", "1"),
+        ];
+        let after = vec![
+            DebugSection::new("A").entry("count", "1"),
+            DebugSection::new("B").entry("count", "5"),
+        ];
+
+        let diff = diff_sections(&before, &after);
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].section, "B");
+    }
+
+    #[test]
+    fn test_nested_sections_use_path() {
+        let state = |host: &str| {
+            vec![DebugSection::new("App").child(DebugSection::new("Connection").entry("host", host))]
+        };
+
+        let diff = diff_sections(&state("a"), &state("b"));
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].section, "App / Connection");
+        assert_eq!(diff[0].kind(), StateChangeKind::Changed);
+    }
+}
@@ -0,0 +1,511 @@
+//! Frame export in colored formats
+//!
+//! Serializes a captured frame buffer to ANSI text, HTML or SVG so visual
+//! bug reports keep their colors and text attributes.
+
+use std::fmt::Write;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+
+use super::widgets::buffer_to_text;
+
+/// Width of one cell in SVG user units
+const SVG_CELL_WIDTH: u32 = 8;
+/// Height of one cell in SVG user units
+const SVG_CELL_HEIGHT: u32 = 16;
+/// Foreground used for `Color::Reset` in HTML/SVG output
+const DEFAULT_FG: &str = "#d0d0d0";
+/// Background used for `Color::Reset` in HTML/SVG output
+const DEFAULT_BG: &str = "#000000";
+
+/// Output format for frame exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameFormat {
+    /// Plain text without styling
+    Text,
+    /// Text with ANSI SGR escape sequences
+    Ansi,
+    /// Standalone HTML with inline styles per cell run
+    Html,
+    /// Standalone SVG image
+    Svg,
+}
+
+impl FrameFormat {
+    /// All supported formats
+    pub const ALL: [FrameFormat; 4] = [Self::Text, Self::Ansi, Self::Html, Self::Svg];
+
+    /// Conventional file extension for this format
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Text => "txt",
+            Self::Ansi => "ans",
+            Self::Html => "html",
+            Self::Svg => "svg",
+        }
+    }
+
+    /// Human-readable format name
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Ansi => "ANSI",
+            Self::Html => "HTML",
+            Self::Svg => "SVG",
+        }
+    }
+}
+
+/// A serialized frame produced by the debug layer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameExport {
+    /// Format of `content`
+    pub format: FrameFormat,
+    /// Serialized frame
+    pub content: String,
+}
+
+/// Serialize a buffer in the given format
+pub fn export_buffer(buffer: &Buffer, format: FrameFormat) -> String {
+    match format {
+        FrameFormat::Text => buffer_to_text(buffer),
+        FrameFormat::Ansi => buffer_to_ansi(buffer),
+        FrameFormat::Html => buffer_to_html(buffer),
+        FrameFormat::Svg => buffer_to_svg(buffer),
+    }
+}
+
+/// Serialize a buffer as text with ANSI SGR escape sequences
+///
+/// Styles are emitted only when they change and reset at the end of each line.
+pub fn buffer_to_ansi(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+
+    for y in area.y..area.y.saturating_add(area.height) {
+        let mut current: Option<CellStyle> = None;
+        for x in area.x..area.x.saturating_add(area.width) {
+            let cell = &buffer[(x, y)];
+            let style = CellStyle::of(cell);
+            if current != Some(style) {
+                out.push_str(&style.sgr());
+                current = Some(style);
+            }
+            out.push_str(cell.symbol());
+        }
+        out.push_str("\x1b[0m");
+        if y + 1 < area.y.saturating_add(area.height) {
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// Serialize a buffer as a standalone HTML document
+///
+/// Runs of cells with the same style share one `<span>` with inline styles.
+pub fn buffer_to_html(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"></head>\n<body>\n");
+    let _ = write!(
+        out,
+        "<pre style=\"font-family:monospace;line-height:1.2;color:{DEFAULT_FG};\
+         background:{DEFAULT_BG};padding:4px\">"
+    );
+
+    let rows = style_runs(buffer);
+    let last = rows.len().saturating_sub(1);
+    for (i, runs) in rows.into_iter().enumerate() {
+        for run in runs {
+            let css = run.style.css();
+            if css.is_empty() {
+                out.push_str(&escape_xml(&run.text));
+            } else {
+                let _ = write!(
+                    out,
+                    "<span style=\"{css}\">{}</span>",
+                    escape_xml(&run.text)
+                );
+            }
+        }
+        if i < last {
+            out.push('\n');
+        }
+    }
+
+    out.push_str("</pre>\n</body>\n</html>\n");
+    out
+}
+
+/// Serialize a buffer as a standalone SVG image
+///
+/// Each style run becomes a background `<rect>` (when set) and a `<text>`
+/// element positioned on the cell grid.
+pub fn buffer_to_svg(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let width = u32::from(area.width) * SVG_CELL_WIDTH;
+    let height = u32::from(area.height) * SVG_CELL_HEIGHT;
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"13\">"
+    );
+    let _ = writeln!(
+        out,
+        "<rect width=\"100%\" height=\"100%\" fill=\"{DEFAULT_BG}\"/>"
+    );
+
+    for (row, runs) in style_runs(buffer).into_iter().enumerate() {
+        let y = row as u32 * SVG_CELL_HEIGHT;
+        for run in runs {
+            let x = u32::from(run.column) * SVG_CELL_WIDTH;
+            let run_width = u32::from(run.width) * SVG_CELL_WIDTH;
+            let (fg, bg) = run.style.colors();
+            if let Some(bg) = bg {
+                let _ = writeln!(
+                    out,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{run_width}\" \
+                     height=\"{SVG_CELL_HEIGHT}\" fill=\"{bg}\"/>"
+                );
+            }
+            if run.text.trim().is_empty() {
+                continue;
+            }
+            let mut attrs = format!("fill=\"{}\"", fg.as_deref().unwrap_or(DEFAULT_FG));
+            let modifier = run.style.modifier;
+            if modifier.contains(Modifier::BOLD) {
+                attrs.push_str(" font-weight=\"bold\"");
+            }
+            if modifier.contains(Modifier::ITALIC) {
+                attrs.push_str(" font-style=\"italic\"");
+            }
+            if modifier.contains(Modifier::DIM) {
+                attrs.push_str(" fill-opacity=\"0.6\"");
+            }
+            match (
+                modifier.contains(Modifier::UNDERLINED),
+                modifier.contains(Modifier::CROSSED_OUT),
+            ) {
+                (true, true) => attrs.push_str(" text-decoration=\"underline line-through\""),
+                (true, false) => attrs.push_str(" text-decoration=\"underline\""),
+                (false, true) => attrs.push_str(" text-decoration=\"line-through\""),
+                (false, false) => {}
+            }
+            let baseline = y + SVG_CELL_HEIGHT - 4;
+            let _ = writeln!(
+                out,
+                "<text x=\"{x}\" y=\"{baseline}\" textLength=\"{run_width}\" \
+                 lengthAdjust=\"spacingAndGlyphs\" xml:space=\"preserve\" {attrs}>{}</text>",
+                escape_xml(&run.text)
+            );
+        }
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Visual style of a single cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CellStyle {
+    fg: Color,
+    bg: Color,
+    modifier: Modifier,
+}
+
+impl CellStyle {
+    fn of(cell: &Cell) -> Self {
+        Self {
+            fg: cell.fg,
+            bg: cell.bg,
+            modifier: cell.modifier,
+        }
+    }
+
+    /// Resolved (fg, bg) hex colors, `None` meaning the terminal default
+    fn colors(&self) -> (Option<String>, Option<String>) {
+        let fg = color_to_hex(self.fg);
+        let bg = color_to_hex(self.bg);
+        if self.modifier.contains(Modifier::REVERSED) {
+            (
+                Some(bg.unwrap_or_else(|| DEFAULT_BG.to_string())),
+                Some(fg.unwrap_or_else(|| DEFAULT_FG.to_string())),
+            )
+        } else {
+            (fg, bg)
+        }
+    }
+
+    fn sgr(&self) -> String {
+        let mut codes = vec!["0".to_string()];
+        for (flag, code) in [
+            (Modifier::BOLD, "1"),
+            (Modifier::DIM, "2"),
+            (Modifier::ITALIC, "3"),
+            (Modifier::UNDERLINED, "4"),
+            (Modifier::SLOW_BLINK, "5"),
+            (Modifier::RAPID_BLINK, "6"),
+            (Modifier::REVERSED, "7"),
+            (Modifier::HIDDEN, "8"),
+            (Modifier::CROSSED_OUT, "9"),
+        ] {
+            if self.modifier.contains(flag) {
+                codes.push(code.to_string());
+            }
+        }
+        if let Some(code) = ansi_color(self.fg, false) {
+            codes.push(code);
+        }
+        if let Some(code) = ansi_color(self.bg, true) {
+            codes.push(code);
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+
+    fn css(&self) -> String {
+        let (fg, bg) = self.colors();
+        let mut css = String::new();
+        if let Some(fg) = fg {
+            let _ = write!(css, "color:{fg};");
+        }
+        if let Some(bg) = bg {
+            let _ = write!(css, "background:{bg};");
+        }
+        if self.modifier.contains(Modifier::BOLD) {
+            css.push_str("font-weight:bold;");
+        }
+        if self.modifier.contains(Modifier::DIM) {
+            css.push_str("opacity:0.6;");
+        }
+        if self.modifier.contains(Modifier::ITALIC) {
+            css.push_str("font-style:italic;");
+        }
+        match (
+            self.modifier.contains(Modifier::UNDERLINED),
+            self.modifier.contains(Modifier::CROSSED_OUT),
+        ) {
+            (true, true) => css.push_str("text-decoration:underline line-through;"),
+            (true, false) => css.push_str("text-decoration:underline;"),
+            (false, true) => css.push_str("text-decoration:line-through;"),
+            (false, false) => {}
+        }
+        if self.modifier.contains(Modifier::HIDDEN) {
+            css.push_str("visibility:hidden;");
+        }
+        css
+    }
+}
+
+/// Consecutive cells on one row sharing a style
+struct StyleRun {
+    column: u16,
+    width: u16,
+    style: CellStyle,
+    text: String,
+}
+
+fn style_runs(buffer: &Buffer) -> Vec<Vec<StyleRun>> {
+    let area = buffer.area;
+    let mut rows = Vec::with_capacity(area.height as usize);
+
+    for y in area.y..area.y.saturating_add(area.height) {
+        let mut runs: Vec<StyleRun> = Vec::new();
+        for x in area.x..area.x.saturating_add(area.width) {
+            let cell = &buffer[(x, y)];
+            let style = CellStyle::of(cell);
+            match runs.last_mut() {
+                Some(run) if run.style == style => {
+                    run.width += 1;
+                    run.text.push_str(cell.symbol());
+                }
+                _ => runs.push(StyleRun {
+                    column: x - area.x,
+                    width: 1,
+                    style,
+                    text: cell.symbol().to_string(),
+                }),
+            }
+        }
+        rows.push(runs);
+    }
+
+    rows
+}
+
+fn ansi_color(color: Color, background: bool) -> Option<String> {
+    let base = if background { 40 } else { 30 };
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+        Color::Indexed(i) => return Some(format!("{};5;{i}", base + 8)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{r};{g};{b}", base + 8)),
+    };
+    Some(code.to_string())
+}
+
+/// Map a ratatui color to a CSS hex string, `None` for `Color::Reset`
+fn color_to_hex(color: Color) -> Option<String> {
+    let (r, g, b) = match color {
+        Color::Reset => return None,
+        Color::Black => ANSI_PALETTE[0],
+        Color::Red => ANSI_PALETTE[1],
+        Color::Green => ANSI_PALETTE[2],
+        Color::Yellow => ANSI_PALETTE[3],
+        Color::Blue => ANSI_PALETTE[4],
+        Color::Magenta => ANSI_PALETTE[5],
+        Color::Cyan => ANSI_PALETTE[6],
+        Color::Gray => ANSI_PALETTE[7],
+        Color::DarkGray => ANSI_PALETTE[8],
+        Color::LightRed => ANSI_PALETTE[9],
+        Color::LightGreen => ANSI_PALETTE[10],
+        Color::LightYellow => ANSI_PALETTE[11],
+        Color::LightBlue => ANSI_PALETTE[12],
+        Color::LightMagenta => ANSI_PALETTE[13],
+        Color::LightCyan => ANSI_PALETTE[14],
+        Color::White => ANSI_PALETTE[15],
+        Color::Indexed(i) => indexed_rgb(i),
+        Color::Rgb(r, g, b) => (r, g, b),
+    };
+    Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+/// xterm default values for the 16 named colors
+const ANSI_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_PALETTE[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ => {
+            let v = 8 + (index - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    fn sample_buffer() -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buffer.set_string(0, 0, "ab", Style::default().fg(Color::Red));
+        buffer.set_string(2, 0, "<&>", Style::default().bg(Color::Rgb(0, 0, 255)));
+        buffer.set_string(0, 1, "ok", Style::default().add_modifier(Modifier::BOLD));
+        buffer
+    }
+
+    #[test]
+    fn test_text_matches_buffer_to_text() {
+        let buffer = sample_buffer();
+        assert_eq!(
+            export_buffer(&buffer, FrameFormat::Text),
+            buffer_to_text(&buffer)
+        );
+    }
+
+    #[test]
+    fn test_ansi_output() {
+        let ansi = buffer_to_ansi(&sample_buffer());
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("\x1b[0;31mab\x1b[0;48;2;0;0;255m<&>"));
+        assert!(lines[0].ends_with("\x1b[0m"));
+        assert!(lines[1].starts_with("\x1b[0;1mok"));
+    }
+
+    #[test]
+    fn test_html_output() {
+        let html = buffer_to_html(&sample_buffer());
+        assert!(html.contains("<span style=\"color:#cd0000;\">ab</span>"));
+        assert!(html.contains("<span style=\"background:#0000ff;\">&lt;&amp;&gt;</span>"));
+        assert!(html.contains("<span style=\"font-weight:bold;\">ok</span>"));
+    }
+
+    #[test]
+    fn test_svg_output() {
+        let svg = buffer_to_svg(&sample_buffer());
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("width=\"48\" height=\"32\""));
+        assert!(
+            svg.contains("<rect x=\"16\" y=\"0\" width=\"24\" height=\"16\" fill=\"#0000ff\"/>")
+        );
+        assert!(svg.contains(">&lt;&amp;&gt;</text>"));
+        assert!(svg.contains("font-weight=\"bold\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_reversed_swaps_colors() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buffer.set_string(0, 0, "x", Style::default().add_modifier(Modifier::REVERSED));
+        let html = buffer_to_html(&buffer);
+        assert!(html.contains(&format!("color:{DEFAULT_BG};background:{DEFAULT_FG};")));
+    }
+
+    #[test]
+    fn test_indexed_palette() {
+        assert_eq!(color_to_hex(Color::Indexed(1)), Some("#cd0000".into()));
+        assert_eq!(color_to_hex(Color::Indexed(16)), Some("#000000".into()));
+        assert_eq!(color_to_hex(Color::Indexed(231)), Some("#ffffff".into()));
+        assert_eq!(color_to_hex(Color::Indexed(255)), Some("#eeeeee".into()));
+        assert_eq!(color_to_hex(Color::Reset), None);
+    }
+}
@@ -3,9 +3,10 @@
 //! Provides a self-contained debug overlay with automatic pause/resume of
 //! tasks and subscriptions.
 
-use std::io::Write;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
-use base64::prelude::*;
 use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEventKind};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
@@ -17,19 +18,65 @@ use super::action_logger::{ActionLog, ActionLogConfig};
 use super::actions::{DebugAction, DebugSideEffect};
 use super::cell::inspect_cell;
 use super::config::DebugStyle;
-use super::state::DebugState;
+use super::diff::{diff_sections, StateChangeKind, StateDiffEntry};
+use super::export::{export_buffer, FrameExport, FrameFormat};
+use super::metrics::{MetricsMiddleware, PerfMetrics, RenderCause};
+#[cfg(feature = "debug-remote")]
+use super::remote::RemoteInspector;
+use super::slow::SlowReducerMiddleware;
+use super::state::{DebugSection, DebugState};
+use super::strict::{StrictChecks, StrictMiddleware};
+#[cfg(feature = "tasks")]
+use super::table::DebugTableRow;
 use super::table::{ActionLogOverlay, DebugOverlay, DebugTableBuilder, DebugTableOverlay};
+use super::tree::StateTree;
 use super::widgets::{
     dim_buffer, paint_snapshot, ActionLogWidget, BannerItem, CellPreviewWidget, DebugBanner,
     DebugTableWidget,
 };
 use super::DebugFreeze;
+use crate::clipboard::copy_to_clipboard;
+use crate::store::MiddlewareInfo;
+use crate::strings::Strings;
 #[cfg(feature = "subscriptions")]
 use crate::subscriptions::SubPauseHandle;
 #[cfg(feature = "tasks")]
-use crate::tasks::TaskPauseHandle;
+use crate::tasks::{TaskPauseHandle, TaskProgressView};
 use crate::Action;
 
+/// Number of actions listed in the metrics overlay.
+const METRICS_TOP_ACTIONS: usize = 10;
+
+/// Add a "Tasks" section listing running progress tasks.
+#[cfg(feature = "tasks")]
+fn append_task_progress(table: &mut DebugTableOverlay, view: &TaskProgressView) {
+    let progress = view.snapshot();
+    if progress.is_empty() {
+        return;
+    }
+    table.rows.push(DebugTableRow::Section("Tasks".to_string()));
+    for task in progress {
+        let percent = task
+            .fraction
+            .map(|fraction| format!("{:.0}%", fraction * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        let mut value = format!("{percent} ({:.1}s)", task.elapsed.as_secs_f64());
+        if let Some(action) = task.last_action {
+            value.push_str(&format!(" last: {action}"));
+        }
+        table.rows.push(DebugTableRow::Entry {
+            key: task.key.name().to_string(),
+            value,
+        });
+    }
+}
+
+/// Default file for `DebugAction::ExportActionLog`.
+const DEFAULT_ACTION_LOG_EXPORT_PATH: &str = "action_log.jsonl";
+
+/// Default file for `DebugAction::ExportState`.
+const DEFAULT_STATE_EXPORT_PATH: &str = "state_snapshot.json";
+
 /// Location of the debug banner relative to the app area.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BannerPosition {
@@ -46,10 +93,10 @@ impl BannerPosition {
         }
     }
 
-    fn label(self) -> &'static str {
+    fn label(self, strings: &Strings) -> &'static str {
         match self {
-            Self::Bottom => "bar:bottom",
-            Self::Top => "bar:top",
+            Self::Bottom => strings.debug_banner_bottom,
+            Self::Top => strings.debug_banner_top,
         }
     }
 }
@@ -62,6 +109,8 @@ pub struct DebugOutcome<A> {
     pub queued_actions: Vec<A>,
     /// Whether a re-render is needed.
     pub needs_render: bool,
+    /// Frames exported by this event (e.g. via `debug.export.html`).
+    pub exports: Vec<FrameExport>,
 }
 
 impl<A> DebugOutcome<A> {
@@ -70,14 +119,16 @@ impl<A> DebugOutcome<A> {
             consumed: false,
             queued_actions: Vec::new(),
             needs_render: false,
+            exports: Vec::new(),
         }
     }
 
-    fn consumed(queued_actions: Vec<A>) -> Self {
+    fn consumed(queued_actions: Vec<A>, exports: Vec<FrameExport>) -> Self {
         Self {
             consumed: true,
             queued_actions,
             needs_render: true,
+            exports,
         }
     }
 
@@ -147,15 +198,45 @@ pub struct DebugLayer<A> {
     active: bool,
     /// Action log for display
     action_log: ActionLog,
+    /// Name filter for the action log overlay and exports
+    action_log_filter: String,
+    /// File written by `DebugAction::ExportActionLog`
+    action_log_export_path: PathBuf,
+    /// File written by `DebugAction::ExportState`
+    state_export_path: PathBuf,
+    /// Whether to capture state diffs per logged action
+    state_diff_enabled: bool,
+    /// State sections after the last recorded dispatch
+    last_state_sections: Option<Vec<DebugSection>>,
+    /// Sequence of the logged action awaiting its state diff
+    pending_diff_sequence: Option<u64>,
+    /// State diffs keyed by action sequence (bounded by log capacity)
+    state_diffs: VecDeque<(u64, Vec<StateDiffEntry>)>,
     /// Cached state snapshot for the state overlay
     state_snapshot: Option<DebugTableOverlay>,
     /// Scroll offset for state/inspect table overlays
     table_scroll_offset: usize,
     /// Cached page size for table overlay scrolling
     table_page_size: usize,
+    /// Performance metrics shared with `MetricsMiddleware`
+    metrics: Arc<Mutex<PerfMetrics>>,
+    /// Whether frames are attributed to the actions that changed the state
+    render_reasons: bool,
+    /// Most recently logged action
+    last_logged: Option<RenderCause>,
+    /// Actions that changed the state since the last frame
+    pending_render_causes: Vec<RenderCause>,
+    /// Ignored-action checks shared with `StrictMiddleware`
+    strict: Arc<Mutex<StrictChecks>>,
+    /// Socket server for remote inspection
+    #[cfg(feature = "debug-remote")]
+    remote: Option<RemoteInspector>,
     /// Handle to pause/resume task manager
     #[cfg(feature = "tasks")]
     task_handle: Option<TaskPauseHandle<A>>,
+    /// Progress of running tasks, listed in the metrics overlay
+    #[cfg(feature = "tasks")]
+    task_progress: Option<TaskProgressView>,
     /// Handle to pause/resume subscriptions
     #[cfg(feature = "subscriptions")]
     sub_handle: Option<SubPauseHandle>,
@@ -194,11 +275,27 @@ impl<A: Action> DebugLayer<A> {
             style: DebugStyle::default(),
             active: true,
             action_log: ActionLog::new(ActionLogConfig::with_capacity(100)),
+            action_log_filter: String::new(),
+            action_log_export_path: PathBuf::from(DEFAULT_ACTION_LOG_EXPORT_PATH),
+            state_export_path: PathBuf::from(DEFAULT_STATE_EXPORT_PATH),
+            state_diff_enabled: true,
+            last_state_sections: None,
+            pending_diff_sequence: None,
+            state_diffs: VecDeque::new(),
             state_snapshot: None,
             table_scroll_offset: 0,
             table_page_size: 1,
+            metrics: Arc::new(Mutex::new(PerfMetrics::default())),
+            render_reasons: false,
+            last_logged: None,
+            pending_render_causes: Vec::new(),
+            strict: Arc::new(Mutex::new(StrictChecks::default())),
+            #[cfg(feature = "debug-remote")]
+            remote: None,
             #[cfg(feature = "tasks")]
             task_handle: None,
+            #[cfg(feature = "tasks")]
+            task_progress: None,
             #[cfg(feature = "subscriptions")]
             sub_handle: None,
         }
@@ -231,10 +328,12 @@ impl<A: Action> DebugLayer<A> {
     /// Connect a task manager for automatic pause/resume.
     ///
     /// When debug mode is enabled, the task manager will be paused.
-    /// When disabled, queued actions will be returned.
+    /// When disabled, queued actions will be returned. Running progress tasks
+    /// are listed in the metrics overlay.
     #[cfg(feature = "tasks")]
     pub fn with_task_manager(mut self, tasks: &crate::tasks::TaskManager<A>) -> Self {
         self.task_handle = Some(tasks.pause_handle());
+        self.task_progress = Some(tasks.progress_view());
         self
     }
 
@@ -253,6 +352,47 @@ impl<A: Action> DebugLayer<A> {
         self
     }
 
+    /// Set the file the action log is exported to (default `action_log.jsonl`).
+    ///
+    /// Exports are triggered by `w` in the action log overlay or
+    /// [`DebugAction::ExportActionLog`].
+    pub fn with_action_log_export_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.action_log_export_path = path.into();
+        self
+    }
+
+    /// Set the file state snapshots are written to (default `state_snapshot.json`).
+    ///
+    /// Snapshots are written by `d` while debug mode is on, or
+    /// [`export_state`](Self::export_state).
+    pub fn with_state_export_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.state_export_path = path.into();
+        self
+    }
+
+    /// Enable or disable per-action state diffing (enabled by default).
+    ///
+    /// When enabled, [`record_state`](Self::record_state) snapshots the
+    /// state after each dispatch that changed it, so the action detail view
+    /// can show what changed.
+    pub fn with_state_diff(mut self, enabled: bool) -> Self {
+        self.state_diff_enabled = enabled;
+        self
+    }
+
+    /// Enable or disable render cause tracking (disabled by default).
+    ///
+    /// When enabled, each frame is attributed to the actions that changed
+    /// the state since the previous one (see [`record_change`](Self::record_change)).
+    /// The banner shows the last cause and how often it rendered in the last
+    /// second, and the metrics overlay lists the actions causing the most
+    /// renders. Reducers that return `true` without a visible change stand
+    /// out there.
+    pub fn with_render_reasons(mut self, enabled: bool) -> Self {
+        self.render_reasons = enabled;
+        self
+    }
+
     /// Set custom style.
     pub fn with_style(mut self, style: DebugStyle) -> Self {
         self.style = style;
@@ -304,6 +444,14 @@ impl<A: Action> DebugLayer<A> {
         matches!(self.freeze.overlay, Some(DebugOverlay::State(_)))
     }
 
+    /// Whether the overlay needs mouse events for cell inspection (toggle with 'i').
+    ///
+    /// The runtimes enable terminal mouse capture while this is true, even
+    /// if the app has it off.
+    pub fn wants_mouse_capture(&self) -> bool {
+        self.is_enabled() && self.freeze.mouse_capture_enabled
+    }
+
     /// Get a reference to the underlying freeze state.
     pub fn freeze(&self) -> &DebugFreeze<A> {
         &self.freeze
@@ -314,20 +462,252 @@ impl<A: Action> DebugLayer<A> {
         &mut self.freeze
     }
 
+    /// Serve the action log and state to remote clients.
+    ///
+    /// Every logged action and recorded state is forwarded to the
+    /// inspector. See [`RemoteInspector`] for the protocol.
+    #[cfg(feature = "debug-remote")]
+    pub fn with_remote_inspector(mut self, inspector: RemoteInspector) -> Self {
+        self.remote = Some(inspector);
+        self
+    }
+
+    /// Get the attached remote inspector, if any.
+    #[cfg(feature = "debug-remote")]
+    pub fn remote_inspector(&self) -> Option<&RemoteInspector> {
+        self.remote.as_ref()
+    }
+
     /// Log an action to the action log.
     ///
     /// Call this when dispatching actions to record them for the debug overlay.
     pub fn log_action<T: crate::ActionParams>(&mut self, action: &T) {
         if self.active {
-            self.action_log.log(action);
+            self.log_strict_warnings();
+            self.last_logged = Some(RenderCause {
+                name: action.name(),
+                category: None,
+            });
+            let entry = self.action_log.log(action);
+            #[cfg(feature = "debug-remote")]
+            if let (Some(remote), Some(entry)) = (&self.remote, entry) {
+                remote.push_action(entry);
+            }
+            self.pending_diff_sequence = entry.map(|entry| entry.sequence);
+        }
+    }
+
+    /// Log an action together with its category.
+    ///
+    /// Like [`log_action`](Self::log_action), for actions deriving
+    /// categories; the category is included in action log exports.
+    pub fn log_categorized_action<T: crate::ActionParams + crate::ActionCategory>(
+        &mut self,
+        action: &T,
+    ) {
+        if self.active {
+            self.log_strict_warnings();
+            self.last_logged = Some(RenderCause {
+                name: action.name(),
+                category: action.category(),
+            });
+            let entry = self.action_log.log_categorized(action);
+            #[cfg(feature = "debug-remote")]
+            if let (Some(remote), Some(entry)) = (&self.remote, entry) {
+                remote.push_action(entry);
+            }
+            self.pending_diff_sequence = entry.map(|entry| entry.sequence);
+        }
+    }
+
+    /// Record the state after dispatching the last logged action.
+    ///
+    /// Call this after each dispatch (and once at startup to set a baseline).
+    /// The diff against the previous snapshot is attached to the most recently
+    /// logged action and shown in its detail view.
+    pub fn record_state<S: DebugState>(&mut self, state: &S) {
+        if !self.active {
+            return;
+        }
+        self.log_strict_warnings();
+
+        #[cfg(feature = "debug-remote")]
+        let wants_sections = self.state_diff_enabled || self.remote.is_some();
+        #[cfg(not(feature = "debug-remote"))]
+        let wants_sections = self.state_diff_enabled;
+        if !wants_sections {
+            return;
+        }
+
+        // Formatted once and shared by the diff and the remote inspector
+        let sections = state.debug_sections();
+        #[cfg(feature = "debug-remote")]
+        if let Some(remote) = &self.remote {
+            remote.set_state(sections.clone());
+        }
+
+        if !self.state_diff_enabled {
+            return;
+        }
+        if let (Some(sequence), Some(before)) = (
+            self.pending_diff_sequence.take(),
+            self.last_state_sections.as_ref(),
+        ) {
+            let diff = diff_sections(before, &sections);
+            self.push_state_diff(sequence, diff);
+        }
+        self.last_state_sections = Some(sections);
+    }
+
+    /// Record the state after a dispatch, skipping the snapshot if the
+    /// reducer reported no change.
+    ///
+    /// Like [`record_state`](Self::record_state), but unchanged dispatches
+    /// cost nothing: the logged action gets an empty diff and the previous
+    /// snapshot stays the baseline. The runtimes call this after each
+    /// dispatch.
+    pub fn record_dispatched_state<S: DebugState>(&mut self, state: &S, changed: bool) {
+        if changed {
+            self.record_state(state);
+            return;
+        }
+        if !self.active {
+            return;
+        }
+        self.log_strict_warnings();
+        if let (Some(sequence), Some(_)) = (
+            self.pending_diff_sequence.take(),
+            self.last_state_sections.as_ref(),
+        ) {
+            self.push_state_diff(sequence, Vec::new());
         }
     }
 
+    fn push_state_diff(&mut self, sequence: u64, diff: Vec<StateDiffEntry>) {
+        let capacity = self.action_log.config().capacity.max(1);
+        while self.state_diffs.len() >= capacity {
+            self.state_diffs.pop_front();
+        }
+        self.state_diffs.push_back((sequence, diff));
+    }
+
+    /// Get the recorded state diff for an action sequence number.
+    pub fn state_diff(&self, sequence: u64) -> Option<&[StateDiffEntry]> {
+        self.state_diffs
+            .iter()
+            .find(|(seq, _)| *seq == sequence)
+            .map(|(_, diff)| diff.as_slice())
+    }
+
     /// Get the action log.
     pub fn action_log(&self) -> &ActionLog {
         &self.action_log
     }
 
+    /// Create middleware that times reducer runs for the metrics overlay.
+    ///
+    /// Attach it to your store (e.g., via `StoreWithMiddleware`) to populate
+    /// reducer timings and actions per second.
+    pub fn metrics_middleware(&self) -> MetricsMiddleware {
+        MetricsMiddleware::new(self.metrics.clone()).active(self.active)
+    }
+
+    /// Create middleware that reports dispatches slower than `threshold`.
+    ///
+    /// Slow dispatches are logged with `tracing` and counted in the metrics
+    /// overlay's "Slow reducers" section.
+    pub fn slow_reducer_middleware(
+        &self,
+        threshold: std::time::Duration,
+    ) -> SlowReducerMiddleware<A>
+    where
+        A: crate::ActionParams,
+    {
+        SlowReducerMiddleware::new(threshold)
+            .with_metrics(self.metrics.clone())
+            .active(self.active)
+    }
+
+    /// Create middleware that warns about actions the reducer keeps ignoring.
+    ///
+    /// Warnings show up in the action log on the next `log_action` or
+    /// `record_state` call. Only active in debug builds.
+    pub fn strict_middleware(&self) -> StrictMiddleware {
+        StrictMiddleware::new(self.strict.clone()).active(self.active)
+    }
+
+    /// Get a snapshot of the ignored-action checks.
+    pub fn strict_checks(&self) -> StrictChecks {
+        self.strict
+            .lock()
+            .map(|checks| checks.clone())
+            .unwrap_or_default()
+    }
+
+    /// Move pending strict-mode warnings into the action log.
+    fn log_strict_warnings(&mut self) {
+        let warnings = match self.strict.lock() {
+            Ok(mut checks) => checks.take_warnings(),
+            Err(_) => return,
+        };
+        for warning in warnings {
+            let _entry = self
+                .action_log
+                .log_warning(warning.name, &warning.to_string());
+            #[cfg(feature = "debug-remote")]
+            if let Some(remote) = &self.remote {
+                remote.push_action(_entry);
+            }
+        }
+    }
+
+    /// Record whether the last logged action changed the state.
+    ///
+    /// With [`with_render_reasons`](Self::with_render_reasons), a change
+    /// marks that action as a cause of the next frame. The runtimes call
+    /// this automatically after each dispatch.
+    pub fn record_change(&mut self, changed: bool) {
+        if !self.active || !self.render_reasons || !changed {
+            return;
+        }
+        if let Some(cause) = self.last_logged {
+            self.pending_render_causes
+                .retain(|pending| pending.name != cause.name);
+            self.pending_render_causes.push(cause);
+        }
+    }
+
+    /// Record how many actions are waiting to be dispatched.
+    ///
+    /// The runtimes call this automatically when a debug layer is attached.
+    pub fn record_queue_depth(&self, depth: usize) {
+        if self.active {
+            if let Ok(mut metrics) = self.metrics.lock() {
+                metrics.record_queue_depth(depth);
+            }
+        }
+    }
+
+    /// Record the middleware attached to the store for the metrics overlay.
+    ///
+    /// Pass [`ComposedMiddleware::iter`](crate::ComposedMiddleware::iter);
+    /// call again after enabling or disabling one.
+    pub fn record_middlewares(&self, middlewares: impl IntoIterator<Item = MiddlewareInfo>) {
+        if self.active {
+            if let Ok(mut metrics) = self.metrics.lock() {
+                metrics.set_middlewares(middlewares);
+            }
+        }
+    }
+
+    /// Get a snapshot of the current performance metrics.
+    pub fn metrics(&self) -> PerfMetrics {
+        self.metrics
+            .lock()
+            .map(|metrics| metrics.clone())
+            .unwrap_or_default()
+    }
+
     /// Render with automatic debug handling.
     ///
     /// When debug mode is disabled, simply calls `render_fn` with the full frame area.
@@ -355,6 +735,9 @@ impl<A: Action> DebugLayer<A> {
 
         // Inactive or not in debug mode: just render normally
         if !self.active || !self.freeze.enabled {
+            if self.active {
+                self.record_frame();
+            }
             let _ = render_fn(frame, screen, false);
             return;
         }
@@ -364,6 +747,7 @@ impl<A: Action> DebugLayer<A> {
 
         if self.freeze.pending_capture || self.freeze.snapshot.is_none() {
             // Capture mode: render app, then capture
+            self.record_frame();
             let state_snapshot = render_fn(frame, app_area, true);
             self.state_snapshot = state_snapshot;
             if let Some(ref table) = self.state_snapshot {
@@ -480,13 +864,18 @@ impl<A: Action> DebugLayer<A> {
         };
 
         let mut queued_actions = Vec::new();
+        let mut exports = Vec::new();
         for effect in effects {
-            if let DebugSideEffect::ProcessQueuedActions(actions) = effect {
-                queued_actions.extend(actions);
+            match effect {
+                DebugSideEffect::ProcessQueuedActions(actions) => queued_actions.extend(actions),
+                DebugSideEffect::ExportFrame(export) => exports.push(export),
+                DebugSideEffect::CopyToClipboard(_)
+                | DebugSideEffect::ActionLogExported { .. }
+                | DebugSideEffect::StateExported { .. } => {}
             }
         }
 
-        DebugOutcome::consumed(queued_actions)
+        DebugOutcome::consumed(queued_actions, exports)
     }
 
     fn intercepts_with_effects_internal<S: DebugState>(
@@ -530,6 +919,16 @@ impl<A: Action> DebugLayer<A> {
                     return None;
                 }
 
+                // The state tree scrolls by moving its selection
+                let step = if *delta > 0 {
+                    KeyCode::Up
+                } else {
+                    KeyCode::Down
+                };
+                if self.handle_state_tree_key(step) {
+                    return Some(vec![]);
+                }
+
                 match self.freeze.overlay.as_ref() {
                     Some(DebugOverlay::ActionLog(_)) => {
                         let action = if *delta > 0 {
@@ -539,7 +938,9 @@ impl<A: Action> DebugLayer<A> {
                         };
                         self.handle_action(action);
                     }
-                    Some(DebugOverlay::State(table)) | Some(DebugOverlay::Inspect(table)) => {
+                    Some(DebugOverlay::State(table))
+                    | Some(DebugOverlay::Inspect(table))
+                    | Some(DebugOverlay::Metrics(table)) => {
                         if *delta > 0 {
                             self.scroll_table_up();
                         } else {
@@ -551,8 +952,13 @@ impl<A: Action> DebugLayer<A> {
 
                 Some(vec![])
             }
-            // Don't intercept resize or tick events
-            EventKind::Resize(_, _) | EventKind::Tick => None,
+            // Swallow pastes and key releases while frozen so they don't reach the app
+            EventKind::Paste(_) | EventKind::KeyRelease(_) => self.freeze.enabled.then(Vec::new),
+            // Don't intercept resize, tick or focus events
+            EventKind::Resize(_, _)
+            | EventKind::Tick
+            | EventKind::FocusGained
+            | EventKind::FocusLost => None,
         }
     }
 
@@ -564,10 +970,25 @@ impl<A: Action> DebugLayer<A> {
 
     /// Show action log overlay.
     pub fn show_action_log(&mut self) {
-        let overlay = ActionLogOverlay::from_log(&self.action_log, "Action Log");
+        let mut overlay = ActionLogOverlay::from_log(&self.action_log, "Action Log");
+        overlay.set_filter(self.action_log_filter.clone());
         self.freeze.set_overlay(DebugOverlay::ActionLog(overlay));
     }
 
+    /// Show performance metrics overlay.
+    ///
+    /// The table is a snapshot taken when the overlay opens.
+    pub fn show_metrics(&mut self) {
+        #[allow(unused_mut)]
+        let mut table = self.metrics().build_table(METRICS_TOP_ACTIONS);
+        #[cfg(feature = "tasks")]
+        if let Some(ref view) = self.task_progress {
+            append_task_progress(&mut table, view);
+        }
+        self.table_scroll_offset = 0;
+        self.freeze.set_overlay(DebugOverlay::Metrics(table));
+    }
+
     /// Queue an action to be processed when debug mode is disabled.
     pub fn queue_action(&mut self, action: A) {
         self.freeze.queue(action);
@@ -585,6 +1006,13 @@ impl<A: Action> DebugLayer<A> {
     // Private helpers
     // =========================================================================
 
+    fn record_frame(&mut self) {
+        let causes = std::mem::take(&mut self.pending_render_causes);
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.record_frame_caused_by(&causes);
+        }
+    }
+
     fn set_state_overlay(&mut self, table: DebugTableOverlay) {
         if !matches!(self.freeze.overlay, Some(DebugOverlay::State(_))) {
             self.table_scroll_offset = 0;
@@ -596,6 +1024,15 @@ impl<A: Action> DebugLayer<A> {
     fn update_table_scroll(&mut self, table: &DebugTableOverlay, table_area: Rect) {
         let visible_rows = table_area.height.saturating_sub(1) as usize;
         self.table_page_size = visible_rows.max(1);
+        if let Some(ref tree) = table.tree {
+            // Keep the selected row in view
+            let selected = tree.selected();
+            if selected < self.table_scroll_offset {
+                self.table_scroll_offset = selected;
+            } else if selected >= self.table_scroll_offset + self.table_page_size {
+                self.table_scroll_offset = selected + 1 - self.table_page_size;
+            }
+        }
         let max_offset = table.rows.len().saturating_sub(visible_rows);
         self.table_scroll_offset = self.table_scroll_offset.min(max_offset);
     }
@@ -689,6 +1126,87 @@ impl<A: Action> DebugLayer<A> {
         }
     }
 
+    /// Navigate the state overlay's section tree; false if it has none
+    fn handle_state_tree_key(&mut self, key: KeyCode) -> bool {
+        let page_size = self.table_page_size_value();
+        let Some(DebugOverlay::State(ref mut table)) = self.freeze.overlay else {
+            return false;
+        };
+        let Some(selected) = table.tree.as_ref().map(StateTree::selected) else {
+            return false;
+        };
+
+        if matches!(key, KeyCode::Char('c') | KeyCode::Char('C')) {
+            let value = table
+                .tree
+                .as_ref()
+                .and_then(StateTree::selected_value)
+                .map(str::to_string);
+            match value.map(|value| copy_to_clipboard(&value)) {
+                Some(Ok(_)) => self.freeze.set_message("Copied value to clipboard"),
+                Some(Err(error)) => self.freeze.set_message(format!("Copy failed: {error}")),
+                None => self.freeze.set_message("Select an entry to copy its value"),
+            }
+            return true;
+        }
+
+        table
+            .update_tree(|tree| {
+                match key {
+                    KeyCode::Char('j') | KeyCode::Down => tree.select(selected + 1),
+                    KeyCode::Char('k') | KeyCode::Up => tree.select(selected.saturating_sub(1)),
+                    KeyCode::Char('g') => tree.select(0),
+                    KeyCode::Char('G') => tree.select(usize::MAX),
+                    KeyCode::PageDown => tree.select(selected + page_size),
+                    KeyCode::PageUp => tree.select(selected.saturating_sub(page_size)),
+                    KeyCode::Enter => {
+                        tree.toggle();
+                    }
+                    KeyCode::Char('l') | KeyCode::Right => {
+                        tree.expand();
+                    }
+                    KeyCode::Char('h') | KeyCode::Left => {
+                        tree.collapse();
+                    }
+                    _ => return false,
+                }
+                true
+            })
+            .unwrap_or(false)
+    }
+
+    /// Write the state's [`debug_snapshot`](DebugState::debug_snapshot) to the
+    /// state export file.
+    ///
+    /// The file can be attached to bug reports and loaded back with
+    /// `Store::import_snapshot`. The result is shown in the banner.
+    pub fn export_state<S: DebugState>(&mut self, state: &S) -> Option<DebugSideEffect<A>> {
+        let Some(json) = state.debug_snapshot() else {
+            self.freeze
+                .set_message("State snapshots need DebugState::debug_snapshot");
+            return None;
+        };
+        let path = self.state_export_path.clone();
+        let written = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&path, json));
+        match written {
+            Ok(()) => {
+                self.freeze
+                    .set_message(format!("Exported state to {}", path.display()));
+                Some(DebugSideEffect::StateExported { path })
+            }
+            Err(error) => {
+                tracing::warn!(%error, "Failed to export state");
+                self.freeze
+                    .set_message(format!("State export failed: {error}"));
+                None
+            }
+        }
+    }
+
     fn handle_key_event<S: DebugState>(
         &mut self,
         key: KeyEvent,
@@ -700,6 +1218,27 @@ impl<A: Action> DebugLayer<A> {
             return Some(effect.into_iter().collect());
         }
 
+        // The action log filter prompt captures keys before any shortcut
+        if let Some(DebugOverlay::ActionLog(ref mut log)) = self.freeze.overlay {
+            if self.freeze.enabled && log.filter_active {
+                let mut filter = log.filter.clone();
+                match key.code {
+                    KeyCode::Enter => log.filter_active = false,
+                    KeyCode::Esc => {
+                        log.filter_active = false;
+                        filter.clear();
+                    }
+                    KeyCode::Backspace => {
+                        filter.pop();
+                    }
+                    KeyCode::Char(c) => filter.push(c),
+                    _ => {}
+                }
+                self.handle_action(DebugAction::ActionLogFilter(filter));
+                return Some(vec![]);
+            }
+        }
+
         // Esc also toggles off when enabled
         if self.freeze.enabled && key.code == KeyCode::Esc {
             let effect = self.toggle();
@@ -717,6 +1256,13 @@ impl<A: Action> DebugLayer<A> {
                 self.freeze.request_capture();
                 return Some(vec![]);
             }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                let effect = match state {
+                    Some(state) => self.export_state(state),
+                    None => self.handle_action(DebugAction::ExportState),
+                };
+                return Some(effect.into_iter().collect());
+            }
             KeyCode::Char('s') | KeyCode::Char('S') => {
                 if matches!(self.freeze.overlay, Some(DebugOverlay::State(_))) {
                     self.freeze.clear_overlay();
@@ -743,7 +1289,11 @@ impl<A: Action> DebugLayer<A> {
         // Handle internal debug commands (hardcoded keys)
         let action = match key.code {
             KeyCode::Char('a') | KeyCode::Char('A') => Some(DebugAction::ToggleActionLog),
+            KeyCode::Char('m') | KeyCode::Char('M') => Some(DebugAction::ToggleMetrics),
             KeyCode::Char('y') | KeyCode::Char('Y') => Some(DebugAction::CopyFrame),
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                Some(DebugAction::ExportFrame(FrameFormat::Html))
+            }
             KeyCode::Char('i') | KeyCode::Char('I') => Some(DebugAction::ToggleMouseCapture),
             KeyCode::Char('q') | KeyCode::Char('Q') => Some(DebugAction::CloseOverlay),
             _ => None,
@@ -754,6 +1304,24 @@ impl<A: Action> DebugLayer<A> {
             return Some(effect.into_iter().collect());
         }
 
+        if let Some(DebugOverlay::ActionLog(ref mut log)) = self.freeze.overlay {
+            match key.code {
+                KeyCode::Char('/') => {
+                    log.filter_active = true;
+                    return Some(vec![]);
+                }
+                KeyCode::Char('w') | KeyCode::Char('W') => {
+                    let effect = self.handle_action(DebugAction::ExportActionLog);
+                    return Some(effect.into_iter().collect());
+                }
+                _ => {}
+            }
+        }
+
+        if self.handle_state_tree_key(key.code) {
+            return Some(vec![]);
+        }
+
         // Handle overlay-specific navigation
         match &self.freeze.overlay {
             Some(DebugOverlay::ActionLog(_)) => {
@@ -772,7 +1340,9 @@ impl<A: Action> DebugLayer<A> {
                     return Some(vec![]);
                 }
             }
-            Some(DebugOverlay::State(table)) | Some(DebugOverlay::Inspect(table)) => {
+            Some(DebugOverlay::State(table))
+            | Some(DebugOverlay::Inspect(table))
+            | Some(DebugOverlay::Metrics(table)) => {
                 if self.handle_table_scroll_key(key.code, table.rows.len()) {
                     return Some(vec![]);
                 }
@@ -845,12 +1415,47 @@ impl<A: Action> DebugLayer<A> {
         match action {
             DebugAction::Toggle => self.toggle(),
             DebugAction::CopyFrame => {
-                let text = &self.freeze.snapshot_text;
-                // Use OSC52 escape sequence to copy to clipboard
-                let encoded = BASE64_STANDARD.encode(text);
-                print!("\x1b]52;c;{}\x07", encoded);
-                std::io::stdout().flush().ok();
-                self.freeze.set_message("Copied to clipboard");
+                match copy_to_clipboard(&self.freeze.snapshot_text) {
+                    Ok(_) => self.freeze.set_message("Copied to clipboard"),
+                    Err(error) => self.freeze.set_message(format!("Copy failed: {error}")),
+                }
+                None
+            }
+            DebugAction::ExportFrame(format) => {
+                let Some(ref snapshot) = self.freeze.snapshot else {
+                    self.freeze.set_message("No frame captured");
+                    return None;
+                };
+                let content = export_buffer(snapshot, format);
+                self.freeze
+                    .set_message(format!("Exported frame as {}", format.label()));
+                Some(DebugSideEffect::ExportFrame(FrameExport {
+                    format,
+                    content,
+                }))
+            }
+            DebugAction::ExportActionLog => {
+                let path = self.action_log_export_path.clone();
+                match self.action_log.export_jsonl(&path, &self.action_log_filter) {
+                    Ok(entries) => {
+                        self.freeze.set_message(format!(
+                            "Exported {} actions to {}",
+                            entries,
+                            path.display()
+                        ));
+                        Some(DebugSideEffect::ActionLogExported { path, entries })
+                    }
+                    Err(error) => {
+                        tracing::warn!(%error, "Failed to export action log");
+                        self.freeze
+                            .set_message(format!("Action log export failed: {error}"));
+                        None
+                    }
+                }
+            }
+            DebugAction::ExportState => {
+                // Only reachable without state; `export_state` has it
+                self.freeze.set_message("No state to export");
                 None
             }
             DebugAction::ToggleState => {
@@ -879,6 +1484,14 @@ impl<A: Action> DebugLayer<A> {
                 }
                 None
             }
+            DebugAction::ToggleMetrics => {
+                if matches!(self.freeze.overlay, Some(DebugOverlay::Metrics(_))) {
+                    self.freeze.clear_overlay();
+                } else {
+                    self.show_metrics();
+                }
+                None
+            }
             DebugAction::ActionLogScrollUp => {
                 if let Some(DebugOverlay::ActionLog(ref mut log)) = self.freeze.overlay {
                     log.scroll_up();
@@ -917,12 +1530,20 @@ impl<A: Action> DebugLayer<A> {
             }
             DebugAction::ActionLogShowDetail => {
                 if let Some(DebugOverlay::ActionLog(ref log)) = self.freeze.overlay {
-                    if let Some(detail) = log.selected_detail() {
+                    if let Some(mut detail) = log.selected_detail() {
+                        detail.state_diff = self.state_diff(detail.sequence).map(<[_]>::to_vec);
                         self.freeze.set_overlay(DebugOverlay::ActionDetail(detail));
                     }
                 }
                 None
             }
+            DebugAction::ActionLogFilter(filter) => {
+                if let Some(DebugOverlay::ActionLog(ref mut log)) = self.freeze.overlay {
+                    log.set_filter(filter.clone());
+                }
+                self.action_log_filter = filter;
+                None
+            }
             DebugAction::ActionLogBackToList => {
                 // Go back to action log from detail view
                 if matches!(self.freeze.overlay, Some(DebugOverlay::ActionDetail(_))) {
@@ -993,7 +1614,9 @@ impl<A: Action> DebugLayer<A> {
             dim_buffer(frame.buffer_mut(), self.style.dim_factor);
 
             match overlay {
-                DebugOverlay::Inspect(table) | DebugOverlay::State(table) => {
+                DebugOverlay::Inspect(table)
+                | DebugOverlay::State(table)
+                | DebugOverlay::Metrics(table) => {
                     self.render_table_modal(frame, app_area, table);
                 }
                 DebugOverlay::ActionLog(log) => {
@@ -1015,28 +1638,48 @@ impl<A: Action> DebugLayer<A> {
         }
 
         let keys = &self.style.key_styles;
+        let strings = &self.style.strings;
         let toggle_key_str = format_key(self.toggle_key);
         let mut banner = DebugBanner::new()
-            .title("DEBUG")
+            .title(strings.debug_title)
             .title_style(self.style.title_style)
             .label_style(self.style.label_style)
             .background(self.style.banner_bg);
 
         // Add standard debug commands with hardcoded keys
-        banner = banner.item(BannerItem::new(&toggle_key_str, "resume", keys.toggle));
-        banner = banner.item(BannerItem::new("a", "actions", keys.actions));
-        banner = banner.item(BannerItem::new("s", "state", keys.state));
+        banner = banner.item(BannerItem::new(
+            &toggle_key_str,
+            strings.debug_resume,
+            keys.toggle,
+        ));
+        banner = banner.item(BannerItem::new("a", strings.debug_actions, keys.actions));
+        banner = banner.item(BannerItem::new("s", strings.debug_state, keys.state));
+        banner = banner.item(BannerItem::new("m", strings.debug_metrics, keys.state));
         banner = banner.item(BannerItem::new(
             "b",
-            self.banner_position.label(),
+            self.banner_position.label(strings),
             keys.actions,
         ));
-        banner = banner.item(BannerItem::new("y", "copy", keys.copy));
+        banner = banner.item(BannerItem::new("y", strings.debug_copy, keys.copy));
+        banner = banner.item(BannerItem::new("e", strings.debug_export, keys.copy));
+        if self.is_state_overlay_visible() {
+            banner = banner.item(BannerItem::new("c", strings.debug_copy_value, keys.copy));
+            banner = banner.item(BannerItem::new("d", strings.debug_save_state, keys.copy));
+        }
 
         if self.freeze.mouse_capture_enabled {
-            banner = banner.item(BannerItem::new("click", "inspect", keys.mouse));
+            banner = banner.item(BannerItem::new("click", strings.debug_inspect, keys.mouse));
         } else {
-            banner = banner.item(BannerItem::new("i", "mouse", keys.mouse));
+            banner = banner.item(BannerItem::new("i", strings.debug_mouse, keys.mouse));
+        }
+
+        let render_cause = self
+            .render_reasons
+            .then(|| self.metrics.lock().ok()?.render_cause_summary())
+            .flatten()
+            .map(|summary| format!("{}: {}", strings.debug_render_cause, summary));
+        if let Some(ref render_cause) = render_cause {
+            banner = banner.item(BannerItem::new("", render_cause, self.style.value_style));
         }
 
         // Add message if present
@@ -1062,9 +1705,14 @@ impl<A: Action> DebugLayer<A> {
 
         frame.render_widget(Clear, modal_area);
 
+        // State trees show the path to the selected row
+        let title = match table.tree.as_ref().map(StateTree::breadcrumb) {
+            Some(path) if !path.is_empty() => format!(" {} › {} ", table.title, path.join(" › ")),
+            _ => format!(" {} ", table.title),
+        };
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(format!(" {} ", table.title))
+            .title(title)
             .style(self.style.banner_bg);
 
         let inner = block.inner(modal_area);
@@ -1118,7 +1766,9 @@ impl<A: Action> DebugLayer<A> {
         };
 
         self.update_table_scroll(table, table_area);
-        let table_widget = DebugTableWidget::new(table).scroll_offset(self.table_scroll_offset);
+        let table_widget = DebugTableWidget::new(table)
+            .scroll_offset(self.table_scroll_offset)
+            .selected(table.tree.as_ref().map(StateTree::selected));
         frame.render_widget(table_widget, table_area);
 
         if let Some(scrollbar_area) = scrollbar_area {
@@ -1147,15 +1797,30 @@ impl<A: Action> DebugLayer<A> {
         frame.render_widget(Clear, modal_area);
 
         let entry_count = log.entries.len();
-        let title = if entry_count > 0 {
+        let title = if !log.filter.is_empty() {
+            format!(
+                " {} ({} of {} entries) ",
+                log.title,
+                entry_count,
+                log.all_entries.len()
+            )
+        } else if entry_count > 0 {
             format!(" {} ({} entries) ", log.title, entry_count)
         } else {
             format!(" {} (empty) ", log.title)
         };
+        let footer = if log.filter_active {
+            format!(" filter: {}▏ ", log.filter)
+        } else if !log.filter.is_empty() {
+            format!(" filter: {} · / edit · w export ", log.filter)
+        } else {
+            " / filter · w export ".to_string()
+        };
 
         let block = Block::default()
             .borders(Borders::ALL)
             .title(title)
+            .title_bottom(footer)
             .style(self.style.banner_bg);
 
         let mut log_area = block.inner(modal_area);
@@ -1266,6 +1931,34 @@ impl<A: Action> DebugLayer<A> {
             }
         }
 
+        // State changes
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("State changes:", label_style)));
+        match &detail.state_diff {
+            None => lines.push(Line::from(Span::styled("  (not captured)", label_style))),
+            Some(diff) if diff.is_empty() => {
+                lines.push(Line::from(Span::styled("  (no changes)", value_style)));
+            }
+            Some(diff) => {
+                for change in diff {
+                    let (marker, color) = match change.kind() {
+                        StateChangeKind::Added => ("+", DebugStyle::neon_green()),
+                        StateChangeKind::Removed => ("-", DebugStyle::neon_pink()),
+                        StateChangeKind::Changed => ("~", DebugStyle::neon_amber()),
+                    };
+                    let before = change.before.as_deref().unwrap_or("∅");
+                    let after = change.after.as_deref().unwrap_or("∅");
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("  {marker} "), Style::default().fg(color)),
+                        Span::styled(format!("{}.{}: ", change.section, change.key), label_style),
+                        Span::styled(before.to_string(), value_style),
+                        Span::styled(" → ", label_style),
+                        Span::styled(after.to_string(), Style::default().fg(color)),
+                    ]));
+                }
+            }
+        }
+
         // Footer hint
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
@@ -1448,4 +2141,287 @@ mod tests {
 
         assert_eq!(layer.action_log().entries().count(), 2);
     }
+
+    struct Counter(i32);
+
+    impl DebugState for Counter {
+        fn debug_sections(&self) -> Vec<DebugSection> {
+            vec![DebugSection::new("Counter").entry("value", self.0.to_string())]
+        }
+    }
+
+    #[test]
+    fn test_state_diff_per_action() {
+        let mut layer: DebugLayer<TestAction> = DebugLayer::new(KeyCode::F(12));
+        layer.record_state(&Counter(0));
+
+        layer.log_action(&TestAction::Foo);
+        layer.record_state(&Counter(1));
+
+        layer.log_action(&TestAction::Bar);
+        layer.record_state(&Counter(1));
+
+        let diff = layer.state_diff(0).unwrap();
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].key, "value");
+        assert_eq!(diff[0].before.as_deref(), Some("0"));
+        assert_eq!(diff[0].after.as_deref(), Some("1"));
+
+        assert!(layer.state_diff(1).unwrap().is_empty());
+        assert!(layer.state_diff(2).is_none());
+    }
+
+    #[test]
+    fn test_unchanged_dispatch_skips_snapshot() {
+        struct Counted<'a>(&'a std::cell::Cell<usize>, i32);
+
+        impl DebugState for Counted<'_> {
+            fn debug_sections(&self) -> Vec<DebugSection> {
+                self.0.set(self.0.get() + 1);
+                vec![DebugSection::new("Counter").entry("value", self.1.to_string())]
+            }
+        }
+
+        let calls = std::cell::Cell::new(0);
+        let mut layer: DebugLayer<TestAction> = DebugLayer::new(KeyCode::F(12));
+        layer.record_state(&Counted(&calls, 0));
+
+        layer.log_action(&TestAction::Foo);
+        layer.record_dispatched_state(&Counted(&calls, 0), false);
+        assert_eq!(calls.get(), 1);
+        assert!(layer.state_diff(0).unwrap().is_empty());
+
+        layer.log_action(&TestAction::Bar);
+        layer.record_dispatched_state(&Counted(&calls, 1), true);
+        assert_eq!(calls.get(), 2);
+        assert_eq!(layer.state_diff(1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_state_diff_disabled() {
+        let mut layer: DebugLayer<TestAction> =
+            DebugLayer::new(KeyCode::F(12)).with_state_diff(false);
+        layer.record_state(&Counter(0));
+        layer.log_action(&TestAction::Foo);
+        layer.record_state(&Counter(1));
+
+        assert!(layer.state_diff(0).is_none());
+    }
+
+    #[test]
+    fn test_strict_warnings_in_action_log() {
+        let mut layer: DebugLayer<TestAction> = DebugLayer::new(KeyCode::F(12));
+        let mut middleware = layer.strict_middleware();
+        for _ in 0..3 {
+            layer.log_action(&TestAction::Foo);
+            crate::store::Middleware::after(&mut middleware, &TestAction::Foo, false);
+        }
+        layer.record_state(&Counter(0));
+
+        let last = layer.action_log().entries_rev().next().unwrap();
+        assert_eq!(last.name, "Foo");
+        assert!(last.params().starts_with("⚠ Foo was dispatched 3 times"));
+        assert_eq!(layer.strict_checks().never_handled(), vec!["Foo"]);
+        assert!(layer.strict_checks().warnings().is_empty());
+    }
+
+    #[test]
+    fn test_metrics_overlay_toggle() {
+        let mut layer: DebugLayer<TestAction> = DebugLayer::new(KeyCode::F(12));
+        let mut middleware = layer.metrics_middleware();
+        crate::store::Middleware::before(&mut middleware, &TestAction::Foo);
+        crate::store::Middleware::after(&mut middleware, &TestAction::Foo, true);
+        layer.record_queue_depth(4);
+
+        let metrics = layer.metrics();
+        assert_eq!(metrics.dispatch_count(), 1);
+        assert_eq!(metrics.peak_queue_depth(), 4);
+
+        layer.toggle();
+        layer.handle_action(DebugAction::ToggleMetrics);
+        assert!(matches!(
+            layer.freeze().overlay,
+            Some(DebugOverlay::Metrics(_))
+        ));
+
+        layer.handle_action(DebugAction::ToggleMetrics);
+        assert!(layer.freeze().overlay.is_none());
+    }
+
+    #[test]
+    fn test_export_frame_effect() {
+        let mut layer: DebugLayer<TestAction> = DebugLayer::new(KeyCode::F(12));
+        layer.toggle();
+        assert!(layer
+            .handle_action(DebugAction::ExportFrame(FrameFormat::Html))
+            .is_none());
+
+        let mut snapshot = Buffer::empty(Rect::new(0, 0, 4, 1));
+        snapshot.set_string(0, 0, "hi", Style::default());
+        layer.freeze_mut().snapshot = Some(snapshot);
+
+        let effect = layer.handle_action(DebugAction::ExportFrame(FrameFormat::Svg));
+        let Some(DebugSideEffect::ExportFrame(export)) = effect else {
+            panic!("expected export effect");
+        };
+        assert_eq!(export.format, FrameFormat::Svg);
+        assert!(export.content.contains(">hi  </text>"));
+        assert_eq!(
+            layer.freeze().message.as_deref(),
+            Some("Exported frame as SVG")
+        );
+    }
+
+    #[test]
+    fn test_render_reasons() {
+        let mut layer: DebugLayer<TestAction> =
+            DebugLayer::new(KeyCode::F(12)).with_render_reasons(true);
+
+        layer.log_action(&TestAction::Foo);
+        layer.record_change(true);
+        layer.log_action(&TestAction::Bar);
+        layer.record_change(false);
+        layer.record_frame();
+
+        let metrics = layer.metrics();
+        assert_eq!(
+            metrics.last_render_cause().map(|cause| cause.name),
+            Some("Foo")
+        );
+        assert_eq!(metrics.renders_caused_by("Foo"), 1);
+        assert_eq!(metrics.renders_caused_by("Bar"), 0);
+
+        // Off by default
+        let mut layer: DebugLayer<TestAction> = DebugLayer::new(KeyCode::F(12));
+        layer.log_action(&TestAction::Foo);
+        layer.record_change(true);
+        layer.record_frame();
+        assert!(layer.metrics().last_render_cause().is_none());
+    }
+
+    #[test]
+    fn test_state_tree_navigation() {
+        struct Nested;
+
+        impl DebugState for Nested {
+            fn debug_sections(&self) -> Vec<DebugSection> {
+                vec![DebugSection::new("App")
+                    .entry("mode", "normal")
+                    .child(DebugSection::new("Connection").entry("host", "localhost"))]
+            }
+        }
+
+        let mut layer: DebugLayer<TestAction> = DebugLayer::new(KeyCode::F(12));
+        layer.toggle();
+        let mut press = |code: KeyCode| {
+            layer.handle_key_event(KeyEvent::from(code), Some(&Nested));
+            match &layer.freeze().overlay {
+                Some(DebugOverlay::State(table)) => {
+                    let tree = table.tree.as_ref().unwrap();
+                    (table.rows.len(), tree.breadcrumb().join("/"))
+                }
+                _ => (0, String::new()),
+            }
+        };
+
+        assert_eq!(press(KeyCode::Char('s')), (3, "App".to_string()));
+        press(KeyCode::Char('G'));
+        assert_eq!(press(KeyCode::Enter), (4, "App/Connection".to_string()));
+        press(KeyCode::Char('j'));
+        assert_eq!(press(KeyCode::Char('h')), (4, "App/Connection".to_string()));
+        assert_eq!(press(KeyCode::Char('h')), (3, "App/Connection".to_string()));
+        assert_eq!(press(KeyCode::Char('h')), (3, "App".to_string()));
+    }
+
+    #[test]
+    fn test_action_log_filter_prompt() {
+        let mut layer: DebugLayer<TestAction> = DebugLayer::new(KeyCode::F(12));
+        layer.log_action(&TestAction::Foo);
+        layer.log_action(&TestAction::Bar);
+        layer.toggle();
+        layer.handle_action(DebugAction::ToggleActionLog);
+
+        let press = |layer: &mut DebugLayer<TestAction>, code: KeyCode| {
+            layer.handle_key_event::<()>(KeyEvent::from(code), None)
+        };
+        let visible = |layer: &DebugLayer<TestAction>| match &layer.freeze().overlay {
+            Some(DebugOverlay::ActionLog(log)) => log.entries.len(),
+            _ => 0,
+        };
+
+        press(&mut layer, KeyCode::Char('/'));
+        // Typed characters go to the prompt, not to shortcuts like 'a'
+        press(&mut layer, KeyCode::Char('B'));
+        press(&mut layer, KeyCode::Char('a'));
+        assert_eq!(visible(&layer), 1);
+        press(&mut layer, KeyCode::Enter);
+        assert!(layer.is_enabled());
+
+        // The filter survives reopening the log
+        layer.handle_action(DebugAction::ToggleActionLog);
+        layer.handle_action(DebugAction::ToggleActionLog);
+        assert_eq!(visible(&layer), 1);
+
+        // Esc in the prompt clears the filter without leaving debug mode
+        press(&mut layer, KeyCode::Char('/'));
+        press(&mut layer, KeyCode::Esc);
+        assert_eq!(visible(&layer), 2);
+        assert!(layer.is_enabled());
+    }
+
+    #[test]
+    fn test_export_state_snapshot() {
+        struct Snapshot;
+
+        impl DebugState for Snapshot {
+            fn debug_sections(&self) -> Vec<DebugSection> {
+                vec![]
+            }
+
+            fn debug_snapshot(&self) -> Option<String> {
+                crate::debug::snapshot_json(&serde_json::json!({ "count": 3 }))
+            }
+        }
+
+        let path =
+            std::env::temp_dir().join(format!("tui-dispatch-state-{}.json", std::process::id()));
+        let mut layer: DebugLayer<TestAction> =
+            DebugLayer::new(KeyCode::F(12)).with_state_export_path(&path);
+
+        // State without a snapshot can't be exported
+        assert!(layer.export_state(&()).is_none());
+
+        let effect = layer.export_state(&Snapshot);
+        assert!(matches!(
+            effect,
+            Some(DebugSideEffect::StateExported { .. })
+        ));
+        let content = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert!(content.contains("\"count\": 3"));
+    }
+
+    #[test]
+    fn test_export_action_log() {
+        let path = std::env::temp_dir().join(format!(
+            "tui-dispatch-action-log-{}.jsonl",
+            std::process::id()
+        ));
+        let mut layer: DebugLayer<TestAction> =
+            DebugLayer::new(KeyCode::F(12)).with_action_log_export_path(&path);
+        layer.log_action(&TestAction::Foo);
+        layer.log_action(&TestAction::Bar);
+        layer.handle_action(DebugAction::ActionLogFilter("Bar".into()));
+
+        let effect = layer.handle_action(DebugAction::ExportActionLog);
+        let Some(DebugSideEffect::ActionLogExported { entries, .. }) = effect else {
+            panic!("expected export effect");
+        };
+        assert_eq!(entries, 1);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(content.lines().count(), 1);
+        assert!(content.contains(r#""name":"Bar""#));
+    }
 }
@@ -0,0 +1,694 @@
+//! Performance metrics for the debug overlay
+//!
+//! [`PerfMetrics`] tracks frame rate, action throughput, reducer timing per
+//! action name, and action queue depth. [`DebugLayer`](super::DebugLayer)
+//! owns one and shows it in the metrics overlay (`m` key).
+//!
+//! Reducer timing comes from [`MetricsMiddleware`]; attach it to your store:
+//!
+//! ```ignore
+//! let debug = DebugLayer::simple().active(args.debug);
+//! let store = StoreWithMiddleware::new(state, reducer, debug.metrics_middleware());
+//! ```
+//!
+//! Dispatches over a threshold are counted by
+//! [`SlowReducerMiddleware`](super::SlowReducerMiddleware) and listed under
+//! "Slow reducers".
+//!
+//! With [`DebugLayer::with_render_reasons`](super::DebugLayer::with_render_reasons),
+//! each frame is attributed to the actions whose reducers reported a change
+//! since the previous frame, listed under "Render causes".
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::table::{DebugTableBuilder, DebugTableOverlay};
+use crate::store::{Middleware, MiddlewareInfo};
+use crate::Action;
+
+/// Accumulated reducer timing for one action name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionTiming {
+    /// Action name
+    pub name: &'static str,
+    /// Number of dispatches
+    pub count: u64,
+    /// Total time spent in the reducer
+    pub total: Duration,
+}
+
+impl ActionTiming {
+    /// Average reducer time per dispatch
+    pub fn average(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.count as f64)
+        }
+    }
+}
+
+/// Dispatches of one action name that exceeded the slow reducer threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlowTiming {
+    /// Action name
+    pub name: &'static str,
+    /// Number of slow dispatches
+    pub count: u64,
+    /// Longest dispatch
+    pub max: Duration,
+}
+
+/// An action whose state change triggered a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderCause {
+    /// Action name
+    pub name: &'static str,
+    /// Action category, if the action was logged with one
+    pub category: Option<&'static str>,
+}
+
+impl RenderCause {
+    /// Label such as "SearchStart (search)"
+    pub fn label(&self) -> String {
+        match self.category {
+            Some(category) => format!("{} ({})", self.name, category),
+            None => self.name.to_string(),
+        }
+    }
+}
+
+/// Rolling performance metrics
+///
+/// Rates (frames and actions per second) are measured over a sliding
+/// window (1 second by default). Totals accumulate until [`reset`](Self::reset).
+#[derive(Debug, Clone)]
+pub struct PerfMetrics {
+    window: Duration,
+    frames: VecDeque<Instant>,
+    actions: VecDeque<Instant>,
+    reducer_total: Duration,
+    reducer_count: u64,
+    by_action: HashMap<&'static str, ActionTiming>,
+    slow: HashMap<&'static str, SlowTiming>,
+    queue_depth: usize,
+    peak_queue_depth: usize,
+    caused_frames: VecDeque<(Instant, &'static str)>,
+    renders_by_action: HashMap<&'static str, u64>,
+    last_render_cause: Option<RenderCause>,
+    middlewares: Vec<MiddlewareInfo>,
+}
+
+impl Default for PerfMetrics {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1))
+    }
+}
+
+impl PerfMetrics {
+    /// Create metrics with the given rate window
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window: window.max(Duration::from_millis(1)),
+            frames: VecDeque::new(),
+            actions: VecDeque::new(),
+            reducer_total: Duration::ZERO,
+            reducer_count: 0,
+            by_action: HashMap::new(),
+            slow: HashMap::new(),
+            queue_depth: 0,
+            peak_queue_depth: 0,
+            caused_frames: VecDeque::new(),
+            renders_by_action: HashMap::new(),
+            last_render_cause: None,
+            middlewares: Vec::new(),
+        }
+    }
+
+    /// Record a rendered frame
+    pub fn record_frame(&mut self) {
+        self.record_frame_at(Instant::now());
+    }
+
+    /// Record a rendered frame and the actions that triggered it
+    ///
+    /// The last cause becomes [`last_render_cause`](Self::last_render_cause).
+    pub fn record_frame_caused_by(&mut self, causes: &[RenderCause]) {
+        self.record_frame_caused_by_at(causes, Instant::now());
+    }
+
+    /// Record one reducer run for an action
+    pub fn record_action(&mut self, name: &'static str, elapsed: Duration) {
+        self.record_action_at(name, elapsed, Instant::now());
+    }
+
+    /// Record a dispatch that exceeded the slow reducer threshold
+    pub fn record_slow(&mut self, name: &'static str, elapsed: Duration) {
+        let timing = self.slow.entry(name).or_insert(SlowTiming {
+            name,
+            count: 0,
+            max: Duration::ZERO,
+        });
+        timing.count += 1;
+        timing.max = timing.max.max(elapsed);
+    }
+
+    /// Record the number of actions waiting in the queue
+    pub fn record_queue_depth(&mut self, depth: usize) {
+        self.queue_depth = depth;
+        self.peak_queue_depth = self.peak_queue_depth.max(depth);
+    }
+
+    /// Record the middleware attached to the store, listed in the table
+    pub fn set_middlewares(&mut self, middlewares: impl IntoIterator<Item = MiddlewareInfo>) {
+        self.middlewares = middlewares.into_iter().collect();
+    }
+
+    /// Frames rendered per second over the rate window
+    pub fn fps(&self) -> f64 {
+        self.rate_at(&self.frames, Instant::now())
+    }
+
+    /// Actions dispatched per second over the rate window
+    pub fn actions_per_sec(&self) -> f64 {
+        self.rate_at(&self.actions, Instant::now())
+    }
+
+    /// Average reducer time across all actions
+    pub fn avg_reducer_time(&self) -> Duration {
+        if self.reducer_count == 0 {
+            Duration::ZERO
+        } else {
+            self.reducer_total.div_f64(self.reducer_count as f64)
+        }
+    }
+
+    /// Total number of timed dispatches
+    pub fn dispatch_count(&self) -> u64 {
+        self.reducer_count
+    }
+
+    /// Last recorded queue depth
+    pub fn queue_depth(&self) -> usize {
+        self.queue_depth
+    }
+
+    /// Highest recorded queue depth
+    pub fn peak_queue_depth(&self) -> usize {
+        self.peak_queue_depth
+    }
+
+    /// Actions with the most total reducer time, highest first
+    pub fn top_actions(&self, n: usize) -> Vec<ActionTiming> {
+        let mut timings: Vec<_> = self.by_action.values().copied().collect();
+        timings.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(b.name)));
+        timings.truncate(n);
+        timings
+    }
+
+    /// The action behind the most recent frame with a recorded cause
+    pub fn last_render_cause(&self) -> Option<RenderCause> {
+        self.last_render_cause
+    }
+
+    /// Frames caused by an action over the rate window
+    pub fn renders_caused_by(&self, name: &str) -> usize {
+        self.renders_caused_by_at(name, Instant::now())
+    }
+
+    /// Actions that triggered the most frames, highest first
+    pub fn top_render_causes(&self, n: usize) -> Vec<(&'static str, u64)> {
+        let mut causes: Vec<_> = self
+            .renders_by_action
+            .iter()
+            .map(|(name, count)| (*name, *count))
+            .collect();
+        causes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        causes.truncate(n);
+        causes
+    }
+
+    /// Summary such as "SearchStart (search), 12 renders in last 1.0s"
+    pub fn render_cause_summary(&self) -> Option<String> {
+        let cause = self.last_render_cause?;
+        let count = self.renders_caused_by(cause.name);
+        Some(format!(
+            "{}, {} {} in last {:.1}s",
+            cause.label(),
+            count,
+            if count == 1 { "render" } else { "renders" },
+            self.window.as_secs_f64()
+        ))
+    }
+
+    /// Actions with slow dispatches, most frequent first
+    pub fn slow_reducers(&self) -> Vec<SlowTiming> {
+        let mut timings: Vec<_> = self.slow.values().copied().collect();
+        timings.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(b.name)));
+        timings
+    }
+
+    /// Middleware recorded with [`set_middlewares`](Self::set_middlewares)
+    pub fn middlewares(&self) -> &[MiddlewareInfo] {
+        &self.middlewares
+    }
+
+    /// Clear all recorded metrics
+    ///
+    /// The recorded middleware list is kept.
+    pub fn reset(&mut self) {
+        let middlewares = std::mem::take(&mut self.middlewares);
+        *self = Self::new(self.window);
+        self.middlewares = middlewares;
+    }
+
+    /// Build a debug table with the current metrics
+    pub fn build_table(&self, top_n: usize) -> DebugTableOverlay {
+        let mut builder = DebugTableBuilder::new();
+
+        builder.push_section("Throughput");
+        builder.push_entry("frames/s", format!("{:.1}", self.fps()));
+        builder.push_entry("actions/s", format!("{:.1}", self.actions_per_sec()));
+
+        builder.push_section("Reducer");
+        if self.reducer_count == 0 {
+            builder.push_entry(
+                "hint",
+                "Attach DebugLayer::metrics_middleware() to the store to time reducers",
+            );
+        } else {
+            builder.push_entry("dispatches", self.reducer_count.to_string());
+            builder.push_entry("avg time", format_duration(self.avg_reducer_time()));
+        }
+
+        builder.push_section("Queue");
+        builder.push_entry("depth", self.queue_depth.to_string());
+        builder.push_entry("peak", self.peak_queue_depth.to_string());
+
+        if !self.middlewares.is_empty() {
+            builder.push_section("Middleware (in order)");
+            for info in &self.middlewares {
+                builder.push_entry(info.name, if info.enabled { "on" } else { "off" });
+            }
+        }
+
+        let top = self.top_actions(top_n);
+        if !top.is_empty() {
+            builder.push_section(format!("Top {} actions (total time)", top.len()));
+            for timing in top {
+                builder.push_entry(
+                    timing.name,
+                    format!(
+                        "{} total, {}x, avg {}",
+                        format_duration(timing.total),
+                        timing.count,
+                        format_duration(timing.average())
+                    ),
+                );
+            }
+        }
+
+        if let Some(summary) = self.render_cause_summary() {
+            builder.push_section("Render causes");
+            builder.push_entry("last", summary);
+            for (name, count) in self.top_render_causes(top_n) {
+                builder.push_entry(name, format!("{}x", count));
+            }
+        }
+
+        let slow = self.slow_reducers();
+        if !slow.is_empty() {
+            builder.push_section("Slow reducers");
+            for timing in slow {
+                builder.push_entry(
+                    timing.name,
+                    format!("{}x, max {}", timing.count, format_duration(timing.max)),
+                );
+            }
+        }
+
+        builder.finish("Performance Metrics")
+    }
+
+    fn record_frame_at(&mut self, now: Instant) {
+        self.frames.push_back(now);
+        Self::prune(&mut self.frames, self.window, now);
+    }
+
+    fn record_frame_caused_by_at(&mut self, causes: &[RenderCause], now: Instant) {
+        self.record_frame_at(now);
+        for cause in causes {
+            self.caused_frames.push_back((now, cause.name));
+            *self.renders_by_action.entry(cause.name).or_insert(0) += 1;
+        }
+        Self::prune_caused(&mut self.caused_frames, self.window, now);
+        if let Some(cause) = causes.last() {
+            self.last_render_cause = Some(*cause);
+        }
+    }
+
+    fn renders_caused_by_at(&self, name: &str, now: Instant) -> usize {
+        self.caused_frames
+            .iter()
+            .filter(|(at, cause)| {
+                *cause == name && now.saturating_duration_since(*at) <= self.window
+            })
+            .count()
+    }
+
+    fn record_action_at(&mut self, name: &'static str, elapsed: Duration, now: Instant) {
+        self.actions.push_back(now);
+        Self::prune(&mut self.actions, self.window, now);
+
+        self.reducer_total += elapsed;
+        self.reducer_count += 1;
+        let timing = self.by_action.entry(name).or_insert(ActionTiming {
+            name,
+            count: 0,
+            total: Duration::ZERO,
+        });
+        timing.count += 1;
+        timing.total += elapsed;
+    }
+
+    fn rate_at(&self, events: &VecDeque<Instant>, now: Instant) -> f64 {
+        let recent = events
+            .iter()
+            .filter(|at| now.saturating_duration_since(**at) <= self.window)
+            .count();
+        recent as f64 / self.window.as_secs_f64()
+    }
+
+    fn prune(events: &mut VecDeque<Instant>, window: Duration, now: Instant) {
+        while events
+            .front()
+            .is_some_and(|at| now.saturating_duration_since(*at) > window)
+        {
+            events.pop_front();
+        }
+    }
+
+    fn prune_caused(
+        events: &mut VecDeque<(Instant, &'static str)>,
+        window: Duration,
+        now: Instant,
+    ) {
+        while events
+            .front()
+            .is_some_and(|(at, _)| now.saturating_duration_since(*at) > window)
+        {
+            events.pop_front();
+        }
+    }
+}
+
+/// Middleware that times reducer runs into shared [`PerfMetrics`]
+///
+/// Create one with [`DebugLayer::metrics_middleware`](super::DebugLayer::metrics_middleware).
+/// Batches are timed as a whole and split evenly across their actions.
+#[derive(Debug, Clone)]
+pub struct MetricsMiddleware {
+    metrics: Arc<Mutex<PerfMetrics>>,
+    started: Option<Instant>,
+    active: bool,
+}
+
+impl MetricsMiddleware {
+    /// Create middleware that records into `metrics`
+    pub fn new(metrics: Arc<Mutex<PerfMetrics>>) -> Self {
+        Self {
+            metrics,
+            started: None,
+            active: true,
+        }
+    }
+
+    /// Set whether the middleware is active.
+    ///
+    /// When inactive (`false`), all methods become no-ops.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Get the shared metrics
+    pub fn metrics(&self) -> &Arc<Mutex<PerfMetrics>> {
+        &self.metrics
+    }
+}
+
+impl<A: Action> Middleware<A> for MetricsMiddleware {
+    fn before(&mut self, _action: &A) {
+        if self.active {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    fn after(&mut self, action: &A, _state_changed: bool) {
+        if let Some(started) = self.started.take() {
+            let elapsed = started.elapsed();
+            if let Ok(mut metrics) = self.metrics.lock() {
+                metrics.record_action(action.name(), elapsed);
+            }
+        }
+    }
+
+    fn before_batch(&mut self, _actions: &[A]) {
+        if self.active {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    fn after_batch(&mut self, actions: &[A], _changed: &[bool]) {
+        let Some(started) = self.started.take() else {
+            return;
+        };
+        if actions.is_empty() {
+            return;
+        }
+        let share = started.elapsed().div_f64(actions.len() as f64);
+        if let Ok(mut metrics) = self.metrics.lock() {
+            for action in actions {
+                metrics.record_action(action.name(), share);
+            }
+        }
+    }
+}
+
+pub(super) fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.0}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    } else {
+        format!("{:.2}s", micros / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debug::DebugTableRow;
+    use crate::store::StoreWithMiddleware;
+
+    #[derive(Clone, Debug)]
+    enum TestAction {
+        Inc,
+        Dec,
+    }
+
+    impl Action for TestAction {
+        fn name(&self) -> &'static str {
+            match self {
+                TestAction::Inc => "Inc",
+                TestAction::Dec => "Dec",
+            }
+        }
+    }
+
+    fn reducer(state: &mut i32, action: TestAction) -> bool {
+        match action {
+            TestAction::Inc => *state += 1,
+            TestAction::Dec => *state -= 1,
+        }
+        true
+    }
+
+    #[test]
+    fn test_rates_use_window() {
+        let mut metrics = PerfMetrics::new(Duration::from_secs(1));
+        let start = Instant::now();
+
+        for i in 0..30 {
+            metrics.record_frame_at(start + Duration::from_millis(i * 50));
+        }
+
+        let now = start + Duration::from_millis(1450);
+        assert_eq!(metrics.rate_at(&metrics.frames, now), 21.0);
+        assert!(metrics.frames.len() <= 21);
+    }
+
+    #[test]
+    fn test_top_actions_by_total_time() {
+        let mut metrics = PerfMetrics::default();
+        metrics.record_action("Fast", Duration::from_micros(10));
+        metrics.record_action("Fast", Duration::from_micros(10));
+        metrics.record_action("Slow", Duration::from_millis(5));
+        metrics.record_action("Mid", Duration::from_millis(1));
+
+        let top = metrics.top_actions(2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].name, "Slow");
+        assert_eq!(top[1].name, "Mid");
+
+        let all = metrics.top_actions(10);
+        let fast = all.iter().find(|t| t.name == "Fast").unwrap();
+        assert_eq!(fast.count, 2);
+        assert_eq!(fast.average(), Duration::from_micros(10));
+        assert_eq!(metrics.dispatch_count(), 4);
+    }
+
+    #[test]
+    fn test_queue_depth_peak() {
+        let mut metrics = PerfMetrics::default();
+        metrics.record_queue_depth(3);
+        metrics.record_queue_depth(1);
+
+        assert_eq!(metrics.queue_depth(), 1);
+        assert_eq!(metrics.peak_queue_depth(), 3);
+
+        metrics.reset();
+        assert_eq!(metrics.peak_queue_depth(), 0);
+    }
+
+    #[test]
+    fn test_middleware_records_dispatches() {
+        let metrics = Arc::new(Mutex::new(PerfMetrics::default()));
+        let mut store =
+            StoreWithMiddleware::new(0, reducer, MetricsMiddleware::new(metrics.clone()));
+
+        store.dispatch(TestAction::Inc);
+        store.dispatch(TestAction::Inc);
+        store.dispatch_all([TestAction::Dec, TestAction::Inc]);
+
+        let metrics = metrics.lock().unwrap();
+        assert_eq!(metrics.dispatch_count(), 4);
+        let top = metrics.top_actions(10);
+        assert_eq!(top.iter().find(|t| t.name == "Inc").unwrap().count, 3);
+        assert_eq!(top.iter().find(|t| t.name == "Dec").unwrap().count, 1);
+    }
+
+    #[test]
+    fn test_inactive_middleware_is_noop() {
+        let metrics = Arc::new(Mutex::new(PerfMetrics::default()));
+        let middleware = MetricsMiddleware::new(metrics.clone()).active(false);
+        let mut store = StoreWithMiddleware::new(0, reducer, middleware);
+
+        store.dispatch(TestAction::Inc);
+
+        assert_eq!(metrics.lock().unwrap().dispatch_count(), 0);
+    }
+
+    #[test]
+    fn test_build_table() {
+        let mut metrics = PerfMetrics::default();
+        let table = metrics.build_table(5);
+        assert!(table.rows.iter().any(|row| matches!(
+            row,
+            DebugTableRow::Entry { key, .. } if key == "hint"
+        )));
+
+        metrics.record_action("Inc", Duration::from_micros(250));
+        let table = metrics.build_table(5);
+        assert_eq!(table.title, "Performance Metrics");
+        assert!(table.rows.iter().any(|row| matches!(
+            row,
+            DebugTableRow::Entry { key, value } if key == "Inc" && value.contains("250µs")
+        )));
+    }
+
+    #[test]
+    fn test_slow_reducers_in_table() {
+        let mut metrics = PerfMetrics::default();
+        metrics.record_slow("Load", Duration::from_millis(20));
+        metrics.record_slow("Sort", Duration::from_millis(5));
+        metrics.record_slow("Sort", Duration::from_millis(8));
+
+        let slow = metrics.slow_reducers();
+        assert_eq!(slow[0].name, "Sort");
+        assert_eq!(slow[0].count, 2);
+        assert_eq!(slow[0].max, Duration::from_millis(8));
+
+        let table = metrics.build_table(5);
+        assert!(table.rows.iter().any(|row| matches!(
+            row,
+            DebugTableRow::Entry { key, value } if key == "Load" && value == "1x, max 20.00ms"
+        )));
+    }
+
+    #[test]
+    fn test_render_causes() {
+        let mut metrics = PerfMetrics::new(Duration::from_secs(1));
+        let start = Instant::now();
+        let tick = RenderCause {
+            name: "Tick",
+            category: None,
+        };
+        let search = RenderCause {
+            name: "SearchStart",
+            category: Some("search"),
+        };
+
+        for i in 0..4 {
+            metrics.record_frame_caused_by_at(&[tick], start + Duration::from_millis(i * 400));
+        }
+        metrics.record_frame_caused_by_at(&[tick, search], start + Duration::from_millis(1700));
+
+        let now = start + Duration::from_millis(1700);
+        assert_eq!(metrics.renders_caused_by_at("Tick", now), 3);
+        assert_eq!(metrics.last_render_cause(), Some(search));
+        assert_eq!(search.label(), "SearchStart (search)");
+        assert_eq!(
+            metrics.top_render_causes(5),
+            vec![("Tick", 5), ("SearchStart", 1)]
+        );
+
+        let table = metrics.build_table(5);
+        assert!(table.rows.iter().any(|row| matches!(
+            row,
+            DebugTableRow::Entry { key, value } if key == "last" && value.starts_with("SearchStart (search), ")
+        )));
+    }
+
+    #[test]
+    fn test_middlewares_in_table() {
+        let mut metrics = PerfMetrics::default();
+        metrics.set_middlewares([
+            MiddlewareInfo {
+                name: "metrics",
+                enabled: true,
+            },
+            MiddlewareInfo {
+                name: "log",
+                enabled: false,
+            },
+        ]);
+        metrics.reset();
+        assert_eq!(metrics.middlewares().len(), 2);
+
+        let table = metrics.build_table(5);
+        assert!(table.rows.iter().any(|row| matches!(
+            row,
+            DebugTableRow::Entry { key, value } if key == "log" && value == "off"
+        )));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(12)), "12µs");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+    }
+}
@@ -7,6 +7,8 @@
 //! - **Frame Freeze**: Capture and inspect UI state
 //! - **Cell Inspection**: Examine individual buffer cells
 //! - **Debug Widgets**: Render debug overlays and tables
+//! - **Remote Inspection**: Serve the action log and state over a local socket
+//!   (`debug-remote` feature)
 //!
 //! # Quick Start (Recommended)
 //!
@@ -24,8 +26,11 @@
 //! // Built-in keybindings (when debug mode is active):
 //! // - Toggle key (e.g., F12): Toggle debug mode
 //! // - S: Show/hide state overlay
+//! // - M: Show/hide performance metrics
 //! // - B: Toggle debug banner position
 //! // - J/K, arrows, PgUp/PgDn, g/G: Scroll overlays
+//! // - Enter/L, H: Expand, collapse state sections
+//! // - C: Copy the selected state value
 //! // - Y: Copy frozen frame to clipboard
 //! // - I: Toggle mouse capture for cell inspection
 //! ```
@@ -69,7 +74,8 @@
 //!         vec![
 //!             DebugSection::new("Connection")
 //!                 .entry("host", &self.host)
-//!                 .entry("status", format!("{:?}", self.status)),
+//!                 .entry("status", format!("{:?}", self.status))
+//!                 .child(DebugSection::new("Pool").entry("idle", self.idle.to_string())),
 //!         ]
 //!     }
 //! }
@@ -118,9 +124,17 @@ pub mod action_logger;
 pub mod actions;
 pub mod cell;
 pub mod config;
+pub mod diff;
+pub mod export;
 pub mod layer;
+pub mod metrics;
+#[cfg(feature = "debug-remote")]
+pub mod remote;
+pub mod slow;
 pub mod state;
+pub mod strict;
 pub mod table;
+pub mod tree;
 pub mod widgets;
 
 // Re-export commonly used types
@@ -131,12 +145,26 @@ pub use config::{
     default_debug_keybindings, default_debug_keybindings_with_toggle, DebugConfig, DebugStyle,
     KeyStyles, ScrollbarStyle, StatusItem,
 };
+pub use diff::{diff_sections, StateChangeKind, StateDiffEntry};
+pub use export::{
+    buffer_to_ansi, buffer_to_html, buffer_to_svg, export_buffer, FrameExport, FrameFormat,
+};
 pub use layer::{BannerPosition, DebugLayer, DebugOutcome};
-pub use state::{DebugEntry, DebugSection, DebugState, DebugWrapper};
+pub use metrics::{ActionTiming, MetricsMiddleware, PerfMetrics, RenderCause, SlowTiming};
+#[cfg(feature = "debug-remote")]
+pub use remote::{RemoteAddr, RemoteInspector};
+pub use slow::{SlowDispatch, SlowReducerMiddleware};
+pub use state::{
+    debug_list, debug_map, snapshot_json, DebugEntry, DebugSection, DebugState, DebugWrapper,
+};
+pub use strict::{
+    ActionOutcomes, StrictChecks, StrictMiddleware, StrictWarning, DEFAULT_STRICT_THRESHOLD,
+};
+pub use tree::StateTree;
 
 // Action logging
 pub use action_logger::{
-    glob_match, ActionLog, ActionLogConfig, ActionLogEntry, ActionLoggerConfig,
+    glob_match, matches_filter, ActionLog, ActionLogConfig, ActionLogEntry, ActionLoggerConfig,
     ActionLoggerMiddleware,
 };
 
@@ -145,8 +173,8 @@ pub use cell::{
     format_color_compact, format_modifier_compact, inspect_cell, point_in_rect, CellPreview,
 };
 pub use table::{
-    ActionLogDisplayEntry, ActionLogOverlay, DebugOverlay, DebugTableBuilder, DebugTableOverlay,
-    DebugTableRow,
+    ActionDetailOverlay, ActionLogDisplayEntry, ActionLogOverlay, DebugOverlay, DebugTableBuilder,
+    DebugTableOverlay, DebugTableRow,
 };
 pub use widgets::{
     buffer_to_text, dim_buffer, paint_snapshot, ActionLogStyle, ActionLogWidget, BannerItem,
@@ -0,0 +1,446 @@
+//! Remote inspection of the debug layer over a local socket
+//!
+//! [`RemoteInspector`] serves the action log and the latest state snapshot
+//! to clients on a TCP port or Unix socket, so a second terminal can follow
+//! the app without covering its UI. Attach it with
+//! [`DebugLayer::with_remote_inspector`](super::DebugLayer::with_remote_inspector);
+//! the layer then forwards every logged action and recorded state.
+//!
+//! # Protocol
+//!
+//! Newline-delimited JSON. Each request line gets exactly one response line.
+//! A request is either an object like `{"cmd": "actions", "since": 42}` or
+//! just the command name (handy with `nc`):
+//!
+//! - `ping` - `{"ok": true}`
+//! - `actions` - `{"ok": true, "actions": [{"sequence", "name", "params", "elapsed"}]}`,
+//!   oldest first; `since` skips actions with a lower sequence number
+//! - `state` - `{"ok": true, "state": [{"title", "entries": [{"key", "value"}], "sections": [..]}]}`
+//!
+//! Anything else answers `{"ok": false, "error": "..."}`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use tui_dispatch::debug::{DebugLayer, RemoteInspector};
+//!
+//! let inspector = RemoteInspector::bind_tcp("127.0.0.1:7878")?;
+//! let debug = DebugLayer::simple()
+//!     .active(args.debug)
+//!     .with_remote_inspector(inspector);
+//!
+//! // In another terminal:
+//! // $ echo actions | nc 127.0.0.1 7878
+//! ```
+
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, ErrorKind, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use super::action_logger::ActionLogEntry;
+use super::state::DebugSection;
+
+/// Number of actions kept for clients by default
+const DEFAULT_CAPACITY: usize = 500;
+
+/// How often idle server threads check for shutdown
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// An action as served to remote clients
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RemoteAction {
+    sequence: u64,
+    name: &'static str,
+    params: String,
+    elapsed: String,
+}
+
+/// Data shared between the debug layer and the server threads
+#[derive(Debug, Default)]
+struct Snapshot {
+    actions: VecDeque<RemoteAction>,
+    capacity: usize,
+    state: Vec<DebugSection>,
+}
+
+/// Where a [`RemoteInspector`] listens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteAddr {
+    /// A TCP address, e.g. `127.0.0.1:7878`
+    Tcp(SocketAddr),
+    /// A Unix domain socket path
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+/// Serves the debug layer's action log and state over a local socket
+///
+/// Binding spawns a background thread that accepts clients, each served on
+/// its own thread. Dropping the inspector stops the threads (within
+/// ~50ms) and removes the Unix socket file.
+pub struct RemoteInspector {
+    snapshot: Arc<Mutex<Snapshot>>,
+    stop: Arc<AtomicBool>,
+    addr: RemoteAddr,
+}
+
+impl std::fmt::Debug for RemoteInspector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteInspector")
+            .field("addr", &self.addr)
+            .finish()
+    }
+}
+
+impl RemoteInspector {
+    /// Listen on a TCP address
+    ///
+    /// Use a loopback address - the protocol has no authentication. Port 0
+    /// picks a free port; see [`addr`](Self::addr).
+    pub fn bind_tcp(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = RemoteAddr::Tcp(listener.local_addr()?);
+        Self::start(Listener::Tcp(listener), addr)
+    }
+
+    /// Listen on a Unix domain socket, replacing a stale socket file
+    ///
+    /// Fails with [`ErrorKind::AlreadyExists`] if something other than a
+    /// socket is at `path`; such files are never removed.
+    #[cfg(unix)]
+    pub fn bind_unix(path: impl AsRef<Path>) -> io::Result<Self> {
+        use std::os::unix::fs::FileTypeExt;
+
+        let path = path.as_ref().to_path_buf();
+        match std::fs::symlink_metadata(&path) {
+            Ok(meta) if meta.file_type().is_socket() => std::fs::remove_file(&path)?,
+            Ok(_) => {
+                return Err(io::Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!("{} exists and is not a socket", path.display()),
+                ))
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        let listener = UnixListener::bind(&path)?;
+        Self::start(Listener::Unix(listener), RemoteAddr::Unix(path))
+    }
+
+    fn start(listener: Listener, addr: RemoteAddr) -> io::Result<Self> {
+        listener.set_nonblocking(true)?;
+        let snapshot = Arc::new(Mutex::new(Snapshot {
+            capacity: DEFAULT_CAPACITY,
+            ..Snapshot::default()
+        }));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let server_snapshot = snapshot.clone();
+        let server_stop = stop.clone();
+        thread::Builder::new()
+            .name("debug-remote".into())
+            .spawn(move || accept_loop(listener, server_snapshot, server_stop))?;
+
+        Ok(Self {
+            snapshot,
+            stop,
+            addr,
+        })
+    }
+
+    /// Set how many recent actions are kept for clients (default: 500)
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        if let Ok(mut snapshot) = self.snapshot.lock() {
+            snapshot.capacity = capacity.max(1);
+            while snapshot.actions.len() > snapshot.capacity {
+                snapshot.actions.pop_front();
+            }
+        }
+        self
+    }
+
+    /// The address the inspector is listening on
+    pub fn addr(&self) -> &RemoteAddr {
+        &self.addr
+    }
+
+    /// Publish a logged action
+    pub fn push_action(&self, entry: &ActionLogEntry) {
+        if let Ok(mut snapshot) = self.snapshot.lock() {
+            if snapshot.actions.len() >= snapshot.capacity {
+                snapshot.actions.pop_front();
+            }
+            snapshot.actions.push_back(RemoteAction {
+                sequence: entry.sequence,
+                name: entry.name,
+                params: entry.params().to_string(),
+                elapsed: entry.elapsed(),
+            });
+        }
+    }
+
+    /// Publish the current state sections
+    pub fn set_state(&self, sections: Vec<DebugSection>) {
+        if let Ok(mut snapshot) = self.snapshot.lock() {
+            snapshot.state = sections;
+        }
+    }
+}
+
+impl Drop for RemoteInspector {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        #[cfg(unix)]
+        if let RemoteAddr::Unix(path) = &self.addr {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+impl Listener {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        match self {
+            Listener::Tcp(listener) => listener.set_nonblocking(nonblocking),
+            #[cfg(unix)]
+            Listener::Unix(listener) => listener.set_nonblocking(nonblocking),
+        }
+    }
+
+    /// Accept a client and spawn a thread serving it
+    fn accept(&self, snapshot: &Arc<Mutex<Snapshot>>, stop: &Arc<AtomicBool>) -> io::Result<()> {
+        let (snapshot, stop) = (snapshot.clone(), stop.clone());
+        match self {
+            Listener::Tcp(listener) => {
+                let (stream, _) = listener.accept()?;
+                let reader = prepare_tcp(&stream)?;
+                thread::spawn(move || serve_client(reader, stream, snapshot, stop));
+            }
+            #[cfg(unix)]
+            Listener::Unix(listener) => {
+                let (stream, _) = listener.accept()?;
+                let reader = prepare_unix(&stream)?;
+                thread::spawn(move || serve_client(reader, stream, snapshot, stop));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn prepare_tcp(stream: &TcpStream) -> io::Result<TcpStream> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(POLL_INTERVAL))?;
+    stream.try_clone()
+}
+
+#[cfg(unix)]
+fn prepare_unix(stream: &UnixStream) -> io::Result<UnixStream> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(POLL_INTERVAL))?;
+    stream.try_clone()
+}
+
+fn accept_loop(listener: Listener, snapshot: Arc<Mutex<Snapshot>>, stop: Arc<AtomicBool>) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept(&snapshot, &stop) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                tracing::warn!(error = %e, "Debug remote inspector failed to accept a client");
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn serve_client<R: io::Read, W: Write>(
+    reader: R,
+    mut writer: W,
+    snapshot: Arc<Mutex<Snapshot>>,
+    stop: Arc<AtomicBool>,
+) {
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+    while !stop.load(Ordering::Relaxed) {
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {
+                let response = match snapshot.lock() {
+                    Ok(snapshot) => respond(line.trim(), &snapshot),
+                    Err(_) => error("inspector state unavailable"),
+                };
+                line.clear();
+                if writeln!(writer, "{response}").is_err() {
+                    break;
+                }
+            }
+            // Timeouts keep any partial line in `line` and re-check `stop`
+            Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(_) => break,
+        }
+    }
+}
+
+/// Answer one request line
+fn respond(request: &str, snapshot: &Snapshot) -> Value {
+    let (cmd, since) = match serde_json::from_str::<Value>(request) {
+        Ok(Value::Object(fields)) => (
+            fields
+                .get("cmd")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            fields.get("since").and_then(Value::as_u64).unwrap_or(0),
+        ),
+        _ => (request.to_string(), 0),
+    };
+
+    match cmd.as_str() {
+        "ping" => json!({ "ok": true }),
+        "actions" => {
+            let actions: Vec<Value> = snapshot
+                .actions
+                .iter()
+                .filter(|action| action.sequence >= since)
+                .map(|action| {
+                    json!({
+                        "sequence": action.sequence,
+                        "name": action.name,
+                        "params": action.params,
+                        "elapsed": action.elapsed,
+                    })
+                })
+                .collect();
+            json!({ "ok": true, "actions": actions })
+        }
+        "state" => {
+            let sections: Vec<Value> = snapshot.state.iter().map(section_json).collect();
+            json!({ "ok": true, "state": sections })
+        }
+        "" => error("missing command"),
+        other => error(&format!("unknown command \"{other}\"")),
+    }
+}
+
+fn section_json(section: &DebugSection) -> Value {
+    let entries: Vec<Value> = section
+        .entries
+        .iter()
+        .map(|entry| json!({ "key": entry.key, "value": entry.value }))
+        .collect();
+    let sections: Vec<Value> = section.children.iter().map(section_json).collect();
+    json!({ "title": section.title, "entries": entries, "sections": sections })
+}
+
+fn error(message: &str) -> Value {
+    json!({ "ok": false, "error": message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(stream: &mut TcpStream, line: &str) -> Value {
+        writeln!(stream, "{line}").unwrap();
+        let mut response = String::new();
+        BufReader::new(stream.try_clone().unwrap())
+            .read_line(&mut response)
+            .unwrap();
+        serde_json::from_str(&response).unwrap()
+    }
+
+    #[test]
+    fn test_respond() {
+        let snapshot = Snapshot {
+            actions: VecDeque::from([
+                RemoteAction {
+                    sequence: 0,
+                    name: "Connect",
+                    params: "\"db\"".into(),
+                    elapsed: "0ms".into(),
+                },
+                RemoteAction {
+                    sequence: 1,
+                    name: "Tick",
+                    params: String::new(),
+                    elapsed: "16ms".into(),
+                },
+            ]),
+            capacity: 10,
+            state: vec![DebugSection::new("Connection").entry("host", "localhost")],
+        };
+
+        assert_eq!(respond("ping", &snapshot), json!({ "ok": true }));
+        let response = respond(r#"{"cmd": "actions", "since": 1}"#, &snapshot);
+        assert_eq!(response["actions"].as_array().unwrap().len(), 1);
+        assert_eq!(response["actions"][0]["name"], "Tick");
+        assert_eq!(
+            respond("state", &snapshot)["state"][0]["entries"][0]["value"],
+            "localhost"
+        );
+        assert_eq!(respond("nope", &snapshot)["ok"], false);
+    }
+
+    #[test]
+    fn test_serves_tcp_clients() {
+        let inspector = RemoteInspector::bind_tcp("127.0.0.1:0").unwrap();
+        let RemoteAddr::Tcp(addr) = inspector.addr().clone() else {
+            panic!("expected a TCP address");
+        };
+
+        for sequence in 0..3 {
+            inspector.push_action(&ActionLogEntry::new("Tick", String::new(), sequence));
+        }
+        inspector.set_state(vec![DebugSection::new("App").entry("count", "3")]);
+        let inspector = inspector.with_capacity(2);
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let actions = request(&mut stream, "actions");
+        let sequences: Vec<u64> = actions["actions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|action| action["sequence"].as_u64().unwrap())
+            .collect();
+        assert_eq!(sequences, vec![1, 2]);
+
+        let state = request(&mut stream, r#"{"cmd": "state"}"#);
+        assert_eq!(state["state"][0]["title"], "App");
+
+        drop(inspector);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_bind_unix_keeps_regular_files() {
+        let path =
+            std::env::temp_dir().join(format!("tui-dispatch-remote-{}.sock", std::process::id()));
+        std::fs::write(&path, "not a socket").unwrap();
+
+        let err = RemoteInspector::bind_unix(&path).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a socket");
+        std::fs::remove_file(&path).unwrap();
+
+        // A stale socket left behind is replaced
+        drop(UnixListener::bind(&path).unwrap());
+        let inspector = RemoteInspector::bind_unix(&path).unwrap();
+        drop(inspector);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,282 @@
+//! Warnings for reducers that take too long
+//!
+//! Reducers run on the event loop, so a slow one delays input handling and
+//! rendering. [`SlowReducerMiddleware`] times each dispatch and reports the
+//! ones over a threshold, with the action's name and params:
+//!
+//! ```ignore
+//! let debug = DebugLayer::simple().active(args.debug);
+//! let slow = debug.slow_reducer_middleware(Duration::from_millis(4));
+//! let store = StoreWithMiddleware::new(state, reducer, slow);
+//! ```
+//!
+//! Reports go to `tracing` and, when created through the
+//! [`DebugLayer`](super::DebugLayer), into the "Slow reducers" section of
+//! the metrics overlay. [`dispatch_on_slow`](SlowReducerMiddleware::dispatch_on_slow)
+//! also sends them back to the app as an action.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc;
+
+use super::metrics::{format_duration, PerfMetrics};
+use crate::store::Middleware;
+use crate::ActionParams;
+
+/// A dispatch that took longer than the threshold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlowDispatch {
+    /// Action name
+    pub name: &'static str,
+    /// Action parameters
+    pub params: String,
+    /// Time spent in the reducer (and inner middleware)
+    pub elapsed: Duration,
+    /// Threshold that was exceeded
+    pub threshold: Duration,
+}
+
+impl std::fmt::Display for SlowDispatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.params.is_empty() {
+            write!(f, " {}", self.params)?;
+        }
+        write!(
+            f,
+            " took {} (threshold {})",
+            format_duration(self.elapsed),
+            format_duration(self.threshold)
+        )
+    }
+}
+
+/// Middleware that reports dispatches slower than a threshold
+///
+/// Create one with
+/// [`DebugLayer::slow_reducer_middleware`](super::DebugLayer::slow_reducer_middleware)
+/// to see stats in the metrics overlay, or with [`new`](Self::new) for
+/// `tracing` only. Batches are timed as a whole and split evenly across
+/// their actions.
+pub struct SlowReducerMiddleware<A> {
+    threshold: Duration,
+    metrics: Option<Arc<Mutex<PerfMetrics>>>,
+    on_slow: Option<Box<dyn Fn(&SlowDispatch) -> A>>,
+    started: Option<Instant>,
+    pending: Vec<SlowDispatch>,
+    active: bool,
+}
+
+impl<A> std::fmt::Debug for SlowReducerMiddleware<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlowReducerMiddleware")
+            .field("threshold", &self.threshold)
+            .field("metrics", &self.metrics.is_some())
+            .field("dispatch_on_slow", &self.on_slow.is_some())
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+impl<A> SlowReducerMiddleware<A> {
+    /// Report dispatches that take longer than `threshold`
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            metrics: None,
+            on_slow: None,
+            started: None,
+            pending: Vec::new(),
+            active: true,
+        }
+    }
+
+    /// Count slow dispatches in `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<Mutex<PerfMetrics>>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Dispatch the action built by `f` after each slow dispatch
+    ///
+    /// Needs the store's action sender (see
+    /// [`StoreWithMiddleware::set_action_sender`](crate::StoreWithMiddleware::set_action_sender));
+    /// the runtimes set it for you. The diagnostic action is timed like any other.
+    pub fn dispatch_on_slow(mut self, f: impl Fn(&SlowDispatch) -> A + 'static) -> Self {
+        self.on_slow = Some(Box::new(f));
+        self
+    }
+
+    /// Set whether the middleware is active.
+    ///
+    /// When inactive (`false`), all methods become no-ops.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// The configured threshold
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    fn start(&mut self) {
+        self.pending.clear();
+        if self.active {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    fn check(&mut self, action: &A, elapsed: Duration)
+    where
+        A: ActionParams,
+    {
+        if elapsed <= self.threshold {
+            return;
+        }
+        let report = SlowDispatch {
+            name: action.name(),
+            params: action.params(),
+            elapsed,
+            threshold: self.threshold,
+        };
+        tracing::warn!(
+            action = %report.name,
+            elapsed_us = elapsed.as_micros() as u64,
+            "{}",
+            report
+        );
+        if let Some(metrics) = &self.metrics {
+            if let Ok(mut metrics) = metrics.lock() {
+                metrics.record_slow(report.name, elapsed);
+            }
+        }
+        if self.on_slow.is_some() {
+            self.pending.push(report);
+        }
+    }
+}
+
+impl<A: ActionParams> Middleware<A> for SlowReducerMiddleware<A> {
+    fn before(&mut self, _action: &A) {
+        self.start();
+    }
+
+    fn after(&mut self, action: &A, _state_changed: bool) {
+        if let Some(started) = self.started.take() {
+            self.check(action, started.elapsed());
+        }
+    }
+
+    fn follow_up(
+        &mut self,
+        _action: &A,
+        _state_changed: bool,
+        action_tx: &mpsc::UnboundedSender<A>,
+    ) {
+        if let Some(on_slow) = &self.on_slow {
+            for report in self.pending.drain(..) {
+                let _ = action_tx.send(on_slow(&report));
+            }
+        }
+    }
+
+    fn before_batch(&mut self, _actions: &[A]) {
+        self.start();
+    }
+
+    fn after_batch(&mut self, actions: &[A], _changed: &[bool]) {
+        let Some(started) = self.started.take() else {
+            return;
+        };
+        if actions.is_empty() {
+            return;
+        }
+        let share = started.elapsed().div_f64(actions.len() as f64);
+        for action in actions {
+            self.check(action, share);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::StoreWithMiddleware;
+    use crate::Action;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum TestAction {
+        Inc,
+        Sleep(u64),
+        Slow(String),
+    }
+
+    impl Action for TestAction {
+        fn name(&self) -> &'static str {
+            match self {
+                TestAction::Inc => "Inc",
+                TestAction::Sleep(_) => "Sleep",
+                TestAction::Slow(_) => "Slow",
+            }
+        }
+    }
+
+    impl ActionParams for TestAction {
+        fn params(&self) -> String {
+            match self {
+                TestAction::Sleep(millis) => millis.to_string(),
+                _ => String::new(),
+            }
+        }
+    }
+
+    fn reducer(state: &mut i32, action: TestAction) -> bool {
+        match action {
+            TestAction::Inc => *state += 1,
+            TestAction::Sleep(millis) => std::thread::sleep(Duration::from_millis(millis)),
+            TestAction::Slow(_) => {}
+        }
+        true
+    }
+
+    #[test]
+    fn test_reports_slow_dispatches() {
+        let metrics = Arc::new(Mutex::new(PerfMetrics::default()));
+        let middleware = SlowReducerMiddleware::new(Duration::from_millis(5))
+            .with_metrics(metrics.clone())
+            .dispatch_on_slow(|report| TestAction::Slow(report.to_string()));
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut store = StoreWithMiddleware::new(0, reducer, middleware);
+        store.set_action_sender(tx);
+
+        store.dispatch(TestAction::Inc);
+        store.dispatch(TestAction::Sleep(10));
+
+        let slow = metrics.lock().unwrap().slow_reducers();
+        assert_eq!(slow.len(), 1);
+        assert_eq!(slow[0].name, "Sleep");
+        assert!(slow[0].max >= Duration::from_millis(10));
+
+        let Ok(TestAction::Slow(message)) = rx.try_recv() else {
+            panic!("expected a diagnostic action");
+        };
+        assert!(message.starts_with("Sleep 10 took "));
+        assert!(message.ends_with("(threshold 5.00ms)"));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_inactive_middleware_is_noop() {
+        let metrics = Arc::new(Mutex::new(PerfMetrics::default()));
+        let middleware = SlowReducerMiddleware::new(Duration::ZERO)
+            .with_metrics(metrics.clone())
+            .active(false);
+        let mut store = StoreWithMiddleware::new(0, reducer, middleware);
+
+        store.dispatch_all([TestAction::Inc, TestAction::Sleep(1)]);
+
+        assert!(metrics.lock().unwrap().slow_reducers().is_empty());
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Provides a trait for state types to expose their contents for debug overlays.
 
-use super::table::{DebugTableBuilder, DebugTableOverlay};
+use super::table::DebugTableOverlay;
 
 /// A debug entry (key-value pair)
 #[derive(Debug, Clone)]
@@ -21,11 +21,15 @@ impl DebugEntry {
     }
 }
 
-/// A debug section with a title and entries
+/// A debug section with a title, entries and nested sections
+///
+/// Nested sections show up as expandable rows in the state overlay, listed
+/// after the section's own entries.
 #[derive(Debug, Clone)]
 pub struct DebugSection {
     pub title: String,
     pub entries: Vec<DebugEntry>,
+    pub children: Vec<DebugSection>,
 }
 
 impl DebugSection {
@@ -34,6 +38,7 @@ impl DebugSection {
         Self {
             title: title.into(),
             entries: Vec::new(),
+            children: Vec::new(),
         }
     }
 
@@ -47,11 +52,83 @@ impl DebugSection {
     pub fn push_entry(&mut self, key: impl Into<String>, value: impl Into<String>) {
         self.entries.push(DebugEntry::new(key, value));
     }
+
+    /// Add a nested section
+    pub fn child(mut self, section: DebugSection) -> Self {
+        self.children.push(section);
+        self
+    }
+
+    /// Add a nested section (mutable)
+    pub fn push_child(&mut self, section: DebugSection) {
+        self.children.push(section);
+    }
+}
+
+/// Summarize a collection for a debug entry: its length and first `limit` items
+///
+/// Used by `#[derive(DebugState)]` for `Vec`, sets and similar fields.
+///
+/// ```
+/// use tui_dispatch_core::debug::debug_list;
+///
+/// assert_eq!(debug_list([1, 2, 3].iter(), 2), "3 items [1, 2, …]");
+/// ```
+pub fn debug_list<T: std::fmt::Debug>(
+    items: impl ExactSizeIterator<Item = T>,
+    limit: usize,
+) -> String {
+    let len = items.len();
+    let shown: Vec<String> = items
+        .take(limit)
+        .map(|item| format!("{:?}", item))
+        .collect();
+    format!("{} [{}]", count_label(len), join_shown(shown, len))
+}
+
+/// Summarize a map for a debug entry: its length and first `limit` entries
+///
+/// Used by `#[derive(DebugState)]` for `HashMap` and `BTreeMap` fields.
+/// `HashMap` entries come in arbitrary order.
+pub fn debug_map<K: std::fmt::Debug, V: std::fmt::Debug>(
+    entries: impl ExactSizeIterator<Item = (K, V)>,
+    limit: usize,
+) -> String {
+    let len = entries.len();
+    let shown: Vec<String> = entries
+        .take(limit)
+        .map(|(key, value)| format!("{:?}: {:?}", key, value))
+        .collect();
+    format!("{} {{{}}}", count_label(len), join_shown(shown, len))
+}
+
+fn count_label(len: usize) -> String {
+    if len == 1 {
+        "1 item".to_string()
+    } else {
+        format!("{} items", len)
+    }
+}
+
+fn join_shown(mut shown: Vec<String>, len: usize) -> String {
+    if shown.len() < len {
+        shown.push("…".to_string());
+    }
+    shown.join(", ")
+}
+
+/// Pretty-printed JSON of a value, for [`DebugState::debug_snapshot`]
+///
+/// Used by `#[derive(DebugState)]` with `#[debug_state(snapshot)]`.
+pub fn snapshot_json<T: serde::Serialize + ?Sized>(value: &T) -> Option<String> {
+    serde_json::to_string_pretty(value).ok()
 }
 
 /// Trait for types that can provide debug state information
 ///
-/// Implement this trait to enable the state overlay in debug mode.
+/// Implement this trait to enable the state overlay in debug mode. Use
+/// [`DebugSection::child`] to group related entries into sections that can
+/// be expanded in the overlay.
 ///
 /// # Example
 ///
@@ -84,14 +161,16 @@ pub trait DebugState {
     ///
     /// Default implementation uses `debug_sections()`.
     fn build_debug_table(&self, title: impl Into<String>) -> DebugTableOverlay {
-        let mut builder = DebugTableBuilder::new();
-        for section in self.debug_sections() {
-            builder.push_section(&section.title);
-            for entry in section.entries {
-                builder.push_entry(entry.key, entry.value);
-            }
-        }
-        builder.finish(title)
+        DebugTableOverlay::from_sections(title, self.debug_sections())
+    }
+
+    /// Full state as JSON, written by the debug layer's state export
+    ///
+    /// Returns `None` by default (export unsupported). Serializable state
+    /// can return [`snapshot_json(self)`](snapshot_json); the file loads
+    /// back with `Store::import_snapshot`.
+    fn debug_snapshot(&self) -> Option<String> {
+        None
     }
 }
 
@@ -150,12 +229,20 @@ impl<T: DebugState> DebugState for &T {
     fn debug_sections(&self) -> Vec<DebugSection> {
         (*self).debug_sections()
     }
+
+    fn debug_snapshot(&self) -> Option<String> {
+        (*self).debug_snapshot()
+    }
 }
 
 impl<T: DebugState> DebugState for &mut T {
     fn debug_sections(&self) -> Vec<DebugSection> {
         (**self).debug_sections()
     }
+
+    fn debug_snapshot(&self) -> Option<String> {
+        (**self).debug_snapshot()
+    }
 }
 
 #[cfg(test)]
@@ -200,6 +287,18 @@ mod tests {
         let table = state.build_debug_table("State Info");
         assert_eq!(table.title, "State Info");
         assert_eq!(table.rows.len(), 3); // 1 section + 2 entries
+        assert!(table.tree.is_some());
+    }
+
+    #[test]
+    fn test_nested_sections() {
+        let section = DebugSection::new("App")
+            .entry("mode", "normal")
+            .child(DebugSection::new("Connection").entry("host", "localhost"));
+
+        assert_eq!(section.entries.len(), 1);
+        assert_eq!(section.children[0].title, "Connection");
+        assert_eq!(section.children[0].entries[0].value, "localhost");
     }
 
     #[test]
@@ -226,6 +325,20 @@ mod tests {
         assert_eq!(sections[1].title, "B");
     }
 
+    #[test]
+    fn test_debug_collections() {
+        assert_eq!(debug_list(Vec::<i32>::new().iter(), 5), "0 items []");
+        assert_eq!(debug_list(["a"].iter(), 5), "1 item [\"a\"]");
+        assert_eq!(
+            debug_list((0..10).collect::<Vec<_>>().iter(), 3),
+            "10 items [0, 1, 2, …]"
+        );
+
+        let map: std::collections::BTreeMap<_, _> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_eq!(debug_map(map.iter(), 5), "2 items {\"a\": 1, \"b\": 2}");
+        assert_eq!(debug_map(map.iter(), 1), "2 items {\"a\": 1, …}");
+    }
+
     #[test]
     fn test_debug_wrapper() {
         #[derive(Debug)]
@@ -0,0 +1,317 @@
+//! Dev-time checks for actions the reducer ignores
+//!
+//! A reducer that returns `false` for an action either had nothing to do or
+//! never handled it - typically a new `Action` variant that was forgotten in
+//! a `match` arm, or a category with no `dispatch_*` override in a
+//! `generate_dispatcher` trait. [`StrictMiddleware`] watches reducer results
+//! and flags actions that keep returning `false`:
+//!
+//! ```ignore
+//! let debug = DebugLayer::simple().active(args.debug);
+//! let store = StoreWithMiddleware::new(state, reducer, debug.strict_middleware());
+//! ```
+//!
+//! Warnings go to `tracing` and, when created through the
+//! [`DebugLayer`](super::DebugLayer), into its action log. The checks only
+//! run in debug builds.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::action_logger::ActionLoggerConfig;
+use crate::store::Middleware;
+use crate::Action;
+
+/// Default number of unchanged dispatches in a row before warning
+pub const DEFAULT_STRICT_THRESHOLD: u32 = 3;
+
+/// Reducer results seen for one action name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionOutcomes {
+    /// Action name
+    pub name: &'static str,
+    /// Number of dispatches
+    pub dispatched: u64,
+    /// Number of dispatches that changed state
+    pub changed: u64,
+    /// Unchanged dispatches since the last change
+    pub streak: u32,
+}
+
+/// An action that left state unchanged too many times in a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrictWarning {
+    /// Action name
+    pub name: &'static str,
+    /// Unchanged dispatches in a row
+    pub streak: u32,
+    /// Whether the action has never changed state at all
+    pub never_handled: bool,
+}
+
+impl std::fmt::Display for StrictWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.never_handled {
+            write!(
+                f,
+                "{} was dispatched {} times and never changed state - missing reducer arm?",
+                self.name, self.streak
+            )
+        } else {
+            write!(
+                f,
+                "{} left state unchanged {} times in a row",
+                self.name, self.streak
+            )
+        }
+    }
+}
+
+/// Reducer outcomes per action plus pending warnings
+///
+/// Shared between [`StrictMiddleware`] and the debug layer.
+#[derive(Debug, Clone)]
+pub struct StrictChecks {
+    threshold: u32,
+    filter: ActionLoggerConfig,
+    outcomes: HashMap<&'static str, ActionOutcomes>,
+    warnings: Vec<StrictWarning>,
+}
+
+impl Default for StrictChecks {
+    fn default() -> Self {
+        Self::new(DEFAULT_STRICT_THRESHOLD)
+    }
+}
+
+impl StrictChecks {
+    /// Warn after `threshold` unchanged dispatches in a row
+    ///
+    /// `Tick` and `Render` are skipped by default (see [`with_filter`](Self::with_filter)).
+    pub fn new(threshold: u32) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            filter: ActionLoggerConfig::default(),
+            outcomes: HashMap::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Only check actions that pass `filter`
+    pub fn with_filter(mut self, filter: ActionLoggerConfig) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Record one reducer result
+    ///
+    /// Returns a warning when the action reaches the threshold. Each streak
+    /// warns once; a state change starts a new one.
+    pub fn record(&mut self, name: &'static str, state_changed: bool) -> Option<StrictWarning> {
+        if !self.filter.should_log(name) {
+            return None;
+        }
+
+        let outcomes = self.outcomes.entry(name).or_insert(ActionOutcomes {
+            name,
+            dispatched: 0,
+            changed: 0,
+            streak: 0,
+        });
+        outcomes.dispatched += 1;
+        if state_changed {
+            outcomes.changed += 1;
+            outcomes.streak = 0;
+            return None;
+        }
+
+        outcomes.streak += 1;
+        if outcomes.streak != self.threshold {
+            return None;
+        }
+        let warning = StrictWarning {
+            name,
+            streak: outcomes.streak,
+            never_handled: outcomes.changed == 0,
+        };
+        self.warnings.push(warning);
+        Some(warning)
+    }
+
+    /// Outcomes for an action name
+    pub fn outcomes(&self, name: &str) -> Option<&ActionOutcomes> {
+        self.outcomes.get(name)
+    }
+
+    /// Names of dispatched actions that never changed state, sorted
+    pub fn never_handled(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = self
+            .outcomes
+            .values()
+            .filter(|outcomes| outcomes.changed == 0)
+            .map(|outcomes| outcomes.name)
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Warnings raised since the last [`take_warnings`](Self::take_warnings)
+    pub fn warnings(&self) -> &[StrictWarning] {
+        &self.warnings
+    }
+
+    /// Remove and return the pending warnings
+    pub fn take_warnings(&mut self) -> Vec<StrictWarning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Forget all recorded outcomes and warnings
+    pub fn reset(&mut self) {
+        self.outcomes.clear();
+        self.warnings.clear();
+    }
+}
+
+/// Middleware that warns about actions the reducer keeps ignoring
+///
+/// Create one with [`DebugLayer::strict_middleware`](super::DebugLayer::strict_middleware)
+/// to see warnings in the action log, or with [`new`](Self::new) for
+/// `tracing` only. Inactive in release builds. Batches report a single
+/// result for all their actions, so they are not checked.
+#[derive(Debug, Clone)]
+pub struct StrictMiddleware {
+    checks: Arc<Mutex<StrictChecks>>,
+    active: bool,
+}
+
+impl StrictMiddleware {
+    /// Create middleware that records into `checks`
+    pub fn new(checks: Arc<Mutex<StrictChecks>>) -> Self {
+        Self {
+            checks,
+            active: cfg!(debug_assertions),
+        }
+    }
+
+    /// Set whether the middleware is active.
+    ///
+    /// Has no effect in release builds, where the checks never run.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active && cfg!(debug_assertions);
+        self
+    }
+
+    /// Get the shared checks
+    pub fn checks(&self) -> &Arc<Mutex<StrictChecks>> {
+        &self.checks
+    }
+}
+
+impl<A: Action> Middleware<A> for StrictMiddleware {
+    fn before(&mut self, _action: &A) {}
+
+    fn after(&mut self, action: &A, state_changed: bool) {
+        if !self.active {
+            return;
+        }
+        let warning = match self.checks.lock() {
+            Ok(mut checks) => checks.record(action.name(), state_changed),
+            Err(_) => None,
+        };
+        if let Some(warning) = warning {
+            tracing::warn!(action = %warning.name, streak = warning.streak, "{}", warning);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::StoreWithMiddleware;
+
+    #[derive(Clone, Debug)]
+    enum TestAction {
+        Inc,
+        Forgotten,
+        Tick,
+    }
+
+    impl Action for TestAction {
+        fn name(&self) -> &'static str {
+            match self {
+                TestAction::Inc => "Inc",
+                TestAction::Forgotten => "Forgotten",
+                TestAction::Tick => "Tick",
+            }
+        }
+    }
+
+    fn reducer(state: &mut i32, action: TestAction) -> bool {
+        match action {
+            TestAction::Inc => {
+                *state += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_warns_once_per_streak() {
+        let mut checks = StrictChecks::new(2);
+
+        assert_eq!(checks.record("Save", true), None);
+        assert_eq!(checks.record("Save", false), None);
+        let warning = checks.record("Save", false).unwrap();
+        assert_eq!(warning.streak, 2);
+        assert!(!warning.never_handled);
+        assert_eq!(checks.record("Save", false), None);
+
+        // A change starts a new streak
+        checks.record("Save", true);
+        checks.record("Save", false);
+        assert!(checks.record("Save", false).is_some());
+
+        assert_eq!(checks.take_warnings().len(), 2);
+        assert!(checks.warnings().is_empty());
+        assert_eq!(checks.outcomes("Save").unwrap().dispatched, 7);
+    }
+
+    #[test]
+    fn test_middleware_flags_forgotten_actions() {
+        let checks = Arc::new(Mutex::new(StrictChecks::default()));
+        let middleware = StrictMiddleware::new(checks.clone()).active(true);
+        let mut store = StoreWithMiddleware::new(0, reducer, middleware);
+
+        store.dispatch(TestAction::Inc);
+        for _ in 0..3 {
+            store.dispatch(TestAction::Forgotten);
+            store.dispatch(TestAction::Tick);
+        }
+
+        let checks = checks.lock().unwrap();
+        assert_eq!(checks.never_handled(), vec!["Forgotten"]);
+        assert_eq!(
+            checks.warnings(),
+            &[StrictWarning {
+                name: "Forgotten",
+                streak: 3,
+                never_handled: true,
+            }]
+        );
+        assert!(checks.outcomes("Tick").is_none());
+    }
+
+    #[test]
+    fn test_warning_message() {
+        let warning = StrictWarning {
+            name: "Refresh",
+            streak: 5,
+            never_handled: false,
+        };
+        assert_eq!(
+            warning.to_string(),
+            "Refresh left state unchanged 5 times in a row"
+        );
+    }
+}
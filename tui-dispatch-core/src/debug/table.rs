@@ -4,8 +4,11 @@
 //! with sections and key-value entries. Also includes action log overlay
 //! for displaying recent actions.
 
-use super::action_logger::ActionLog;
+use super::action_logger::{matches_filter, ActionLog};
 use super::cell::CellPreview;
+use super::diff::StateDiffEntry;
+use super::state::DebugSection;
+use super::tree::StateTree;
 
 /// A row in a debug table - either a section header or a key-value entry
 #[derive(Debug, Clone)]
@@ -25,6 +28,8 @@ pub struct DebugTableOverlay {
     pub rows: Vec<DebugTableRow>,
     /// Optional cell preview for inspect overlays
     pub cell_preview: Option<CellPreview>,
+    /// Section tree behind `rows`, for navigable state overlays
+    pub tree: Option<StateTree>,
 }
 
 impl DebugTableOverlay {
@@ -34,9 +39,31 @@ impl DebugTableOverlay {
             title: title.into(),
             rows,
             cell_preview: None,
+            tree: None,
         }
     }
 
+    /// Create an overlay whose rows follow an expandable section tree
+    pub fn from_sections(title: impl Into<String>, sections: Vec<DebugSection>) -> Self {
+        let tree = StateTree::new(sections);
+        Self {
+            title: title.into(),
+            rows: tree.table_rows(),
+            cell_preview: None,
+            tree: Some(tree),
+        }
+    }
+
+    /// Update the tree and rebuild `rows` from it
+    ///
+    /// Returns None if the overlay has no tree.
+    pub fn update_tree<R>(&mut self, update: impl FnOnce(&mut StateTree) -> R) -> Option<R> {
+        let tree = self.tree.as_mut()?;
+        let result = update(tree);
+        self.rows = tree.table_rows();
+        Some(result)
+    }
+
     /// Create a new overlay with cell preview
     pub fn with_cell_preview(
         title: impl Into<String>,
@@ -47,6 +74,7 @@ impl DebugTableOverlay {
             title: title.into(),
             rows,
             cell_preview: Some(preview),
+            tree: None,
         }
     }
 }
@@ -62,6 +90,8 @@ pub enum DebugOverlay {
     ActionLog(ActionLogOverlay),
     /// Action detail overlay - shows full details of a single action
     ActionDetail(ActionDetailOverlay),
+    /// Metrics overlay - shows frame rate, reducer timing, and queue depth
+    Metrics(DebugTableOverlay),
 }
 
 /// Overlay for displaying detailed action information
@@ -75,13 +105,17 @@ pub struct ActionDetailOverlay {
     pub params: String,
     /// Elapsed time display
     pub elapsed: String,
+    /// State changes caused by this action (None if not captured)
+    pub state_diff: Option<Vec<StateDiffEntry>>,
 }
 
 impl DebugOverlay {
-    /// Get the underlying table from the overlay (for Table/State/Inspect)
+    /// Get the underlying table from the overlay (for State/Inspect/Metrics)
     pub fn table(&self) -> Option<&DebugTableOverlay> {
         match self {
-            DebugOverlay::Inspect(table) | DebugOverlay::State(table) => Some(table),
+            DebugOverlay::Inspect(table)
+            | DebugOverlay::State(table)
+            | DebugOverlay::Metrics(table) => Some(table),
             DebugOverlay::ActionLog(_) | DebugOverlay::ActionDetail(_) => None,
         }
     }
@@ -109,6 +143,7 @@ impl DebugOverlay {
             DebugOverlay::State(_) => "state",
             DebugOverlay::ActionLog(_) => "action_log",
             DebugOverlay::ActionDetail(_) => "action_detail",
+            DebugOverlay::Metrics(_) => "metrics",
         }
     }
 }
@@ -135,12 +170,18 @@ pub struct ActionLogDisplayEntry {
 pub struct ActionLogOverlay {
     /// Title for the overlay
     pub title: String,
-    /// Action entries to display
+    /// Action entries to display (those matching `filter`)
     pub entries: Vec<ActionLogDisplayEntry>,
+    /// Every captured entry, before filtering
+    pub all_entries: Vec<ActionLogDisplayEntry>,
     /// Currently selected entry index (for scrolling)
     pub selected: usize,
     /// Scroll offset for visible window
     pub scroll_offset: usize,
+    /// Name filter: comma-separated globs (empty = show all)
+    pub filter: String,
+    /// Whether the filter prompt is capturing keys
+    pub filter_active: bool,
 }
 
 impl ActionLogOverlay {
@@ -151,19 +192,36 @@ impl ActionLogOverlay {
             .map(|e| ActionLogDisplayEntry {
                 sequence: e.sequence,
                 name: e.name.to_string(),
-                params: e.params.clone(),
-                elapsed: e.elapsed.clone(),
+                params: e.params().to_string(),
+                elapsed: e.elapsed(),
             })
             .collect();
 
         Self {
             title: title.into(),
-            entries,
+            entries: entries.clone(),
+            all_entries: entries,
             selected: 0,
             scroll_offset: 0,
+            filter: String::new(),
+            filter_active: false,
         }
     }
 
+    /// Show only entries whose name matches `filter`
+    ///
+    /// See [`matches_filter`] for the syntax. The selection is kept in range.
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+        self.entries = self
+            .all_entries
+            .iter()
+            .filter(|entry| matches_filter(&self.filter, &entry.name))
+            .cloned()
+            .collect();
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
     /// Scroll up (select previous entry)
     pub fn scroll_up(&mut self) {
         if self.selected > 0 {
@@ -224,6 +282,7 @@ impl ActionLogOverlay {
             name: entry.name.clone(),
             params: entry.params.clone(),
             elapsed: entry.elapsed.clone(),
+            state_diff: None,
         })
     }
 }
@@ -303,6 +362,7 @@ impl DebugTableBuilder {
             title: title.into(),
             rows: self.rows,
             cell_preview: self.cell_preview,
+            tree: None,
         }
     }
 
@@ -355,6 +415,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_update_tree_rebuilds_rows() {
+        let mut table = DebugTableOverlay::from_sections(
+            "State",
+            vec![DebugSection::new("App")
+                .entry("mode", "normal")
+                .child(DebugSection::new("Connection").entry("host", "localhost"))],
+        );
+        assert_eq!(table.rows.len(), 3);
+
+        table.update_tree(|tree| {
+            tree.select(2);
+            tree.expand()
+        });
+        assert_eq!(table.rows.len(), 4);
+        assert!(DebugTableBuilder::new()
+            .finish("Flat")
+            .update_tree(|tree| tree.len())
+            .is_none());
+    }
+
     #[test]
     fn test_overlay_kinds() {
         let table = DebugTableBuilder::new().finish("Test");
@@ -364,14 +445,21 @@ mod tests {
         assert!(inspect.table().is_some());
         assert!(inspect.action_log().is_none());
 
+        let metrics = DebugOverlay::Metrics(table.clone());
+        assert_eq!(metrics.kind(), "metrics");
+        assert!(metrics.table().is_some());
+
         let state = DebugOverlay::State(table);
         assert_eq!(state.kind(), "state");
 
         let action_log = ActionLogOverlay {
             title: "Test".to_string(),
             entries: vec![],
+            all_entries: vec![],
             selected: 0,
             scroll_offset: 0,
+            filter: String::new(),
+            filter_active: false,
         };
         let log_overlay = DebugOverlay::ActionLog(action_log);
         assert_eq!(log_overlay.kind(), "action_log");
@@ -403,8 +491,11 @@ mod tests {
                     elapsed: "2ms".to_string(),
                 },
             ],
+            all_entries: vec![],
             selected: 0,
             scroll_offset: 0,
+            filter: String::new(),
+            filter_active: false,
         };
 
         assert_eq!(overlay.selected, 0);
@@ -427,4 +518,36 @@ mod tests {
         overlay.scroll_to_bottom();
         assert_eq!(overlay.selected, 2);
     }
+
+    #[test]
+    fn test_action_log_overlay_filter() {
+        let entry = |sequence, name: &str| ActionLogDisplayEntry {
+            sequence,
+            name: name.to_string(),
+            params: String::new(),
+            elapsed: "0ms".to_string(),
+        };
+        let all_entries = vec![
+            entry(2, "SearchClear"),
+            entry(1, "Connect"),
+            entry(0, "SearchStart"),
+        ];
+        let mut overlay = ActionLogOverlay {
+            title: "Test".to_string(),
+            entries: all_entries.clone(),
+            all_entries,
+            selected: 2,
+            scroll_offset: 0,
+            filter: String::new(),
+            filter_active: false,
+        };
+
+        overlay.set_filter("Search*");
+        let names: Vec<_> = overlay.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["SearchClear", "SearchStart"]);
+        assert_eq!(overlay.selected, 1);
+
+        overlay.set_filter("");
+        assert_eq!(overlay.entries.len(), 3);
+    }
 }
@@ -0,0 +1,310 @@
+//! Expandable section tree for the state overlay
+//!
+//! [`StateTree`] lays out nested [`DebugSection`]s as table rows, showing the
+//! contents of expanded sections only, and tracks the selected row.
+
+use std::collections::HashSet;
+
+use super::state::DebugSection;
+use super::table::DebugTableRow;
+
+/// A visible row, addressed by the index path of its section
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TreeRow {
+    /// The section header itself
+    Section(Vec<usize>),
+    /// An entry of the section, by index
+    Entry(Vec<usize>, usize),
+}
+
+impl TreeRow {
+    fn path(&self) -> &[usize] {
+        match self {
+            TreeRow::Section(path) | TreeRow::Entry(path, _) => path,
+        }
+    }
+}
+
+/// Navigation state over nested debug sections
+///
+/// Top-level sections start expanded and nested ones collapsed, so flat
+/// state looks the same as a plain table. Within a section, entries come
+/// before nested sections.
+///
+/// # Example
+///
+/// ```
+/// use tui_dispatch_core::debug::{DebugSection, StateTree};
+///
+/// let mut tree = StateTree::new(vec![DebugSection::new("App")
+///     .entry("mode", "normal")
+///     .child(DebugSection::new("Connection").entry("host", "localhost"))]);
+/// assert_eq!(tree.len(), 3);
+///
+/// tree.select(2);
+/// tree.expand();
+/// tree.select(3);
+/// assert_eq!(tree.breadcrumb(), ["App", "Connection"]);
+/// assert_eq!(tree.selected_value(), Some("localhost"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StateTree {
+    sections: Vec<DebugSection>,
+    expanded: HashSet<Vec<usize>>,
+    rows: Vec<TreeRow>,
+    selected: usize,
+}
+
+impl StateTree {
+    /// Create a tree with the top-level sections expanded
+    pub fn new(sections: Vec<DebugSection>) -> Self {
+        let expanded = (0..sections.len()).map(|index| vec![index]).collect();
+        let mut tree = Self {
+            sections,
+            expanded,
+            rows: Vec::new(),
+            selected: 0,
+        };
+        tree.layout();
+        tree
+    }
+
+    /// The sections shown by the tree
+    pub fn sections(&self) -> &[DebugSection] {
+        &self.sections
+    }
+
+    /// Number of visible rows
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether no rows are visible
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Index of the selected row
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select a row, clamped to the visible rows
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.rows.len().saturating_sub(1));
+    }
+
+    /// Whether the selected row is a collapsed or expanded section
+    pub fn is_section_selected(&self) -> bool {
+        matches!(self.rows.get(self.selected), Some(TreeRow::Section(_)))
+    }
+
+    /// Expand the selected section
+    ///
+    /// Returns false if the selection is an entry or already expanded.
+    pub fn expand(&mut self) -> bool {
+        let Some(TreeRow::Section(path)) = self.rows.get(self.selected) else {
+            return false;
+        };
+        let changed = self.expanded.insert(path.clone());
+        self.layout();
+        changed
+    }
+
+    /// Collapse the selected section
+    ///
+    /// On an entry or an already collapsed section, selects the enclosing
+    /// section instead. Returns false at the top level.
+    pub fn collapse(&mut self) -> bool {
+        let Some(row) = self.rows.get(self.selected).cloned() else {
+            return false;
+        };
+        if let TreeRow::Section(ref path) = row {
+            if self.expanded.remove(path) {
+                self.layout();
+                return true;
+            }
+        }
+
+        let parent = match row {
+            TreeRow::Entry(path, _) => path,
+            TreeRow::Section(mut path) => {
+                path.pop();
+                path
+            }
+        };
+        match self
+            .rows
+            .iter()
+            .position(|row| *row == TreeRow::Section(parent.clone()))
+        {
+            Some(index) => {
+                self.selected = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Expand or collapse the selected section
+    pub fn toggle(&mut self) -> bool {
+        let Some(TreeRow::Section(path)) = self.rows.get(self.selected) else {
+            return false;
+        };
+        if !self.expanded.remove(path) {
+            self.expanded.insert(path.clone());
+        }
+        self.layout();
+        true
+    }
+
+    /// Titles of the sections leading to the selected row
+    pub fn breadcrumb(&self) -> Vec<&str> {
+        let Some(row) = self.rows.get(self.selected) else {
+            return Vec::new();
+        };
+        let path = row.path();
+        (1..=path.len())
+            .map(|depth| self.section(&path[..depth]).title.as_str())
+            .collect()
+    }
+
+    /// Value of the selected entry (None on a section)
+    pub fn selected_value(&self) -> Option<&str> {
+        match self.rows.get(self.selected)? {
+            TreeRow::Entry(path, index) => Some(self.section(path).entries[*index].value.as_str()),
+            TreeRow::Section(_) => None,
+        }
+    }
+
+    /// Visible rows as table rows, indented by depth
+    ///
+    /// Section titles are marked `▾` when expanded and `▸` when collapsed.
+    pub fn table_rows(&self) -> Vec<DebugTableRow> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let indent = "  ".repeat(row.path().len() - 1);
+                match row {
+                    TreeRow::Section(path) => {
+                        let marker = if self.expanded.contains(path) {
+                            '▾'
+                        } else {
+                            '▸'
+                        };
+                        let title = &self.section(path).title;
+                        DebugTableRow::Section(format!("{indent}{marker} {title}"))
+                    }
+                    TreeRow::Entry(path, index) => {
+                        let entry = &self.section(path).entries[*index];
+                        DebugTableRow::Entry {
+                            key: format!("{indent}{}", entry.key),
+                            value: entry.value.clone(),
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn section(&self, path: &[usize]) -> &DebugSection {
+        let mut section = &self.sections[path[0]];
+        for &index in &path[1..] {
+            section = &section.children[index];
+        }
+        section
+    }
+
+    fn layout(&mut self) {
+        let mut rows = Vec::new();
+        for (index, section) in self.sections.iter().enumerate() {
+            self.push_rows(section, vec![index], &mut rows);
+        }
+        self.rows = rows;
+        self.select(self.selected);
+    }
+
+    fn push_rows(&self, section: &DebugSection, path: Vec<usize>, rows: &mut Vec<TreeRow>) {
+        rows.push(TreeRow::Section(path.clone()));
+        if !self.expanded.contains(&path) {
+            return;
+        }
+        rows.extend((0..section.entries.len()).map(|index| TreeRow::Entry(path.clone(), index)));
+        for (index, child) in section.children.iter().enumerate() {
+            let mut child_path = path.clone();
+            child_path.push(index);
+            self.push_rows(child, child_path, rows);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> StateTree {
+        StateTree::new(vec![
+            DebugSection::new("App").entry("mode", "normal").child(
+                DebugSection::new("Connection")
+                    .entry("host", "localhost")
+                    .entry("port", "6379"),
+            ),
+            DebugSection::new("UI").entry("focus", "list"),
+        ])
+    }
+
+    fn keys(tree: &StateTree) -> Vec<String> {
+        tree.table_rows()
+            .into_iter()
+            .map(|row| match row {
+                DebugTableRow::Section(title) => title,
+                DebugTableRow::Entry { key, .. } => key,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_nested_sections_start_collapsed() {
+        let tree = tree();
+        assert_eq!(
+            keys(&tree),
+            ["▾ App", "mode", "  ▸ Connection", "▾ UI", "focus"]
+        );
+    }
+
+    #[test]
+    fn test_expand_and_collapse() {
+        let mut tree = tree();
+        tree.select(2);
+        assert!(tree.expand());
+        assert!(!tree.expand());
+        assert_eq!(keys(&tree)[2..5], ["  ▾ Connection", "  host", "  port"]);
+
+        // On an entry, collapse moves to the enclosing section first
+        tree.select(4);
+        assert!(tree.collapse());
+        assert_eq!(tree.selected(), 2);
+        assert!(tree.collapse());
+        assert_eq!(tree.len(), 5);
+
+        // Collapsing the top-level section keeps the selection in range
+        tree.select(0);
+        assert!(tree.toggle());
+        assert_eq!(keys(&tree), ["▸ App", "▾ UI", "focus"]);
+        assert!(!tree.collapse());
+    }
+
+    #[test]
+    fn test_breadcrumb_and_value() {
+        let mut tree = tree();
+        tree.select(2);
+        tree.expand();
+        tree.select(4);
+        assert_eq!(tree.breadcrumb(), ["App", "Connection"]);
+        assert_eq!(tree.selected_value(), Some("6379"));
+
+        tree.select(2);
+        assert!(tree.is_section_selected());
+        assert_eq!(tree.selected_value(), None);
+    }
+}
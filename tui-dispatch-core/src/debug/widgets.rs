@@ -340,6 +340,8 @@ pub struct DebugTableStyle {
     pub key: Style,
     /// Style for entry values
     pub value: Style,
+    /// Selected row style
+    pub selected: Style,
     /// Alternating row styles (even, odd)
     pub row_styles: (Style, Style),
 }
@@ -358,6 +360,9 @@ impl Default for DebugTableStyle {
                 .fg(DebugStyle::neon_amber())
                 .add_modifier(Modifier::BOLD),
             value: Style::default().fg(DebugStyle::text_primary()),
+            selected: Style::default()
+                .bg(DebugStyle::bg_highlight())
+                .add_modifier(Modifier::BOLD),
             row_styles: (
                 Style::default().bg(DebugStyle::bg_panel()),
                 Style::default().bg(DebugStyle::bg_surface()),
@@ -371,6 +376,7 @@ pub struct DebugTableWidget<'a> {
     table: &'a DebugTableOverlay,
     style: DebugTableStyle,
     scroll_offset: usize,
+    selected: Option<usize>,
 }
 
 impl<'a> DebugTableWidget<'a> {
@@ -380,6 +386,7 @@ impl<'a> DebugTableWidget<'a> {
             table,
             style: DebugTableStyle::default(),
             scroll_offset: 0,
+            selected: None,
         }
     }
 
@@ -394,6 +401,12 @@ impl<'a> DebugTableWidget<'a> {
         self.scroll_offset = scroll_offset;
         self
     }
+
+    /// Highlight a row by index
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
 }
 
 impl Widget for DebugTableWidget<'_> {
@@ -435,23 +448,30 @@ impl Widget for DebugTableWidget<'_> {
             .iter()
             .skip(scroll_offset)
             .enumerate()
-            .map(|(idx, row)| match row {
-                DebugTableRow::Section(title) => Row::new(vec![
-                    Cell::from(format!(" {title} ")).style(self.style.section),
-                    Cell::from(""),
-                ]),
-                DebugTableRow::Entry { key, value } => {
-                    let row_index = idx + scroll_offset;
-                    let row_style = if row_index % 2 == 0 {
-                        self.style.row_styles.0
-                    } else {
-                        self.style.row_styles.1
-                    };
-                    Row::new(vec![
-                        Cell::from(key.clone()).style(self.style.key),
-                        Cell::from(value.clone()).style(self.style.value),
-                    ])
-                    .style(row_style)
+            .map(|(idx, row)| {
+                let row_index = idx + scroll_offset;
+                let row = match row {
+                    DebugTableRow::Section(title) => Row::new(vec![
+                        Cell::from(format!(" {title} ")).style(self.style.section),
+                        Cell::from(""),
+                    ]),
+                    DebugTableRow::Entry { key, value } => {
+                        let row_style = if row_index % 2 == 0 {
+                            self.style.row_styles.0
+                        } else {
+                            self.style.row_styles.1
+                        };
+                        Row::new(vec![
+                            Cell::from(key.clone()).style(self.style.key),
+                            Cell::from(value.clone()).style(self.style.value),
+                        ])
+                        .style(row_style)
+                    }
+                };
+                if self.selected == Some(row_index) {
+                    row.style(self.style.selected)
+                } else {
+                    row
                 }
             })
             .collect();
@@ -68,10 +68,238 @@
 //! }
 //! ```
 
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::ops::Deref;
+use tokio::sync::mpsc;
 
 use crate::action::Action;
-use crate::store::Middleware;
+use crate::event::ComponentId;
+use crate::selector::Selector;
+use crate::store::{ListenerId, Listeners, Middleware};
+
+/// A batch of effects returned by a reducer.
+///
+/// Modeled after Elm's `Cmd`: a reducer returns `Cmd::none()`, a single
+/// effect with [`Cmd::of`], or several commands combined with
+/// [`Cmd::batch`]. Slice reducers with their own effect type can be lifted
+/// into the parent effect type with [`Cmd::map`].
+///
+/// `Cmd` dereferences to a slice, so `len()`, `is_empty()`, indexing and
+/// iteration work as they did on `Vec<E>`.
+///
+/// # Example
+///
+/// ```ignore
+/// use tui_dispatch::Cmd;
+///
+/// enum SearchEffect { Query(String) }
+/// enum Effect { Search(SearchEffect), Save }
+///
+/// let search: Cmd<SearchEffect> = Cmd::of(SearchEffect::Query("rust".into()));
+/// let cmd = Cmd::batch([search.map(Effect::Search), Cmd::of(Effect::Save)]);
+/// assert_eq!(cmd.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cmd<E> {
+    effects: Vec<E>,
+}
+
+impl<E> Cmd<E> {
+    /// Create a command with no effects.
+    #[inline]
+    pub fn none() -> Self {
+        Self {
+            effects: Vec::new(),
+        }
+    }
+
+    /// Create a command with a single effect.
+    #[inline]
+    pub fn of(effect: E) -> Self {
+        Self {
+            effects: vec![effect],
+        }
+    }
+
+    /// Combine several commands into one, keeping their order.
+    pub fn batch(cmds: impl IntoIterator<Item = Cmd<E>>) -> Self {
+        let mut batch = Self::none();
+        for cmd in cmds {
+            batch.effects.extend(cmd.effects);
+        }
+        batch
+    }
+
+    /// Convert every effect with `f`.
+    ///
+    /// Used to lift a slice reducer's effects into the parent effect type.
+    pub fn map<F>(self, f: impl FnMut(E) -> F) -> Cmd<F> {
+        Cmd {
+            effects: self.effects.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Append an effect.
+    #[inline]
+    pub fn push(&mut self, effect: E) {
+        self.effects.push(effect);
+    }
+
+    /// Append all effects of another command.
+    #[inline]
+    pub fn append(&mut self, other: Cmd<E>) {
+        self.effects.extend(other.effects);
+    }
+
+    /// Convert into the underlying effects.
+    #[inline]
+    pub fn into_vec(self) -> Vec<E> {
+        self.effects
+    }
+}
+
+impl<E> Default for Cmd<E> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<E> Deref for Cmd<E> {
+    type Target = [E];
+
+    fn deref(&self) -> &[E] {
+        &self.effects
+    }
+}
+
+impl<E> From<Vec<E>> for Cmd<E> {
+    fn from(effects: Vec<E>) -> Self {
+        Self { effects }
+    }
+}
+
+impl<E> From<Cmd<E>> for Vec<E> {
+    fn from(cmd: Cmd<E>) -> Self {
+        cmd.effects
+    }
+}
+
+impl<E: PartialEq> PartialEq<Vec<E>> for Cmd<E> {
+    fn eq(&self, other: &Vec<E>) -> bool {
+        self.effects == *other
+    }
+}
+
+impl<E> FromIterator<E> for Cmd<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        Self {
+            effects: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<E> Extend<E> for Cmd<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        self.effects.extend(iter);
+    }
+}
+
+impl<E> IntoIterator for Cmd<E> {
+    type Item = E;
+    type IntoIter = std::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.effects.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a Cmd<E> {
+    type Item = &'a E;
+    type IntoIter = std::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.effects.iter()
+    }
+}
+
+/// Components touched by state changes, used as dirty-region hints.
+///
+/// Reducers report them with [`DispatchResult::touching`]. The effect runtime
+/// collects them between frames into
+/// [`RenderContext::dirty`](crate::RenderContext::dirty), so render code can
+/// reuse cached output for components whose data did not change.
+///
+/// This is a compact set: `contains` may report a component that was not
+/// inserted (a hash collision), but never misses one that was.
+/// [`all`](Self::all) contains every component; changes without hints
+/// count as touching everything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirtyComponents {
+    bits: u128,
+}
+
+impl Default for DirtyComponents {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl DirtyComponents {
+    /// Every component is dirty.
+    pub const fn all() -> Self {
+        Self { bits: u128::MAX }
+    }
+
+    /// No component is dirty.
+    pub const fn none() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Mark `id` dirty.
+    pub fn insert<C: ComponentId>(&mut self, id: C) {
+        self.bits |= Self::bit(id);
+    }
+
+    /// Whether `id` may have changed.
+    pub fn contains<C: ComponentId>(&self, id: C) -> bool {
+        self.bits & Self::bit(id) != 0
+    }
+
+    /// Whether every component counts as dirty.
+    pub fn is_all(&self) -> bool {
+        self.bits == u128::MAX
+    }
+
+    /// Whether no component is dirty.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Add every component dirty in `other`.
+    pub fn merge(&mut self, other: DirtyComponents) {
+        self.bits |= other.bits;
+    }
+
+    fn bit<C: ComponentId>(id: C) -> u128 {
+        // DefaultHasher::new() uses fixed keys, so ids hash the same everywhere
+        let mut hasher = DefaultHasher::new();
+        id.hash(&mut hasher);
+        1 << (hasher.finish() % 128)
+    }
+}
+
+impl<C: ComponentId> FromIterator<C> for DirtyComponents {
+    fn from_iter<I: IntoIterator<Item = C>>(ids: I) -> Self {
+        let mut dirty = Self::none();
+        for id in ids {
+            dirty.insert(id);
+        }
+        dirty
+    }
+}
 
 /// Result of dispatching an action to an effect-aware store.
 ///
@@ -81,7 +309,12 @@ pub struct DispatchResult<E> {
     /// Whether the state was modified by this action.
     pub changed: bool,
     /// Effects to be processed after dispatch.
-    pub effects: Vec<E>,
+    pub effects: Cmd<E>,
+    /// Components whose data the change touched (None = all of them).
+    ///
+    /// Set with [`touching`](Self::touching). A change that touches no
+    /// component doesn't trigger a redraw.
+    pub changed_components: Option<DirtyComponents>,
 }
 
 impl<E> Default for DispatchResult<E> {
@@ -96,7 +329,8 @@ impl<E> DispatchResult<E> {
     pub fn unchanged() -> Self {
         Self {
             changed: false,
-            effects: vec![],
+            effects: Cmd::none(),
+            changed_components: None,
         }
     }
 
@@ -105,7 +339,8 @@ impl<E> DispatchResult<E> {
     pub fn changed() -> Self {
         Self {
             changed: true,
-            effects: vec![],
+            effects: Cmd::none(),
+            changed_components: None,
         }
     }
 
@@ -114,7 +349,8 @@ impl<E> DispatchResult<E> {
     pub fn effect(effect: E) -> Self {
         Self {
             changed: false,
-            effects: vec![effect],
+            effects: Cmd::of(effect),
+            changed_components: None,
         }
     }
 
@@ -123,7 +359,8 @@ impl<E> DispatchResult<E> {
     pub fn effects(effects: Vec<E>) -> Self {
         Self {
             changed: false,
-            effects,
+            effects: effects.into(),
+            changed_components: None,
         }
     }
 
@@ -132,7 +369,8 @@ impl<E> DispatchResult<E> {
     pub fn changed_with(effect: E) -> Self {
         Self {
             changed: true,
-            effects: vec![effect],
+            effects: Cmd::of(effect),
+            changed_components: None,
         }
     }
 
@@ -141,7 +379,8 @@ impl<E> DispatchResult<E> {
     pub fn changed_with_many(effects: Vec<E>) -> Self {
         Self {
             changed: true,
-            effects,
+            effects: effects.into(),
+            changed_components: None,
         }
     }
 
@@ -152,6 +391,35 @@ impl<E> DispatchResult<E> {
         self
     }
 
+    /// Create a result with a command but no state change.
+    #[inline]
+    pub fn cmd(cmd: Cmd<E>) -> Self {
+        Self {
+            changed: false,
+            effects: cmd,
+            changed_components: None,
+        }
+    }
+
+    /// Append all effects of a command to this result.
+    #[inline]
+    pub fn with_cmd(mut self, cmd: Cmd<E>) -> Self {
+        self.effects.append(cmd);
+        self
+    }
+
+    /// Convert every effect with `f`, keeping the changed flag.
+    ///
+    /// Lets a slice reducer with its own effect type be composed into a
+    /// parent reducer.
+    pub fn map<F>(self, f: impl FnMut(E) -> F) -> DispatchResult<F> {
+        DispatchResult {
+            changed: self.changed,
+            effects: self.effects.map(f),
+            changed_components: self.changed_components,
+        }
+    }
+
     /// Set the changed flag to true.
     #[inline]
     pub fn mark_changed(mut self) -> Self {
@@ -164,6 +432,48 @@ impl<E> DispatchResult<E> {
     pub fn has_effects(&self) -> bool {
         !self.effects.is_empty()
     }
+
+    /// Report the components this change touched, as dirty-region hints.
+    ///
+    /// Can be called repeatedly; the components add up. Passing no
+    /// components marks a change that nothing on screen depends on.
+    ///
+    /// ```ignore
+    /// state.chart.push(sample);
+    /// DispatchResult::changed().touching([Panel::Chart, Panel::Stats])
+    /// ```
+    pub fn touching<C: ComponentId>(mut self, ids: impl IntoIterator<Item = C>) -> Self {
+        let touched = self
+            .changed_components
+            .get_or_insert_with(DirtyComponents::none);
+        for id in ids {
+            touched.insert(id);
+        }
+        self
+    }
+
+    /// Components to redraw for this result: none if unchanged.
+    pub fn dirty(&self) -> DirtyComponents {
+        if !self.changed {
+            return DirtyComponents::none();
+        }
+        self.changed_components.unwrap_or_else(DirtyComponents::all)
+    }
+
+    /// Fold another result into this one.
+    ///
+    /// Changed flags and touched components combine and effects are
+    /// appended, as when several slice reducers or a batch of actions
+    /// produce one result.
+    pub fn merge(&mut self, other: DispatchResult<E>) {
+        if other.changed {
+            let mut touched = self.dirty();
+            touched.merge(other.dirty());
+            self.changed = true;
+            self.changed_components = Some(touched);
+        }
+        self.effects.append(other.effects);
+    }
 }
 
 /// A reducer function that can emit effects.
@@ -204,6 +514,9 @@ pub type EffectReducer<S, A, E> = fn(&mut S, A) -> DispatchResult<E>;
 pub struct EffectStore<S, A, E> {
     state: S,
     reducer: EffectReducer<S, A, E>,
+    /// Bumped whenever the state may have changed (see [`EffectStore::version`])
+    version: u64,
+    listeners: Listeners<S, A>,
     _marker: PhantomData<(A, E)>,
 }
 
@@ -216,6 +529,8 @@ where
         Self {
             state,
             reducer,
+            version: 0,
+            listeners: Listeners::default(),
             _marker: PhantomData,
         }
     }
@@ -229,27 +544,128 @@ where
     /// Get a mutable reference to the state.
     ///
     /// Use sparingly - prefer dispatching actions for state changes.
-    /// This is mainly useful for initialization.
+    /// This is mainly useful for initialization. Bumps the state version.
     #[inline]
     pub fn state_mut(&mut self) -> &mut S {
+        self.version += 1;
         &mut self.state
     }
 
+    /// Get the state version.
+    ///
+    /// Increments each time a dispatch reports a change (or `state_mut`
+    /// is called). Used by [`Selector`] for memoization.
+    #[inline]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Compute derived data with a memoized selector.
+    #[inline]
+    pub fn select<'s, T>(&self, selector: &'s mut Selector<S, T>) -> &'s T {
+        selector.select(&self.state, self.version)
+    }
+
+    /// Serialize the state to pretty-printed JSON.
+    ///
+    /// See [`Store::export_snapshot`](crate::Store::export_snapshot).
+    pub fn export_snapshot(&self) -> serde_json::Result<String>
+    where
+        S: Serialize,
+    {
+        serde_json::to_string_pretty(&self.state)
+    }
+
+    /// Replace the state with an exported snapshot.
+    ///
+    /// See [`Store::import_snapshot`](crate::Store::import_snapshot).
+    pub fn import_snapshot(&mut self, json: &str) -> serde_json::Result<()>
+    where
+        S: DeserializeOwned,
+    {
+        *self.state_mut() = serde_json::from_str(json)?;
+        Ok(())
+    }
+
     /// Dispatch an action to the store.
     ///
     /// The reducer is called with the current state and action,
     /// returning whether state changed and any effects to process.
     #[inline]
     pub fn dispatch(&mut self, action: A) -> DispatchResult<E> {
-        (self.reducer)(&mut self.state, action)
+        if self.listeners.is_empty() {
+            let result = (self.reducer)(&mut self.state, action);
+            if result.changed {
+                self.version += 1;
+            }
+            return result;
+        }
+        let result = (self.reducer)(&mut self.state, action.clone());
+        if result.changed {
+            self.version += 1;
+        }
+        self.listeners.notify(&action, &self.state, result.changed);
+        result
+    }
+
+    /// Dispatch several actions as one batch.
+    ///
+    /// Actions are reduced in order. The combined result is changed if any
+    /// action changed state, and collects all effects in dispatch order.
+    /// The state version is bumped at most once.
+    pub fn dispatch_all(&mut self, actions: impl IntoIterator<Item = A>) -> DispatchResult<E> {
+        self.dispatch_batch(actions, |_| {})
+    }
+
+    /// Like [`dispatch_all`](Self::dispatch_all), reporting each action's
+    /// changed flag to `on_reduced` in order.
+    pub(crate) fn dispatch_batch(
+        &mut self,
+        actions: impl IntoIterator<Item = A>,
+        mut on_reduced: impl FnMut(bool),
+    ) -> DispatchResult<E> {
+        let mut combined = DispatchResult::unchanged();
+        for action in actions {
+            let result = if self.listeners.is_empty() {
+                (self.reducer)(&mut self.state, action)
+            } else {
+                let result = (self.reducer)(&mut self.state, action.clone());
+                self.listeners.notify(&action, &self.state, result.changed);
+                result
+            };
+            on_reduced(result.changed);
+            combined.merge(result);
+        }
+        if combined.changed {
+            self.version += 1;
+        }
+        combined
+    }
+
+    /// Observe dispatched actions outside the render loop.
+    ///
+    /// See [`Store::subscribe`](crate::Store::subscribe). Listeners see the
+    /// changed flag, not the effects.
+    pub fn subscribe(&mut self, listener: impl FnMut(&A, &S, bool) + 'static) -> ListenerId {
+        self.listeners.subscribe(listener)
+    }
+
+    /// Remove a listener added with [`subscribe`](Self::subscribe).
+    pub fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        self.listeners.unsubscribe(id)
+    }
+
+    /// Number of registered listeners.
+    pub fn listener_count(&self) -> usize {
+        self.listeners.len()
     }
 }
 
 /// An effect store with middleware support.
 ///
 /// Wraps an [`EffectStore`] and calls middleware hooks before and after
-/// each dispatch. The middleware receives action references and the
-/// state change indicator, but not the effects.
+/// each dispatch. The middleware can rewrite or swallow actions and
+/// receives the state change indicator, but not the effects.
 ///
 /// # Example
 ///
@@ -275,6 +691,7 @@ where
 {
     store: EffectStore<S, A, E>,
     middleware: M,
+    action_tx: Option<mpsc::UnboundedSender<A>>,
 }
 
 impl<S, A, E, M> EffectStoreWithMiddleware<S, A, E, M>
@@ -287,9 +704,17 @@ where
         Self {
             store: EffectStore::new(state, reducer),
             middleware,
+            action_tx: None,
         }
     }
 
+    /// Set the sender passed to [`Middleware::follow_up`].
+    ///
+    /// `EffectRuntime` calls this automatically with its action queue.
+    pub fn set_action_sender(&mut self, action_tx: mpsc::UnboundedSender<A>) {
+        self.action_tx = Some(action_tx);
+    }
+
     /// Get a reference to the current state.
     #[inline]
     pub fn state(&self) -> &S {
@@ -302,6 +727,49 @@ where
         self.store.state_mut()
     }
 
+    /// Get the state version.
+    #[inline]
+    pub fn version(&self) -> u64 {
+        self.store.version()
+    }
+
+    /// Compute derived data with a memoized selector.
+    #[inline]
+    pub fn select<'s, T>(&self, selector: &'s mut Selector<S, T>) -> &'s T {
+        self.store.select(selector)
+    }
+
+    /// Serialize the state to JSON (see [`EffectStore::export_snapshot`]).
+    pub fn export_snapshot(&self) -> serde_json::Result<String>
+    where
+        S: Serialize,
+    {
+        self.store.export_snapshot()
+    }
+
+    /// Replace the state from JSON (see [`EffectStore::import_snapshot`]).
+    pub fn import_snapshot(&mut self, json: &str) -> serde_json::Result<()>
+    where
+        S: DeserializeOwned,
+    {
+        self.store.import_snapshot(json)
+    }
+
+    /// Observe dispatched actions (see [`EffectStore::subscribe`]).
+    pub fn subscribe(&mut self, listener: impl FnMut(&A, &S, bool) + 'static) -> ListenerId {
+        self.store.subscribe(listener)
+    }
+
+    /// Remove a listener added with [`subscribe`](Self::subscribe).
+    pub fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        self.store.unsubscribe(id)
+    }
+
+    /// Number of registered listeners.
+    pub fn listener_count(&self) -> usize {
+        self.store.listener_count()
+    }
+
     /// Get a reference to the middleware.
     #[inline]
     pub fn middleware(&self) -> &M {
@@ -316,12 +784,47 @@ where
 
     /// Dispatch an action through middleware and store.
     ///
-    /// Calls `middleware.before()`, then `store.dispatch()`,
-    /// then `middleware.after()` with the state change indicator.
+    /// Calls `middleware.intercept()`, then `middleware.before()`,
+    /// `store.dispatch()` and `middleware.after()` with the state change
+    /// indicator, and finally `middleware.follow_up()` if a sender is set.
+    /// Swallowed actions return an unchanged result with no effects.
     pub fn dispatch(&mut self, action: A) -> DispatchResult<E> {
+        let Some(action) = self.middleware.intercept(action) else {
+            return DispatchResult::unchanged();
+        };
         self.middleware.before(&action);
         let result = self.store.dispatch(action.clone());
         self.middleware.after(&action, result.changed);
+        if let Some(action_tx) = &self.action_tx {
+            self.middleware
+                .follow_up(&action, result.changed, action_tx);
+        }
+        result
+    }
+
+    /// Dispatch several actions as one transaction.
+    ///
+    /// Middleware is notified once with the whole batch via
+    /// `middleware.before_batch()` / `middleware.after_batch()`.
+    pub fn dispatch_all(&mut self, actions: impl IntoIterator<Item = A>) -> DispatchResult<E> {
+        let actions: Vec<A> = actions
+            .into_iter()
+            .filter_map(|action| self.middleware.intercept(action))
+            .collect();
+        if actions.is_empty() {
+            return DispatchResult::unchanged();
+        }
+        self.middleware.before_batch(&actions);
+        let mut changes = Vec::with_capacity(actions.len());
+        let result = self
+            .store
+            .dispatch_batch(actions.iter().cloned(), |changed| changes.push(changed));
+        self.middleware.after_batch(&actions, &changes);
+        if let Some(action_tx) = &self.action_tx {
+            for (action, &changed) in actions.iter().zip(&changes) {
+                self.middleware.follow_up(action, changed, action_tx);
+            }
+        }
         result
     }
 }
@@ -329,6 +832,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::event::NumericComponentId;
 
     #[derive(Clone, Debug)]
     enum TestAction {
@@ -440,6 +944,31 @@ mod tests {
         assert_eq!(result.effects.len(), 2);
     }
 
+    #[test]
+    fn test_effect_store_dispatch_all() {
+        let mut store = EffectStore::new(TestState::default(), test_reducer);
+
+        let result = store.dispatch_all([
+            TestAction::Increment,
+            TestAction::TriggerEffect,
+            TestAction::Decrement,
+        ]);
+        assert!(result.changed);
+        assert_eq!(
+            result.effects,
+            vec![
+                TestEffect::Log("triggered".into()),
+                TestEffect::Save,
+                TestEffect::Log("count: 0".into()),
+            ]
+        );
+        assert_eq!(store.version(), 1);
+
+        let result = store.dispatch_all([TestAction::NoOp]);
+        assert!(!result.changed);
+        assert_eq!(store.version(), 1);
+    }
+
     #[test]
     fn test_effect_store_state_mut() {
         let mut store = EffectStore::new(TestState::default(), test_reducer);
@@ -447,6 +976,41 @@ mod tests {
         assert_eq!(store.state().count, 100);
     }
 
+    #[test]
+    fn test_effect_store_subscribe() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let mut store = EffectStoreWithMiddleware::new(
+            TestState::default(),
+            test_reducer,
+            crate::NoopMiddleware,
+        );
+
+        let recorder = seen.clone();
+        let id = store.subscribe(move |action: &TestAction, state: &TestState, changed| {
+            recorder
+                .borrow_mut()
+                .push((action.name(), state.count, changed));
+        });
+
+        store.dispatch(TestAction::Increment);
+        store.dispatch_all([TestAction::TriggerEffect, TestAction::Decrement]);
+        assert!(store.unsubscribe(id));
+        store.dispatch(TestAction::Increment);
+
+        assert_eq!(store.listener_count(), 0);
+        assert_eq!(
+            *seen.borrow(),
+            vec![
+                ("Increment", 1, true),
+                ("TriggerEffect", 1, false),
+                ("Decrement", 0, true),
+            ]
+        );
+    }
+
     #[test]
     fn test_has_effects() {
         let r: DispatchResult<TestEffect> = DispatchResult::unchanged();
@@ -455,4 +1019,167 @@ mod tests {
         let r = DispatchResult::effect(TestEffect::Save);
         assert!(r.has_effects());
     }
+
+    /// Swallows `TriggerEffect` and follows every change with `NoOp`.
+    struct GateMiddleware;
+
+    impl Middleware<TestAction> for GateMiddleware {
+        fn intercept(&mut self, action: TestAction) -> Option<TestAction> {
+            match action {
+                TestAction::TriggerEffect => None,
+                action => Some(action),
+            }
+        }
+
+        fn before(&mut self, _action: &TestAction) {}
+
+        fn after(&mut self, _action: &TestAction, _state_changed: bool) {}
+
+        fn follow_up(
+            &mut self,
+            _action: &TestAction,
+            state_changed: bool,
+            action_tx: &mpsc::UnboundedSender<TestAction>,
+        ) {
+            if state_changed {
+                let _ = action_tx.send(TestAction::NoOp);
+            }
+        }
+    }
+
+    #[test]
+    fn test_middleware_intercept_and_follow_up() {
+        let mut store =
+            EffectStoreWithMiddleware::new(TestState::default(), test_reducer, GateMiddleware);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        store.set_action_sender(tx);
+
+        let result = store.dispatch(TestAction::TriggerEffect);
+        assert!(!result.changed);
+        assert!(result.effects.is_empty());
+        assert!(rx.try_recv().is_err());
+
+        assert!(store.dispatch(TestAction::Increment).changed);
+        assert!(matches!(rx.try_recv(), Ok(TestAction::NoOp)));
+    }
+
+    #[derive(Default)]
+    struct SplitState {
+        a: TestState,
+        b: TestState,
+    }
+
+    crate::combine_reducers! {
+        fn split_reducer(SplitState, TestAction) -> DispatchResult<TestEffect> {
+            a: test_reducer,
+            b: test_reducer,
+        }
+    }
+
+    #[test]
+    fn test_combine_effect_reducers() {
+        let mut store = EffectStore::new(SplitState::default(), split_reducer);
+
+        let result = store.dispatch(TestAction::Decrement);
+        assert!(result.changed);
+        assert_eq!(
+            result.effects,
+            vec![
+                TestEffect::Log("count: -1".into()),
+                TestEffect::Log("count: -1".into()),
+            ]
+        );
+        assert_eq!(store.state().a.count, -1);
+        assert_eq!(store.state().b.count, -1);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum ParentEffect {
+        Child(TestEffect),
+        Refresh,
+    }
+
+    #[test]
+    fn test_cmd_combinators() {
+        let cmd: Cmd<TestEffect> = Cmd::none();
+        assert!(cmd.is_empty());
+
+        let cmd = Cmd::batch([
+            Cmd::of(TestEffect::Save),
+            Cmd::none(),
+            Cmd::from(vec![TestEffect::Log("a".into()), TestEffect::Save]),
+        ]);
+        assert_eq!(
+            cmd,
+            vec![
+                TestEffect::Save,
+                TestEffect::Log("a".into()),
+                TestEffect::Save
+            ]
+        );
+
+        let mapped = cmd.map(ParentEffect::Child);
+        assert_eq!(mapped.len(), 3);
+        assert_eq!(mapped[0], ParentEffect::Child(TestEffect::Save));
+    }
+
+    #[test]
+    fn test_dispatch_result_map_and_with_cmd() {
+        let child = test_reducer(&mut TestState::default(), TestAction::Decrement);
+        let result = child
+            .map(ParentEffect::Child)
+            .with_cmd(Cmd::of(ParentEffect::Refresh));
+        assert!(result.changed);
+        assert_eq!(
+            result.effects,
+            vec![
+                ParentEffect::Child(TestEffect::Log("count: -1".into())),
+                ParentEffect::Refresh,
+            ]
+        );
+
+        let result: DispatchResult<TestEffect> = DispatchResult::cmd(Cmd::none());
+        assert!(!result.changed);
+        assert!(!result.has_effects());
+    }
+
+    #[test]
+    fn test_dirty_components() {
+        let dirty: DirtyComponents = [NumericComponentId(1), NumericComponentId(2)]
+            .into_iter()
+            .collect();
+        assert!(dirty.contains(NumericComponentId(1)));
+        assert!(dirty.contains(NumericComponentId(2)));
+        assert!(!dirty.is_all());
+
+        assert!(DirtyComponents::none().is_empty());
+        assert!(DirtyComponents::all().contains(NumericComponentId(7)));
+        assert_eq!(DirtyComponents::default(), DirtyComponents::all());
+    }
+
+    #[test]
+    fn test_dispatch_result_touching_and_merge() {
+        let unchanged = DispatchResult::<TestEffect>::unchanged().touching([NumericComponentId(1)]);
+        assert!(unchanged.dirty().is_empty());
+
+        let hinted = DispatchResult::<TestEffect>::changed().touching([NumericComponentId(1)]);
+        assert!(hinted.dirty().contains(NumericComponentId(1)));
+        assert!(!hinted.dirty().is_all());
+
+        let untouched = DispatchResult::<TestEffect>::changed().touching::<NumericComponentId>([]);
+        assert!(untouched.changed);
+        assert!(untouched.dirty().is_empty());
+
+        let mut combined = DispatchResult::unchanged();
+        combined.merge(hinted);
+        combined.merge(DispatchResult::effect(TestEffect::Save));
+        assert!(combined.changed);
+        assert!(combined.dirty().contains(NumericComponentId(1)));
+        assert!(!combined.dirty().is_all());
+        assert_eq!(combined.effects, vec![TestEffect::Save]);
+
+        // A change without hints touches everything
+        combined.merge(DispatchResult::changed());
+        assert!(combined.dirty().is_all());
+    }
 }
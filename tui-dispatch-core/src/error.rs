@@ -0,0 +1,70 @@
+//! Crate-wide error type
+//!
+//! Fallible APIs return their own error types ([`KeyParseError`],
+//! [`KeybindingsError`], [`PersistError`], ...). [`Error`] wraps them, so an
+//! app can use `?` on any of them in a function returning
+//! [`error::Result`](Result):
+//!
+//! ```ignore
+//! use tui_dispatch::error::Result;
+//!
+//! fn setup(config: &Config) -> Result<(Keybindings<Context>, KeyEvent)> {
+//!     let bindings = Keybindings::load_with_defaults(&config.keys_path, defaults())?;
+//!     let leader = parse_key_string(&config.leader)?;
+//!     enable_bracketed_paste()?;
+//!     Ok((bindings, leader))
+//! }
+//! ```
+
+use std::io;
+
+use tokio::task::JoinError;
+
+use crate::keybindings::{KeyParseError, KeybindingsError};
+use crate::persistence::PersistError;
+
+/// Result with [`Error`] as the default error type
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Any error returned by tui-dispatch
+///
+/// New variants may be added in minor releases.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// A key string could not be parsed
+    #[error("{0}")]
+    KeyParse(#[from] KeyParseError),
+    /// Keybindings could not be loaded or validated
+    #[error("{0}")]
+    KeybindingConfig(#[from] KeybindingsError),
+    /// Terminal setup, restore or I/O failed
+    #[error("Terminal error: {0}")]
+    Terminal(#[from] io::Error),
+    /// A spawned task panicked or was cancelled before returning
+    #[error("Task failed: {0}")]
+    TaskJoin(#[from] JoinError),
+    /// State could not be saved or restored
+    #[error("{0}")]
+    Persistence(#[from] PersistError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keybindings::parse_key_string;
+
+    fn parse(key: &str) -> Result<crossterm::event::KeyEvent> {
+        Ok(parse_key_string(key)?)
+    }
+
+    #[test]
+    fn test_question_mark_converts_errors() {
+        assert!(parse("ctrl+p").is_ok());
+
+        let err = parse("ctrl+nope").unwrap_err();
+        assert!(matches!(err, Error::KeyParse(KeyParseError::UnknownKey(_))));
+        assert_eq!(err.to_string(), "Unknown key \"ctrl+nope\"");
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
@@ -58,27 +58,43 @@ pub enum EventType {
 /// The actual event payload
 #[derive(Debug, Clone)]
 pub enum EventKind {
-    /// Keyboard event
+    /// Keyboard event (key press, or repeat with keyboard enhancement)
     Key(KeyEvent),
+    /// Key release (requires keyboard enhancement, or Windows)
+    ///
+    /// See [`enable_keyboard_enhancement`](crate::bus::enable_keyboard_enhancement).
+    KeyRelease(KeyEvent),
     /// Mouse event
     Mouse(MouseEvent),
     /// Scroll event with position and delta
     Scroll { column: u16, row: u16, delta: isize },
     /// Terminal resize
     Resize(u16, u16),
+    /// Pasted text (bracketed paste or IME commit), delivered as one event
+    Paste(String),
     /// Periodic tick
     Tick,
+    /// The terminal window gained focus (requires focus change reporting)
+    FocusGained,
+    /// The terminal window lost focus (requires focus change reporting)
+    FocusLost,
 }
 
 impl EventKind {
     /// Get the event type for this event kind
+    ///
+    /// Pastes and key releases count as keyboard input, so [`EventType::Key`]
+    /// subscribers get them.
+    /// Focus changes concern the whole app and go to [`EventType::Global`].
     pub fn event_type(&self) -> EventType {
         match self {
-            EventKind::Key(_) => EventType::Key,
+            EventKind::Key(_) | EventKind::KeyRelease(_) => EventType::Key,
             EventKind::Mouse(_) => EventType::Mouse,
             EventKind::Scroll { .. } => EventType::Scroll,
             EventKind::Resize(_, _) => EventType::Resize,
+            EventKind::Paste(_) => EventType::Key,
             EventKind::Tick => EventType::Tick,
+            EventKind::FocusGained | EventKind::FocusLost => EventType::Global,
         }
     }
 
@@ -91,7 +107,7 @@ impl EventKind {
                     || (key.modifiers.contains(KeyModifiers::CONTROL)
                         && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('q')))
             }
-            EventKind::Resize(_, _) => true,
+            EventKind::Resize(_, _) | EventKind::FocusGained | EventKind::FocusLost => true,
             _ => false,
         }
     }
@@ -2,21 +2,28 @@
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
+use std::path::{Path, PathBuf};
 
 /// Trait for user-defined keybinding contexts
 ///
 /// Implement this trait for your own context enum, or use `#[derive(BindingContext)]`
 /// from `tui-dispatch-macros` to auto-generate the implementation.
 ///
+/// Contexts can inherit bindings from a parent context: lookups walk the
+/// chain from the context up through its ancestors before falling back to
+/// global bindings.
+///
 /// # Example
 /// ```ignore
 /// #[derive(BindingContext, Clone, Copy, PartialEq, Eq, Hash)]
 /// pub enum MyContext {
 ///     Default,
-///     Search,
+///     #[binding(parent = Default)]
 ///     Modal,
+///     #[binding(parent = Modal)]
+///     SearchModal,
 /// }
 /// ```
 pub trait BindingContext: Clone + Copy + Eq + Hash {
@@ -28,6 +35,27 @@ pub trait BindingContext: Clone + Copy + Eq + Hash {
 
     /// Get all possible context values (for iteration/config loading)
     fn all() -> &'static [Self];
+
+    /// Context this one inherits bindings from
+    fn parent(&self) -> Option<Self> {
+        None
+    }
+}
+
+/// A context followed by its ancestors, nearest first
+///
+/// Stops at the first repeated context so a cyclic parent chain can't loop.
+fn context_chain<C: BindingContext>(context: C) -> Vec<C> {
+    let mut chain = vec![context];
+    let mut current = context;
+    while let Some(parent) = current.parent() {
+        if chain.contains(&parent) {
+            break;
+        }
+        chain.push(parent);
+        current = parent;
+    }
+    chain
 }
 
 /// Keybindings configuration with context support
@@ -127,12 +155,15 @@ impl<C: BindingContext> Keybindings<C> {
 
     /// Get command name for a key event in the given context
     ///
-    /// First checks context-specific bindings, then falls back to global
+    /// First checks the context and its ancestors (see
+    /// [`BindingContext::parent`]), then falls back to global
     pub fn get_command(&self, key: KeyEvent, context: C) -> Option<String> {
-        // First try context-specific bindings
-        if let Some(context_bindings) = self.contexts.get(&context) {
-            if let Some(cmd) = self.match_key_in_bindings(key, context_bindings) {
-                return Some(cmd);
+        // First try the context, then each parent in turn
+        for context in context_chain(context) {
+            if let Some(context_bindings) = self.contexts.get(&context) {
+                if let Some(cmd) = self.match_key_in_bindings(key, context_bindings) {
+                    return Some(cmd);
+                }
             }
         }
 
@@ -140,6 +171,11 @@ impl<C: BindingContext> Keybindings<C> {
         self.match_key_in_bindings(key, &self.global)
     }
 
+    /// Get the global command for a key event, ignoring contexts
+    pub fn get_global_command(&self, key: KeyEvent) -> Option<String> {
+        self.match_key_in_bindings(key, &self.global)
+    }
+
     /// Helper to match a key against a set of bindings
     fn match_key_in_bindings(
         &self,
@@ -148,7 +184,7 @@ impl<C: BindingContext> Keybindings<C> {
     ) -> Option<String> {
         for (command, keys) in bindings {
             for key_str in keys {
-                if let Some(parsed_key) = parse_key_string(key_str) {
+                if let Ok(parsed_key) = parse_key_string(key_str) {
                     // Compare code and modifiers (ignore kind and state)
                     // For character keys, compare case-insensitively
                     let codes_match = match (&parsed_key.code, &key.code) {
@@ -167,14 +203,58 @@ impl<C: BindingContext> Keybindings<C> {
         None
     }
 
+    /// Get every command bound to a key in the given context
+    ///
+    /// Context-specific commands come first, then those inherited from each
+    /// ancestor, then global ones; commands already listed are skipped and
+    /// each group is sorted. When the key is not ambiguous, the first entry is
+    /// what [`get_command`](Self::get_command) returns.
+    pub fn commands_for_key(&self, key: KeyEvent, context: C) -> Vec<String> {
+        let layers = context_chain(context)
+            .into_iter()
+            .filter_map(|context| self.contexts.get(&context))
+            .chain(std::iter::once(&self.global));
+
+        let mut commands = Vec::new();
+        for bindings in layers {
+            for command in matching_commands(key, bindings) {
+                if !commands.contains(&command) {
+                    commands.push(command);
+                }
+            }
+        }
+        commands
+    }
+
+    /// List the expected commands that have no key in any context
+    ///
+    /// A command bound to an empty key list counts as unbound. Results keep
+    /// the order of `expected`.
+    pub fn unbound_commands(&self, expected: &[&str]) -> Vec<String> {
+        expected
+            .iter()
+            .filter(|command| !self.is_bound(command))
+            .map(|command| command.to_string())
+            .collect()
+    }
+
+    /// Whether a command has at least one key in any context
+    fn is_bound(&self, command: &str) -> bool {
+        std::iter::once(&self.global)
+            .chain(self.contexts.values())
+            .any(|bindings| bindings.get(command).is_some_and(|keys| !keys.is_empty()))
+    }
+
     /// Get the first keybinding string for a command in the given context
     ///
-    /// First checks context-specific bindings, then falls back to global
+    /// First checks the context and its ancestors, then falls back to global
     pub fn get_first_keybinding(&self, command: &str, context: C) -> Option<String> {
-        if let Some(context_bindings) = self.contexts.get(&context) {
-            if let Some(keys) = context_bindings.get(command) {
-                if let Some(first) = keys.first() {
-                    return Some(first.clone());
+        for context in context_chain(context) {
+            if let Some(context_bindings) = self.contexts.get(&context) {
+                if let Some(keys) = context_bindings.get(command) {
+                    if let Some(first) = keys.first() {
+                        return Some(first.clone());
+                    }
                 }
             }
         }
@@ -203,17 +283,364 @@ impl<C: BindingContext> Keybindings<C> {
     }
 }
 
+// ============================================================================
+// Config file loading and validation
+// ============================================================================
+
+/// Config file format for keybindings
+///
+/// Parsing each format requires the matching crate feature (`toml` / `yaml`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeybindingsFormat {
+    /// TOML (`.toml`)
+    Toml,
+    /// YAML (`.yaml` / `.yml`)
+    Yaml,
+}
+
+impl KeybindingsFormat {
+    /// Detect the format from a file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Name of the crate feature that enables this format
+    pub fn feature(self) -> &'static str {
+        match self {
+            Self::Toml => "toml",
+            Self::Yaml => "yaml",
+        }
+    }
+}
+
+/// Two or more commands bound to the same key within one context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeybindingConflict {
+    /// Context name (`"global"` for global bindings)
+    pub context: String,
+    /// The key string as written in the config (first occurrence)
+    pub key: String,
+    /// Commands bound to this key (sorted)
+    pub commands: Vec<String>,
+}
+
+impl std::fmt::Display for KeybindingConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "key \"{}\" is bound to multiple commands in [{}]: {}",
+            self.key,
+            self.context,
+            self.commands.join(", ")
+        )
+    }
+}
+
+/// Error loading keybindings from a config file
+#[derive(Debug)]
+pub enum KeybindingsError {
+    /// Failed to read the config file
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// File extension is not a supported format
+    UnsupportedFormat(PathBuf),
+    /// Format is supported but its crate feature is not enabled
+    FormatDisabled(KeybindingsFormat),
+    /// Config contents could not be parsed
+    Parse(String),
+    /// Config references a context that doesn't exist
+    UnknownContext(String),
+    /// A key string could not be parsed
+    InvalidKey {
+        context: String,
+        command: String,
+        key: String,
+    },
+    /// The same key is bound to several commands in one context
+    Conflict(KeybindingConflict),
+}
+
+impl std::fmt::Display for KeybindingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeybindingsError::Io { path, source } => {
+                write!(f, "Failed to read {}: {}", path.display(), source)
+            }
+            KeybindingsError::UnsupportedFormat(path) => {
+                write!(f, "Unsupported keybindings format: {}", path.display())
+            }
+            KeybindingsError::FormatDisabled(format) => write!(
+                f,
+                "Loading {:?} keybindings requires the `{}` feature",
+                format,
+                format.feature()
+            ),
+            KeybindingsError::Parse(msg) => write!(f, "Failed to parse keybindings: {}", msg),
+            KeybindingsError::UnknownContext(name) => {
+                write!(f, "Unknown keybinding context: [{}]", name)
+            }
+            KeybindingsError::InvalidKey {
+                context,
+                command,
+                key,
+            } => write!(
+                f,
+                "Invalid key \"{}\" for command \"{}\" in [{}]",
+                key, command, context
+            ),
+            KeybindingsError::Conflict(conflict) => {
+                write!(f, "Conflicting keybinding: {}", conflict)
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeybindingsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KeybindingsError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Error parsing a key string with [`parse_key_string`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyParseError {
+    /// The key string is empty
+    Empty,
+    /// A modifier other than ctrl, shift or alt
+    UnknownModifier { key: String, modifier: String },
+    /// The key name is not recognized
+    UnknownKey(String),
+}
+
+impl std::fmt::Display for KeyParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyParseError::Empty => write!(f, "Empty key string"),
+            KeyParseError::UnknownModifier { key, modifier } => write!(
+                f,
+                "Unknown modifier \"{}\" in \"{}\" (expected ctrl, shift or alt)",
+                modifier, key
+            ),
+            KeyParseError::UnknownKey(key) => write!(f, "Unknown key \"{}\"", key),
+        }
+    }
+}
+
+impl std::error::Error for KeyParseError {}
+
+/// Raw config layout: context name -> command -> key strings
+type RawBindings = HashMap<String, HashMap<String, Vec<String>>>;
+
+/// Normalize a parsed key for comparison (matches `get_command` semantics)
+fn normalize_key(key: KeyEvent) -> (KeyCode, KeyModifiers) {
+    let code = match key.code {
+        KeyCode::Char(c) => KeyCode::Char(c.to_lowercase().next().unwrap_or(c)),
+        code => code,
+    };
+    (code, key.modifiers)
+}
+
+/// Commands in one set of bindings that a key triggers (sorted)
+fn matching_commands(key: KeyEvent, bindings: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let key = normalize_key(key);
+    let mut commands: Vec<String> = bindings
+        .iter()
+        .filter(|(_, keys)| {
+            keys.iter()
+                .filter_map(|key_str| parse_key_string(key_str).ok())
+                .any(|parsed| normalize_key(parsed) == key)
+        })
+        .map(|(command, _)| command.clone())
+        .collect();
+    commands.sort();
+    commands
+}
+
+/// Find keys bound to multiple commands within one set of bindings
+fn find_conflicts(
+    context: &str,
+    bindings: &HashMap<String, Vec<String>>,
+) -> Vec<KeybindingConflict> {
+    let mut commands: Vec<&String> = bindings.keys().collect();
+    commands.sort();
+
+    // (code, modifiers) can't be ordered, so group by Debug repr for stable output
+    let mut by_key: BTreeMap<String, (String, Vec<String>)> = BTreeMap::new();
+    for command in commands {
+        for key_str in &bindings[command] {
+            let Ok(parsed) = parse_key_string(key_str) else {
+                continue;
+            };
+            let entry = by_key
+                .entry(format!("{:?}", normalize_key(parsed)))
+                .or_insert_with(|| (key_str.clone(), Vec::new()));
+            if !entry.1.contains(command) {
+                entry.1.push(command.clone());
+            }
+        }
+    }
+
+    by_key
+        .into_values()
+        .filter(|(_, commands)| commands.len() > 1)
+        .map(|(key, commands)| KeybindingConflict {
+            context: context.to_string(),
+            key,
+            commands,
+        })
+        .collect()
+}
+
+impl<C: BindingContext> Keybindings<C> {
+    /// Load keybindings from a TOML or YAML file
+    ///
+    /// The format is chosen from the file extension. The file is validated
+    /// strictly: unknown contexts, unparseable key strings and keys bound to
+    /// several commands in the same context are all errors.
+    ///
+    /// ```toml
+    /// [global]
+    /// quit = ["q", "ctrl+c"]
+    ///
+    /// [search]
+    /// clear = ["esc"]
+    /// ```
+    pub fn load_from_path(path: impl AsRef<Path>) -> Result<Self, KeybindingsError> {
+        let path = path.as_ref();
+        let format = KeybindingsFormat::from_path(path)
+            .ok_or_else(|| KeybindingsError::UnsupportedFormat(path.to_path_buf()))?;
+        let contents = std::fs::read_to_string(path).map_err(|source| KeybindingsError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_str_with_format(&contents, format)
+    }
+
+    /// Load user keybindings from a file and merge them onto defaults
+    ///
+    /// Returns `defaults` unchanged if the file doesn't exist. The merged
+    /// result is checked for conflicts, so rebinding a key that a default
+    /// command still uses is reported as an error.
+    pub fn load_with_defaults(
+        path: impl AsRef<Path>,
+        defaults: Self,
+    ) -> Result<Self, KeybindingsError> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(defaults);
+        }
+
+        let merged = Self::merge(defaults, Self::load_from_path(path)?);
+        match merged.conflicts().into_iter().next() {
+            Some(conflict) => Err(KeybindingsError::Conflict(conflict)),
+            None => Ok(merged),
+        }
+    }
+
+    /// Parse keybindings from a string in the given format
+    ///
+    /// Applies the same validation as [`Keybindings::load_from_path`].
+    pub fn from_str_with_format(
+        contents: &str,
+        format: KeybindingsFormat,
+    ) -> Result<Self, KeybindingsError> {
+        let raw: RawBindings = match format {
+            #[cfg(feature = "toml")]
+            KeybindingsFormat::Toml => {
+                toml::from_str(contents).map_err(|e| KeybindingsError::Parse(e.to_string()))?
+            }
+            #[cfg(feature = "yaml")]
+            KeybindingsFormat::Yaml => serde_norway::from_str(contents)
+                .map_err(|e| KeybindingsError::Parse(e.to_string()))?,
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = contents;
+                return Err(KeybindingsError::FormatDisabled(format));
+            }
+        };
+        Self::from_raw(raw)
+    }
+
+    /// Build keybindings from raw config, validating everything
+    fn from_raw(raw: RawBindings) -> Result<Self, KeybindingsError> {
+        let mut keybindings = Self::new();
+
+        // Sort for deterministic error reporting
+        let mut raw: Vec<_> = raw.into_iter().collect();
+        raw.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (context_name, bindings) in raw {
+            let mut commands: Vec<_> = bindings.iter().collect();
+            commands.sort_by(|a, b| a.0.cmp(b.0));
+            for (command, keys) in commands {
+                if let Some(key) = keys.iter().find(|k| parse_key_string(k).is_err()) {
+                    return Err(KeybindingsError::InvalidKey {
+                        context: context_name.clone(),
+                        command: command.clone(),
+                        key: key.clone(),
+                    });
+                }
+            }
+
+            if let Some(conflict) = find_conflicts(&context_name, &bindings).into_iter().next() {
+                return Err(KeybindingsError::Conflict(conflict));
+            }
+
+            if context_name == "global" {
+                keybindings.global = bindings;
+            } else if let Some(context) = C::from_name(&context_name) {
+                keybindings.contexts.insert(context, bindings);
+            } else {
+                return Err(KeybindingsError::UnknownContext(context_name));
+            }
+        }
+
+        Ok(keybindings)
+    }
+
+    /// Report keys bound to multiple commands, per context
+    ///
+    /// Global bindings are reported under `"global"`, followed by contexts
+    /// in [`BindingContext::all`] order. A context binding that shadows a
+    /// global one is an intentional override and is not reported.
+    pub fn conflicts(&self) -> Vec<KeybindingConflict> {
+        let mut conflicts = find_conflicts("global", &self.global);
+        for context in C::all() {
+            if let Some(bindings) = self.contexts.get(context) {
+                conflicts.extend(find_conflicts(context.name(), bindings));
+            }
+        }
+        conflicts
+    }
+
+    /// Report keys bound to multiple commands (same as [`conflicts`](Self::conflicts))
+    pub fn validate(&self) -> Vec<KeybindingConflict> {
+        self.conflicts()
+    }
+}
+
 /// Parse a key string like "q", "esc", "ctrl+p", "shift+tab" into a KeyEvent
-pub fn parse_key_string(key_str: &str) -> Option<KeyEvent> {
+///
+/// Returns a [`KeyParseError`] naming the part that could not be parsed.
+pub fn parse_key_string(key_str: &str) -> Result<KeyEvent, KeyParseError> {
     let key_str = key_str.trim().to_lowercase();
 
     if key_str.is_empty() {
-        return None;
+        return Err(KeyParseError::Empty);
     }
 
     // Special case: shift+tab should be BackTab
     if key_str == "shift+tab" || key_str == "backtab" {
-        return Some(KeyEvent {
+        return Ok(KeyEvent {
             code: KeyCode::BackTab,
             modifiers: KeyModifiers::SHIFT,
             kind: crossterm::event::KeyEventKind::Press,
@@ -224,7 +651,7 @@ pub fn parse_key_string(key_str: &str) -> Option<KeyEvent> {
     // Check for modifiers
     let parts: Vec<&str> = key_str.split('+').collect();
     let mut modifiers = KeyModifiers::empty();
-    let key_part = parts.last()?.trim();
+    let key_part = parts[parts.len() - 1].trim();
 
     if parts.len() > 1 {
         for part in &parts[..parts.len() - 1] {
@@ -232,7 +659,12 @@ pub fn parse_key_string(key_str: &str) -> Option<KeyEvent> {
                 "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
                 "shift" => modifiers |= KeyModifiers::SHIFT,
                 "alt" => modifiers |= KeyModifiers::ALT,
-                _ => {}
+                modifier => {
+                    return Err(KeyParseError::UnknownModifier {
+                        key: key_str.clone(),
+                        modifier: modifier.to_string(),
+                    })
+                }
             }
         }
     }
@@ -273,14 +705,11 @@ pub fn parse_key_string(key_str: &str) -> Option<KeyEvent> {
         "f12" => KeyCode::F(12),
         "space" => KeyCode::Char(' '),
         // Single character
-        c if c.len() == 1 => {
-            let ch = c.chars().next()?;
-            KeyCode::Char(ch)
-        }
-        _ => return None,
+        c if c.len() == 1 => KeyCode::Char(c.chars().next().unwrap_or_default()),
+        _ => return Err(KeyParseError::UnknownKey(key_str.clone())),
     };
 
-    Some(KeyEvent {
+    Ok(KeyEvent {
         code,
         modifiers,
         kind: crossterm::event::KeyEventKind::Press,
@@ -396,6 +825,44 @@ mod tests {
         }
     }
 
+    // Layered contexts: SearchModal -> Modal -> Base
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    enum LayeredContext {
+        Base,
+        Modal,
+        SearchModal,
+    }
+
+    impl BindingContext for LayeredContext {
+        fn name(&self) -> &'static str {
+            match self {
+                LayeredContext::Base => "base",
+                LayeredContext::Modal => "modal",
+                LayeredContext::SearchModal => "search_modal",
+            }
+        }
+
+        fn from_name(name: &str) -> Option<Self> {
+            Self::all().iter().copied().find(|c| c.name() == name)
+        }
+
+        fn all() -> &'static [Self] {
+            &[
+                LayeredContext::Base,
+                LayeredContext::Modal,
+                LayeredContext::SearchModal,
+            ]
+        }
+
+        fn parent(&self) -> Option<Self> {
+            match self {
+                LayeredContext::Base => None,
+                LayeredContext::Modal => Some(LayeredContext::Base),
+                LayeredContext::SearchModal => Some(LayeredContext::Modal),
+            }
+        }
+    }
+
     #[test]
     fn test_parse_simple_key() {
         let result = parse_key_string("q").unwrap();
@@ -430,6 +897,22 @@ mod tests {
         assert!(result.modifiers.contains(KeyModifiers::SHIFT));
     }
 
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_key_string("  "), Err(KeyParseError::Empty));
+        assert_eq!(
+            parse_key_string("hyper+x"),
+            Err(KeyParseError::UnknownModifier {
+                key: "hyper+x".into(),
+                modifier: "hyper".into(),
+            })
+        );
+        assert_eq!(
+            parse_key_string("ctrl+nope"),
+            Err(KeyParseError::UnknownKey("ctrl+nope".into()))
+        );
+    }
+
     #[test]
     fn test_parse_arrow_keys() {
         let result = parse_key_string("up").unwrap();
@@ -501,6 +984,226 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_reports_conflicts() {
+        let mut bindings: Keybindings<TestContext> = Keybindings::new();
+        bindings.add_global("quit", vec!["q".to_string()]);
+        bindings.add_global("query", vec!["Q".to_string()]);
+        bindings.add(TestContext::Search, "clear", vec!["esc".to_string()]);
+        bindings.add(TestContext::Search, "close", vec!["esc".to_string()]);
+        bindings.add(TestContext::Search, "next", vec!["n".to_string()]);
+
+        let conflicts = bindings.validate();
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].context, "global");
+        assert_eq!(conflicts[0].commands, vec!["query", "quit"]);
+        assert_eq!(conflicts[1].context, "search");
+        assert_eq!(conflicts[1].commands, vec!["clear", "close"]);
+    }
+
+    #[test]
+    fn test_validate_allows_context_override() {
+        let mut bindings: Keybindings<TestContext> = Keybindings::new();
+        bindings.add_global("quit", vec!["q".to_string()]);
+        bindings.add(TestContext::Search, "type_q", vec!["q".to_string()]);
+
+        assert!(bindings.validate().is_empty());
+    }
+
+    #[test]
+    fn test_conflicts_reports_duplicates() {
+        let mut bindings: Keybindings<TestContext> = Keybindings::new();
+        bindings.add_global("quit", vec!["q".to_string()]);
+        bindings.add_global("query", vec!["Q".to_string()]);
+        bindings.add(TestContext::Search, "clear", vec!["esc".to_string()]);
+        bindings.add(TestContext::Search, "close", vec!["esc".to_string()]);
+        bindings.add(TestContext::Search, "next", vec!["n".to_string()]);
+
+        let conflicts = bindings.conflicts();
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].context, "global");
+        assert_eq!(conflicts[0].commands, vec!["query", "quit"]);
+        assert_eq!(conflicts[1].context, "search");
+        assert_eq!(conflicts[1].commands, vec!["clear", "close"]);
+    }
+
+    #[test]
+    fn test_conflicts_allows_context_override() {
+        let mut bindings: Keybindings<TestContext> = Keybindings::new();
+        bindings.add_global("quit", vec!["q".to_string()]);
+        bindings.add(TestContext::Search, "type_q", vec!["q".to_string()]);
+
+        assert!(bindings.conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_commands_for_key() {
+        let mut bindings: Keybindings<TestContext> = Keybindings::new();
+        bindings.add_global("quit", vec!["q".to_string(), "esc".to_string()]);
+        bindings.add_global("query", vec!["Q".to_string()]);
+        bindings.add(TestContext::Search, "close", vec!["esc".to_string()]);
+        bindings.add(TestContext::Search, "clear", vec!["esc".to_string()]);
+
+        let esc = parse_key_string("esc").unwrap();
+        assert_eq!(
+            bindings.commands_for_key(esc, TestContext::Search),
+            vec!["clear", "close", "quit"]
+        );
+        assert_eq!(
+            bindings.commands_for_key(esc, TestContext::Default),
+            vec!["quit"]
+        );
+
+        let q = parse_key_string("q").unwrap();
+        assert_eq!(
+            bindings.commands_for_key(q, TestContext::Default),
+            vec!["query", "quit"]
+        );
+        assert!(bindings
+            .commands_for_key(parse_key_string("x").unwrap(), TestContext::Default)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parent_context_chain() {
+        let mut bindings: Keybindings<LayeredContext> = Keybindings::new();
+        bindings.add_global("quit", vec!["ctrl+c".to_string()]);
+        bindings.add(LayeredContext::Base, "help", vec!["?".to_string()]);
+        bindings.add(LayeredContext::Modal, "close", vec!["esc".to_string()]);
+        bindings.add(LayeredContext::Modal, "confirm", vec!["enter".to_string()]);
+        bindings.add(
+            LayeredContext::SearchModal,
+            "submit",
+            vec!["enter".to_string()],
+        );
+
+        let key = |s: &str| parse_key_string(s).unwrap();
+        let search = LayeredContext::SearchModal;
+
+        // Inherited from parent and grandparent
+        assert_eq!(
+            bindings.get_command(key("esc"), search),
+            Some("close".into())
+        );
+        assert_eq!(bindings.get_command(key("?"), search), Some("help".into()));
+        assert_eq!(
+            bindings.get_command(key("ctrl+c"), search),
+            Some("quit".into())
+        );
+
+        // The nearest context wins
+        assert_eq!(
+            bindings.get_command(key("enter"), search),
+            Some("submit".into())
+        );
+        assert_eq!(
+            bindings.get_command(key("enter"), LayeredContext::Modal),
+            Some("confirm".into())
+        );
+
+        // Parents don't see child bindings
+        assert_eq!(bindings.get_command(key("esc"), LayeredContext::Base), None);
+
+        assert_eq!(
+            bindings.commands_for_key(key("enter"), search),
+            vec!["submit", "confirm"]
+        );
+        assert_eq!(
+            bindings.get_first_keybinding("close", search),
+            Some("esc".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unbound_commands() {
+        let mut bindings: Keybindings<TestContext> = Keybindings::new();
+        bindings.add_global("quit", vec!["q".to_string()]);
+        bindings.add(TestContext::Search, "clear", vec!["esc".to_string()]);
+        bindings.add(TestContext::Search, "next", vec![]);
+
+        assert_eq!(
+            bindings.unbound_commands(&["save", "quit", "next", "clear", "help"]),
+            vec!["save", "next", "help"]
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_load_toml() {
+        let config = r#"
+            [global]
+            quit = ["q", "ctrl+c"]
+
+            [search]
+            clear = ["esc"]
+        "#;
+
+        let bindings: Keybindings<TestContext> =
+            Keybindings::from_str_with_format(config, KeybindingsFormat::Toml).unwrap();
+        assert_eq!(
+            bindings.get_command(parse_key_string("ctrl+c").unwrap(), TestContext::Default),
+            Some("quit".to_string())
+        );
+        assert_eq!(
+            bindings.get_command(parse_key_string("esc").unwrap(), TestContext::Search),
+            Some("clear".to_string())
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_load_toml_errors() {
+        let unknown = "[settings]\nquit = [\"q\"]\n";
+        let err =
+            Keybindings::<TestContext>::from_str_with_format(unknown, KeybindingsFormat::Toml)
+                .unwrap_err();
+        assert!(matches!(err, KeybindingsError::UnknownContext(ref name) if name == "settings"));
+
+        let invalid = "[global]\nquit = [\"ctrl+nope\"]\n";
+        let err =
+            Keybindings::<TestContext>::from_str_with_format(invalid, KeybindingsFormat::Toml)
+                .unwrap_err();
+        assert!(matches!(err, KeybindingsError::InvalidKey { ref key, .. } if key == "ctrl+nope"));
+
+        let duplicate = "[search]\nclear = [\"esc\"]\nclose = [\"esc\"]\n";
+        let err =
+            Keybindings::<TestContext>::from_str_with_format(duplicate, KeybindingsFormat::Toml)
+                .unwrap_err();
+        assert!(matches!(err, KeybindingsError::Conflict(ref c) if c.context == "search"));
+
+        let malformed = "[global\n";
+        let err =
+            Keybindings::<TestContext>::from_str_with_format(malformed, KeybindingsFormat::Toml)
+                .unwrap_err();
+        assert!(matches!(err, KeybindingsError::Parse(_)));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_load_yaml() {
+        let config = "global:\n  quit: [q]\nsearch:\n  clear: [esc]\n";
+
+        let bindings: Keybindings<TestContext> =
+            Keybindings::from_str_with_format(config, KeybindingsFormat::Yaml).unwrap();
+        assert_eq!(
+            bindings.get_command(parse_key_string("q").unwrap(), TestContext::Search),
+            Some("quit".to_string())
+        );
+    }
+
+    #[test]
+    fn test_load_with_defaults_missing_file() {
+        let mut defaults: Keybindings<TestContext> = Keybindings::new();
+        defaults.add_global("quit", vec!["q".to_string()]);
+
+        let bindings =
+            Keybindings::load_with_defaults("/nonexistent/keybindings.toml", defaults).unwrap();
+        assert_eq!(
+            bindings.global_bindings().get("quit"),
+            Some(&vec!["q".to_string()])
+        );
+    }
+
     #[test]
     fn test_format_key_for_display() {
         assert_eq!(format_key_for_display("q"), "Q");
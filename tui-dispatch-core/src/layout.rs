@@ -0,0 +1,369 @@
+//! Declarative grid layouts with named areas
+//!
+//! [`GridSpec`] describes rows and columns as ratatui [`Constraint`]s plus an
+//! optional template of named areas, in the spirit of CSS `grid-template-areas`.
+//! Resolving it against a frame area yields a `name -> Rect` map instead of
+//! nested `Layout::vertical` / `Layout::horizontal` calls.
+//!
+//! ```
+//! use ratatui::layout::{Constraint, Rect};
+//! use tui_dispatch_core::layout::GridSpec;
+//!
+//! let grid = GridSpec::new()
+//!     .rows([Constraint::Length(1), Constraint::Fill(1), Constraint::Length(1)])
+//!     .columns([Constraint::Length(20), Constraint::Fill(1)])
+//!     .areas([
+//!         "header  header",
+//!         "sidebar main",
+//!         "footer  footer",
+//!     ]);
+//!
+//! let areas = grid.resolve(Rect::new(0, 0, 80, 24)).unwrap();
+//! assert_eq!(areas["header"], Rect::new(0, 0, 80, 1));
+//! assert_eq!(areas["sidebar"], Rect::new(0, 1, 20, 22));
+//! assert_eq!(areas["main"], Rect::new(20, 1, 60, 22));
+//! ```
+
+use std::collections::HashMap;
+
+use ratatui::layout::{Constraint, Layout, Rect};
+
+/// Placeholder for template cells that belong to no named area
+const EMPTY_CELL: &str = ".";
+
+/// Bounding (top, left, bottom, right) cell indices of a named area
+type CellSpan = (usize, usize, usize, usize);
+
+/// Error resolving a [`GridSpec`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The template has a different number of rows than the row constraints
+    RowCount { expected: usize, found: usize },
+    /// A template row has a different number of cells than the column constraints
+    ColumnCount {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A named area doesn't cover a single rectangle of cells
+    NotRectangular(String),
+}
+
+impl std::fmt::Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::RowCount { expected, found } => {
+                write!(f, "Grid template has {} rows, expected {}", found, expected)
+            }
+            GridError::ColumnCount {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Grid template row {} has {} cells, expected {}",
+                row, found, expected
+            ),
+            GridError::NotRectangular(name) => {
+                write!(f, "Grid area \"{}\" is not rectangular", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// Declarative grid layout
+///
+/// Rows and columns default to a single `Fill(1)` track. When a template is
+/// set via [`areas`](Self::areas) and no explicit constraints are given,
+/// each template row/column gets an equal `Fill(1)` track.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GridSpec {
+    rows: Vec<Constraint>,
+    columns: Vec<Constraint>,
+    row_gap: u16,
+    column_gap: u16,
+    template: Vec<Vec<String>>,
+}
+
+impl GridSpec {
+    /// Create an empty grid spec
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the row constraints, top to bottom
+    pub fn rows(mut self, rows: impl IntoIterator<Item = Constraint>) -> Self {
+        self.rows = rows.into_iter().collect();
+        self
+    }
+
+    /// Set the column constraints, left to right
+    pub fn columns(mut self, columns: impl IntoIterator<Item = Constraint>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    /// Set both the row and column gap
+    pub fn gap(self, gap: u16) -> Self {
+        self.row_gap(gap).column_gap(gap)
+    }
+
+    /// Set the gap between rows
+    pub fn row_gap(mut self, gap: u16) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
+    /// Set the gap between columns
+    pub fn column_gap(mut self, gap: u16) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
+    /// Set the named-area template, one string per row
+    ///
+    /// Cells are separated by whitespace. Repeating a name across adjacent
+    /// cells makes the area span them; `.` leaves a cell unnamed.
+    pub fn areas<I, S>(mut self, template: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.template = template
+            .into_iter()
+            .map(|row| row.as_ref().split_whitespace().map(String::from).collect())
+            .collect();
+        self
+    }
+
+    /// Resolve the grid against an area
+    pub fn resolve(&self, area: Rect) -> Result<GridAreas, GridError> {
+        let rows = self.tracks(&self.rows, self.template.len());
+        let columns = self.tracks(
+            &self.columns,
+            self.template.first().map_or(0, |row| row.len()),
+        );
+        self.validate(rows.len(), columns.len())?;
+
+        let row_rects = Layout::vertical(rows).spacing(self.row_gap).split(area);
+        let column_rects = Layout::horizontal(columns)
+            .spacing(self.column_gap)
+            .split(area);
+
+        let mut named = HashMap::new();
+        for (name, (top, left, bottom, right)) in self.spans()? {
+            let first = Rect::new(
+                column_rects[left].x,
+                row_rects[top].y,
+                column_rects[left].width,
+                row_rects[top].height,
+            );
+            let last = Rect::new(
+                column_rects[right].x,
+                row_rects[bottom].y,
+                column_rects[right].width,
+                row_rects[bottom].height,
+            );
+            named.insert(name, first.union(last));
+        }
+
+        Ok(GridAreas {
+            rows: row_rects.to_vec(),
+            columns: column_rects.to_vec(),
+            named,
+        })
+    }
+
+    fn tracks(&self, constraints: &[Constraint], template_len: usize) -> Vec<Constraint> {
+        if !constraints.is_empty() {
+            constraints.to_vec()
+        } else {
+            vec![Constraint::Fill(1); template_len.max(1)]
+        }
+    }
+
+    fn validate(&self, rows: usize, columns: usize) -> Result<(), GridError> {
+        if self.template.is_empty() {
+            return Ok(());
+        }
+        if self.template.len() != rows {
+            return Err(GridError::RowCount {
+                expected: rows,
+                found: self.template.len(),
+            });
+        }
+        for (row, cells) in self.template.iter().enumerate() {
+            if cells.len() != columns {
+                return Err(GridError::ColumnCount {
+                    row,
+                    expected: columns,
+                    found: cells.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Cell span of each named area
+    fn spans(&self) -> Result<HashMap<String, CellSpan>, GridError> {
+        let mut spans: HashMap<String, CellSpan> = HashMap::new();
+        for (row, cells) in self.template.iter().enumerate() {
+            for (column, name) in cells.iter().enumerate() {
+                if name == EMPTY_CELL {
+                    continue;
+                }
+                let span = spans
+                    .entry(name.clone())
+                    .or_insert((row, column, row, column));
+                span.0 = span.0.min(row);
+                span.1 = span.1.min(column);
+                span.2 = span.2.max(row);
+                span.3 = span.3.max(column);
+            }
+        }
+
+        // Every cell inside the bounding box must carry the name
+        for (name, &(top, left, bottom, right)) in &spans {
+            let filled = (top..=bottom)
+                .all(|row| (left..=right).all(|column| &self.template[row][column] == name));
+            let count: usize = self
+                .template
+                .iter()
+                .map(|cells| cells.iter().filter(|cell| *cell == name).count())
+                .sum();
+            if !filled || count != (bottom - top + 1) * (right - left + 1) {
+                return Err(GridError::NotRectangular(name.clone()));
+            }
+        }
+
+        Ok(spans)
+    }
+}
+
+/// Resolved grid: named areas plus the raw row and column tracks
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GridAreas {
+    rows: Vec<Rect>,
+    columns: Vec<Rect>,
+    named: HashMap<String, Rect>,
+}
+
+impl GridAreas {
+    /// Get a named area
+    pub fn get(&self, name: &str) -> Option<Rect> {
+        self.named.get(name).copied()
+    }
+
+    /// Get the area of a single cell by row and column index
+    pub fn cell(&self, row: usize, column: usize) -> Option<Rect> {
+        let row = self.rows.get(row)?;
+        let column = self.columns.get(column)?;
+        Some(Rect::new(column.x, row.y, column.width, row.height))
+    }
+
+    /// Iterate over the named areas
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Rect)> {
+        self.named.iter().map(|(name, rect)| (name.as_str(), *rect))
+    }
+
+    /// Number of rows in the grid
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Number of columns in the grid
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+}
+
+impl std::ops::Index<&str> for GridAreas {
+    type Output = Rect;
+
+    /// Get a named area, panicking if the template doesn't define it
+    fn index(&self, name: &str) -> &Rect {
+        self.named
+            .get(name)
+            .unwrap_or_else(|| panic!("no grid area named \"{name}\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_areas_with_gap() {
+        let grid = GridSpec::new()
+            .rows([Constraint::Length(3), Constraint::Fill(1)])
+            .columns([Constraint::Length(10), Constraint::Fill(1)])
+            .gap(1)
+            .areas(["header header", "nav    body"]);
+
+        let areas = grid.resolve(Rect::new(0, 0, 41, 20)).unwrap();
+        assert_eq!(areas["header"], Rect::new(0, 0, 41, 3));
+        assert_eq!(areas["nav"], Rect::new(0, 4, 10, 16));
+        assert_eq!(areas["body"], Rect::new(11, 4, 30, 16));
+        assert_eq!(areas.cell(1, 1), Some(areas["body"]));
+        assert_eq!(areas.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_default_tracks_from_template() {
+        let grid = GridSpec::new().areas(["a b", ". c"]);
+        let areas = grid.resolve(Rect::new(0, 0, 10, 4)).unwrap();
+        assert_eq!(areas.row_count(), 2);
+        assert_eq!(areas.column_count(), 2);
+        assert_eq!(areas.get("a"), Some(Rect::new(0, 0, 5, 2)));
+        assert_eq!(areas.get("c"), Some(Rect::new(5, 2, 5, 2)));
+        assert_eq!(areas.get("."), None);
+    }
+
+    #[test]
+    fn test_vertical_span() {
+        let grid = GridSpec::new()
+            .rows([Constraint::Length(2), Constraint::Length(2)])
+            .columns([Constraint::Length(4), Constraint::Fill(1)])
+            .areas(["side top", "side bottom"]);
+        let areas = grid.resolve(Rect::new(1, 1, 10, 4)).unwrap();
+        assert_eq!(areas["side"], Rect::new(1, 1, 4, 4));
+        assert_eq!(areas["bottom"], Rect::new(5, 3, 6, 2));
+    }
+
+    #[test]
+    fn test_template_errors() {
+        let err = GridSpec::new()
+            .rows([Constraint::Fill(1)])
+            .areas(["a", "b"])
+            .resolve(Rect::new(0, 0, 10, 10))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            GridError::RowCount {
+                expected: 1,
+                found: 2
+            }
+        );
+
+        let err = GridSpec::new()
+            .areas(["a b", "c"])
+            .resolve(Rect::new(0, 0, 10, 10))
+            .unwrap_err();
+        assert!(matches!(err, GridError::ColumnCount { row: 1, .. }));
+
+        let err = GridSpec::new()
+            .areas(["a b", "b a"])
+            .resolve(Rect::new(0, 0, 10, 10))
+            .unwrap_err();
+        assert!(matches!(err, GridError::NotRectangular(_)));
+    }
+
+    #[test]
+    #[should_panic(expected = "no grid area named")]
+    fn test_index_missing_area_panics() {
+        let areas = GridSpec::new().resolve(Rect::new(0, 0, 1, 1)).unwrap();
+        let _rect = areas["missing"];
+    }
+}
@@ -92,56 +92,125 @@
 //! these are automatically grouped (e.g., `DataFetch` and `DataDidLoad` both get category `"data"`).
 
 pub mod action;
+pub mod animations;
 pub mod bus;
+pub mod clipboard;
 pub mod component;
+#[cfg(feature = "subscriptions")]
+pub mod cron;
 pub mod debug;
 pub mod effect;
+pub mod error;
 pub mod event;
 pub mod features;
 pub mod keybindings;
+pub mod layout;
+pub mod notifications;
+pub mod persistence;
+pub mod rate_limit;
+pub mod replay;
 pub mod runtime;
+pub mod selector;
 pub mod store;
+pub mod strings;
 #[cfg(feature = "subscriptions")]
 pub mod subscriptions;
 #[cfg(feature = "tasks")]
 pub mod tasks;
+pub mod terminal;
 pub mod testing;
+pub mod theme;
+pub mod thunk;
+pub mod view_state;
 
 // Core trait exports
 #[allow(deprecated)]
-pub use action::{Action, ActionCategory, ActionParams, ActionSummary};
-pub use component::Component;
+pub use action::{
+    Action, ActionCategory, ActionDescribe, ActionParams, ActionSchema, ActionSummary, FieldSchema,
+    VariantKind, VariantSchema,
+};
+pub use component::{Component, ComponentTree};
+pub use error::Error;
 pub use features::{DynamicFeatures, FeatureFlags};
 
 // Event system exports
-pub use bus::{process_raw_event, spawn_event_poller, EventBus, RawEvent};
+pub use bus::{
+    disable_bracketed_paste, disable_focus_change, disable_keyboard_enhancement,
+    enable_bracketed_paste, enable_focus_change, enable_keyboard_enhancement, process_raw_event,
+    spawn_event_poller, supports_keyboard_enhancement, EventBus, RawEvent,
+};
 pub use event::{ComponentId, Event, EventContext, EventKind, EventType, NumericComponentId};
 
 // Keybindings exports
-pub use keybindings::{format_key_for_display, parse_key_string, BindingContext, Keybindings};
+pub use keybindings::{
+    format_key_for_display, parse_key_string, BindingContext, KeyParseError, KeybindingConflict,
+    Keybindings, KeybindingsError, KeybindingsFormat,
+};
+
+// Layout exports
+pub use layout::{GridAreas, GridError, GridSpec};
 
 // Store exports
+pub use selector::Selector;
 pub use store::{
-    ComposedMiddleware, LoggingMiddleware, Middleware, NoopMiddleware, Reducer, Store,
-    StoreWithMiddleware,
+    CategoryRouter, ComposedMiddleware, EnvReducer, Listener, ListenerId, LoggingMiddleware,
+    Middleware, MiddlewareInfo, NoopMiddleware, Reducer, SliceChanges, Store, StoreWithMiddleware,
+};
+
+#[cfg(feature = "tracing-middleware")]
+pub use store::TracingMiddleware;
+
+// Animation exports
+pub use animations::{lerp, lerp_color, Animations, Easing, Tween};
+
+// Notification exports
+pub use notifications::{Notification, NotificationLevel, NotificationState};
+
+// Persistence exports
+pub use persistence::{Persist, PersistError, PersistFormat, PersistPolicy, Persister};
+pub use view_state::{ViewEntry, ViewState};
+
+// Rate limit exports
+pub use rate_limit::RateLimitMiddleware;
+
+// Replay exports
+pub use replay::{
+    load_recording, load_recording_file, replay_actions, RecordedAction, RecorderMiddleware,
 };
 
 // Runtime exports
 pub use runtime::{
-    DispatchRuntime, DispatchStore, EffectContext, EffectRuntime, EffectStoreLike, EventOutcome,
-    PollerConfig, RenderContext,
+    DispatchRuntime, DispatchRuntimeBuilder, DispatchStore, EffectContext, EffectRuntime,
+    EffectStoreLike, EventOutcome, GlobalHotkeys, PollerConfig, RenderContext, Runtime,
+    RuntimeBuilder,
 };
+pub use terminal::{PanicHookGuard, TerminalGuard, TerminalHost, TerminalModes};
+
+// Clipboard exports
+pub use clipboard::{copy_to_clipboard, ClipboardBackend};
+
+// Theme exports
+pub use strings::Strings;
+pub use theme::{Theme, ThemeError};
 
 // Effect exports
-pub use effect::{DispatchResult, EffectReducer, EffectStore, EffectStoreWithMiddleware};
+pub use effect::{
+    Cmd, DirtyComponents, DispatchResult, EffectReducer, EffectStore, EffectStoreWithMiddleware,
+};
+pub use thunk::Thunk;
 
 // Task exports (requires "tasks" feature)
 #[cfg(feature = "tasks")]
-pub use tasks::{TaskKey, TaskManager, TaskPauseHandle};
+pub use tasks::{
+    ProgressHandle, RetryPolicy, TaskKey, TaskManager, TaskPauseHandle, TaskPriority, TaskProgress,
+    TaskProgressView,
+};
 
 // Subscription exports (requires "subscriptions" feature)
 #[cfg(feature = "subscriptions")]
-pub use subscriptions::{SubKey, SubPauseHandle, Subscriptions};
+pub use cron::{CronParseError, CronSchedule};
+#[cfg(feature = "subscriptions")]
+pub use subscriptions::{ChannelSource, SubKey, SubPauseHandle, Subscriptions};
 
 // Re-export ratatui types for convenience
 pub use ratatui::{
@@ -154,20 +223,31 @@ pub use ratatui::{
 // Testing exports
 pub use testing::{
     alt_key, buffer_rect_to_string_plain, buffer_to_string, buffer_to_string_plain, char_key,
-    ctrl_key, into_event, key, key_event, key_events, keys, ActionAssertions, ActionAssertionsEq,
-    RenderHarness, TestHarness,
+    ctrl_key, fuzz_events, into_event, key, key_event, key_events, keys, ActionAssertions,
+    ActionAssertionsEq, AppHarness, BufferSnapshot, EffectTestHarness, EventFuzzer, RenderHarness,
+    TestHarness,
 };
 
+#[cfg(feature = "proptest")]
+pub use testing::assert_reducer_invariant;
 #[cfg(feature = "testing-time")]
 pub use testing::{advance_time, pause_time, resume_time};
 
+/// The proptest crate, for strategies and `#[derive(ArbitraryAction)]`
+#[cfg(feature = "proptest")]
+pub use proptest;
+
 /// Prelude module for convenient imports
 pub mod prelude {
-    pub use crate::action::{Action, ActionCategory, ActionParams};
-    pub use crate::bus::{process_raw_event, spawn_event_poller, EventBus, RawEvent};
-    pub use crate::component::Component;
+    pub use crate::action::{Action, ActionCategory, ActionDescribe, ActionParams};
+    pub use crate::bus::{
+        disable_bracketed_paste, disable_focus_change, disable_keyboard_enhancement,
+        enable_bracketed_paste, enable_focus_change, enable_keyboard_enhancement,
+        process_raw_event, spawn_event_poller, supports_keyboard_enhancement, EventBus, RawEvent,
+    };
+    pub use crate::component::{Component, ComponentTree};
     pub use crate::effect::{
-        DispatchResult, EffectReducer, EffectStore, EffectStoreWithMiddleware,
+        Cmd, DirtyComponents, DispatchResult, EffectReducer, EffectStore, EffectStoreWithMiddleware,
     };
     pub use crate::event::{
         ComponentId, Event, EventContext, EventKind, EventType, NumericComponentId,
@@ -176,20 +256,32 @@ pub mod prelude {
     pub use crate::keybindings::{
         format_key_for_display, parse_key_string, BindingContext, Keybindings,
     };
+    pub use crate::layout::{GridAreas, GridSpec};
+    pub use crate::rate_limit::RateLimitMiddleware;
+    pub use crate::selector::Selector;
     pub use crate::store::{
-        ComposedMiddleware, LoggingMiddleware, Middleware, NoopMiddleware, Reducer, Store,
-        StoreWithMiddleware,
+        CategoryRouter, ComposedMiddleware, EnvReducer, ListenerId, LoggingMiddleware, Middleware,
+        MiddlewareInfo, NoopMiddleware, Reducer, SliceChanges, Store, StoreWithMiddleware,
     };
+    pub use crate::strings::Strings;
+    pub use crate::theme::Theme;
+    pub use crate::thunk::Thunk;
 
     // Runtime helpers
+    #[cfg(feature = "subscriptions")]
+    pub use crate::cron::CronSchedule;
     pub use crate::runtime::{
-        DispatchRuntime, DispatchStore, EffectContext, EffectRuntime, EffectStoreLike,
-        EventOutcome, PollerConfig, RenderContext,
+        DispatchRuntime, DispatchRuntimeBuilder, DispatchStore, EffectContext, EffectRuntime,
+        EffectStoreLike, EventOutcome, GlobalHotkeys, PollerConfig, RenderContext, Runtime,
+        RuntimeBuilder,
     };
     #[cfg(feature = "subscriptions")]
     pub use crate::subscriptions::{SubKey, SubPauseHandle, Subscriptions};
     #[cfg(feature = "tasks")]
-    pub use crate::tasks::{TaskKey, TaskManager, TaskPauseHandle};
+    pub use crate::tasks::{
+        ProgressHandle, RetryPolicy, TaskKey, TaskManager, TaskPauseHandle, TaskPriority,
+    };
+    pub use crate::terminal::{TerminalGuard, TerminalModes};
 
     // Re-export ratatui types
     pub use ratatui::{
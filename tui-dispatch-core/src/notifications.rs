@@ -0,0 +1,260 @@
+//! Notification queue for toast-style messages
+//!
+//! [`NotificationState`] is a small state helper meant to live inside app
+//! state. Reducers push and dismiss notifications, and call
+//! [`NotificationState::tick`] on each `Tick` action so notifications
+//! expire after their time-to-live. Rendering is left to the app (see
+//! `ToastStack` in `tui-dispatch-components`).
+//!
+//! # Example
+//!
+//! ```
+//! use tui_dispatch_core::notifications::{NotificationLevel, NotificationState};
+//!
+//! let mut notifications = NotificationState::new().with_default_ttl(2);
+//! let id = notifications.push(NotificationLevel::Info, "Saved");
+//! notifications.error("Disk full");
+//! assert_eq!(notifications.len(), 2);
+//!
+//! // In the reducer, on Tick:
+//! notifications.tick();
+//! notifications.tick();
+//! assert!(notifications.is_empty());
+//!
+//! // Or dismiss explicitly:
+//! assert!(!notifications.dismiss(id));
+//! ```
+
+use std::collections::VecDeque;
+
+/// Severity of a notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NotificationLevel {
+    /// Neutral information
+    #[default]
+    Info,
+    /// Completed operation
+    Success,
+    /// Something needs attention
+    Warning,
+    /// Failed operation
+    Error,
+}
+
+impl NotificationLevel {
+    /// Short display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotificationLevel::Info => "Info",
+            NotificationLevel::Success => "Success",
+            NotificationLevel::Warning => "Warning",
+            NotificationLevel::Error => "Error",
+        }
+    }
+}
+
+/// A single queued notification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// Unique id (for dismissal)
+    pub id: u64,
+    /// Severity
+    pub level: NotificationLevel,
+    /// Message text
+    pub message: String,
+    /// Remaining ticks before expiry (None = sticky until dismissed)
+    pub ttl: Option<u64>,
+}
+
+/// Bounded queue of notifications with per-item time-to-live
+///
+/// Notifications are ordered oldest first. When the queue is full, pushing
+/// drops the oldest notification.
+#[derive(Debug, Clone)]
+pub struct NotificationState {
+    items: VecDeque<Notification>,
+    next_id: u64,
+    capacity: usize,
+    default_ttl: Option<u64>,
+}
+
+impl Default for NotificationState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationState {
+    /// Create an empty queue (capacity 8, 50-tick default TTL)
+    pub fn new() -> Self {
+        Self {
+            items: VecDeque::new(),
+            next_id: 0,
+            capacity: 8,
+            default_ttl: Some(50),
+        }
+    }
+
+    /// Set the maximum number of queued notifications (minimum 1)
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Set the default time-to-live in ticks
+    pub fn with_default_ttl(mut self, ticks: u64) -> Self {
+        self.default_ttl = Some(ticks);
+        self
+    }
+
+    /// Make notifications sticky by default (no automatic expiry)
+    pub fn sticky(mut self) -> Self {
+        self.default_ttl = None;
+        self
+    }
+
+    /// Push a notification with the default TTL and return its id
+    pub fn push(&mut self, level: NotificationLevel, message: impl Into<String>) -> u64 {
+        self.push_with_ttl(level, message, self.default_ttl)
+    }
+
+    /// Push a notification with an explicit TTL (None = sticky) and return its id
+    pub fn push_with_ttl(
+        &mut self,
+        level: NotificationLevel,
+        message: impl Into<String>,
+        ttl: Option<u64>,
+    ) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        while self.items.len() >= self.capacity {
+            self.items.pop_front();
+        }
+
+        self.items.push_back(Notification {
+            id,
+            level,
+            message: message.into(),
+            ttl,
+        });
+        id
+    }
+
+    /// Push an info notification
+    pub fn info(&mut self, message: impl Into<String>) -> u64 {
+        self.push(NotificationLevel::Info, message)
+    }
+
+    /// Push a success notification
+    pub fn success(&mut self, message: impl Into<String>) -> u64 {
+        self.push(NotificationLevel::Success, message)
+    }
+
+    /// Push a warning notification
+    pub fn warning(&mut self, message: impl Into<String>) -> u64 {
+        self.push(NotificationLevel::Warning, message)
+    }
+
+    /// Push an error notification
+    pub fn error(&mut self, message: impl Into<String>) -> u64 {
+        self.push(NotificationLevel::Error, message)
+    }
+
+    /// Dismiss a notification by id; returns whether it was present
+    pub fn dismiss(&mut self, id: u64) -> bool {
+        let before = self.items.len();
+        self.items.retain(|n| n.id != id);
+        self.items.len() != before
+    }
+
+    /// Dismiss all notifications; returns whether any were present
+    pub fn clear(&mut self) -> bool {
+        let had_items = !self.items.is_empty();
+        self.items.clear();
+        had_items
+    }
+
+    /// Advance time by one tick, expiring notifications whose TTL ran out
+    ///
+    /// Returns whether any notification was removed (i.e. a re-render is needed).
+    pub fn tick(&mut self) -> bool {
+        let before = self.items.len();
+        for item in self.items.iter_mut() {
+            if let Some(ttl) = item.ttl.as_mut() {
+                *ttl = ttl.saturating_sub(1);
+            }
+        }
+        self.items.retain(|n| n.ttl != Some(0));
+        self.items.len() != before
+    }
+
+    /// Iterate over notifications, oldest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Notification> {
+        self.items.iter()
+    }
+
+    /// Get a notification by id
+    pub fn get(&self, id: u64) -> Option<&Notification> {
+        self.items.iter().find(|n| n.id == id)
+    }
+
+    /// Number of queued notifications
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_and_dismiss() {
+        let mut state = NotificationState::new();
+        let a = state.info("a");
+        let b = state.error("b");
+        assert_ne!(a, b);
+        assert_eq!(state.get(b).unwrap().level, NotificationLevel::Error);
+
+        assert!(state.dismiss(a));
+        assert!(!state.dismiss(a));
+        assert_eq!(state.len(), 1);
+
+        assert!(state.clear());
+        assert!(!state.clear());
+    }
+
+    #[test]
+    fn test_ttl_expiry() {
+        let mut state = NotificationState::new().with_default_ttl(2);
+        state.info("short");
+        state.push_with_ttl(NotificationLevel::Warning, "sticky", None);
+        state.push_with_ttl(NotificationLevel::Info, "long", Some(3));
+
+        assert!(!state.tick());
+        assert!(state.tick());
+        let remaining: Vec<_> = state.iter().map(|n| n.message.as_str()).collect();
+        assert_eq!(remaining, vec!["sticky", "long"]);
+
+        assert!(state.tick());
+        assert_eq!(state.len(), 1);
+        assert!(!state.tick());
+    }
+
+    #[test]
+    fn test_capacity_drops_oldest() {
+        let mut state = NotificationState::new().with_capacity(2);
+        state.info("1");
+        state.info("2");
+        state.info("3");
+
+        let messages: Vec<_> = state.iter().map(|n| n.message.as_str()).collect();
+        assert_eq!(messages, vec!["2", "3"]);
+    }
+}
@@ -0,0 +1,428 @@
+//! Saving and restoring app state across runs
+//!
+//! Implement [`Persist`] to choose which parts of state survive a restart,
+//! then use a [`Persister`] to hydrate state at startup and save it back.
+//! With [`EffectRuntime::with_persistence`](crate::EffectRuntime::with_persistence)
+//! the runtime saves automatically according to a [`PersistPolicy`].
+//!
+//! Snapshots are stored as JSON, or TOML with the `toml` feature; the
+//! format is chosen from the file extension.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use serde::{Deserialize, Serialize};
+//! use tui_dispatch::persistence::{Persist, PersistPolicy, Persister};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Saved {
+//!     query: String,
+//!     favorites: Vec<String>,
+//! }
+//!
+//! impl Persist for AppState {
+//!     type Snapshot = Saved;
+//!
+//!     fn snapshot(&self) -> Saved {
+//!         Saved { query: self.query.clone(), favorites: self.favorites.clone() }
+//!     }
+//!
+//!     fn hydrate(&mut self, saved: Saved) {
+//!         self.query = saved.query;
+//!         self.favorites = saved.favorites;
+//!     }
+//! }
+//!
+//! let mut state = AppState::default();
+//! Persister::new("state.json")?.load_into(&mut state)?;
+//!
+//! let mut runtime = EffectRuntime::new(state, reducer).with_persistence(
+//!     "state.json",
+//!     PersistPolicy::on_quit().debounced(Duration::from_secs(2)),
+//! )?;
+//! ```
+
+use std::io;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// State that can be saved to and restored from disk
+///
+/// `Snapshot` is the serializable subset of state; transient data
+/// (loading flags, selections, caches) is usually left out.
+pub trait Persist {
+    /// Serializable form of the persisted state
+    type Snapshot: Serialize + DeserializeOwned;
+
+    /// Capture the parts of state to persist
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Restore state from a saved snapshot
+    fn hydrate(&mut self, snapshot: Self::Snapshot);
+}
+
+/// File format for persisted state
+///
+/// JSON is always available; TOML requires the `toml` crate feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersistFormat {
+    /// JSON (`.json`)
+    Json,
+    /// TOML (`.toml`)
+    Toml,
+}
+
+impl PersistFormat {
+    /// Detect the format from a file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+
+    /// Name of the crate feature that enables this format (if any)
+    pub fn feature(self) -> Option<&'static str> {
+        match self {
+            Self::Json => None,
+            Self::Toml => Some("toml"),
+        }
+    }
+}
+
+/// Error loading or saving persisted state
+#[derive(Debug)]
+pub enum PersistError {
+    /// Failed to read or write the state file
+    Io { path: PathBuf, source: io::Error },
+    /// File extension is not a supported format
+    UnsupportedFormat(PathBuf),
+    /// Format is supported but its crate feature is not enabled
+    FormatDisabled(PersistFormat),
+    /// Snapshot could not be serialized
+    Serialize(String),
+    /// State file contents could not be parsed
+    Parse { path: PathBuf, message: String },
+}
+
+impl std::fmt::Display for PersistError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersistError::Io { path, source } => {
+                write!(f, "Failed to access {}: {}", path.display(), source)
+            }
+            PersistError::UnsupportedFormat(path) => {
+                write!(f, "Unsupported state file format: {}", path.display())
+            }
+            PersistError::FormatDisabled(format) => write!(
+                f,
+                "Persisting {:?} state requires the `{}` feature",
+                format,
+                format.feature().unwrap_or_default()
+            ),
+            PersistError::Serialize(msg) => write!(f, "Failed to serialize state: {}", msg),
+            PersistError::Parse { path, message } => {
+                write!(f, "Failed to parse {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PersistError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PersistError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Reads and writes [`Persist`] snapshots for state `S` at a fixed path
+pub struct Persister<S> {
+    path: PathBuf,
+    format: PersistFormat,
+    _state: PhantomData<fn(&S)>,
+}
+
+impl<S> std::fmt::Debug for Persister<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Persister")
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .finish()
+    }
+}
+
+impl<S: Persist> Persister<S> {
+    /// Create a persister, detecting the format from the file extension
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, PersistError> {
+        let path = path.into();
+        let format = PersistFormat::from_path(&path)
+            .ok_or_else(|| PersistError::UnsupportedFormat(path.clone()))?;
+        Ok(Self::with_format(path, format))
+    }
+
+    /// Create a persister with an explicit format
+    pub fn with_format(path: impl Into<PathBuf>, format: PersistFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            _state: PhantomData,
+        }
+    }
+
+    /// Path of the state file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load the saved snapshot, or `None` if no state file exists yet
+    pub fn load(&self) -> Result<Option<S::Snapshot>, PersistError> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(PersistError::Io {
+                    path: self.path.clone(),
+                    source,
+                })
+            }
+        };
+
+        let parse_error = |message: String| PersistError::Parse {
+            path: self.path.clone(),
+            message,
+        };
+        let snapshot = match self.format {
+            PersistFormat::Json => {
+                serde_json::from_str(&contents).map_err(|e| parse_error(e.to_string()))?
+            }
+            #[cfg(feature = "toml")]
+            PersistFormat::Toml => {
+                toml::from_str(&contents).map_err(|e| parse_error(e.to_string()))?
+            }
+            #[allow(unreachable_patterns)]
+            format => return Err(PersistError::FormatDisabled(format)),
+        };
+        Ok(Some(snapshot))
+    }
+
+    /// Hydrate `state` from the saved snapshot
+    ///
+    /// Returns `false` (leaving `state` untouched) if no state file exists.
+    pub fn load_into(&self, state: &mut S) -> Result<bool, PersistError> {
+        match self.load()? {
+            Some(snapshot) => {
+                state.hydrate(snapshot);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Save a snapshot of `state`
+    ///
+    /// Writes to a temporary file next to the target and renames it into
+    /// place, so a crash mid-write never leaves a truncated state file.
+    /// Missing parent directories are created.
+    pub fn save(&self, state: &S) -> Result<(), PersistError> {
+        let snapshot = state.snapshot();
+        let contents = match self.format {
+            PersistFormat::Json => serde_json::to_string_pretty(&snapshot)
+                .map_err(|e| PersistError::Serialize(e.to_string()))?,
+            #[cfg(feature = "toml")]
+            PersistFormat::Toml => toml::to_string_pretty(&snapshot)
+                .map_err(|e| PersistError::Serialize(e.to_string()))?,
+            #[allow(unreachable_patterns)]
+            format => return Err(PersistError::FormatDisabled(format)),
+        };
+
+        let io_error = |source| PersistError::Io {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        std::fs::write(&tmp, contents).map_err(io_error)?;
+        std::fs::rename(&tmp, &self.path).map_err(io_error)
+    }
+}
+
+/// When the runtime saves persisted state
+///
+/// Policies combine: e.g. `PersistPolicy::on_quit().debounced(..)` saves
+/// shortly after changes settle and once more on exit.
+pub struct PersistPolicy<A> {
+    /// Save when the run loop exits
+    pub on_quit: bool,
+    /// Save right after dispatching actions matching this predicate
+    pub on_action: Option<fn(&A) -> bool>,
+    /// Save once state has stopped changing for this long
+    pub debounce: Option<Duration>,
+}
+
+impl<A> std::fmt::Debug for PersistPolicy<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PersistPolicy")
+            .field("on_quit", &self.on_quit)
+            .field("on_action", &self.on_action.is_some())
+            .field("debounce", &self.debounce)
+            .finish()
+    }
+}
+
+impl<A> Default for PersistPolicy<A> {
+    fn default() -> Self {
+        Self::on_quit()
+    }
+}
+
+impl<A> PersistPolicy<A> {
+    /// Save only when the run loop exits
+    pub fn on_quit() -> Self {
+        Self {
+            on_quit: true,
+            on_action: None,
+            debounce: None,
+        }
+    }
+
+    /// Never save automatically (combine with the builder methods below)
+    pub fn manual() -> Self {
+        Self {
+            on_quit: false,
+            on_action: None,
+            debounce: None,
+        }
+    }
+
+    /// Also save after dispatching actions matching `predicate`
+    pub fn on_action(mut self, predicate: fn(&A) -> bool) -> Self {
+        self.on_action = Some(predicate);
+        self
+    }
+
+    /// Also save once state has stopped changing for `delay`
+    pub fn debounced(mut self, delay: Duration) -> Self {
+        self.debounce = Some(delay);
+        self
+    }
+
+    /// Whether `action` should trigger an immediate save
+    pub fn saves_on(&self, action: &A) -> bool {
+        self.on_action.is_some_and(|predicate| predicate(action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Default)]
+    struct AppState {
+        query: String,
+        count: u32,
+        loading: bool,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Saved {
+        query: String,
+        count: u32,
+    }
+
+    impl Persist for AppState {
+        type Snapshot = Saved;
+
+        fn snapshot(&self) -> Saved {
+            Saved {
+                query: self.query.clone(),
+                count: self.count,
+            }
+        }
+
+        fn hydrate(&mut self, saved: Saved) {
+            self.query = saved.query;
+            self.count = saved.count;
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("tui-dispatch-persist-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = temp_path("roundtrip.json");
+        let persister = Persister::<AppState>::new(&path).unwrap();
+
+        let state = AppState {
+            query: "rust".into(),
+            count: 3,
+            loading: true,
+        };
+        persister.save(&state).unwrap();
+
+        let mut restored = AppState::default();
+        assert!(persister.load_into(&mut restored).unwrap());
+        assert_eq!(restored.query, "rust");
+        assert_eq!(restored.count, 3);
+        assert!(!restored.loading);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_missing_file_is_not_an_error() {
+        let persister = Persister::<AppState>::new(temp_path("missing.json")).unwrap();
+        let mut state = AppState::default();
+        assert!(!persister.load_into(&mut state).unwrap());
+    }
+
+    #[test]
+    fn test_format_detection_and_parse_errors() {
+        assert!(matches!(
+            Persister::<AppState>::new("state.txt"),
+            Err(PersistError::UnsupportedFormat(_))
+        ));
+        assert_eq!(
+            PersistFormat::from_path(Path::new("a/state.TOML")),
+            Some(PersistFormat::Toml)
+        );
+
+        let path = temp_path("corrupt.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{ not json").unwrap();
+        let err = Persister::<AppState>::new(&path)
+            .unwrap()
+            .load()
+            .unwrap_err();
+        assert!(matches!(err, PersistError::Parse { .. }));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_policy_builders() {
+        let policy = PersistPolicy::<u8>::manual()
+            .on_action(|a| *a == 1)
+            .debounced(Duration::from_millis(10));
+        assert!(!policy.on_quit);
+        assert!(policy.saves_on(&1));
+        assert!(!policy.saves_on(&2));
+        assert_eq!(policy.debounce, Some(Duration::from_millis(10)));
+
+        assert!(PersistPolicy::<u8>::default().on_quit);
+    }
+}
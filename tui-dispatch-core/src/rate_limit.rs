@@ -0,0 +1,308 @@
+//! Rate limiting and duplicate suppression for dispatched actions
+//!
+//! [`RateLimitMiddleware`] drops actions before they reach the reducer when
+//! they arrive faster than a configured rate, or when they repeat the
+//! previous action of the same name. Use it to protect reducers from floods
+//! caused by key auto-repeat or chatty subscriptions:
+//!
+//! ```ignore
+//! let limiter = RateLimitMiddleware::new()
+//!     // At most 10 SearchInput actions per second
+//!     .limit("SearchInput", 10, Duration::from_secs(1))
+//!     // At most 30 actions of the `scroll` category per second
+//!     .limit_category("scroll", 30, Duration::from_secs(1))
+//!     // Only pass SetClock when its value changed
+//!     .dedupe("SetClock");
+//! let store = StoreWithMiddleware::new(state, reducer, limiter);
+//! ```
+//!
+//! Dropped actions skip the reducer and every other middleware hook, like
+//! any action swallowed by [`Middleware::intercept`].
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::store::Middleware;
+use crate::{Action, ActionCategory};
+
+/// Which actions a rate limit applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RateTarget {
+    Name(&'static str),
+    Category(&'static str),
+}
+
+/// A sliding-window limit and the times of the actions it let through
+#[derive(Debug)]
+struct RateRule {
+    target: RateTarget,
+    max: usize,
+    window: Duration,
+    passed: VecDeque<Instant>,
+}
+
+impl RateRule {
+    fn matches(&self, name: &str, category: Option<&str>) -> bool {
+        match self.target {
+            RateTarget::Name(target) => target == name,
+            RateTarget::Category(target) => category == Some(target),
+        }
+    }
+
+    /// Forget passes that left the window; true if another one fits
+    fn has_room(&mut self, now: Instant) -> bool {
+        while self
+            .passed
+            .front()
+            .is_some_and(|&at| now.duration_since(at) >= self.window)
+        {
+            self.passed.pop_front();
+        }
+        self.passed.len() < self.max
+    }
+}
+
+/// Middleware that drops actions over a rate limit or repeating the last one
+///
+/// Limits are per action name ([`limit`](Self::limit)) or per category
+/// ([`limit_category`](Self::limit_category)) and count the actions let
+/// through in a sliding window. An action matching several limits passes
+/// only if all of them have room. [`dedupe`](Self::dedupe) drops an action
+/// equal (by its `Debug` output) to the last one of the same name that
+/// passed, so repeated ticks only dispatch when something changed.
+pub struct RateLimitMiddleware<A> {
+    rules: Vec<RateRule>,
+    dedupe: Vec<&'static str>,
+    /// Debug output of the last passed action, per deduplicated name
+    last: HashMap<&'static str, String>,
+    category: Option<fn(&A) -> Option<&'static str>>,
+    dropped: u64,
+}
+
+impl<A> std::fmt::Debug for RateLimitMiddleware<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let limits: Vec<_> = self.rules.iter().map(|rule| rule.target).collect();
+        f.debug_struct("RateLimitMiddleware")
+            .field("limits", &limits)
+            .field("dedupe", &self.dedupe)
+            .field("dropped", &self.dropped)
+            .finish()
+    }
+}
+
+impl<A> Default for RateLimitMiddleware<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> RateLimitMiddleware<A> {
+    /// Create a middleware that lets everything through
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            dedupe: Vec::new(),
+            last: HashMap::new(),
+            category: None,
+            dropped: 0,
+        }
+    }
+
+    /// Let at most `max` actions named `name` through per `window`
+    pub fn limit(mut self, name: &'static str, max: usize, window: Duration) -> Self {
+        self.push_rule(RateTarget::Name(name), max, window);
+        self
+    }
+
+    /// Let at most `max` actions in `category` through per `window`
+    ///
+    /// Requires [`ActionCategory`], which `#[derive(Action)]` implements
+    /// when categories are enabled.
+    pub fn limit_category(mut self, category: &'static str, max: usize, window: Duration) -> Self
+    where
+        A: ActionCategory,
+    {
+        self.category = Some(<A as ActionCategory>::category);
+        self.push_rule(RateTarget::Category(category), max, window);
+        self
+    }
+
+    /// Drop actions named `name` that equal the last one let through
+    pub fn dedupe(mut self, name: &'static str) -> Self {
+        self.dedupe.push(name);
+        self
+    }
+
+    /// Number of actions dropped so far
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Clear all windows and remembered actions
+    pub fn reset(&mut self) {
+        for rule in &mut self.rules {
+            rule.passed.clear();
+        }
+        self.last.clear();
+    }
+
+    fn push_rule(&mut self, target: RateTarget, max: usize, window: Duration) {
+        self.rules.push(RateRule {
+            target,
+            max,
+            window,
+            passed: VecDeque::new(),
+        });
+    }
+}
+
+impl<A: Action> RateLimitMiddleware<A> {
+    /// Decide whether `action` passes at `now`, recording it if so
+    fn allow_at(&mut self, action: &A, now: Instant) -> bool {
+        let name = action.name();
+        let category = self.category.and_then(|category| category(action));
+
+        let repr = self.dedupe.contains(&name).then(|| format!("{:?}", action));
+        let duplicate = repr
+            .as_ref()
+            .is_some_and(|repr| self.last.get(name) == Some(repr));
+
+        // Check every matching rule so each one prunes its window
+        let mut limited = false;
+        for rule in &mut self.rules {
+            if rule.matches(name, category) && !rule.has_room(now) {
+                limited = true;
+            }
+        }
+
+        if duplicate || limited {
+            self.dropped += 1;
+            tracing::trace!(action = name, duplicate, "Action rate limited");
+            return false;
+        }
+
+        for rule in &mut self.rules {
+            if rule.matches(name, category) {
+                rule.passed.push_back(now);
+            }
+        }
+        if let Some(repr) = repr {
+            self.last.insert(name, repr);
+        }
+        true
+    }
+}
+
+impl<A: Action> Middleware<A> for RateLimitMiddleware<A> {
+    fn intercept(&mut self, action: A) -> Option<A> {
+        self.allow_at(&action, Instant::now()).then_some(action)
+    }
+
+    fn before(&mut self, _action: &A) {}
+
+    fn after(&mut self, _action: &A, _state_changed: bool) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::StoreWithMiddleware;
+
+    #[derive(Clone, Debug)]
+    enum TestAction {
+        SearchInput(char),
+        ScrollDown,
+        SetClock(u32),
+        Quit,
+    }
+
+    impl Action for TestAction {
+        fn name(&self) -> &'static str {
+            match self {
+                TestAction::SearchInput(_) => "SearchInput",
+                TestAction::ScrollDown => "ScrollDown",
+                TestAction::SetClock(_) => "SetClock",
+                TestAction::Quit => "Quit",
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestCategory {
+        Scroll,
+        Uncategorized,
+    }
+
+    impl ActionCategory for TestAction {
+        type Category = TestCategory;
+
+        fn category(&self) -> Option<&'static str> {
+            matches!(self, TestAction::ScrollDown).then_some("scroll")
+        }
+
+        fn category_enum(&self) -> TestCategory {
+            match self {
+                TestAction::ScrollDown => TestCategory::Scroll,
+                _ => TestCategory::Uncategorized,
+            }
+        }
+    }
+
+    #[test]
+    fn test_limit_by_name_uses_sliding_window() {
+        let mut limiter =
+            RateLimitMiddleware::new().limit("SearchInput", 2, Duration::from_secs(1));
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let key = TestAction::SearchInput('a');
+
+        assert!(limiter.allow_at(&key, at(0)));
+        assert!(limiter.allow_at(&key, at(300)));
+        assert!(!limiter.allow_at(&key, at(600)));
+        // Other actions are not limited
+        assert!(limiter.allow_at(&TestAction::Quit, at(600)));
+        // The first pass left the window
+        assert!(limiter.allow_at(&key, at(1000)));
+        assert!(!limiter.allow_at(&key, at(1200)));
+        assert!(limiter.allow_at(&key, at(1300)));
+        assert_eq!(limiter.dropped(), 2);
+    }
+
+    #[test]
+    fn test_limit_by_category() {
+        let mut limiter =
+            RateLimitMiddleware::new().limit_category("scroll", 1, Duration::from_millis(100));
+        let start = Instant::now();
+
+        assert!(limiter.allow_at(&TestAction::ScrollDown, start));
+        assert!(!limiter.allow_at(&TestAction::ScrollDown, start + Duration::from_millis(50)));
+        assert!(limiter.allow_at(&TestAction::SearchInput('a'), start));
+        assert!(limiter.allow_at(&TestAction::ScrollDown, start + Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_dedupe_drops_repeated_values() {
+        let mut store = StoreWithMiddleware::new(
+            Vec::new(),
+            |seen: &mut Vec<u32>, action: TestAction| match action {
+                TestAction::SetClock(time) => {
+                    seen.push(time);
+                    true
+                }
+                _ => false,
+            },
+            RateLimitMiddleware::new().dedupe("SetClock"),
+        );
+
+        for time in [1, 1, 1, 2, 2, 1] {
+            store.dispatch(TestAction::SetClock(time));
+        }
+
+        assert_eq!(store.state(), &vec![1, 2, 1]);
+        assert_eq!(store.middleware().dropped(), 3);
+
+        store.middleware_mut().reset();
+        store.dispatch(TestAction::SetClock(1));
+        assert_eq!(store.state().len(), 4);
+    }
+}
@@ -0,0 +1,504 @@
+//! Action recording and deterministic replay
+//!
+//! [`RecorderMiddleware`] writes every dispatched action to a JSON Lines
+//! stream together with the time elapsed since recording started.
+//! [`load_recording`] reads such a stream back, and [`replay_actions`]
+//! drives a fresh store with the recorded actions, optionally preserving
+//! (or scaling) the original timing.
+//!
+//! Actions must implement `serde::Serialize` and [`ActionDescribe`] (derived
+//! by `#[derive(Action)]`) to be recorded, and `serde::de::DeserializeOwned`
+//! to be replayed. Fields marked `#[param(redact)]` are written as `"***"`
+//! unless [`record_secrets`](RecorderMiddleware::record_secrets) is enabled.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use tui_dispatch::replay::{load_recording_file, replay_actions, RecorderMiddleware};
+//!
+//! // Record a session
+//! let recorder = RecorderMiddleware::to_file("session.jsonl")?;
+//! let mut store = StoreWithMiddleware::new(AppState::default(), reducer, recorder);
+//! // ... run the app ...
+//! store.middleware_mut().flush()?;
+//!
+//! // Later: replay it against a fresh store at double speed
+//! let recording = load_recording_file::<AppAction>("session.jsonl")?;
+//! let mut store = Store::new(AppState::default(), reducer);
+//! replay_actions(&mut store, recording, Some(2.0));
+//! ```
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::action::{ActionDescribe, ActionSchema, VariantKind};
+use crate::runtime::DispatchStore;
+use crate::store::Middleware;
+use crate::Action;
+
+/// Written in place of `#[param(redact)]` field values
+const REDACTED: &str = "***";
+
+/// Redacted fields of one variant, as serde lays them out
+#[derive(Debug)]
+enum RedactedFields {
+    /// Struct variant field names
+    Named(Vec<String>),
+    /// Tuple variant positions, out of `len` fields
+    Positions { positions: Vec<usize>, len: usize },
+}
+
+/// Redacted fields per variant name, built from an [`ActionSchema`]
+fn redactions(schema: &ActionSchema) -> HashMap<String, RedactedFields> {
+    schema
+        .variants
+        .iter()
+        .filter(|variant| variant.fields.iter().any(|field| field.redacted))
+        .map(|variant| {
+            let redacted = variant
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| f.redacted);
+            let fields = match variant.kind {
+                VariantKind::Struct => {
+                    RedactedFields::Named(redacted.filter_map(|(_, f)| f.name.clone()).collect())
+                }
+                _ => RedactedFields::Positions {
+                    positions: redacted.map(|(index, _)| index).collect(),
+                    len: variant.fields.len(),
+                },
+            };
+            (variant.name.clone(), fields)
+        })
+        .collect()
+}
+
+/// Replace redacted values in a serialized action (serde's default
+/// externally tagged layout)
+///
+/// Fails rather than leak a value when the layout isn't the expected one,
+/// e.g. with `#[serde(tag = "...")]` or renamed fields.
+fn redact(value: &mut serde_json::Value, fields: &RedactedFields) -> io::Result<()> {
+    use serde_json::Value;
+
+    let unexpected = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "cannot locate #[param(redact)] fields in the serialized action",
+        )
+    };
+    let Value::Object(map) = value else {
+        return Err(unexpected());
+    };
+    if map.len() != 1 {
+        return Err(unexpected());
+    }
+    let Some(inner) = map.values_mut().next() else {
+        return Err(unexpected());
+    };
+    match (fields, inner) {
+        (RedactedFields::Named(names), Value::Object(inner)) => {
+            for name in names {
+                *inner.get_mut(name).ok_or_else(unexpected)? = REDACTED.into();
+            }
+        }
+        // Newtype variants serialize their only field directly
+        (RedactedFields::Positions { len: 1, .. }, inner) => *inner = REDACTED.into(),
+        (RedactedFields::Positions { positions, .. }, Value::Array(items)) => {
+            for &position in positions {
+                *items.get_mut(position).ok_or_else(unexpected)? = REDACTED.into();
+            }
+        }
+        _ => return Err(unexpected()),
+    }
+    Ok(())
+}
+
+/// A single recorded action with its timing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedAction<A> {
+    /// Milliseconds since the recording started
+    pub elapsed_ms: u64,
+    /// The dispatched action
+    pub action: A,
+}
+
+/// Middleware that records dispatched actions as JSON Lines
+///
+/// Each action is written as one `RecordedAction` object per line before it
+/// reaches the reducer. Write errors are logged via `tracing` and counted;
+/// they never interrupt dispatch.
+///
+/// Fields marked `#[param(redact)]` are written as `"***"`, so secrets never
+/// reach the file. Replaying such a line gives the field the value `"***"`
+/// (or fails to load if the field isn't a string); opt in to writing the
+/// real values with [`record_secrets`](Self::record_secrets).
+pub struct RecorderMiddleware<W: Write> {
+    writer: W,
+    start: Instant,
+    recorded: usize,
+    errors: usize,
+    record_secrets: bool,
+    /// Built from the action schema on the first record
+    redactions: Option<HashMap<String, RedactedFields>>,
+}
+
+impl<W: Write> std::fmt::Debug for RecorderMiddleware<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecorderMiddleware")
+            .field("recorded", &self.recorded)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+impl RecorderMiddleware<BufWriter<File>> {
+    /// Create a recorder that writes to a file (truncating it)
+    pub fn to_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> RecorderMiddleware<W> {
+    /// Create a recorder that writes to the given writer
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            start: Instant::now(),
+            recorded: 0,
+            errors: 0,
+            record_secrets: false,
+            redactions: None,
+        }
+    }
+
+    /// Write `#[param(redact)]` fields as they are (default `false`)
+    ///
+    /// Only for recordings that stay on the machine, e.g. to replay a
+    /// session exactly.
+    pub fn record_secrets(mut self, enabled: bool) -> Self {
+        self.record_secrets = enabled;
+        self
+    }
+
+    /// Number of actions successfully recorded
+    pub fn recorded(&self) -> usize {
+        self.recorded
+    }
+
+    /// Number of actions that failed to serialize or write
+    pub fn errors(&self) -> usize {
+        self.errors
+    }
+
+    /// Flush the underlying writer
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Get a reference to the underlying writer
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consume the recorder and return the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn record<A: ActionDescribe + Serialize>(&mut self, action: &A) -> io::Result<()> {
+        let mut action_value = serde_json::to_value(action)?;
+        if !self.record_secrets {
+            let table = self
+                .redactions
+                .get_or_insert_with(|| redactions(&A::schema()));
+            if let Some(fields) = table.get(action.name()) {
+                redact(&mut action_value, fields)?;
+            }
+        }
+        let entry = RecordedAction {
+            elapsed_ms: self.start.elapsed().as_millis() as u64,
+            action: action_value,
+        };
+        serde_json::to_writer(&mut self.writer, &entry)?;
+        self.writer.write_all(b"\n")
+    }
+}
+
+impl<A: ActionDescribe + Serialize, W: Write> Middleware<A> for RecorderMiddleware<W> {
+    fn before(&mut self, action: &A) {
+        match self.record(action) {
+            Ok(()) => self.recorded += 1,
+            Err(e) => {
+                self.errors += 1;
+                tracing::warn!(action = %action.name(), error = %e, "Failed to record action");
+            }
+        }
+    }
+
+    fn after(&mut self, _action: &A, _state_changed: bool) {}
+}
+
+/// Read a recording from a JSON Lines stream
+///
+/// Blank lines are skipped. Fails on the first line that cannot be parsed.
+pub fn load_recording<A: DeserializeOwned>(
+    reader: impl BufRead,
+) -> io::Result<Vec<RecordedAction<A>>> {
+    let mut recording = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", index + 1, e),
+            )
+        })?;
+        recording.push(entry);
+    }
+    Ok(recording)
+}
+
+/// Read a recording from a file
+pub fn load_recording_file<A: DeserializeOwned>(
+    path: impl AsRef<Path>,
+) -> io::Result<Vec<RecordedAction<A>>> {
+    load_recording(BufReader::new(File::open(path)?))
+}
+
+/// Replay recorded actions against a store
+///
+/// `time_scale` controls pacing between actions:
+/// - `None`: dispatch as fast as possible (deterministic tests)
+/// - `Some(1.0)`: preserve original timing
+/// - `Some(2.0)`: twice as fast, `Some(0.5)`: half speed
+///
+/// Pacing uses `std::thread::sleep`, so avoid calling this with a time
+/// scale from inside an async task.
+///
+/// Returns the number of actions that changed state.
+pub fn replay_actions<S, A, St>(
+    store: &mut St,
+    recording: impl IntoIterator<Item = RecordedAction<A>>,
+    time_scale: Option<f64>,
+) -> usize
+where
+    A: Action,
+    St: DispatchStore<S, A>,
+{
+    let mut last_ms = 0;
+    let mut changed = 0;
+
+    for entry in recording {
+        if let Some(scale) = time_scale.filter(|s| *s > 0.0) {
+            let delta_ms = entry.elapsed_ms.saturating_sub(last_ms);
+            if delta_ms > 0 {
+                std::thread::sleep(Duration::from_secs_f64(delta_ms as f64 / 1000.0 / scale));
+            }
+        }
+        last_ms = entry.elapsed_ms;
+
+        if store.dispatch(entry.action) {
+            changed += 1;
+        }
+    }
+
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::{Store, StoreWithMiddleware};
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    enum TestAction {
+        Add(i32),
+        NoOp,
+    }
+
+    impl Action for TestAction {
+        fn name(&self) -> &'static str {
+            match self {
+                TestAction::Add(_) => "Add",
+                TestAction::NoOp => "NoOp",
+            }
+        }
+    }
+
+    impl ActionDescribe for TestAction {
+        fn schema() -> ActionSchema {
+            ActionSchema {
+                name: "TestAction".into(),
+                doc: None,
+                variants: Vec::new(),
+            }
+        }
+    }
+
+    fn reducer(state: &mut i32, action: TestAction) -> bool {
+        match action {
+            TestAction::Add(n) => {
+                *state += n;
+                true
+            }
+            TestAction::NoOp => false,
+        }
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let mut store = StoreWithMiddleware::new(0, reducer, RecorderMiddleware::new(Vec::new()));
+        store.dispatch(TestAction::Add(2));
+        store.dispatch(TestAction::NoOp);
+        store.dispatch(TestAction::Add(5));
+        assert_eq!(store.middleware().recorded(), 3);
+
+        let bytes = std::mem::take(&mut store.middleware_mut().writer);
+        let recording: Vec<RecordedAction<TestAction>> = load_recording(bytes.as_slice()).unwrap();
+        assert_eq!(recording.len(), 3);
+        assert_eq!(recording[0].action, TestAction::Add(2));
+
+        let mut replayed = Store::new(0, reducer);
+        let changed = replay_actions(&mut replayed, recording, None);
+        assert_eq!(changed, 2);
+        assert_eq!(*replayed.state(), *store.state());
+    }
+
+    #[test]
+    fn test_redacted_fields_never_recorded() {
+        use crate::action::{FieldSchema, VariantSchema};
+
+        #[derive(Clone, Debug, Serialize)]
+        enum Login {
+            Submit { user: String, password: String },
+            Token(String),
+            Pair(u32, String),
+        }
+
+        impl Action for Login {
+            fn name(&self) -> &'static str {
+                match self {
+                    Login::Submit { .. } => "Submit",
+                    Login::Token(_) => "Token",
+                    Login::Pair(..) => "Pair",
+                }
+            }
+        }
+
+        fn field(name: Option<&str>, redacted: bool) -> FieldSchema {
+            FieldSchema {
+                name: name.map(Into::into),
+                ty: "String".into(),
+                doc: None,
+                redacted,
+            }
+        }
+
+        fn variant(name: &str, kind: VariantKind, fields: Vec<FieldSchema>) -> VariantSchema {
+            VariantSchema {
+                name: name.into(),
+                category: None,
+                doc: None,
+                kind,
+                fields,
+            }
+        }
+
+        impl ActionDescribe for Login {
+            fn schema() -> ActionSchema {
+                ActionSchema {
+                    name: "Login".into(),
+                    doc: None,
+                    variants: vec![
+                        variant(
+                            "Submit",
+                            VariantKind::Struct,
+                            vec![field(Some("user"), false), field(Some("password"), true)],
+                        ),
+                        variant("Token", VariantKind::Tuple, vec![field(None, true)]),
+                        variant(
+                            "Pair",
+                            VariantKind::Tuple,
+                            vec![field(None, false), field(None, true)],
+                        ),
+                    ],
+                }
+            }
+        }
+
+        let actions = [
+            Login::Submit {
+                user: "ada".into(),
+                password: "hunter2".into(),
+            },
+            Login::Token("hunter2".into()),
+            Login::Pair(7, "hunter2".into()),
+        ];
+
+        let mut recorder = RecorderMiddleware::new(Vec::new());
+        for action in &actions {
+            Middleware::<Login>::before(&mut recorder, action);
+        }
+        assert_eq!(recorder.recorded(), 3);
+        let output = String::from_utf8(recorder.into_inner()).unwrap();
+        assert!(!output.contains("hunter2"));
+        assert!(output.contains(r#"{"Submit":{"user":"ada","password":"***"}}"#));
+        assert!(output.contains(r#"{"Token":"***"}"#));
+        assert!(output.contains(r#"{"Pair":[7,"***"]}"#));
+
+        let mut recorder = RecorderMiddleware::new(Vec::new()).record_secrets(true);
+        Middleware::<Login>::before(&mut recorder, &actions[1]);
+        assert!(String::from_utf8(recorder.into_inner())
+            .unwrap()
+            .contains("hunter2"));
+    }
+
+    #[test]
+    fn test_load_recording_skips_blank_lines() {
+        let input =
+            "{\"elapsed_ms\":0,\"action\":\"NoOp\"}\n\n{\"elapsed_ms\":5,\"action\":{\"Add\":1}}\n";
+        let recording: Vec<RecordedAction<TestAction>> = load_recording(input.as_bytes()).unwrap();
+        assert_eq!(recording.len(), 2);
+        assert_eq!(recording[1].elapsed_ms, 5);
+    }
+
+    #[test]
+    fn test_load_recording_reports_line() {
+        let input = "{\"elapsed_ms\":0,\"action\":\"NoOp\"}\nnot json\n";
+        let err = load_recording::<TestAction>(input.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 2"));
+    }
+
+    #[test]
+    fn test_replay_time_scale() {
+        let recording = vec![
+            RecordedAction {
+                elapsed_ms: 0,
+                action: TestAction::Add(1),
+            },
+            RecordedAction {
+                elapsed_ms: 40,
+                action: TestAction::Add(1),
+            },
+        ];
+
+        let mut store = Store::new(0, reducer);
+        let start = Instant::now();
+        replay_actions(&mut store, recording, Some(2.0));
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(*store.state(), 2);
+    }
+}
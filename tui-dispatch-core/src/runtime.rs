@@ -3,20 +3,31 @@
 //! These helpers wrap the common event/action/render loop while keeping
 //! the same behavior as the manual wiring shown in the examples.
 
+use std::collections::VecDeque;
+use std::future::Future;
 use std::io;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::process::{Command, ExitStatus};
+use std::sync::{Arc, Mutex, TryLockError};
 use std::time::Duration;
 
-use ratatui::backend::Backend;
+use crossterm::event::{KeyEvent, MouseEventKind};
+use ratatui::backend::{Backend, CrosstermBackend};
 use ratatui::layout::Rect;
 use ratatui::{Frame, Terminal};
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
-use crate::bus::{process_raw_event, spawn_event_poller, RawEvent};
-use crate::debug::{DebugLayer, DebugState};
-use crate::effect::{DispatchResult, EffectStore, EffectStoreWithMiddleware};
+use crate::bus::{process_raw_event, spawn_gated_event_poller, RawEvent};
+use crate::debug::{ActionLog, ActionLogConfig, DebugLayer, DebugState};
+use crate::effect::{DirtyComponents, DispatchResult, EffectStore, EffectStoreWithMiddleware};
 use crate::event::EventKind;
+use crate::keybindings::{BindingContext, Keybindings};
+use crate::persistence::{Persist, PersistError, PersistPolicy, Persister};
 use crate::store::{Middleware, Reducer, Store, StoreWithMiddleware};
+use crate::terminal::{PanicHookGuard, TerminalGuard, TerminalModes};
+use crate::thunk::Thunk;
 use crate::{Action, ActionParams};
 
 #[cfg(feature = "subscriptions")]
@@ -31,6 +42,18 @@ pub struct PollerConfig {
     pub poll_timeout: Duration,
     /// Sleep between poll cycles.
     pub loop_sleep: Duration,
+    /// Upper bound on draws per second (None = draw after every change).
+    ///
+    /// State changes within one frame budget are coalesced into a single
+    /// draw at the end of the budget. See `render_immediately_on` on the
+    /// runtimes for actions that should skip the wait.
+    pub max_fps: Option<u32>,
+    /// Merge queued scroll and mouse move/drag events before dispatch.
+    ///
+    /// Consecutive scrolls at the same position become one scroll with the
+    /// summed delta, and runs of moves or drags keep only the last position,
+    /// so a fast wheel or drag costs one action instead of dozens.
+    pub coalesce_input: bool,
 }
 
 impl Default for PollerConfig {
@@ -38,17 +61,40 @@ impl Default for PollerConfig {
         Self {
             poll_timeout: Duration::from_millis(10),
             loop_sleep: Duration::from_millis(16),
+            max_fps: None,
+            coalesce_input: true,
         }
     }
 }
 
+impl PollerConfig {
+    /// Limit draws to `fps` per second.
+    pub fn with_max_fps(mut self, fps: u32) -> Self {
+        self.max_fps = Some(fps);
+        self
+    }
+
+    /// Enable or disable merging of scroll and mouse move/drag floods.
+    pub fn with_input_coalescing(mut self, enabled: bool) -> Self {
+        self.coalesce_input = enabled;
+        self
+    }
+}
+
 /// Result of mapping an event into actions plus an optional render hint.
+///
+/// `consumed` tells a parent whether the event was handled, so it can stop
+/// offering it to other handlers (see [`or_else`](Self::or_else)). An event
+/// can be consumed without producing actions, e.g. a key swallowed by an
+/// open dropdown.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EventOutcome<A> {
     /// Actions to enqueue.
     pub actions: Vec<A>,
     /// Whether to force a re-render.
     pub needs_render: bool,
+    /// Whether the event was handled and should not bubble further.
+    pub consumed: bool,
 }
 
 /// Context passed to render closures.
@@ -56,6 +102,29 @@ pub struct EventOutcome<A> {
 pub struct RenderContext {
     /// Whether the debug overlay is currently active.
     pub debug_enabled: bool,
+    /// Store state version, for memoizing derived data with a [`Selector`](crate::Selector).
+    pub state_version: u64,
+    /// Number of frames rendered before this one by the current `run`.
+    pub frame_index: u64,
+    /// Time since the previous frame (zero for the first frame).
+    pub delta_time: Duration,
+    /// Monotonic time since the runtime loop started.
+    ///
+    /// Render functions can drive animations from this instead of keeping
+    /// timing in app state.
+    pub timestamp: Duration,
+    /// Components touched since the previous frame.
+    ///
+    /// Filled by [`EffectRuntime`] from [`DispatchResult::touching`](crate::DispatchResult::touching)
+    /// hints; everything else counts as dirty. Render code can reuse cached
+    /// output (layouts, formatted rows) for components not in the set.
+    pub dirty: DirtyComponents,
+    /// Whether the terminal currently reports mouse events to the app.
+    ///
+    /// Apps can show a hint (e.g. "mouse: select text") when it is off.
+    pub mouse_capture: bool,
+    /// Terminal size as `(width, height)`, updated on resize.
+    pub terminal_size: (u16, u16),
 }
 
 impl RenderContext {
@@ -66,37 +135,47 @@ impl RenderContext {
 }
 
 impl<A> EventOutcome<A> {
-    /// No actions and no render.
+    /// No actions and no render; the event was not handled.
     pub fn ignored() -> Self {
         Self {
             actions: Vec::new(),
             needs_render: false,
+            consumed: false,
         }
     }
 
-    /// No actions, but request a render.
-    pub fn needs_render() -> Self {
+    /// Handled, but no actions and no render.
+    pub fn consumed() -> Self {
         Self {
             actions: Vec::new(),
-            needs_render: true,
+            needs_render: false,
+            consumed: true,
         }
     }
 
+    /// No actions, but request a render. Counts as consumed.
+    pub fn needs_render() -> Self {
+        Self::consumed().with_render()
+    }
+
     /// Wrap a single action.
     pub fn action(action: A) -> Self {
         Self {
             actions: vec![action],
             needs_render: false,
+            consumed: true,
         }
     }
 
-    /// Wrap multiple actions.
+    /// Wrap multiple actions. Consumed if there is at least one.
     pub fn actions<I>(actions: I) -> Self
     where
         I: IntoIterator<Item = A>,
     {
+        let actions: Vec<A> = actions.into_iter().collect();
         Self {
-            actions: actions.into_iter().collect(),
+            consumed: !actions.is_empty(),
+            actions,
             needs_render: false,
         }
     }
@@ -106,6 +185,28 @@ impl<A> EventOutcome<A> {
         self.needs_render = true;
         self
     }
+
+    /// Fall back to `f` if this outcome did not consume the event.
+    ///
+    /// Chain handlers from the innermost component outwards to let events
+    /// bubble until one handles them:
+    ///
+    /// ```ignore
+    /// let outcome = dropdown
+    ///     .handle_event_outcome(event, dropdown_props)
+    ///     .or_else(|| form.handle_event_outcome(event, form_props))
+    ///     .or_else(|| global_keys(event));
+    /// ```
+    ///
+    /// A render request from an unconsumed outcome is kept.
+    pub fn or_else(self, f: impl FnOnce() -> Self) -> Self {
+        if self.consumed {
+            return self;
+        }
+        let mut next = f();
+        next.needs_render |= self.needs_render;
+        next
+    }
 }
 
 impl<A> Default for EventOutcome<A> {
@@ -122,10 +223,7 @@ impl<A> From<A> for EventOutcome<A> {
 
 impl<A> From<Vec<A>> for EventOutcome<A> {
     fn from(actions: Vec<A>) -> Self {
-        Self {
-            actions,
-            needs_render: false,
-        }
+        Self::actions(actions)
     }
 }
 
@@ -144,10 +242,79 @@ impl<A> EventOutcome<A> {
     /// Useful for converting `Component::handle_event` results which return
     /// `impl IntoIterator<Item = A>`.
     pub fn from_actions(iter: impl IntoIterator<Item = A>) -> Self {
+        Self::actions(iter)
+    }
+}
+
+/// App-wide hotkeys checked before `map_event`.
+///
+/// Maps the global bindings of a [`Keybindings`] table to actions, so keys
+/// like quit, help or the command palette live in one place instead of every
+/// mode's `match`. Attach with `with_global_hotkeys` on a runtime, or call
+/// [`handle`](Self::handle) from your own event mapping.
+///
+/// ```ignore
+/// let mut keys = Keybindings::<Ctx>::new();
+/// keys.add_global("quit", vec!["q".into(), "ctrl+c".into()]);
+/// keys.add_global("help", vec!["?".into()]);
+///
+/// let hotkeys = GlobalHotkeys::new(keys)
+///     .command("quit", Action::Quit)
+///     .command("help", Action::ToggleHelp)
+///     // Let text inputs receive `q` and `?`
+///     .skip_when(|state: &AppState| state.search.is_focused());
+/// ```
+pub struct GlobalHotkeys<S, A> {
+    lookup: Box<dyn Fn(KeyEvent) -> Option<String>>,
+    commands: Vec<(String, A)>,
+    skip_when: Option<Box<dyn Fn(&S) -> bool>>,
+}
+
+impl<S, A: Action> GlobalHotkeys<S, A> {
+    /// Create hotkeys from the global bindings of `keybindings`.
+    ///
+    /// Context-specific bindings are ignored.
+    pub fn new<C: BindingContext + 'static>(keybindings: Keybindings<C>) -> Self {
         Self {
-            actions: iter.into_iter().collect(),
-            needs_render: false,
+            lookup: Box::new(move |key| keybindings.get_global_command(key)),
+            commands: Vec::new(),
+            skip_when: None,
+        }
+    }
+
+    /// Dispatch `action` when a key bound to `command` is pressed.
+    ///
+    /// Commands without an action are left to `map_event`.
+    pub fn command(mut self, command: impl Into<String>, action: A) -> Self {
+        self.commands.push((command.into(), action));
+        self
+    }
+
+    /// Skip hotkeys while `predicate` holds, e.g. while a text input has focus.
+    pub fn skip_when(mut self, predicate: impl Fn(&S) -> bool + 'static) -> Self {
+        self.skip_when = Some(Box::new(predicate));
+        self
+    }
+
+    /// Map a key event to its hotkey action.
+    ///
+    /// Returns a consumed outcome with the action on a match, otherwise
+    /// [`EventOutcome::ignored`].
+    pub fn handle(&self, event: &EventKind, state: &S) -> EventOutcome<A> {
+        let EventKind::Key(key) = event else {
+            return EventOutcome::ignored();
+        };
+        if self.skip_when.as_ref().is_some_and(|skip| skip(state)) {
+            return EventOutcome::ignored();
         }
+        let Some(command) = (self.lookup)(*key) else {
+            return EventOutcome::ignored();
+        };
+        self.commands
+            .iter()
+            .find(|(name, _)| *name == command)
+            .map(|(_, action)| action.clone())
+            .into()
     }
 }
 
@@ -168,7 +335,12 @@ trait DebugAdapter<S, A>: 'static {
     ) -> Option<bool>;
 
     fn log_action(&mut self, action: &A);
+    fn record_state(&mut self, state: &S);
+    fn record_dispatched_state(&mut self, state: &S, changed: bool);
+    fn record_change(&mut self, changed: bool);
+    fn record_queue_depth(&self, depth: usize);
     fn is_enabled(&self) -> bool;
+    fn wants_mouse_capture(&self) -> bool;
 }
 
 impl<S, A> DebugAdapter<S, A> for DebugLayer<A>
@@ -204,9 +376,222 @@ where
         DebugLayer::log_action(self, action);
     }
 
+    fn record_state(&mut self, state: &S) {
+        DebugLayer::record_state(self, state);
+    }
+
+    fn record_dispatched_state(&mut self, state: &S, changed: bool) {
+        DebugLayer::record_dispatched_state(self, state, changed);
+    }
+
+    fn record_change(&mut self, changed: bool) {
+        DebugLayer::record_change(self, changed);
+    }
+
+    fn record_queue_depth(&self, depth: usize) {
+        DebugLayer::record_queue_depth(self, depth);
+    }
+
     fn is_enabled(&self) -> bool {
         DebugLayer::is_enabled(self)
     }
+
+    fn wants_mouse_capture(&self) -> bool {
+        DebugLayer::wants_mouse_capture(self)
+    }
+}
+
+/// Frame timing for [`RenderContext`] and the `max_fps` limit.
+struct FrameClock {
+    started: tokio::time::Instant,
+    last_frame: Option<tokio::time::Instant>,
+    frame_index: u64,
+    /// Minimum time between frames
+    frame_budget: Option<Duration>,
+}
+
+impl FrameClock {
+    fn new(max_fps: Option<u32>) -> Self {
+        Self {
+            started: tokio::time::Instant::now(),
+            last_frame: None,
+            frame_index: 0,
+            frame_budget: max_fps
+                .filter(|&fps| fps > 0)
+                .map(|fps| Duration::from_secs(1) / fps),
+        }
+    }
+
+    /// When the next frame may be drawn, or `None` if it may be drawn now.
+    fn next_frame(&self) -> Option<tokio::time::Instant> {
+        let due = self.last_frame? + self.frame_budget?;
+        (due > tokio::time::Instant::now()).then_some(due)
+    }
+
+    /// Start a frame, filling in the timing fields of `ctx`.
+    fn frame(&mut self, ctx: RenderContext) -> RenderContext {
+        let now = tokio::time::Instant::now();
+        let delta_time = self
+            .last_frame
+            .map_or(Duration::ZERO, |last| now.duration_since(last));
+        let ctx = RenderContext {
+            frame_index: self.frame_index,
+            delta_time,
+            timestamp: now.duration_since(self.started),
+            ..ctx
+        };
+        self.last_frame = Some(now);
+        self.frame_index += 1;
+        ctx
+    }
+}
+
+/// Persistence wiring for `EffectRuntime::with_persistence`.
+struct PersistHook<S, A> {
+    write: Box<dyn Fn(&S) -> Result<(), PersistError>>,
+    policy: PersistPolicy<A>,
+    /// Pending debounced save
+    deadline: Option<tokio::time::Instant>,
+}
+
+impl<S, A> PersistHook<S, A> {
+    fn save(&mut self, state: &S) {
+        self.deadline = None;
+        if let Err(error) = (self.write)(state) {
+            tracing::warn!(%error, "Failed to persist state");
+        }
+    }
+
+    fn after_dispatch(&mut self, state: &S, save_now: bool, changed: bool) {
+        if save_now {
+            self.save(state);
+        } else if changed {
+            if let Some(delay) = self.policy.debounce {
+                self.deadline = Some(tokio::time::Instant::now() + delay);
+            }
+        }
+    }
+
+    fn on_exit(&mut self, state: &S) {
+        if self.policy.on_quit || self.deadline.is_some() {
+            self.save(state);
+        }
+    }
+}
+
+/// Default time budget for shutdown hooks.
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Future returned by a shutdown hook.
+type ShutdownFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Async cleanup registered with `on_shutdown`.
+struct ShutdownHooks<S> {
+    hooks: Vec<Box<dyn FnMut(&S) -> ShutdownFuture>>,
+    timeout: Duration,
+}
+
+impl<S> ShutdownHooks<S> {
+    fn new() -> Self {
+        Self {
+            hooks: Vec::new(),
+            timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+        }
+    }
+
+    fn push<F, Fut>(&mut self, mut hook: F)
+    where
+        F: FnMut(&S) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.hooks
+            .push(Box::new(move |state| Box::pin(hook(state))));
+    }
+
+    /// Run all hooks in registration order, giving up after the timeout.
+    async fn run(&mut self, state: &S) {
+        if self.hooks.is_empty() {
+            return;
+        }
+        let futures: Vec<ShutdownFuture> = self.hooks.iter_mut().map(|hook| hook(state)).collect();
+        let run_all = async {
+            for future in futures {
+                future.await;
+            }
+        };
+        if tokio::time::timeout(self.timeout, run_all).await.is_err() {
+            tracing::warn!(
+                timeout_ms = self.timeout.as_millis() as u64,
+                "Shutdown hooks timed out"
+            );
+        }
+    }
+}
+
+/// Recent actions kept for the crash report, see `with_crash_report`.
+struct CrashLog<A> {
+    log: Arc<Mutex<ActionLog>>,
+    record: fn(&mut ActionLog, &A),
+}
+
+impl<A: ActionParams> CrashLog<A> {
+    fn new(count: usize) -> Self {
+        Self {
+            log: Arc::new(Mutex::new(ActionLog::new(ActionLogConfig::with_capacity(
+                count.max(1),
+            )))),
+            record: |log, action| {
+                log.log(action);
+            },
+        }
+    }
+}
+
+impl<A> CrashLog<A> {
+    fn record(&self, action: &A) {
+        if let Ok(mut log) = self.log.lock() {
+            (self.record)(&mut log, action);
+        }
+    }
+
+    /// Install the panic hook for `run`, including the report if enabled.
+    fn install_panic_hook(crash_log: Option<&Self>, modes: TerminalModes) -> PanicHookGuard {
+        match crash_log {
+            Some(crash_log) => {
+                let log = crash_log.log.clone();
+                PanicHookGuard::install_with_report(modes, move || crash_report(&log))
+            }
+            None => PanicHookGuard::install(modes),
+        }
+    }
+}
+
+/// Format the crash log, oldest action first.
+///
+/// Uses `try_lock` because the panic may have happened while the log was
+/// locked on the same thread.
+fn crash_report(log: &Mutex<ActionLog>) -> Option<String> {
+    let log = match log.try_lock() {
+        Ok(log) => log,
+        Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+        Err(TryLockError::WouldBlock) => return None,
+    };
+    if log.is_empty() {
+        return None;
+    }
+    let mut report = format!("Last {} actions before the panic:", log.len());
+    for entry in log.entries() {
+        let line = format!(
+            "  #{} [{}] {} {}",
+            entry.sequence,
+            entry.elapsed(),
+            entry.name,
+            entry.params()
+        );
+        report.push('\n');
+        report.push_str(line.trim_end());
+    }
+    Some(report)
 }
 
 /// Store interface used by `DispatchRuntime`.
@@ -215,9 +600,13 @@ pub trait DispatchStore<S, A: Action> {
     fn dispatch(&mut self, action: A) -> bool;
     /// Get the current state.
     fn state(&self) -> &S;
+    /// Get the state version (bumped when a dispatch changes state).
+    fn version(&self) -> u64;
+    /// Connect the runtime's action queue for middleware follow-up actions.
+    fn set_action_sender(&mut self, _action_tx: mpsc::UnboundedSender<A>) {}
 }
 
-impl<S, A: Action> DispatchStore<S, A> for Store<S, A> {
+impl<S, A: Action, E> DispatchStore<S, A> for Store<S, A, E> {
     fn dispatch(&mut self, action: A) -> bool {
         Store::dispatch(self, action)
     }
@@ -225,6 +614,10 @@ impl<S, A: Action> DispatchStore<S, A> for Store<S, A> {
     fn state(&self) -> &S {
         Store::state(self)
     }
+
+    fn version(&self) -> u64 {
+        Store::version(self)
+    }
 }
 
 impl<S, A: Action, M: Middleware<A>> DispatchStore<S, A> for StoreWithMiddleware<S, A, M> {
@@ -235,6 +628,14 @@ impl<S, A: Action, M: Middleware<A>> DispatchStore<S, A> for StoreWithMiddleware
     fn state(&self) -> &S {
         StoreWithMiddleware::state(self)
     }
+
+    fn version(&self) -> u64 {
+        StoreWithMiddleware::version(self)
+    }
+
+    fn set_action_sender(&mut self, action_tx: mpsc::UnboundedSender<A>) {
+        StoreWithMiddleware::set_action_sender(self, action_tx)
+    }
 }
 
 /// Effect store interface used by `EffectRuntime`.
@@ -243,6 +644,10 @@ pub trait EffectStoreLike<S, A: Action, E> {
     fn dispatch(&mut self, action: A) -> DispatchResult<E>;
     /// Get the current state.
     fn state(&self) -> &S;
+    /// Get the state version (bumped when a dispatch changes state).
+    fn version(&self) -> u64;
+    /// Connect the runtime's action queue for middleware follow-up actions.
+    fn set_action_sender(&mut self, _action_tx: mpsc::UnboundedSender<A>) {}
 }
 
 impl<S, A: Action, E> EffectStoreLike<S, A, E> for EffectStore<S, A, E> {
@@ -253,6 +658,10 @@ impl<S, A: Action, E> EffectStoreLike<S, A, E> for EffectStore<S, A, E> {
     fn state(&self) -> &S {
         EffectStore::state(self)
     }
+
+    fn version(&self) -> u64 {
+        EffectStore::version(self)
+    }
 }
 
 impl<S, A: Action, E, M: Middleware<A>> EffectStoreLike<S, A, E>
@@ -265,6 +674,264 @@ impl<S, A: Action, E, M: Middleware<A>> EffectStoreLike<S, A, E>
     fn state(&self) -> &S {
         EffectStoreWithMiddleware::state(self)
     }
+
+    fn version(&self) -> u64 {
+        EffectStoreWithMiddleware::version(self)
+    }
+
+    fn set_action_sender(&mut self, action_tx: mpsc::UnboundedSender<A>) {
+        EffectStoreWithMiddleware::set_action_sender(self, action_tx)
+    }
+}
+
+/// A scripted input for headless runs (see [`AppHarness`](crate::testing::AppHarness)).
+#[derive(Debug, Clone)]
+pub(crate) enum ScriptStep {
+    /// Deliver an event as if it came from the terminal.
+    Event(EventKind),
+    /// Sleep for a duration so timers and tasks can make progress.
+    Wait(Duration),
+}
+
+/// Where the run loop reads events from.
+enum EventFeed<'a, A> {
+    /// Live terminal events from the crossterm poller.
+    Terminal {
+        events: mpsc::UnboundedReceiver<RawEvent>,
+        /// Locked while the terminal is handed to the shell or a child process
+        gate: Arc<Mutex<()>>,
+        /// Event read while coalescing, delivered next
+        deferred: Option<EventKind>,
+        /// Merge scroll and mouse move/drag floods (see [`PollerConfig`])
+        coalesce_input: bool,
+    },
+    /// Scripted events; every action pulled from the queue is recorded.
+    Script {
+        steps: VecDeque<ScriptStep>,
+        history: &'a mut Vec<A>,
+    },
+}
+
+/// Maps an action and the current setting to a new mouse capture setting.
+type MouseCaptureToggle<A> = Box<dyn FnMut(&A, bool) -> Option<bool>>;
+
+/// Builds the action dispatched after a resize.
+type ResizeAction<A> = Box<dyn FnMut(u16, u16) -> A>;
+
+/// Merge resize events queued right after a resize into the last one.
+///
+/// `next` yields queued events without waiting. Returns the final size and
+/// the first event that was not a resize, if one was read.
+fn coalesce_resize(
+    mut size: (u16, u16),
+    mut next: impl FnMut() -> Option<EventKind>,
+) -> ((u16, u16), Option<EventKind>) {
+    while let Some(event) = next() {
+        match event {
+            EventKind::Resize(width, height) => size = (width, height),
+            other => return (size, Some(other)),
+        }
+    }
+    (size, None)
+}
+
+/// Fold `next` into `event` if both belong to the same pointer gesture.
+///
+/// Scrolls at the same position sum their deltas; moves, and drags with the
+/// same button, keep the latest position. Modifiers must match.
+fn merge_pointer(event: &mut EventKind, next: &EventKind) -> bool {
+    match (event, next) {
+        (
+            EventKind::Scroll { column, row, delta },
+            EventKind::Scroll {
+                column: next_column,
+                row: next_row,
+                delta: next_delta,
+            },
+        ) if column == next_column && row == next_row => {
+            *delta = delta.saturating_add(*next_delta);
+            true
+        }
+        (EventKind::Mouse(mouse), EventKind::Mouse(next_mouse))
+            if mouse.modifiers == next_mouse.modifiers
+                && match (mouse.kind, next_mouse.kind) {
+                    (MouseEventKind::Moved, MouseEventKind::Moved) => true,
+                    (MouseEventKind::Drag(button), MouseEventKind::Drag(next_button)) => {
+                        button == next_button
+                    }
+                    _ => false,
+                } =>
+        {
+            *mouse = *next_mouse;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Merge queued events of the same pointer gesture into `event`.
+///
+/// `next` yields queued events without waiting. Returns the merged event
+/// and the first event that could not be merged, if one was read.
+fn coalesce_pointer(
+    mut event: EventKind,
+    mut next: impl FnMut() -> Option<EventKind>,
+) -> (EventKind, Option<EventKind>) {
+    while let Some(queued) = next() {
+        if !merge_pointer(&mut event, &queued) {
+            return (event, Some(queued));
+        }
+    }
+    (event, None)
+}
+
+/// Terminal hand-off requested by an effect handler.
+enum TerminalRequest<A> {
+    Suspend,
+    Command {
+        command: Command,
+        on_exit: Box<dyn FnOnce(io::Result<ExitStatus>) -> A>,
+    },
+}
+
+impl<A> EventFeed<'_, A> {
+    /// Give the terminal to the shell or a child process, then take it back.
+    ///
+    /// Returns the action reporting a command's exit. Scripted runs have no
+    /// terminal: suspending does nothing and commands fail with `Unsupported`.
+    fn hand_off<B: Backend>(
+        &self,
+        terminal: &mut Terminal<B>,
+        modes: TerminalModes,
+        request: TerminalRequest<A>,
+    ) -> io::Result<Option<A>> {
+        let gate = match self {
+            EventFeed::Terminal { gate, .. } => Some(gate),
+            EventFeed::Script { .. } => None,
+        };
+        // Keep the poller from reading input while someone else owns the terminal
+        let _paused = gate.map(|gate| gate.lock().unwrap_or_else(|poisoned| poisoned.into_inner()));
+
+        match request {
+            TerminalRequest::Suspend => {
+                if gate.is_some() {
+                    crate::terminal::suspend(terminal, modes)?;
+                }
+                Ok(None)
+            }
+            TerminalRequest::Command {
+                mut command,
+                on_exit,
+            } => {
+                let status = if gate.is_some() {
+                    crate::terminal::run_external_command(terminal, modes, &mut command)
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::Unsupported,
+                        "external commands need a terminal",
+                    ))
+                };
+                Ok(Some(on_exit(status)))
+            }
+        }
+    }
+
+    /// Switch terminal mouse capture (nothing to switch for scripted runs).
+    fn set_mouse_capture(&self, enabled: bool) -> io::Result<()> {
+        match self {
+            EventFeed::Terminal { .. } => crate::terminal::set_mouse_capture(enabled),
+            EventFeed::Script { .. } => Ok(()),
+        }
+    }
+}
+
+/// One unit of work for the run loop.
+enum LoopInput<A> {
+    Event(EventKind),
+    Action(A),
+    PersistDue,
+    FrameDue,
+    Idle,
+    Closed,
+}
+
+impl<A: Action> EventFeed<'_, A> {
+    async fn next(
+        &mut self,
+        action_rx: &mut mpsc::UnboundedReceiver<A>,
+        persist_deadline: Option<tokio::time::Instant>,
+        frame_deadline: Option<tokio::time::Instant>,
+    ) -> LoopInput<A> {
+        match self {
+            EventFeed::Terminal {
+                events,
+                deferred,
+                coalesce_input,
+                ..
+            } => {
+                if let Some(event) = deferred.take() {
+                    return LoopInput::Event(event);
+                }
+                let input = tokio::select! {
+                    Some(raw_event) = events.recv() => LoopInput::Event(process_raw_event(raw_event)),
+                    Some(action) = action_rx.recv() => LoopInput::Action(action),
+                    _ = tokio::time::sleep_until(
+                        persist_deadline.unwrap_or_else(tokio::time::Instant::now)
+                    ), if persist_deadline.is_some() => LoopInput::PersistDue,
+                    _ = tokio::time::sleep_until(
+                        frame_deadline.unwrap_or_else(tokio::time::Instant::now)
+                    ), if frame_deadline.is_some() => LoopInput::FrameDue,
+                    else => LoopInput::Closed,
+                };
+                let LoopInput::Event(event) = input else {
+                    return input;
+                };
+                let queued = || events.try_recv().ok().map(process_raw_event);
+                let (event, next) = match event {
+                    EventKind::Resize(width, height) => {
+                        let ((width, height), next) = coalesce_resize((width, height), queued);
+                        (EventKind::Resize(width, height), next)
+                    }
+                    event if *coalesce_input => coalesce_pointer(event, queued),
+                    event => (event, None),
+                };
+                *deferred = next;
+                LoopInput::Event(event)
+            }
+            // Scripted runs drain the action queue before the next step so
+            // each event is fully processed (and rendered) before the next.
+            EventFeed::Script { steps, history } => {
+                if let Ok(action) = action_rx.try_recv() {
+                    history.push(action.clone());
+                    return LoopInput::Action(action);
+                }
+                if persist_deadline.is_some_and(|deadline| deadline <= tokio::time::Instant::now())
+                {
+                    return LoopInput::PersistDue;
+                }
+                match steps.pop_front() {
+                    Some(ScriptStep::Event(EventKind::Resize(width, height))) => {
+                        // Only back-to-back resize steps (no wait between) merge
+                        let ((width, height), _) =
+                            coalesce_resize((width, height), || match steps.front() {
+                                Some(&ScriptStep::Event(EventKind::Resize(width, height))) => {
+                                    steps.pop_front();
+                                    Some(EventKind::Resize(width, height))
+                                }
+                                _ => None,
+                            });
+                        LoopInput::Event(EventKind::Resize(width, height))
+                    }
+                    Some(ScriptStep::Event(event)) => LoopInput::Event(event),
+                    Some(ScriptStep::Wait(duration)) => {
+                        tokio::time::sleep(duration).await;
+                        LoopInput::Idle
+                    }
+                    None => LoopInput::Closed,
+                }
+            }
+        }
+    }
 }
 
 /// Runtime helper for simple stores (no effects).
@@ -274,6 +941,14 @@ pub struct DispatchRuntime<S, A: Action, St: DispatchStore<S, A> = Store<S, A>>
     action_rx: mpsc::UnboundedReceiver<A>,
     poller_config: PollerConfig,
     debug: Option<Box<dyn DebugAdapter<S, A>>>,
+    hotkeys: Option<GlobalHotkeys<S, A>>,
+    shutdown: ShutdownHooks<S>,
+    terminal_modes: TerminalModes,
+    suspend_on: Option<Box<dyn FnMut(&A) -> bool>>,
+    mouse_capture_on: Option<MouseCaptureToggle<A>>,
+    resize_action: Option<ResizeAction<A>>,
+    render_now_on: Option<Box<dyn FnMut(&A) -> bool>>,
+    crash_log: Option<CrashLog<A>>,
     should_render: bool,
     _state: std::marker::PhantomData<S>,
 }
@@ -287,14 +962,23 @@ impl<S: 'static, A: Action> DispatchRuntime<S, A, Store<S, A>> {
 
 impl<S: 'static, A: Action, St: DispatchStore<S, A>> DispatchRuntime<S, A, St> {
     /// Create a runtime from an existing store.
-    pub fn from_store(store: St) -> Self {
+    pub fn from_store(mut store: St) -> Self {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
+        store.set_action_sender(action_tx.clone());
         Self {
             store,
             action_tx,
             action_rx,
             poller_config: PollerConfig::default(),
             debug: None,
+            hotkeys: None,
+            shutdown: ShutdownHooks::new(),
+            terminal_modes: TerminalModes::default(),
+            suspend_on: None,
+            mouse_capture_on: None,
+            resize_action: None,
+            render_now_on: None,
+            crash_log: None,
             should_render: true,
             _state: std::marker::PhantomData,
         }
@@ -311,12 +995,151 @@ impl<S: 'static, A: Action, St: DispatchStore<S, A>> DispatchRuntime<S, A, St> {
         self
     }
 
+    /// Check [`GlobalHotkeys`] before `map_event`.
+    ///
+    /// A matching key dispatches its action and is not passed to
+    /// `map_event`. The debug layer still sees events first.
+    pub fn with_global_hotkeys(mut self, hotkeys: GlobalHotkeys<S, A>) -> Self {
+        self.hotkeys = Some(hotkeys);
+        self
+    }
+
     /// Configure event polling behavior.
     pub fn with_event_poller(mut self, config: PollerConfig) -> Self {
         self.poller_config = config;
         self
     }
 
+    /// Describe the terminal modes the app enabled at startup.
+    ///
+    /// Used to hand the terminal over and take it back when suspending or
+    /// running external commands. Defaults to the alternate screen only.
+    pub fn with_terminal_modes(mut self, modes: TerminalModes) -> Self {
+        self.terminal_modes = modes;
+        self
+    }
+
+    /// Suspend the app (like Ctrl+Z in a shell) when `predicate` matches an action.
+    ///
+    /// Raw mode delivers Ctrl+Z as a key event, so map it to an action and
+    /// match that action here. The matching action is not dispatched. The
+    /// terminal is restored while suspended and redrawn on resume.
+    pub fn suspend_on(mut self, predicate: impl FnMut(&A) -> bool + 'static) -> Self {
+        self.suspend_on = Some(Box::new(predicate));
+        self
+    }
+
+    /// Switch mouse capture when `toggle` returns a new setting for an action.
+    ///
+    /// `toggle` gets the action and whether capture is on now. With capture
+    /// off the terminal handles the mouse, so users can select text; the
+    /// action is still dispatched.
+    ///
+    /// ```ignore
+    /// runtime.mouse_capture_on(|action, on| matches!(action, Action::ToggleMouse).then_some(!on))
+    /// ```
+    pub fn mouse_capture_on(
+        mut self,
+        toggle: impl FnMut(&A, bool) -> Option<bool> + 'static,
+    ) -> Self {
+        self.mouse_capture_on = Some(Box::new(toggle));
+        self
+    }
+
+    /// Turn mouse capture on or off.
+    ///
+    /// Applied when the loop runs; [`RenderContext::mouse_capture`] reports
+    /// the current setting.
+    pub fn set_mouse_capture(&mut self, enabled: bool) {
+        self.terminal_modes.mouse_capture = enabled;
+    }
+
+    /// Whether the app has mouse capture on.
+    ///
+    /// The debug layer's inspect mode ('i') captures the mouse on top of
+    /// this without changing it.
+    pub fn mouse_capture(&self) -> bool {
+        self.terminal_modes.mouse_capture
+    }
+
+    /// Mouse capture the app or the debug layer needs right now.
+    fn wanted_mouse_capture(&self) -> bool {
+        self.terminal_modes.mouse_capture
+            || self
+                .debug
+                .as_ref()
+                .is_some_and(|debug| debug.wants_mouse_capture())
+    }
+
+    /// Dispatch the action built by `action` after the terminal is resized.
+    ///
+    /// Resize events queued back to back are merged into the last one, so a
+    /// window drag dispatches one action per burst instead of one per step.
+    /// Either way the next frame is drawn at the new size and
+    /// [`RenderContext::terminal_size`] reports it; `map_event` still sees
+    /// the (merged) resize event.
+    ///
+    /// ```ignore
+    /// runtime.on_resize(Action::UiTerminalResize)
+    /// ```
+    pub fn on_resize(mut self, action: impl FnMut(u16, u16) -> A + 'static) -> Self {
+        self.resize_action = Some(Box::new(action));
+        self
+    }
+
+    /// Draw right after actions matching `predicate`, ignoring `max_fps`.
+    ///
+    /// For actions whose feedback should not wait for the frame budget,
+    /// e.g. typed characters. Without a `max_fps` limit every change is
+    /// drawn right away anyway.
+    pub fn render_immediately_on(mut self, predicate: impl FnMut(&A) -> bool + 'static) -> Self {
+        self.render_now_on = Some(Box::new(predicate));
+        self
+    }
+
+    /// Print the last `count` actions to stderr if the app panics.
+    ///
+    /// `run` always restores the terminal before the panic message is
+    /// printed; this adds the actions that led up to the crash. `Tick` and
+    /// `Render` are not recorded.
+    pub fn with_crash_report(mut self, count: usize) -> Self
+    where
+        A: ActionParams,
+    {
+        self.crash_log = Some(CrashLog::new(count));
+        self
+    }
+
+    /// The crash report as it would be printed now, if enabled.
+    pub fn crash_report(&self) -> Option<String> {
+        self.crash_log
+            .as_ref()
+            .and_then(|crash_log| crash_report(&crash_log.log))
+    }
+
+    /// Register an async hook to run when the loop exits.
+    ///
+    /// Hooks run in registration order after the last action is dispatched
+    /// and before background work (event polling, tasks, subscriptions) is
+    /// cancelled, so they can flush writes or disconnect cleanly. The hook
+    /// gets the final state; the future it returns must own what it needs.
+    pub fn on_shutdown<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: FnMut(&S) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.shutdown.push(hook);
+        self
+    }
+
+    /// Limit how long shutdown hooks may take in total (default: 5s).
+    ///
+    /// Hooks still running when the timeout expires are dropped.
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown.timeout = timeout;
+        self
+    }
+
     /// Send an action into the runtime queue.
     pub fn enqueue(&self, action: A) {
         let _ = self.action_tx.send(action);
@@ -333,12 +1156,16 @@ impl<S: 'static, A: Action, St: DispatchStore<S, A>> DispatchRuntime<S, A, St> {
     }
 
     /// Run the event/action loop until quit.
+    ///
+    /// While running, a panic hook restores the terminal (see
+    /// [`with_terminal_modes`](Self::with_terminal_modes)) before the panic
+    /// message is printed.
     pub async fn run<B, FRender, FEvent, FQuit, R>(
         &mut self,
         terminal: &mut Terminal<B>,
-        mut render: FRender,
-        mut map_event: FEvent,
-        mut should_quit: FQuit,
+        render: FRender,
+        map_event: FEvent,
+        should_quit: FQuit,
     ) -> io::Result<()>
     where
         B: Backend,
@@ -347,28 +1174,113 @@ impl<S: 'static, A: Action, St: DispatchStore<S, A>> DispatchRuntime<S, A, St> {
         R: Into<EventOutcome<A>>,
         FQuit: FnMut(&A) -> bool,
     {
-        let (event_tx, mut event_rx) = mpsc::unbounded_channel::<RawEvent>();
+        let _panic_hook =
+            CrashLog::install_panic_hook(self.crash_log.as_ref(), self.terminal_modes);
+        let (event_tx, event_rx) = mpsc::unbounded_channel::<RawEvent>();
         let cancel_token = CancellationToken::new();
-        let _handle = spawn_event_poller(
+        let gate = Arc::new(Mutex::new(()));
+        let _handle = spawn_gated_event_poller(
             event_tx,
             self.poller_config.poll_timeout,
             self.poller_config.loop_sleep,
             cancel_token.clone(),
+            gate.clone(),
         );
 
-        loop {
-            if self.should_render {
-                let state = self.store.state();
-                let render_ctx = RenderContext {
-                    debug_enabled: self
-                        .debug
-                        .as_ref()
-                        .map(|debug| debug.is_enabled())
-                        .unwrap_or(false),
-                };
-                terminal.draw(|frame| {
-                    if let Some(debug) = self.debug.as_mut() {
-                        let mut render_fn =
+        let result = self
+            .run_loop(
+                terminal,
+                EventFeed::Terminal {
+                    events: event_rx,
+                    gate,
+                    deferred: None,
+                    coalesce_input: self.poller_config.coalesce_input,
+                },
+                render,
+                map_event,
+                should_quit,
+            )
+            .await;
+        cancel_token.cancel();
+        result
+    }
+
+    /// Run the loop over scripted steps until they and the action queue are exhausted.
+    pub(crate) async fn run_script<B, FRender, FEvent, FQuit, R>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        steps: VecDeque<ScriptStep>,
+        history: &mut Vec<A>,
+        render: FRender,
+        map_event: FEvent,
+        should_quit: FQuit,
+    ) -> io::Result<()>
+    where
+        B: Backend,
+        FRender: FnMut(&mut Frame, Rect, &S, RenderContext),
+        FEvent: FnMut(&EventKind, &S) -> R,
+        R: Into<EventOutcome<A>>,
+        FQuit: FnMut(&A) -> bool,
+    {
+        let feed = EventFeed::Script { steps, history };
+        self.run_loop(terminal, feed, render, map_event, should_quit)
+            .await
+    }
+
+    async fn run_loop<B, FRender, FEvent, FQuit, R>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        mut feed: EventFeed<'_, A>,
+        mut render: FRender,
+        mut map_event: FEvent,
+        mut should_quit: FQuit,
+    ) -> io::Result<()>
+    where
+        B: Backend,
+        FRender: FnMut(&mut Frame, Rect, &S, RenderContext),
+        FEvent: FnMut(&EventKind, &S) -> R,
+        R: Into<EventOutcome<A>>,
+        FQuit: FnMut(&A) -> bool,
+    {
+        if let Some(debug) = self.debug.as_mut() {
+            debug.record_state(self.store.state());
+        }
+
+        // Scripted runs draw every change so each step can be inspected
+        let max_fps = match feed {
+            EventFeed::Terminal { .. } => self.poller_config.max_fps,
+            EventFeed::Script { .. } => None,
+        };
+        let mut clock = FrameClock::new(max_fps);
+        let mut render_now = false;
+        // Assumes the terminal was set up with `terminal_modes`
+        let mut mouse_capture = self.terminal_modes.mouse_capture;
+        let size = terminal.size()?;
+        let mut terminal_size = (size.width, size.height);
+        loop {
+            let wanted = self.wanted_mouse_capture();
+            if wanted != mouse_capture {
+                feed.set_mouse_capture(wanted)?;
+                mouse_capture = wanted;
+                self.should_render = true;
+                render_now = true;
+            }
+            if self.should_render && (render_now || clock.next_frame().is_none()) {
+                let state = self.store.state();
+                let render_ctx = clock.frame(RenderContext {
+                    debug_enabled: self
+                        .debug
+                        .as_ref()
+                        .map(|debug| debug.is_enabled())
+                        .unwrap_or(false),
+                    state_version: self.store.version(),
+                    mouse_capture,
+                    terminal_size,
+                    ..RenderContext::default()
+                });
+                terminal.draw(|frame| {
+                    if let Some(debug) = self.debug.as_mut() {
+                        let mut render_fn =
                             |f: &mut Frame, area: Rect, state: &S, ctx: RenderContext| {
                                 render(f, area, state, ctx);
                             };
@@ -378,22 +1290,36 @@ impl<S: 'static, A: Action, St: DispatchStore<S, A>> DispatchRuntime<S, A, St> {
                     }
                 })?;
                 self.should_render = false;
+                render_now = false;
             }
-
-            tokio::select! {
-                Some(raw_event) = event_rx.recv() => {
-                    let event = process_raw_event(raw_event);
-
+            let frame_deadline = self.should_render.then(|| clock.next_frame()).flatten();
+            match feed.next(&mut self.action_rx, None, frame_deadline).await {
+                LoopInput::Event(event) => {
+                    if let EventKind::Resize(width, height) = event {
+                        terminal_size = (width, height);
+                        self.should_render = true;
+                        if let Some(resize_action) = self.resize_action.as_mut() {
+                            let _ = self.action_tx.send(resize_action(width, height));
+                        }
+                    }
                     if let Some(debug) = self.debug.as_mut() {
                         if let Some(needs_render) =
                             debug.handle_event(&event, self.store.state(), &self.action_tx)
                         {
-                            self.should_render = needs_render;
+                            // Debug overlays respond to input without waiting for a frame
+                            self.should_render |= needs_render;
+                            render_now |= needs_render;
                             continue;
                         }
                     }
 
-                    let outcome: EventOutcome<A> = map_event(&event, self.store.state()).into();
+                    let state = self.store.state();
+                    let outcome = match &self.hotkeys {
+                        Some(hotkeys) => hotkeys
+                            .handle(&event, state)
+                            .or_else(|| map_event(&event, state).into()),
+                        None => map_event(&event, state).into(),
+                    };
                     if outcome.needs_render {
                         self.should_render = true;
                     }
@@ -402,32 +1328,268 @@ impl<S: 'static, A: Action, St: DispatchStore<S, A>> DispatchRuntime<S, A, St> {
                     }
                 }
 
-                Some(action) = self.action_rx.recv() => {
+                LoopInput::Action(action) => {
                     if should_quit(&action) {
                         break;
                     }
+                    if self
+                        .suspend_on
+                        .as_mut()
+                        .is_some_and(|suspend| suspend(&action))
+                    {
+                        let modes = TerminalModes {
+                            mouse_capture,
+                            ..self.terminal_modes
+                        };
+                        feed.hand_off(terminal, modes, TerminalRequest::Suspend)?;
+                        self.should_render = true;
+                        render_now = true;
+                        continue;
+                    }
 
+                    let capture = self.terminal_modes.mouse_capture;
+                    if let Some(enabled) = self
+                        .mouse_capture_on
+                        .as_mut()
+                        .and_then(|toggle| toggle(&action, capture))
+                    {
+                        self.terminal_modes.mouse_capture = enabled;
+                    }
                     if let Some(debug) = self.debug.as_mut() {
+                        debug.record_queue_depth(self.action_rx.len());
                         debug.log_action(&action);
                     }
-
-                    self.should_render = self.store.dispatch(action);
+                    if let Some(crash_log) = &self.crash_log {
+                        crash_log.record(&action);
+                    }
+                    let urgent = self
+                        .render_now_on
+                        .as_mut()
+                        .is_some_and(|urgent| urgent(&action));
+
+                    let changed = self.store.dispatch(action);
+                    self.should_render |= changed;
+                    render_now |= changed && urgent;
+                    if let Some(debug) = self.debug.as_mut() {
+                        debug.record_dispatched_state(self.store.state(), changed);
+                        debug.record_change(changed);
+                    }
                 }
 
-                else => {
+                LoopInput::PersistDue | LoopInput::FrameDue | LoopInput::Idle => {}
+
+                LoopInput::Closed => {
                     break;
                 }
             }
         }
 
-        cancel_token.cancel();
+        // Leave the terminal as the app configured it, without the debug layer's capture
+        if mouse_capture != self.terminal_modes.mouse_capture {
+            feed.set_mouse_capture(self.terminal_modes.mouse_capture)?;
+        }
+        self.shutdown.run(self.store.state()).await;
+
         Ok(())
     }
 }
 
+type BoxedRender<S> = Box<dyn FnMut(&mut Frame, Rect, &S, RenderContext)>;
+type BoxedMapEvent<S, A> = Box<dyn FnMut(&EventKind, &S) -> EventOutcome<A>>;
+
+/// Entry point for apps that let the runtime own the terminal.
+///
+/// Builds a [`DispatchRuntime`]; there is no builder for [`EffectRuntime`].
+///
+/// ```ignore
+/// Runtime::builder()
+///     .mouse_capture(true)
+///     .state(AppState::default(), reducer)
+///     .render(render_app)
+///     .map_event(map_event)
+///     .quit_on(|action| matches!(action, Action::Quit))
+///     .run()
+///     .await
+/// ```
+pub struct Runtime;
+
+impl Runtime {
+    /// Start configuring the terminal, then pick a store.
+    pub fn builder() -> RuntimeBuilder {
+        RuntimeBuilder::default()
+    }
+}
+
+/// Terminal and polling options for [`Runtime::builder`].
+///
+/// Raw mode and the alternate screen are on by default; mouse capture,
+/// bracketed paste, focus reporting and the kitty keyboard protocol are
+/// opt-in.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuntimeBuilder {
+    modes: TerminalModes,
+    poller_config: PollerConfig,
+}
+
+impl RuntimeBuilder {
+    /// Draw on the alternate screen (default: true).
+    pub fn alternate_screen(mut self, enabled: bool) -> Self {
+        self.modes.alternate_screen = enabled;
+        self
+    }
+
+    /// Report mouse clicks, drags and scrolling.
+    pub fn mouse_capture(mut self, enabled: bool) -> Self {
+        self.modes.mouse_capture = enabled;
+        self
+    }
+
+    /// Deliver pastes as a single [`EventKind::Paste`].
+    pub fn bracketed_paste(mut self, enabled: bool) -> Self {
+        self.modes.bracketed_paste = enabled;
+        self
+    }
+
+    /// Report [`EventKind::FocusGained`] / [`EventKind::FocusLost`].
+    pub fn focus_change(mut self, enabled: bool) -> Self {
+        self.modes.focus_change = enabled;
+        self
+    }
+
+    /// Use the kitty keyboard protocol where the terminal supports it.
+    pub fn keyboard_enhancement(mut self, enabled: bool) -> Self {
+        self.modes.keyboard_enhancement = enabled;
+        self
+    }
+
+    /// Configure event polling behavior.
+    pub fn event_poller(mut self, config: PollerConfig) -> Self {
+        self.poller_config = config;
+        self
+    }
+
+    /// Run an existing store.
+    pub fn store<S: 'static, A: Action, St: DispatchStore<S, A>>(
+        self,
+        store: St,
+    ) -> DispatchRuntimeBuilder<S, A, St> {
+        let runtime = DispatchRuntime::from_store(store)
+            .with_terminal_modes(self.modes)
+            .with_event_poller(self.poller_config);
+        DispatchRuntimeBuilder {
+            runtime,
+            render: None,
+            map_event: None,
+            should_quit: Box::new(|_| false),
+        }
+    }
+
+    /// Run a [`Store`] built from state + reducer.
+    pub fn state<S: 'static, A: Action>(
+        self,
+        state: S,
+        reducer: Reducer<S, A>,
+    ) -> DispatchRuntimeBuilder<S, A> {
+        self.store(Store::new(state, reducer))
+    }
+}
+
+/// A [`DispatchRuntime`] plus the closures for its loop, from [`Runtime::builder`].
+///
+/// [`run`](Self::run) enters raw mode and the configured terminal modes,
+/// runs the loop on stdout, and restores the terminal when the loop ends,
+/// returns an error or panics.
+///
+/// The builder only drives a [`DispatchRuntime`]. Apps with effects,
+/// persistence, tasks or subscriptions build an [`EffectRuntime`] and pass
+/// it a terminal from [`TerminalGuard`] themselves.
+pub struct DispatchRuntimeBuilder<S, A: Action, St: DispatchStore<S, A> = Store<S, A>> {
+    runtime: DispatchRuntime<S, A, St>,
+    render: Option<BoxedRender<S>>,
+    map_event: Option<BoxedMapEvent<S, A>>,
+    should_quit: Box<dyn FnMut(&A) -> bool>,
+}
+
+impl<S: 'static, A: Action, St: DispatchStore<S, A>> DispatchRuntimeBuilder<S, A, St> {
+    /// Render function (required).
+    pub fn render(
+        mut self,
+        render: impl FnMut(&mut Frame, Rect, &S, RenderContext) + 'static,
+    ) -> Self {
+        self.render = Some(Box::new(render));
+        self
+    }
+
+    /// Map terminal events to actions (required).
+    pub fn map_event<R: Into<EventOutcome<A>>>(
+        mut self,
+        mut map_event: impl FnMut(&EventKind, &S) -> R + 'static,
+    ) -> Self {
+        self.map_event = Some(Box::new(move |event, state| map_event(event, state).into()));
+        self
+    }
+
+    /// Stop the loop when `predicate` matches an action.
+    ///
+    /// Without it the loop only ends when every action sender is dropped.
+    pub fn quit_on(mut self, predicate: impl FnMut(&A) -> bool + 'static) -> Self {
+        self.should_quit = Box::new(predicate);
+        self
+    }
+
+    /// Adjust the runtime itself, e.g. to attach a debug layer.
+    ///
+    /// ```ignore
+    /// .configure(|runtime| runtime.with_debug(DebugLayer::simple()).suspend_on(is_suspend))
+    /// ```
+    pub fn configure(
+        mut self,
+        configure: impl FnOnce(DispatchRuntime<S, A, St>) -> DispatchRuntime<S, A, St>,
+    ) -> Self {
+        self.runtime = configure(self.runtime);
+        self
+    }
+
+    /// Set up the terminal, run the loop until quit, and restore the terminal.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] if `render` or
+    /// `map_event` was not set.
+    pub async fn run(self) -> io::Result<()> {
+        let (mut runtime, render, map_event, should_quit) = self.into_parts()?;
+
+        let _guard = TerminalGuard::enter(runtime.terminal_modes)?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+        terminal.clear()?;
+        runtime
+            .run(&mut terminal, render, map_event, should_quit)
+            .await
+    }
+
+    /// Check the required closures and hand back the configured runtime.
+    #[allow(clippy::type_complexity)]
+    fn into_parts(
+        self,
+    ) -> io::Result<(
+        DispatchRuntime<S, A, St>,
+        BoxedRender<S>,
+        BoxedMapEvent<S, A>,
+        Box<dyn FnMut(&A) -> bool>,
+    )> {
+        let (Some(render), Some(map_event)) = (self.render, self.map_event) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Runtime::builder() needs render and map_event before run",
+            ));
+        };
+        Ok((self.runtime, render, map_event, self.should_quit))
+    }
+}
+
 /// Context passed to effect handlers.
 pub struct EffectContext<'a, A: Action> {
     action_tx: &'a mpsc::UnboundedSender<A>,
+    terminal_requests: &'a mut Vec<TerminalRequest<A>>,
+    mouse_capture: &'a mut bool,
     #[cfg(feature = "tasks")]
     tasks: &'a mut TaskManager<A>,
     #[cfg(feature = "subscriptions")]
@@ -445,6 +1607,51 @@ impl<'a, A: Action> EffectContext<'a, A> {
         self.action_tx
     }
 
+    /// Spawn a [`Thunk`] whose actions are sent back to the runtime.
+    ///
+    /// Unlike tasks spawned through the task manager, thunks are not keyed
+    /// (no cancellation or replacement) and are not held back while the
+    /// debug layer is paused.
+    pub fn spawn_thunk(&self, thunk: Thunk<A>) {
+        thunk.spawn(self.action_tx);
+    }
+
+    /// Suspend the app (like Ctrl+Z in a shell) after the current effects.
+    ///
+    /// The terminal is restored while suspended and redrawn on resume.
+    pub fn suspend(&mut self) {
+        self.terminal_requests.push(TerminalRequest::Suspend);
+    }
+
+    /// Turn mouse capture on or off after the current effects.
+    ///
+    /// With capture off the terminal handles the mouse, so users can
+    /// select text. [`RenderContext::mouse_capture`] reports the setting.
+    pub fn set_mouse_capture(&mut self, enabled: bool) {
+        *self.mouse_capture = enabled;
+    }
+
+    /// Whether the app has mouse capture on.
+    pub fn mouse_capture(&self) -> bool {
+        *self.mouse_capture
+    }
+
+    /// Run an interactive program, such as `$EDITOR`, after the current effects.
+    ///
+    /// The runtime hands the terminal to `command`, waits for it to exit,
+    /// then redraws and dispatches the action built by `on_exit`. Event
+    /// polling is paused meanwhile, so the whole app waits for the command.
+    pub fn run_external_command(
+        &mut self,
+        command: Command,
+        on_exit: impl FnOnce(io::Result<ExitStatus>) -> A + 'static,
+    ) {
+        self.terminal_requests.push(TerminalRequest::Command {
+            command,
+            on_exit: Box::new(on_exit),
+        });
+    }
+
     /// Access the task manager.
     #[cfg(feature = "tasks")]
     pub fn tasks(&mut self) -> &mut TaskManager<A> {
@@ -465,11 +1672,25 @@ pub struct EffectRuntime<S, A: Action, E, St: EffectStoreLike<S, A, E> = EffectS
     action_rx: mpsc::UnboundedReceiver<A>,
     poller_config: PollerConfig,
     debug: Option<Box<dyn DebugAdapter<S, A>>>,
+    hotkeys: Option<GlobalHotkeys<S, A>>,
+    persistence: Option<PersistHook<S, A>>,
+    shutdown: ShutdownHooks<S>,
+    terminal_modes: TerminalModes,
+    suspend_on: Option<Box<dyn FnMut(&A) -> bool>>,
+    mouse_capture_on: Option<MouseCaptureToggle<A>>,
+    resize_action: Option<ResizeAction<A>>,
+    render_now_on: Option<Box<dyn FnMut(&A) -> bool>>,
+    crash_log: Option<CrashLog<A>>,
+    terminal_requests: Vec<TerminalRequest<A>>,
     should_render: bool,
+    /// Components touched since the last draw
+    dirty: DirtyComponents,
     #[cfg(feature = "tasks")]
     tasks: TaskManager<A>,
     #[cfg(feature = "subscriptions")]
     subscriptions: Subscriptions<A>,
+    #[cfg(feature = "subscriptions")]
+    pause_on_blur: bool,
     _state: std::marker::PhantomData<S>,
     _effect: std::marker::PhantomData<E>,
 }
@@ -483,8 +1704,9 @@ impl<S: 'static, A: Action, E> EffectRuntime<S, A, E, EffectStore<S, A, E>> {
 
 impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A, E, St> {
     /// Create a runtime from an existing effect store.
-    pub fn from_store(store: St) -> Self {
+    pub fn from_store(mut store: St) -> Self {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
+        store.set_action_sender(action_tx.clone());
 
         #[cfg(feature = "tasks")]
         let tasks = TaskManager::new(action_tx.clone());
@@ -497,11 +1719,24 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
             action_rx,
             poller_config: PollerConfig::default(),
             debug: None,
+            hotkeys: None,
+            persistence: None,
+            shutdown: ShutdownHooks::new(),
+            terminal_modes: TerminalModes::default(),
+            suspend_on: None,
+            mouse_capture_on: None,
+            resize_action: None,
+            render_now_on: None,
+            crash_log: None,
+            terminal_requests: Vec::new(),
             should_render: true,
+            dirty: DirtyComponents::all(),
             #[cfg(feature = "tasks")]
             tasks,
             #[cfg(feature = "subscriptions")]
             subscriptions,
+            #[cfg(feature = "subscriptions")]
+            pause_on_blur: false,
             _state: std::marker::PhantomData,
             _effect: std::marker::PhantomData,
         }
@@ -526,12 +1761,186 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
         self
     }
 
+    /// Check [`GlobalHotkeys`] before `map_event`.
+    ///
+    /// A matching key dispatches its action and is not passed to
+    /// `map_event`. The debug layer still sees events first.
+    pub fn with_global_hotkeys(mut self, hotkeys: GlobalHotkeys<S, A>) -> Self {
+        self.hotkeys = Some(hotkeys);
+        self
+    }
+
     /// Configure event polling behavior.
     pub fn with_event_poller(mut self, config: PollerConfig) -> Self {
         self.poller_config = config;
         self
     }
 
+    /// Save persisted state to `path` according to `policy`.
+    ///
+    /// The format is detected from the file extension. Hydrate the initial
+    /// state with [`Persister::load_into`] before building the runtime.
+    /// Save failures are logged and never stop the run loop.
+    pub fn with_persistence(
+        mut self,
+        path: impl Into<PathBuf>,
+        policy: PersistPolicy<A>,
+    ) -> Result<Self, PersistError>
+    where
+        S: Persist,
+    {
+        let persister = Persister::<S>::new(path)?;
+        self.persistence = Some(PersistHook {
+            write: Box::new(move |state| persister.save(state)),
+            policy,
+            deadline: None,
+        });
+        Ok(self)
+    }
+
+    /// Describe the terminal modes the app enabled at startup.
+    ///
+    /// Used to hand the terminal over and take it back when suspending or
+    /// running external commands. Defaults to the alternate screen only.
+    pub fn with_terminal_modes(mut self, modes: TerminalModes) -> Self {
+        self.terminal_modes = modes;
+        self
+    }
+
+    /// Suspend the app (like Ctrl+Z in a shell) when `predicate` matches an action.
+    ///
+    /// Raw mode delivers Ctrl+Z as a key event, so map it to an action and
+    /// match that action here. The matching action is not dispatched. The
+    /// terminal is restored while suspended and redrawn on resume.
+    pub fn suspend_on(mut self, predicate: impl FnMut(&A) -> bool + 'static) -> Self {
+        self.suspend_on = Some(Box::new(predicate));
+        self
+    }
+
+    /// Switch mouse capture when `toggle` returns a new setting for an action.
+    ///
+    /// `toggle` gets the action and whether capture is on now. With capture
+    /// off the terminal handles the mouse, so users can select text; the
+    /// action is still dispatched.
+    ///
+    /// ```ignore
+    /// runtime.mouse_capture_on(|action, on| matches!(action, Action::ToggleMouse).then_some(!on))
+    /// ```
+    pub fn mouse_capture_on(
+        mut self,
+        toggle: impl FnMut(&A, bool) -> Option<bool> + 'static,
+    ) -> Self {
+        self.mouse_capture_on = Some(Box::new(toggle));
+        self
+    }
+
+    /// Turn mouse capture on or off.
+    ///
+    /// Applied when the loop runs; [`RenderContext::mouse_capture`] reports
+    /// the current setting.
+    pub fn set_mouse_capture(&mut self, enabled: bool) {
+        self.terminal_modes.mouse_capture = enabled;
+    }
+
+    /// Whether the app has mouse capture on.
+    ///
+    /// The debug layer's inspect mode ('i') captures the mouse on top of
+    /// this without changing it.
+    pub fn mouse_capture(&self) -> bool {
+        self.terminal_modes.mouse_capture
+    }
+
+    /// Mouse capture the app or the debug layer needs right now.
+    fn wanted_mouse_capture(&self) -> bool {
+        self.terminal_modes.mouse_capture
+            || self
+                .debug
+                .as_ref()
+                .is_some_and(|debug| debug.wants_mouse_capture())
+    }
+
+    /// Dispatch the action built by `action` after the terminal is resized.
+    ///
+    /// Resize events queued back to back are merged into the last one, so a
+    /// window drag dispatches one action per burst instead of one per step.
+    /// Either way the next frame is drawn at the new size and
+    /// [`RenderContext::terminal_size`] reports it; `map_event` still sees
+    /// the (merged) resize event.
+    ///
+    /// ```ignore
+    /// runtime.on_resize(Action::UiTerminalResize)
+    /// ```
+    pub fn on_resize(mut self, action: impl FnMut(u16, u16) -> A + 'static) -> Self {
+        self.resize_action = Some(Box::new(action));
+        self
+    }
+
+    /// Draw right after actions matching `predicate`, ignoring `max_fps`.
+    ///
+    /// For actions whose feedback should not wait for the frame budget,
+    /// e.g. typed characters. Without a `max_fps` limit every change is
+    /// drawn right away anyway.
+    pub fn render_immediately_on(mut self, predicate: impl FnMut(&A) -> bool + 'static) -> Self {
+        self.render_now_on = Some(Box::new(predicate));
+        self
+    }
+
+    /// Pause subscriptions while the terminal window is unfocused.
+    ///
+    /// Subscriptions pause on [`EventKind::FocusLost`] and resume on
+    /// [`EventKind::FocusGained`], unless the debug layer has them frozen.
+    /// Requires focus change reporting (see
+    /// [`enable_focus_change`](crate::bus::enable_focus_change)); the
+    /// events still reach `map_event` as usual.
+    #[cfg(feature = "subscriptions")]
+    pub fn pause_subscriptions_on_blur(mut self) -> Self {
+        self.pause_on_blur = true;
+        self
+    }
+
+    /// Print the last `count` actions to stderr if the app panics.
+    ///
+    /// `run` always restores the terminal before the panic message is
+    /// printed; this adds the actions that led up to the crash. `Tick` and
+    /// `Render` are not recorded.
+    pub fn with_crash_report(mut self, count: usize) -> Self
+    where
+        A: ActionParams,
+    {
+        self.crash_log = Some(CrashLog::new(count));
+        self
+    }
+
+    /// The crash report as it would be printed now, if enabled.
+    pub fn crash_report(&self) -> Option<String> {
+        self.crash_log
+            .as_ref()
+            .and_then(|crash_log| crash_report(&crash_log.log))
+    }
+
+    /// Register an async hook to run when the loop exits.
+    ///
+    /// Hooks run in registration order after the last action is dispatched
+    /// and before background work (event polling, tasks, subscriptions) is
+    /// cancelled, so they can flush writes or disconnect cleanly. The hook
+    /// gets the final state; the future it returns must own what it needs.
+    pub fn on_shutdown<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: FnMut(&S) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        self.shutdown.push(hook);
+        self
+    }
+
+    /// Limit how long shutdown hooks may take in total (default: 5s).
+    ///
+    /// Hooks still running when the timeout expires are dropped.
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown.timeout = timeout;
+        self
+    }
+
     /// Send an action into the runtime queue.
     pub fn enqueue(&self, action: A) {
         let _ = self.action_tx.send(action);
@@ -563,6 +1972,8 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
     fn effect_context(&mut self) -> EffectContext<'_, A> {
         EffectContext {
             action_tx: &self.action_tx,
+            terminal_requests: &mut self.terminal_requests,
+            mouse_capture: &mut self.terminal_modes.mouse_capture,
             tasks: &mut self.tasks,
             subscriptions: &mut self.subscriptions,
         }
@@ -572,6 +1983,8 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
     fn effect_context(&mut self) -> EffectContext<'_, A> {
         EffectContext {
             action_tx: &self.action_tx,
+            terminal_requests: &mut self.terminal_requests,
+            mouse_capture: &mut self.terminal_modes.mouse_capture,
             tasks: &mut self.tasks,
         }
     }
@@ -580,6 +1993,8 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
     fn effect_context(&mut self) -> EffectContext<'_, A> {
         EffectContext {
             action_tx: &self.action_tx,
+            terminal_requests: &mut self.terminal_requests,
+            mouse_capture: &mut self.terminal_modes.mouse_capture,
             subscriptions: &mut self.subscriptions,
         }
     }
@@ -588,17 +2003,36 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
     fn effect_context(&mut self) -> EffectContext<'_, A> {
         EffectContext {
             action_tx: &self.action_tx,
+            terminal_requests: &mut self.terminal_requests,
+            mouse_capture: &mut self.terminal_modes.mouse_capture,
+        }
+    }
+
+    /// Pause or resume subscriptions for a focus change.
+    #[cfg(feature = "subscriptions")]
+    fn follow_focus(&self, event: &EventKind) {
+        match event {
+            EventKind::FocusLost => self.subscriptions.pause(),
+            // Leave subscriptions frozen while the debug layer is open
+            EventKind::FocusGained if !self.debug.as_ref().is_some_and(|d| d.is_enabled()) => {
+                self.subscriptions.resume()
+            }
+            _ => {}
         }
     }
 
     /// Run the event/action loop until quit.
+    ///
+    /// While running, a panic hook restores the terminal (see
+    /// [`with_terminal_modes`](Self::with_terminal_modes)) before the panic
+    /// message is printed.
     pub async fn run<B, FRender, FEvent, FQuit, FEffect, R>(
         &mut self,
         terminal: &mut Terminal<B>,
-        mut render: FRender,
-        mut map_event: FEvent,
-        mut should_quit: FQuit,
-        mut handle_effect: FEffect,
+        render: FRender,
+        map_event: FEvent,
+        should_quit: FQuit,
+        handle_effect: FEffect,
     ) -> io::Result<()>
     where
         B: Backend,
@@ -608,25 +2042,125 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
         FQuit: FnMut(&A) -> bool,
         FEffect: FnMut(E, &mut EffectContext<A>),
     {
-        let (event_tx, mut event_rx) = mpsc::unbounded_channel::<RawEvent>();
+        let _panic_hook =
+            CrashLog::install_panic_hook(self.crash_log.as_ref(), self.terminal_modes);
+        let (event_tx, event_rx) = mpsc::unbounded_channel::<RawEvent>();
         let cancel_token = CancellationToken::new();
-        let _handle = spawn_event_poller(
+        let gate = Arc::new(Mutex::new(()));
+        let _handle = spawn_gated_event_poller(
             event_tx,
             self.poller_config.poll_timeout,
             self.poller_config.loop_sleep,
             cancel_token.clone(),
+            gate.clone(),
         );
 
+        let feed = EventFeed::Terminal {
+            events: event_rx,
+            gate,
+            deferred: None,
+            coalesce_input: self.poller_config.coalesce_input,
+        };
+        let result = self
+            .run_loop(
+                terminal,
+                feed,
+                render,
+                map_event,
+                should_quit,
+                handle_effect,
+            )
+            .await;
+        cancel_token.cancel();
+        result
+    }
+
+    /// Run the loop over scripted steps until they and the action queue are exhausted.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn run_script<B, FRender, FEvent, FQuit, FEffect, R>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        steps: VecDeque<ScriptStep>,
+        history: &mut Vec<A>,
+        render: FRender,
+        map_event: FEvent,
+        should_quit: FQuit,
+        handle_effect: FEffect,
+    ) -> io::Result<()>
+    where
+        B: Backend,
+        FRender: FnMut(&mut Frame, Rect, &S, RenderContext),
+        FEvent: FnMut(&EventKind, &S) -> R,
+        R: Into<EventOutcome<A>>,
+        FQuit: FnMut(&A) -> bool,
+        FEffect: FnMut(E, &mut EffectContext<A>),
+    {
+        let feed = EventFeed::Script { steps, history };
+        self.run_loop(
+            terminal,
+            feed,
+            render,
+            map_event,
+            should_quit,
+            handle_effect,
+        )
+        .await
+    }
+
+    async fn run_loop<B, FRender, FEvent, FQuit, FEffect, R>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        mut feed: EventFeed<'_, A>,
+        mut render: FRender,
+        mut map_event: FEvent,
+        mut should_quit: FQuit,
+        mut handle_effect: FEffect,
+    ) -> io::Result<()>
+    where
+        B: Backend,
+        FRender: FnMut(&mut Frame, Rect, &S, RenderContext),
+        FEvent: FnMut(&EventKind, &S) -> R,
+        R: Into<EventOutcome<A>>,
+        FQuit: FnMut(&A) -> bool,
+        FEffect: FnMut(E, &mut EffectContext<A>),
+    {
+        if let Some(debug) = self.debug.as_mut() {
+            debug.record_state(self.store.state());
+        }
+
+        // Scripted runs draw every change so each step can be inspected
+        let max_fps = match feed {
+            EventFeed::Terminal { .. } => self.poller_config.max_fps,
+            EventFeed::Script { .. } => None,
+        };
+        let mut clock = FrameClock::new(max_fps);
+        let mut render_now = false;
+        // Assumes the terminal was set up with `terminal_modes`
+        let mut mouse_capture = self.terminal_modes.mouse_capture;
+        let size = terminal.size()?;
+        let mut terminal_size = (size.width, size.height);
         loop {
-            if self.should_render {
+            let wanted = self.wanted_mouse_capture();
+            if wanted != mouse_capture {
+                feed.set_mouse_capture(wanted)?;
+                mouse_capture = wanted;
+                self.should_render = true;
+                render_now = true;
+            }
+            if self.should_render && (render_now || clock.next_frame().is_none()) {
                 let state = self.store.state();
-                let render_ctx = RenderContext {
+                let render_ctx = clock.frame(RenderContext {
                     debug_enabled: self
                         .debug
                         .as_ref()
                         .map(|debug| debug.is_enabled())
                         .unwrap_or(false),
-                };
+                    state_version: self.store.version(),
+                    dirty: self.dirty,
+                    mouse_capture,
+                    terminal_size,
+                    ..RenderContext::default()
+                });
                 terminal.draw(|frame| {
                     if let Some(debug) = self.debug.as_mut() {
                         let mut render_fn =
@@ -639,56 +2173,159 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
                     }
                 })?;
                 self.should_render = false;
+                self.dirty = DirtyComponents::none();
+                render_now = false;
             }
-
-            tokio::select! {
-                Some(raw_event) = event_rx.recv() => {
-                    let event = process_raw_event(raw_event);
-
+            let persist_deadline = self.persistence.as_ref().and_then(|p| p.deadline);
+            let frame_deadline = self.should_render.then(|| clock.next_frame()).flatten();
+            match feed
+                .next(&mut self.action_rx, persist_deadline, frame_deadline)
+                .await
+            {
+                LoopInput::Event(event) => {
+                    #[cfg(feature = "subscriptions")]
+                    if self.pause_on_blur {
+                        self.follow_focus(&event);
+                    }
+                    if let EventKind::Resize(width, height) = event {
+                        terminal_size = (width, height);
+                        self.should_render = true;
+                        self.dirty = DirtyComponents::all();
+                        if let Some(resize_action) = self.resize_action.as_mut() {
+                            let _ = self.action_tx.send(resize_action(width, height));
+                        }
+                    }
                     if let Some(debug) = self.debug.as_mut() {
                         if let Some(needs_render) =
                             debug.handle_event(&event, self.store.state(), &self.action_tx)
                         {
-                            self.should_render = needs_render;
+                            // Debug overlays respond to input without waiting for a frame
+                            if needs_render {
+                                self.dirty = DirtyComponents::all();
+                            }
+                            self.should_render |= needs_render;
+                            render_now |= needs_render;
                             continue;
                         }
                     }
 
-                    let outcome: EventOutcome<A> = map_event(&event, self.store.state()).into();
+                    let state = self.store.state();
+                    let outcome = match &self.hotkeys {
+                        Some(hotkeys) => hotkeys
+                            .handle(&event, state)
+                            .or_else(|| map_event(&event, state).into()),
+                        None => map_event(&event, state).into(),
+                    };
                     if outcome.needs_render {
                         self.should_render = true;
+                        self.dirty = DirtyComponents::all();
                     }
                     for action in outcome.actions {
                         let _ = self.action_tx.send(action);
                     }
                 }
 
-                Some(action) = self.action_rx.recv() => {
+                LoopInput::Action(action) => {
                     if should_quit(&action) {
                         break;
                     }
+                    if self
+                        .suspend_on
+                        .as_mut()
+                        .is_some_and(|suspend| suspend(&action))
+                    {
+                        let modes = TerminalModes {
+                            mouse_capture,
+                            ..self.terminal_modes
+                        };
+                        feed.hand_off(terminal, modes, TerminalRequest::Suspend)?;
+                        self.should_render = true;
+                        self.dirty = DirtyComponents::all();
+                        render_now = true;
+                        continue;
+                    }
 
+                    let capture = self.terminal_modes.mouse_capture;
+                    if let Some(enabled) = self
+                        .mouse_capture_on
+                        .as_mut()
+                        .and_then(|toggle| toggle(&action, capture))
+                    {
+                        self.terminal_modes.mouse_capture = enabled;
+                    }
                     if let Some(debug) = self.debug.as_mut() {
+                        debug.record_queue_depth(self.action_rx.len());
                         debug.log_action(&action);
                     }
+                    if let Some(crash_log) = &self.crash_log {
+                        crash_log.record(&action);
+                    }
+                    let urgent = self
+                        .render_now_on
+                        .as_mut()
+                        .is_some_and(|urgent| urgent(&action));
 
+                    let save_now = self
+                        .persistence
+                        .as_ref()
+                        .is_some_and(|persist| persist.policy.saves_on(&action));
                     let result = self.store.dispatch(action);
+                    if let Some(debug) = self.debug.as_mut() {
+                        debug.record_dispatched_state(self.store.state(), result.changed);
+                    }
+                    if let Some(persist) = self.persistence.as_mut() {
+                        persist.after_dispatch(self.store.state(), save_now, result.changed);
+                    }
+                    let touched = result.dirty();
+                    if let Some(debug) = self.debug.as_mut() {
+                        debug.record_change(!touched.is_empty());
+                    }
                     if result.has_effects() {
                         let mut ctx = self.effect_context();
                         for effect in result.effects {
                             handle_effect(effect, &mut ctx);
                         }
                     }
-                    self.should_render = result.changed;
+                    // A change that touches no components needs no redraw
+                    self.dirty.merge(touched);
+                    self.should_render |= !touched.is_empty();
+                    render_now |= !touched.is_empty() && urgent;
+                    for request in std::mem::take(&mut self.terminal_requests) {
+                        let modes = TerminalModes {
+                            mouse_capture,
+                            ..self.terminal_modes
+                        };
+                        if let Some(action) = feed.hand_off(terminal, modes, request)? {
+                            let _ = self.action_tx.send(action);
+                        }
+                        self.should_render = true;
+                        self.dirty = DirtyComponents::all();
+                        render_now = true;
+                    }
                 }
 
-                else => {
+                LoopInput::PersistDue => {
+                    if let Some(persist) = self.persistence.as_mut() {
+                        persist.save(self.store.state());
+                    }
+                }
+
+                LoopInput::FrameDue | LoopInput::Idle => {}
+
+                LoopInput::Closed => {
                     break;
                 }
             }
         }
 
-        cancel_token.cancel();
+        // Leave the terminal as the app configured it, without the debug layer's capture
+        if mouse_capture != self.terminal_modes.mouse_capture {
+            feed.set_mouse_capture(self.terminal_modes.mouse_capture)?;
+        }
+        if let Some(persist) = self.persistence.as_mut() {
+            persist.on_exit(self.store.state());
+        }
+        self.shutdown.run(self.store.state()).await;
         #[cfg(feature = "subscriptions")]
         self.subscriptions.cancel_all();
         #[cfg(feature = "tasks")]
@@ -697,3 +2334,214 @@ impl<S: 'static, A: Action, E, St: EffectStoreLike<S, A, E>> EffectRuntime<S, A,
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_clock_limits_frame_rate() {
+        let mut clock = FrameClock::new(Some(10));
+        // The first frame is never delayed
+        assert!(clock.next_frame().is_none());
+
+        clock.frame(RenderContext::default());
+        let due = clock.next_frame().expect("frame budget not elapsed");
+        assert!(due <= tokio::time::Instant::now() + Duration::from_millis(100));
+
+        // Without a limit (or with 0 fps) every frame may be drawn now
+        for max_fps in [None, Some(0)] {
+            let mut clock = FrameClock::new(max_fps);
+            clock.frame(RenderContext::default());
+            assert!(clock.next_frame().is_none());
+        }
+    }
+
+    fn mouse(kind: MouseEventKind, column: u16) -> EventKind {
+        EventKind::Mouse(crossterm::event::MouseEvent {
+            kind,
+            column,
+            row: 0,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        })
+    }
+
+    fn scroll(column: u16, delta: isize) -> EventKind {
+        EventKind::Scroll {
+            column,
+            row: 0,
+            delta,
+        }
+    }
+
+    fn coalesce(events: Vec<EventKind>) -> (String, Option<String>) {
+        let mut queue = events.into_iter();
+        let first = queue.next().unwrap();
+        let (event, next) = coalesce_pointer(first, || queue.next());
+        (format!("{event:?}"), next.map(|event| format!("{event:?}")))
+    }
+
+    #[test]
+    fn test_coalesce_scroll_sums_deltas() {
+        let (event, next) = coalesce(vec![
+            scroll(3, 1),
+            scroll(3, 1),
+            scroll(3, -1),
+            scroll(3, 1),
+        ]);
+        assert_eq!(event, format!("{:?}", scroll(3, 2)));
+        assert!(next.is_none());
+
+        // A scroll somewhere else starts a new gesture
+        let (event, next) = coalesce(vec![scroll(3, 1), scroll(3, 1), scroll(9, 1)]);
+        assert_eq!(event, format!("{:?}", scroll(3, 2)));
+        assert_eq!(next, Some(format!("{:?}", scroll(9, 1))));
+    }
+
+    #[test]
+    fn test_coalesce_moves_and_drags_keep_last_position() {
+        use crossterm::event::MouseButton;
+
+        let drag = MouseEventKind::Drag(MouseButton::Left);
+        let (event, next) = coalesce(vec![
+            mouse(drag, 1),
+            mouse(drag, 2),
+            mouse(drag, 5),
+            mouse(MouseEventKind::Up(MouseButton::Left), 5),
+        ]);
+        assert_eq!(event, format!("{:?}", mouse(drag, 5)));
+        assert_eq!(
+            next,
+            Some(format!(
+                "{:?}",
+                mouse(MouseEventKind::Up(MouseButton::Left), 5)
+            ))
+        );
+
+        let (event, next) = coalesce(vec![
+            mouse(MouseEventKind::Moved, 1),
+            mouse(MouseEventKind::Moved, 4),
+            mouse(MouseEventKind::Drag(MouseButton::Right), 4),
+        ]);
+        assert_eq!(event, format!("{:?}", mouse(MouseEventKind::Moved, 4)));
+        assert!(next.is_some());
+
+        // Clicks and keys are never merged
+        let click = mouse(MouseEventKind::Down(MouseButton::Left), 1);
+        let (event, next) = coalesce(vec![click.clone(), click.clone()]);
+        assert_eq!(event, format!("{click:?}"));
+        assert_eq!(next, Some(format!("{click:?}")));
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum BuilderAction {
+        Inc,
+        Quit,
+    }
+
+    impl Action for BuilderAction {
+        fn name(&self) -> &'static str {
+            match self {
+                BuilderAction::Inc => "Inc",
+                BuilderAction::Quit => "Quit",
+            }
+        }
+    }
+
+    fn builder_reducer(state: &mut i32, action: BuilderAction) -> bool {
+        match action {
+            BuilderAction::Inc => {
+                *state += 1;
+                true
+            }
+            BuilderAction::Quit => false,
+        }
+    }
+
+    fn builder_map_event(event: &EventKind, _state: &i32) -> Option<BuilderAction> {
+        match event {
+            EventKind::Key(key) if key.code == crossterm::event::KeyCode::Char('+') => {
+                Some(BuilderAction::Inc)
+            }
+            EventKind::Key(key) if key.code == crossterm::event::KeyCode::Char('q') => {
+                Some(BuilderAction::Quit)
+            }
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_builder_requires_render_and_map_event() {
+        let err = Runtime::builder()
+            .state(0, builder_reducer)
+            .map_event(builder_map_event)
+            .run()
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        let err = Runtime::builder()
+            .state(0, builder_reducer)
+            .render(|_, _, _, _| {})
+            .run()
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[tokio::test]
+    async fn test_builder_options_reach_runtime() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let mouse_capture = Rc::new(Cell::new(false));
+        let shut_down = Rc::new(Cell::new(None));
+        let render_mouse = mouse_capture.clone();
+        let hook_state = shut_down.clone();
+
+        let (mut runtime, render, map_event, should_quit) = Runtime::builder()
+            .mouse_capture(true)
+            .event_poller(PollerConfig::default().with_max_fps(30))
+            .state(0, builder_reducer)
+            .render(move |_, _, _, ctx| render_mouse.set(ctx.mouse_capture))
+            .map_event(builder_map_event)
+            .quit_on(|action| *action == BuilderAction::Quit)
+            .configure(move |runtime| {
+                runtime.on_shutdown(move |state: &i32| {
+                    hook_state.set(Some(*state));
+                    async {}
+                })
+            })
+            .into_parts()
+            .unwrap();
+
+        assert_eq!(runtime.poller_config.max_fps, Some(30));
+        assert!(runtime.mouse_capture());
+
+        let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(10, 2)).unwrap();
+        let steps = crate::testing::keys("+ + q +")
+            .into_iter()
+            .map(|key| ScriptStep::Event(EventKind::Key(key)))
+            .collect();
+        let mut history = Vec::new();
+        runtime
+            .run_script(
+                &mut terminal,
+                steps,
+                &mut history,
+                render,
+                map_event,
+                should_quit,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            history,
+            [BuilderAction::Inc, BuilderAction::Inc, BuilderAction::Quit]
+        );
+        assert_eq!(*runtime.state(), 2);
+        assert!(mouse_capture.get());
+        assert_eq!(shut_down.get(), Some(2));
+    }
+}
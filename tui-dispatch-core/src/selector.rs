@@ -0,0 +1,207 @@
+//! Memoized selectors for derived state
+//!
+//! A [`Selector`] wraps a function that computes derived data from state
+//! (filtered lists, totals, layout tables) and caches the result. Stores
+//! keep a version counter that is bumped whenever a dispatch reports a
+//! change, so the selector only recomputes when the state may have changed
+//! instead of on every render.
+//!
+//! # Example
+//!
+//! ```
+//! use tui_dispatch_core::{Action, Selector, Store};
+//!
+//! #[derive(Clone, Debug)]
+//! enum Msg {
+//!     Add(u32),
+//!     Noop,
+//! }
+//!
+//! impl Action for Msg {
+//!     fn name(&self) -> &'static str {
+//!         "Msg"
+//!     }
+//! }
+//!
+//! fn reducer(state: &mut Vec<u32>, action: Msg) -> bool {
+//!     match action {
+//!         Msg::Add(n) => {
+//!             state.push(n);
+//!             true
+//!         }
+//!         Msg::Noop => false,
+//!     }
+//! }
+//!
+//! let mut store = Store::new(vec![1, 2], reducer);
+//! let mut total = Selector::new(|items: &Vec<u32>| items.iter().sum::<u32>());
+//!
+//! assert_eq!(*store.select(&mut total), 3);
+//! store.dispatch(Msg::Noop);
+//! assert_eq!(*store.select(&mut total), 3); // cached
+//! store.dispatch(Msg::Add(4));
+//! assert_eq!(*store.select(&mut total), 7); // recomputed
+//! ```
+//!
+//! With a runtime, the version is available as
+//! [`RenderContext::state_version`](crate::RenderContext::state_version):
+//!
+//! ```ignore
+//! runtime.run(terminal, |frame, area, state, ctx| {
+//!     let visible = visible_items.select(state, ctx.state_version);
+//!     list.render(frame, area, ListProps { items: visible, .. });
+//! }, map_event, should_quit).await?;
+//! ```
+
+/// A memoized computation of derived data from state `S`.
+///
+/// The cached value is reused while the state version stays the same.
+/// Selector functions must be pure: their output should depend only on
+/// the state they are given.
+pub struct Selector<S, T> {
+    compute: fn(&S) -> T,
+    cached: Option<(u64, T)>,
+}
+
+impl<S, T> std::fmt::Debug for Selector<S, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Selector")
+            .field("cached_version", &self.cached_version())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, T> Selector<S, T> {
+    /// Create a selector from a compute function.
+    pub fn new(compute: fn(&S) -> T) -> Self {
+        Self {
+            compute,
+            cached: None,
+        }
+    }
+
+    /// Get the derived value for `state` at `version`.
+    ///
+    /// Recomputes only if nothing is cached yet or `version` differs from
+    /// the version of the cached value.
+    pub fn select(&mut self, state: &S, version: u64) -> &T {
+        if self.cached_version() != Some(version) {
+            self.cached = Some((version, (self.compute)(state)));
+        }
+        let (_, value) = self
+            .cached
+            .as_ref()
+            .expect("selector cache populated above");
+        value
+    }
+
+    /// Version of the cached value, if any.
+    pub fn cached_version(&self) -> Option<u64> {
+        self.cached.as_ref().map(|(version, _)| *version)
+    }
+
+    /// Drop the cached value so the next `select` recomputes.
+    pub fn invalidate(&mut self) {
+        self.cached = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::effect::{DispatchResult, EffectStore};
+    use crate::store::Store;
+    use crate::Action;
+    use std::cell::Cell;
+
+    #[derive(Clone, Debug)]
+    enum TestAction {
+        Push(u32),
+        Noop,
+    }
+
+    impl Action for TestAction {
+        fn name(&self) -> &'static str {
+            match self {
+                TestAction::Push(_) => "Push",
+                TestAction::Noop => "Noop",
+            }
+        }
+    }
+
+    fn reducer(state: &mut Vec<u32>, action: TestAction) -> bool {
+        match action {
+            TestAction::Push(n) => {
+                state.push(n);
+                true
+            }
+            TestAction::Noop => false,
+        }
+    }
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn counted_sum(items: &Vec<u32>) -> u32 {
+        CALLS.with(|calls| calls.set(calls.get() + 1));
+        items.iter().sum()
+    }
+
+    #[test]
+    fn test_recomputes_only_on_version_change() {
+        CALLS.with(|calls| calls.set(0));
+        let mut store = Store::new(vec![1], reducer);
+        let mut sum = Selector::new(counted_sum);
+
+        assert_eq!(*store.select(&mut sum), 1);
+        assert_eq!(*store.select(&mut sum), 1);
+        store.dispatch(TestAction::Noop);
+        assert_eq!(*store.select(&mut sum), 1);
+        assert_eq!(CALLS.with(Cell::get), 1);
+
+        store.dispatch(TestAction::Push(2));
+        assert_eq!(*store.select(&mut sum), 3);
+        assert_eq!(CALLS.with(Cell::get), 2);
+        assert_eq!(sum.cached_version(), Some(store.version()));
+    }
+
+    #[test]
+    fn test_state_mut_and_invalidate() {
+        CALLS.with(|calls| calls.set(0));
+        let mut store = Store::new(vec![1], reducer);
+        let mut sum = Selector::new(counted_sum);
+        store.select(&mut sum);
+
+        store.state_mut().push(10);
+        assert_eq!(*store.select(&mut sum), 11);
+
+        sum.invalidate();
+        assert_eq!(sum.cached_version(), None);
+        store.select(&mut sum);
+        assert_eq!(CALLS.with(Cell::get), 3);
+    }
+
+    #[test]
+    fn test_effect_store_version() {
+        fn effect_reducer(state: &mut u32, action: TestAction) -> DispatchResult<()> {
+            match action {
+                TestAction::Push(n) => {
+                    *state += n;
+                    DispatchResult::changed()
+                }
+                TestAction::Noop => DispatchResult::unchanged(),
+            }
+        }
+
+        let mut store = EffectStore::new(0, effect_reducer);
+        let mut doubled = Selector::new(|n: &u32| n * 2);
+
+        store.dispatch(TestAction::Noop);
+        assert_eq!(store.version(), 0);
+        store.dispatch(TestAction::Push(3));
+        assert_eq!(store.version(), 1);
+        assert_eq!(*store.select(&mut doubled), 6);
+    }
+}
@@ -1,13 +1,134 @@
 //! Centralized state store with reducer pattern
 
-use crate::Action;
+use crate::selector::Selector;
+use crate::{Action, ActionCategory};
+use serde::{de::DeserializeOwned, Serialize};
 use std::marker::PhantomData;
+use tokio::sync::mpsc;
 
 /// A reducer function that handles actions and mutates state
 ///
 /// Returns `true` if the state changed and a re-render is needed.
 pub type Reducer<S, A> = fn(&mut S, A) -> bool;
 
+/// A reducer that also receives the store's environment
+///
+/// The environment carries dependencies such as a clock, RNG or config, so
+/// reducers don't reach for globals and tests can swap in fakes. Created with
+/// [`Store::new_with_env`].
+pub type EnvReducer<S, A, E> = fn(&mut S, A, &E) -> bool;
+
+/// Reducer held by a [`Store`]
+enum StoreReducer<S, A, E> {
+    Plain(Reducer<S, A>),
+    WithEnv(EnvReducer<S, A, E>),
+}
+
+impl<S, A, E> StoreReducer<S, A, E> {
+    fn reduce(&self, state: &mut S, action: A, env: &E) -> bool {
+        match self {
+            StoreReducer::Plain(reducer) => reducer(state, action),
+            StoreReducer::WithEnv(reducer) => reducer(state, action, env),
+        }
+    }
+}
+
+/// Which slices a combined reducer changed
+///
+/// Returned by reducers generated with [`combine_reducers!`](crate::combine_reducers)
+/// using the `-> SliceChanges` form. Slices are identified by their field name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SliceChanges {
+    changed: Vec<&'static str>,
+}
+
+impl SliceChanges {
+    /// Create an empty set of changes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the result of one slice reducer
+    pub fn record(&mut self, slice: &'static str, changed: bool) {
+        if changed {
+            self.changed.push(slice);
+        }
+    }
+
+    /// Whether any slice changed
+    pub fn any(&self) -> bool {
+        !self.changed.is_empty()
+    }
+
+    /// Whether the named slice changed
+    pub fn contains(&self, slice: &str) -> bool {
+        self.changed.contains(&slice)
+    }
+
+    /// Names of the changed slices, in reducer order
+    pub fn slices(&self) -> &[&'static str] {
+        &self.changed
+    }
+}
+
+impl From<SliceChanges> for bool {
+    fn from(changes: SliceChanges) -> bool {
+        changes.any()
+    }
+}
+
+/// Token returned by `subscribe`, used to remove the listener again
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId(u64);
+
+/// A store listener: called with the action, the state after the reducer
+/// ran, and whether the state changed
+pub type Listener<S, A> = Box<dyn FnMut(&A, &S, bool)>;
+
+/// Listeners registered on a store
+pub(crate) struct Listeners<S, A> {
+    listeners: Vec<(ListenerId, Listener<S, A>)>,
+    next_id: u64,
+}
+
+impl<S, A> Default for Listeners<S, A> {
+    fn default() -> Self {
+        Self {
+            listeners: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<S, A> Listeners<S, A> {
+    pub(crate) fn subscribe(&mut self, listener: impl FnMut(&A, &S, bool) + 'static) -> ListenerId {
+        let id = ListenerId(self.next_id);
+        self.next_id += 1;
+        self.listeners.push((id, Box::new(listener)));
+        id
+    }
+
+    pub(crate) fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        let before = self.listeners.len();
+        self.listeners.retain(|(listener_id, _)| *listener_id != id);
+        self.listeners.len() != before
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.listeners.len()
+    }
+
+    pub(crate) fn notify(&mut self, action: &A, state: &S, changed: bool) {
+        for (_, listener) in &mut self.listeners {
+            listener(action, state, changed);
+        }
+    }
+}
+
 /// Centralized state store with Redux-like reducer pattern
 ///
 /// The store holds the application state and provides a single point
@@ -47,9 +168,36 @@ pub type Reducer<S, A> = fn(&mut S, A) -> bool;
 /// store.dispatch(MyAction::Increment);
 /// assert_eq!(store.state().counter, 1);
 /// ```
-pub struct Store<S, A: Action> {
+///
+/// # Environment
+///
+/// Reducers that need outside dependencies take them from an environment
+/// `E` instead of globals:
+///
+/// ```ignore
+/// struct Env {
+///     clock: Box<dyn Fn() -> SystemTime>,
+/// }
+///
+/// fn reducer(state: &mut AppState, action: MyAction, env: &Env) -> bool {
+///     match action {
+///         MyAction::Save => {
+///             state.saved_at = Some((env.clock)());
+///             true
+///         }
+///     }
+/// }
+///
+/// let env = Env { clock: Box::new(SystemTime::now) };
+/// let mut store = Store::new_with_env(AppState::default(), env, reducer);
+/// ```
+pub struct Store<S, A: Action, E = ()> {
     state: S,
-    reducer: Reducer<S, A>,
+    reducer: StoreReducer<S, A, E>,
+    env: E,
+    /// Bumped whenever the state may have changed (see [`Store::version`])
+    version: u64,
+    listeners: Listeners<S, A>,
     _marker: PhantomData<A>,
 }
 
@@ -58,17 +206,127 @@ impl<S, A: Action> Store<S, A> {
     pub fn new(state: S, reducer: Reducer<S, A>) -> Self {
         Self {
             state,
-            reducer,
+            reducer: StoreReducer::Plain(reducer),
+            env: (),
+            version: 0,
+            listeners: Listeners::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, A: Action, E> Store<S, A, E> {
+    /// Create a store whose reducer receives `env` with every action
+    ///
+    /// Use a real environment in the app and a fake one (fixed clock,
+    /// seeded RNG) in tests. The runtimes take such a store via
+    /// `from_store`.
+    pub fn new_with_env(state: S, env: E, reducer: EnvReducer<S, A, E>) -> Self {
+        Self {
+            state,
+            reducer: StoreReducer::WithEnv(reducer),
+            env,
+            version: 0,
+            listeners: Listeners::default(),
             _marker: PhantomData,
         }
     }
 
+    /// Get a reference to the reducer environment
+    pub fn env(&self) -> &E {
+        &self.env
+    }
+
+    /// Get a mutable reference to the reducer environment
+    ///
+    /// Handy in tests, e.g. to advance a fake clock between dispatches.
+    pub fn env_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+
     /// Dispatch an action to the store
     ///
     /// The reducer will be called with the current state and action.
     /// Returns `true` if the state changed and a re-render is needed.
     pub fn dispatch(&mut self, action: A) -> bool {
-        (self.reducer)(&mut self.state, action)
+        if self.listeners.is_empty() {
+            let changed = self.reducer.reduce(&mut self.state, action, &self.env);
+            if changed {
+                self.version += 1;
+            }
+            return changed;
+        }
+        let changed = self
+            .reducer
+            .reduce(&mut self.state, action.clone(), &self.env);
+        if changed {
+            self.version += 1;
+        }
+        self.listeners.notify(&action, &self.state, changed);
+        changed
+    }
+
+    /// Dispatch several actions as one batch
+    ///
+    /// Every action is run through the reducer in order. Returns `true` if
+    /// any of them changed the state; the state version is bumped at most
+    /// once, so selectors and renders see the batch as a single update.
+    ///
+    /// Listeners are notified after each action with that action's result.
+    pub fn dispatch_all(&mut self, actions: impl IntoIterator<Item = A>) -> bool {
+        self.dispatch_batch(actions, |_| {})
+    }
+
+    /// Like [`dispatch_all`](Self::dispatch_all), reporting each action's
+    /// changed flag to `on_reduced` in order
+    pub(crate) fn dispatch_batch(
+        &mut self,
+        actions: impl IntoIterator<Item = A>,
+        mut on_reduced: impl FnMut(bool),
+    ) -> bool {
+        let mut changed = false;
+        for action in actions {
+            let action_changed = if self.listeners.is_empty() {
+                self.reducer.reduce(&mut self.state, action, &self.env)
+            } else {
+                let action_changed =
+                    self.reducer
+                        .reduce(&mut self.state, action.clone(), &self.env);
+                self.listeners.notify(&action, &self.state, action_changed);
+                action_changed
+            };
+            on_reduced(action_changed);
+            changed |= action_changed;
+        }
+        if changed {
+            self.version += 1;
+        }
+        changed
+    }
+
+    /// Observe dispatched actions outside the render loop
+    ///
+    /// The listener is called after every reduced action with the action,
+    /// the state after the reducer ran, and whether it changed. Use it for
+    /// metrics, persistence or plugins that should not be middleware.
+    /// Actions swallowed by middleware never reach listeners, and
+    /// `state_mut` does not notify them.
+    ///
+    /// Returns a token for [`unsubscribe`](Self::unsubscribe).
+    pub fn subscribe(&mut self, listener: impl FnMut(&A, &S, bool) + 'static) -> ListenerId {
+        self.listeners.subscribe(listener)
+    }
+
+    /// Remove a listener added with [`subscribe`](Self::subscribe)
+    ///
+    /// Returns `false` if the listener was already removed.
+    pub fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        self.listeners.unsubscribe(id)
+    }
+
+    /// Number of registered listeners
+    pub fn listener_count(&self) -> usize {
+        self.listeners.len()
     }
 
     /// Get a reference to the current state
@@ -81,9 +339,52 @@ impl<S, A: Action> Store<S, A> {
     /// Use this sparingly - prefer dispatching actions for state changes.
     /// This is useful for initializing state or for cases where the
     /// action pattern doesn't fit well.
+    ///
+    /// Bumps the state version, since the caller may mutate the state.
     pub fn state_mut(&mut self) -> &mut S {
+        self.version += 1;
         &mut self.state
     }
+
+    /// Get the state version
+    ///
+    /// Starts at 0 and increments each time a dispatch reports a change
+    /// (or `state_mut` is called). Used by [`Selector`] for memoization.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Compute derived data with a memoized selector
+    ///
+    /// Recomputes only if the state version changed since the selector
+    /// last ran.
+    pub fn select<'s, T>(&self, selector: &'s mut Selector<S, T>) -> &'s T {
+        selector.select(&self.state, self.version)
+    }
+
+    /// Serialize the state to pretty-printed JSON
+    ///
+    /// Attach the output to a bug report and load it with
+    /// [`import_snapshot`](Self::import_snapshot) to reproduce the state,
+    /// e.g. in a test.
+    pub fn export_snapshot(&self) -> serde_json::Result<String>
+    where
+        S: Serialize,
+    {
+        serde_json::to_string_pretty(&self.state)
+    }
+
+    /// Replace the state with one written by [`export_snapshot`](Self::export_snapshot)
+    ///
+    /// On error the state is left unchanged. Listeners are not notified;
+    /// the version is bumped so selectors recompute.
+    pub fn import_snapshot(&mut self, json: &str) -> serde_json::Result<()>
+    where
+        S: DeserializeOwned,
+    {
+        *self.state_mut() = serde_json::from_str(json)?;
+        Ok(())
+    }
 }
 
 /// Store with middleware support
@@ -93,6 +394,8 @@ impl<S, A: Action> Store<S, A> {
 pub struct StoreWithMiddleware<S, A: Action, M: Middleware<A>> {
     store: Store<S, A>,
     middleware: M,
+    /// Sender for middleware follow-up actions
+    action_tx: Option<mpsc::UnboundedSender<A>>,
 }
 
 impl<S, A: Action, M: Middleware<A>> StoreWithMiddleware<S, A, M> {
@@ -101,14 +404,60 @@ impl<S, A: Action, M: Middleware<A>> StoreWithMiddleware<S, A, M> {
         Self {
             store: Store::new(state, reducer),
             middleware,
+            action_tx: None,
         }
     }
 
+    /// Set the sender passed to [`Middleware::follow_up`]
+    ///
+    /// Runtimes call this automatically with their action queue; without a
+    /// sender, `follow_up` is not called.
+    pub fn set_action_sender(&mut self, action_tx: mpsc::UnboundedSender<A>) {
+        self.action_tx = Some(action_tx);
+    }
+
     /// Dispatch an action through middleware and store
+    ///
+    /// The action is first passed through [`Middleware::intercept`]; if it is
+    /// swallowed, nothing else runs and `false` is returned.
     pub fn dispatch(&mut self, action: A) -> bool {
+        let Some(action) = self.middleware.intercept(action) else {
+            return false;
+        };
         self.middleware.before(&action);
         let changed = self.store.dispatch(action.clone());
         self.middleware.after(&action, changed);
+        if let Some(action_tx) = &self.action_tx {
+            self.middleware.follow_up(&action, changed, action_tx);
+        }
+        changed
+    }
+
+    /// Dispatch several actions as one transaction
+    ///
+    /// Middleware is notified once with the whole batch
+    /// ([`Middleware::before_batch`] / [`Middleware::after_batch`]) and a
+    /// single changed flag is returned, e.g. for one render and one undo
+    /// entry per batch.
+    pub fn dispatch_all(&mut self, actions: impl IntoIterator<Item = A>) -> bool {
+        let actions: Vec<A> = actions
+            .into_iter()
+            .filter_map(|action| self.middleware.intercept(action))
+            .collect();
+        if actions.is_empty() {
+            return false;
+        }
+        self.middleware.before_batch(&actions);
+        let mut changes = Vec::with_capacity(actions.len());
+        let changed = self
+            .store
+            .dispatch_batch(actions.iter().cloned(), |changed| changes.push(changed));
+        self.middleware.after_batch(&actions, &changes);
+        if let Some(action_tx) = &self.action_tx {
+            for (action, &action_changed) in actions.iter().zip(&changes) {
+                self.middleware.follow_up(action, action_changed, action_tx);
+            }
+        }
         changed
     }
 
@@ -122,6 +471,49 @@ impl<S, A: Action, M: Middleware<A>> StoreWithMiddleware<S, A, M> {
         self.store.state_mut()
     }
 
+    /// Get the state version
+    pub fn version(&self) -> u64 {
+        self.store.version()
+    }
+
+    /// Compute derived data with a memoized selector
+    pub fn select<'s, T>(&self, selector: &'s mut Selector<S, T>) -> &'s T {
+        self.store.select(selector)
+    }
+
+    /// Serialize the state to JSON (see [`Store::export_snapshot`])
+    pub fn export_snapshot(&self) -> serde_json::Result<String>
+    where
+        S: Serialize,
+    {
+        self.store.export_snapshot()
+    }
+
+    /// Replace the state from JSON (see [`Store::import_snapshot`])
+    pub fn import_snapshot(&mut self, json: &str) -> serde_json::Result<()>
+    where
+        S: DeserializeOwned,
+    {
+        self.store.import_snapshot(json)
+    }
+
+    /// Observe dispatched actions (see [`Store::subscribe`])
+    ///
+    /// Listeners run after the reducer and before [`Middleware::after`].
+    pub fn subscribe(&mut self, listener: impl FnMut(&A, &S, bool) + 'static) -> ListenerId {
+        self.store.subscribe(listener)
+    }
+
+    /// Remove a listener added with [`subscribe`](Self::subscribe)
+    pub fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        self.store.unsubscribe(id)
+    }
+
+    /// Number of registered listeners
+    pub fn listener_count(&self) -> usize {
+        self.store.listener_count()
+    }
+
     /// Get a reference to the middleware
     pub fn middleware(&self) -> &M {
         &self.middleware
@@ -137,12 +529,64 @@ impl<S, A: Action, M: Middleware<A>> StoreWithMiddleware<S, A, M> {
 ///
 /// Implement this trait to add logging, persistence, or other
 /// cross-cutting concerns to your store.
+///
+/// For each dispatch the store calls [`intercept`](Middleware::intercept),
+/// then `before`, the reducer, `after`, and finally
+/// [`follow_up`](Middleware::follow_up). Only `before` and `after` are
+/// required; the other hooks let middleware validate or rewrite actions and
+/// derive new ones.
 pub trait Middleware<A: Action> {
+    /// Transform or swallow an action before it is dispatched
+    ///
+    /// Return the action (or a replacement) to continue, or `None` to drop
+    /// it - a dropped action skips the reducer and the other hooks.
+    /// Defaults to passing the action through unchanged.
+    fn intercept(&mut self, action: A) -> Option<A> {
+        Some(action)
+    }
+
     /// Called before the action is dispatched to the reducer
     fn before(&mut self, action: &A);
 
     /// Called after the action is processed by the reducer
     fn after(&mut self, action: &A, state_changed: bool);
+
+    /// Emit follow-up actions after an action was processed
+    ///
+    /// Actions sent on `action_tx` are queued and dispatched after the
+    /// current one, like any other action. Only called when the store has a
+    /// sender (see [`StoreWithMiddleware::set_action_sender`]). Defaults to
+    /// doing nothing.
+    fn follow_up(
+        &mut self,
+        _action: &A,
+        _state_changed: bool,
+        _action_tx: &mpsc::UnboundedSender<A>,
+    ) {
+    }
+
+    /// Called once before a batch of actions is dispatched
+    ///
+    /// Defaults to calling [`before`](Middleware::before) for each action.
+    /// The whole batch is reduced in one go, so with the defaults every
+    /// `before` runs first, then every `after`, rather than in pairs.
+    fn before_batch(&mut self, actions: &[A]) {
+        for action in actions {
+            self.before(action);
+        }
+    }
+
+    /// Called once after a batch of actions is processed
+    ///
+    /// `changed` holds each action's changed flag, in the order of
+    /// `actions`; the batch changed state if any of them is true. Defaults
+    /// to calling [`after`](Middleware::after) for each action with its own
+    /// flag.
+    fn after_batch(&mut self, actions: &[A], changed: &[bool]) {
+        for (action, &state_changed) in actions.iter().zip(changed) {
+            self.after(action, state_changed);
+        }
+    }
 }
 
 /// A no-op middleware that does nothing
@@ -199,15 +643,208 @@ impl<A: Action> Middleware<A> for LoggingMiddleware {
     }
 }
 
+/// Middleware that emits structured `tracing` spans per action
+///
+/// Each dispatch runs inside a `dispatch` span at DEBUG level with the
+/// action name, its category (see [`with_categories`](Self::with_categories)),
+/// its parameters ([`ActionParams`], honoring `#[param(redact)]`), the
+/// reducer time in microseconds and whether the state changed. Logs emitted
+/// by the reducer are nested under that span, and a `dispatched` event is
+/// emitted when the reducer returns. Batches get one `dispatch_batch` span.
+///
+/// Nothing is formatted unless a subscriber has DEBUG enabled for this
+/// crate, so it is cheap to leave installed. The middleware keeps a plain
+/// [`Span`](tracing::Span) between `before` and `after` rather than an
+/// entered guard, so it stays `Send`.
+#[cfg(feature = "tracing-middleware")]
+pub struct TracingMiddleware<A> {
+    category: Option<fn(&A) -> Option<&'static str>>,
+    params: bool,
+    /// Span entered in `before`, exited in `after`
+    span: Option<tracing::Span>,
+    started: Option<std::time::Instant>,
+}
+
+#[cfg(feature = "tracing-middleware")]
+impl<A> std::fmt::Debug for TracingMiddleware<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TracingMiddleware")
+            .field("categories", &self.category.is_some())
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+#[cfg(feature = "tracing-middleware")]
+impl<A> Default for TracingMiddleware<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tracing-middleware")]
+impl<A> TracingMiddleware<A> {
+    /// Create a tracing middleware that records names and parameters
+    pub fn new() -> Self {
+        Self {
+            category: None,
+            params: true,
+            span: None,
+            started: None,
+        }
+    }
+
+    /// Set whether action parameters are recorded (default: true)
+    pub fn params(mut self, enabled: bool) -> Self {
+        self.params = enabled;
+        self
+    }
+
+    /// Record each action's category
+    ///
+    /// Requires [`ActionCategory`](crate::ActionCategory), which
+    /// `#[derive(Action)]` implements when categories are enabled.
+    pub fn with_categories(mut self) -> Self
+    where
+        A: crate::ActionCategory,
+    {
+        self.category = Some(<A as crate::ActionCategory>::category);
+        self
+    }
+
+    /// Enter `span` until [`finish`](Self::finish), so the reducer's logs nest under it
+    fn enter(&mut self, span: tracing::Span) {
+        // `after` never ran for the previous dispatch, e.g. because the
+        // middleware was disabled in between
+        self.exit();
+        span.with_subscriber(|(id, dispatch)| dispatch.enter(id));
+        self.started = Some(std::time::Instant::now());
+        self.span = Some(span);
+    }
+
+    /// Exit the held span, if any, without recording a result
+    fn exit(&mut self) {
+        if let Some(span) = self.span.take() {
+            span.with_subscriber(|(id, dispatch)| dispatch.exit(id));
+        }
+        self.started = None;
+    }
+
+    /// Record the reducer time and result on the current span and exit it
+    fn finish(&mut self, state_changed: bool) {
+        let Some(span) = self.span.take() else {
+            return;
+        };
+        let elapsed_us = self
+            .started
+            .take()
+            .map_or(0, |started| started.elapsed().as_micros() as u64);
+        span.record("elapsed_us", elapsed_us);
+        span.record("state_changed", state_changed);
+        tracing::debug!(elapsed_us, state_changed, "dispatched");
+        span.with_subscriber(|(id, dispatch)| dispatch.exit(id));
+    }
+}
+
+#[cfg(feature = "tracing-middleware")]
+impl<A> Drop for TracingMiddleware<A> {
+    fn drop(&mut self) {
+        // A reducer panicked between `before` and `after`
+        self.exit();
+    }
+}
+
+#[cfg(feature = "tracing-middleware")]
+impl<A: crate::ActionParams> Middleware<A> for TracingMiddleware<A> {
+    fn before(&mut self, action: &A) {
+        let span = tracing::debug_span!(
+            "dispatch",
+            action = action.name(),
+            category = tracing::field::Empty,
+            params = tracing::field::Empty,
+            elapsed_us = tracing::field::Empty,
+            state_changed = tracing::field::Empty,
+        );
+        if span.is_disabled() {
+            return;
+        }
+        if let Some(category) = self.category.and_then(|category| category(action)) {
+            span.record("category", category);
+        }
+        if self.params {
+            let params = action.params();
+            if !params.is_empty() {
+                span.record("params", params.as_str());
+            }
+        }
+        self.enter(span);
+    }
+
+    fn after(&mut self, _action: &A, state_changed: bool) {
+        self.finish(state_changed);
+    }
+
+    fn before_batch(&mut self, actions: &[A]) {
+        let span = tracing::debug_span!(
+            "dispatch_batch",
+            count = actions.len(),
+            actions = tracing::field::Empty,
+            elapsed_us = tracing::field::Empty,
+            state_changed = tracing::field::Empty,
+        );
+        if span.is_disabled() {
+            return;
+        }
+        let names: Vec<&str> = actions.iter().map(|action| action.name()).collect();
+        span.record("actions", names.join(", ").as_str());
+        self.enter(span);
+    }
+
+    fn after_batch(&mut self, _actions: &[A], changed: &[bool]) {
+        self.finish(changed.contains(&true));
+    }
+}
+
+/// Name and state of a middleware in a [`ComposedMiddleware`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MiddlewareInfo {
+    /// Name given with [`add_named`](ComposedMiddleware::add_named), or the type name
+    pub name: &'static str,
+    /// Whether the middleware runs
+    pub enabled: bool,
+}
+
+struct PipelineEntry<A: Action> {
+    info: MiddlewareInfo,
+    middleware: Box<dyn Middleware<A>>,
+}
+
 /// Compose multiple middleware into a single middleware
+///
+/// Middleware run in the order they were added: `intercept` and `before`
+/// front to back, `after` and `follow_up` back to front. Each one has a
+/// name and can be switched off at runtime; a disabled middleware is
+/// skipped by every hook. Build one with [`middleware_pipeline!`](crate::middleware_pipeline):
+///
+/// ```ignore
+/// let middleware = middleware_pipeline![
+///     debug.metrics_middleware(),
+///     "log" => LoggingMiddleware::new(),
+/// ];
+/// let mut store = StoreWithMiddleware::new(state, reducer, middleware);
+///
+/// store.middleware_mut().set_enabled("log", false);
+/// debug.record_middlewares(store.middleware().iter());
+/// ```
 pub struct ComposedMiddleware<A: Action> {
-    middlewares: Vec<Box<dyn Middleware<A>>>,
+    middlewares: Vec<PipelineEntry<A>>,
 }
 
 impl<A: Action> std::fmt::Debug for ComposedMiddleware<A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let middlewares: Vec<_> = self.iter().collect();
         f.debug_struct("ComposedMiddleware")
-            .field("middlewares_count", &self.middlewares.len())
+            .field("middlewares", &middlewares)
             .finish()
     }
 }
@@ -226,54 +863,465 @@ impl<A: Action> ComposedMiddleware<A> {
         }
     }
 
-    /// Add a middleware to the composition
+    /// Add a middleware to the composition, named after its type
     pub fn add<M: Middleware<A> + 'static>(&mut self, middleware: M) {
-        self.middlewares.push(Box::new(middleware));
+        self.add_named(short_type_name::<M>(), middleware);
+    }
+
+    /// Add a middleware under a name, for [`set_enabled`](Self::set_enabled)
+    pub fn add_named<M: Middleware<A> + 'static>(&mut self, name: &'static str, middleware: M) {
+        self.middlewares.push(PipelineEntry {
+            info: MiddlewareInfo {
+                name,
+                enabled: true,
+            },
+            middleware: Box::new(middleware),
+        });
+    }
+
+    /// Enable or disable every middleware with this name
+    ///
+    /// Returns false if no middleware has the name.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        let mut found = false;
+        for entry in &mut self.middlewares {
+            if entry.info.name == name {
+                entry.info.enabled = enabled;
+                found = true;
+            }
+        }
+        found
+    }
+
+    /// Whether the first middleware with this name is enabled
+    pub fn is_enabled(&self, name: &str) -> Option<bool> {
+        self.iter()
+            .find(|info| info.name == name)
+            .map(|info| info.enabled)
+    }
+
+    /// The attached middleware, in order
+    pub fn iter(&self) -> impl Iterator<Item = MiddlewareInfo> + '_ {
+        self.middlewares.iter().map(|entry| entry.info)
+    }
+
+    /// Number of attached middleware
+    pub fn len(&self) -> usize {
+        self.middlewares.len()
+    }
+
+    /// Whether no middleware is attached
+    pub fn is_empty(&self) -> bool {
+        self.middlewares.is_empty()
+    }
+
+    fn enabled(&mut self) -> impl DoubleEndedIterator<Item = &mut Box<dyn Middleware<A>>> + '_ {
+        self.middlewares
+            .iter_mut()
+            .filter(|entry| entry.info.enabled)
+            .map(|entry| &mut entry.middleware)
     }
 }
 
 impl<A: Action> Middleware<A> for ComposedMiddleware<A> {
+    fn intercept(&mut self, action: A) -> Option<A> {
+        // Each middleware sees the previous one's output
+        self.enabled()
+            .try_fold(action, |action, middleware| middleware.intercept(action))
+    }
+
     fn before(&mut self, action: &A) {
-        for middleware in &mut self.middlewares {
+        for middleware in self.enabled() {
             middleware.before(action);
         }
     }
 
     fn after(&mut self, action: &A, state_changed: bool) {
         // Call in reverse order for proper nesting
-        for middleware in self.middlewares.iter_mut().rev() {
+        for middleware in self.enabled().rev() {
             middleware.after(action, state_changed);
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[derive(Default)]
-    struct TestState {
-        counter: i32,
+    fn follow_up(&mut self, action: &A, state_changed: bool, action_tx: &mpsc::UnboundedSender<A>) {
+        for middleware in self.enabled().rev() {
+            middleware.follow_up(action, state_changed, action_tx);
+        }
     }
 
-    #[derive(Clone, Debug)]
-    enum TestAction {
-        Increment,
-        Decrement,
-        NoOp,
+    fn before_batch(&mut self, actions: &[A]) {
+        for middleware in self.enabled() {
+            middleware.before_batch(actions);
+        }
     }
 
-    impl Action for TestAction {
-        fn name(&self) -> &'static str {
-            match self {
-                TestAction::Increment => "Increment",
-                TestAction::Decrement => "Decrement",
-                TestAction::NoOp => "NoOp",
-            }
+    fn after_batch(&mut self, actions: &[A], changed: &[bool]) {
+        for middleware in self.enabled().rev() {
+            middleware.after_batch(actions, changed);
         }
     }
+}
 
-    fn test_reducer(state: &mut TestState, action: TestAction) -> bool {
+/// Type name without its module path or generic parameters
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let base = name.split('<').next().unwrap_or(name);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
+/// Build a [`ComposedMiddleware`] from a list of middleware
+///
+/// Middleware run in list order (see [`ComposedMiddleware`]). Prefix an
+/// entry with `"name" =>` to name it; others are named after their type.
+///
+/// ```ignore
+/// let middleware = middleware_pipeline![
+///     debug.metrics_middleware(),
+///     "log" => LoggingMiddleware::new(),
+///     "persist" => SettingsPersister::new(path),
+/// ];
+/// ```
+#[macro_export]
+macro_rules! middleware_pipeline {
+    (@add $pipeline:ident;) => {};
+    (@add $pipeline:ident; $name:literal => $middleware:expr $(, $($rest:tt)*)?) => {
+        $pipeline.add_named($name, $middleware);
+        $crate::middleware_pipeline!(@add $pipeline; $($($rest)*)?);
+    };
+    (@add $pipeline:ident; $middleware:expr $(, $($rest:tt)*)?) => {
+        $pipeline.add($middleware);
+        $crate::middleware_pipeline!(@add $pipeline; $($($rest)*)?);
+    };
+    ($($items:tt)*) => {{
+        let mut pipeline = $crate::store::ComposedMiddleware::new();
+        $crate::middleware_pipeline!(@add pipeline; $($items)*);
+        pipeline
+    }};
+}
+
+/// Route actions to middleware by category
+///
+/// Each action goes to the middleware registered for its
+/// [`category_enum`](ActionCategory::category_enum), in registration order,
+/// or to the fallback middleware when no route matches. Use it to apply a
+/// middleware to part of the app only, e.g. log only `async_result` actions
+/// or persist only `settings` changes:
+///
+/// ```ignore
+/// let mut router = CategoryRouter::new();
+/// router.route(AppActionCategory::AsyncResult, LoggingMiddleware::new());
+/// router.route(AppActionCategory::Settings, SettingsPersister::new(path));
+/// let mut store = StoreWithMiddleware::new(state, reducer, router);
+///
+/// // Routes can be added later as well
+/// store
+///     .middleware_mut()
+///     .route(AppActionCategory::Search, TracingMiddleware::new());
+/// ```
+///
+/// Actions are routed by their category before interception, and every
+/// later hook uses that original category too: a routed middleware that
+/// rewrites an action into another category still sees the replacement in
+/// `before`, `after` and `follow_up`. Batches are routed action by action.
+pub struct CategoryRouter<A: ActionCategory> {
+    routes: Vec<(A::Category, Box<dyn Middleware<A>>)>,
+    fallback: Vec<Box<dyn Middleware<A>>>,
+    /// `(routed by, category after intercept)` for each intercepted action
+    /// not yet claimed by `before`; actions swallowed downstream leave theirs
+    intercepted: Vec<(A::Category, A::Category)>,
+    /// Routing categories of the current dispatch, set by `before`
+    current: Vec<A::Category>,
+    /// Number of `follow_up` calls since `before`
+    follow_ups: usize,
+}
+
+impl<A: ActionCategory> std::fmt::Debug for CategoryRouter<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let categories: Vec<_> = self.routes.iter().map(|(category, _)| category).collect();
+        f.debug_struct("CategoryRouter")
+            .field("routes", &categories)
+            .field("fallback_count", &self.fallback.len())
+            .finish()
+    }
+}
+
+impl<A: ActionCategory> Default for CategoryRouter<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: ActionCategory> CategoryRouter<A> {
+    /// Create a router with no routes
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            fallback: Vec::new(),
+            intercepted: Vec::new(),
+            current: Vec::new(),
+            follow_ups: 0,
+        }
+    }
+
+    /// Send actions in `category` to `middleware`
+    ///
+    /// A category can have several middleware; they run in the order added.
+    pub fn route<M: Middleware<A> + 'static>(&mut self, category: A::Category, middleware: M) {
+        self.routes.push((category, Box::new(middleware)));
+    }
+
+    /// Send actions whose category has no route to `middleware`
+    pub fn fallback<M: Middleware<A> + 'static>(&mut self, middleware: M) {
+        self.fallback.push(Box::new(middleware));
+    }
+
+    /// Whether any middleware is routed for `category`
+    pub fn is_routed(&self, category: A::Category) -> bool {
+        self.routes.iter().any(|(routed, _)| *routed == category)
+    }
+
+    /// Middleware that handle `category`
+    fn handlers(
+        &mut self,
+        category: A::Category,
+    ) -> impl DoubleEndedIterator<Item = &mut Box<dyn Middleware<A>>> + '_ {
+        let routed = self.is_routed(category);
+        let routes = self
+            .routes
+            .iter_mut()
+            .filter(move |(route, _)| routed && *route == category)
+            .map(|(_, middleware)| middleware);
+        let fallback = self.fallback.iter_mut().filter(move |_| !routed);
+        routes.chain(fallback)
+    }
+
+    /// Work out which category each action of the current dispatch was
+    /// routed by, and forget everything intercepted before it
+    ///
+    /// Records are matched from the most recent backwards by the category
+    /// the action left `intercept` with, so entries of actions another
+    /// middleware swallowed are skipped. Actions without a record use their
+    /// own category.
+    fn start_hooks(&mut self, actions: &[A]) {
+        let mut intercepted = std::mem::take(&mut self.intercepted);
+        self.current.clear();
+        for action in actions.iter().rev() {
+            let category = action.category_enum();
+            match intercepted
+                .iter()
+                .rposition(|(_, rewritten)| *rewritten == category)
+            {
+                Some(index) => {
+                    self.current.push(intercepted[index].0);
+                    intercepted.truncate(index);
+                }
+                None => self.current.push(category),
+            }
+        }
+        self.current.reverse();
+        self.follow_ups = 0;
+    }
+
+    /// Category the `index`th action of the current dispatch was routed by
+    fn routed_category(&self, index: usize, action: &A) -> A::Category {
+        self.current
+            .get(index)
+            .copied()
+            .unwrap_or_else(|| action.category_enum())
+    }
+}
+
+impl<A: ActionCategory> Middleware<A> for CategoryRouter<A> {
+    fn intercept(&mut self, action: A) -> Option<A> {
+        let category = action.category_enum();
+        let action = self
+            .handlers(category)
+            .try_fold(action, |action, middleware| middleware.intercept(action))?;
+        self.intercepted.push((category, action.category_enum()));
+        Some(action)
+    }
+
+    fn before(&mut self, action: &A) {
+        self.start_hooks(std::slice::from_ref(action));
+        let category = self.routed_category(0, action);
+        for middleware in self.handlers(category) {
+            middleware.before(action);
+        }
+    }
+
+    fn after(&mut self, action: &A, state_changed: bool) {
+        let category = self.routed_category(0, action);
+        // Reverse order for proper nesting, like ComposedMiddleware
+        for middleware in self.handlers(category).rev() {
+            middleware.after(action, state_changed);
+        }
+    }
+
+    fn follow_up(&mut self, action: &A, state_changed: bool, action_tx: &mpsc::UnboundedSender<A>) {
+        let category = self.routed_category(self.follow_ups, action);
+        self.follow_ups += 1;
+        for middleware in self.handlers(category).rev() {
+            middleware.follow_up(action, state_changed, action_tx);
+        }
+    }
+
+    fn before_batch(&mut self, actions: &[A]) {
+        self.start_hooks(actions);
+        for (index, action) in actions.iter().enumerate() {
+            let category = self.routed_category(index, action);
+            for middleware in self.handlers(category) {
+                middleware.before(action);
+            }
+        }
+    }
+
+    fn after_batch(&mut self, actions: &[A], changed: &[bool]) {
+        for (index, (action, &state_changed)) in actions.iter().zip(changed).enumerate() {
+            let category = self.routed_category(index, action);
+            for middleware in self.handlers(category).rev() {
+                middleware.after(action, state_changed);
+            }
+        }
+    }
+}
+
+/// Compose slice reducers into one reducer
+///
+/// Splits a large state into fields ("slices"), each with its own reducer
+/// that sees only its slice and every action. The generated function runs
+/// all slice reducers in order (none are skipped) and combines their results.
+///
+/// Three forms are supported, chosen by the return type:
+///
+/// - no return type: a [`Reducer`] returning `true` if any slice changed
+/// - `-> SliceChanges`: per-slice changed flags ([`SliceChanges`])
+/// - `-> DispatchResult<E>`: an [`EffectReducer`](crate::EffectReducer);
+///   slice reducers return `DispatchResult<E>`, their effects are
+///   concatenated in order and touched components combine
+///   ([`DispatchResult::merge`](crate::DispatchResult::merge))
+///
+/// # Example
+///
+/// ```ignore
+/// use tui_dispatch::{combine_reducers, SliceChanges, Store};
+///
+/// struct AppState {
+///     search: SearchState,
+///     ui: UiState,
+/// }
+///
+/// fn search_reducer(state: &mut SearchState, action: Action) -> bool { /* ... */ }
+/// fn ui_reducer(state: &mut UiState, action: Action) -> bool { /* ... */ }
+///
+/// combine_reducers! {
+///     /// Root reducer
+///     pub fn reducer(AppState, Action) {
+///         search: search_reducer,
+///         ui: ui_reducer,
+///     }
+/// }
+///
+/// combine_reducers! {
+///     fn reducer_slices(AppState, Action) -> SliceChanges {
+///         search: search_reducer,
+///         ui: ui_reducer,
+///     }
+/// }
+///
+/// let mut store = Store::new(state, reducer);
+/// ```
+#[macro_export]
+macro_rules! combine_reducers {
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($state:ty, $action:ty) -> SliceChanges {
+            $($field:ident : $reducer:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis fn $name(state: &mut $state, action: $action) -> $crate::store::SliceChanges {
+            let mut changes = $crate::store::SliceChanges::new();
+            $(
+                let changed: bool = ($reducer)(
+                    &mut state.$field,
+                    ::core::clone::Clone::clone(&action),
+                );
+                changes.record(::core::stringify!($field), changed);
+            )+
+            changes
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($state:ty, $action:ty) -> DispatchResult<$effect:ty> {
+            $($field:ident : $reducer:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis fn $name(
+            state: &mut $state,
+            action: $action,
+        ) -> $crate::effect::DispatchResult<$effect> {
+            let mut result = $crate::effect::DispatchResult::unchanged();
+            $(
+                let slice: $crate::effect::DispatchResult<$effect> = ($reducer)(
+                    &mut state.$field,
+                    ::core::clone::Clone::clone(&action),
+                );
+                result.merge(slice);
+            )+
+            result
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($state:ty, $action:ty) {
+            $($field:ident : $reducer:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis fn $name(state: &mut $state, action: $action) -> bool {
+            let mut changed = false;
+            $(
+                let slice_changed: bool = ($reducer)(
+                    &mut state.$field,
+                    ::core::clone::Clone::clone(&action),
+                );
+                changed |= slice_changed;
+            )+
+            changed
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct TestState {
+        counter: i32,
+    }
+
+    #[derive(Clone, Debug)]
+    enum TestAction {
+        Increment,
+        Decrement,
+        NoOp,
+    }
+
+    impl Action for TestAction {
+        fn name(&self) -> &'static str {
+            match self {
+                TestAction::Increment => "Increment",
+                TestAction::Decrement => "Decrement",
+                TestAction::NoOp => "NoOp",
+            }
+        }
+    }
+
+    fn test_reducer(state: &mut TestState, action: TestAction) -> bool {
         match action {
             TestAction::Increment => {
                 state.counter += 1;
@@ -287,6 +1335,38 @@ mod tests {
         }
     }
 
+    struct StepEnv {
+        step: i32,
+    }
+
+    fn env_reducer(state: &mut TestState, action: TestAction, env: &StepEnv) -> bool {
+        match action {
+            TestAction::Increment => {
+                state.counter += env.step;
+                true
+            }
+            TestAction::Decrement => {
+                state.counter -= env.step;
+                true
+            }
+            TestAction::NoOp => false,
+        }
+    }
+
+    #[test]
+    fn test_store_with_env() {
+        let mut store = Store::new_with_env(TestState::default(), StepEnv { step: 5 }, env_reducer);
+
+        assert!(store.dispatch(TestAction::Increment));
+        assert_eq!(store.state().counter, 5);
+
+        store.env_mut().step = 2;
+        assert!(store.dispatch_all([TestAction::Decrement, TestAction::NoOp]));
+        assert_eq!(store.state().counter, 3);
+        assert_eq!(store.env().step, 2);
+        assert_eq!(store.version(), 2);
+    }
+
     #[test]
     fn test_store_dispatch() {
         let mut store = Store::new(TestState::default(), test_reducer);
@@ -321,6 +1401,7 @@ mod tests {
     struct CountingMiddleware {
         before_count: usize,
         after_count: usize,
+        changed: Vec<bool>,
     }
 
     impl<A: Action> Middleware<A> for CountingMiddleware {
@@ -328,9 +1409,42 @@ mod tests {
             self.before_count += 1;
         }
 
-        fn after(&mut self, _action: &A, _state_changed: bool) {
+        fn after(&mut self, _action: &A, state_changed: bool) {
             self.after_count += 1;
+            self.changed.push(state_changed);
+        }
+    }
+
+    #[test]
+    fn test_store_snapshot_roundtrip() {
+        #[derive(Default, serde::Serialize, serde::Deserialize)]
+        struct Counter {
+            count: i32,
+            label: String,
         }
+
+        fn reducer(state: &mut Counter, action: TestAction) -> bool {
+            if let TestAction::Increment = action {
+                state.count += 1;
+            }
+            true
+        }
+
+        let mut store = Store::new(Counter::default(), reducer);
+        store.dispatch(TestAction::Increment);
+        store.state_mut().label = "bug".into();
+        let json = store.export_snapshot().unwrap();
+        assert!(json.contains("\"count\": 1"));
+
+        let mut restored = Store::new(Counter::default(), reducer);
+        let version = restored.version();
+        restored.import_snapshot(&json).unwrap();
+        assert_eq!(restored.state().count, 1);
+        assert_eq!(restored.state().label, "bug");
+        assert!(restored.version() > version);
+
+        assert!(restored.import_snapshot("{ not json").is_err());
+        assert_eq!(restored.state().count, 1);
     }
 
     #[test]
@@ -348,4 +1462,569 @@ mod tests {
         assert_eq!(store.middleware().after_count, 2);
         assert_eq!(store.state().counter, 2);
     }
+
+    #[test]
+    fn test_store_dispatch_all() {
+        let mut store = Store::new(TestState::default(), test_reducer);
+
+        assert!(!store.dispatch_all([TestAction::NoOp, TestAction::NoOp]));
+        assert_eq!(store.version(), 0);
+
+        let changed = store.dispatch_all(vec![
+            TestAction::Increment,
+            TestAction::Increment,
+            TestAction::NoOp,
+        ]);
+        assert!(changed);
+        assert_eq!(store.state().counter, 2);
+        assert_eq!(store.version(), 1);
+    }
+
+    #[test]
+    fn test_store_subscribe() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut store = Store::new(TestState::default(), test_reducer);
+
+        let recorder = seen.clone();
+        let id = store.subscribe(move |action: &TestAction, state: &TestState, changed| {
+            let entry = (action.name(), state.counter, changed);
+            recorder.lock().unwrap().push(entry);
+        });
+        assert_eq!(store.listener_count(), 1);
+
+        store.dispatch(TestAction::Increment);
+        store.dispatch(TestAction::NoOp);
+        store.dispatch_all([TestAction::Increment, TestAction::Decrement]);
+
+        assert!(store.unsubscribe(id));
+        assert!(!store.unsubscribe(id));
+        store.dispatch(TestAction::Increment);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                ("Increment", 1, true),
+                ("NoOp", 1, false),
+                ("Increment", 2, true),
+                ("Decrement", 1, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_listeners_skip_swallowed_actions() {
+        let count = Arc::new(Mutex::new(0));
+        let mut store = StoreWithMiddleware::new(
+            TestState::default(),
+            test_reducer,
+            RewritingMiddleware::default(),
+        );
+
+        let counter = count.clone();
+        store.subscribe(move |action: &TestAction, _state: &TestState, _changed| {
+            // Listeners see the rewritten action
+            assert!(matches!(action, TestAction::Increment));
+            *counter.lock().unwrap() += 1;
+        });
+
+        store.dispatch(TestAction::NoOp);
+        store.dispatch(TestAction::Decrement);
+
+        assert_eq!(*count.lock().unwrap(), 1);
+    }
+
+    #[derive(Default)]
+    struct BatchMiddleware {
+        batches: Vec<(usize, bool)>,
+        after_count: usize,
+    }
+
+    impl<A: Action> Middleware<A> for BatchMiddleware {
+        fn before(&mut self, _action: &A) {}
+
+        fn after(&mut self, _action: &A, _state_changed: bool) {
+            self.after_count += 1;
+        }
+
+        fn after_batch(&mut self, actions: &[A], changed: &[bool]) {
+            self.batches.push((actions.len(), changed.contains(&true)));
+        }
+    }
+
+    #[test]
+    fn test_middleware_notified_once_per_batch() {
+        let mut store = StoreWithMiddleware::new(
+            TestState::default(),
+            test_reducer,
+            BatchMiddleware::default(),
+        );
+
+        assert!(store.dispatch_all([TestAction::Increment, TestAction::Decrement]));
+        assert!(!store.dispatch_all([]));
+        assert!(!store.dispatch_all([TestAction::NoOp]));
+
+        assert_eq!(store.middleware().batches, vec![(2, true), (1, false)]);
+        assert_eq!(store.middleware().after_count, 0);
+        assert_eq!(store.state().counter, 0);
+    }
+
+    #[test]
+    fn test_default_batch_hooks_forward_per_action() {
+        let mut store = StoreWithMiddleware::new(
+            TestState::default(),
+            test_reducer,
+            CountingMiddleware::default(),
+        );
+
+        store.dispatch_all([
+            TestAction::Increment,
+            TestAction::NoOp,
+            TestAction::Increment,
+        ]);
+
+        assert_eq!(store.middleware().before_count, 3);
+        assert_eq!(store.middleware().after_count, 3);
+        // Each action gets its own changed flag, not the batch result
+        assert_eq!(store.middleware().changed, vec![true, false, true]);
+    }
+
+    /// Drops `NoOp`, turns `Decrement` into `Increment`, and asks for a
+    /// `Decrement` after every change.
+    #[derive(Default)]
+    struct RewritingMiddleware {
+        after: Vec<bool>,
+    }
+
+    impl Middleware<TestAction> for RewritingMiddleware {
+        fn intercept(&mut self, action: TestAction) -> Option<TestAction> {
+            match action {
+                TestAction::NoOp => None,
+                TestAction::Decrement => Some(TestAction::Increment),
+                action => Some(action),
+            }
+        }
+
+        fn before(&mut self, _action: &TestAction) {}
+
+        fn after(&mut self, _action: &TestAction, state_changed: bool) {
+            self.after.push(state_changed);
+        }
+
+        fn follow_up(
+            &mut self,
+            _action: &TestAction,
+            state_changed: bool,
+            action_tx: &mpsc::UnboundedSender<TestAction>,
+        ) {
+            if state_changed {
+                let _ = action_tx.send(TestAction::Decrement);
+            }
+        }
+    }
+
+    impl ActionCategory for TestAction {
+        type Category = &'static str;
+
+        fn category(&self) -> Option<&'static str> {
+            match self {
+                TestAction::Increment | TestAction::Decrement => Some("counter"),
+                TestAction::NoOp => None,
+            }
+        }
+
+        fn category_enum(&self) -> Self::Category {
+            self.category().unwrap_or("uncategorized")
+        }
+    }
+
+    /// Records every hook call as "<label> <hook> <action>"
+    struct LabelMiddleware {
+        label: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware<TestAction> for LabelMiddleware {
+        fn before(&mut self, action: &TestAction) {
+            let call = format!("{} before {}", self.label, action.name());
+            self.calls.lock().unwrap().push(call);
+        }
+
+        fn after(&mut self, action: &TestAction, _state_changed: bool) {
+            let call = format!("{} after {}", self.label, action.name());
+            self.calls.lock().unwrap().push(call);
+        }
+    }
+
+    #[test]
+    fn test_category_router_routes_by_category() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let label = |label| LabelMiddleware {
+            label,
+            calls: calls.clone(),
+        };
+
+        let mut router = CategoryRouter::new();
+        router.route("counter", label("a"));
+        router.fallback(label("fallback"));
+        let mut store = StoreWithMiddleware::new(TestState::default(), test_reducer, router);
+        store.middleware_mut().route("counter", label("b"));
+        assert!(store.middleware().is_routed("counter"));
+        assert!(!store.middleware().is_routed("uncategorized"));
+
+        store.dispatch(TestAction::Increment);
+        store.dispatch(TestAction::NoOp);
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "a before Increment",
+                "b before Increment",
+                "b after Increment",
+                "a after Increment",
+                "fallback before NoOp",
+                "fallback after NoOp",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_category_router_intercept() {
+        let mut router = CategoryRouter::new();
+        router.route("counter", RewritingMiddleware::default());
+        let mut store = StoreWithMiddleware::new(TestState::default(), test_reducer, router);
+
+        // Decrement is rewritten, NoOp has no route and passes through
+        assert!(store.dispatch(TestAction::Decrement));
+        assert!(!store.dispatch(TestAction::NoOp));
+        assert_eq!(store.state().counter, 1);
+    }
+
+    #[test]
+    fn test_category_router_keeps_original_category() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let label = |label| LabelMiddleware {
+            label,
+            calls: calls.clone(),
+        };
+
+        /// Rewrites the uncategorized NoOp into a counter action
+        struct PromoteNoOp;
+
+        impl Middleware<TestAction> for PromoteNoOp {
+            fn intercept(&mut self, action: TestAction) -> Option<TestAction> {
+                match action {
+                    TestAction::NoOp => Some(TestAction::Increment),
+                    action => Some(action),
+                }
+            }
+
+            fn before(&mut self, _action: &TestAction) {}
+
+            fn after(&mut self, _action: &TestAction, _state_changed: bool) {}
+        }
+
+        let mut router = CategoryRouter::new();
+        router.route("counter", label("counter"));
+        router.fallback(PromoteNoOp);
+        router.fallback(label("fallback"));
+        let mut store = StoreWithMiddleware::new(TestState::default(), test_reducer, router);
+
+        assert!(store.dispatch(TestAction::NoOp));
+        assert!(store.dispatch_all([TestAction::NoOp, TestAction::Increment]));
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "fallback before Increment",
+                "fallback after Increment",
+                "fallback before Increment",
+                "counter before Increment",
+                "fallback after Increment",
+                "counter after Increment",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_category_router_ignores_actions_swallowed_downstream() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let label = |label| LabelMiddleware {
+            label,
+            calls: calls.clone(),
+        };
+
+        let mut router = CategoryRouter::new();
+        router.route("counter", label("counter"));
+        router.fallback(label("fallback"));
+        // Drops NoOp after the router let it through
+        let mut pipeline = ComposedMiddleware::new();
+        pipeline.add(router);
+        pipeline.add(RewritingMiddleware::default());
+        let mut store = StoreWithMiddleware::new(TestState::default(), test_reducer, pipeline);
+
+        assert!(!store.dispatch(TestAction::NoOp));
+        assert!(store.dispatch(TestAction::Increment));
+        assert!(store.dispatch_all([TestAction::NoOp, TestAction::Increment]));
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "counter before Increment",
+                "counter after Increment",
+                "counter before Increment",
+                "counter after Increment",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_middleware_intercept() {
+        let mut store = StoreWithMiddleware::new(
+            TestState::default(),
+            test_reducer,
+            RewritingMiddleware::default(),
+        );
+
+        assert!(!store.dispatch(TestAction::NoOp));
+        assert!(store.dispatch(TestAction::Decrement));
+        assert!(store.dispatch_all([TestAction::NoOp, TestAction::Decrement]));
+
+        assert_eq!(store.state().counter, 2);
+        // Swallowed actions never reach `after`
+        assert_eq!(store.middleware().after, vec![true, true]);
+    }
+
+    #[test]
+    fn test_middleware_follow_up() {
+        let mut store = StoreWithMiddleware::new(
+            TestState::default(),
+            test_reducer,
+            RewritingMiddleware::default(),
+        );
+
+        // No sender yet: follow-ups are skipped
+        store.dispatch(TestAction::Increment);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        store.set_action_sender(tx);
+        store.dispatch(TestAction::Increment);
+        store.dispatch(TestAction::NoOp);
+
+        assert!(matches!(rx.try_recv(), Ok(TestAction::Decrement)));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_middleware_pipeline_order_and_toggle() {
+        let mut composed: ComposedMiddleware<TestAction> = crate::middleware_pipeline![
+            CountingMiddleware::default(),
+            "rewrite" => RewritingMiddleware::default(),
+        ];
+
+        let names: Vec<_> = composed.iter().map(|info| info.name).collect();
+        assert_eq!(names, ["CountingMiddleware", "rewrite"]);
+        assert!(composed.intercept(TestAction::NoOp).is_none());
+
+        // A disabled middleware is skipped
+        assert!(composed.set_enabled("rewrite", false));
+        assert_eq!(composed.is_enabled("rewrite"), Some(false));
+        assert!(matches!(
+            composed.intercept(TestAction::NoOp),
+            Some(TestAction::NoOp)
+        ));
+        assert!(!composed.set_enabled("missing", false));
+        assert_eq!(composed.len(), 2);
+    }
+
+    #[test]
+    fn test_composed_intercept_chains() {
+        let mut composed = ComposedMiddleware::new();
+        composed.add(RewritingMiddleware::default());
+        composed.add(CountingMiddleware::default());
+
+        assert!(matches!(
+            composed.intercept(TestAction::Decrement),
+            Some(TestAction::Increment)
+        ));
+        assert!(composed.intercept(TestAction::NoOp).is_none());
+    }
+
+    #[derive(Default)]
+    struct AppState {
+        left: TestState,
+        right: TestState,
+    }
+
+    fn right_reducer(state: &mut TestState, action: TestAction) -> bool {
+        match action {
+            TestAction::Decrement => {
+                state.counter -= 10;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    crate::combine_reducers! {
+        fn app_reducer(AppState, TestAction) {
+            left: test_reducer,
+            right: right_reducer,
+        }
+    }
+
+    crate::combine_reducers! {
+        fn app_slices(AppState, TestAction) -> SliceChanges {
+            left: test_reducer,
+            right: right_reducer,
+        }
+    }
+
+    #[test]
+    fn test_combine_reducers() {
+        let mut store = Store::new(AppState::default(), app_reducer);
+
+        assert!(store.dispatch(TestAction::Increment));
+        assert!(store.dispatch(TestAction::Decrement));
+        assert!(!store.dispatch(TestAction::NoOp));
+
+        assert_eq!(store.state().left.counter, 0);
+        assert_eq!(store.state().right.counter, -10);
+    }
+
+    #[test]
+    fn test_combine_reducers_slice_changes() {
+        let mut state = AppState::default();
+
+        let changes = app_slices(&mut state, TestAction::Increment);
+        assert_eq!(changes.slices(), ["left"]);
+        assert!(changes.contains("left"));
+        assert!(!changes.contains("right"));
+
+        let changes = app_slices(&mut state, TestAction::Decrement);
+        assert_eq!(changes.slices(), ["left", "right"]);
+
+        let changes = app_slices(&mut state, TestAction::NoOp);
+        assert!(!changes.any());
+        assert!(!bool::from(changes));
+    }
+
+    #[cfg(feature = "tracing-middleware")]
+    #[test]
+    fn test_tracing_middleware_spans() {
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        impl crate::ActionParams for TestAction {
+            fn params(&self) -> String {
+                match self {
+                    TestAction::Increment => "by 1".to_string(),
+                    _ => String::new(),
+                }
+            }
+        }
+
+        #[derive(Default)]
+        struct Fields(Vec<String>);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push(format!("{}={:?}", field.name(), value));
+            }
+        }
+
+        /// Records spans, field updates and events as lines
+        #[derive(Clone, Default)]
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        impl Recorder {
+            fn push(&self, line: String) {
+                self.0.lock().unwrap().push(line);
+            }
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut fields = Fields::default();
+                span.record(&mut fields);
+                self.push(format!("{} {}", span.metadata().name(), fields.0.join(" ")));
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &Id, values: &Record<'_>) {
+                let mut fields = Fields::default();
+                values.record(&mut fields);
+                self.push(fields.0.join(" "));
+            }
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = Fields::default();
+                event.record(&mut fields);
+                self.push(fields.0.join(" "));
+            }
+
+            fn enter(&self, _span: &Id) {
+                self.push("enter".into());
+            }
+
+            fn exit(&self, _span: &Id) {
+                self.push("exit".into());
+            }
+        }
+
+        fn assert_send<T: Send>() {}
+        assert_send::<TracingMiddleware<TestAction>>();
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut store = StoreWithMiddleware::new(
+                TestState::default(),
+                test_reducer,
+                TracingMiddleware::new(),
+            );
+            store.dispatch(TestAction::Increment);
+            store.dispatch_all([TestAction::Increment, TestAction::NoOp]);
+        });
+
+        let lines = recorder.0.lock().unwrap().clone();
+        assert_eq!(lines[0], "dispatch action=\"Increment\"");
+        assert_eq!(lines[1], "params=\"by 1\"");
+        assert_eq!(lines[2], "enter");
+        assert!(lines[3].starts_with("elapsed_us="));
+        assert_eq!(lines[4], "state_changed=true");
+        assert!(lines[5].contains("message=dispatched"));
+        assert_eq!(lines[6], "exit");
+        assert_eq!(lines[7], "dispatch_batch count=2");
+        assert_eq!(lines[8], "actions=\"Increment, NoOp\"");
+        assert_eq!(lines[9], "enter");
+        assert_eq!(lines.last().map(String::as_str), Some("exit"));
+
+        // Disabled between `before` and `after`: the next dispatch exits the
+        // stale span before entering its own
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut pipeline = ComposedMiddleware::new();
+            pipeline.add_named("tracing", TracingMiddleware::new());
+            pipeline.before(&TestAction::Increment);
+            pipeline.set_enabled("tracing", false);
+            pipeline.after(&TestAction::Increment, true);
+            pipeline.set_enabled("tracing", true);
+            pipeline.before(&TestAction::Increment);
+            pipeline.after(&TestAction::Increment, true);
+        });
+
+        let lines = recorder.0.lock().unwrap().clone();
+        let spans: Vec<&str> = lines
+            .iter()
+            .map(String::as_str)
+            .filter(|line| matches!(*line, "enter" | "exit"))
+            .collect();
+        assert_eq!(spans, ["enter", "exit", "enter", "exit"]);
+    }
 }
@@ -0,0 +1,105 @@
+//! Translatable labels drawn by components and the debug layer
+//!
+//! Built-in labels come from a [`Strings`] table carried by the
+//! [`Theme`](crate::Theme), so an app translates them where it picks its
+//! colors. Override the labels you need and keep English for the rest:
+//!
+//! ```ignore
+//! const GERMAN: Strings = Strings {
+//!     help_title: "Tastenbelegung",
+//!     help_search: "Tasten suchen...",
+//!     page: "Seite",
+//!     item: "Eintrag",
+//!     items: "Einträge",
+//!     ..Strings::ENGLISH
+//! };
+//!
+//! let theme = Theme { strings: GERMAN, ..Theme::DARK };
+//! let debug = DebugLayer::simple().with_style(DebugStyle::from_theme(&theme));
+//! ```
+//!
+//! Labels are `&'static str` so tables can be constants; leak strings
+//! loaded at runtime (`Box::leak`) to build one from a translation file.
+
+/// Labels for each built-in string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Strings {
+    /// Debug banner title
+    pub debug_title: &'static str,
+    /// Debug banner hint for leaving debug mode
+    pub debug_resume: &'static str,
+    /// Debug banner hint for the action log
+    pub debug_actions: &'static str,
+    /// Debug banner hint for the state overlay
+    pub debug_state: &'static str,
+    /// Debug banner hint for the metrics overlay
+    pub debug_metrics: &'static str,
+    /// Debug banner hint for moving the banner while it is at the top
+    pub debug_banner_top: &'static str,
+    /// Debug banner hint for moving the banner while it is at the bottom
+    pub debug_banner_bottom: &'static str,
+    /// Debug banner hint for copying the frame
+    pub debug_copy: &'static str,
+    /// Debug banner hint for copying the selected state value
+    pub debug_copy_value: &'static str,
+    /// Debug banner hint for exporting the frame
+    pub debug_export: &'static str,
+    /// Debug banner hint for saving a state snapshot
+    pub debug_save_state: &'static str,
+    /// Debug banner hint for inspecting a cell with the mouse
+    pub debug_inspect: &'static str,
+    /// Debug banner hint for enabling mouse capture
+    pub debug_mouse: &'static str,
+    /// Debug banner label for the action behind the last frame
+    pub debug_render_cause: &'static str,
+    /// `HelpOverlay` title
+    pub help_title: &'static str,
+    /// `HelpOverlay` search placeholder
+    pub help_search: &'static str,
+    /// `HelpOverlay` text when no binding matches the search
+    pub help_no_matches: &'static str,
+    /// `HelpOverlay` section of global bindings
+    pub help_global: &'static str,
+    /// `InputPrompt` hint below the input
+    pub prompt_hint: &'static str,
+    /// `Paginator` word before the page numbers
+    pub page: &'static str,
+    /// `Paginator` item count noun for one item
+    pub item: &'static str,
+    /// `Paginator` item count noun for other counts
+    pub items: &'static str,
+}
+
+impl Strings {
+    /// English labels, the built-in defaults
+    pub const ENGLISH: Strings = Strings {
+        debug_title: "DEBUG",
+        debug_resume: "resume",
+        debug_actions: "actions",
+        debug_state: "state",
+        debug_metrics: "metrics",
+        debug_banner_top: "bar:top",
+        debug_banner_bottom: "bar:bottom",
+        debug_copy: "copy",
+        debug_copy_value: "copy value",
+        debug_export: "export",
+        debug_save_state: "save state",
+        debug_inspect: "inspect",
+        debug_mouse: "mouse",
+        debug_render_cause: "last render",
+        help_title: "Keybindings",
+        help_search: "Search keybindings...",
+        help_no_matches: "No matching keybindings",
+        help_global: "global",
+        prompt_hint: "Enter to confirm · Esc to cancel",
+        page: "Page",
+        item: "item",
+        items: "items",
+    };
+}
+
+impl Default for Strings {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
@@ -7,7 +7,7 @@
 //!
 //! ```ignore
 //! use tui_dispatch::subscriptions::Subscriptions;
-//! use std::time::Duration;
+//! use std::time::{Duration, SystemTime};
 //!
 //! let (action_tx, mut action_rx) = tokio::sync::mpsc::unbounded_channel();
 //! let mut subs = Subscriptions::new(action_tx);
@@ -21,6 +21,15 @@
 //! // Stream from external source
 //! subs.stream("events", backend.event_stream());
 //!
+//! // Forward messages from a tokio channel
+//! subs.channel("jobs", job_rx, Action::JobUpdate);
+//!
+//! // Clear a status message after 3 seconds
+//! subs.delay("status", Duration::from_secs(3), Action::StatusClear);
+//!
+//! // Daily report at 18:00 UTC
+//! subs.schedule("report", "0 18 * * *", || Action::ReportDue)?;
+//!
 //! // Cancel all on shutdown
 //! subs.cancel_all();
 //! ```
@@ -31,12 +40,16 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc, watch};
 use tokio::task::JoinHandle;
 use tokio_stream::{Stream, StreamExt};
 
+use crate::cron::{CronParseError, CronSchedule};
 use crate::Action;
 
+/// How often a due delayed action re-checks the pause flag.
+const PAUSED_RETRY: Duration = Duration::from_millis(50);
+
 /// Identifies a subscription for cancellation.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct SubKey(String);
@@ -94,6 +107,60 @@ impl SubPauseHandle {
     }
 }
 
+/// A channel receiver that [`Subscriptions::channel`] can forward from.
+///
+/// Implemented for tokio's `mpsc` (bounded and unbounded), `watch` and
+/// `broadcast` receivers.
+pub trait ChannelSource: Send + 'static {
+    /// Item type carried by the channel.
+    type Item: Send + 'static;
+
+    /// Wait for the next item, or `None` once all senders are gone.
+    fn next_item(&mut self) -> impl Future<Output = Option<Self::Item>> + Send;
+}
+
+impl<T: Send + 'static> ChannelSource for mpsc::Receiver<T> {
+    type Item = T;
+
+    async fn next_item(&mut self) -> Option<T> {
+        self.recv().await
+    }
+}
+
+impl<T: Send + 'static> ChannelSource for mpsc::UnboundedReceiver<T> {
+    type Item = T;
+
+    async fn next_item(&mut self) -> Option<T> {
+        self.recv().await
+    }
+}
+
+/// Yields each new value; call `mark_changed()` on the receiver first to
+/// also get the current one.
+impl<T: Clone + Send + Sync + 'static> ChannelSource for watch::Receiver<T> {
+    type Item = T;
+
+    async fn next_item(&mut self) -> Option<T> {
+        self.changed().await.ok()?;
+        Some(self.borrow_and_update().clone())
+    }
+}
+
+/// Skips messages lost when the receiver lags behind.
+impl<T: Clone + Send + 'static> ChannelSource for broadcast::Receiver<T> {
+    type Item = T;
+
+    async fn next_item(&mut self) -> Option<T> {
+        loop {
+            match self.recv().await {
+                Ok(item) => return Some(item),
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
 /// Manages declarative subscriptions that continuously emit actions.
 ///
 /// Subscriptions are long-lived sources of actions, unlike one-shot tasks.
@@ -343,6 +410,156 @@ where
         self
     }
 
+    /// Add a subscription that forwards items from a tokio channel as actions.
+    ///
+    /// Accepts `mpsc`, `watch` and `broadcast` receivers (see
+    /// [`ChannelSource`]); `map` turns each item into an action. The
+    /// subscription ends when all senders are dropped. Like streams, items
+    /// arriving while paused are dropped. If a subscription with the same
+    /// key exists, it is cancelled first.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Progress reports from a worker thread
+    /// subs.channel("worker", progress_rx, Action::WorkerProgress);
+    ///
+    /// // Config reloads published through a watch channel
+    /// subs.channel("config", config_rx, |config| Action::ConfigChanged(config));
+    /// ```
+    pub fn channel<R, F>(
+        &mut self,
+        key: impl Into<SubKey>,
+        mut receiver: R,
+        mut map: F,
+    ) -> &mut Self
+    where
+        R: ChannelSource,
+        F: FnMut(R::Item) -> A + Send + 'static,
+    {
+        let key = key.into();
+
+        // Cancel existing subscription with this key
+        self.cancel(&key);
+
+        let tx = self.action_tx.clone();
+        let paused = self.paused.clone();
+        let handle = tokio::spawn(async move {
+            while let Some(item) = receiver.next_item().await {
+                // Skip if paused
+                if paused.load(Ordering::SeqCst) {
+                    continue;
+                }
+                if tx.send(map(item)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        self.handles.insert(key, handle);
+        self
+    }
+
+    /// Dispatch a single action after a delay.
+    ///
+    /// Unlike ticks, a delayed action is never dropped while subscriptions
+    /// are paused; it is held until they resume. If a subscription with the
+    /// same key exists, it is cancelled first, so re-arming a delay (e.g. for
+    /// a status message that was replaced) restarts the countdown. Cancel it
+    /// with [`cancel`](Self::cancel) before it fires.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Auto-dismiss a status message
+    /// subs.delay("status", Duration::from_secs(3), Action::StatusClear);
+    /// ```
+    pub fn delay(&mut self, key: impl Into<SubKey>, duration: Duration, action: A) -> &mut Self {
+        let key = key.into();
+
+        // Cancel existing subscription with this key
+        self.cancel(&key);
+
+        let tx = self.action_tx.clone();
+        let paused = self.paused.clone();
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(duration).await;
+            // Hold the action until resumed
+            while paused.load(Ordering::SeqCst) {
+                tokio::time::sleep(PAUSED_RETRY).await;
+            }
+            let _ = tx.send(action);
+        });
+
+        self.handles.insert(key, handle);
+        self
+    }
+
+    /// Add a cron-style subscription that emits at matching times of day.
+    ///
+    /// `cron_expr` uses the five-field cron syntax described in
+    /// [`crate::cron`], evaluated in UTC. For a fixed local offset, build a
+    /// [`CronSchedule`] and use [`schedule_with`](Self::schedule_with).
+    /// If a subscription with the same key exists, it is cancelled first.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Every weekday at 09:00
+    /// subs.schedule("standup", "0 9 * * 1-5", || Action::StandupReminder)?;
+    /// ```
+    pub fn schedule<F>(
+        &mut self,
+        key: impl Into<SubKey>,
+        cron_expr: &str,
+        action_fn: F,
+    ) -> Result<&mut Self, CronParseError>
+    where
+        F: Fn() -> A + Send + 'static,
+    {
+        let schedule = CronSchedule::parse(cron_expr)?;
+        Ok(self.schedule_with(key, schedule, action_fn))
+    }
+
+    /// Add a subscription that emits at each time matched by `schedule`.
+    ///
+    /// Like intervals, occurrences that fall while paused are skipped. The
+    /// subscription ends if the schedule never matches again.
+    pub fn schedule_with<F>(
+        &mut self,
+        key: impl Into<SubKey>,
+        schedule: CronSchedule,
+        action_fn: F,
+    ) -> &mut Self
+    where
+        F: Fn() -> A + Send + 'static,
+    {
+        let key = key.into();
+
+        // Cancel existing subscription with this key
+        self.cancel(&key);
+
+        let tx = self.action_tx.clone();
+        let paused = self.paused.clone();
+        let handle = tokio::spawn(async move {
+            while let Some(next) = schedule.next_after(SystemTime::now()) {
+                let wait = next.duration_since(SystemTime::now()).unwrap_or_default();
+                tokio::time::sleep(wait).await;
+                // Skip if paused
+                if paused.load(Ordering::SeqCst) {
+                    continue;
+                }
+                if tx.send(action_fn()).is_err() {
+                    // Channel closed, stop the subscription
+                    break;
+                }
+            }
+        });
+
+        self.handles.insert(key, handle);
+        self
+    }
+
     /// Cancel a subscription by key.
     ///
     /// If no subscription exists with the given key, this is a no-op.
@@ -362,23 +579,30 @@ where
     }
 
     /// Check if a subscription with the given key is active.
+    ///
+    /// Finished subscriptions (fired delays, ended streams) are not active.
     pub fn is_active(&self, key: &SubKey) -> bool {
-        self.handles.contains_key(key)
+        self.handles
+            .get(key)
+            .is_some_and(|handle| !handle.is_finished())
     }
 
     /// Get the number of active subscriptions.
     pub fn len(&self) -> usize {
-        self.handles.len()
+        self.active_keys().count()
     }
 
     /// Check if there are no active subscriptions.
     pub fn is_empty(&self) -> bool {
-        self.handles.is_empty()
+        self.len() == 0
     }
 
     /// Get the keys of all active subscriptions.
     pub fn active_keys(&self) -> impl Iterator<Item = &SubKey> {
-        self.handles.keys()
+        self.handles
+            .iter()
+            .filter(|(_, handle)| !handle.is_finished())
+            .map(|(key, _)| key)
     }
 }
 
@@ -491,6 +715,63 @@ mod tests {
         assert_eq!(values, vec![1, 2, 3]);
     }
 
+    #[tokio::test]
+    async fn test_channel_forwards_mpsc_and_broadcast() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut subs = Subscriptions::new(tx);
+
+        let (job_tx, job_rx) = mpsc::channel(4);
+        subs.channel("jobs", job_rx, TestAction::Value);
+        job_tx.send(1).await.unwrap();
+        job_tx.send(2).await.unwrap();
+
+        // Lagged broadcast messages are skipped, not fatal
+        let (news_tx, news_rx) = broadcast::channel(1);
+        news_tx.send(10).unwrap();
+        news_tx.send(20).unwrap();
+        subs.channel("news", news_rx, |n: usize| TestAction::Value(n + 1));
+
+        let mut values = vec![];
+        for _ in 0..3 {
+            let action = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+                .await
+                .expect("timeout")
+                .expect("channel closed");
+            if let TestAction::Value(v) = action {
+                values.push(v);
+            }
+        }
+        values.sort();
+        assert_eq!(values, vec![1, 2, 21]);
+
+        // Dropping the senders ends the subscriptions
+        drop(job_tx);
+        drop(news_tx);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(subs.handles.values().all(|handle| handle.is_finished()));
+    }
+
+    #[tokio::test]
+    async fn test_channel_forwards_watch_changes() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut subs = Subscriptions::new(tx);
+
+        let (config_tx, mut config_rx) = watch::channel(1);
+        config_rx.mark_changed();
+        subs.channel("config", config_rx, TestAction::Value);
+
+        let first = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .expect("timeout");
+        assert!(matches!(first, Some(TestAction::Value(1))));
+
+        config_tx.send(5).unwrap();
+        let second = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .expect("timeout");
+        assert!(matches!(second, Some(TestAction::Value(5))));
+    }
+
     #[tokio::test]
     async fn test_cancel_stops_subscription() {
         let (tx, mut rx) = mpsc::unbounded_channel();
@@ -641,4 +922,77 @@ mod tests {
         handle2.resume();
         assert!(!handle1.is_paused());
     }
+    #[tokio::test]
+    async fn test_delay_fires_once() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut subs = Subscriptions::new(tx);
+
+        subs.delay("status", Duration::from_millis(10), TestAction::Value(1));
+        assert!(subs.is_active(&SubKey::new("status")));
+
+        let action = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(action, TestAction::Value(1)));
+
+        // No further emissions, and the finished delay is no longer active
+        let result = tokio::time::timeout(Duration::from_millis(30), rx.recv()).await;
+        assert!(result.is_err());
+        assert!(!subs.is_active(&SubKey::new("status")));
+        assert!(subs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delay_rearm_and_cancel() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut subs = Subscriptions::new(tx);
+
+        // Re-arming replaces the pending action
+        subs.delay("status", Duration::from_millis(10), TestAction::Value(1));
+        subs.delay("status", Duration::from_millis(10), TestAction::Value(2));
+        let action = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(action, TestAction::Value(2)));
+
+        subs.delay("status", Duration::from_millis(10), TestAction::Value(3));
+        subs.cancel(&SubKey::new("status"));
+        let result = tokio::time::timeout(Duration::from_millis(50), rx.recv()).await;
+        assert!(result.is_err(), "cancelled delay should not fire");
+    }
+
+    #[tokio::test]
+    async fn test_delay_held_while_paused() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut subs = Subscriptions::new(tx);
+
+        subs.pause();
+        subs.delay("status", Duration::from_millis(5), TestAction::Value(1));
+        let result = tokio::time::timeout(Duration::from_millis(80), rx.recv()).await;
+        assert!(result.is_err(), "delay should wait while paused");
+
+        subs.resume();
+        let action = tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(action, TestAction::Value(1)));
+    }
+
+    #[tokio::test]
+    async fn test_schedule_rejects_invalid_expr() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut subs = Subscriptions::new(tx);
+
+        assert!(subs
+            .schedule("bad", "0 25 * * *", || TestAction::Tick)
+            .is_err());
+        assert!(!subs.is_active(&SubKey::new("bad")));
+
+        subs.schedule("daily", "0 9 * * *", || TestAction::Tick)
+            .unwrap();
+        assert!(subs.is_active(&SubKey::new("daily")));
+    }
 }
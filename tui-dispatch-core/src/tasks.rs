@@ -3,6 +3,8 @@
 //! Provides lifecycle management for async tasks with support for:
 //! - Automatic cancellation when spawning with same key
 //! - Debounced execution
+//! - Retries with exponential backoff
+//! - Per-key concurrency limits with prioritized queues
 //! - Manual cancellation
 //!
 //! # Example
@@ -26,6 +28,36 @@
 //!     Action::DidSearch(results)
 //! });
 //!
+//! // Retried task - re-runs the future on error, with backoff between attempts
+//! tasks.spawn_with_retry(
+//!     "fetch",
+//!     RetryPolicy::new(3),
+//!     move || async move { fetch_data().await.map(Action::DidFetch) },
+//!     |e| Action::DidFail(e.to_string()),
+//! );
+//!
+//! // Fallible task - errors become actions through the registered mapper
+//! tasks.set_error_mapper(|key, e: ApiError| Action::DidFail(key.name().into(), e.to_string()));
+//! tasks.spawn_result("fetch", async move { fetch_data().await.map(Action::DidFetch) });
+//!
+//! // Progress task - reports progress actions and can check for cancellation
+//! tasks.spawn_with_progress("export", |progress| async move {
+//!     for (i, chunk) in chunks.iter().enumerate() {
+//!         if progress.is_cancelled() {
+//!             break;
+//!         }
+//!         write_chunk(chunk).await;
+//!         let fraction = (i + 1) as f64 / chunks.len() as f64;
+//!         progress.report(fraction, Action::ExportProgress(fraction));
+//!     }
+//!     Action::ExportDidFinish
+//! });
+//!
+//! // Limited task - at most 2 "thumbs" run at once, the rest wait in a queue
+//! tasks.spawn_limited("thumbs", 2, async move {
+//!     Action::DidLoadThumb(load_thumb(id).await)
+//! });
+//!
 //! // Cancel a specific task
 //! tasks.cancel(&TaskKey::new("fetch"));
 //!
@@ -33,14 +65,19 @@
 //! tasks.cancel_all();
 //! ```
 
+use std::any::{Any, TypeId};
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
 use std::future::Future;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
 use tokio::sync::mpsc;
 use tokio::task::{AbortHandle, JoinHandle};
+use tokio_util::sync::CancellationToken;
 
 use crate::Action;
 
@@ -75,6 +112,374 @@ impl From<String> for TaskKey {
     }
 }
 
+/// Retry configuration for [`TaskManager::spawn_with_retry`].
+///
+/// Delays grow exponentially from `initial_delay` by `multiplier` per
+/// attempt, capped at `max_delay`. `jitter` randomizes each delay by up to
+/// that fraction in either direction to avoid retry storms.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tui_dispatch_core::tasks::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(4)
+///     .with_initial_delay(Duration::from_millis(100))
+///     .with_multiplier(2.0)
+///     .with_jitter(0.0);
+///
+/// assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+/// assert_eq!(policy.delay_for(2), Duration::from_millis(200));
+/// assert_eq!(policy.delay_for(3), Duration::from_millis(400));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (minimum 1)
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+    /// Upper bound for any single delay
+    pub max_delay: Duration,
+    /// Growth factor applied to the delay after each retry
+    pub multiplier: f64,
+    /// Random spread as a fraction of the delay (0.0 - 1.0)
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.1,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with the given total number of attempts.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Self::default()
+        }
+    }
+
+    /// Set the delay before the first retry.
+    pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Set the maximum delay between attempts.
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the backoff multiplier.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Set the jitter fraction (clamped to 0.0 - 1.0).
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Base delay (without jitter) before retry number `retry` (1-based).
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let factor = self.multiplier.max(1.0).powi(exponent);
+        let secs = self.initial_delay.as_secs_f64() * factor;
+        // Huge (or infinite) backoffs don't fit a Duration; they clamp anyway
+        Duration::try_from_secs_f64(secs)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// Delay before retry number `retry`, with jitter applied.
+    fn jittered_delay(&self, retry: u32, random: f64) -> Duration {
+        let base = self.delay_for(retry);
+        if self.jitter <= 0.0 {
+            return base;
+        }
+        // random in [0, 1) -> spread in [-jitter, +jitter)
+        let spread = (random * 2.0 - 1.0) * self.jitter.min(1.0);
+        Duration::try_from_secs_f64((base.as_secs_f64() * (1.0 + spread)).max(0.0)).unwrap_or(base)
+    }
+}
+
+/// Uniform-ish random value in [0, 1) without an RNG dependency.
+fn jitter_random(seed: u32) -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    seed.hash(&mut hasher);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Queue priority for [`TaskManager::spawn_limited_with_priority`].
+///
+/// When a key is at its concurrency limit, queued tasks start in priority
+/// order, and in spawn order within the same priority.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    /// Runs after all other queued work
+    Low,
+    /// Default priority
+    #[default]
+    Normal,
+    /// Runs before normal and low priority work
+    High,
+}
+
+/// Sends task actions to the main loop, or queues them while paused.
+struct ActionSink<A> {
+    tx: mpsc::UnboundedSender<A>,
+    paused: Arc<AtomicBool>,
+    queued: Arc<Mutex<Vec<A>>>,
+}
+
+impl<A> Clone for ActionSink<A> {
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            paused: self.paused.clone(),
+            queued: self.queued.clone(),
+        }
+    }
+}
+
+impl<A> ActionSink<A> {
+    fn send(&self, action: A) {
+        if self.paused.load(Ordering::SeqCst) {
+            self.queued.lock().unwrap().push(action);
+        } else {
+            let _ = self.tx.send(action);
+        }
+    }
+}
+
+type BoxedTask<A> = Pin<Box<dyn Future<Output = A> + Send>>;
+
+struct PendingTask<A> {
+    id: u64,
+    priority: TaskPriority,
+    future: BoxedTask<A>,
+}
+
+/// Running and queued tasks for one key spawned via `spawn_limited`.
+struct LimitedPool<A> {
+    limit: usize,
+    next_id: u64,
+    running: HashMap<u64, AbortHandle>,
+    /// Sorted by priority (highest first), then by spawn order
+    pending: Vec<PendingTask<A>>,
+}
+
+impl<A> LimitedPool<A> {
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            next_id: 0,
+            running: HashMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    fn enqueue(&mut self, priority: TaskPriority, future: BoxedTask<A>) {
+        let id = self.next_id;
+        self.next_id += 1;
+        let index = self
+            .pending
+            .iter()
+            .position(|task| task.priority < priority)
+            .unwrap_or(self.pending.len());
+        self.pending.insert(
+            index,
+            PendingTask {
+                id,
+                priority,
+                future,
+            },
+        );
+    }
+
+    fn abort_all(&mut self) {
+        self.pending.clear();
+        for (_, handle) in self.running.drain() {
+            handle.abort();
+        }
+    }
+}
+
+impl<A: Send + 'static> LimitedPool<A> {
+    /// Start queued tasks until the pool is at its limit.
+    ///
+    /// Each started task holds a [`SlotGuard`] that frees its slot and
+    /// fills it again, even if the task panics.
+    fn fill(pool: &Arc<Mutex<Self>>, guard: &mut Self, sink: &ActionSink<A>) {
+        while guard.running.len() < guard.limit && !guard.pending.is_empty() {
+            let task = guard.pending.remove(0);
+            let id = task.id;
+            let pool_ref = pool.clone();
+            let sink_ref = sink.clone();
+            let handle: JoinHandle<()> = tokio::spawn(async move {
+                let _slot = SlotGuard {
+                    pool: pool_ref,
+                    sink: sink_ref.clone(),
+                    id,
+                };
+                let action = task.future.await;
+                sink_ref.send(action);
+            });
+            guard.running.insert(id, handle.abort_handle());
+        }
+    }
+}
+
+/// Frees a pool slot when its task finishes, panics, or is aborted.
+struct SlotGuard<A: Send + 'static> {
+    pool: Arc<Mutex<LimitedPool<A>>>,
+    sink: ActionSink<A>,
+    id: u64,
+}
+
+impl<A: Send + 'static> Drop for SlotGuard<A> {
+    fn drop(&mut self) {
+        let mut guard = self.pool.lock().unwrap_or_else(PoisonError::into_inner);
+        guard.running.remove(&self.id);
+        // Tasks dropped during runtime shutdown have nowhere to spawn into
+        if tokio::runtime::Handle::try_current().is_ok() {
+            LimitedPool::fill(&self.pool, &mut guard, &self.sink);
+        }
+    }
+}
+
+/// Last reported progress of a running task, see
+/// [`TaskManager::spawn_with_progress`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskProgress {
+    /// Key the task was spawned with
+    pub key: TaskKey,
+    /// Last reported completion (0.0 - 1.0), if any
+    pub fraction: Option<f64>,
+    /// Name of the last progress action sent
+    pub last_action: Option<&'static str>,
+    /// Time since the task started
+    pub elapsed: Duration,
+}
+
+struct ProgressEntry {
+    /// Distinguishes a task from a later one spawned with the same key
+    id: u64,
+    started: Instant,
+    fraction: Option<f64>,
+    last_action: Option<&'static str>,
+}
+
+type ProgressMap = Arc<Mutex<HashMap<TaskKey, ProgressEntry>>>;
+
+/// Remove a task's progress entry unless it was replaced already.
+fn finish_progress(progress: &ProgressMap, key: &TaskKey, id: u64) {
+    let mut progress = progress.lock().unwrap();
+    if progress.get(key).is_some_and(|entry| entry.id == id) {
+        progress.remove(key);
+    }
+}
+
+/// Passed to tasks spawned with [`TaskManager::spawn_with_progress`].
+///
+/// Sends progress actions through the task manager (queued while paused,
+/// like task results) and records the latest progress for the debug
+/// layer's metrics overlay. Cloneable, so it can be moved into
+/// `spawn_blocking` work.
+pub struct ProgressHandle<A> {
+    key: TaskKey,
+    id: u64,
+    sink: ActionSink<A>,
+    progress: ProgressMap,
+    token: CancellationToken,
+}
+
+impl<A> Clone for ProgressHandle<A> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            id: self.id,
+            sink: self.sink.clone(),
+            progress: self.progress.clone(),
+            token: self.token.clone(),
+        }
+    }
+}
+
+impl<A: Action> ProgressHandle<A> {
+    /// Key the task was spawned with.
+    pub fn key(&self) -> &TaskKey {
+        &self.key
+    }
+
+    /// Record `fraction` complete (clamped to 0.0 - 1.0) and send `action`.
+    ///
+    /// Nothing is sent once the task is cancelled.
+    pub fn report(&self, fraction: f64, action: A) {
+        if self.is_cancelled() {
+            return;
+        }
+        if let Some(entry) = self.progress.lock().unwrap().get_mut(&self.key) {
+            if entry.id == self.id {
+                entry.fraction = Some(fraction.clamp(0.0, 1.0));
+                entry.last_action = Some(action.name());
+            }
+        }
+        self.sink.send(action);
+    }
+
+    /// Whether the task was cancelled or replaced.
+    ///
+    /// Cancellation also aborts the task at its next `.await`; check this in
+    /// long synchronous loops or blocking work that never yields.
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Wait until the task is cancelled or replaced.
+    pub async fn cancelled(&self) {
+        self.token.cancelled().await;
+    }
+}
+
+/// Read-only view of running progress tasks, for the debug layer.
+#[derive(Clone)]
+pub struct TaskProgressView {
+    progress: ProgressMap,
+}
+
+impl TaskProgressView {
+    /// Progress of every running progress task, sorted by key.
+    pub fn snapshot(&self) -> Vec<TaskProgress> {
+        let mut snapshot: Vec<TaskProgress> = self
+            .progress
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(key, entry)| TaskProgress {
+                key: key.clone(),
+                fraction: entry.fraction,
+                last_action: entry.last_action,
+                elapsed: entry.started.elapsed(),
+            })
+            .collect();
+        snapshot.sort_by(|a, b| a.key.name().cmp(b.key.name()));
+        snapshot
+    }
+}
+
 /// Handle for pausing/resuming a TaskManager.
 ///
 /// This is a lightweight, cloneable handle that can be used to pause and resume
@@ -127,6 +532,17 @@ pub struct TaskManager<A> {
     paused: Arc<AtomicBool>,
     /// Actions queued while paused
     queued_actions: Arc<Mutex<Vec<A>>>,
+    /// Concurrency-limited tasks, keyed like `tasks`
+    pools: HashMap<TaskKey, Arc<Mutex<LimitedPool<A>>>>,
+    /// `fn(&TaskKey, E) -> A` per error type `E`, for `spawn_result`
+    error_mappers: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    /// Builds the action for tasks that exceed their timeout
+    timeout_mapper: Option<fn(&TaskKey, Duration) -> A>,
+    /// Last progress of tasks spawned with `spawn_with_progress`
+    progress: ProgressMap,
+    /// Cancellation tokens of progress tasks, keyed like `tasks`
+    progress_tokens: HashMap<TaskKey, CancellationToken>,
+    next_progress_id: u64,
 }
 
 impl<A> TaskManager<A>
@@ -143,6 +559,12 @@ where
             action_tx,
             paused: Arc::new(AtomicBool::new(false)),
             queued_actions: Arc::new(Mutex::new(Vec::new())),
+            pools: HashMap::new(),
+            error_mappers: HashMap::new(),
+            timeout_mapper: None,
+            progress: Arc::new(Mutex::new(HashMap::new())),
+            progress_tokens: HashMap::new(),
+            next_progress_id: 0,
         }
     }
 
@@ -269,51 +691,507 @@ where
         self
     }
 
+    /// Spawn a fallible task that is retried according to `policy`.
+    ///
+    /// `make_future` is called once per attempt. On `Ok(action)` the action is
+    /// sent; on `Err` the task sleeps for the policy's backoff delay and tries
+    /// again. When all attempts fail, `on_exhausted` turns the last error into
+    /// an action. Like [`spawn`](Self::spawn), this cancels any existing task
+    /// with the same key, including one that is waiting between retries.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// ctx.tasks().spawn_with_retry(
+    ///     "fetch",
+    ///     RetryPolicy::new(5).with_initial_delay(Duration::from_millis(250)),
+    ///     move || {
+    ///         let client = client.clone();
+    ///         async move { client.fetch().await.map(Action::DidFetch) }
+    ///     },
+    ///     |e| Action::FetchFailed(e.to_string()),
+    /// );
+    /// ```
+    pub fn spawn_with_retry<F, Fut, E, G>(
+        &mut self,
+        key: impl Into<TaskKey>,
+        policy: RetryPolicy,
+        mut make_future: F,
+        on_exhausted: G,
+    ) -> &mut Self
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<A, E>> + Send + 'static,
+        E: std::fmt::Display + Send + 'static,
+        G: FnOnce(E) -> A + Send + 'static,
+    {
+        let key = key.into();
+
+        // Cancel existing task with this key
+        self.cancel(&key);
+
+        let name = key.name().to_string();
+        let tx = self.action_tx.clone();
+        let paused = self.paused.clone();
+        let queued = self.queued_actions.clone();
+        let handle: JoinHandle<()> = tokio::spawn(async move {
+            let max_attempts = policy.max_attempts.max(1);
+            let mut attempt = 1;
+            let action = loop {
+                match make_future().await {
+                    Ok(action) => break action,
+                    Err(e) if attempt >= max_attempts => {
+                        tracing::warn!(
+                            task = %name,
+                            attempts = attempt,
+                            error = %e,
+                            "Task retries exhausted"
+                        );
+                        break on_exhausted(e);
+                    }
+                    Err(e) => {
+                        let delay = policy.jittered_delay(attempt, jitter_random(attempt));
+                        tracing::debug!(
+                            task = %name,
+                            attempt,
+                            ?delay,
+                            error = %e,
+                            "Task failed, retrying"
+                        );
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                    }
+                }
+            };
+            // Check if paused - if so, queue instead of send
+            if paused.load(Ordering::SeqCst) {
+                queued.lock().unwrap().push(action);
+            } else {
+                let _ = tx.send(action);
+            }
+        });
+
+        self.tasks.insert(key, handle.abort_handle());
+        self
+    }
+
+    /// Register how errors of type `E` become actions.
+    ///
+    /// Used by [`spawn_result`](Self::spawn_result) for tasks whose future
+    /// returns `Result<A, E>`. Registering a mapper for the same error type
+    /// again replaces it.
+    pub fn set_error_mapper<E: 'static>(&mut self, mapper: fn(&TaskKey, E) -> A) -> &mut Self {
+        self.error_mappers
+            .insert(TypeId::of::<E>(), Box::new(mapper));
+        self
+    }
+
+    /// Register the action sent when a task exceeds its timeout.
+    ///
+    /// The mapper gets the task key and the timeout that expired. Used by
+    /// [`spawn_with_timeout`](Self::spawn_with_timeout) and
+    /// [`spawn_result_with_timeout`](Self::spawn_result_with_timeout).
+    pub fn set_timeout_mapper(&mut self, mapper: fn(&TaskKey, Duration) -> A) -> &mut Self {
+        self.timeout_mapper = Some(mapper);
+        self
+    }
+
+    /// Spawn a fallible task, cancelling any existing task with the same key.
+    ///
+    /// `Ok(action)` is sent as is; `Err(e)` goes through the mapper
+    /// registered for `E` with [`set_error_mapper`](Self::set_error_mapper).
+    /// Without a mapper the error is logged and no action is sent.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tasks.set_error_mapper(|key, e: reqwest::Error| {
+    ///     Action::RequestDidFail(key.name().to_string(), e.to_string())
+    /// });
+    /// tasks.spawn_result("weather", async move {
+    ///     api::fetch_weather(lat, lon).await.map(Action::WeatherDidLoad)
+    /// });
+    /// ```
+    pub fn spawn_result<F, E>(&mut self, key: impl Into<TaskKey>, future: F) -> &mut Self
+    where
+        F: Future<Output = Result<A, E>> + Send + 'static,
+        E: std::fmt::Display + Send + 'static,
+    {
+        let key = key.into();
+        let result = self.map_result(key.clone(), future);
+        self.spawn_optional(key, result)
+    }
+
+    /// Spawn a task that gives up after `timeout`.
+    ///
+    /// If the future does not finish in time it is dropped and the action
+    /// from [`set_timeout_mapper`](Self::set_timeout_mapper) is sent instead
+    /// (or nothing, with a warning, if no mapper is registered).
+    pub fn spawn_with_timeout<F>(
+        &mut self,
+        key: impl Into<TaskKey>,
+        timeout: Duration,
+        future: F,
+    ) -> &mut Self
+    where
+        F: Future<Output = A> + Send + 'static,
+    {
+        let key = key.into();
+        let limited = self.with_timeout(key.clone(), timeout, async move { Some(future.await) });
+        self.spawn_optional(key, limited)
+    }
+
+    /// Spawn a fallible task with a timeout.
+    ///
+    /// Combines [`spawn_result`](Self::spawn_result) and
+    /// [`spawn_with_timeout`](Self::spawn_with_timeout).
+    pub fn spawn_result_with_timeout<F, E>(
+        &mut self,
+        key: impl Into<TaskKey>,
+        timeout: Duration,
+        future: F,
+    ) -> &mut Self
+    where
+        F: Future<Output = Result<A, E>> + Send + 'static,
+        E: std::fmt::Display + Send + 'static,
+    {
+        let key = key.into();
+        let result = self.map_result(key.clone(), future);
+        let limited = self.with_timeout(key.clone(), timeout, result);
+        self.spawn_optional(key, limited)
+    }
+
+    /// Spawn a task that reports progress, cancelling any existing task with the same key.
+    ///
+    /// `make_future` receives a [`ProgressHandle`] for sending progress
+    /// actions and checking for cancellation; the future's output is sent
+    /// when it completes, like [`spawn`](Self::spawn). Running progress tasks
+    /// are listed in the debug layer's metrics overlay with their latest
+    /// progress (see [`progress`](Self::progress)).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// tasks.spawn_with_progress("scan", move |progress| async move {
+    ///     let mut found = Vec::new();
+    ///     for (i, dir) in dirs.iter().enumerate() {
+    ///         found.extend(scan_dir(dir).await);
+    ///         let fraction = (i + 1) as f64 / dirs.len() as f64;
+    ///         progress.report(fraction, Action::ScanProgress(fraction));
+    ///     }
+    ///     Action::ScanDidFinish(found)
+    /// });
+    /// ```
+    pub fn spawn_with_progress<F, Fut>(
+        &mut self,
+        key: impl Into<TaskKey>,
+        make_future: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(ProgressHandle<A>) -> Fut,
+        Fut: Future<Output = A> + Send + 'static,
+    {
+        let key = key.into();
+
+        // Cancel existing task with this key
+        self.cancel(&key);
+
+        let id = self.next_progress_id;
+        self.next_progress_id += 1;
+        let token = CancellationToken::new();
+        self.progress.lock().unwrap().insert(
+            key.clone(),
+            ProgressEntry {
+                id,
+                started: Instant::now(),
+                fraction: None,
+                last_action: None,
+            },
+        );
+        let future = make_future(ProgressHandle {
+            key: key.clone(),
+            id,
+            sink: self.sink(),
+            progress: self.progress.clone(),
+            token: token.clone(),
+        });
+
+        let sink = self.sink();
+        let progress = self.progress.clone();
+        let done_key = key.clone();
+        let handle: JoinHandle<()> = tokio::spawn(async move {
+            let action = future.await;
+            finish_progress(&progress, &done_key, id);
+            sink.send(action);
+        });
+
+        self.tasks.insert(key.clone(), handle.abort_handle());
+        self.progress_tokens.insert(key, token);
+        self
+    }
+
+    /// Latest progress of running [`spawn_with_progress`](Self::spawn_with_progress) tasks.
+    pub fn progress(&self) -> Vec<TaskProgress> {
+        self.progress_view().snapshot()
+    }
+
+    /// Get a view of task progress that can be read from elsewhere.
+    ///
+    /// Used by the debug layer's metrics overlay.
+    pub fn progress_view(&self) -> TaskProgressView {
+        TaskProgressView {
+            progress: self.progress.clone(),
+        }
+    }
+
+    /// Spawn a task under a per-key concurrency limit.
+    ///
+    /// Unlike [`spawn`](Self::spawn), tasks with the same key do not replace
+    /// each other. At most `limit` of them run at once; the rest wait in a
+    /// queue and start as running tasks finish. The most recent `limit`
+    /// passed for a key applies to the whole key (minimum 1).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for id in visible_ids {
+    ///     tasks.spawn_limited("thumbs", 4, async move {
+    ///         Action::ThumbDidLoad(id, api::thumbnail(id).await)
+    ///     });
+    /// }
+    /// ```
+    pub fn spawn_limited<F>(
+        &mut self,
+        key: impl Into<TaskKey>,
+        limit: usize,
+        future: F,
+    ) -> &mut Self
+    where
+        F: Future<Output = A> + Send + 'static,
+    {
+        self.spawn_limited_with_priority(key, limit, TaskPriority::Normal, future)
+    }
+
+    /// Spawn a concurrency-limited task with a queue priority.
+    ///
+    /// Higher-priority tasks start before lower-priority ones when the key
+    /// is at its limit. Tasks that are already running are not preempted.
+    pub fn spawn_limited_with_priority<F>(
+        &mut self,
+        key: impl Into<TaskKey>,
+        limit: usize,
+        priority: TaskPriority,
+        future: F,
+    ) -> &mut Self
+    where
+        F: Future<Output = A> + Send + 'static,
+    {
+        let limit = limit.max(1);
+        let pool = self
+            .pools
+            .entry(key.into())
+            .or_insert_with(|| Arc::new(Mutex::new(LimitedPool::new(limit))))
+            .clone();
+        let sink = self.sink();
+
+        let mut guard = pool.lock().unwrap();
+        guard.limit = limit;
+        guard.enqueue(priority, Box::pin(future));
+        LimitedPool::fill(&pool, &mut guard, &sink);
+        drop(guard);
+        self
+    }
+
+    /// Drop queued tasks for a key that have not started yet.
+    ///
+    /// Running tasks are left alone. Returns the number of dropped tasks.
+    pub fn cancel_key_pending(&mut self, key: &TaskKey) -> usize {
+        self.pools.get(key).map_or(0, |pool| {
+            let mut guard = pool.lock().unwrap();
+            let dropped = guard.pending.len();
+            guard.pending.clear();
+            dropped
+        })
+    }
+
+    /// Get the number of queued (not yet started) tasks for a key.
+    pub fn pending_count(&self, key: &TaskKey) -> usize {
+        self.pools
+            .get(key)
+            .map_or(0, |pool| pool.lock().unwrap().pending.len())
+    }
+
     /// Cancel a task by key.
     ///
+    /// This also aborts running and queued tasks spawned with
+    /// [`spawn_limited`](Self::spawn_limited) under the key.
     /// If no task exists with the given key, this is a no-op.
     pub fn cancel(&mut self, key: &TaskKey) {
         if let Some(handle) = self.tasks.remove(key) {
             handle.abort();
         }
+        if let Some(token) = self.progress_tokens.remove(key) {
+            token.cancel();
+            self.progress.lock().unwrap().remove(key);
+        }
+        if let Some(pool) = self.pools.remove(key) {
+            pool.lock().unwrap().abort_all();
+        }
     }
 
     /// Cancel all running tasks.
     ///
     /// Useful for cleanup on shutdown.
     pub fn cancel_all(&mut self) {
-        for (_, handle) in self.tasks.drain() {
-            handle.abort();
-        }
+        self.abort_everything();
     }
 
     /// Check if a task with the given key is currently running.
     pub fn is_running(&self, key: &TaskKey) -> bool {
-        self.tasks.contains_key(key)
+        self.tasks.contains_key(key) || self.running_in_pool(key) > 0
     }
 
     /// Get the number of running tasks.
     pub fn len(&self) -> usize {
-        self.tasks.len()
+        let limited: usize = self
+            .pools
+            .values()
+            .map(|pool| pool.lock().unwrap().running.len())
+            .sum();
+        self.tasks.len() + limited
     }
 
     /// Check if there are no running tasks.
     pub fn is_empty(&self) -> bool {
-        self.tasks.is_empty()
+        self.len() == 0
     }
 
     /// Get the keys of all running tasks.
     pub fn running_keys(&self) -> impl Iterator<Item = &TaskKey> {
-        self.tasks.keys()
+        let limited = self
+            .pools
+            .keys()
+            .filter(|key| !self.tasks.contains_key(*key) && self.running_in_pool(key) > 0);
+        self.tasks.keys().chain(limited)
+    }
+
+    fn running_in_pool(&self, key: &TaskKey) -> usize {
+        self.pools
+            .get(key)
+            .map_or(0, |pool| pool.lock().unwrap().running.len())
+    }
+
+    /// Spawn a keyed task that may finish without an action.
+    fn spawn_optional<F>(&mut self, key: TaskKey, future: F) -> &mut Self
+    where
+        F: Future<Output = Option<A>> + Send + 'static,
+    {
+        // Cancel existing task with this key
+        self.cancel(&key);
+
+        let sink = self.sink();
+        let handle: JoinHandle<()> = tokio::spawn(async move {
+            if let Some(action) = future.await {
+                sink.send(action);
+            }
+        });
+
+        self.tasks.insert(key, handle.abort_handle());
+        self
+    }
+
+    /// Turn a fallible future into one producing the action or mapped error.
+    fn map_result<F, E>(
+        &self,
+        key: TaskKey,
+        future: F,
+    ) -> impl Future<Output = Option<A>> + Send + 'static
+    where
+        F: Future<Output = Result<A, E>> + Send + 'static,
+        E: std::fmt::Display + Send + 'static,
+    {
+        let mapper = self
+            .error_mappers
+            .get(&TypeId::of::<E>())
+            .and_then(|mapper| mapper.downcast_ref::<fn(&TaskKey, E) -> A>())
+            .copied();
+        async move {
+            match future.await {
+                Ok(action) => Some(action),
+                Err(e) => match mapper {
+                    Some(mapper) => Some(mapper(&key, e)),
+                    None => {
+                        tracing::warn!(
+                            task = %key.name(),
+                            error = %e,
+                            "Task failed with no error mapper registered"
+                        );
+                        None
+                    }
+                },
+            }
+        }
+    }
+
+    /// Limit a future to `timeout`, producing the timeout action when it expires.
+    fn with_timeout<F>(
+        &self,
+        key: TaskKey,
+        timeout: Duration,
+        future: F,
+    ) -> impl Future<Output = Option<A>> + Send + 'static
+    where
+        F: Future<Output = Option<A>> + Send + 'static,
+    {
+        let mapper = self.timeout_mapper;
+        async move {
+            match tokio::time::timeout(timeout, future).await {
+                Ok(action) => action,
+                Err(_) => {
+                    tracing::debug!(task = %key.name(), ?timeout, "Task timed out");
+                    match mapper {
+                        Some(mapper) => Some(mapper(&key, timeout)),
+                        None => {
+                            tracing::warn!(
+                                task = %key.name(),
+                                "Task timed out with no timeout mapper registered"
+                            );
+                            None
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn sink(&self) -> ActionSink<A> {
+        ActionSink {
+            tx: self.action_tx.clone(),
+            paused: self.paused.clone(),
+            queued: self.queued_actions.clone(),
+        }
     }
 }
 
-impl<A> Drop for TaskManager<A> {
-    fn drop(&mut self) {
-        // Abort all running tasks on drop
+impl<A> TaskManager<A> {
+    fn abort_everything(&mut self) {
         for (_, handle) in self.tasks.drain() {
             handle.abort();
         }
+        for (_, token) in self.progress_tokens.drain() {
+            token.cancel();
+        }
+        self.progress.lock().unwrap().clear();
+        for (_, pool) in self.pools.drain() {
+            pool.lock().unwrap().abort_all();
+        }
+    }
+}
+
+impl<A> Drop for TaskManager<A> {
+    fn drop(&mut self) {
+        // Abort all running tasks on drop
+        self.abort_everything();
     }
 }
 
@@ -532,6 +1410,365 @@ mod tests {
         assert!(matches!(queued[0], TestAction::Done(42)));
     }
 
+    #[tokio::test]
+    async fn test_spawn_result_maps_errors() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        tasks.set_error_mapper(|key, e: String| TestAction::Done(key.name().len() + e.len()));
+
+        tasks.spawn_result("ok", async { Ok::<_, String>(TestAction::Done(1)) });
+        let action = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(action, TestAction::Done(1)));
+
+        tasks.spawn_result("fail", async { Err::<TestAction, _>("boom".to_string()) });
+        let action = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(action, TestAction::Done(8)));
+    }
+
+    #[tokio::test]
+    async fn test_spawn_result_without_mapper_sends_nothing() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        // A mapper for a different error type does not apply
+        tasks.set_error_mapper(|_, _: std::io::Error| TestAction::Done(0));
+
+        tasks.spawn_result("fail", async { Err::<TestAction, _>("boom") });
+
+        let result = tokio::time::timeout(Duration::from_millis(50), rx.recv()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_spawn_with_timeout() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        tasks.set_timeout_mapper(|_, timeout| TestAction::Done(timeout.as_millis() as usize));
+
+        tasks.spawn_with_timeout("slow", Duration::from_millis(20), async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            TestAction::Done(1)
+        });
+        tasks.spawn_result_with_timeout("fast", Duration::from_secs(10), async {
+            Ok::<_, String>(TestAction::Done(2))
+        });
+
+        let first = tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        let second = tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(first, TestAction::Done(2)));
+        assert!(matches!(second, TestAction::Done(20)));
+    }
+
+    #[tokio::test]
+    async fn test_spawn_with_progress_reports() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        let (step_tx, mut step_rx) = mpsc::unbounded_channel::<()>();
+
+        tasks.spawn_with_progress("scan", |progress| async move {
+            progress.report(0.5, TestAction::Done(50));
+            step_rx.recv().await;
+            progress.report(2.0, TestAction::Done(100));
+            TestAction::Done(0)
+        });
+
+        let action = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(action, TestAction::Done(50)));
+        let progress = tasks.progress();
+        assert_eq!(progress.len(), 1);
+        assert_eq!(progress[0].key.name(), "scan");
+        assert_eq!(progress[0].fraction, Some(0.5));
+        assert_eq!(progress[0].last_action, Some("Done"));
+
+        step_tx.send(()).unwrap();
+        let action = rx.recv().await.unwrap();
+        assert!(matches!(action, TestAction::Done(100)));
+        let action = rx.recv().await.unwrap();
+        assert!(matches!(action, TestAction::Done(0)));
+        // Finished tasks no longer report progress
+        assert!(tasks.progress().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_progress_handle_sees_cancellation() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        let (handle_tx, mut handle_rx) = mpsc::unbounded_channel();
+
+        tasks.spawn_with_progress("export", move |progress| {
+            let _ = handle_tx.send(progress.clone());
+            async move {
+                progress.cancelled().await;
+                TestAction::Done(1)
+            }
+        });
+        let progress = handle_rx.recv().await.unwrap();
+        assert!(!progress.is_cancelled());
+
+        tasks.cancel(&TaskKey::new("export"));
+        assert!(progress.is_cancelled());
+        assert!(tasks.progress().is_empty());
+
+        // Reports after cancellation are dropped, and the task was aborted
+        progress.report(1.0, TestAction::Done(2));
+        let result = tokio::time::timeout(Duration::from_millis(50), rx.recv()).await;
+        assert!(result.is_err());
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts)
+            .with_initial_delay(Duration::from_millis(5))
+            .with_jitter(0.0)
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy::new(10)
+            .with_initial_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500))
+            .with_multiplier(3.0);
+
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(300));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(500));
+        assert_eq!(policy.delay_for(100), Duration::from_millis(500));
+
+        // Backoffs beyond what a Duration holds clamp instead of panicking
+        let unbounded = RetryPolicy::new(10)
+            .with_initial_delay(Duration::from_secs(1))
+            .with_max_delay(Duration::MAX)
+            .with_multiplier(1e10)
+            .with_jitter(1.0);
+        assert_eq!(unbounded.delay_for(100), Duration::MAX);
+        assert_eq!(unbounded.jittered_delay(100, 0.99), Duration::MAX);
+    }
+
+    #[test]
+    fn test_retry_policy_jitter_bounds() {
+        let policy = RetryPolicy::new(3)
+            .with_initial_delay(Duration::from_millis(100))
+            .with_jitter(0.5);
+
+        assert_eq!(policy.jittered_delay(1, 0.0), Duration::from_millis(50));
+        assert_eq!(policy.jittered_delay(1, 0.5), Duration::from_millis(100));
+        for seed in 0..20 {
+            let delay = policy.jittered_delay(1, jitter_random(seed));
+            assert!(delay >= Duration::from_millis(50) && delay < Duration::from_millis(150));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spawn_with_retry_succeeds_after_failures() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        let counter = attempts.clone();
+        tasks.spawn_with_retry(
+            "retry",
+            fast_policy(3),
+            move || {
+                let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                async move {
+                    if n < 3 {
+                        Err("not yet")
+                    } else {
+                        Ok(TestAction::Done(n))
+                    }
+                }
+            },
+            |_| TestAction::Done(0),
+        );
+
+        let action = tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+
+        assert!(matches!(action, TestAction::Done(3)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_with_retry_exhausted() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        let counter = attempts.clone();
+        tasks.spawn_with_retry(
+            "retry",
+            fast_policy(2),
+            move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { Err::<TestAction, _>("boom") }
+            },
+            |e: &str| TestAction::Done(e.len()),
+        );
+
+        let action = tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+
+        assert!(matches!(action, TestAction::Done(4)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_limited_respects_limit() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        for i in 0..5 {
+            let active = active.clone();
+            let peak = peak.clone();
+            tasks.spawn_limited("fetch", 2, async move {
+                let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+                TestAction::Done(i)
+            });
+        }
+
+        let key = TaskKey::new("fetch");
+        assert!(tasks.is_running(&key));
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks.pending_count(&key), 3);
+
+        let mut done = Vec::new();
+        for _ in 0..5 {
+            let action = tokio::time::timeout(Duration::from_millis(500), rx.recv())
+                .await
+                .expect("timeout")
+                .expect("channel closed");
+            let TestAction::Done(i) = action;
+            done.push(i);
+        }
+
+        done.sort_unstable();
+        assert_eq!(done, vec![0, 1, 2, 3, 4]);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(tasks.pending_count(&key), 0);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_limited_priority_order() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+
+        tasks.spawn_limited("fetch", 1, async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            TestAction::Done(0)
+        });
+        tasks.spawn_limited_with_priority("fetch", 1, TaskPriority::Low, async {
+            TestAction::Done(1)
+        });
+        tasks.spawn_limited("fetch", 1, async { TestAction::Done(2) });
+        tasks.spawn_limited_with_priority("fetch", 1, TaskPriority::High, async {
+            TestAction::Done(3)
+        });
+        tasks.spawn_limited("fetch", 1, async { TestAction::Done(4) });
+
+        let mut order = Vec::new();
+        for _ in 0..5 {
+            let action = tokio::time::timeout(Duration::from_millis(200), rx.recv())
+                .await
+                .expect("timeout")
+                .expect("channel closed");
+            let TestAction::Done(i) = action;
+            order.push(i);
+        }
+
+        assert_eq!(order, vec![0, 3, 2, 4, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_limited_panic_frees_slot() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        let key = TaskKey::new("fetch");
+
+        tasks.spawn_limited("fetch", 1, async {
+            tokio::task::yield_now().await;
+            panic!("task failed");
+        });
+        tasks.spawn_limited("fetch", 1, async { TestAction::Done(1) });
+        assert_eq!(tasks.pending_count(&key), 1);
+
+        let action = tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(action, TestAction::Done(1)));
+        assert_eq!(tasks.pending_count(&key), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_key_pending() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        let key = TaskKey::new("fetch");
+
+        for i in 0..3 {
+            tasks.spawn_limited("fetch", 1, async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                TestAction::Done(i)
+            });
+        }
+
+        assert_eq!(tasks.cancel_key_pending(&key), 2);
+        assert_eq!(tasks.pending_count(&key), 0);
+        assert!(tasks.is_running(&key));
+
+        let action = tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+        assert!(matches!(action, TestAction::Done(0)));
+
+        let result = tokio::time::timeout(Duration::from_millis(60), rx.recv()).await;
+        assert!(result.is_err());
+        assert!(!tasks.is_running(&key));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_aborts_limited_tasks() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut tasks = TaskManager::new(tx);
+        let key = TaskKey::new("fetch");
+
+        for i in 0..3 {
+            tasks.spawn_limited("fetch", 2, async move {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                TestAction::Done(i)
+            });
+        }
+
+        assert_eq!(tasks.running_keys().count(), 1);
+        tasks.cancel(&key);
+
+        assert!(tasks.is_empty());
+        assert_eq!(tasks.pending_count(&key), 0);
+    }
+
     #[tokio::test]
     async fn test_pause_handle_clone() {
         let (tx, _rx) = mpsc::unbounded_channel::<TestAction>();
@@ -0,0 +1,361 @@
+//! Handing the terminal back to the shell or another program
+//!
+//! A TUI keeps the terminal in raw mode, usually on the alternate screen. To
+//! suspend (Ctrl+Z) or open `$EDITOR`, it has to give the terminal back and
+//! reclaim it afterwards. [`TerminalModes`] records what the app enabled at
+//! startup so [`leave_tui`] and [`enter_tui`] undo and redo exactly that.
+//!
+//! The runtimes do this for you: see `suspend_on` on
+//! [`DispatchRuntime`](crate::DispatchRuntime) and
+//! [`EffectRuntime`](crate::EffectRuntime), and
+//! [`EffectContext::run_external_command`](crate::EffectContext::run_external_command).
+//! They also pause the event poller so it doesn't read the child's input.
+//!
+//! [`TerminalGuard`] restores the terminal when it goes out of scope, and
+//! [`PanicHookGuard`] does so before a panic message is printed, so a crash
+//! doesn't leave the shell in raw mode. `run` on both runtimes installs the
+//! panic hook for you.
+//!
+//! ```ignore
+//! let modes = TerminalModes {
+//!     mouse_capture: true,
+//!     ..TerminalModes::default()
+//! };
+//! let status = run_external_command(&mut terminal, modes, Command::new("vim").arg(&path))?;
+//! ```
+
+use std::io;
+use std::panic::{self, PanicHookInfo};
+use std::process::{Command, ExitStatus};
+use std::sync::Arc;
+use std::thread;
+
+use crossterm::event::{
+    DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+    EnableFocusChange, EnableMouseCapture, PopKeyboardEnhancementFlags,
+    PushKeyboardEnhancementFlags,
+};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen, SetTitle,
+};
+use crossterm::{cursor, execute};
+use ratatui::backend::Backend;
+use ratatui::Terminal;
+
+/// Terminal features the app enabled at startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalModes {
+    /// Whether the app runs on the alternate screen (default: true)
+    pub alternate_screen: bool,
+    /// Whether mouse capture is enabled
+    pub mouse_capture: bool,
+    /// Whether bracketed paste is enabled
+    pub bracketed_paste: bool,
+    /// Whether focus change reporting is enabled
+    pub focus_change: bool,
+    /// Whether the kitty keyboard protocol is enabled
+    /// (see [`enable_keyboard_enhancement`](crate::bus::enable_keyboard_enhancement))
+    pub keyboard_enhancement: bool,
+}
+
+impl Default for TerminalModes {
+    fn default() -> Self {
+        Self {
+            alternate_screen: true,
+            mouse_capture: false,
+            bracketed_paste: false,
+            focus_change: false,
+            keyboard_enhancement: false,
+        }
+    }
+}
+
+/// Restore the normal terminal: leave raw mode and the alternate screen
+///
+/// Mouse capture is always disabled, since the runtimes can switch it on
+/// after startup (see [`set_mouse_capture`]).
+pub fn leave_tui(modes: TerminalModes) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    execute!(stdout, DisableMouseCapture)?;
+    if modes.bracketed_paste {
+        execute!(stdout, DisableBracketedPaste)?;
+    }
+    if modes.focus_change {
+        execute!(stdout, DisableFocusChange)?;
+    }
+    if modes.keyboard_enhancement {
+        // Errors on terminals (and Windows) without the protocol; nothing to undo there
+        let _ = execute!(stdout, PopKeyboardEnhancementFlags);
+    }
+    if modes.alternate_screen {
+        execute!(stdout, LeaveAlternateScreen)?;
+    }
+    execute!(stdout, cursor::Show)?;
+    disable_raw_mode()
+}
+
+/// Take the terminal back after [`leave_tui`]
+pub fn enter_tui(modes: TerminalModes) -> io::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    if modes.alternate_screen {
+        execute!(stdout, EnterAlternateScreen)?;
+    }
+    if modes.mouse_capture {
+        execute!(stdout, EnableMouseCapture)?;
+    }
+    if modes.bracketed_paste {
+        execute!(stdout, EnableBracketedPaste)?;
+    }
+    if modes.focus_change {
+        execute!(stdout, EnableFocusChange)?;
+    }
+    if modes.keyboard_enhancement && crate::bus::supports_keyboard_enhancement()? {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(crate::bus::keyboard_enhancement_flags())
+        )?;
+    }
+    Ok(())
+}
+
+/// Turn mouse capture on or off while the TUI is running
+///
+/// With capture on the app receives clicks, drags and scrolling; with it
+/// off the terminal handles the mouse itself, so text can be selected.
+pub fn set_mouse_capture(enabled: bool) -> io::Result<()> {
+    if enabled {
+        execute!(io::stdout(), EnableMouseCapture)
+    } else {
+        execute!(io::stdout(), DisableMouseCapture)
+    }
+}
+
+/// Set the terminal window title
+///
+/// Most terminals keep the title after the app exits, so set it back (or
+/// to an empty string) during cleanup.
+pub fn set_title(title: &str) -> io::Result<()> {
+    execute!(io::stdout(), SetTitle(title))
+}
+
+/// Terminal the app runs in, as far as the environment tells
+///
+/// Only matters on Windows, where the classic console lacks features such
+/// as OSC 52 clipboard writes that other terminals support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalHost {
+    /// Windows Terminal
+    WindowsTerminal,
+    /// The classic Windows console (conhost.exe)
+    Conhost,
+    /// Any other terminal, including every terminal outside Windows
+    Other,
+}
+
+impl TerminalHost {
+    /// Detect the host from environment variables
+    pub fn detect() -> Self {
+        Self::from_env(cfg!(windows), |name| std::env::var_os(name).is_some())
+    }
+
+    fn from_env(windows: bool, is_set: impl Fn(&str) -> bool) -> Self {
+        if !windows {
+            Self::Other
+        } else if is_set("WT_SESSION") {
+            Self::WindowsTerminal
+        } else if is_set("TERM_PROGRAM") || is_set("TERM") {
+            // VS Code, WezTerm, Alacritty and mintty identify themselves
+            Self::Other
+        } else {
+            Self::Conhost
+        }
+    }
+
+    /// Whether the terminal turns OSC 52 sequences into clipboard writes
+    pub fn supports_osc52(self) -> bool {
+        self != Self::Conhost
+    }
+}
+
+/// Stop the process as if the shell sent Ctrl+Z
+///
+/// Returns once the shell resumes the process (`fg`). Does nothing on
+/// platforms without job control.
+pub fn suspend_process() -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        // SAFETY: raise has no preconditions; SIGTSTP stops the whole process
+        if unsafe { libc::raise(libc::SIGTSTP) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// Suspend the app and restore it on resume
+///
+/// The terminal is cleared afterwards so the next draw repaints everything.
+pub fn suspend<B: Backend>(terminal: &mut Terminal<B>, modes: TerminalModes) -> io::Result<()> {
+    leave_tui(modes)?;
+    let result = suspend_process();
+    enter_tui(modes)?;
+    terminal.clear()?;
+    result
+}
+
+/// Run a program in the normal terminal and wait for it to exit
+///
+/// Meant for interactive programs such as `$EDITOR` or a pager. The
+/// terminal is cleared afterwards so the next draw repaints everything.
+pub fn run_external_command<B: Backend>(
+    terminal: &mut Terminal<B>,
+    modes: TerminalModes,
+    command: &mut Command,
+) -> io::Result<ExitStatus> {
+    leave_tui(modes)?;
+    let status = command.status();
+    enter_tui(modes)?;
+    terminal.clear()?;
+    status
+}
+
+/// Enters the TUI on creation and restores the terminal when dropped
+///
+/// Dropping also happens while unwinding from a panic, so the terminal is
+/// restored even when the app doesn't exit normally.
+///
+/// ```ignore
+/// let _guard = TerminalGuard::enter(TerminalModes::default())?;
+/// let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+/// runtime.run(&mut terminal, render, map_event, should_quit).await?;
+/// // terminal restored here
+/// ```
+#[derive(Debug)]
+pub struct TerminalGuard {
+    modes: TerminalModes,
+}
+
+impl TerminalGuard {
+    /// Enable raw mode and the given modes
+    pub fn enter(modes: TerminalModes) -> io::Result<Self> {
+        enter_tui(modes)?;
+        Ok(Self { modes })
+    }
+
+    /// Modes that will be restored on drop
+    pub fn modes(&self) -> TerminalModes {
+        self.modes
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = leave_tui(self.modes);
+    }
+}
+
+type PanicHook = dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static;
+
+/// Panic hook that restores the terminal before the panic message is printed
+///
+/// Without it the message is written to the alternate screen and lost. The
+/// previously installed hook still runs, and is put back when the guard is
+/// dropped. Only panics on the installing thread restore the terminal; a
+/// panic in a background thread or task just runs the previous hook.
+pub struct PanicHookGuard {
+    previous: Arc<PanicHook>,
+}
+
+impl PanicHookGuard {
+    /// Install a hook that restores `modes`
+    pub fn install(modes: TerminalModes) -> Self {
+        Self::install_with_report(modes, || None)
+    }
+
+    /// Install a hook that restores `modes` and prints an extra report
+    ///
+    /// `report` runs after the panic message; whatever it returns is
+    /// written to stderr. Like the restore, it only runs for panics on the
+    /// installing thread.
+    pub fn install_with_report(
+        modes: TerminalModes,
+        report: impl Fn() -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        let previous: Arc<PanicHook> = Arc::from(panic::take_hook());
+        let chained = previous.clone();
+        let owner = thread::current().id();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().id() != owner {
+                chained(info);
+                return;
+            }
+            let _ = leave_tui(modes);
+            chained(info);
+            if let Some(report) = report() {
+                eprintln!("{report}");
+            }
+        }));
+        Self { previous }
+    }
+}
+
+impl Drop for PanicHookGuard {
+    fn drop(&mut self) {
+        // set_hook panics when called while unwinding; keep ours in place
+        if thread::panicking() {
+            return;
+        }
+        let previous = self.previous.clone();
+        panic::set_hook(Box::new(move |info| previous(info)));
+    }
+}
+
+impl std::fmt::Debug for PanicHookGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PanicHookGuard").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_host_from_env() {
+        let env = |vars: &'static [&'static str]| move |name: &str| vars.contains(&name);
+
+        assert_eq!(
+            TerminalHost::from_env(true, env(&["WT_SESSION"])),
+            TerminalHost::WindowsTerminal
+        );
+        assert_eq!(
+            TerminalHost::from_env(true, env(&["TERM_PROGRAM"])),
+            TerminalHost::Other
+        );
+        assert_eq!(
+            TerminalHost::from_env(true, env(&[])),
+            TerminalHost::Conhost
+        );
+        assert_eq!(TerminalHost::from_env(false, env(&[])), TerminalHost::Other);
+        assert!(!TerminalHost::Conhost.supports_osc52());
+    }
+
+    #[test]
+    fn test_panic_hook_ignores_other_threads() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let reported = Arc::new(AtomicBool::new(false));
+        let flag = reported.clone();
+        let guard = PanicHookGuard::install_with_report(TerminalModes::default(), move || {
+            flag.store(true, Ordering::SeqCst);
+            None
+        });
+
+        let result = thread::spawn(|| panic!("background failure")).join();
+        drop(guard);
+
+        assert!(result.is_err());
+        assert!(!reported.load(Ordering::SeqCst));
+    }
+}
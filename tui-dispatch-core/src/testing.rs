@@ -5,6 +5,11 @@
 //! - [`key`]: Create `KeyEvent` from string (e.g., `key("ctrl+p")`)
 //! - [`key_events`]: Create multiple `Event`s from space-separated key string
 //! - [`TestHarness`]: Generic test harness with action channel and state management
+//! - [`RenderHarness`]: Render to a test buffer, with golden-file snapshots
+//! - [`AppHarness`]: Drive a full runtime loop headlessly with scripted events
+//! - [`EffectTestHarness`]: Run reducer and effect flows with scripted effect responses
+//! - [`EventFuzzer`] and [`fuzz_events`]: Seeded random event sequences for invariant checks
+//! - `assert_reducer_invariant` (`proptest` feature): Property-test a reducer over random actions
 //! - [`ActionAssertions`]: Fluent assertion trait for action vectors
 //! - Assertion macros for verifying emitted actions
 //!
@@ -410,7 +415,7 @@ impl<A: PartialEq + Debug> ActionAssertionsEq<A> for [A] {
 ///
 /// Panics if the key string cannot be parsed.
 pub fn key(s: &str) -> KeyEvent {
-    parse_key_string(s).unwrap_or_else(|| panic!("Invalid key string: {:?}", s))
+    parse_key_string(s).unwrap_or_else(|err| panic!("Invalid key string {:?}: {}", s, err))
 }
 
 /// Create a `KeyEvent` for a character with no modifiers.
@@ -991,7 +996,10 @@ macro_rules! assert_state_matches {
 
 use ratatui::backend::{Backend, TestBackend};
 use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Style;
 use ratatui::Terminal;
+use std::path::{Path, PathBuf};
 
 /// Test harness for capturing rendered output.
 ///
@@ -1010,11 +1018,14 @@ use ratatui::Terminal;
 ///     my_component.render(frame, frame.area(), props);
 /// });
 ///
-/// // Use with insta for snapshot testing
-/// insta::assert_snapshot!(output);
+/// // Or compare against a golden file, ignoring a volatile clock area
+/// render.redact(Rect::new(70, 0, 10, 1));
+/// render.assert_snapshot("main_screen");
 /// ```
 pub struct RenderHarness {
     terminal: Terminal<TestBackend>,
+    snapshot_dir: Option<PathBuf>,
+    redactions: Vec<Rect>,
 }
 
 impl RenderHarness {
@@ -1022,7 +1033,82 @@ impl RenderHarness {
     pub fn new(width: u16, height: u16) -> Self {
         let backend = TestBackend::new(width, height);
         let terminal = Terminal::new(backend).expect("Failed to create test terminal");
-        Self { terminal }
+        Self {
+            terminal,
+            snapshot_dir: None,
+            redactions: Vec::new(),
+        }
+    }
+
+    /// Set the directory for golden snapshot files.
+    ///
+    /// Defaults to `tests/snapshots` in the crate being tested.
+    pub fn with_snapshot_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.snapshot_dir = Some(dir.into());
+        self
+    }
+
+    /// Mask a region in snapshots (e.g., timestamps or spinners).
+    ///
+    /// Redacted cells are stored and compared as [`REDACTED_SYMBOL`] with
+    /// the default style.
+    pub fn redact(&mut self, rect: Rect) -> &mut Self {
+        self.redactions.push(rect);
+        self
+    }
+
+    /// Remove all redacted regions.
+    pub fn clear_redactions(&mut self) {
+        self.redactions.clear();
+    }
+
+    /// Snapshot the last rendered buffer, with redactions applied.
+    pub fn snapshot(&self) -> BufferSnapshot {
+        BufferSnapshot::from_buffer(self.terminal.backend().buffer(), &self.redactions)
+    }
+
+    /// Compare the last rendered buffer against the golden file `<name>.snap`.
+    ///
+    /// A missing golden file is created from the current output. On
+    /// mismatch, panics with a cell-level diff of symbols and styles. Set
+    /// `TUI_DISPATCH_UPDATE_SNAPSHOTS=1` to overwrite golden files instead.
+    #[track_caller]
+    pub fn assert_snapshot(&self, name: &str) {
+        let path = self.snapshot_path(name);
+        let actual = self.snapshot();
+        let update = std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some_and(|v| v != "0");
+
+        let expected = match std::fs::read_to_string(&path) {
+            Ok(text) => BufferSnapshot::parse(&text)
+                .unwrap_or_else(|e| panic!("invalid snapshot file {}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                write_snapshot(&path, &actual);
+                return;
+            }
+            Err(e) => panic!("failed to read snapshot {}: {e}", path.display()),
+        };
+
+        if expected == actual {
+            return;
+        }
+        if update {
+            write_snapshot(&path, &actual);
+            return;
+        }
+        panic!(
+            "snapshot '{name}' does not match {}\n{}\n\
+             set {UPDATE_SNAPSHOTS_ENV}=1 to accept the new output",
+            path.display(),
+            expected.diff_report(&actual)
+        );
+    }
+
+    fn snapshot_path(&self, name: &str) -> PathBuf {
+        let dir = self.snapshot_dir.clone().unwrap_or_else(|| {
+            let root = std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_else(|| ".".into());
+            PathBuf::from(root).join("tests").join("snapshots")
+        });
+        dir.join(format!("{name}.snap"))
     }
 
     /// Render using the provided function and return the buffer.
@@ -1207,6 +1293,898 @@ pub fn buffer_rect_to_string_plain(buffer: &Buffer, rect: ratatui::layout::Rect)
     result
 }
 
+// ============================================================================
+// Snapshot Testing
+// ============================================================================
+
+/// Environment variable that makes [`RenderHarness::assert_snapshot`]
+/// overwrite golden files instead of failing.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "TUI_DISPATCH_UPDATE_SNAPSHOTS";
+
+/// Symbol stored for redacted cells.
+pub const REDACTED_SYMBOL: &str = "░";
+
+const SNAPSHOT_HEADER: &str = "# tui-dispatch snapshot";
+const MAX_REPORTED_CELLS: usize = 40;
+
+/// A single cell in a [`BufferSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotCell {
+    /// Cell symbol
+    pub symbol: String,
+    /// Compact style description (e.g., `fg=Red bg=Reset mod=B`)
+    pub style: String,
+}
+
+/// A difference between two snapshots at one cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellDiff {
+    /// Column
+    pub x: u16,
+    /// Row
+    pub y: u16,
+    /// Cell in the golden snapshot
+    pub expected: SnapshotCell,
+    /// Cell in the rendered output
+    pub actual: SnapshotCell,
+}
+
+/// Error parsing a snapshot file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotParseError {
+    /// 1-based line number
+    pub line: usize,
+    /// What went wrong
+    pub message: String,
+}
+
+impl std::fmt::Display for SnapshotParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for SnapshotParseError {}
+
+/// A comparable capture of a buffer's symbols and styles.
+///
+/// The text format keeps rows readable in code review: each row is fenced
+/// by `|`, followed by runs of non-default styles and any cells whose
+/// symbol is more than one character.
+///
+/// ```text
+/// # tui-dispatch snapshot 10x1
+/// |Hello     |
+/// # styles
+/// 0 0 5 fg=Red bg=Reset mod=B
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferSnapshot {
+    width: u16,
+    height: u16,
+    cells: Vec<SnapshotCell>,
+}
+
+impl BufferSnapshot {
+    /// Capture a buffer, masking cells inside `redactions`.
+    pub fn from_buffer(buffer: &Buffer, redactions: &[Rect]) -> Self {
+        let area = buffer.area();
+        let mut cells = Vec::with_capacity(area.area() as usize);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let redacted = redactions
+                    .iter()
+                    .any(|rect| rect.contains(Position::new(x, y)));
+                let cell = if redacted {
+                    SnapshotCell {
+                        symbol: REDACTED_SYMBOL.to_string(),
+                        style: style_token(Style::default()),
+                    }
+                } else {
+                    let cell = &buffer[(x, y)];
+                    SnapshotCell {
+                        symbol: cell.symbol().to_string(),
+                        style: style_token(cell.style()),
+                    }
+                };
+                cells.push(cell);
+            }
+        }
+        Self {
+            width: area.width,
+            height: area.height,
+            cells,
+        }
+    }
+
+    /// Snapshot dimensions as `(width, height)`.
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// Get the cell at a position.
+    pub fn cell(&self, x: u16, y: u16) -> Option<&SnapshotCell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells
+            .get(y as usize * self.width as usize + x as usize)
+    }
+
+    /// Render the snapshot in its text file format.
+    pub fn to_text(&self) -> String {
+        use std::fmt::Write;
+
+        let default_style = style_token(Style::default());
+        let mut text = format!("{SNAPSHOT_HEADER} {}x{}\n", self.width, self.height);
+        let mut styles = String::new();
+        let mut symbols = String::new();
+
+        for (y, row) in self.rows().enumerate() {
+            text.push('|');
+            let mut run: Option<(usize, usize, &str)> = None;
+            for (x, cell) in row.iter().enumerate() {
+                let mut chars = cell.symbol.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => text.push(c),
+                    _ => {
+                        text.push('?');
+                        let _ = writeln!(symbols, "{x} {y} {}", cell.symbol);
+                    }
+                }
+
+                match run {
+                    Some((start, len, style)) if style == cell.style => {
+                        run = Some((start, len + 1, style));
+                    }
+                    _ => {
+                        if let Some((start, len, style)) = run.take() {
+                            let _ = writeln!(styles, "{start} {y} {len} {style}");
+                        }
+                        if cell.style != default_style {
+                            run = Some((x, 1, cell.style.as_str()));
+                        }
+                    }
+                }
+            }
+            if let Some((start, len, style)) = run {
+                let _ = writeln!(styles, "{start} {y} {len} {style}");
+            }
+            text.push_str("|\n");
+        }
+
+        if !styles.is_empty() {
+            text.push_str("# styles\n");
+            text.push_str(&styles);
+        }
+        if !symbols.is_empty() {
+            text.push_str("# symbols\n");
+            text.push_str(&symbols);
+        }
+        text
+    }
+
+    /// Parse a snapshot from its text file format.
+    pub fn parse(text: &str) -> Result<Self, SnapshotParseError> {
+        let err = |line: usize, message: String| SnapshotParseError { line, message };
+        let last_line = text.lines().count();
+        let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+        let (_, header) = lines
+            .next()
+            .ok_or_else(|| err(1, "empty snapshot".to_string()))?;
+        let size = header
+            .strip_prefix(SNAPSHOT_HEADER)
+            .map(str::trim)
+            .ok_or_else(|| err(1, format!("expected '{SNAPSHOT_HEADER} WxH' header")))?;
+        let (width, height) = size
+            .split_once('x')
+            .and_then(|(w, h)| Some((w.parse::<u16>().ok()?, h.parse::<u16>().ok()?)))
+            .ok_or_else(|| err(1, format!("invalid size '{size}'")))?;
+
+        let default_style = style_token(Style::default());
+        let mut cells = Vec::with_capacity(width as usize * height as usize);
+        for _ in 0..height {
+            let (number, line) = lines
+                .next()
+                .ok_or_else(|| err(last_line, format!("expected {height} rows")))?;
+            let row = line
+                .strip_prefix('|')
+                .and_then(|row| row.strip_suffix('|'))
+                .ok_or_else(|| err(number, "row must be fenced by '|'".to_string()))?;
+            let before = cells.len();
+            cells.extend(row.chars().map(|c| SnapshotCell {
+                symbol: c.to_string(),
+                style: default_style.clone(),
+            }));
+            if cells.len() - before != width as usize {
+                return Err(err(number, format!("expected {width} cells")));
+            }
+        }
+
+        let mut snapshot = Self {
+            width,
+            height,
+            cells,
+        };
+
+        let mut section = "";
+        for (number, line) in lines {
+            if let Some(name) = line.strip_prefix("# ") {
+                section = match name {
+                    "styles" | "symbols" => name,
+                    other => return Err(err(number, format!("unknown section '{other}'"))),
+                };
+                continue;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            let mut parts = line.splitn(if section == "styles" { 4 } else { 3 }, ' ');
+            let mut coord = || -> Option<u16> { parts.next()?.parse().ok() };
+            let (Some(x), Some(y)) = (coord(), coord()) else {
+                return Err(err(number, "expected 'x y ...'".to_string()));
+            };
+            match section {
+                "styles" => {
+                    let len: u16 = parts
+                        .next()
+                        .and_then(|len| len.parse().ok())
+                        .ok_or_else(|| err(number, "expected run length".to_string()))?;
+                    let style = parts.next().unwrap_or_default();
+                    for offset in 0..len {
+                        let cell = snapshot
+                            .cell_mut(x.saturating_add(offset), y)
+                            .ok_or_else(|| err(number, "style run out of bounds".to_string()))?;
+                        cell.style = style.to_string();
+                    }
+                }
+                "symbols" => {
+                    let symbol = parts.next().unwrap_or_default();
+                    let cell = snapshot
+                        .cell_mut(x, y)
+                        .ok_or_else(|| err(number, "symbol out of bounds".to_string()))?;
+                    cell.symbol = symbol.to_string();
+                }
+                _ => return Err(err(number, "content outside of a section".to_string())),
+            }
+        }
+
+        Ok(snapshot)
+    }
+
+    /// List cells that differ from `actual`.
+    ///
+    /// Returns an empty list when the sizes differ; compare [`size`](Self::size) first.
+    pub fn diff(&self, actual: &BufferSnapshot) -> Vec<CellDiff> {
+        if self.size() != actual.size() {
+            return Vec::new();
+        }
+        let width = self.width as usize;
+        self.cells
+            .iter()
+            .zip(&actual.cells)
+            .enumerate()
+            .filter(|(_, (expected, actual))| expected != actual)
+            .map(|(i, (expected, actual))| CellDiff {
+                x: (i % width) as u16,
+                y: (i / width) as u16,
+                expected: expected.clone(),
+                actual: actual.clone(),
+            })
+            .collect()
+    }
+
+    /// Describe the differences from `actual` with ANSI colors.
+    pub fn diff_report(&self, actual: &BufferSnapshot) -> String {
+        use std::fmt::Write;
+
+        const RED: &str = "\x1b[31m";
+        const GREEN: &str = "\x1b[32m";
+        const RESET: &str = "\x1b[0m";
+
+        let mut report = String::new();
+        if self.size() != actual.size() {
+            let _ = writeln!(
+                report,
+                "size differs: {RED}-{}x{}{RESET} {GREEN}+{}x{}{RESET}",
+                self.width, self.height, actual.width, actual.height
+            );
+        } else {
+            let diffs = self.diff(actual);
+            let _ = writeln!(report, "{} cell(s) differ:", diffs.len());
+            for diff in diffs.iter().take(MAX_REPORTED_CELLS) {
+                let _ = write!(report, "  ({}, {})", diff.x, diff.y);
+                if diff.expected.symbol != diff.actual.symbol {
+                    let _ = write!(
+                        report,
+                        " symbol {RED}-{:?}{RESET} {GREEN}+{:?}{RESET}",
+                        diff.expected.symbol, diff.actual.symbol
+                    );
+                }
+                if diff.expected.style != diff.actual.style {
+                    let _ = write!(
+                        report,
+                        " style {RED}-[{}]{RESET} {GREEN}+[{}]{RESET}",
+                        diff.expected.style, diff.actual.style
+                    );
+                }
+                report.push('\n');
+            }
+            if diffs.len() > MAX_REPORTED_CELLS {
+                let _ = writeln!(
+                    report,
+                    "  ... and {} more",
+                    diffs.len() - MAX_REPORTED_CELLS
+                );
+            }
+        }
+
+        let _ = writeln!(report, "{RED}expected:{RESET}");
+        for row in self.rows() {
+            let _ = writeln!(report, "  |{}|", row_text(row));
+        }
+        let _ = writeln!(report, "{GREEN}actual:{RESET}");
+        for row in actual.rows() {
+            let _ = writeln!(report, "  |{}|", row_text(row));
+        }
+        report
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[SnapshotCell]> {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+
+    fn cell_mut(&mut self, x: u16, y: u16) -> Option<&mut SnapshotCell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells
+            .get_mut(y as usize * self.width as usize + x as usize)
+    }
+}
+
+impl std::fmt::Display for BufferSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+fn style_token(style: Style) -> String {
+    use crate::debug::{format_color_compact, format_modifier_compact};
+
+    let fg = format_color_compact(style.fg.unwrap_or_default());
+    let bg = format_color_compact(style.bg.unwrap_or_default());
+    let modifier = format_modifier_compact(style.add_modifier);
+    if modifier.is_empty() {
+        format!("fg={fg} bg={bg}")
+    } else {
+        format!("fg={fg} bg={bg} mod={modifier}")
+    }
+}
+
+fn row_text(row: &[SnapshotCell]) -> String {
+    row.iter().map(|cell| cell.symbol.as_str()).collect()
+}
+
+fn write_snapshot(path: &Path, snapshot: &BufferSnapshot) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .unwrap_or_else(|e| panic!("failed to create {}: {e}", parent.display()));
+    }
+    std::fs::write(path, snapshot.to_text())
+        .unwrap_or_else(|e| panic!("failed to write snapshot {}: {e}", path.display()));
+}
+
+// ============================================================================
+// Event Fuzzing
+// ============================================================================
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
+/// Keys [`EventFuzzer`] picks from unless replaced with [`EventFuzzer::keys`]
+///
+/// Navigation and editing keys plus a few characters. Quit keys (`q`,
+/// `ctrl+c`) are left out so fuzzed apps keep running.
+pub const DEFAULT_FUZZ_KEYS: &str = "up down left right home end pageup pagedown enter esc tab \
+     shift+tab backspace delete space a z 0 / ctrl+a ctrl+e shift+up shift+down";
+
+/// Seeded generator of random but valid events
+///
+/// Produces key presses from a key pool, mouse clicks, drags and scrolls
+/// inside the current terminal area, and resizes (at least 1x1). The same
+/// seed always produces the same sequence, so a failure found by
+/// [`fuzz_events`] can be replayed. It is an endless iterator, so it also
+/// feeds an [`AppHarness`]:
+///
+/// ```ignore
+/// app.send_all(EventFuzzer::new(42).take(500));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventFuzzer {
+    seed: u64,
+    rng: u64,
+    keys: Vec<KeyEvent>,
+    width: u16,
+    height: u16,
+    mouse: bool,
+    resize: bool,
+}
+
+impl EventFuzzer {
+    /// Create a fuzzer for an 80x24 terminal
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: seed,
+            keys: keys(DEFAULT_FUZZ_KEYS),
+            width: 80,
+            height: 24,
+            mouse: true,
+            resize: true,
+        }
+    }
+
+    /// Set the terminal size mouse positions start in
+    pub fn area(mut self, width: u16, height: u16) -> Self {
+        self.width = width.max(1);
+        self.height = height.max(1);
+        self
+    }
+
+    /// Replace the key pool with a space-separated key string (see [`keys`])
+    pub fn keys(mut self, key_str: &str) -> Self {
+        self.keys = keys(key_str);
+        self
+    }
+
+    /// Include mouse and scroll events (default true)
+    pub fn mouse(mut self, enabled: bool) -> Self {
+        self.mouse = enabled;
+        self
+    }
+
+    /// Include resize events (default true)
+    pub fn resize(mut self, enabled: bool) -> Self {
+        self.resize = enabled;
+        self
+    }
+
+    /// The seed this fuzzer started from
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Current terminal size, following generated resizes
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// Generate the next event
+    pub fn next_event(&mut self) -> EventKind {
+        let roll = self.below(100);
+        if self.mouse && roll < 12 {
+            let (column, row) = self.position();
+            let kind = match self.below(3) {
+                0 => MouseEventKind::Down(MouseButton::Left),
+                1 => MouseEventKind::Up(MouseButton::Left),
+                _ => MouseEventKind::Drag(MouseButton::Left),
+            };
+            return EventKind::Mouse(MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: KeyModifiers::empty(),
+            });
+        }
+        if self.mouse && roll < 24 {
+            let (column, row) = self.position();
+            let delta = if self.below(2) == 0 { -1 } else { 1 };
+            return EventKind::Scroll { column, row, delta };
+        }
+        if self.resize && roll < 28 {
+            self.width = self.below(200) as u16 + 1;
+            self.height = self.below(60) as u16 + 1;
+            return EventKind::Resize(self.width, self.height);
+        }
+        match self.keys.len() {
+            0 => EventKind::Tick,
+            len => EventKind::Key(self.keys[self.below(len as u64) as usize]),
+        }
+    }
+
+    fn position(&mut self) -> (u16, u16) {
+        let column = self.below(self.width as u64) as u16;
+        let row = self.below(self.height as u64) as u16;
+        (column, row)
+    }
+
+    /// Uniform-enough value in `0..n` (SplitMix64)
+    fn below(&mut self, n: u64) -> u64 {
+        self.rng = self.rng.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        (z ^ (z >> 31)) % n
+    }
+}
+
+impl Iterator for EventFuzzer {
+    type Item = EventKind;
+
+    fn next(&mut self) -> Option<EventKind> {
+        Some(self.next_event())
+    }
+}
+
+/// Feed `count` random events to `step`, reporting the seed on failure
+///
+/// `step` handles one event and asserts the invariants that must hold
+/// after it (no panic, cursor in bounds, selection below the item count).
+/// A panic is re-raised with the seed, the event number and the event, so
+/// the sequence can be replayed with the same seed.
+///
+/// # Example
+///
+/// ```ignore
+/// use tui_dispatch::testing::{fuzz_events, EventFuzzer};
+///
+/// let mut list = ListState::new(items);
+/// fuzz_events(EventFuzzer::new(7), 1000, |event| {
+///     for action in component.handle_event(event, list.props()) {
+///         list.apply(action);
+///     }
+///     assert!(list.selected < list.items.len());
+/// });
+/// ```
+pub fn fuzz_events(mut fuzzer: EventFuzzer, count: usize, mut step: impl FnMut(&EventKind)) {
+    let seed = fuzzer.seed();
+    for index in 0..count {
+        let event = fuzzer.next_event();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| step(&event)));
+        if let Err(payload) = result {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            panic!("fuzz_events (seed {seed}) failed at event #{index} {event:?}: {message}");
+        }
+    }
+}
+
+// ============================================================================
+// App Harness
+// ============================================================================
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::runtime::{
+    DispatchRuntime, DispatchStore, EffectContext, EffectRuntime, EffectStoreLike, EventOutcome,
+    RenderContext, ScriptStep,
+};
+
+/// Headless harness that drives a full runtime loop in tests.
+///
+/// Queue events and waits, then run a [`DispatchRuntime`] or
+/// [`EffectRuntime`] against a [`TestBackend`]. The real loop is used, so
+/// the debug layer, persistence, tasks and subscriptions behave as in the
+/// app. Each queued event is processed, along with every action it leads
+/// to, before the next one; the run ends when the script and the action
+/// queue are both exhausted, or when `should_quit` returns true.
+///
+/// Waits use `tokio::time::sleep`. With a paused clock
+/// (`#[tokio::test(start_paused = true)]` or `pause_time`) they complete
+/// instantly while still firing timers, debounces and task sleeps.
+///
+/// # Example
+///
+/// ```ignore
+/// use tui_dispatch::testing::AppHarness;
+///
+/// #[tokio::test(start_paused = true)]
+/// async fn search_flow() {
+///     let mut runtime = EffectRuntime::new(AppState::default(), reducer);
+///     let mut app = AppHarness::new(80, 24);
+///
+///     app.send_keys("/ f o o enter").wait(Duration::from_millis(300));
+///     app.run_effects(&mut runtime, render, map_event, |a| matches!(a, Action::Quit), {
+///         // Stub handler: answer the search effect without touching the network
+///         |effect, ctx| match effect {
+///             Effect::Search(query) => ctx.emit(Action::SearchDidLoad(vec![query])),
+///         }
+///     })
+///     .await
+///     .unwrap();
+///
+///     assert!(app.actions().iter().any(|a| matches!(a, Action::SearchDidLoad(_))));
+///     assert!(app.buffer_string_plain().contains("foo"));
+/// }
+/// ```
+pub struct AppHarness<A: Action> {
+    terminal: Terminal<TestBackend>,
+    steps: VecDeque<ScriptStep>,
+    history: Vec<A>,
+}
+
+impl<A: Action> AppHarness<A> {
+    /// Create a harness with a test terminal of the specified dimensions.
+    pub fn new(width: u16, height: u16) -> Self {
+        let backend = TestBackend::new(width, height);
+        let terminal = Terminal::new(backend).expect("Failed to create test terminal");
+        Self {
+            terminal,
+            steps: VecDeque::new(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Queue an event for the next run.
+    pub fn send(&mut self, event: EventKind) -> &mut Self {
+        self.steps.push_back(ScriptStep::Event(event));
+        self
+    }
+
+    /// Queue several events for the next run.
+    pub fn send_all(&mut self, events: impl IntoIterator<Item = EventKind>) -> &mut Self {
+        self.steps.extend(events.into_iter().map(ScriptStep::Event));
+        self
+    }
+
+    /// Queue key events from a space-separated key string (see [`keys`]).
+    pub fn send_keys(&mut self, key_str: &str) -> &mut Self {
+        self.send_all(keys(key_str).into_iter().map(EventKind::Key))
+    }
+
+    /// Queue a pause, letting timers and spawned tasks make progress.
+    ///
+    /// Actions those tasks send are dispatched before the next step.
+    pub fn wait(&mut self, duration: Duration) -> &mut Self {
+        self.steps.push_back(ScriptStep::Wait(duration));
+        self
+    }
+
+    /// Number of steps queued for the next run.
+    pub fn pending_steps(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Run a [`DispatchRuntime`] over the queued steps.
+    pub async fn run<S, St, FRender, FEvent, FQuit, R>(
+        &mut self,
+        runtime: &mut DispatchRuntime<S, A, St>,
+        render: FRender,
+        map_event: FEvent,
+        should_quit: FQuit,
+    ) -> std::io::Result<()>
+    where
+        S: 'static,
+        St: DispatchStore<S, A>,
+        FRender: FnMut(&mut ratatui::Frame, Rect, &S, RenderContext),
+        FEvent: FnMut(&EventKind, &S) -> R,
+        R: Into<EventOutcome<A>>,
+        FQuit: FnMut(&A) -> bool,
+    {
+        let steps = std::mem::take(&mut self.steps);
+        runtime
+            .run_script(
+                &mut self.terminal,
+                steps,
+                &mut self.history,
+                render,
+                map_event,
+                should_quit,
+            )
+            .await
+    }
+
+    /// Run an [`EffectRuntime`] over the queued steps.
+    ///
+    /// `handle_effect` can be the app's real handler or a stub that answers
+    /// effects synchronously via [`EffectContext::emit`].
+    pub async fn run_effects<S, E, St, FRender, FEvent, FQuit, FEffect, R>(
+        &mut self,
+        runtime: &mut EffectRuntime<S, A, E, St>,
+        render: FRender,
+        map_event: FEvent,
+        should_quit: FQuit,
+        handle_effect: FEffect,
+    ) -> std::io::Result<()>
+    where
+        S: 'static,
+        St: EffectStoreLike<S, A, E>,
+        FRender: FnMut(&mut ratatui::Frame, Rect, &S, RenderContext),
+        FEvent: FnMut(&EventKind, &S) -> R,
+        R: Into<EventOutcome<A>>,
+        FQuit: FnMut(&A) -> bool,
+        FEffect: FnMut(E, &mut EffectContext<A>),
+    {
+        let steps = std::mem::take(&mut self.steps);
+        runtime
+            .run_script(
+                &mut self.terminal,
+                steps,
+                &mut self.history,
+                render,
+                map_event,
+                should_quit,
+                handle_effect,
+            )
+            .await
+    }
+
+    /// Actions dispatched so far, in order (including the quit action).
+    pub fn actions(&self) -> &[A] {
+        &self.history
+    }
+
+    /// Take the dispatched action history, leaving it empty.
+    pub fn take_actions(&mut self) -> Vec<A> {
+        std::mem::take(&mut self.history)
+    }
+
+    /// The last rendered buffer.
+    pub fn buffer(&self) -> &Buffer {
+        self.terminal.backend().buffer()
+    }
+
+    /// The last rendered buffer as plain text (no ANSI codes).
+    pub fn buffer_string_plain(&self) -> String {
+        buffer_to_string_plain(self.buffer())
+    }
+
+    /// Snapshot the last rendered buffer for golden-file comparisons.
+    pub fn snapshot(&self) -> BufferSnapshot {
+        BufferSnapshot::from_buffer(self.buffer(), &[])
+    }
+
+    /// Resize the test terminal.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.terminal.backend_mut().resize(width, height);
+    }
+}
+
+// ============================================================================
+// Effect Test Harness
+// ============================================================================
+
+use crate::effect::{EffectReducer, EffectStore};
+
+/// Scripted answer to an effect: `Some(action)` resolves it, `None` passes.
+type EffectResponder<A, E> = Box<dyn FnMut(&E) -> Option<A>>;
+
+/// Upper bound on actions per [`EffectTestHarness::dispatch`], to catch
+/// responders that keep answering each other forever
+const MAX_EFFECT_STEPS: usize = 1000;
+
+/// Harness for testing reducer and effect flows without a runtime.
+///
+/// Wraps an [`EffectStore`] and answers effects with scripted responders
+/// instead of real handlers: when the reducer returns an effect, the first
+/// responder that matches it produces the action that would have come back
+/// (e.g. `PresetDidLoad` for `LoadPreset`), which is dispatched in turn.
+/// Everything runs synchronously, with no tokio tasks.
+///
+/// # Example
+///
+/// ```ignore
+/// use tui_dispatch::testing::EffectTestHarness;
+///
+/// let mut harness = EffectTestHarness::new(AppState::default(), reducer);
+/// harness.on_effect(|effect| match effect {
+///     Effect::LoadPreset(name) => Some(Action::PresetDidLoad(preset(name))),
+///     _ => None,
+/// });
+///
+/// harness.dispatch(Action::SelectPreset("dark".into()));
+///
+/// assert_eq!(harness.state().preset.name, "dark");
+/// assert!(harness.unhandled().is_empty());
+/// ```
+pub struct EffectTestHarness<S, A, E> {
+    store: EffectStore<S, A, E>,
+    responders: Vec<EffectResponder<A, E>>,
+    actions: Vec<A>,
+    effects: Vec<E>,
+    /// Indices into `effects`
+    unhandled: Vec<usize>,
+}
+
+impl<S, A: Action, E> EffectTestHarness<S, A, E> {
+    /// Create a harness with the given initial state and reducer.
+    pub fn new(state: S, reducer: EffectReducer<S, A, E>) -> Self {
+        Self {
+            store: EffectStore::new(state, reducer),
+            responders: Vec::new(),
+            actions: Vec::new(),
+            effects: Vec::new(),
+            unhandled: Vec::new(),
+        }
+    }
+
+    /// Add a responder for effects.
+    ///
+    /// Responders are tried in the order they were added; the first to
+    /// return `Some(action)` resolves the effect with that action.
+    pub fn on_effect(&mut self, responder: impl FnMut(&E) -> Option<A> + 'static) -> &mut Self {
+        self.responders.push(Box::new(responder));
+        self
+    }
+
+    /// Dispatch an action, then every action the responders resolve effects with.
+    ///
+    /// Follow-up actions are processed in the order their effects were
+    /// produced. Panics if the chain does not settle within 1000 actions.
+    pub fn dispatch(&mut self, action: A) -> &mut Self {
+        let mut queue = VecDeque::from([action]);
+        let mut steps = 0;
+
+        while let Some(action) = queue.pop_front() {
+            steps += 1;
+            assert!(
+                steps <= MAX_EFFECT_STEPS,
+                "effect chain did not settle after {MAX_EFFECT_STEPS} actions (last: {})",
+                action.name()
+            );
+
+            self.actions.push(action.clone());
+            for effect in self.store.dispatch(action).effects {
+                match self.responders.iter_mut().find_map(|r| r(&effect)) {
+                    Some(response) => queue.push_back(response),
+                    None => self.unhandled.push(self.effects.len()),
+                }
+                self.effects.push(effect);
+            }
+        }
+        self
+    }
+
+    /// Dispatch several actions in order (see [`Self::dispatch`]).
+    pub fn dispatch_all(&mut self, actions: impl IntoIterator<Item = A>) -> &mut Self {
+        for action in actions {
+            self.dispatch(action);
+        }
+        self
+    }
+
+    /// Current state.
+    pub fn state(&self) -> &S {
+        self.store.state()
+    }
+
+    /// Mutable access to the state, e.g. for test setup.
+    pub fn state_mut(&mut self) -> &mut S {
+        self.store.state_mut()
+    }
+
+    /// Every action dispatched so far, including responder results.
+    pub fn actions(&self) -> &[A] {
+        &self.actions
+    }
+
+    /// Every effect produced so far, in order.
+    pub fn effects(&self) -> &[E] {
+        &self.effects
+    }
+
+    /// Effects that no responder matched, in order.
+    pub fn unhandled(&self) -> Vec<&E> {
+        self.unhandled.iter().map(|&i| &self.effects[i]).collect()
+    }
+
+    /// Take and clear the recorded actions.
+    pub fn take_actions(&mut self) -> Vec<A> {
+        std::mem::take(&mut self.actions)
+    }
+
+    /// Take and clear the recorded effects (unhandled ones included).
+    pub fn take_effects(&mut self) -> Vec<E> {
+        self.unhandled.clear();
+        std::mem::take(&mut self.effects)
+    }
+}
+
 // ============================================================================
 // Time Control (Feature-gated)
 // ============================================================================
@@ -1255,6 +2233,86 @@ pub async fn advance_time(duration: std::time::Duration) {
     tokio::time::advance(duration).await;
 }
 
+// ============================================================================
+// Property Testing
+// ============================================================================
+
+/// Most actions applied per generated case in [`assert_reducer_invariant`]
+#[cfg(feature = "proptest")]
+pub const MAX_INVARIANT_ACTIONS: usize = 32;
+
+/// Property-test that a reducer keeps an invariant (requires the `proptest` feature)
+///
+/// Generates a starting state from `states` and up to
+/// [`MAX_INVARIANT_ACTIONS`] actions from `actions`, applies them with
+/// `reducer` and checks `invariant` on the starting state and after every
+/// action. On failure, the case is shrunk and the panic message names the
+/// action that broke the invariant and the minimal failing input.
+///
+/// `#[derive(ArbitraryAction)]` provides `any::<Action>()`. The number of
+/// cases follows proptest's `PROPTEST_CASES` environment variable.
+///
+/// # Example
+///
+/// ```ignore
+/// use tui_dispatch::proptest::prelude::*;
+/// use tui_dispatch::testing::assert_reducer_invariant;
+///
+/// #[derive(Action, ArbitraryAction, Clone, Debug)]
+/// enum Action {
+///     Next,
+///     Prev,
+///     SetItems(#[arbitrary(strategy = "0..20usize")] usize),
+/// }
+///
+/// #[test]
+/// fn selection_stays_in_range() {
+///     let states = (0..20usize).prop_map(|len| ListState { len, selected: 0 });
+///     assert_reducer_invariant(states, any::<Action>(), reducer, |state| {
+///         state.len == 0 || state.selected < state.len
+///     });
+/// }
+/// ```
+#[cfg(feature = "proptest")]
+pub fn assert_reducer_invariant<S, A>(
+    states: impl proptest::strategy::Strategy<Value = S>,
+    actions: impl proptest::strategy::Strategy<Value = A>,
+    reducer: impl Fn(&mut S, A) -> bool,
+    invariant: impl Fn(&S) -> bool,
+) where
+    S: Debug,
+    A: Debug,
+{
+    use proptest::collection::vec;
+    use proptest::test_runner::{Config, TestCaseError, TestRunner};
+
+    let config = Config {
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let cases = (states, vec(actions, 1..=MAX_INVARIANT_ACTIONS));
+    let result = TestRunner::new(config).run(&cases, |(mut state, actions)| {
+        if !invariant(&state) {
+            return Err(TestCaseError::fail(format!(
+                "starting state breaks the invariant: {state:?}"
+            )));
+        }
+        for (index, action) in actions.into_iter().enumerate() {
+            let name = format!("{action:?}");
+            reducer(&mut state, action);
+            if !invariant(&state) {
+                return Err(TestCaseError::fail(format!(
+                    "action #{index} {name} broke the invariant: {state:?}"
+                )));
+            }
+        }
+        Ok(())
+    });
+    if let Err(err) = result {
+        panic!("reducer invariant failed: {err}");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1440,6 +2498,116 @@ mod tests {
         assert_eq!(harness.size(), (100, 30));
     }
 
+    fn snapshot_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "tui-dispatch-snapshots-{}-{test}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn render_styled(harness: &mut RenderHarness, text: &'static str) {
+        use ratatui::style::Stylize;
+        use ratatui::widgets::Paragraph;
+
+        harness.render(|frame| {
+            frame.render_widget(Paragraph::new(text.red().bold()), frame.area());
+        });
+    }
+
+    #[test]
+    fn test_buffer_snapshot_roundtrip() {
+        let mut harness = RenderHarness::new(8, 2);
+        render_styled(&mut harness, "Hi |x|");
+        let snapshot = harness.snapshot();
+
+        let text = snapshot.to_text();
+        assert!(text.starts_with("# tui-dispatch snapshot 8x2\n|Hi |x|  |\n"));
+        assert!(text.contains("# styles\n0 0 6 fg=Red bg=Reset mod=B\n"));
+        assert_eq!(BufferSnapshot::parse(&text).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_buffer_snapshot_multi_char_symbol() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer[(1, 0)].set_symbol("e\u{301}");
+        let snapshot = BufferSnapshot::from_buffer(&buffer, &[]);
+
+        let text = snapshot.to_text();
+        assert!(text.contains("| ? |"));
+        let parsed = BufferSnapshot::parse(&text).unwrap();
+        assert_eq!(parsed.cell(1, 0).unwrap().symbol, "e\u{301}");
+        assert_eq!(parsed, snapshot);
+    }
+
+    #[test]
+    fn test_buffer_snapshot_parse_errors() {
+        let err = BufferSnapshot::parse("nope").unwrap_err();
+        assert_eq!(err.line, 1);
+
+        let err = BufferSnapshot::parse("# tui-dispatch snapshot 3x1\n|ab|\n").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn test_buffer_snapshot_diff() {
+        let mut harness = RenderHarness::new(6, 1);
+        render_styled(&mut harness, "abc");
+        let expected = harness.snapshot();
+        render_styled(&mut harness, "abd");
+        let actual = harness.snapshot();
+
+        let diffs = expected.diff(&actual);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!((diffs[0].x, diffs[0].y), (2, 0));
+        assert_eq!(diffs[0].expected.symbol, "c");
+        assert_eq!(diffs[0].actual.symbol, "d");
+
+        let report = expected.diff_report(&actual);
+        assert!(report.contains("1 cell(s) differ"));
+        assert!(report.contains("(2, 0) symbol"));
+    }
+
+    #[test]
+    fn test_snapshot_redaction() {
+        let mut harness = RenderHarness::new(6, 1);
+        harness.redact(Rect::new(3, 0, 3, 1));
+        render_styled(&mut harness, "12:00");
+        let first = harness.snapshot();
+        render_styled(&mut harness, "12:59");
+        let second = harness.snapshot();
+
+        assert_eq!(first, second);
+        assert_eq!(first.cell(4, 0).unwrap().symbol, REDACTED_SYMBOL);
+        assert_eq!(first.cell(4, 0).unwrap().style, "fg=Reset bg=Reset");
+    }
+
+    #[test]
+    fn test_assert_snapshot_creates_and_compares() {
+        let dir = snapshot_dir("compare");
+        let mut harness = RenderHarness::new(6, 1).with_snapshot_dir(&dir);
+
+        render_styled(&mut harness, "hello");
+        harness.assert_snapshot("greeting");
+        assert!(dir.join("greeting.snap").exists());
+        harness.assert_snapshot("greeting");
+
+        render_styled(&mut harness, "hullo");
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            harness.assert_snapshot("greeting");
+        }));
+        let message = result
+            .unwrap_err()
+            .downcast::<String>()
+            .map(|message| *message)
+            .unwrap_or_default();
+        assert!(message.contains("does not match"));
+        assert!(message.contains("(1, 0) symbol"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     // complete_action tests
     #[test]
     fn test_complete_action() {
@@ -1470,4 +2638,611 @@ mod tests {
         assert_state!(harness, count, 42);
         assert_state!(harness, name, "test".to_string());
     }
+
+    // AppHarness tests
+    #[derive(Clone, Debug, PartialEq)]
+    enum AppAction {
+        Inc,
+        Load,
+        Loaded(i32),
+        Resized(u16, u16),
+        Quit,
+    }
+
+    impl Action for AppAction {
+        fn name(&self) -> &'static str {
+            match self {
+                AppAction::Inc => "Inc",
+                AppAction::Load => "Load",
+                AppAction::Loaded(_) => "Loaded",
+                AppAction::Resized(..) => "Resized",
+                AppAction::Quit => "Quit",
+            }
+        }
+    }
+
+    impl crate::ActionParams for AppAction {
+        fn params(&self) -> String {
+            match self {
+                AppAction::Loaded(value) => value.to_string(),
+                _ => String::new(),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum AppEffect {
+        Fetch,
+    }
+
+    fn app_reducer(state: &mut i32, action: AppAction) -> bool {
+        match action {
+            AppAction::Inc => {
+                *state += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn app_effect_reducer(
+        state: &mut i32,
+        action: AppAction,
+    ) -> crate::effect::DispatchResult<AppEffect> {
+        match action {
+            AppAction::Load => crate::effect::DispatchResult::effect(AppEffect::Fetch),
+            AppAction::Loaded(value) => {
+                *state = value;
+                crate::effect::DispatchResult::changed()
+            }
+            _ => crate::effect::DispatchResult::unchanged(),
+        }
+    }
+
+    fn app_map_event(event: &EventKind, _state: &i32) -> Option<AppAction> {
+        match event {
+            EventKind::Key(k) if k.code == KeyCode::Char('+') => Some(AppAction::Inc),
+            EventKind::Key(k) if k.code == KeyCode::Char('l') => Some(AppAction::Load),
+            EventKind::Key(k) if k.code == KeyCode::Char('q') => Some(AppAction::Quit),
+            _ => None,
+        }
+    }
+
+    fn app_render(frame: &mut ratatui::Frame, area: Rect, state: &i32, _ctx: RenderContext) {
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(format!("count: {state}")),
+            area,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_app_harness_runs_dispatch_runtime() {
+        let mut runtime = DispatchRuntime::new(0, app_reducer);
+        let mut app = AppHarness::new(20, 2);
+
+        app.send_keys("+ + x +");
+        app.run(&mut runtime, app_render, app_map_event, |a| {
+            *a == AppAction::Quit
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            app.actions(),
+            [AppAction::Inc, AppAction::Inc, AppAction::Inc]
+        );
+        assert_eq!(*runtime.state(), 3);
+        assert!(app.buffer_string_plain().contains("count: 3"));
+        assert_eq!(app.pending_steps(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_app_harness_stops_on_quit() {
+        let mut runtime = DispatchRuntime::new(0, app_reducer);
+        let mut app = AppHarness::new(20, 2);
+
+        app.send_keys("+ q +");
+        app.run(&mut runtime, app_render, app_map_event, |a| {
+            *a == AppAction::Quit
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(app.take_actions(), vec![AppAction::Inc, AppAction::Quit]);
+        assert_eq!(*runtime.state(), 1);
+        assert!(app.actions().is_empty());
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    struct AppContext;
+
+    impl crate::BindingContext for AppContext {
+        fn name(&self) -> &'static str {
+            "app"
+        }
+
+        fn from_name(name: &str) -> Option<Self> {
+            (name == "app").then_some(AppContext)
+        }
+
+        fn all() -> &'static [Self] {
+            &[AppContext]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_global_hotkeys_run_before_map_event() {
+        let mut keys = crate::Keybindings::<AppContext>::new();
+        keys.add_global("inc", vec!["i".into()]);
+        keys.add_global("unmapped", vec!["l".into()]);
+        let hotkeys = crate::GlobalHotkeys::new(keys)
+            .command("inc", AppAction::Inc)
+            .skip_when(|state: &i32| *state >= 2);
+        let mut runtime = DispatchRuntime::new(0, app_reducer).with_global_hotkeys(hotkeys);
+        let mut app = AppHarness::new(20, 2);
+
+        // `l` has no hotkey action and falls through to `map_event`; the
+        // third `i` is skipped and `map_event` ignores it
+        app.send_keys("l i i i +");
+        app.run(&mut runtime, app_render, app_map_event, |a| {
+            *a == AppAction::Quit
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            app.actions(),
+            [
+                AppAction::Load,
+                AppAction::Inc,
+                AppAction::Inc,
+                AppAction::Inc
+            ]
+        );
+        assert_eq!(*runtime.state(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_render_context_frame_timing() {
+        let mut runtime = DispatchRuntime::new(0, app_reducer);
+        let mut app = AppHarness::new(20, 2);
+        let mut frames = Vec::new();
+
+        app.send_keys("+")
+            .wait(Duration::from_millis(20))
+            .send_keys("+");
+        app.run(
+            &mut runtime,
+            |frame, area, state, ctx| {
+                frames.push(ctx);
+                app_render(frame, area, state, ctx);
+            },
+            app_map_event,
+            |a| *a == AppAction::Quit,
+        )
+        .await
+        .unwrap();
+
+        let indices: Vec<u64> = frames.iter().map(|ctx| ctx.frame_index).collect();
+        assert_eq!(indices, [0, 1, 2]);
+        assert_eq!(frames[0].delta_time, Duration::ZERO);
+        assert!(frames[2].delta_time >= Duration::from_millis(20));
+        assert!(frames[2].timestamp >= frames[1].timestamp + frames[2].delta_time);
+    }
+
+    #[tokio::test]
+    async fn test_app_harness_stub_effects() {
+        let mut runtime = EffectRuntime::new(0, app_effect_reducer);
+        let mut app = AppHarness::new(20, 2);
+
+        app.send_keys("l");
+        app.run_effects(
+            &mut runtime,
+            app_render,
+            app_map_event,
+            |_| false,
+            |effect, ctx| match effect {
+                AppEffect::Fetch => ctx.emit(AppAction::Loaded(42)),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(app.actions(), [AppAction::Load, AppAction::Loaded(42)]);
+        assert!(app.buffer_string_plain().contains("count: 42"));
+    }
+
+    #[tokio::test]
+    async fn test_app_harness_wait_lets_tasks_finish() {
+        let mut runtime = EffectRuntime::new(0, app_effect_reducer);
+        let mut app = AppHarness::new(20, 2);
+
+        app.send_keys("l")
+            .wait(Duration::from_millis(100))
+            .send_keys("+");
+        app.run_effects(
+            &mut runtime,
+            app_render,
+            app_map_event,
+            |_| false,
+            |effect, ctx| match effect {
+                AppEffect::Fetch => {
+                    let tx = ctx.action_tx().clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        let _ = tx.send(AppAction::Loaded(7));
+                    });
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            app.actions(),
+            [AppAction::Load, AppAction::Loaded(7), AppAction::Inc]
+        );
+        assert_eq!(*runtime.state(), 8);
+        assert!(app.buffer_string_plain().contains("count: 8"));
+    }
+
+    #[tokio::test]
+    async fn test_external_command_needs_terminal() {
+        let mut runtime = EffectRuntime::new(0, app_effect_reducer);
+        let mut app = AppHarness::new(20, 2);
+
+        app.send_keys("l");
+        app.run_effects(
+            &mut runtime,
+            app_render,
+            app_map_event,
+            |_| false,
+            |effect, ctx| match effect {
+                AppEffect::Fetch => {
+                    ctx.run_external_command(std::process::Command::new("vi"), |status| {
+                        let unsupported = status
+                            .is_err_and(|error| error.kind() == std::io::ErrorKind::Unsupported);
+                        AppAction::Loaded(if unsupported { -1 } else { 0 })
+                    })
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(app.actions(), [AppAction::Load, AppAction::Loaded(-1)]);
+    }
+
+    #[tokio::test]
+    async fn test_suspend_action_is_not_dispatched() {
+        let mut runtime =
+            DispatchRuntime::new(0, app_reducer).suspend_on(|a| *a == AppAction::Load);
+        let mut app = AppHarness::new(20, 2);
+
+        app.send_keys("+ l +");
+        app.run(&mut runtime, app_render, app_map_event, |_| false)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            app.actions(),
+            [AppAction::Inc, AppAction::Load, AppAction::Inc]
+        );
+        assert_eq!(*runtime.state(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_mouse_capture_toggles_from_actions() {
+        let mut runtime = DispatchRuntime::new(0, app_reducer)
+            .mouse_capture_on(|a, on| (*a == AppAction::Load).then_some(!on));
+        let mut app = AppHarness::new(20, 2);
+        let mut captures = Vec::new();
+
+        app.send_keys("l + l");
+        app.run(
+            &mut runtime,
+            |frame, area, state, ctx| {
+                captures.push(ctx.mouse_capture);
+                app_render(frame, area, state, ctx);
+            },
+            app_map_event,
+            |_| false,
+        )
+        .await
+        .unwrap();
+
+        // Toggling redraws even though the reducer ignores `Load`
+        assert_eq!(captures, [false, true, true, false]);
+        assert!(!runtime.mouse_capture());
+        assert_eq!(*runtime.state(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resize_storm_coalesces() {
+        let mut runtime = EffectRuntime::new(0, app_effect_reducer).on_resize(AppAction::Resized);
+        let mut app = AppHarness::new(20, 2);
+        let mut sizes = Vec::new();
+        let mut resize_events = 0;
+
+        // Two bursts: a 50-step drag, then a short one after a pause
+        app.send_all((1..=50).map(|i| EventKind::Resize(20 + i, 2 + i % 5)));
+        app.wait(Duration::from_millis(10));
+        app.send_all([EventKind::Resize(30, 4), EventKind::Resize(32, 5)]);
+        app.run_effects(
+            &mut runtime,
+            |frame, area, state, ctx| {
+                sizes.push(ctx.terminal_size);
+                app_render(frame, area, state, ctx);
+            },
+            |event, state| {
+                if matches!(event, EventKind::Resize(..)) {
+                    resize_events += 1;
+                }
+                app_map_event(event, state)
+            },
+            |_| false,
+            |_, _| {},
+        )
+        .await
+        .unwrap();
+
+        let resized: Vec<_> = app
+            .actions()
+            .iter()
+            .filter(|a| matches!(a, AppAction::Resized(..)))
+            .cloned()
+            .collect();
+        assert_eq!(
+            resized,
+            [AppAction::Resized(70, 2), AppAction::Resized(32, 5)]
+        );
+        assert_eq!(resize_events, 2);
+        assert_eq!(sizes, [(20, 2), (70, 2), (32, 5)]);
+    }
+
+    #[cfg(feature = "subscriptions")]
+    #[tokio::test]
+    async fn test_subscriptions_pause_on_blur() {
+        let mut runtime = EffectRuntime::new(0, app_effect_reducer).pause_subscriptions_on_blur();
+        let mut app = AppHarness::new(20, 2);
+
+        app.send(EventKind::FocusLost);
+        app.run_effects(
+            &mut runtime,
+            app_render,
+            app_map_event,
+            |_| false,
+            |_, _| {},
+        )
+        .await
+        .unwrap();
+        assert!(runtime.subscriptions().is_paused());
+
+        app.send(EventKind::FocusGained);
+        app.run_effects(
+            &mut runtime,
+            app_render,
+            app_map_event,
+            |_| false,
+            |_, _| {},
+        )
+        .await
+        .unwrap();
+        assert!(!runtime.subscriptions().is_paused());
+    }
+
+    #[tokio::test]
+    async fn test_crash_report_keeps_last_actions() {
+        let mut runtime = EffectRuntime::new(0, app_effect_reducer).with_crash_report(2);
+        let mut app = AppHarness::new(20, 2);
+        assert_eq!(runtime.crash_report(), None);
+
+        app.send_keys("+ + l");
+        app.run_effects(
+            &mut runtime,
+            app_render,
+            app_map_event,
+            |_| false,
+            |effect, ctx| match effect {
+                AppEffect::Fetch => ctx.emit(AppAction::Loaded(42)),
+            },
+        )
+        .await
+        .unwrap();
+
+        let report = runtime.crash_report().unwrap();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[0], "Last 2 actions before the panic:");
+        assert!(lines[1].starts_with("  #2 ") && lines[1].ends_with("] Load"));
+        assert!(lines[2].starts_with("  #3 ") && lines[2].ends_with("] Loaded 42"));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_hooks_run_in_order() {
+        let flushed = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let first = flushed.clone();
+        let second = flushed.clone();
+        let mut runtime = DispatchRuntime::new(0, app_reducer)
+            .on_shutdown(move |state: &i32| {
+                let (log, state) = (first.clone(), *state);
+                async move {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                    log.borrow_mut().push(format!("flush {state}"));
+                }
+            })
+            .on_shutdown(move |_: &i32| {
+                let log = second.clone();
+                async move { log.borrow_mut().push("disconnect".to_string()) }
+            });
+        let mut app = AppHarness::new(20, 2);
+
+        app.send_keys("+ + q");
+        app.run(&mut runtime, app_render, app_map_event, |a| {
+            *a == AppAction::Quit
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(*flushed.borrow(), ["flush 2", "disconnect"]);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_hooks_time_out() {
+        let finished = std::rc::Rc::new(std::cell::Cell::new(false));
+        let flag = finished.clone();
+        let mut runtime = EffectRuntime::new(0, app_effect_reducer)
+            .with_shutdown_timeout(Duration::from_millis(20))
+            .on_shutdown(move |_: &i32| {
+                let flag = flag.clone();
+                async move {
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                    flag.set(true);
+                }
+            });
+        let mut app = AppHarness::new(20, 2);
+
+        app.send_keys("q");
+        app.run_effects(
+            &mut runtime,
+            app_render,
+            app_map_event,
+            |a| *a == AppAction::Quit,
+            |_effect, _ctx| {},
+        )
+        .await
+        .unwrap();
+
+        assert!(!finished.get());
+    }
+
+    #[test]
+    fn test_effect_harness_resolves_effects() {
+        let mut harness = EffectTestHarness::new(0, app_effect_reducer);
+        harness.on_effect(|effect| match effect {
+            AppEffect::Fetch => Some(AppAction::Loaded(42)),
+        });
+
+        harness.dispatch(AppAction::Load);
+
+        assert_eq!(*harness.state(), 42);
+        assert_eq!(harness.actions(), [AppAction::Load, AppAction::Loaded(42)]);
+        assert_eq!(harness.effects(), [AppEffect::Fetch]);
+        assert!(harness.unhandled().is_empty());
+    }
+
+    #[test]
+    fn test_effect_harness_records_unhandled() {
+        let mut harness = EffectTestHarness::new(0, app_effect_reducer);
+
+        harness.dispatch_all([AppAction::Load, AppAction::Inc, AppAction::Load]);
+
+        assert_eq!(*harness.state(), 0);
+        assert_eq!(harness.unhandled(), [&AppEffect::Fetch, &AppEffect::Fetch]);
+        assert_eq!(harness.take_effects().len(), 2);
+        assert!(harness.unhandled().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "effect chain did not settle")]
+    fn test_effect_harness_detects_loops() {
+        let mut harness = EffectTestHarness::new(0, app_effect_reducer);
+        harness.on_effect(|_| Some(AppAction::Load));
+
+        harness.dispatch(AppAction::Load);
+    }
+
+    #[test]
+    fn test_event_fuzzer_is_reproducible() {
+        let events = |seed| format!("{:?}", EventFuzzer::new(seed).take(200).collect::<Vec<_>>());
+        let (first, second, other) = (events(7), events(7), events(8));
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_event_fuzzer_stays_in_bounds() {
+        let mut fuzzer = EventFuzzer::new(3).area(20, 5).keys("j k");
+        for _ in 0..1000 {
+            let (width, height) = fuzzer.size();
+            match fuzzer.next_event() {
+                EventKind::Mouse(mouse) => assert!(mouse.column < width && mouse.row < height),
+                EventKind::Scroll { column, row, .. } => assert!(column < width && row < height),
+                EventKind::Resize(width, height) => assert!(width > 0 && height > 0),
+                EventKind::Key(key) => {
+                    assert!(matches!(key.code, KeyCode::Char('j') | KeyCode::Char('k')))
+                }
+                other => panic!("unexpected event {other:?}"),
+            }
+        }
+
+        let keys_only = EventFuzzer::new(3).mouse(false).resize(false);
+        assert!(keys_only
+            .take(500)
+            .all(|event| matches!(event, EventKind::Key(_))));
+    }
+
+    #[test]
+    fn test_fuzz_events_keeps_selection_in_range() {
+        let len = 5;
+        let mut selected = 0usize;
+        fuzz_events(EventFuzzer::new(11), 500, |event| {
+            if let EventKind::Key(key) = event {
+                match key.code {
+                    KeyCode::Up => selected = selected.saturating_sub(1),
+                    KeyCode::Down => selected = (selected + 1).min(len - 1),
+                    KeyCode::End => selected = len - 1,
+                    KeyCode::Home => selected = 0,
+                    _ => {}
+                }
+            }
+            assert!(selected < len);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "fuzz_events (seed 5) failed at event #")]
+    fn test_fuzz_events_reports_seed() {
+        let mut selected = 0usize;
+        fuzz_events(EventFuzzer::new(5).keys("down"), 100, |event| {
+            if let EventKind::Key(_) = event {
+                selected += 1;
+            }
+            assert!(selected < 3, "selection out of range");
+        });
+    }
+
+    #[cfg(feature = "proptest")]
+    fn clamped_reducer(state: &mut (usize, usize), delta: i8) -> bool {
+        let (len, selected) = state;
+        *selected = selected.saturating_add_signed(delta as isize);
+        *selected = (*selected).min(len.saturating_sub(1));
+        true
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn test_reducer_invariant_holds() {
+        use proptest::prelude::*;
+
+        let states = (1..10usize).prop_map(|len| (len, 0));
+        assert_reducer_invariant(states, -2..=2i8, clamped_reducer, |(len, selected)| {
+            selected < len
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    #[should_panic(expected = "broke the invariant")]
+    fn test_reducer_invariant_reports_action() {
+        use proptest::prelude::*;
+
+        let states = (1..10usize).prop_map(|len| (len, 0));
+        let unclamped = |state: &mut (usize, usize), delta: i8| {
+            state.1 = state.1.saturating_add_signed(delta as isize);
+            true
+        };
+        assert_reducer_invariant(states, -2..=2i8, unclamped, |(len, selected)| {
+            selected < len
+        });
+    }
 }
@@ -0,0 +1,299 @@
+//! Color palette shared by components and the debug layer
+//!
+//! A [`Theme`] names colors by role rather than by hue, so a whole app can be
+//! restyled in one place. Keep it in app state (or a constant) and pass it to
+//! components through their `theme` prop.
+//! It also carries the [`Strings`] table of built-in labels, for apps that
+//! translate them.
+//! [`DebugStyle::from_theme`](crate::debug::DebugStyle::from_theme) styles
+//! the debug layer to match.
+//!
+//! Themes can be loaded from TOML with the `toml` feature. Unset roles come
+//! from the `base` theme (`"dark"` by default):
+//!
+//! ```toml
+//! base = "light"
+//! accent = "#005f87"
+//! error = "light-red"
+//! ```
+//!
+//! Colors accept names (`"red"`, `"dark-gray"`), `#rrggbb` hex or a 0-255
+//! palette index.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+
+use crate::strings::Strings;
+
+/// Colors for each UI role, plus the labels components draw
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Focus, selection and other highlights
+    pub accent: Color,
+    /// Background of panels, popups and modals
+    pub surface: Color,
+    /// Borders of unfocused elements
+    pub border: Color,
+    /// Regular text
+    pub text_primary: Color,
+    /// Placeholders, hints and secondary text
+    pub text_muted: Color,
+    /// Errors and destructive actions
+    pub error: Color,
+    /// Warnings and search matches
+    pub warning: Color,
+    /// Success messages and additions
+    pub success: Color,
+    /// Built-in labels (English by default)
+    pub strings: Strings,
+}
+
+impl Theme {
+    /// Dark theme, matching the components' built-in colors
+    pub const DARK: Theme = Theme {
+        accent: Color::Cyan,
+        surface: Color::Rgb(30, 30, 40),
+        border: Color::DarkGray,
+        text_primary: Color::Reset,
+        text_muted: Color::DarkGray,
+        error: Color::Red,
+        warning: Color::Yellow,
+        success: Color::Green,
+        strings: Strings::ENGLISH,
+    };
+
+    /// Light theme for light terminal backgrounds
+    pub const LIGHT: Theme = Theme {
+        accent: Color::Blue,
+        surface: Color::Rgb(235, 235, 240),
+        border: Color::Gray,
+        text_primary: Color::Black,
+        text_muted: Color::DarkGray,
+        error: Color::Red,
+        warning: Color::Rgb(175, 95, 0),
+        success: Color::Rgb(0, 135, 0),
+        strings: Strings::ENGLISH,
+    };
+
+    /// The dark theme
+    pub const fn dark() -> Self {
+        Self::DARK
+    }
+
+    /// The light theme
+    pub const fn light() -> Self {
+        Self::LIGHT
+    }
+
+    /// Border style for an element with or without focus
+    pub fn border_style(&self, focused: bool) -> Style {
+        Style::default().fg(if focused { self.accent } else { self.border })
+    }
+
+    /// Style for regular text
+    pub fn text(&self) -> Style {
+        Style::default().fg(self.text_primary)
+    }
+
+    /// Style for placeholders and secondary text
+    pub fn muted(&self) -> Style {
+        Style::default().fg(self.text_muted)
+    }
+
+    /// Style for the selected row of a list
+    pub fn selected(&self) -> Style {
+        Style::default()
+            .fg(self.accent)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Load a theme from a TOML file
+    ///
+    /// Requires the `toml` feature.
+    pub fn load_from_path(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|source| ThemeError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_toml_str(&contents)
+    }
+
+    /// Parse a theme from TOML
+    ///
+    /// Requires the `toml` feature.
+    pub fn from_toml_str(contents: &str) -> Result<Self, ThemeError> {
+        #[cfg(feature = "toml")]
+        {
+            let raw: RawTheme =
+                toml::from_str(contents).map_err(|e| ThemeError::Parse(e.to_string()))?;
+            raw.resolve()
+        }
+        #[cfg(not(feature = "toml"))]
+        {
+            let _ = contents;
+            Err(ThemeError::TomlDisabled)
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DARK
+    }
+}
+
+/// Error loading a theme
+#[derive(Debug)]
+pub enum ThemeError {
+    /// Failed to read the theme file
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// Loading TOML requires the `toml` feature
+    TomlDisabled,
+    /// Theme contents could not be parsed
+    Parse(String),
+    /// `base` is not `"dark"` or `"light"`
+    UnknownBase(String),
+    /// A color value could not be parsed
+    InvalidColor { role: &'static str, value: String },
+}
+
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeError::Io { path, source } => {
+                write!(f, "Failed to read {}: {}", path.display(), source)
+            }
+            ThemeError::TomlDisabled => write!(f, "Loading themes requires the `toml` feature"),
+            ThemeError::Parse(msg) => write!(f, "Failed to parse theme: {}", msg),
+            ThemeError::UnknownBase(base) => {
+                write!(
+                    f,
+                    "Unknown base theme \"{}\" (expected dark or light)",
+                    base
+                )
+            }
+            ThemeError::InvalidColor { role, value } => {
+                write!(f, "Invalid color \"{}\" for {}", value, role)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Theme as written in a config file, before colors are parsed
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(not(feature = "toml"), allow(dead_code))]
+struct RawTheme {
+    base: Option<String>,
+    accent: Option<String>,
+    surface: Option<String>,
+    border: Option<String>,
+    text_primary: Option<String>,
+    text_muted: Option<String>,
+    error: Option<String>,
+    warning: Option<String>,
+    success: Option<String>,
+}
+
+#[cfg_attr(not(feature = "toml"), allow(dead_code))]
+impl RawTheme {
+    fn resolve(self) -> Result<Theme, ThemeError> {
+        let mut theme = match self.base.as_deref() {
+            None | Some("dark") => Theme::DARK,
+            Some("light") => Theme::LIGHT,
+            Some(other) => return Err(ThemeError::UnknownBase(other.to_string())),
+        };
+
+        let roles = [
+            ("accent", self.accent, &mut theme.accent),
+            ("surface", self.surface, &mut theme.surface),
+            ("border", self.border, &mut theme.border),
+            ("text_primary", self.text_primary, &mut theme.text_primary),
+            ("text_muted", self.text_muted, &mut theme.text_muted),
+            ("error", self.error, &mut theme.error),
+            ("warning", self.warning, &mut theme.warning),
+            ("success", self.success, &mut theme.success),
+        ];
+        for (role, value, color) in roles {
+            if let Some(value) = value {
+                *color = Color::from_str(&value)
+                    .map_err(|_| ThemeError::InvalidColor { role, value })?;
+            }
+        }
+        Ok(theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_overrides_base() {
+        let raw = RawTheme {
+            base: Some("light".into()),
+            accent: Some("#005f87".into()),
+            error: Some("light-red".into()),
+            text_muted: Some("245".into()),
+            ..Default::default()
+        };
+        let theme = raw.resolve().unwrap();
+        assert_eq!(theme.accent, Color::Rgb(0, 95, 135));
+        assert_eq!(theme.error, Color::LightRed);
+        assert_eq!(theme.text_muted, Color::Indexed(245));
+        assert_eq!(theme.surface, Theme::LIGHT.surface);
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let raw = RawTheme {
+            base: Some("solarized".into()),
+            ..Default::default()
+        };
+        assert!(matches!(raw.resolve(), Err(ThemeError::UnknownBase(_))));
+
+        let raw = RawTheme {
+            warning: Some("not-a-color".into()),
+            ..Default::default()
+        };
+        let error = raw.resolve().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid color \"not-a-color\" for warning"
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let theme = Theme::from_toml_str("accent = \"magenta\"\n").unwrap();
+        assert_eq!(theme.accent, Color::Magenta);
+        assert_eq!(theme.border, Theme::DARK.border);
+
+        let error = Theme::from_toml_str("acent = \"magenta\"\n").unwrap_err();
+        assert!(matches!(error, ThemeError::Parse(_)));
+    }
+
+    #[test]
+    fn test_border_style_follows_focus() {
+        let theme = Theme::LIGHT;
+        assert_eq!(theme.border_style(true).fg, Some(Color::Blue));
+        assert_eq!(theme.border_style(false).fg, Some(Color::Gray));
+    }
+}
@@ -0,0 +1,227 @@
+//! Thunk effects: async work carried directly by reducer results
+//!
+//! For trivial async follow-ups, defining an `Effect` enum variant and a
+//! matching `handle_effect` arm is boilerplate. A [`Thunk`] packages the
+//! async work itself, so a reducer can return it as the effect and the
+//! runtime just spawns it. Actions produced by the thunk are sent back to
+//! the main loop like any other task result.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use tui_dispatch::{DispatchResult, EffectRuntime, Thunk};
+//!
+//! fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Thunk<Action>> {
+//!     match action {
+//!         Action::Fetch => {
+//!             state.loading = true;
+//!             let url = state.url.clone();
+//!             DispatchResult::changed_with(Thunk::new(async move {
+//!                 match api::get(&url).await {
+//!                     Ok(body) => Action::DidFetch(body),
+//!                     Err(e) => Action::DidFail(e.to_string()),
+//!                 }
+//!             }))
+//!         }
+//!         Action::DidFetch(body) => { /* ... */ }
+//!     }
+//! }
+//!
+//! runtime
+//!     .run(terminal, render, map_event, should_quit, |thunk, ctx| {
+//!         ctx.spawn_thunk(thunk);
+//!     })
+//!     .await?;
+//! ```
+//!
+//! Thunks can also be a variant of a larger effect enum
+//! (`Effect::Thunk(Thunk<Action>)`) alongside declarative effects.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+type ThunkFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type ThunkFn<A> = Box<dyn FnOnce(mpsc::UnboundedSender<A>) -> ThunkFuture + Send>;
+
+/// Deferred async work that dispatches actions when it runs.
+///
+/// Create one with [`Thunk::new`] (single resulting action),
+/// [`Thunk::optional`] (zero or one action) or [`Thunk::dispatch`]
+/// (any number of actions over time).
+pub struct Thunk<A> {
+    run: ThunkFn<A>,
+}
+
+impl<A> std::fmt::Debug for Thunk<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Thunk").finish_non_exhaustive()
+    }
+}
+
+impl<A: Send + 'static> Thunk<A> {
+    /// Create a thunk whose future resolves to a single action.
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = A> + Send + 'static,
+    {
+        Self::dispatch(|tx| async move {
+            let _ = tx.send(future.await);
+        })
+    }
+
+    /// Create a thunk whose future may or may not produce an action.
+    pub fn optional<F>(future: F) -> Self
+    where
+        F: Future<Output = Option<A>> + Send + 'static,
+    {
+        Self::dispatch(|tx| async move {
+            if let Some(action) = future.await {
+                let _ = tx.send(action);
+            }
+        })
+    }
+
+    /// Create a thunk that receives a sender and can dispatch any number of
+    /// actions while it runs (e.g. progress updates).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// Thunk::dispatch(|tx| async move {
+    ///     for (i, file) in files.into_iter().enumerate() {
+    ///         upload(file).await;
+    ///         let _ = tx.send(Action::UploadProgress(i + 1));
+    ///     }
+    ///     let _ = tx.send(Action::UploadDidFinish);
+    /// })
+    /// ```
+    pub fn dispatch<F, Fut>(f: F) -> Self
+    where
+        F: FnOnce(mpsc::UnboundedSender<A>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            run: Box::new(move |tx| Box::pin(f(tx))),
+        }
+    }
+
+    /// Spawn the thunk on the tokio runtime, sending its actions to `action_tx`.
+    pub fn spawn(self, action_tx: &mpsc::UnboundedSender<A>) -> JoinHandle<()> {
+        tokio::spawn((self.run)(action_tx.clone()))
+    }
+
+    /// Run the thunk to completion and collect the actions it dispatched.
+    ///
+    /// Useful for testing reducers that return thunks.
+    pub async fn run(self) -> Vec<A> {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        (self.run)(tx).await;
+
+        let mut actions = Vec::new();
+        while let Ok(action) = rx.try_recv() {
+            actions.push(action);
+        }
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::effect::{DispatchResult, EffectStore};
+    use crate::Action;
+    use std::time::Duration;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum TestAction {
+        Fetch,
+        DidFetch(u32),
+        Progress(u32),
+    }
+
+    impl Action for TestAction {
+        fn name(&self) -> &'static str {
+            match self {
+                TestAction::Fetch => "Fetch",
+                TestAction::DidFetch(_) => "DidFetch",
+                TestAction::Progress(_) => "Progress",
+            }
+        }
+    }
+
+    fn reducer(state: &mut u32, action: TestAction) -> DispatchResult<Thunk<TestAction>> {
+        match action {
+            TestAction::Fetch => {
+                let next = *state + 1;
+                DispatchResult::effect(Thunk::new(async move { TestAction::DidFetch(next) }))
+            }
+            TestAction::DidFetch(value) => {
+                *state = value;
+                DispatchResult::changed()
+            }
+            TestAction::Progress(_) => DispatchResult::unchanged(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_thunk_from_reducer() {
+        let mut store = EffectStore::new(0, reducer);
+
+        let result = store.dispatch(TestAction::Fetch);
+        assert!(!result.changed);
+        assert_eq!(result.effects.len(), 1);
+
+        for thunk in result.effects {
+            for action in thunk.run().await {
+                store.dispatch(action);
+            }
+        }
+        assert_eq!(*store.state(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_thunk_optional() {
+        let none = Thunk::<TestAction>::optional(async { None }).run().await;
+        assert!(none.is_empty());
+
+        let some = Thunk::optional(async { Some(TestAction::DidFetch(7)) })
+            .run()
+            .await;
+        assert_eq!(some, vec![TestAction::DidFetch(7)]);
+    }
+
+    #[tokio::test]
+    async fn test_thunk_spawn_dispatches_many() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        Thunk::dispatch(|tx| async move {
+            for i in 1..=3 {
+                let _ = tx.send(TestAction::Progress(i));
+            }
+            let _ = tx.send(TestAction::DidFetch(3));
+        })
+        .spawn(&tx);
+
+        let mut received = Vec::new();
+        for _ in 0..4 {
+            let action = tokio::time::timeout(Duration::from_millis(100), rx.recv())
+                .await
+                .expect("timeout")
+                .expect("channel closed");
+            received.push(action);
+        }
+
+        assert_eq!(
+            received,
+            vec![
+                TestAction::Progress(1),
+                TestAction::Progress(2),
+                TestAction::Progress(3),
+                TestAction::DidFetch(3),
+            ]
+        );
+    }
+}
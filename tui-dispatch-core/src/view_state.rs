@@ -0,0 +1,321 @@
+//! Per-component UI positions that survive restarts
+//!
+//! [`ViewState`] remembers scroll offsets, selected indices and collapsed
+//! nodes for each component, so apps can restore exact positions between
+//! runs without adding a field per list to their own state. Keep one in
+//! app state and save it with [`ViewState::save`], or embed its
+//! [`Persist`] snapshot in the app's own.
+//!
+//! ```ignore
+//! let mut views = ViewState::<ComponentId>::load("views.json")?;
+//!
+//! // In the reducer
+//! views.set_selected(ComponentId::Files, Some(index));
+//!
+//! // When rendering, after the data has loaded
+//! views.clamp(ComponentId::Files, state.files.len());
+//! let selected = views.selected(ComponentId::Files).unwrap_or(0);
+//!
+//! // On exit
+//! views.save("views.json")?;
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::event::ComponentId;
+use crate::persistence::{Persist, PersistError, Persister};
+
+/// Saved view positions of one component
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewEntry {
+    /// Vertical scroll offset
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub scroll: usize,
+    /// Horizontal scroll offset
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub scroll_x: usize,
+    /// Selected index
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected: Option<usize>,
+    /// Keys of collapsed nodes (tree paths, section names, ...)
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub collapsed: BTreeSet<String>,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+impl ViewEntry {
+    /// Whether nothing is stored (all defaults)
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Registry of [`ViewEntry`]s keyed by component id
+///
+/// Entries are stored under [`ComponentId::name`], which keeps the saved
+/// file readable and stable across builds, so ids need distinct names.
+/// Entries left at their defaults are dropped.
+pub struct ViewState<Id> {
+    entries: BTreeMap<String, ViewEntry>,
+    _id: PhantomData<fn(Id)>,
+}
+
+impl<Id> Default for ViewState<Id> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            _id: PhantomData,
+        }
+    }
+}
+
+impl<Id> Clone for ViewState<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            _id: PhantomData,
+        }
+    }
+}
+
+impl<Id> std::fmt::Debug for ViewState<Id> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(&self.entries).finish()
+    }
+}
+
+impl<Id> PartialEq for ViewState<Id> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<Id: ComponentId> ViewState<Id> {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load saved positions, or an empty registry if the file doesn't exist
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, PersistError> {
+        let mut views = Self::new();
+        Persister::new(path)?.load_into(&mut views)?;
+        Ok(views)
+    }
+
+    /// Save positions (JSON or TOML, by file extension)
+    pub fn save(&self, path: impl Into<PathBuf>) -> Result<(), PersistError> {
+        Persister::new(path)?.save(self)
+    }
+
+    /// Stored positions of a component
+    pub fn get(&self, id: Id) -> Option<&ViewEntry> {
+        self.entries.get(id.name())
+    }
+
+    /// Update a component's positions, dropping the entry if left empty
+    pub fn update<R>(&mut self, id: Id, f: impl FnOnce(&mut ViewEntry) -> R) -> R {
+        let entry = self.entries.entry(id.name().to_string()).or_default();
+        let result = f(entry);
+        if entry.is_empty() {
+            self.entries.remove(id.name());
+        }
+        result
+    }
+
+    /// Vertical scroll offset (0 if unset)
+    pub fn scroll(&self, id: Id) -> usize {
+        self.get(id).map_or(0, |entry| entry.scroll)
+    }
+
+    /// Set the vertical scroll offset
+    pub fn set_scroll(&mut self, id: Id, scroll: usize) {
+        self.update(id, |entry| entry.scroll = scroll);
+    }
+
+    /// Horizontal scroll offset (0 if unset)
+    pub fn scroll_x(&self, id: Id) -> usize {
+        self.get(id).map_or(0, |entry| entry.scroll_x)
+    }
+
+    /// Set the horizontal scroll offset
+    pub fn set_scroll_x(&mut self, id: Id, scroll_x: usize) {
+        self.update(id, |entry| entry.scroll_x = scroll_x);
+    }
+
+    /// Selected index
+    pub fn selected(&self, id: Id) -> Option<usize> {
+        self.get(id).and_then(|entry| entry.selected)
+    }
+
+    /// Set or clear the selected index
+    pub fn set_selected(&mut self, id: Id, selected: Option<usize>) {
+        self.update(id, |entry| entry.selected = selected);
+    }
+
+    /// Whether the node `key` of a component is collapsed
+    pub fn is_collapsed(&self, id: Id, key: &str) -> bool {
+        self.get(id)
+            .is_some_and(|entry| entry.collapsed.contains(key))
+    }
+
+    /// Collapse or expand the node `key`
+    pub fn set_collapsed(&mut self, id: Id, key: impl Into<String>, collapsed: bool) {
+        let key = key.into();
+        self.update(id, |entry| {
+            if collapsed {
+                entry.collapsed.insert(key);
+            } else {
+                entry.collapsed.remove(&key);
+            }
+        });
+    }
+
+    /// Flip the node `key`, returning whether it is now collapsed
+    pub fn toggle_collapsed(&mut self, id: Id, key: impl Into<String>) -> bool {
+        let key = key.into();
+        self.update(id, |entry| {
+            if entry.collapsed.remove(&key) {
+                false
+            } else {
+                entry.collapsed.insert(key);
+                true
+            }
+        })
+    }
+
+    /// Keep the selection and scroll offset within `len` items
+    ///
+    /// Saved positions can point past the end when data changed between
+    /// runs; call this once the component's items are known.
+    pub fn clamp(&mut self, id: Id, len: usize) {
+        if self.get(id).is_none() {
+            return;
+        }
+        let last = len.saturating_sub(1);
+        self.update(id, |entry| {
+            entry.scroll = entry.scroll.min(last);
+            entry.selected = match entry.selected {
+                Some(_) if len == 0 => None,
+                selected => selected.map(|index| index.min(last)),
+            };
+        });
+    }
+
+    /// Forget a component's positions
+    pub fn remove(&mut self, id: Id) -> Option<ViewEntry> {
+        self.entries.remove(id.name())
+    }
+
+    /// Forget all positions
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of components with stored positions
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no positions are stored
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<Id> Persist for ViewState<Id> {
+    type Snapshot = BTreeMap<String, ViewEntry>;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.entries.clone()
+    }
+
+    fn hydrate(&mut self, snapshot: Self::Snapshot) {
+        self.entries = snapshot
+            .into_iter()
+            .filter(|(_, entry)| !entry.is_empty())
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Id {
+        Files,
+        Preview,
+    }
+
+    impl ComponentId for Id {
+        fn name(&self) -> &'static str {
+            match self {
+                Id::Files => "Files",
+                Id::Preview => "Preview",
+            }
+        }
+    }
+
+    #[test]
+    fn test_positions_per_component() {
+        let mut views = ViewState::<Id>::new();
+        views.set_selected(Id::Files, Some(4));
+        views.set_scroll(Id::Preview, 120);
+        views.set_collapsed(Id::Files, "src", true);
+
+        assert_eq!(views.selected(Id::Files), Some(4));
+        assert_eq!(views.scroll(Id::Files), 0);
+        assert_eq!(views.scroll(Id::Preview), 120);
+        assert!(views.is_collapsed(Id::Files, "src"));
+        assert!(!views.toggle_collapsed(Id::Files, "src"));
+
+        // Entries back at their defaults are dropped
+        views.set_selected(Id::Files, None);
+        views.set_scroll(Id::Preview, 0);
+        assert!(views.is_empty());
+    }
+
+    #[test]
+    fn test_clamp_after_data_shrinks() {
+        let mut views = ViewState::<Id>::new();
+        views.update(Id::Files, |entry| {
+            entry.selected = Some(9);
+            entry.scroll = 6;
+        });
+
+        views.clamp(Id::Files, 3);
+        assert_eq!(views.selected(Id::Files), Some(2));
+        assert_eq!(views.scroll(Id::Files), 2);
+
+        views.clamp(Id::Files, 0);
+        assert!(views.get(Id::Files).is_none());
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let path = std::env::temp_dir()
+            .join(format!("tui-dispatch-views-{}", std::process::id()))
+            .join("views.json");
+
+        let mut views = ViewState::<Id>::new();
+        views.set_selected(Id::Files, Some(2));
+        views.set_scroll_x(Id::Preview, 8);
+        views.save(&path).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("\"Files\""));
+        assert!(!contents.contains("collapsed"));
+        assert_eq!(ViewState::<Id>::load(&path).unwrap(), views);
+
+        let _ = std::fs::remove_file(&path);
+        assert!(ViewState::<Id>::load(&path).unwrap().is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 //! Procedural macros for tui-dispatch
 
-use darling::{FromDeriveInput, FromField, FromVariant};
+use darling::{FromDeriveInput, FromField, FromMeta, FromVariant};
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use quote::{format_ident, quote};
@@ -12,15 +12,51 @@ use syn::{parse_macro_input, DeriveInput};
 #[darling(attributes(action), supports(enum_any))]
 struct ActionOpts {
     ident: syn::Ident,
+    vis: syn::Visibility,
     data: darling::ast::Data<ActionVariant, ()>,
 
     /// Enable automatic category inference from variant name prefixes
     #[darling(default)]
     infer_categories: bool,
 
-    /// Generate dispatcher trait
+    /// Generate dispatcher trait (`generate_dispatcher(async)` for an async one)
     #[darling(default)]
-    generate_dispatcher: bool,
+    generate_dispatcher: Option<DispatcherOpts>,
+
+    /// Generate snake_case constructor fns for variants with fields
+    #[darling(default)]
+    constructors: bool,
+}
+
+/// Options for `#[action(generate_dispatcher)]`
+#[derive(Debug, Default)]
+struct DispatcherOpts {
+    /// Generate methods returning futures instead of `bool`
+    is_async: bool,
+}
+
+impl FromMeta for DispatcherOpts {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        let mut opts = Self::default();
+        for item in items {
+            match item {
+                darling::ast::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("async") => {
+                    opts.is_async = true;
+                }
+                other => {
+                    return Err(darling::Error::custom(
+                        "expected `async` in `generate_dispatcher(...)`",
+                    )
+                    .with_span(other));
+                }
+            }
+        }
+        Ok(opts)
+    }
 }
 
 /// Variant-level attributes
@@ -37,6 +73,18 @@ struct ActionVariant {
     /// Exclude from category inference
     #[darling(default)]
     skip_category: bool,
+
+    /// Mirror this variant into the generated effect enum
+    #[darling(default)]
+    effect: bool,
+
+    /// Generate `From<T>` for this single-field variant
+    #[darling(default)]
+    from: bool,
+
+    /// Name of the generated constructor (default: snake_case variant name)
+    #[darling(default)]
+    constructor: Option<String>,
 }
 
 /// Common action verbs that typically appear as the last part of a variant name
@@ -88,6 +136,42 @@ fn to_snake_case(s: &str) -> String {
     result
 }
 
+/// Inherent and trait methods `#[derive(Action)]` generates on the enum itself
+const GENERATED_METHODS: &[&str] = &[
+    "name",
+    "params",
+    "schema",
+    "category",
+    "category_enum",
+    "to_effect",
+];
+
+/// Identifier for a generated constructor fn
+///
+/// Keywords (e.g. `move`) become raw identifiers. Names that can't be used
+/// (`self`, `super`, ...) or that clash with the derive's own methods are
+/// rejected with a message for the variant.
+fn constructor_ident(name: &str, predicates: &[String]) -> Result<Ident, String> {
+    if matches!(name, "self" | "Self" | "super" | "crate" | "_") {
+        return Err(format!(
+            "`{name}` can't be used as a constructor name; \
+             rename it with #[action(constructor = \"...\")]"
+        ));
+    }
+    if GENERATED_METHODS.contains(&name) || predicates.iter().any(|p| p == name) {
+        return Err(format!(
+            "constructor `{name}` clashes with a method generated by #[derive(Action)]; \
+             rename it with #[action(constructor = \"...\")]"
+        ));
+    }
+    if syn::parse_str::<Ident>(name).is_ok() {
+        Ok(format_ident!("{}", name))
+    } else {
+        syn::parse_str::<Ident>(&format!("r#{name}"))
+            .map_err(|_| format!("`{name}` is not a valid constructor name"))
+    }
+}
+
 /// Convert snake_case to PascalCase
 fn to_pascal_case(s: &str) -> String {
     s.split('_')
@@ -157,9 +241,123 @@ fn infer_category(name: &str) -> Option<String> {
     Some(to_snake_case(&prefix))
 }
 
+/// Category of a variant: explicit, inferred, or None with `skip_category`
+fn variant_category(v: &ActionVariant) -> Option<String> {
+    if v.skip_category {
+        None
+    } else if let Some(ref explicit_cat) = v.category {
+        Some(explicit_cat.clone())
+    } else {
+        infer_category(&v.ident.to_string())
+    }
+}
+
+/// Collect `///` doc comments into one string (None if there are none)
+fn doc_string(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }),
+                ..
+            }) => Some(lit.value()),
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// Render a type as written in source, e.g. `Vec<(u16, u16)>`
+fn type_string(ty: &syn::Type) -> String {
+    let tokens = quote!(#ty).to_string();
+    let chars: Vec<char> = tokens.chars().collect();
+    let mut out = String::with_capacity(tokens.len());
+    for (i, &c) in chars.iter().enumerate() {
+        // `quote` spaces every token; keep only the spaces a person would write
+        if c == ' ' && !out.ends_with("->") {
+            let prev = out.chars().last();
+            let next = chars.get(i + 1).copied();
+            if prev.is_some_and(|p| "<(&[:".contains(p))
+                || next.is_some_and(|n| "<>()];:,".contains(n))
+            {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// `Option<String>` expression for a generated schema
+fn option_string(value: Option<String>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => {
+            quote! { ::core::option::Option::Some(::std::string::String::from(#value)) }
+        }
+        None => quote! { ::core::option::Option::None },
+    }
+}
+
+/// `VariantSchema` expression for one variant
+fn variant_schema(
+    variant: &syn::Variant,
+    category: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let variant_str = variant.ident.to_string();
+    let doc = option_string(doc_string(&variant.attrs));
+    let kind = match &variant.fields {
+        syn::Fields::Unit => quote! { tui_dispatch::VariantKind::Unit },
+        syn::Fields::Unnamed(_) => quote! { tui_dispatch::VariantKind::Tuple },
+        syn::Fields::Named(_) => quote! { tui_dispatch::VariantKind::Struct },
+    };
+    let fields = variant.fields.iter().map(|f| {
+        let field_name = option_string(f.ident.as_ref().map(|ident| ident.to_string()));
+        let ty = type_string(&f.ty);
+        let field_doc = option_string(doc_string(&f.attrs));
+        // Invalid #[param] attributes are reported by params()
+        let redacted = ParamField::from_field(f).is_ok_and(|param| param.redact);
+        quote! {
+            tui_dispatch::FieldSchema {
+                name: #field_name,
+                ty: ::std::string::String::from(#ty),
+                doc: #field_doc,
+                redacted: #redacted,
+            }
+        }
+    });
+
+    quote! {
+        tui_dispatch::VariantSchema {
+            name: ::std::string::String::from(#variant_str),
+            category: #category,
+            doc: #doc,
+            kind: #kind,
+            fields: ::std::vec![#(#fields),*],
+        }
+    }
+}
+
 /// Derive macro for the Action trait
 ///
-/// Generates a `name()` method that returns the variant name as a static string.
+/// Generates a `name()` method that returns the variant name as a static string,
+/// plus an `ActionParams` implementation (see [`macro@ActionParams`] for
+/// `#[param(redact)]`).
+///
+/// Also generates `schema() -> ActionSchema` (and an `ActionDescribe`
+/// implementation) listing every variant with its fields and their types,
+/// its category and its doc comments. The schema is serde-serializable.
 ///
 /// With `#[action(infer_categories)]`, also generates:
 /// - `category() -> Option<&'static str>` - Get action's category
@@ -170,6 +368,24 @@ fn infer_category(name: &str) -> Option<String> {
 /// With `#[action(generate_dispatcher)]`, also generates:
 /// - `{Name}Dispatcher` trait with category-based dispatch methods
 ///
+/// `#[action(generate_dispatcher(async))]` generates the same trait with
+/// methods returning `impl Future<Output = bool> + Send`, so handlers can be
+/// written as `async fn` and await backend calls.
+///
+/// With `#[action(constructors)]`, also generates:
+/// - `{variant}(..) -> Self` constructor fns (snake_case) for tuple and struct
+///   variants, taking the fields in order; `#[action(constructor = "...")]`
+///   on a variant picks another name
+///
+/// With `#[action(from)]` on a single-field variant, also generates:
+/// - `From<FieldType>` for the action enum
+///
+/// With `#[action(effect)]` on one or more variants, also generates:
+/// - `{Name}Effect` enum mirroring the marked variants and their fields
+/// - `to_effect() -> Option<{Name}Effect>` to convert an action into its effect
+/// - `{Name}EffectHandler` trait with one `perform_{variant}` method per effect
+///   and a `perform()` method that routes effects to them
+///
 /// # Example
 /// ```ignore
 /// #[derive(Action, Clone, Debug)]
@@ -188,7 +404,7 @@ fn infer_category(name: &str) -> Option<String> {
 /// assert_eq!(action.category(), Some("search"));
 /// assert!(action.is_search());
 /// ```
-#[proc_macro_derive(Action, attributes(action))]
+#[proc_macro_derive(Action, attributes(action, param))]
 pub fn derive_action(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -234,42 +450,17 @@ pub fn derive_action(input: TokenStream) -> TokenStream {
     });
 
     // Generate params() implementation - outputs field values without variant name
-    let params_arms = syn_variants.iter().map(|v| {
-        let variant_name = &v.ident;
+    let params_arms = match params_match_arms(name, syn_variants) {
+        Ok(arms) => arms,
+        Err(e) => return e.write_errors().into(),
+    };
 
-        match &v.fields {
-            syn::Fields::Unit => quote! {
-                #name::#variant_name => ::std::string::String::new()
-            },
-            syn::Fields::Unnamed(fields) => {
-                let field_count = fields.unnamed.len();
-                let field_names: Vec<_> = (0..field_count)
-                    .map(|i| format_ident!("_{}", i))
-                    .collect();
-                let format_str = (0..field_count).map(|_| "{:?}").collect::<Vec<_>>().join(", ");
-                quote! {
-                    #name::#variant_name(#(#field_names),*) => ::std::format!(#format_str, #(#field_names),*)
-                }
-            },
-            syn::Fields::Named(fields) => {
-                let field_names: Vec<_> = fields.named.iter()
-                    .filter_map(|f| f.ident.as_ref())
-                    .collect();
-                if field_names.is_empty() {
-                    quote! {
-                        #name::#variant_name { .. } => ::std::string::String::new()
-                    }
-                } else {
-                    let format_str = field_names.iter()
-                        .map(|n| format!("{}: {{:?}}", n))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    quote! {
-                        #name::#variant_name { #(#field_names),*, .. } => ::std::format!(#format_str, #(#field_names),*)
-                    }
-                }
-            },
-        }
+    // Generate schema() - variants, fields, categories and docs
+    let name_str = name.to_string();
+    let enum_doc = option_string(doc_string(&input.attrs));
+    let variant_schemas = variants.iter().zip(syn_variants.iter()).map(|(v, syn_v)| {
+        let category = option_string(opts.infer_categories.then(|| variant_category(v)).flatten());
+        variant_schema(syn_v, category)
     });
 
     let mut expanded = quote! {
@@ -288,8 +479,271 @@ pub fn derive_action(input: TokenStream) -> TokenStream {
                 }
             }
         }
+
+        impl #name {
+            /// Describe every variant with its fields, category and doc comments
+            pub fn schema() -> tui_dispatch::ActionSchema {
+                tui_dispatch::ActionSchema {
+                    name: ::std::string::String::from(#name_str),
+                    doc: #enum_doc,
+                    variants: ::std::vec![#(#variant_schemas),*],
+                }
+            }
+        }
+
+        impl tui_dispatch::ActionDescribe for #name {
+            fn schema() -> tui_dispatch::ActionSchema {
+                #name::schema()
+            }
+        }
     };
 
+    // Generate effect enum and handler trait for #[action(effect)] variants
+    if variants.iter().any(|v| v.effect) {
+        let vis = &opts.vis;
+        let effect_enum_name = format_ident!("{}Effect", name);
+        let handler_trait_name = format_ident!("{}EffectHandler", name);
+
+        let mut effect_variants = Vec::new();
+        let mut to_effect_arms = Vec::new();
+        let mut perform_arms = Vec::new();
+        let mut perform_methods = Vec::new();
+
+        for (v, syn_v) in variants.iter().zip(syn_variants.iter()) {
+            if !v.effect {
+                continue;
+            }
+
+            let variant_name = &v.ident;
+            let method_name = format_ident!("perform_{}", to_snake_case(&variant_name.to_string()));
+            let method_doc = format!("Perform the `{}` effect.", variant_name);
+
+            let (bindings, types): (Vec<Ident>, Vec<&syn::Type>) = match &syn_v.fields {
+                syn::Fields::Unit => (Vec::new(), Vec::new()),
+                syn::Fields::Unnamed(fields) => fields
+                    .unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| (format_ident!("_{}", i), &f.ty))
+                    .unzip(),
+                syn::Fields::Named(fields) => fields
+                    .named
+                    .iter()
+                    .filter_map(|f| f.ident.clone().map(|ident| (ident, &f.ty)))
+                    .unzip(),
+            };
+
+            match &syn_v.fields {
+                syn::Fields::Unit => {
+                    effect_variants.push(quote! { #variant_name });
+                    to_effect_arms.push(quote! {
+                        #name::#variant_name => ::core::option::Option::Some(#effect_enum_name::#variant_name)
+                    });
+                    perform_arms.push(quote! {
+                        #effect_enum_name::#variant_name => self.#method_name(ctx)
+                    });
+                }
+                syn::Fields::Unnamed(_) => {
+                    effect_variants.push(quote! { #variant_name(#(#types),*) });
+                    to_effect_arms.push(quote! {
+                        #name::#variant_name(#(#bindings),*) => ::core::option::Option::Some(
+                            #effect_enum_name::#variant_name(#(::core::clone::Clone::clone(#bindings)),*)
+                        )
+                    });
+                    perform_arms.push(quote! {
+                        #effect_enum_name::#variant_name(#(#bindings),*) => self.#method_name(ctx, #(#bindings),*)
+                    });
+                }
+                syn::Fields::Named(_) => {
+                    effect_variants.push(quote! { #variant_name { #(#bindings: #types),* } });
+                    to_effect_arms.push(quote! {
+                        #name::#variant_name { #(#bindings),* } => ::core::option::Option::Some(
+                            #effect_enum_name::#variant_name { #(#bindings: ::core::clone::Clone::clone(#bindings)),* }
+                        )
+                    });
+                    perform_arms.push(quote! {
+                        #effect_enum_name::#variant_name { #(#bindings),* } => self.#method_name(ctx, #(#bindings),*)
+                    });
+                }
+            }
+
+            perform_methods.push(quote! {
+                #[doc = #method_doc]
+                fn #method_name(
+                    &mut self,
+                    ctx: &mut tui_dispatch::EffectContext<'_, #name>,
+                    #(#bindings: #types),*
+                );
+            });
+        }
+
+        let effect_enum_doc = format!(
+            "Effects mirrored from [`{}`] variants marked `#[action(effect)]`.",
+            name
+        );
+        let handler_doc = format!(
+            "Effect handler for [`{}`].\n\n\
+             Implement one `perform_*` method per effect; [`perform()`](Self::perform) \
+             routes each effect to its method.",
+            effect_enum_name
+        );
+
+        expanded = quote! {
+            #expanded
+
+            #[doc = #effect_enum_doc]
+            #[derive(Debug, Clone)]
+            #vis enum #effect_enum_name {
+                #(#effect_variants,)*
+            }
+
+            impl #name {
+                /// Convert this action into its mirrored effect, if it has one.
+                #[allow(unreachable_patterns)]
+                pub fn to_effect(&self) -> ::core::option::Option<#effect_enum_name> {
+                    match self {
+                        #(#to_effect_arms,)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+
+            #[doc = #handler_doc]
+            #vis trait #handler_trait_name {
+                #(#perform_methods)*
+
+                /// Route an effect to its `perform_*` method.
+                fn perform(
+                    &mut self,
+                    effect: #effect_enum_name,
+                    ctx: &mut tui_dispatch::EffectContext<'_, #name>,
+                ) {
+                    match effect {
+                        #(#perform_arms,)*
+                    }
+                }
+            }
+        };
+    }
+
+    if opts.constructors {
+        // `is_{category}` predicates generated below
+        let predicates: Vec<String> = if opts.infer_categories {
+            variants
+                .iter()
+                .filter_map(variant_category)
+                .map(|category| format!("is_{category}"))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let mut constructors = Vec::new();
+        for (v, syn_v) in variants.iter().zip(syn_variants.iter()) {
+            if matches!(syn_v.fields, syn::Fields::Unit) {
+                continue;
+            }
+            let variant_name = &v.ident;
+            let name_str = v
+                .constructor
+                .clone()
+                .unwrap_or_else(|| to_snake_case(&variant_name.to_string()));
+            let fn_name = match constructor_ident(&name_str, &predicates) {
+                Ok(ident) => ident,
+                Err(message) => {
+                    return syn::Error::new_spanned(
+                        syn_v,
+                        format!("variant `{variant_name}`: {message}"),
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            };
+            constructors.push((v, syn_v, fn_name));
+        }
+        let constructors = constructors.into_iter().map(|(v, syn_v, fn_name)| {
+            let variant_name = &v.ident;
+            let doc = format!("Create a [`{}::{}`] action.", name, variant_name);
+            let (args, body) = match &syn_v.fields {
+                syn::Fields::Unit => unreachable!(),
+                syn::Fields::Unnamed(fields) => {
+                    let (args, bindings): (Vec<_>, Vec<_>) = fields
+                        .unnamed
+                        .iter()
+                        .enumerate()
+                        .map(|(i, f)| {
+                            let binding = format_ident!("arg{}", i);
+                            let ty = &f.ty;
+                            (quote! { #binding: #ty }, binding)
+                        })
+                        .unzip();
+                    (args, quote! { #name::#variant_name(#(#bindings),*) })
+                }
+                syn::Fields::Named(fields) => {
+                    let (args, bindings): (Vec<_>, Vec<_>) = fields
+                        .named
+                        .iter()
+                        .filter_map(|f| {
+                            let ident = f.ident.as_ref()?;
+                            let ty = &f.ty;
+                            Some((quote! { #ident: #ty }, ident))
+                        })
+                        .unzip();
+                    (args, quote! { #name::#variant_name { #(#bindings),* } })
+                }
+            };
+            quote! {
+                #[doc = #doc]
+                pub fn #fn_name(#(#args),*) -> Self {
+                    #body
+                }
+            }
+        });
+
+        expanded = quote! {
+            #expanded
+
+            impl #name {
+                #(#constructors)*
+            }
+        };
+    }
+
+    // Generate From impls for #[action(from)] variants
+    for (v, syn_v) in variants.iter().zip(syn_variants.iter()) {
+        if !v.from {
+            continue;
+        }
+        let variant_name = &v.ident;
+        let (ty, body) = match &syn_v.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (
+                &fields.unnamed[0].ty,
+                quote! { #name::#variant_name(value) },
+            ),
+            syn::Fields::Named(fields) if fields.named.len() == 1 => {
+                let field = &fields.named[0];
+                let ident = &field.ident;
+                (&field.ty, quote! { #name::#variant_name { #ident: value } })
+            }
+            _ => {
+                return syn::Error::new_spanned(
+                    syn_v,
+                    "#[action(from)] requires a variant with exactly one field",
+                )
+                .to_compile_error()
+                .into();
+            }
+        };
+        expanded = quote! {
+            #expanded
+
+            impl ::core::convert::From<#ty> for #name {
+                fn from(value: #ty) -> Self {
+                    #body
+                }
+            }
+        };
+    }
+
     // If category inference is enabled, generate category-related code
     if opts.infer_categories {
         // Collect categories and their variants
@@ -297,13 +751,7 @@ pub fn derive_action(input: TokenStream) -> TokenStream {
         let mut variant_categories: Vec<(&Ident, Option<String>)> = Vec::new();
 
         for v in variants.iter() {
-            let cat = if v.skip_category {
-                None
-            } else if let Some(ref explicit_cat) = v.category {
-                Some(explicit_cat.clone())
-            } else {
-                infer_category(&v.ident.to_string())
-            };
+            let cat = variant_category(v);
 
             variant_categories.push((&v.ident, cat.clone()));
 
@@ -441,9 +889,21 @@ pub fn derive_action(input: TokenStream) -> TokenStream {
         };
 
         // Generate dispatcher trait if requested
-        if opts.generate_dispatcher {
+        if let Some(dispatcher) = &opts.generate_dispatcher {
             let dispatcher_trait_name = format_ident!("{}Dispatcher", name);
 
+            // Async handlers return futures and `dispatch()` awaits them
+            let (output, unhandled, call_suffix, supertrait) = if dispatcher.is_async {
+                (
+                    quote! { impl ::core::future::Future<Output = bool> + ::core::marker::Send },
+                    quote! { async { false } },
+                    quote! { .await },
+                    quote! { : ::core::marker::Send },
+                )
+            } else {
+                (quote! { bool }, quote! { false }, quote! {}, quote! {})
+            };
+
             let dispatch_methods: Vec<_> = sorted_categories
                 .iter()
                 .map(|cat| {
@@ -451,8 +911,8 @@ pub fn derive_action(input: TokenStream) -> TokenStream {
                     let doc = format!("Handle actions in the `{}` category.", cat);
                     quote! {
                         #[doc = #doc]
-                        fn #method_name(&mut self, action: &#name) -> bool {
-                            false
+                        fn #method_name(&mut self, action: &#name) -> #output {
+                            #unhandled
                         }
                     }
                 })
@@ -464,36 +924,52 @@ pub fn derive_action(input: TokenStream) -> TokenStream {
                     let method_name = format_ident!("dispatch_{}", cat);
                     let cat_variant = format_ident!("{}", to_pascal_case(cat));
                     quote! {
-                        #category_enum_name::#cat_variant => self.#method_name(action)
+                        #category_enum_name::#cat_variant => self.#method_name(action)#call_suffix
                     }
                 })
                 .collect();
 
+            let route = quote! {
+                match action.category_enum() {
+                    #(#dispatch_arms,)*
+                    #category_enum_name::Uncategorized => {
+                        self.dispatch_uncategorized(action)#call_suffix
+                    }
+                }
+            };
+            let route = if dispatcher.is_async {
+                quote! { async move { #route } }
+            } else {
+                route
+            };
+
             let dispatcher_doc = format!(
                 "Dispatcher trait for [`{}`].\n\n\
                  Implement the `dispatch_*` methods for each category you want to handle.\n\
-                 The [`dispatch()`](Self::dispatch) method automatically routes to the correct handler.",
-                name
+                 The [`dispatch()`](Self::dispatch) method automatically routes to the correct handler.{}",
+                name,
+                if dispatcher.is_async {
+                    "\n\nMethods return futures; implement them as `async fn`."
+                } else {
+                    ""
+                }
             );
 
             expanded = quote! {
                 #expanded
 
                 #[doc = #dispatcher_doc]
-                pub trait #dispatcher_trait_name {
+                pub trait #dispatcher_trait_name #supertrait {
                     #(#dispatch_methods)*
 
                     /// Handle uncategorized actions.
-                    fn dispatch_uncategorized(&mut self, action: &#name) -> bool {
-                        false
+                    fn dispatch_uncategorized(&mut self, action: &#name) -> #output {
+                        #unhandled
                     }
 
                     /// Main dispatch entry point - routes to category-specific handlers.
-                    fn dispatch(&mut self, action: &#name) -> bool {
-                        match action.category_enum() {
-                            #(#dispatch_arms,)*
-                            #category_enum_name::Uncategorized => self.dispatch_uncategorized(action),
-                        }
+                    fn dispatch(&mut self, action: &#name) -> #output {
+                        #route
                     }
                 }
             };
@@ -503,10 +979,181 @@ pub fn derive_action(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Field-level attributes for action params formatting
+#[derive(Debug, FromField)]
+#[darling(attributes(param))]
+struct ParamField {
+    ident: Option<syn::Ident>,
+
+    /// Show the field as `***` instead of its value
+    #[darling(default)]
+    redact: bool,
+}
+
+/// Placeholder shown in place of redacted field values
+const REDACTED: &str = "***";
+
+/// Build `params()` match arms for each variant, honoring `#[param(redact)]`
+fn params_match_arms(
+    name: &syn::Ident,
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+) -> darling::Result<Vec<proc_macro2::TokenStream>> {
+    let mut errors = darling::Error::accumulator();
+    let mut arms = Vec::new();
+
+    for v in variants {
+        let variant_name = &v.ident;
+        let fields: Vec<ParamField> = v
+            .fields
+            .iter()
+            .filter_map(|f| errors.handle(ParamField::from_field(f)))
+            .collect();
+
+        let arm = match &v.fields {
+            syn::Fields::Unit => quote! {
+                #name::#variant_name => ::std::string::String::new()
+            },
+            syn::Fields::Unnamed(_) => {
+                let patterns: Vec<_> = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| {
+                        if f.redact {
+                            quote! { _ }
+                        } else {
+                            let ident = format_ident!("_{}", i);
+                            quote! { #ident }
+                        }
+                    })
+                    .collect();
+                let args: Vec<_> = fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| !f.redact)
+                    .map(|(i, _)| format_ident!("_{}", i))
+                    .collect();
+                let format_str = fields
+                    .iter()
+                    .map(|f| if f.redact { REDACTED } else { "{:?}" })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                quote! {
+                    #name::#variant_name(#(#patterns),*) => ::std::format!(#format_str, #(#args),*)
+                }
+            }
+            syn::Fields::Named(_) => {
+                if fields.is_empty() {
+                    quote! {
+                        #name::#variant_name { .. } => ::std::string::String::new()
+                    }
+                } else {
+                    let patterns: Vec<_> = fields
+                        .iter()
+                        .filter_map(|f| f.ident.as_ref().map(|ident| (ident, f.redact)))
+                        .map(|(ident, redact)| {
+                            if redact {
+                                quote! { #ident: _ }
+                            } else {
+                                quote! { #ident }
+                            }
+                        })
+                        .collect();
+                    let args: Vec<_> = fields
+                        .iter()
+                        .filter(|f| !f.redact)
+                        .filter_map(|f| f.ident.as_ref())
+                        .collect();
+                    let format_str = fields
+                        .iter()
+                        .filter_map(|f| f.ident.as_ref().map(|ident| (ident, f.redact)))
+                        .map(|(ident, redact)| {
+                            if redact {
+                                format!("{}: {}", ident, REDACTED)
+                            } else {
+                                format!("{}: {{:?}}", ident)
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    quote! {
+                        #name::#variant_name { #(#patterns),*, .. } => ::std::format!(#format_str, #(#args),*)
+                    }
+                }
+            }
+        };
+        arms.push(arm);
+    }
+
+    errors.finish_with(arms)
+}
+
+/// Derive macro for the ActionParams trait
+///
+/// Formats variant fields with `Debug` into the params string shown in the
+/// action log. Fields marked `#[param(redact)]` are shown as `***`.
+///
+/// `#[derive(Action)]` already implements `ActionParams` (with the same
+/// `#[param(...)]` support); use this derive when `Action` is implemented
+/// by hand.
+///
+/// # Example
+/// ```ignore
+/// #[derive(ActionParams, Clone, Debug)]
+/// enum AuthAction {
+///     Login {
+///         user: String,
+///         #[param(redact)]
+///         password: String,
+///     },
+///     SetToken(#[param(redact)] String),
+/// }
+///
+/// let action = AuthAction::Login { user: "ana".into(), password: "hunter2".into() };
+/// assert_eq!(action.params(), r#"user: "ana", password: ***"#);
+/// ```
+#[proc_macro_derive(ActionParams, attributes(param))]
+pub fn derive_action_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let variants = match &input.data {
+        syn::Data::Enum(data) => &data.variants,
+        _ => {
+            return syn::Error::new_spanned(&input, "ActionParams can only be derived for enums")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let params_arms = match params_match_arms(name, variants) {
+        Ok(arms) => arms,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    let expanded = quote! {
+        impl tui_dispatch::ActionParams for #name {
+            fn params(&self) -> ::std::string::String {
+                match self {
+                    #(#params_arms),*
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
 /// Derive macro for the BindingContext trait
 ///
 /// Generates implementations for `name()`, `from_name()`, and `all()` methods.
 /// The context name is derived from the variant name converted to snake_case.
+/// `#[binding(parent = Variant)]` makes a context inherit its parent's bindings.
+///
+/// `#[context(name = "...")]` sets the name used in config files, so a
+/// variant can be renamed without breaking existing configs, and
+/// `#[context(alias = "...")]` (repeatable) accepts further names in
+/// `from_name()`, e.g. legacy config keys. `#[binding(...)]` and
+/// `#[context(...)]` accept the same keys.
 ///
 /// # Example
 /// ```ignore
@@ -514,14 +1161,19 @@ pub fn derive_action(input: TokenStream) -> TokenStream {
 /// enum MyContext {
 ///     Default,
 ///     Search,
+///     #[binding(parent = Default)]
 ///     ConnectionForm,
+///     #[context(name = "files", alias = "sidebar")]
+///     FileTree,
 /// }
 ///
-/// // Generated names: "default", "search", "connection_form"
+/// // Generated names: "default", "search", "connection_form", "files"
 /// assert_eq!(MyContext::Default.name(), "default");
 /// assert_eq!(MyContext::from_name("search"), Some(MyContext::Search));
+/// assert_eq!(MyContext::from_name("sidebar"), Some(MyContext::FileTree));
+/// assert_eq!(MyContext::ConnectionForm.parent(), Some(MyContext::Default));
 /// ```
-#[proc_macro_derive(BindingContext)]
+#[proc_macro_derive(BindingContext, attributes(binding, context))]
 pub fn derive_binding_context(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -540,12 +1192,54 @@ pub fn derive_binding_context(input: TokenStream) -> TokenStream {
                 }
             }
 
+            let mut attrs = Vec::new();
+            for variant in &data.variants {
+                match binding_attrs(variant) {
+                    Ok(parsed) => attrs.push(parsed),
+                    Err(err) => return err.to_compile_error().into(),
+                }
+            }
+
             let variant_names: Vec<_> = data.variants.iter().map(|v| &v.ident).collect();
             let variant_strings: Vec<_> = variant_names
                 .iter()
-                .map(|v| to_snake_case(&v.to_string()))
+                .zip(&attrs)
+                .map(|(v, attrs)| {
+                    attrs
+                        .name
+                        .as_ref()
+                        .map(syn::LitStr::value)
+                        .unwrap_or_else(|| to_snake_case(&v.to_string()))
+                })
                 .collect();
 
+            // Every name and alias must pick out a single variant
+            let mut seen = std::collections::HashSet::new();
+            for ((variant, attrs), context_name) in
+                data.variants.iter().zip(&attrs).zip(&variant_strings)
+            {
+                let name_span = attrs
+                    .name
+                    .as_ref()
+                    .map_or(variant.ident.span(), |name| name.span());
+                let names = std::iter::once((context_name.clone(), name_span)).chain(
+                    attrs
+                        .aliases
+                        .iter()
+                        .map(|alias| (alias.value(), alias.span())),
+                );
+                for (context_name, span) in names {
+                    if !seen.insert(context_name.clone()) {
+                        return syn::Error::new(
+                            span,
+                            format!("context name \"{}\" is used twice", context_name),
+                        )
+                        .to_compile_error()
+                        .into();
+                    }
+                }
+            }
+
             let name_arms = variant_names
                 .iter()
                 .zip(variant_strings.iter())
@@ -556,12 +1250,38 @@ pub fn derive_binding_context(input: TokenStream) -> TokenStream {
             let from_name_arms = variant_names
                 .iter()
                 .zip(variant_strings.iter())
-                .map(|(v, s)| {
-                    quote! { #s => ::core::option::Option::Some(#name::#v) }
+                .zip(&attrs)
+                .map(|((v, s), attrs)| {
+                    let aliases = &attrs.aliases;
+                    quote! { #s #(| #aliases)* => ::core::option::Option::Some(#name::#v) }
                 });
 
             let all_variants = variant_names.iter().map(|v| quote! { #name::#v });
 
+            let parent_arms: Vec<_> = variant_names
+                .iter()
+                .zip(&attrs)
+                .filter_map(|(v, attrs)| {
+                    let parent = attrs.parent.as_ref()?;
+                    Some(quote! {
+                        #name::#v => ::core::option::Option::Some(#name::#parent)
+                    })
+                })
+                .collect();
+            let parent_fn = if parent_arms.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    fn parent(&self) -> ::core::option::Option<Self> {
+                        match self {
+                            #(#parent_arms,)*
+                            #[allow(unreachable_patterns)]
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            };
+
             quote! {
                 impl tui_dispatch::BindingContext for #name {
                     fn name(&self) -> &'static str {
@@ -581,6 +1301,8 @@ pub fn derive_binding_context(input: TokenStream) -> TokenStream {
                         static ALL: &[#name] = &[#(#all_variants),*];
                         ALL
                     }
+
+                    #parent_fn
                 }
             }
         }
@@ -594,6 +1316,38 @@ pub fn derive_binding_context(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Variant attributes of a BindingContext
+#[derive(Default)]
+struct BindingAttrs {
+    parent: Option<syn::Ident>,
+    name: Option<syn::LitStr>,
+    aliases: Vec<syn::LitStr>,
+}
+
+/// Parse `#[binding(...)]` / `#[context(...)]` on a BindingContext variant
+fn binding_attrs(variant: &syn::Variant) -> syn::Result<BindingAttrs> {
+    let mut attrs = BindingAttrs::default();
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("binding") && !attr.path().is_ident("context") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("parent") {
+                attrs.parent = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("name") {
+                attrs.name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("alias") {
+                attrs.aliases.push(meta.value()?.parse()?);
+            } else {
+                return Err(meta
+                    .error("expected `parent = Variant`, `name = \"...\"` or `alias = \"...\"`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attrs)
+}
+
 /// Derive macro for the ComponentId trait
 ///
 /// Generates implementations for `name()` method that returns the variant name.
@@ -668,13 +1422,18 @@ pub fn derive_component_id(input: TokenStream) -> TokenStream {
 struct DebugStateOpts {
     ident: syn::Ident,
     data: darling::ast::Data<(), DebugStateField>,
+
+    /// Implement `debug_snapshot` with serde (the struct must be `Serialize`)
+    #[darling(default)]
+    snapshot: bool,
 }
 
 /// Field-level attributes for DebugState
 #[derive(Debug, FromField)]
-#[darling(attributes(debug))]
+#[darling(attributes(debug, debug_state))]
 struct DebugStateField {
     ident: Option<syn::Ident>,
+    ty: syn::Type,
 
     /// Section name for this field (groups fields together)
     #[darling(default)]
@@ -692,9 +1451,99 @@ struct DebugStateField {
     #[darling(default)]
     label: Option<String>,
 
+    /// Alias for `label`
+    #[darling(default)]
+    rename: Option<String>,
+
     /// Use Debug trait instead of Display
     #[darling(default)]
     debug_fmt: bool,
+
+    /// Append the field's own DebugState sections instead of an entry
+    #[darling(default)]
+    flatten: bool,
+
+    /// Number of collection items to show
+    #[darling(default)]
+    limit: Option<usize>,
+}
+
+impl DebugStateField {
+    fn label(&self) -> Option<String> {
+        let ident = self.ident.as_ref()?;
+        Some(
+            self.label
+                .clone()
+                .or_else(|| self.rename.clone())
+                .unwrap_or_else(|| ident.to_string()),
+        )
+    }
+}
+
+/// Collection items shown by default
+const DEBUG_STATE_LIMIT: usize = 5;
+
+/// Last path segment of a type, e.g. `Vec` for `std::vec::Vec<T>`
+fn type_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+/// First generic type argument of a path segment
+fn first_type_arg(segment: &syn::PathSegment) -> Option<&syn::Type> {
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+/// Expression formatting `value` (a reference) for a debug entry, by type
+fn debug_value_expr(
+    ty: &syn::Type,
+    value: proc_macro2::TokenStream,
+    limit: usize,
+) -> proc_macro2::TokenStream {
+    if let syn::Type::Array(_) | syn::Type::Slice(_) = ty {
+        return quote! { tui_dispatch::debug::debug_list((#value).iter(), #limit) };
+    }
+    let Some(segment) = type_segment(ty) else {
+        return quote! { (#value).to_string() };
+    };
+
+    match segment.ident.to_string().as_str() {
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" | "IndexSet" => {
+            quote! { tui_dispatch::debug::debug_list((#value).iter(), #limit) }
+        }
+        "HashMap" | "BTreeMap" | "IndexMap" => {
+            quote! { tui_dispatch::debug::debug_map((#value).iter(), #limit) }
+        }
+        "Option" => match first_type_arg(segment) {
+            Some(inner) => {
+                let inner_expr = debug_value_expr(inner, quote! { inner }, limit);
+                quote! {
+                    match #value {
+                        ::std::option::Option::Some(inner) => #inner_expr,
+                        ::std::option::Option::None => ::std::string::String::from("None"),
+                    }
+                }
+            }
+            None => quote! { format!("{:?}", #value) },
+        },
+        _ => quote! { (#value).to_string() },
+    }
+}
+
+/// A run of output in declaration order
+enum DebugStatePart<'a> {
+    /// A section built from entries
+    Section(String),
+    /// Sections of a flattened field
+    Flatten(&'a DebugStateField),
 }
 
 /// Derive macro for the DebugState trait
@@ -703,11 +1552,21 @@ struct DebugStateField {
 ///
 /// # Attributes
 ///
+/// Field attributes can be written as `#[debug(...)]` or `#[debug_state(...)]`.
+///
 /// - `#[debug(section = "Name")]` - Group field under a section
 /// - `#[debug(skip)]` - Exclude field from debug output
 /// - `#[debug(label = "Custom Label")]` - Use custom label instead of field name
+///   (`rename = "..."` is an alias)
 /// - `#[debug(debug_fmt)]` - Use `{:?}` format instead of `Display`
 /// - `#[debug(format = "{:#?}")]` - Use custom format string
+/// - `#[debug(flatten)]` - Append a nested `DebugState` field's sections,
+///   titled "label / Section"
+/// - `#[debug(limit = 10)]` - Number of collection items to show (default 5)
+///
+/// Without `format` or `debug_fmt`, `Vec`, `VecDeque`, sets, maps, arrays
+/// and slices show their length and first items (using `Debug`), and
+/// `Option` shows its value or "None". Other types use `Display`.
 ///
 /// # Example
 ///
@@ -723,17 +1582,26 @@ struct DebugStateField {
 ///
 ///     #[debug(section = "UI")]
 ///     scroll_offset: usize,
+///     #[debug(section = "UI", limit = 3)]
+///     open_tabs: Vec<String>,
 ///
 ///     #[debug(skip)]
 ///     internal_cache: HashMap<String, Data>,
 ///
 ///     #[debug(section = "Stats", debug_fmt)]
 ///     status: ConnectionStatus,
+///
+///     #[debug_state(flatten, rename = "settings")]
+///     settings: Settings,
 /// }
 /// ```
 ///
 /// Fields without a section attribute are grouped under a section named after
 /// the struct (e.g., "AppState").
+///
+/// With `#[debug_state(snapshot)]` on the struct, `debug_snapshot()` returns
+/// the struct serialized as JSON, so the debug layer can save it to a file.
+/// The struct must implement `serde::Serialize`.
 #[proc_macro_derive(DebugState, attributes(debug, debug_state))]
 pub fn derive_debug_state(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -755,70 +1623,101 @@ pub fn derive_debug_state(input: TokenStream) -> TokenStream {
         }
     };
 
-    // Group fields by section
+    // Group fields by section, keeping flattened fields in place
     let mut sections: HashMap<String, Vec<&DebugStateField>> = HashMap::new();
-    let mut section_order: Vec<String> = Vec::new();
+    let mut parts: Vec<DebugStatePart> = Vec::new();
 
     for field in fields.iter() {
         if field.skip {
             continue;
         }
+        if field.flatten {
+            parts.push(DebugStatePart::Flatten(field));
+            continue;
+        }
 
         let section_name = field
             .section
             .clone()
             .unwrap_or_else(|| default_section.clone());
 
-        if !section_order.contains(&section_name) {
-            section_order.push(section_name.clone());
+        if !sections.contains_key(&section_name) {
+            parts.push(DebugStatePart::Section(section_name.clone()));
         }
 
         sections.entry(section_name).or_default().push(field);
     }
 
-    // Generate code for each section
-    let section_code: Vec<_> = section_order
+    // Generate code for each part
+    let part_code: Vec<_> = parts
         .iter()
-        .map(|section_name| {
-            let fields_in_section = sections.get(section_name).unwrap();
+        .map(|part| match part {
+            DebugStatePart::Section(section_name) => {
+                let fields_in_section = sections.get(section_name).unwrap();
 
-            let entry_calls: Vec<_> = fields_in_section
-                .iter()
-                .filter_map(|field| {
-                    let field_ident = field.ident.as_ref()?;
-                    let label = field
-                        .label
-                        .clone()
-                        .unwrap_or_else(|| field_ident.to_string());
-
-                    let value_expr = if let Some(ref fmt) = field.format {
-                        quote! { format!(#fmt, self.#field_ident) }
-                    } else if field.debug_fmt {
-                        quote! { format!("{:?}", self.#field_ident) }
-                    } else {
-                        quote! { self.#field_ident.to_string() }
-                    };
-
-                    Some(quote! {
-                        .entry(#label, #value_expr)
+                let entry_calls: Vec<_> = fields_in_section
+                    .iter()
+                    .filter_map(|field| {
+                        let field_ident = field.ident.as_ref()?;
+                        let label = field.label()?;
+                        let limit = field.limit.unwrap_or(DEBUG_STATE_LIMIT);
+
+                        let value_expr = if let Some(ref fmt) = field.format {
+                            quote! { format!(#fmt, self.#field_ident) }
+                        } else if field.debug_fmt {
+                            quote! { format!("{:?}", self.#field_ident) }
+                        } else {
+                            debug_value_expr(&field.ty, quote! { &self.#field_ident }, limit)
+                        };
+
+                        Some(quote! {
+                            .entry(#label, #value_expr)
+                        })
                     })
-                })
-                .collect();
+                    .collect();
 
-            quote! {
-                tui_dispatch::debug::DebugSection::new(#section_name)
-                    #(#entry_calls)*
+                quote! {
+                    .chain(::std::iter::once(
+                        tui_dispatch::debug::DebugSection::new(#section_name)
+                            #(#entry_calls)*
+                    ))
+                }
+            }
+            DebugStatePart::Flatten(field) => {
+                let (Some(field_ident), Some(label)) = (field.ident.as_ref(), field.label()) else {
+                    return quote! {};
+                };
+                quote! {
+                    .chain(
+                        tui_dispatch::debug::DebugState::debug_sections(&self.#field_ident)
+                            .into_iter()
+                            .map(|mut section| {
+                                section.title = format!("{} / {}", #label, section.title);
+                                section
+                            }),
+                    )
+                }
             }
         })
         .collect();
 
+    let snapshot = opts.snapshot.then(|| {
+        quote! {
+            fn debug_snapshot(&self) -> ::std::option::Option<::std::string::String> {
+                tui_dispatch::debug::snapshot_json(self)
+            }
+        }
+    });
+
     let expanded = quote! {
         impl tui_dispatch::debug::DebugState for #name {
             fn debug_sections(&self) -> ::std::vec::Vec<tui_dispatch::debug::DebugSection> {
-                ::std::vec![
-                    #(#section_code),*
-                ]
+                ::std::iter::empty::<tui_dispatch::debug::DebugSection>()
+                    #(#part_code)*
+                    .collect()
             }
+
+            #snapshot
         }
     };
 
@@ -995,3 +1894,192 @@ pub fn derive_feature_flags(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Container-level options for #[derive(ArbitraryAction)]
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(arbitrary), supports(enum_any))]
+struct ArbitraryActionOpts {
+    ident: syn::Ident,
+    generics: syn::Generics,
+    data: darling::ast::Data<ArbitraryVariant, ()>,
+}
+
+/// Variant-level attributes for #[derive(ArbitraryAction)]
+#[derive(Debug, FromVariant)]
+#[darling(attributes(arbitrary))]
+struct ArbitraryVariant {
+    ident: syn::Ident,
+    fields: darling::ast::Fields<ArbitraryField>,
+
+    /// Never generate this variant
+    #[darling(default)]
+    skip: bool,
+
+    /// Relative frequency (default 1)
+    #[darling(default)]
+    weight: Option<u32>,
+}
+
+/// Field-level attributes for #[derive(ArbitraryAction)]
+#[derive(Debug, FromField)]
+#[darling(attributes(arbitrary))]
+struct ArbitraryField {
+    ident: Option<syn::Ident>,
+    ty: syn::Type,
+
+    /// Strategy expression used instead of `any::<T>()`
+    #[darling(default)]
+    strategy: Option<String>,
+}
+
+/// Derive macro for proptest's `Arbitrary` on action enums
+///
+/// Generates `any::<Action>()` picking a random variant and filling its
+/// fields with `any::<T>()`. The generated code uses `tui_dispatch::proptest`,
+/// so enable the `proptest` feature.
+///
+/// # Attributes
+///
+/// - `#[arbitrary(skip)]` on a variant - never generate it (e.g. `Quit`)
+/// - `#[arbitrary(weight = 5)]` on a variant - relative frequency (default 1)
+/// - `#[arbitrary(strategy = "0..10usize")]` on a field - use this strategy
+///   instead of `any::<T>()`, for ranges or types without `Arbitrary`
+///
+/// # Example
+///
+/// ```ignore
+/// use tui_dispatch::{Action, ArbitraryAction};
+///
+/// #[derive(Action, ArbitraryAction, Clone, Debug)]
+/// enum Action {
+///     #[arbitrary(weight = 4)]
+///     Next,
+///     Prev,
+///     Select(#[arbitrary(strategy = "0..100usize")] usize),
+///     Search { query: String },
+///     #[arbitrary(skip)]
+///     Quit,
+/// }
+/// ```
+#[proc_macro_derive(ArbitraryAction, attributes(arbitrary))]
+pub fn derive_arbitrary_action(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let opts = match ArbitraryActionOpts::from_derive_input(&input) {
+        Ok(opts) => opts,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    if !opts.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &opts.generics,
+            "ArbitraryAction does not support generic enums",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let name = &opts.ident;
+    let variants = match &opts.data {
+        darling::ast::Data::Enum(variants) => variants,
+        _ => unreachable!("darling checks supports(enum_any)"),
+    };
+
+    let mut arms = Vec::new();
+    for variant in variants.iter().filter(|v| !v.skip) {
+        let ident = &variant.ident;
+        let weight = variant.weight.unwrap_or(1);
+
+        let mut strategies = Vec::new();
+        for field in variant.fields.iter() {
+            let ty = &field.ty;
+            match &field.strategy {
+                Some(expr) => match syn::parse_str::<syn::Expr>(expr) {
+                    Ok(expr) => strategies.push(quote! { (#expr) }),
+                    Err(e) => {
+                        return syn::Error::new_spanned(ty, format!("invalid strategy: {e}"))
+                            .to_compile_error()
+                            .into();
+                    }
+                },
+                None => strategies.push(quote! { tui_dispatch::proptest::arbitrary::any::<#ty>() }),
+            }
+        }
+
+        let bindings: Vec<_> = (0..strategies.len())
+            .map(|i| format_ident!("__field{}", i))
+            .collect();
+        let construct = match variant.fields.style {
+            darling::ast::Style::Unit => quote! { #name::#ident },
+            darling::ast::Style::Tuple => quote! { #name::#ident(#(#bindings),*) },
+            darling::ast::Style::Struct => {
+                let names = variant.fields.iter().map(|f| f.ident.as_ref().unwrap());
+                quote! { #name::#ident { #(#names: #bindings),* } }
+            }
+        };
+
+        let strategy = match strategies.len() {
+            0 => quote! { tui_dispatch::proptest::strategy::Just(#construct).boxed() },
+            1 => {
+                let strategy = &strategies[0];
+                let binding = &bindings[0];
+                quote! { #strategy.prop_map(|#binding| #construct).boxed() }
+            }
+            _ => quote! {
+                (#(#strategies,)*).prop_map(|(#(#bindings,)*)| #construct).boxed()
+            },
+        };
+        arms.push(quote! { (#weight, #strategy) });
+    }
+
+    if arms.is_empty() {
+        return syn::Error::new_spanned(
+            &input,
+            "ArbitraryAction needs at least one variant without #[arbitrary(skip)]",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let expanded = quote! {
+        impl tui_dispatch::proptest::arbitrary::Arbitrary for #name {
+            type Parameters = ();
+            type Strategy = tui_dispatch::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                use tui_dispatch::proptest::strategy::Strategy as _;
+                tui_dispatch::proptest::strategy::Union::new_weighted(::std::vec![#(#arms),*])
+                    .boxed()
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constructor_ident() {
+        assert_eq!(constructor_ident("did_load", &[]).unwrap(), "did_load");
+        assert_eq!(
+            constructor_ident("move", &[]).unwrap().to_string(),
+            "r#move"
+        );
+
+        for name in ["self", "super", "crate"] {
+            let message = constructor_ident(name, &[]).unwrap_err();
+            assert!(message.contains("can't be used"), "{message}");
+        }
+        for name in ["name", "schema", "category", "category_enum", "to_effect"] {
+            let message = constructor_ident(name, &[]).unwrap_err();
+            assert!(message.contains("clashes"), "{message}");
+        }
+
+        let predicates = vec!["is_search".to_string()];
+        assert!(constructor_ident("is_search", &predicates).is_err());
+        assert!(constructor_ident("is_search", &[]).is_ok());
+    }
+}
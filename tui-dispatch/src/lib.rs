@@ -24,44 +24,61 @@
 pub use tui_dispatch_core::*;
 
 // Re-export derive macros
-pub use tui_dispatch_macros::{Action, BindingContext, ComponentId, DebugState, FeatureFlags};
+pub use tui_dispatch_macros::{
+    Action, ActionParams, ArbitraryAction, BindingContext, ComponentId, DebugState, FeatureFlags,
+};
 
 /// Prelude for convenient imports
 pub mod prelude {
     // Traits
     pub use tui_dispatch_core::{
-        Action, ActionCategory, ActionParams, BindingContext, Component, ComponentId,
+        Action, ActionCategory, ActionDescribe, ActionParams, BindingContext, Component,
+        ComponentId,
     };
 
     // Event system
     pub use tui_dispatch_core::{
-        process_raw_event, spawn_event_poller, Event, EventBus, EventContext, EventKind, EventType,
-        NumericComponentId, RawEvent,
+        disable_bracketed_paste, disable_focus_change, disable_keyboard_enhancement,
+        enable_bracketed_paste, enable_focus_change, enable_keyboard_enhancement,
+        process_raw_event, spawn_event_poller, supports_keyboard_enhancement, ComponentTree, Event,
+        EventBus, EventContext, EventKind, EventType, NumericComponentId, RawEvent,
     };
 
     // Keybindings
     pub use tui_dispatch_core::{format_key_for_display, parse_key_string, Keybindings};
 
+    // Layout
+    pub use tui_dispatch_core::{GridAreas, GridSpec};
+
+    // Theme
+    pub use tui_dispatch_core::{Strings, Theme};
+
     // Store
     pub use tui_dispatch_core::{
-        ComposedMiddleware, LoggingMiddleware, Middleware, NoopMiddleware, Reducer, Store,
+        CategoryRouter, ComposedMiddleware, EnvReducer, ListenerId, LoggingMiddleware, Middleware,
+        MiddlewareInfo, NoopMiddleware, Reducer, Selector, SliceChanges, Store,
         StoreWithMiddleware,
     };
 
     // Effects
     pub use tui_dispatch_core::{
-        DispatchResult, EffectReducer, EffectStore, EffectStoreWithMiddleware,
+        Cmd, DirtyComponents, DispatchResult, EffectReducer, EffectStore,
+        EffectStoreWithMiddleware, Thunk,
     };
 
     // Runtime helpers
     pub use tui_dispatch_core::{
-        DispatchRuntime, DispatchStore, EffectContext, EffectRuntime, EffectStoreLike,
-        EventOutcome, PollerConfig, RenderContext,
+        DispatchRuntime, DispatchRuntimeBuilder, DispatchStore, EffectContext, EffectRuntime,
+        EffectStoreLike, EventOutcome, GlobalHotkeys, PollerConfig, RenderContext, Runtime,
+        RuntimeBuilder, TerminalGuard, TerminalModes,
     };
 
+    // Persistence
+    pub use tui_dispatch_core::{Persist, PersistPolicy, Persister, ViewState};
+
     // Tasks (requires "tasks" feature)
     #[cfg(feature = "tasks")]
-    pub use tui_dispatch_core::{TaskKey, TaskManager};
+    pub use tui_dispatch_core::{ProgressHandle, RetryPolicy, TaskKey, TaskManager, TaskPriority};
 
     // Subscriptions (requires "subscriptions" feature)
     #[cfg(feature = "subscriptions")]
@@ -73,7 +90,10 @@ pub mod prelude {
     };
 
     // Derive macros
-    pub use tui_dispatch_macros::{Action, BindingContext, ComponentId, DebugState, FeatureFlags};
+    pub use tui_dispatch_macros::{
+        Action, ActionParams, ArbitraryAction, BindingContext, ComponentId, DebugState,
+        FeatureFlags,
+    };
 
     // Ratatui re-exports
     pub use tui_dispatch_core::{Color, Frame, Line, Modifier, Rect, Span, Style, Text};
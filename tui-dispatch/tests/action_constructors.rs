@@ -0,0 +1,88 @@
+//! Tests for #[action(constructors)] and #[action(from)] on #[derive(Action)]
+
+use tui_dispatch::Action;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Location {
+    name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+}
+
+#[derive(Action, Clone, Debug, PartialEq)]
+#[action(constructors)]
+enum AppAction {
+    SearchInput(char),
+    #[action(from)]
+    SelectLocation(Location),
+    Resize(u16, u16),
+    #[action(from)]
+    Move {
+        direction: Direction,
+    },
+    DidLoad {
+        id: u64,
+        body: String,
+    },
+    // Would clash with the generated `schema()` / be no valid fn name
+    #[action(constructor = "schema_did_load")]
+    Schema(String),
+    #[action(constructor = "go_up")]
+    Super(u8),
+    Quit,
+}
+
+#[test]
+fn test_tuple_constructors() {
+    assert_eq!(AppAction::search_input('c'), AppAction::SearchInput('c'));
+    assert_eq!(AppAction::resize(80, 24), AppAction::Resize(80, 24));
+}
+
+#[test]
+fn test_struct_constructors() {
+    assert_eq!(
+        AppAction::did_load(7, "ok".into()),
+        AppAction::DidLoad {
+            id: 7,
+            body: "ok".into()
+        }
+    );
+    // Keyword names use raw identifiers
+    assert_eq!(
+        AppAction::r#move(Direction::Up),
+        AppAction::Move {
+            direction: Direction::Up
+        }
+    );
+}
+
+#[test]
+fn test_from_impls() {
+    let location = Location {
+        name: "Berlin".into(),
+    };
+    let action: AppAction = location.clone().into();
+    assert_eq!(action, AppAction::SelectLocation(location));
+
+    assert_eq!(
+        AppAction::from(Direction::Down),
+        AppAction::Move {
+            direction: Direction::Down
+        }
+    );
+    assert_eq!(AppAction::Quit.name(), "Quit");
+}
+
+#[test]
+fn test_renamed_constructors() {
+    assert_eq!(
+        AppAction::schema_did_load("v1".into()),
+        AppAction::Schema("v1".into())
+    );
+    assert_eq!(AppAction::go_up(2), AppAction::Super(2));
+    assert_eq!(AppAction::schema().variants.len(), 8);
+}
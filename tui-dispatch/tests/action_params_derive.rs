@@ -0,0 +1,63 @@
+//! Tests for #[derive(ActionParams)] and #[param(redact)]
+
+#![allow(dead_code)]
+
+use tui_dispatch::{Action, ActionParams};
+
+#[derive(Action, Clone, Debug)]
+enum AuthAction {
+    Login {
+        user: String,
+        #[param(redact)]
+        password: String,
+    },
+    SetToken(#[param(redact)] String),
+    Retry(u32, #[param(redact)] String),
+    Logout,
+}
+
+#[test]
+fn test_redact_named_field() {
+    let action = AuthAction::Login {
+        user: "ana".into(),
+        password: "hunter2".into(),
+    };
+    assert_eq!(action.params(), r#"user: "ana", password: ***"#);
+}
+
+#[test]
+fn test_redact_tuple_fields() {
+    assert_eq!(AuthAction::SetToken("secret".into()).params(), "***");
+    assert_eq!(AuthAction::Retry(3, "secret".into()).params(), "3, ***");
+    assert_eq!(AuthAction::Logout.params(), "");
+}
+
+#[test]
+fn test_standalone_derive() {
+    #[derive(ActionParams, Clone, Debug)]
+    enum Manual {
+        Connect {
+            host: String,
+            #[param(redact)]
+            api_key: String,
+        },
+        Ping,
+    }
+
+    impl tui_dispatch::Action for Manual {
+        fn name(&self) -> &'static str {
+            match self {
+                Manual::Connect { .. } => "Connect",
+                Manual::Ping => "Ping",
+            }
+        }
+    }
+
+    let action = Manual::Connect {
+        host: "localhost".into(),
+        api_key: "abc123".into(),
+    };
+    assert_eq!(action.params(), r#"host: "localhost", api_key: ***"#);
+    assert!(!action.params().contains("abc123"));
+    assert_eq!(Manual::Ping.params(), "");
+}
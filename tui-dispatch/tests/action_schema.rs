@@ -0,0 +1,113 @@
+//! Tests for the schema() generated by #[derive(Action)]
+
+#![allow(dead_code)]
+
+use tui_dispatch::{Action, ActionDescribe, ActionSchema, VariantKind};
+
+/// Actions for the file browser
+#[derive(Action, Clone, Debug)]
+#[action(infer_categories)]
+enum BrowserAction {
+    /// Move the selection
+    ///
+    /// Negative deltas move up.
+    ListMove {
+        delta: i32,
+    },
+    /// Open the entry under the cursor
+    ListOpen,
+    SearchUpdate(String),
+    #[action(category = "auth")]
+    Unlock {
+        /// Password for the vault
+        #[param(redact)]
+        password: String,
+        paths: Vec<(u16, std::path::PathBuf)>,
+    },
+    Quit,
+}
+
+#[derive(Action, Clone, Debug)]
+enum PlainAction {
+    Tick(u64, Option<&'static str>),
+    Quit,
+}
+
+fn schema_of<A: ActionDescribe>() -> ActionSchema {
+    A::schema()
+}
+
+#[test]
+fn test_schema_lists_variants_and_fields() {
+    let schema = BrowserAction::schema();
+    assert_eq!(schema.name, "BrowserAction");
+    assert_eq!(schema.doc.as_deref(), Some("Actions for the file browser"));
+
+    let names: Vec<_> = schema.variants.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["ListMove", "ListOpen", "SearchUpdate", "Unlock", "Quit"]
+    );
+
+    let list_move = schema.variant("ListMove").unwrap();
+    assert_eq!(list_move.kind, VariantKind::Struct);
+    assert_eq!(
+        list_move.doc.as_deref(),
+        Some("Move the selection\n\nNegative deltas move up.")
+    );
+    assert_eq!(list_move.fields[0].name.as_deref(), Some("delta"));
+    assert_eq!(list_move.fields[0].ty, "i32");
+
+    let search = schema.variant("SearchUpdate").unwrap();
+    assert_eq!(search.kind, VariantKind::Tuple);
+    assert_eq!(search.fields[0].name, None);
+    assert_eq!(search.fields[0].ty, "String");
+
+    let unlock = schema.variant("Unlock").unwrap();
+    assert!(unlock.fields[0].redacted);
+    assert_eq!(
+        unlock.fields[0].doc.as_deref(),
+        Some("Password for the vault")
+    );
+    assert!(!unlock.fields[1].redacted);
+    assert_eq!(unlock.fields[1].ty, "Vec<(u16, std::path::PathBuf)>");
+
+    let quit = schema.variant("Quit").unwrap();
+    assert_eq!(quit.kind, VariantKind::Unit);
+    assert!(quit.fields.is_empty());
+    assert_eq!(schema.variant("Missing"), None);
+}
+
+#[test]
+fn test_schema_categories() {
+    let schema = BrowserAction::schema();
+    assert_eq!(schema.category("list"), vec!["ListMove", "ListOpen"]);
+    assert_eq!(schema.category("auth"), vec!["Unlock"]);
+    assert_eq!(schema.variant("Quit").unwrap().category, None);
+
+    // Variant names match Action::name()
+    let action = BrowserAction::SearchUpdate("rs".into());
+    let variant = schema.variant(action.name()).unwrap();
+    assert_eq!(variant.category.as_deref(), action.category());
+    assert_eq!(action.category(), Some("search"));
+
+    // Without infer_categories nothing is categorized
+    let plain = schema_of::<PlainAction>();
+    assert!(plain.variants.iter().all(|v| v.category.is_none()));
+    assert_eq!(plain.doc, None);
+    assert_eq!(plain.variants[0].fields[1].ty, "Option<&'static str>");
+}
+
+#[test]
+fn test_schema_serializes_to_json() {
+    let json = BrowserAction::schema().to_json();
+    assert!(json.contains(r#""name": "BrowserAction""#));
+    assert!(json.contains(r#""kind": "struct""#));
+    assert!(json.contains(r#""redacted": true"#));
+    assert!(json.contains(r#""ty": "Vec<(u16, std::path::PathBuf)>""#));
+
+    // Empty docs, categories and fields are left out
+    let json = PlainAction::schema().to_json();
+    assert!(!json.contains("doc"));
+    assert!(!json.contains("category"));
+}
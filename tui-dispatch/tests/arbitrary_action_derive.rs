@@ -0,0 +1,86 @@
+//! Tests for #[derive(ArbitraryAction)] and reducer property testing
+#![cfg(feature = "proptest")]
+
+use tui_dispatch::proptest::prelude::*;
+use tui_dispatch::proptest::strategy::ValueTree;
+use tui_dispatch::proptest::test_runner::TestRunner;
+use tui_dispatch::testing::assert_reducer_invariant;
+use tui_dispatch::{Action, ArbitraryAction};
+
+#[derive(Action, ArbitraryAction, Clone, Debug, PartialEq)]
+enum ListAction {
+    #[arbitrary(weight = 3)]
+    Next,
+    Prev,
+    Select(#[arbitrary(strategy = "0..50usize")] usize),
+    SetItems {
+        #[arbitrary(strategy = "0..20usize")]
+        len: usize,
+        label: String,
+    },
+    #[arbitrary(skip)]
+    Quit,
+}
+
+#[derive(Debug, Clone)]
+struct ListState {
+    len: usize,
+    selected: usize,
+}
+
+fn reducer(state: &mut ListState, action: ListAction) -> bool {
+    let last = state.len.saturating_sub(1);
+    match action {
+        ListAction::Next => state.selected = (state.selected + 1).min(last),
+        ListAction::Prev => state.selected = state.selected.saturating_sub(1),
+        ListAction::Select(index) => state.selected = index.min(last),
+        ListAction::SetItems { len, .. } => {
+            state.len = len;
+            state.selected = state.selected.min(len.saturating_sub(1));
+        }
+        ListAction::Quit => return false,
+    }
+    true
+}
+
+fn in_range(state: &ListState) -> bool {
+    state.len == 0 || state.selected < state.len
+}
+
+#[test]
+fn test_derived_strategy_respects_attributes() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = any::<ListAction>();
+    let actions: Vec<_> = (0..500)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .collect();
+
+    assert!(!actions.contains(&ListAction::Quit));
+    assert!(actions.contains(&ListAction::Next));
+    assert!(actions.contains(&ListAction::Prev));
+    assert!(actions.iter().all(|action| match action {
+        ListAction::Select(index) => *index < 50,
+        ListAction::SetItems { len, .. } => *len < 20,
+        _ => true,
+    }));
+}
+
+#[test]
+fn test_reducer_keeps_selection_in_range() {
+    let states = (0..20usize).prop_map(|len| ListState { len, selected: 0 });
+    assert_reducer_invariant(states, any::<ListAction>(), reducer, in_range);
+}
+
+#[test]
+#[should_panic(expected = "reducer invariant failed")]
+fn test_reducer_invariant_catches_unclamped_select() {
+    let states = (1..20usize).prop_map(|len| ListState { len, selected: 0 });
+    let buggy = |state: &mut ListState, action: ListAction| match action {
+        ListAction::Select(index) => {
+            state.selected = index;
+            true
+        }
+        other => reducer(state, other),
+    };
+    assert_reducer_invariant(states, any::<ListAction>(), buggy, in_range);
+}
@@ -0,0 +1,77 @@
+//! Tests for #[derive(BindingContext)] macro
+
+use tui_dispatch::{parse_key_string, BindingContext, Keybindings};
+
+#[derive(BindingContext, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Context {
+    Default,
+    #[binding(parent = Default)]
+    Modal,
+    #[binding(parent = Modal)]
+    SearchModal,
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(Context::SearchModal.name(), "search_modal");
+    assert_eq!(Context::from_name("modal"), Some(Context::Modal));
+    assert_eq!(Context::all().len(), 3);
+}
+
+#[test]
+fn test_parent_attribute() {
+    assert_eq!(Context::Default.parent(), None);
+    assert_eq!(Context::Modal.parent(), Some(Context::Default));
+    assert_eq!(Context::SearchModal.parent(), Some(Context::Modal));
+}
+
+#[test]
+fn test_lookup_walks_parents() {
+    let mut bindings: Keybindings<Context> = Keybindings::new();
+    bindings.add(Context::Default, "help", vec!["?".into()]);
+    bindings.add(Context::Modal, "close", vec!["esc".into()]);
+
+    let esc = parse_key_string("esc").unwrap();
+    let help = parse_key_string("?").unwrap();
+    assert_eq!(
+        bindings.get_command(esc, Context::SearchModal),
+        Some("close".to_string())
+    );
+    assert_eq!(
+        bindings.get_command(help, Context::SearchModal),
+        Some("help".to_string())
+    );
+    assert_eq!(bindings.get_command(esc, Context::Default), None);
+}
+
+#[derive(BindingContext, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum RenamedContext {
+    Default,
+    #[context(name = "files", alias = "sidebar", alias = "tree")]
+    #[binding(parent = Default)]
+    FileTree,
+}
+
+#[test]
+fn test_custom_names_and_aliases() {
+    assert_eq!(RenamedContext::FileTree.name(), "files");
+    assert_eq!(RenamedContext::Default.name(), "default");
+    assert_eq!(
+        RenamedContext::from_name("files"),
+        Some(RenamedContext::FileTree)
+    );
+    assert_eq!(
+        RenamedContext::from_name("sidebar"),
+        Some(RenamedContext::FileTree)
+    );
+    assert_eq!(
+        RenamedContext::from_name("tree"),
+        Some(RenamedContext::FileTree)
+    );
+    // The variant name no longer matches
+    assert_eq!(RenamedContext::from_name("file_tree"), None);
+    assert_eq!(
+        RenamedContext::FileTree.parent(),
+        Some(RenamedContext::Default)
+    );
+}
@@ -183,3 +183,115 @@ fn test_build_debug_table() {
     assert_eq!(table.title, "My Table");
     assert!(!table.rows.is_empty());
 }
+
+#[test]
+fn test_collections_and_options() {
+    use std::collections::{BTreeMap, HashSet};
+
+    #[derive(DebugState)]
+    struct CollectionState {
+        items: Vec<u32>,
+        #[debug(limit = 1)]
+        tags: BTreeMap<String, usize>,
+        seen: HashSet<u8>,
+        selected: Option<usize>,
+        filter: Option<String>,
+        history: Option<Vec<&'static str>>,
+    }
+
+    let state = CollectionState {
+        items: (1..=8).collect(),
+        tags: [("a".to_string(), 1), ("b".to_string(), 2)]
+            .into_iter()
+            .collect(),
+        seen: HashSet::new(),
+        selected: Some(3),
+        filter: None,
+        history: Some(vec!["home"]),
+    };
+
+    let sections = state.debug_sections();
+    let values: Vec<&str> = sections[0]
+        .entries
+        .iter()
+        .map(|e| e.value.as_str())
+        .collect();
+    assert_eq!(
+        values,
+        [
+            "8 items [1, 2, 3, 4, 5, …]",
+            "2 items {\"a\": 1, …}",
+            "0 items []",
+            "3",
+            "None",
+            "1 item [\"home\"]",
+        ]
+    );
+}
+
+#[test]
+fn test_flatten_and_rename() {
+    #[derive(DebugState)]
+    struct Settings {
+        theme: String,
+        #[debug(section = "Keys")]
+        leader: char,
+    }
+
+    #[derive(DebugState)]
+    struct AppState {
+        #[debug_state(rename = "File")]
+        path: String,
+        #[debug_state(flatten, rename = "settings")]
+        settings: Settings,
+        #[debug_state(section = "UI")]
+        scroll: usize,
+        #[debug_state(skip)]
+        cache: Vec<u8>,
+    }
+
+    let state = AppState {
+        path: "notes.md".to_string(),
+        settings: Settings {
+            theme: "dark".to_string(),
+            leader: ' ',
+        },
+        scroll: 4,
+        cache: Vec::new(),
+    };
+
+    let sections = state.debug_sections();
+    let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        ["AppState", "settings / Settings", "settings / Keys", "UI"]
+    );
+    assert_eq!(sections[0].entries[0].key, "File");
+    assert_eq!(sections[0].entries.len(), 1);
+    assert_eq!(sections[1].entries[0].value, "dark");
+}
+
+#[test]
+fn test_snapshot_attribute() {
+    #[derive(DebugState, serde::Serialize)]
+    #[debug_state(snapshot)]
+    struct SnapshotState {
+        name: String,
+        count: usize,
+    }
+
+    #[derive(DebugState)]
+    struct PlainState {
+        count: usize,
+    }
+
+    let state = SnapshotState {
+        name: "bug".to_string(),
+        count: 3,
+    };
+    let json = state.debug_snapshot().expect("snapshot");
+    assert!(json.contains("\"name\": \"bug\""));
+    assert!(json.contains("\"count\": 3"));
+
+    assert!(PlainState { count: 1 }.debug_snapshot().is_none());
+}
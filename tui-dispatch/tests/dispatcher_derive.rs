@@ -0,0 +1,92 @@
+//! Tests for #[action(generate_dispatcher)] on #[derive(Action)]
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use tui_dispatch::Action;
+
+#[derive(Action, Clone, Debug)]
+#[action(infer_categories, generate_dispatcher)]
+enum SyncAction {
+    SearchStart,
+    SearchClear,
+    Tick,
+}
+
+#[derive(Action, Clone, Debug)]
+#[action(infer_categories, generate_dispatcher(async))]
+enum AsyncAction {
+    SearchStart(String),
+    SearchClear,
+    Tick,
+}
+
+#[derive(Default)]
+struct Handler {
+    queries: Vec<String>,
+}
+
+impl SyncActionDispatcher for Handler {
+    fn dispatch_search(&mut self, action: &SyncAction) -> bool {
+        self.queries.push(action.name().to_string());
+        true
+    }
+}
+
+impl AsyncActionDispatcher for Handler {
+    async fn dispatch_search(&mut self, action: &AsyncAction) -> bool {
+        let AsyncAction::SearchStart(query) = action else {
+            return false;
+        };
+        let results = std::future::ready(query.to_uppercase()).await;
+        self.queries.push(results);
+        true
+    }
+}
+
+/// Poll a future that never waits to completion
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future was not ready"),
+    }
+}
+
+#[test]
+fn test_sync_dispatcher_routes_by_category() {
+    let mut handler = Handler::default();
+    assert!(SyncActionDispatcher::dispatch(
+        &mut handler,
+        &SyncAction::SearchStart
+    ));
+    assert!(!SyncActionDispatcher::dispatch(
+        &mut handler,
+        &SyncAction::Tick
+    ));
+    assert_eq!(handler.queries, vec!["SearchStart"]);
+}
+
+#[test]
+fn test_async_dispatcher_awaits_handlers() {
+    let mut handler = Handler::default();
+    let query = AsyncAction::SearchStart("rust".into());
+
+    assert!(block_on(AsyncActionDispatcher::dispatch(
+        &mut handler,
+        &query
+    )));
+    assert!(!block_on(AsyncActionDispatcher::dispatch(
+        &mut handler,
+        &AsyncAction::SearchClear
+    )));
+    assert!(!block_on(AsyncActionDispatcher::dispatch(
+        &mut handler,
+        &AsyncAction::Tick
+    )));
+    assert_eq!(handler.queries, vec!["RUST"]);
+}
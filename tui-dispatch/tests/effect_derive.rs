@@ -0,0 +1,80 @@
+//! Tests for #[action(effect)] on #[derive(Action)]
+
+use tui_dispatch::{Action, EffectContext};
+
+#[derive(Action, Clone, Debug, PartialEq)]
+enum AppAction {
+    #[action(effect)]
+    Fetch {
+        url: String,
+        retries: u32,
+    },
+    #[action(effect)]
+    Copy(String),
+    #[action(effect)]
+    Quit,
+    DidFetch(String),
+}
+
+#[test]
+fn test_to_effect_mirrors_variants() {
+    let action = AppAction::Fetch {
+        url: "https://example.com".into(),
+        retries: 2,
+    };
+    match action.to_effect() {
+        Some(AppActionEffect::Fetch { url, retries }) => {
+            assert_eq!(url, "https://example.com");
+            assert_eq!(retries, 2);
+        }
+        other => panic!("unexpected effect: {other:?}"),
+    }
+
+    assert!(matches!(
+        AppAction::Copy("text".into()).to_effect(),
+        Some(AppActionEffect::Copy(text)) if text == "text"
+    ));
+    assert!(matches!(
+        AppAction::Quit.to_effect(),
+        Some(AppActionEffect::Quit)
+    ));
+}
+
+#[test]
+fn test_unmarked_variants_have_no_effect() {
+    assert!(AppAction::DidFetch("body".into()).to_effect().is_none());
+    assert_eq!(AppAction::DidFetch("body".into()).name(), "DidFetch");
+}
+
+#[test]
+fn test_handler_trait_is_implementable() {
+    #[derive(Default)]
+    struct Handler {
+        performed: Vec<String>,
+    }
+
+    impl AppActionEffectHandler for Handler {
+        fn perform_fetch(
+            &mut self,
+            _ctx: &mut EffectContext<'_, AppAction>,
+            url: String,
+            retries: u32,
+        ) {
+            self.performed.push(format!("fetch {url} x{retries}"));
+        }
+
+        fn perform_copy(&mut self, _ctx: &mut EffectContext<'_, AppAction>, text: String) {
+            self.performed.push(format!("copy {text}"));
+        }
+
+        fn perform_quit(&mut self, _ctx: &mut EffectContext<'_, AppAction>) {
+            self.performed.push("quit".into());
+        }
+    }
+
+    // The handler plugs into EffectRuntime as `|effect, ctx| handler.perform(effect, ctx)`
+    fn assert_handler<H: AppActionEffectHandler>(_: &H) {}
+    let handler = Handler::default();
+    assert_handler(&handler);
+    assert!(handler.performed.is_empty());
+}